@@ -52,6 +52,8 @@ extern "platform-intrinsic" {
     #[cfg(not(stage0))]
     pub fn simd_select_bitmask<M, T>(m: M, a: T, b: T) -> T;
 
+    pub fn simd_bitmask<T, U>(x: T) -> U;
+
     pub fn simd_fmin<T>(a: T, b: T) -> T;
     pub fn simd_fmax<T>(a: T, b: T) -> T;
 
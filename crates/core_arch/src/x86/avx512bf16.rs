@@ -0,0 +1,743 @@
+use crate::{
+    core_arch::{simd::*, simd_llvm::*, x86::*},
+    mem::transmute,
+};
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Converts packed single-precision (32-bit) floating-point elements in two
+/// vectors `a` and `b` to packed BF16 (16-bit) floating-point elements, and
+/// stores the results in a single vector `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtne2ps_pbh)
+#[inline]
+#[target_feature(enable = "avx512bf16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtne2ps2bf16))]
+pub unsafe fn _mm_cvtne2ps_pbh(a: __m128, b: __m128) -> __m128bh {
+    cvtne2ps2bf16_128(a, b)
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in two
+/// vectors `a` and `b` to packed BF16 (16-bit) floating-point elements, and
+/// stores the results in a single vector `dst` using writemask `k` (elements
+/// are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cvtne2ps_pbh)
+#[inline]
+#[target_feature(enable = "avx512bf16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtne2ps2bf16))]
+pub unsafe fn _mm_mask_cvtne2ps_pbh(src: __m128bh, k: __mmask8, a: __m128, b: __m128) -> __m128bh {
+    let r = _mm_cvtne2ps_pbh(a, b);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in two
+/// vectors `a` and `b` to packed BF16 (16-bit) floating-point elements, and
+/// stores the results in a single vector `dst` using zeromask `k` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_cvtne2ps_pbh)
+#[inline]
+#[target_feature(enable = "avx512bf16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtne2ps2bf16))]
+pub unsafe fn _mm_maskz_cvtne2ps_pbh(k: __mmask8, a: __m128, b: __m128) -> __m128bh {
+    let r = _mm_cvtne2ps_pbh(a, b);
+    let zero = transmute(_mm_setzero_si128());
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in two
+/// vectors `a` and `b` to packed BF16 (16-bit) floating-point elements, and
+/// stores the results in a single vector `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtne2ps_pbh)
+#[inline]
+#[target_feature(enable = "avx512bf16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtne2ps2bf16))]
+pub unsafe fn _mm256_cvtne2ps_pbh(a: __m256, b: __m256) -> __m256bh {
+    cvtne2ps2bf16_256(a, b)
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in two
+/// vectors `a` and `b` to packed BF16 (16-bit) floating-point elements, and
+/// stores the results in a single vector `dst` using writemask `k` (elements
+/// are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cvtne2ps_pbh)
+#[inline]
+#[target_feature(enable = "avx512bf16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtne2ps2bf16))]
+pub unsafe fn _mm256_mask_cvtne2ps_pbh(
+    src: __m256bh,
+    k: __mmask16,
+    a: __m256,
+    b: __m256,
+) -> __m256bh {
+    let r = _mm256_cvtne2ps_pbh(a, b);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in two
+/// vectors `a` and `b` to packed BF16 (16-bit) floating-point elements, and
+/// stores the results in a single vector `dst` using zeromask `k` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_cvtne2ps_pbh)
+#[inline]
+#[target_feature(enable = "avx512bf16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtne2ps2bf16))]
+pub unsafe fn _mm256_maskz_cvtne2ps_pbh(k: __mmask16, a: __m256, b: __m256) -> __m256bh {
+    let r = _mm256_cvtne2ps_pbh(a, b);
+    let zero = transmute(_mm256_setzero_si256());
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in two
+/// vectors `a` and `b` to packed BF16 (16-bit) floating-point elements, and
+/// stores the results in a single vector `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtne2ps_pbh)
+#[inline]
+#[target_feature(enable = "avx512bf16")]
+#[cfg_attr(test, assert_instr(vcvtne2ps2bf16))]
+pub unsafe fn _mm512_cvtne2ps_pbh(a: __m512, b: __m512) -> __m512bh {
+    cvtne2ps2bf16_512(a, b)
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in two
+/// vectors `a` and `b` to packed BF16 (16-bit) floating-point elements, and
+/// stores the results in a single vector `dst` using writemask `k` (elements
+/// are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtne2ps_pbh)
+#[inline]
+#[target_feature(enable = "avx512bf16")]
+#[cfg_attr(test, assert_instr(vcvtne2ps2bf16))]
+pub unsafe fn _mm512_mask_cvtne2ps_pbh(
+    src: __m512bh,
+    k: __mmask32,
+    a: __m512,
+    b: __m512,
+) -> __m512bh {
+    let r = _mm512_cvtne2ps_pbh(a, b);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in two
+/// vectors `a` and `b` to packed BF16 (16-bit) floating-point elements, and
+/// stores the results in a single vector `dst` using zeromask `k` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtne2ps_pbh)
+#[inline]
+#[target_feature(enable = "avx512bf16")]
+#[cfg_attr(test, assert_instr(vcvtne2ps2bf16))]
+pub unsafe fn _mm512_maskz_cvtne2ps_pbh(k: __mmask32, a: __m512, b: __m512) -> __m512bh {
+    let r = _mm512_cvtne2ps_pbh(a, b);
+    let zero = transmute(_mm512_setzero_si512());
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed BF16 (16-bit) floating-point elements, and stores the results in
+/// `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtneps_pbh)
+#[inline]
+#[target_feature(enable = "avx512bf16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtneps2bf16))]
+pub unsafe fn _mm256_cvtneps_pbh(a: __m256) -> __m128bh {
+    cvtneps2bf16_256(a)
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed BF16 (16-bit) floating-point elements, and stores the results in
+/// `dst` using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cvtneps_pbh)
+#[inline]
+#[target_feature(enable = "avx512bf16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtneps2bf16))]
+pub unsafe fn _mm256_mask_cvtneps_pbh(src: __m128bh, k: __mmask8, a: __m256) -> __m128bh {
+    let r = _mm256_cvtneps_pbh(a);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed BF16 (16-bit) floating-point elements, and stores the results in
+/// `dst` using zeromask `k` (elements are zeroed out when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_cvtneps_pbh)
+#[inline]
+#[target_feature(enable = "avx512bf16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtneps2bf16))]
+pub unsafe fn _mm256_maskz_cvtneps_pbh(k: __mmask8, a: __m256) -> __m128bh {
+    let r = _mm256_cvtneps_pbh(a);
+    let zero = transmute(_mm_setzero_si128());
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed BF16 (16-bit) floating-point elements, and stores the results in
+/// `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtneps_pbh)
+#[inline]
+#[target_feature(enable = "avx512bf16")]
+#[cfg_attr(test, assert_instr(vcvtneps2bf16))]
+pub unsafe fn _mm512_cvtneps_pbh(a: __m512) -> __m256bh {
+    cvtneps2bf16_512(a)
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed BF16 (16-bit) floating-point elements, and stores the results in
+/// `dst` using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtneps_pbh)
+#[inline]
+#[target_feature(enable = "avx512bf16")]
+#[cfg_attr(test, assert_instr(vcvtneps2bf16))]
+pub unsafe fn _mm512_mask_cvtneps_pbh(src: __m256bh, k: __mmask16, a: __m512) -> __m256bh {
+    let r = _mm512_cvtneps_pbh(a);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed BF16 (16-bit) floating-point elements, and stores the results in
+/// `dst` using zeromask `k` (elements are zeroed out when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtneps_pbh)
+#[inline]
+#[target_feature(enable = "avx512bf16")]
+#[cfg_attr(test, assert_instr(vcvtneps2bf16))]
+pub unsafe fn _mm512_maskz_cvtneps_pbh(k: __mmask16, a: __m512) -> __m256bh {
+    let r = _mm512_cvtneps_pbh(a);
+    let zero = transmute(_mm256_setzero_si256());
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Computes the dot product of BF16 (16-bit) floating-point pairs in `a` and
+/// `b`, accumulating the intermediate single-precision (32-bit) floating-point
+/// elements with elements in `src`, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_dpbf16_ps)
+#[inline]
+#[target_feature(enable = "avx512bf16,avx512vl")]
+#[cfg_attr(test, assert_instr(vdpbf16ps))]
+pub unsafe fn _mm_dpbf16_ps(src: __m128, a: __m128bh, b: __m128bh) -> __m128 {
+    dpbf16ps_128(src, transmute(a), transmute(b))
+}
+
+/// Computes the dot product of BF16 (16-bit) floating-point pairs in `a` and
+/// `b`, accumulating the intermediate single-precision (32-bit) floating-point
+/// elements with elements in `src`, and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_dpbf16_ps)
+#[inline]
+#[target_feature(enable = "avx512bf16,avx512vl")]
+#[cfg_attr(test, assert_instr(vdpbf16ps))]
+pub unsafe fn _mm_mask_dpbf16_ps(src: __m128, k: __mmask8, a: __m128bh, b: __m128bh) -> __m128 {
+    let r = _mm_dpbf16_ps(src, a, b);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Computes the dot product of BF16 (16-bit) floating-point pairs in `a` and
+/// `b`, accumulating the intermediate single-precision (32-bit) floating-point
+/// elements with elements in `src`, and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_dpbf16_ps)
+#[inline]
+#[target_feature(enable = "avx512bf16,avx512vl")]
+#[cfg_attr(test, assert_instr(vdpbf16ps))]
+pub unsafe fn _mm_maskz_dpbf16_ps(k: __mmask8, src: __m128, a: __m128bh, b: __m128bh) -> __m128 {
+    let r = _mm_dpbf16_ps(src, a, b);
+    let zero = _mm_setzero_ps();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Computes the dot product of BF16 (16-bit) floating-point pairs in `a` and
+/// `b`, accumulating the intermediate single-precision (32-bit) floating-point
+/// elements with elements in `src`, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_dpbf16_ps)
+#[inline]
+#[target_feature(enable = "avx512bf16,avx512vl")]
+#[cfg_attr(test, assert_instr(vdpbf16ps))]
+pub unsafe fn _mm256_dpbf16_ps(src: __m256, a: __m256bh, b: __m256bh) -> __m256 {
+    dpbf16ps_256(src, transmute(a), transmute(b))
+}
+
+/// Computes the dot product of BF16 (16-bit) floating-point pairs in `a` and
+/// `b`, accumulating the intermediate single-precision (32-bit) floating-point
+/// elements with elements in `src`, and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_dpbf16_ps)
+#[inline]
+#[target_feature(enable = "avx512bf16,avx512vl")]
+#[cfg_attr(test, assert_instr(vdpbf16ps))]
+pub unsafe fn _mm256_mask_dpbf16_ps(src: __m256, k: __mmask8, a: __m256bh, b: __m256bh) -> __m256 {
+    let r = _mm256_dpbf16_ps(src, a, b);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Computes the dot product of BF16 (16-bit) floating-point pairs in `a` and
+/// `b`, accumulating the intermediate single-precision (32-bit) floating-point
+/// elements with elements in `src`, and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_dpbf16_ps)
+#[inline]
+#[target_feature(enable = "avx512bf16,avx512vl")]
+#[cfg_attr(test, assert_instr(vdpbf16ps))]
+pub unsafe fn _mm256_maskz_dpbf16_ps(k: __mmask8, src: __m256, a: __m256bh, b: __m256bh) -> __m256 {
+    let r = _mm256_dpbf16_ps(src, a, b);
+    let zero = _mm256_setzero_ps();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Computes the dot product of BF16 (16-bit) floating-point pairs in `a` and
+/// `b`, accumulating the intermediate single-precision (32-bit) floating-point
+/// elements with elements in `src`, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_dpbf16_ps)
+#[inline]
+#[target_feature(enable = "avx512bf16")]
+#[cfg_attr(test, assert_instr(vdpbf16ps))]
+pub unsafe fn _mm512_dpbf16_ps(src: __m512, a: __m512bh, b: __m512bh) -> __m512 {
+    dpbf16ps_512(src, transmute(a), transmute(b))
+}
+
+/// Computes the dot product of BF16 (16-bit) floating-point pairs in `a` and
+/// `b`, accumulating the intermediate single-precision (32-bit) floating-point
+/// elements with elements in `src`, and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_dpbf16_ps)
+#[inline]
+#[target_feature(enable = "avx512bf16")]
+#[cfg_attr(test, assert_instr(vdpbf16ps))]
+pub unsafe fn _mm512_mask_dpbf16_ps(src: __m512, k: __mmask16, a: __m512bh, b: __m512bh) -> __m512 {
+    let r = _mm512_dpbf16_ps(src, a, b);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Computes the dot product of BF16 (16-bit) floating-point pairs in `a` and
+/// `b`, accumulating the intermediate single-precision (32-bit) floating-point
+/// elements with elements in `src`, and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_dpbf16_ps)
+#[inline]
+#[target_feature(enable = "avx512bf16")]
+#[cfg_attr(test, assert_instr(vdpbf16ps))]
+pub unsafe fn _mm512_maskz_dpbf16_ps(
+    k: __mmask16,
+    src: __m512,
+    a: __m512bh,
+    b: __m512bh,
+) -> __m512 {
+    let r = _mm512_dpbf16_ps(src, a, b);
+    let zero = _mm512_setzero_ps();
+    simd_select_bitmask(k, r, zero)
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.avx512bf16.cvtne2ps2bf16.128"]
+    fn cvtne2ps2bf16_128(a: __m128, b: __m128) -> __m128bh;
+    #[link_name = "llvm.x86.avx512bf16.cvtne2ps2bf16.256"]
+    fn cvtne2ps2bf16_256(a: __m256, b: __m256) -> __m256bh;
+    #[link_name = "llvm.x86.avx512bf16.cvtne2ps2bf16.512"]
+    fn cvtne2ps2bf16_512(a: __m512, b: __m512) -> __m512bh;
+    #[link_name = "llvm.x86.avx512bf16.cvtneps2bf16.256"]
+    fn cvtneps2bf16_256(a: __m256) -> __m128bh;
+    #[link_name = "llvm.x86.avx512bf16.cvtneps2bf16.512"]
+    fn cvtneps2bf16_512(a: __m512) -> __m256bh;
+    #[link_name = "llvm.x86.avx512bf16.dpbf16ps.128"]
+    fn dpbf16ps_128(src: __m128, a: i32x4, b: i32x4) -> __m128;
+    #[link_name = "llvm.x86.avx512bf16.dpbf16ps.256"]
+    fn dpbf16ps_256(src: __m256, a: i32x8, b: i32x8) -> __m256;
+    #[link_name = "llvm.x86.avx512bf16.dpbf16ps.512"]
+    fn dpbf16ps_512(src: __m512, a: i32x16, b: i32x16) -> __m512;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use std::mem::transmute;
+    use stdsimd_test::simd_test;
+
+    #[simd_test(enable = "avx512bf16,avx512vl")]
+    unsafe fn test_mm_cvtne2ps_pbh() {
+        let a = _mm_setr_ps(1., 1.5, -2., 1.011_718_75);
+        let b = _mm_setr_ps(0.5, 3., -0.25, 100.);
+        let r = _mm_cvtne2ps_pbh(a, b);
+        #[rustfmt::skip]
+        let e: __m128bh = transmute([
+            0x3f00_u16, 0x4040, 0xbe80, 0x42c8, 0x3f80, 0x3fc0, 0xc000, 0x3f82,
+        ]);
+        assert_eq_m128bh(r, e);
+    }
+
+    #[simd_test(enable = "avx512bf16,avx512vl")]
+    unsafe fn test_mm_mask_cvtne2ps_pbh() {
+        let a = _mm_setr_ps(1., 1.5, -2., 1.011_718_75);
+        let b = _mm_setr_ps(0.5, 3., -0.25, 100.);
+        let src: __m128bh = transmute([0x7fc0_u16; 8]);
+        let r = _mm_mask_cvtne2ps_pbh(src, 0, a, b);
+        assert_eq_m128bh(r, src);
+        let r = _mm_mask_cvtne2ps_pbh(src, 0b00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m128bh = transmute([
+            0x3f00_u16, 0x7fc0, 0xbe80, 0x7fc0, 0x3f80, 0x3fc0, 0x7fc0, 0x7fc0,
+        ]);
+        assert_eq_m128bh(r, e);
+    }
+
+    #[simd_test(enable = "avx512bf16,avx512vl")]
+    unsafe fn test_mm_maskz_cvtne2ps_pbh() {
+        let a = _mm_setr_ps(1., 1.5, -2., 1.011_718_75);
+        let b = _mm_setr_ps(0.5, 3., -0.25, 100.);
+        let r = _mm_maskz_cvtne2ps_pbh(0, a, b);
+        assert_eq_m128bh(r, transmute(_mm_setzero_si128()));
+        let r = _mm_maskz_cvtne2ps_pbh(0b00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m128bh = transmute([
+            0x3f00_u16, 0x0000, 0xbe80, 0x0000, 0x3f80, 0x3fc0, 0x0000, 0x0000,
+        ]);
+        assert_eq_m128bh(r, e);
+    }
+
+    #[simd_test(enable = "avx512bf16,avx512vl")]
+    unsafe fn test_mm256_cvtne2ps_pbh() {
+        let a = _mm256_setr_ps(1., 1.5, -2., 1.011_718_75, 1., 1.5, -2., 1.011_718_75);
+        let b = _mm256_setr_ps(0.5, 3., -0.25, 100., 0.5, 3., -0.25, 100.);
+        let r = _mm256_cvtne2ps_pbh(a, b);
+        #[rustfmt::skip]
+        let e: __m256bh = transmute([
+            0x3f00_u16, 0x4040, 0xbe80, 0x42c8, 0x3f00, 0x4040, 0xbe80, 0x42c8,
+            0x3f80, 0x3fc0, 0xc000, 0x3f82, 0x3f80, 0x3fc0, 0xc000, 0x3f82,
+        ]);
+        assert_eq_m256bh(r, e);
+    }
+
+    #[simd_test(enable = "avx512bf16,avx512vl")]
+    unsafe fn test_mm256_mask_cvtne2ps_pbh() {
+        let a = _mm256_setr_ps(1., 1.5, -2., 1.011_718_75, 1., 1.5, -2., 1.011_718_75);
+        let b = _mm256_setr_ps(0.5, 3., -0.25, 100., 0.5, 3., -0.25, 100.);
+        let src: __m256bh = transmute([0x7fc0_u16; 16]);
+        let r = _mm256_mask_cvtne2ps_pbh(src, 0, a, b);
+        assert_eq_m256bh(r, src);
+        let r = _mm256_mask_cvtne2ps_pbh(src, 0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m256bh = transmute([
+            0x3f00_u16, 0x7fc0, 0xbe80, 0x7fc0, 0x3f00, 0x4040, 0x7fc0, 0x7fc0,
+            0x7fc0, 0x3fc0, 0x7fc0, 0x3f82, 0x7fc0, 0x7fc0, 0xc000, 0x3f82,
+        ]);
+        assert_eq_m256bh(r, e);
+    }
+
+    #[simd_test(enable = "avx512bf16,avx512vl")]
+    unsafe fn test_mm256_maskz_cvtne2ps_pbh() {
+        let a = _mm256_setr_ps(1., 1.5, -2., 1.011_718_75, 1., 1.5, -2., 1.011_718_75);
+        let b = _mm256_setr_ps(0.5, 3., -0.25, 100., 0.5, 3., -0.25, 100.);
+        let r = _mm256_maskz_cvtne2ps_pbh(0, a, b);
+        assert_eq_m256bh(r, transmute(_mm256_setzero_si256()));
+        let r = _mm256_maskz_cvtne2ps_pbh(0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m256bh = transmute([
+            0x3f00_u16, 0x0000, 0xbe80, 0x0000, 0x3f00, 0x4040, 0x0000, 0x0000,
+            0x0000, 0x3fc0, 0x0000, 0x3f82, 0x0000, 0x0000, 0xc000, 0x3f82,
+        ]);
+        assert_eq_m256bh(r, e);
+    }
+
+    #[simd_test(enable = "avx512bf16")]
+    unsafe fn test_mm512_cvtne2ps_pbh() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1., 1.5, -2., 1.011_718_75, 1., 1.5, -2., 1.011_718_75,
+            1., 1.5, -2., 1.011_718_75, 1., 1.5, -2., 1.011_718_75,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_setr_ps(
+            0.5, 3., -0.25, 100., 0.5, 3., -0.25, 100.,
+            0.5, 3., -0.25, 100., 0.5, 3., -0.25, 100.,
+        );
+        let r = _mm512_cvtne2ps_pbh(a, b);
+        #[rustfmt::skip]
+        let e: __m512bh = transmute([
+            0x3f00_u16, 0x4040, 0xbe80, 0x42c8, 0x3f00, 0x4040, 0xbe80, 0x42c8,
+            0x3f00, 0x4040, 0xbe80, 0x42c8, 0x3f00, 0x4040, 0xbe80, 0x42c8,
+            0x3f80, 0x3fc0, 0xc000, 0x3f82, 0x3f80, 0x3fc0, 0xc000, 0x3f82,
+            0x3f80, 0x3fc0, 0xc000, 0x3f82, 0x3f80, 0x3fc0, 0xc000, 0x3f82,
+        ]);
+        assert_eq_m512bh(r, e);
+    }
+
+    #[simd_test(enable = "avx512bf16")]
+    unsafe fn test_mm512_mask_cvtne2ps_pbh() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1., 1.5, -2., 1.011_718_75, 1., 1.5, -2., 1.011_718_75,
+            1., 1.5, -2., 1.011_718_75, 1., 1.5, -2., 1.011_718_75,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_setr_ps(
+            0.5, 3., -0.25, 100., 0.5, 3., -0.25, 100.,
+            0.5, 3., -0.25, 100., 0.5, 3., -0.25, 100.,
+        );
+        let src: __m512bh = transmute([0x7fc0_u16; 32]);
+        let r = _mm512_mask_cvtne2ps_pbh(src, 0, a, b);
+        assert_eq_m512bh(r, src);
+        let r = _mm512_mask_cvtne2ps_pbh(src, 0b01101001_10010110_11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m512bh = transmute([
+            0x3f00_u16, 0x7fc0, 0xbe80, 0x7fc0, 0x3f00, 0x4040, 0x7fc0, 0x7fc0,
+            0x7fc0, 0x4040, 0x7fc0, 0x42c8, 0x7fc0, 0x7fc0, 0xbe80, 0x42c8,
+            0x7fc0, 0x3fc0, 0xc000, 0x7fc0, 0x3f80, 0x7fc0, 0x7fc0, 0x3f82,
+            0x3f80, 0x7fc0, 0x7fc0, 0x3f82, 0x7fc0, 0x3fc0, 0xc000, 0x7fc0,
+        ]);
+        assert_eq_m512bh(r, e);
+    }
+
+    #[simd_test(enable = "avx512bf16")]
+    unsafe fn test_mm512_maskz_cvtne2ps_pbh() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1., 1.5, -2., 1.011_718_75, 1., 1.5, -2., 1.011_718_75,
+            1., 1.5, -2., 1.011_718_75, 1., 1.5, -2., 1.011_718_75,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_setr_ps(
+            0.5, 3., -0.25, 100., 0.5, 3., -0.25, 100.,
+            0.5, 3., -0.25, 100., 0.5, 3., -0.25, 100.,
+        );
+        let r = _mm512_maskz_cvtne2ps_pbh(0, a, b);
+        assert_eq_m512bh(r, transmute(_mm512_setzero_si512()));
+        let r = _mm512_maskz_cvtne2ps_pbh(0b01101001_10010110_11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m512bh = transmute([
+            0x3f00_u16, 0x0000, 0xbe80, 0x0000, 0x3f00, 0x4040, 0x0000, 0x0000,
+            0x0000, 0x4040, 0x0000, 0x42c8, 0x0000, 0x0000, 0xbe80, 0x42c8,
+            0x0000, 0x3fc0, 0xc000, 0x0000, 0x3f80, 0x0000, 0x0000, 0x3f82,
+            0x3f80, 0x0000, 0x0000, 0x3f82, 0x0000, 0x3fc0, 0xc000, 0x0000,
+        ]);
+        assert_eq_m512bh(r, e);
+    }
+
+    #[simd_test(enable = "avx512bf16,avx512vl")]
+    unsafe fn test_mm256_cvtneps_pbh() {
+        let a = _mm256_setr_ps(1., 1.5, -2., 1.011_718_75, 1., 1.5, -2., 1.011_718_75);
+        let r = _mm256_cvtneps_pbh(a);
+        #[rustfmt::skip]
+        let e: __m128bh = transmute([
+            0x3f80_u16, 0x3fc0, 0xc000, 0x3f82, 0x3f80, 0x3fc0, 0xc000, 0x3f82,
+        ]);
+        assert_eq_m128bh(r, e);
+    }
+
+    #[simd_test(enable = "avx512bf16,avx512vl")]
+    unsafe fn test_mm256_mask_cvtneps_pbh() {
+        let a = _mm256_setr_ps(1., 1.5, -2., 1.011_718_75, 1., 1.5, -2., 1.011_718_75);
+        let src: __m128bh = transmute([0x7fc0_u16; 8]);
+        let r = _mm256_mask_cvtneps_pbh(src, 0, a);
+        assert_eq_m128bh(r, src);
+        let r = _mm256_mask_cvtneps_pbh(src, 0b00110101, a);
+        #[rustfmt::skip]
+        let e: __m128bh = transmute([
+            0x3f80_u16, 0x7fc0, 0xc000, 0x7fc0, 0x3f80, 0x3fc0, 0x7fc0, 0x7fc0,
+        ]);
+        assert_eq_m128bh(r, e);
+    }
+
+    #[simd_test(enable = "avx512bf16,avx512vl")]
+    unsafe fn test_mm256_maskz_cvtneps_pbh() {
+        let a = _mm256_setr_ps(1., 1.5, -2., 1.011_718_75, 1., 1.5, -2., 1.011_718_75);
+        let r = _mm256_maskz_cvtneps_pbh(0, a);
+        assert_eq_m128bh(r, transmute(_mm_setzero_si128()));
+        let r = _mm256_maskz_cvtneps_pbh(0b00110101, a);
+        #[rustfmt::skip]
+        let e: __m128bh = transmute([
+            0x3f80_u16, 0x0000, 0xc000, 0x0000, 0x3f80, 0x3fc0, 0x0000, 0x0000,
+        ]);
+        assert_eq_m128bh(r, e);
+    }
+
+    #[simd_test(enable = "avx512bf16")]
+    unsafe fn test_mm512_cvtneps_pbh() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1., 1.5, -2., 1.011_718_75, 1., 1.5, -2., 1.011_718_75,
+            1., 1.5, -2., 1.011_718_75, 1., 1.5, -2., 1.011_718_75,
+        );
+        let r = _mm512_cvtneps_pbh(a);
+        #[rustfmt::skip]
+        let e: __m256bh = transmute([
+            0x3f80_u16, 0x3fc0, 0xc000, 0x3f82, 0x3f80, 0x3fc0, 0xc000, 0x3f82,
+            0x3f80, 0x3fc0, 0xc000, 0x3f82, 0x3f80, 0x3fc0, 0xc000, 0x3f82,
+        ]);
+        assert_eq_m256bh(r, e);
+    }
+
+    #[simd_test(enable = "avx512bf16")]
+    unsafe fn test_mm512_mask_cvtneps_pbh() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1., 1.5, -2., 1.011_718_75, 1., 1.5, -2., 1.011_718_75,
+            1., 1.5, -2., 1.011_718_75, 1., 1.5, -2., 1.011_718_75,
+        );
+        let src: __m256bh = transmute([0x7fc0_u16; 16]);
+        let r = _mm512_mask_cvtneps_pbh(src, 0, a);
+        assert_eq_m256bh(r, src);
+        let r = _mm512_mask_cvtneps_pbh(src, 0b11001010_00110101, a);
+        #[rustfmt::skip]
+        let e: __m256bh = transmute([
+            0x3f80_u16, 0x7fc0, 0xc000, 0x7fc0, 0x3f80, 0x3fc0, 0x7fc0, 0x7fc0,
+            0x7fc0, 0x3fc0, 0x7fc0, 0x3f82, 0x7fc0, 0x7fc0, 0xc000, 0x3f82,
+        ]);
+        assert_eq_m256bh(r, e);
+    }
+
+    #[simd_test(enable = "avx512bf16")]
+    unsafe fn test_mm512_maskz_cvtneps_pbh() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1., 1.5, -2., 1.011_718_75, 1., 1.5, -2., 1.011_718_75,
+            1., 1.5, -2., 1.011_718_75, 1., 1.5, -2., 1.011_718_75,
+        );
+        let r = _mm512_maskz_cvtneps_pbh(0, a);
+        assert_eq_m256bh(r, transmute(_mm256_setzero_si256()));
+        let r = _mm512_maskz_cvtneps_pbh(0b11001010_00110101, a);
+        #[rustfmt::skip]
+        let e: __m256bh = transmute([
+            0x3f80_u16, 0x0000, 0xc000, 0x0000, 0x3f80, 0x3fc0, 0x0000, 0x0000,
+            0x0000, 0x3fc0, 0x0000, 0x3f82, 0x0000, 0x0000, 0xc000, 0x3f82,
+        ]);
+        assert_eq_m256bh(r, e);
+    }
+
+    #[simd_test(enable = "avx512bf16,avx512vl")]
+    unsafe fn test_mm_dpbf16_ps() {
+        let src = _mm_set1_ps(1.);
+        let a: __m128bh = transmute(_mm_set1_epi32(0x4000_3f80));
+        let b: __m128bh = transmute(_mm_set1_epi32(0x3f00_4040));
+        let r = _mm_dpbf16_ps(src, a, b);
+        let e = _mm_set1_ps(5.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512bf16,avx512vl")]
+    unsafe fn test_mm_mask_dpbf16_ps() {
+        let src = _mm_set1_ps(1.);
+        let a: __m128bh = transmute(_mm_set1_epi32(0x4000_3f80));
+        let b: __m128bh = transmute(_mm_set1_epi32(0x3f00_4040));
+        let r = _mm_mask_dpbf16_ps(src, 0, a, b);
+        assert_eq_m128(r, src);
+        let r = _mm_mask_dpbf16_ps(src, 0b00001010, a, b);
+        let e = _mm_setr_ps(1., 5., 1., 5.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512bf16,avx512vl")]
+    unsafe fn test_mm_maskz_dpbf16_ps() {
+        let src = _mm_set1_ps(1.);
+        let a: __m128bh = transmute(_mm_set1_epi32(0x4000_3f80));
+        let b: __m128bh = transmute(_mm_set1_epi32(0x3f00_4040));
+        let r = _mm_maskz_dpbf16_ps(0, src, a, b);
+        assert_eq_m128(r, _mm_setzero_ps());
+        let r = _mm_maskz_dpbf16_ps(0b00001010, src, a, b);
+        let e = _mm_setr_ps(0., 5., 0., 5.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512bf16,avx512vl")]
+    unsafe fn test_mm256_dpbf16_ps() {
+        let src = _mm256_set1_ps(1.);
+        let a: __m256bh = transmute(_mm256_set1_epi32(0x4000_3f80));
+        let b: __m256bh = transmute(_mm256_set1_epi32(0x3f00_4040));
+        let r = _mm256_dpbf16_ps(src, a, b);
+        let e = _mm256_set1_ps(5.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512bf16,avx512vl")]
+    unsafe fn test_mm256_mask_dpbf16_ps() {
+        let src = _mm256_set1_ps(1.);
+        let a: __m256bh = transmute(_mm256_set1_epi32(0x4000_3f80));
+        let b: __m256bh = transmute(_mm256_set1_epi32(0x3f00_4040));
+        let r = _mm256_mask_dpbf16_ps(src, 0, a, b);
+        assert_eq_m256(r, src);
+        let r = _mm256_mask_dpbf16_ps(src, 0b00110101, a, b);
+        let e = _mm256_setr_ps(5., 1., 5., 1., 5., 5., 1., 1.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512bf16,avx512vl")]
+    unsafe fn test_mm256_maskz_dpbf16_ps() {
+        let src = _mm256_set1_ps(1.);
+        let a: __m256bh = transmute(_mm256_set1_epi32(0x4000_3f80));
+        let b: __m256bh = transmute(_mm256_set1_epi32(0x3f00_4040));
+        let r = _mm256_maskz_dpbf16_ps(0, src, a, b);
+        assert_eq_m256(r, _mm256_setzero_ps());
+        let r = _mm256_maskz_dpbf16_ps(0b00110101, src, a, b);
+        let e = _mm256_setr_ps(5., 0., 5., 0., 5., 5., 0., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512bf16")]
+    unsafe fn test_mm512_dpbf16_ps() {
+        let src = _mm512_set1_ps(1.);
+        let a: __m512bh = transmute(_mm512_set1_epi32(0x4000_3f80));
+        let b: __m512bh = transmute(_mm512_set1_epi32(0x3f00_4040));
+        let r = _mm512_dpbf16_ps(src, a, b);
+        let e = _mm512_set1_ps(5.);
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512bf16")]
+    unsafe fn test_mm512_mask_dpbf16_ps() {
+        let src = _mm512_set1_ps(1.);
+        let a: __m512bh = transmute(_mm512_set1_epi32(0x4000_3f80));
+        let b: __m512bh = transmute(_mm512_set1_epi32(0x3f00_4040));
+        let r = _mm512_mask_dpbf16_ps(src, 0, a, b);
+        assert_eq_m512(r, src);
+        let r = _mm512_mask_dpbf16_ps(src, 0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_setr_ps(
+            5., 1., 5., 1., 5., 5., 1., 1.,
+            1., 5., 1., 5., 1., 1., 5., 5.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512bf16")]
+    unsafe fn test_mm512_maskz_dpbf16_ps() {
+        let src = _mm512_set1_ps(1.);
+        let a: __m512bh = transmute(_mm512_set1_epi32(0x4000_3f80));
+        let b: __m512bh = transmute(_mm512_set1_epi32(0x3f00_4040));
+        let r = _mm512_maskz_dpbf16_ps(0, src, a, b);
+        assert_eq_m512(r, _mm512_setzero_ps());
+        let r = _mm512_maskz_dpbf16_ps(0b11001010_00110101, src, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_setr_ps(
+            5., 0., 5., 0., 5., 5., 0., 0.,
+            0., 5., 0., 5., 0., 0., 5., 5.,
+        );
+        assert_eq_m512(r, e);
+    }
+}
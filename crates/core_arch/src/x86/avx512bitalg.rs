@@ -0,0 +1,699 @@
+use crate::{
+    core_arch::{simd::*, simd_llvm::*, x86::*},
+    mem::transmute,
+};
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Counts the number of logical 1 bits in packed 8-bit integers in `a`, and
+/// stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_popcnt_epi8)
+#[inline]
+#[target_feature(enable = "avx512bitalg")]
+#[cfg_attr(test, assert_instr(vpopcntb))]
+pub unsafe fn _mm512_popcnt_epi8(a: __m512i) -> __m512i {
+    transmute(popcnt_v64i8(a.as_i8x64()))
+}
+
+/// Counts the number of logical 1 bits in packed 8-bit integers in `a`, and
+/// stores the results in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_popcnt_epi8)
+#[inline]
+#[target_feature(enable = "avx512bitalg")]
+#[cfg_attr(test, assert_instr(vpopcntb))]
+pub unsafe fn _mm512_mask_popcnt_epi8(src: __m512i, k: __mmask64, a: __m512i) -> __m512i {
+    let r = _mm512_popcnt_epi8(a).as_i8x64();
+    transmute(simd_select_bitmask(k, r, src.as_i8x64()))
+}
+
+/// Counts the number of logical 1 bits in packed 8-bit integers in `a`, and
+/// stores the results in `dst` using zeromask `k` (elements are zeroed out when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_popcnt_epi8)
+#[inline]
+#[target_feature(enable = "avx512bitalg")]
+#[cfg_attr(test, assert_instr(vpopcntb))]
+pub unsafe fn _mm512_maskz_popcnt_epi8(k: __mmask64, a: __m512i) -> __m512i {
+    let r = _mm512_popcnt_epi8(a).as_i8x64();
+    let zero = _mm512_setzero_si512().as_i8x64();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Counts the number of logical 1 bits in packed 8-bit integers in `a`, and
+/// stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_popcnt_epi8)
+#[inline]
+#[target_feature(enable = "avx512bitalg,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntb))]
+pub unsafe fn _mm256_popcnt_epi8(a: __m256i) -> __m256i {
+    transmute(popcnt_v32i8(a.as_i8x32()))
+}
+
+/// Counts the number of logical 1 bits in packed 8-bit integers in `a`, and
+/// stores the results in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_popcnt_epi8)
+#[inline]
+#[target_feature(enable = "avx512bitalg,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntb))]
+pub unsafe fn _mm256_mask_popcnt_epi8(src: __m256i, k: __mmask32, a: __m256i) -> __m256i {
+    let r = _mm256_popcnt_epi8(a).as_i8x32();
+    transmute(simd_select_bitmask(k, r, src.as_i8x32()))
+}
+
+/// Counts the number of logical 1 bits in packed 8-bit integers in `a`, and
+/// stores the results in `dst` using zeromask `k` (elements are zeroed out when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_popcnt_epi8)
+#[inline]
+#[target_feature(enable = "avx512bitalg,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntb))]
+pub unsafe fn _mm256_maskz_popcnt_epi8(k: __mmask32, a: __m256i) -> __m256i {
+    let r = _mm256_popcnt_epi8(a).as_i8x32();
+    let zero = _mm256_setzero_si256().as_i8x32();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Counts the number of logical 1 bits in packed 8-bit integers in `a`, and
+/// stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_popcnt_epi8)
+#[inline]
+#[target_feature(enable = "avx512bitalg,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntb))]
+pub unsafe fn _mm_popcnt_epi8(a: __m128i) -> __m128i {
+    transmute(popcnt_v16i8(a.as_i8x16()))
+}
+
+/// Counts the number of logical 1 bits in packed 8-bit integers in `a`, and
+/// stores the results in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_popcnt_epi8)
+#[inline]
+#[target_feature(enable = "avx512bitalg,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntb))]
+pub unsafe fn _mm_mask_popcnt_epi8(src: __m128i, k: __mmask16, a: __m128i) -> __m128i {
+    let r = _mm_popcnt_epi8(a).as_i8x16();
+    transmute(simd_select_bitmask(k, r, src.as_i8x16()))
+}
+
+/// Counts the number of logical 1 bits in packed 8-bit integers in `a`, and
+/// stores the results in `dst` using zeromask `k` (elements are zeroed out when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_popcnt_epi8)
+#[inline]
+#[target_feature(enable = "avx512bitalg,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntb))]
+pub unsafe fn _mm_maskz_popcnt_epi8(k: __mmask16, a: __m128i) -> __m128i {
+    let r = _mm_popcnt_epi8(a).as_i8x16();
+    let zero = _mm_setzero_si128().as_i8x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Counts the number of logical 1 bits in packed 16-bit integers in `a`, and
+/// stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_popcnt_epi16)
+#[inline]
+#[target_feature(enable = "avx512bitalg")]
+#[cfg_attr(test, assert_instr(vpopcntw))]
+pub unsafe fn _mm512_popcnt_epi16(a: __m512i) -> __m512i {
+    transmute(popcnt_v32i16(a.as_i16x32()))
+}
+
+/// Counts the number of logical 1 bits in packed 16-bit integers in `a`, and
+/// stores the results in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_popcnt_epi16)
+#[inline]
+#[target_feature(enable = "avx512bitalg")]
+#[cfg_attr(test, assert_instr(vpopcntw))]
+pub unsafe fn _mm512_mask_popcnt_epi16(src: __m512i, k: __mmask32, a: __m512i) -> __m512i {
+    let r = _mm512_popcnt_epi16(a).as_i16x32();
+    transmute(simd_select_bitmask(k, r, src.as_i16x32()))
+}
+
+/// Counts the number of logical 1 bits in packed 16-bit integers in `a`, and
+/// stores the results in `dst` using zeromask `k` (elements are zeroed out when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_popcnt_epi16)
+#[inline]
+#[target_feature(enable = "avx512bitalg")]
+#[cfg_attr(test, assert_instr(vpopcntw))]
+pub unsafe fn _mm512_maskz_popcnt_epi16(k: __mmask32, a: __m512i) -> __m512i {
+    let r = _mm512_popcnt_epi16(a).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Counts the number of logical 1 bits in packed 16-bit integers in `a`, and
+/// stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_popcnt_epi16)
+#[inline]
+#[target_feature(enable = "avx512bitalg,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntw))]
+pub unsafe fn _mm256_popcnt_epi16(a: __m256i) -> __m256i {
+    transmute(popcnt_v16i16(a.as_i16x16()))
+}
+
+/// Counts the number of logical 1 bits in packed 16-bit integers in `a`, and
+/// stores the results in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_popcnt_epi16)
+#[inline]
+#[target_feature(enable = "avx512bitalg,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntw))]
+pub unsafe fn _mm256_mask_popcnt_epi16(src: __m256i, k: __mmask16, a: __m256i) -> __m256i {
+    let r = _mm256_popcnt_epi16(a).as_i16x16();
+    transmute(simd_select_bitmask(k, r, src.as_i16x16()))
+}
+
+/// Counts the number of logical 1 bits in packed 16-bit integers in `a`, and
+/// stores the results in `dst` using zeromask `k` (elements are zeroed out when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_popcnt_epi16)
+#[inline]
+#[target_feature(enable = "avx512bitalg,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntw))]
+pub unsafe fn _mm256_maskz_popcnt_epi16(k: __mmask16, a: __m256i) -> __m256i {
+    let r = _mm256_popcnt_epi16(a).as_i16x16();
+    let zero = _mm256_setzero_si256().as_i16x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Counts the number of logical 1 bits in packed 16-bit integers in `a`, and
+/// stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_popcnt_epi16)
+#[inline]
+#[target_feature(enable = "avx512bitalg,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntw))]
+pub unsafe fn _mm_popcnt_epi16(a: __m128i) -> __m128i {
+    transmute(popcnt_v8i16(a.as_i16x8()))
+}
+
+/// Counts the number of logical 1 bits in packed 16-bit integers in `a`, and
+/// stores the results in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_popcnt_epi16)
+#[inline]
+#[target_feature(enable = "avx512bitalg,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntw))]
+pub unsafe fn _mm_mask_popcnt_epi16(src: __m128i, k: __mmask8, a: __m128i) -> __m128i {
+    let r = _mm_popcnt_epi16(a).as_i16x8();
+    transmute(simd_select_bitmask(k, r, src.as_i16x8()))
+}
+
+/// Counts the number of logical 1 bits in packed 16-bit integers in `a`, and
+/// stores the results in `dst` using zeromask `k` (elements are zeroed out when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_popcnt_epi16)
+#[inline]
+#[target_feature(enable = "avx512bitalg,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntw))]
+pub unsafe fn _mm_maskz_popcnt_epi16(k: __mmask8, a: __m128i) -> __m128i {
+    let r = _mm_popcnt_epi16(a).as_i16x8();
+    let zero = _mm_setzero_si128().as_i16x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Gathers 64 bits from `b` using the selection bits in `c`. For each 64-bit
+/// element in `b`, gathers 8 bits from that element at the bit positions given
+/// by the 8 corresponding 8-bit elements of `c`, and stores the results in mask
+/// vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_bitshuffle_epi64_mask)
+#[inline]
+#[target_feature(enable = "avx512bitalg")]
+#[cfg_attr(test, assert_instr(vpshufbitqmb))]
+pub unsafe fn _mm512_bitshuffle_epi64_mask(b: __m512i, c: __m512i) -> __mmask64 {
+    bitshuffle_512(b.as_i8x64(), c.as_i8x64(), !0)
+}
+
+/// Gathers 64 bits from `b` using the selection bits in `c`. For each 64-bit
+/// element in `b`, gathers 8 bits from that element at the bit positions given
+/// by the 8 corresponding 8-bit elements of `c`, and stores the results in mask
+/// vector `k` using zeromask `k1` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_bitshuffle_epi64_mask)
+#[inline]
+#[target_feature(enable = "avx512bitalg")]
+#[cfg_attr(test, assert_instr(vpshufbitqmb))]
+pub unsafe fn _mm512_mask_bitshuffle_epi64_mask(
+    k1: __mmask64,
+    b: __m512i,
+    c: __m512i,
+) -> __mmask64 {
+    bitshuffle_512(b.as_i8x64(), c.as_i8x64(), k1)
+}
+
+/// Gathers 64 bits from `b` using the selection bits in `c`. For each 64-bit
+/// element in `b`, gathers 8 bits from that element at the bit positions given
+/// by the 8 corresponding 8-bit elements of `c`, and stores the results in mask
+/// vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_bitshuffle_epi64_mask)
+#[inline]
+#[target_feature(enable = "avx512bitalg,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshufbitqmb))]
+pub unsafe fn _mm256_bitshuffle_epi64_mask(b: __m256i, c: __m256i) -> __mmask32 {
+    bitshuffle_256(b.as_i8x32(), c.as_i8x32(), !0)
+}
+
+/// Gathers 64 bits from `b` using the selection bits in `c`. For each 64-bit
+/// element in `b`, gathers 8 bits from that element at the bit positions given
+/// by the 8 corresponding 8-bit elements of `c`, and stores the results in mask
+/// vector `k` using zeromask `k1` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_bitshuffle_epi64_mask)
+#[inline]
+#[target_feature(enable = "avx512bitalg,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshufbitqmb))]
+pub unsafe fn _mm256_mask_bitshuffle_epi64_mask(
+    k1: __mmask32,
+    b: __m256i,
+    c: __m256i,
+) -> __mmask32 {
+    bitshuffle_256(b.as_i8x32(), c.as_i8x32(), k1)
+}
+
+/// Gathers 64 bits from `b` using the selection bits in `c`. For each 64-bit
+/// element in `b`, gathers 8 bits from that element at the bit positions given
+/// by the 8 corresponding 8-bit elements of `c`, and stores the results in mask
+/// vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_bitshuffle_epi64_mask)
+#[inline]
+#[target_feature(enable = "avx512bitalg,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshufbitqmb))]
+pub unsafe fn _mm_bitshuffle_epi64_mask(b: __m128i, c: __m128i) -> __mmask16 {
+    bitshuffle_128(b.as_i8x16(), c.as_i8x16(), !0)
+}
+
+/// Gathers 64 bits from `b` using the selection bits in `c`. For each 64-bit
+/// element in `b`, gathers 8 bits from that element at the bit positions given
+/// by the 8 corresponding 8-bit elements of `c`, and stores the results in mask
+/// vector `k` using zeromask `k1` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_bitshuffle_epi64_mask)
+#[inline]
+#[target_feature(enable = "avx512bitalg,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshufbitqmb))]
+pub unsafe fn _mm_mask_bitshuffle_epi64_mask(k1: __mmask16, b: __m128i, c: __m128i) -> __mmask16 {
+    bitshuffle_128(b.as_i8x16(), c.as_i8x16(), k1)
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.ctpop.v64i8"]
+    fn popcnt_v64i8(x: i8x64) -> i8x64;
+    #[link_name = "llvm.ctpop.v32i8"]
+    fn popcnt_v32i8(x: i8x32) -> i8x32;
+    #[link_name = "llvm.ctpop.v16i8"]
+    fn popcnt_v16i8(x: i8x16) -> i8x16;
+    #[link_name = "llvm.ctpop.v32i16"]
+    fn popcnt_v32i16(x: i16x32) -> i16x32;
+    #[link_name = "llvm.ctpop.v16i16"]
+    fn popcnt_v16i16(x: i16x16) -> i16x16;
+    #[link_name = "llvm.ctpop.v8i16"]
+    fn popcnt_v8i16(x: i16x8) -> i16x8;
+    #[link_name = "llvm.x86.avx512.mask.vpshufbitqmb.512"]
+    fn bitshuffle_512(data: i8x64, indices: i8x64, mask: __mmask64) -> __mmask64;
+    #[link_name = "llvm.x86.avx512.mask.vpshufbitqmb.256"]
+    fn bitshuffle_256(data: i8x32, indices: i8x32, mask: __mmask32) -> __mmask32;
+    #[link_name = "llvm.x86.avx512.mask.vpshufbitqmb.128"]
+    fn bitshuffle_128(data: i8x16, indices: i8x16, mask: __mmask16) -> __mmask16;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use stdsimd_test::simd_test;
+
+    #[simd_test(enable = "avx512bitalg")]
+    unsafe fn test_mm512_popcnt_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            30, 62, 126, -2, 0, 2, 6, 14, 30, 62, 126, -2, 0, 2, 6, 14,
+            30, 62, 126, -2, 0, 2, 6, 14, 30, 62, 126, -2, 0, 2, 6, 14,
+            30, 62, 126, -2, 0, 2, 6, 14, 30, 62, 126, -2, 0, 2, 6, 14,
+            30, 62, 126, -2, 0, 2, 6, 14, 30, 62, 126, -2, 0, 2, 6, 14,
+        );
+        let r = _mm512_popcnt_epi8(a);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            4, 5, 6, 7, 0, 1, 2, 3, 4, 5, 6, 7, 0, 1, 2, 3,
+            4, 5, 6, 7, 0, 1, 2, 3, 4, 5, 6, 7, 0, 1, 2, 3,
+            4, 5, 6, 7, 0, 1, 2, 3, 4, 5, 6, 7, 0, 1, 2, 3,
+            4, 5, 6, 7, 0, 1, 2, 3, 4, 5, 6, 7, 0, 1, 2, 3,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bitalg")]
+    unsafe fn test_mm512_mask_popcnt_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            30, 62, 126, -2, 0, 2, 6, 14, 30, 62, 126, -2, 0, 2, 6, 14,
+            30, 62, 126, -2, 0, 2, 6, 14, 30, 62, 126, -2, 0, 2, 6, 14,
+            30, 62, 126, -2, 0, 2, 6, 14, 30, 62, 126, -2, 0, 2, 6, 14,
+            30, 62, 126, -2, 0, 2, 6, 14, 30, 62, 126, -2, 0, 2, 6, 14,
+        );
+        let r = _mm512_mask_popcnt_epi8(a, 0, a);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_popcnt_epi8(
+            a,
+            0b10011001_01010101_00110101_11001010_01101001_10010110_11001010_00110101,
+            a,
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            4, 62, 126, 7, 0, 2, 6, 3, 30, 5, 126, 7, 0, 1, 6, 3,
+            30, 62, 6, 7, 0, 1, 6, 3, 4, 5, 126, -2, 0, 2, 2, 14,
+            30, 5, 6, -2, 0, 2, 6, 3, 4, 62, 126, 7, 0, 1, 2, 14,
+            4, 5, 126, -2, 0, 2, 2, 14, 30, 62, 6, 7, 0, 1, 6, 3,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bitalg")]
+    unsafe fn test_mm512_maskz_popcnt_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            30, 62, 126, -2, 0, 2, 6, 14, 30, 62, 126, -2, 0, 2, 6, 14,
+            30, 62, 126, -2, 0, 2, 6, 14, 30, 62, 126, -2, 0, 2, 6, 14,
+            30, 62, 126, -2, 0, 2, 6, 14, 30, 62, 126, -2, 0, 2, 6, 14,
+            30, 62, 126, -2, 0, 2, 6, 14, 30, 62, 126, -2, 0, 2, 6, 14,
+        );
+        let r = _mm512_maskz_popcnt_epi8(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_popcnt_epi8(
+            0b10011001_01010101_00110101_11001010_01101001_10010110_11001010_00110101,
+            a,
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            4, 0, 0, 7, 0, 0, 0, 3, 0, 5, 0, 7, 0, 1, 0, 3,
+            0, 0, 6, 7, 0, 1, 0, 3, 4, 5, 0, 0, 0, 0, 2, 0,
+            0, 5, 6, 0, 0, 0, 0, 3, 4, 0, 0, 7, 0, 1, 2, 0,
+            4, 5, 0, 0, 0, 0, 2, 0, 0, 0, 6, 7, 0, 1, 0, 3,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bitalg,avx512vl")]
+    unsafe fn test_mm256_popcnt_epi8() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            14, 6, 2, 0, -2, 126, 62, 30, 14, 6, 2, 0, -2, 126, 62, 30,
+            14, 6, 2, 0, -2, 126, 62, 30, 14, 6, 2, 0, -2, 126, 62, 30,
+        );
+        let r = _mm256_popcnt_epi8(a);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            3, 2, 1, 0, 7, 6, 5, 4, 3, 2, 1, 0, 7, 6, 5, 4,
+            3, 2, 1, 0, 7, 6, 5, 4, 3, 2, 1, 0, 7, 6, 5, 4,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bitalg,avx512vl")]
+    unsafe fn test_mm256_mask_popcnt_epi8() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            14, 6, 2, 0, -2, 126, 62, 30, 14, 6, 2, 0, -2, 126, 62, 30,
+            14, 6, 2, 0, -2, 126, 62, 30, 14, 6, 2, 0, -2, 126, 62, 30,
+        );
+        let r = _mm256_mask_popcnt_epi8(a, 0, a);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_popcnt_epi8(a, 0b01101001_10010110_11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            3, 6, 1, 0, 7, 6, 62, 30, 14, 2, 2, 0, -2, 126, 5, 4,
+            14, 2, 1, 0, 7, 126, 62, 4, 3, 6, 2, 0, -2, 6, 5, 30,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bitalg,avx512vl")]
+    unsafe fn test_mm256_maskz_popcnt_epi8() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            14, 6, 2, 0, -2, 126, 62, 30, 14, 6, 2, 0, -2, 126, 62, 30,
+            14, 6, 2, 0, -2, 126, 62, 30, 14, 6, 2, 0, -2, 126, 62, 30,
+        );
+        let r = _mm256_maskz_popcnt_epi8(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_popcnt_epi8(0b01101001_10010110_11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            3, 0, 1, 0, 7, 6, 0, 0, 0, 2, 0, 0, 0, 0, 5, 4,
+            0, 2, 1, 0, 7, 0, 0, 4, 3, 0, 0, 0, 0, 6, 5, 0,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bitalg,avx512vl")]
+    unsafe fn test_mm_popcnt_epi8() {
+        let a = _mm_setr_epi8(14, 6, 2, 0, -2, 126, 62, 30, 14, 6, 2, 0, -2, 126, 62, 30);
+        let r = _mm_popcnt_epi8(a);
+        let e = _mm_setr_epi8(3, 2, 1, 0, 7, 6, 5, 4, 3, 2, 1, 0, 7, 6, 5, 4);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bitalg,avx512vl")]
+    unsafe fn test_mm_mask_popcnt_epi8() {
+        let a = _mm_setr_epi8(14, 6, 2, 0, -2, 126, 62, 30, 14, 6, 2, 0, -2, 126, 62, 30);
+        let r = _mm_mask_popcnt_epi8(a, 0, a);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_popcnt_epi8(a, 0b11001010_00110101, a);
+        let e = _mm_setr_epi8(3, 6, 1, 0, 7, 6, 62, 30, 14, 2, 2, 0, -2, 126, 5, 4);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bitalg,avx512vl")]
+    unsafe fn test_mm_maskz_popcnt_epi8() {
+        let a = _mm_setr_epi8(14, 6, 2, 0, -2, 126, 62, 30, 14, 6, 2, 0, -2, 126, 62, 30);
+        let r = _mm_maskz_popcnt_epi8(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_popcnt_epi8(0b11001010_00110101, a);
+        let e = _mm_setr_epi8(3, 0, 1, 0, 7, 6, 0, 0, 0, 2, 0, 0, 0, 0, 5, 4);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bitalg")]
+    unsafe fn test_mm512_popcnt_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            8190, 62, 32766, 254, 0, 1022, 6, 4094,
+            30, 16382, 126, -2, 510, 2, 2046, 14,
+            8190, 62, 32766, 254, 0, 1022, 6, 4094,
+            30, 16382, 126, -2, 510, 2, 2046, 14,
+        );
+        let r = _mm512_popcnt_epi16(a);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            12, 5, 14, 7, 0, 9, 2, 11, 4, 13, 6, 15, 8, 1, 10, 3,
+            12, 5, 14, 7, 0, 9, 2, 11, 4, 13, 6, 15, 8, 1, 10, 3,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bitalg")]
+    unsafe fn test_mm512_mask_popcnt_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            8190, 62, 32766, 254, 0, 1022, 6, 4094,
+            30, 16382, 126, -2, 510, 2, 2046, 14,
+            8190, 62, 32766, 254, 0, 1022, 6, 4094,
+            30, 16382, 126, -2, 510, 2, 2046, 14,
+        );
+        let r = _mm512_mask_popcnt_epi16(a, 0, a);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_popcnt_epi16(a, 0b01101001_10010110_11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            8190, 5, 14, 254, 0, 1022, 6, 11,
+            4, 16382, 126, 15, 510, 1, 10, 14,
+            12, 5, 32766, 254, 0, 1022, 2, 4094,
+            30, 16382, 6, 15, 510, 1, 2046, 3,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bitalg")]
+    unsafe fn test_mm512_maskz_popcnt_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            8190, 62, 32766, 254, 0, 1022, 6, 4094,
+            30, 16382, 126, -2, 510, 2, 2046, 14,
+            8190, 62, 32766, 254, 0, 1022, 6, 4094,
+            30, 16382, 126, -2, 510, 2, 2046, 14,
+        );
+        let r = _mm512_maskz_popcnt_epi16(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_popcnt_epi16(0b01101001_10010110_11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 5, 14, 0, 0, 0, 0, 11, 4, 0, 0, 15, 0, 1, 10, 0,
+            12, 5, 0, 0, 0, 0, 2, 0, 0, 0, 6, 15, 0, 1, 0, 3,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bitalg,avx512vl")]
+    unsafe fn test_mm256_popcnt_epi16() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            14, 2046, 2, 510, -2, 126, 16382, 30,
+            4094, 6, 1022, 0, 254, 32766, 62, 8190,
+        );
+        let r = _mm256_popcnt_epi16(a);
+        let e = _mm256_setr_epi16(3, 10, 1, 8, 15, 6, 13, 4, 11, 2, 9, 0, 7, 14, 5, 12);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bitalg,avx512vl")]
+    unsafe fn test_mm256_mask_popcnt_epi16() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            14, 2046, 2, 510, -2, 126, 16382, 30,
+            4094, 6, 1022, 0, 254, 32766, 62, 8190,
+        );
+        let r = _mm256_mask_popcnt_epi16(a, 0, a);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_popcnt_epi16(a, 0b11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi16(
+            3, 2046, 1, 510, 15, 6, 16382, 30,
+            4094, 2, 1022, 0, 254, 32766, 5, 12,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bitalg,avx512vl")]
+    unsafe fn test_mm256_maskz_popcnt_epi16() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            14, 2046, 2, 510, -2, 126, 16382, 30,
+            4094, 6, 1022, 0, 254, 32766, 62, 8190,
+        );
+        let r = _mm256_maskz_popcnt_epi16(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_popcnt_epi16(0b11001010_00110101, a);
+        let e = _mm256_setr_epi16(3, 0, 1, 0, 15, 6, 0, 0, 0, 2, 0, 0, 0, 0, 5, 12);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bitalg,avx512vl")]
+    unsafe fn test_mm_popcnt_epi16() {
+        let a = _mm_setr_epi16(14, 2046, 2, 510, -2, 126, 16382, 30);
+        let r = _mm_popcnt_epi16(a);
+        let e = _mm_setr_epi16(3, 10, 1, 8, 15, 6, 13, 4);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bitalg,avx512vl")]
+    unsafe fn test_mm_mask_popcnt_epi16() {
+        let a = _mm_setr_epi16(14, 2046, 2, 510, -2, 126, 16382, 30);
+        let r = _mm_mask_popcnt_epi16(a, 0, a);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_popcnt_epi16(a, 0b00110101, a);
+        let e = _mm_setr_epi16(3, 2046, 1, 510, 15, 6, 16382, 30);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bitalg,avx512vl")]
+    unsafe fn test_mm_maskz_popcnt_epi16() {
+        let a = _mm_setr_epi16(14, 2046, 2, 510, -2, 126, 16382, 30);
+        let r = _mm_maskz_popcnt_epi16(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_popcnt_epi16(0b00110101, a);
+        let e = _mm_setr_epi16(3, 0, 1, 0, 15, 6, 0, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bitalg")]
+    unsafe fn test_mm512_bitshuffle_epi64_mask() {
+        let b = _mm512_set1_epi64(0x0123_4567_89ab_cdef);
+        let c = _mm512_set1_epi64(0x3931_2921_1911_0901);
+        let r = _mm512_bitshuffle_epi64_mask(b, c);
+        let e = 0b01010101_01010101_01010101_01010101_01010101_01010101_01010101_01010101;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bitalg")]
+    unsafe fn test_mm512_mask_bitshuffle_epi64_mask() {
+        let b = _mm512_set1_epi64(0x0123_4567_89ab_cdef);
+        let c = _mm512_set1_epi64(0x3931_2921_1911_0901);
+        let r = _mm512_mask_bitshuffle_epi64_mask(0, b, c);
+        assert_eq!(r, 0);
+        let r = _mm512_mask_bitshuffle_epi64_mask(
+            0b10011001_01010101_00110101_11001010_01101001_10010110_11001010_00110101,
+            b,
+            c,
+        );
+        let e = 0b00010001_01010101_00010101_01000000_01000001_00010100_01000000_00010101;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bitalg,avx512vl")]
+    unsafe fn test_mm256_bitshuffle_epi64_mask() {
+        let b = _mm256_set1_epi64x(0x0123_4567_89ab_cdef);
+        let c = _mm256_set1_epi64x(0x3931_2921_1911_0901);
+        let r = _mm256_bitshuffle_epi64_mask(b, c);
+        let e = 0b01010101_01010101_01010101_01010101;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bitalg,avx512vl")]
+    unsafe fn test_mm256_mask_bitshuffle_epi64_mask() {
+        let b = _mm256_set1_epi64x(0x0123_4567_89ab_cdef);
+        let c = _mm256_set1_epi64x(0x3931_2921_1911_0901);
+        let r = _mm256_mask_bitshuffle_epi64_mask(0, b, c);
+        assert_eq!(r, 0);
+        let r = _mm256_mask_bitshuffle_epi64_mask(0b01101001_10010110_11001010_00110101, b, c);
+        let e = 0b01000001_00010100_01000000_00010101;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bitalg,avx512vl")]
+    unsafe fn test_mm_bitshuffle_epi64_mask() {
+        let b = _mm_set1_epi64x(0x0123_4567_89ab_cdef);
+        let c = _mm_set1_epi64x(0x3931_2921_1911_0901);
+        let r = _mm_bitshuffle_epi64_mask(b, c);
+        let e = 0b01010101_01010101;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bitalg,avx512vl")]
+    unsafe fn test_mm_mask_bitshuffle_epi64_mask() {
+        let b = _mm_set1_epi64x(0x0123_4567_89ab_cdef);
+        let c = _mm_set1_epi64x(0x3931_2921_1911_0901);
+        let r = _mm_mask_bitshuffle_epi64_mask(0, b, c);
+        assert_eq!(r, 0);
+        let r = _mm_mask_bitshuffle_epi64_mask(0b11001010_00110101, b, c);
+        let e = 0b01000000_00010101;
+        assert_eq!(r, e);
+    }
+}
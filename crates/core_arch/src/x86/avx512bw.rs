@@ -0,0 +1,13553 @@
+use crate::{
+    core_arch::{simd::*, simd_llvm::*, x86::*},
+    mem::transmute,
+};
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Computes the bitwise AND of 32-bit masks `a` and `b`, and returns the
+/// result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kand_mask32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(and))] // not kandd: LLVM keeps the mask in a GPR
+pub unsafe fn _kand_mask32(a: __mmask32, b: __mmask32) -> __mmask32 {
+    a & b
+}
+
+/// Computes the bitwise NOT of 32-bit mask `a` and then AND with `b`, and
+/// returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kandn_mask32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(not))] // not kandnd: LLVM keeps the mask in a GPR
+pub unsafe fn _kandn_mask32(a: __mmask32, b: __mmask32) -> __mmask32 {
+    !a & b
+}
+
+/// Computes the bitwise OR of 32-bit masks `a` and `b`, and returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kor_mask32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(or))] // not kord: LLVM keeps the mask in a GPR
+pub unsafe fn _kor_mask32(a: __mmask32, b: __mmask32) -> __mmask32 {
+    a | b
+}
+
+/// Computes the bitwise XNOR of 32-bit masks `a` and `b`, and returns the
+/// result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kxnor_mask32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(xor))] // not kxnord: LLVM keeps the mask in a GPR
+pub unsafe fn _kxnor_mask32(a: __mmask32, b: __mmask32) -> __mmask32 {
+    !(a ^ b)
+}
+
+/// Computes the bitwise XOR of 32-bit masks `a` and `b`, and returns the
+/// result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kxor_mask32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(xor))] // not kxord: LLVM keeps the mask in a GPR
+pub unsafe fn _kxor_mask32(a: __mmask32, b: __mmask32) -> __mmask32 {
+    a ^ b
+}
+
+/// Computes the bitwise NOT of 32-bit mask `a`, and returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_knot_mask32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(not))] // not knotd: LLVM keeps the mask in a GPR
+pub unsafe fn _knot_mask32(a: __mmask32) -> __mmask32 {
+    !a
+}
+
+/// Shifts the bits of 32-bit mask `a` left by `count` while shifting in zeros,
+/// and returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kshiftli_mask32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(shl, count = 5))] // not kshiftld: LLVM keeps the mask in a GPR
+#[rustc_args_required_const(1)]
+pub unsafe fn _kshiftli_mask32(a: __mmask32, count: u32) -> __mmask32 {
+    a.checked_shl(count).unwrap_or(0)
+}
+
+/// Shifts the bits of 32-bit mask `a` right by `count` while shifting in zeros,
+/// and returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kshiftri_mask32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(shr, count = 3))] // not kshiftrd: LLVM keeps the mask in a GPR
+#[rustc_args_required_const(1)]
+pub unsafe fn _kshiftri_mask32(a: __mmask32, count: u32) -> __mmask32 {
+    a.checked_shr(count).unwrap_or(0)
+}
+
+/// Computes the bitwise OR of 32-bit masks `a` and `b`. If the result is all
+/// zeros, returns 1, otherwise returns 0.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortestz_mask32_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(or))] // not kortestd: LLVM keeps the mask in a GPR
+pub unsafe fn _kortestz_mask32_u8(a: __mmask32, b: __mmask32) -> u8 {
+    ((a | b) == 0) as u8
+}
+
+/// Computes the bitwise OR of 32-bit masks `a` and `b`. If the result is all
+/// ones, returns 1, otherwise returns 0.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortestc_mask32_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(or))] // not kortestd: LLVM keeps the mask in a GPR
+pub unsafe fn _kortestc_mask32_u8(a: __mmask32, b: __mmask32) -> u8 {
+    ((a | b) == !0) as u8
+}
+
+/// Computes the bitwise OR of 32-bit masks `a` and `b`. If the result is all
+/// zeros, returns 1, otherwise returns 0. If the result is all ones, stores 1
+/// in `all_ones`, otherwise stores 0 in `all_ones`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortest_mask32_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(or))] // not kortestd: LLVM keeps the mask in a GPR
+pub unsafe fn _kortest_mask32_u8(a: __mmask32, b: __mmask32, all_ones: *mut u8) -> u8 {
+    *all_ones = ((a | b) == !0) as u8;
+    ((a | b) == 0) as u8
+}
+
+/// Computes the bitwise AND of 32-bit masks `a` and `b`. If the result is all
+/// zeros, returns 1, otherwise returns 0.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktestz_mask32_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(test))] // not ktestd: LLVM keeps the mask in a GPR
+pub unsafe fn _ktestz_mask32_u8(a: __mmask32, b: __mmask32) -> u8 {
+    ((a & b) == 0) as u8
+}
+
+/// Computes the bitwise NOT of 32-bit mask `a` and then AND with `b`. If the
+/// result is all zeros, returns 1, otherwise returns 0.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktestc_mask32_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(and))] // not ktestd: LLVM keeps the mask in a GPR
+pub unsafe fn _ktestc_mask32_u8(a: __mmask32, b: __mmask32) -> u8 {
+    ((!a & b) == 0) as u8
+}
+
+/// Computes the bitwise AND of 32-bit masks `a` and `b`. If the result is all
+/// zeros, returns 1, otherwise returns 0. Computes the bitwise NOT of `a` and
+/// then AND with `b`. If the result is all zeros, stores 1 in `and_not`,
+/// otherwise stores 0 in `and_not`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktest_mask32_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(and))] // not ktestd: LLVM keeps the mask in a GPR
+pub unsafe fn _ktest_mask32_u8(a: __mmask32, b: __mmask32, and_not: *mut u8) -> u8 {
+    *and_not = ((!a & b) == 0) as u8;
+    ((a & b) == 0) as u8
+}
+
+/// Converts 32-bit mask `a` into an integer value, and returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_cvtmask32_u32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(mov))] // not kmovd: LLVM keeps the mask in a GPR
+pub unsafe fn _cvtmask32_u32(a: __mmask32) -> u32 {
+    a as u32
+}
+
+/// Converts integer value `a` into a 32-bit mask, and returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_cvtu32_mask32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(mov))] // not kmovd: LLVM keeps the mask in a GPR
+pub unsafe fn _cvtu32_mask32(a: u32) -> __mmask32 {
+    a as __mmask32
+}
+
+/// Loads 32-bit mask from memory, and returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_load_mask32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(mov))] // not kmovd: LLVM keeps the mask in a GPR
+pub unsafe fn _load_mask32(mem_addr: *const __mmask32) -> __mmask32 {
+    *mem_addr
+}
+
+/// Stores 32-bit mask `a` into memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_store_mask32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(mov))] // not kmovd: LLVM keeps the mask in a GPR
+pub unsafe fn _store_mask32(mem_addr: *mut __mmask32, a: __mmask32) {
+    *mem_addr = a;
+}
+
+/// Computes the bitwise AND of 64-bit masks `a` and `b`, and returns the
+/// result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kand_mask64)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(and))] // not kandq: LLVM keeps the mask in a GPR
+pub unsafe fn _kand_mask64(a: __mmask64, b: __mmask64) -> __mmask64 {
+    a & b
+}
+
+/// Computes the bitwise NOT of 64-bit mask `a` and then AND with `b`, and
+/// returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kandn_mask64)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(not))] // not kandnq: LLVM keeps the mask in a GPR
+pub unsafe fn _kandn_mask64(a: __mmask64, b: __mmask64) -> __mmask64 {
+    !a & b
+}
+
+/// Computes the bitwise OR of 64-bit masks `a` and `b`, and returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kor_mask64)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(or))] // not korq: LLVM keeps the mask in a GPR
+pub unsafe fn _kor_mask64(a: __mmask64, b: __mmask64) -> __mmask64 {
+    a | b
+}
+
+/// Computes the bitwise XNOR of 64-bit masks `a` and `b`, and returns the
+/// result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kxnor_mask64)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(xor))] // not kxnorq: LLVM keeps the mask in a GPR
+pub unsafe fn _kxnor_mask64(a: __mmask64, b: __mmask64) -> __mmask64 {
+    !(a ^ b)
+}
+
+/// Computes the bitwise XOR of 64-bit masks `a` and `b`, and returns the
+/// result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kxor_mask64)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(xor))] // not kxorq: LLVM keeps the mask in a GPR
+pub unsafe fn _kxor_mask64(a: __mmask64, b: __mmask64) -> __mmask64 {
+    a ^ b
+}
+
+/// Computes the bitwise NOT of 64-bit mask `a`, and returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_knot_mask64)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(not))] // not knotq: LLVM keeps the mask in a GPR
+pub unsafe fn _knot_mask64(a: __mmask64) -> __mmask64 {
+    !a
+}
+
+/// Shifts the bits of 64-bit mask `a` left by `count` while shifting in zeros,
+/// and returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kshiftli_mask64)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(shl, count = 5))] // not kshiftlq: LLVM keeps the mask in a GPR
+#[rustc_args_required_const(1)]
+pub unsafe fn _kshiftli_mask64(a: __mmask64, count: u32) -> __mmask64 {
+    a.checked_shl(count).unwrap_or(0)
+}
+
+/// Shifts the bits of 64-bit mask `a` right by `count` while shifting in zeros,
+/// and returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kshiftri_mask64)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(shr, count = 3))] // not kshiftrq: LLVM keeps the mask in a GPR
+#[rustc_args_required_const(1)]
+pub unsafe fn _kshiftri_mask64(a: __mmask64, count: u32) -> __mmask64 {
+    a.checked_shr(count).unwrap_or(0)
+}
+
+/// Computes the bitwise OR of 64-bit masks `a` and `b`. If the result is all
+/// zeros, returns 1, otherwise returns 0.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortestz_mask64_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(or))] // not kortestq: LLVM keeps the mask in a GPR
+pub unsafe fn _kortestz_mask64_u8(a: __mmask64, b: __mmask64) -> u8 {
+    ((a | b) == 0) as u8
+}
+
+/// Computes the bitwise OR of 64-bit masks `a` and `b`. If the result is all
+/// ones, returns 1, otherwise returns 0.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortestc_mask64_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(or))] // not kortestq: LLVM keeps the mask in a GPR
+pub unsafe fn _kortestc_mask64_u8(a: __mmask64, b: __mmask64) -> u8 {
+    ((a | b) == !0) as u8
+}
+
+/// Computes the bitwise OR of 64-bit masks `a` and `b`. If the result is all
+/// zeros, returns 1, otherwise returns 0. If the result is all ones, stores 1
+/// in `all_ones`, otherwise stores 0 in `all_ones`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortest_mask64_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(or))] // not kortestq: LLVM keeps the mask in a GPR
+pub unsafe fn _kortest_mask64_u8(a: __mmask64, b: __mmask64, all_ones: *mut u8) -> u8 {
+    *all_ones = ((a | b) == !0) as u8;
+    ((a | b) == 0) as u8
+}
+
+/// Computes the bitwise AND of 64-bit masks `a` and `b`. If the result is all
+/// zeros, returns 1, otherwise returns 0.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktestz_mask64_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(test))] // not ktestq: LLVM keeps the mask in a GPR
+pub unsafe fn _ktestz_mask64_u8(a: __mmask64, b: __mmask64) -> u8 {
+    ((a & b) == 0) as u8
+}
+
+/// Computes the bitwise NOT of 64-bit mask `a` and then AND with `b`. If the
+/// result is all zeros, returns 1, otherwise returns 0.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktestc_mask64_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(and))] // not ktestq: LLVM keeps the mask in a GPR
+pub unsafe fn _ktestc_mask64_u8(a: __mmask64, b: __mmask64) -> u8 {
+    ((!a & b) == 0) as u8
+}
+
+/// Computes the bitwise AND of 64-bit masks `a` and `b`. If the result is all
+/// zeros, returns 1, otherwise returns 0. Computes the bitwise NOT of `a` and
+/// then AND with `b`. If the result is all zeros, stores 1 in `and_not`,
+/// otherwise stores 0 in `and_not`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktest_mask64_u8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(and))] // not ktestq: LLVM keeps the mask in a GPR
+pub unsafe fn _ktest_mask64_u8(a: __mmask64, b: __mmask64, and_not: *mut u8) -> u8 {
+    *and_not = ((!a & b) == 0) as u8;
+    ((a & b) == 0) as u8
+}
+
+/// Loads 64-bit mask from memory, and returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_load_mask64)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(mov))] // not kmovq: LLVM keeps the mask in a GPR
+pub unsafe fn _load_mask64(mem_addr: *const __mmask64) -> __mmask64 {
+    *mem_addr
+}
+
+/// Stores 64-bit mask `a` into memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_store_mask64)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(mov))] // not kmovq: LLVM keeps the mask in a GPR
+pub unsafe fn _store_mask64(mem_addr: *mut __mmask64, a: __mmask64) {
+    *mem_addr = a;
+}
+
+/// Unpacks and interleaves 16 bits from masks `a` and `b`, and returns the
+/// 32-bit result: the low 16 bits come from `b` and the high 16 bits from `a`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_kunpackw)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(mov))] // not kunpckwd: LLVM keeps the mask in a GPR
+pub unsafe fn _mm512_kunpackw(a: __mmask32, b: __mmask32) -> __mmask32 {
+    ((a & 0xffff) << 16) | (b & 0xffff)
+}
+
+/// Unpacks and interleaves 32 bits from masks `a` and `b`, and returns the
+/// 64-bit result: the low 32 bits come from `b` and the high 32 bits from `a`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_kunpackd)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(mov))] // not kunpckdq: LLVM keeps the mask in a GPR
+pub unsafe fn _mm512_kunpackd(a: __mmask64, b: __mmask64) -> __mmask64 {
+    ((a & 0xffff_ffff) << 32) | (b & 0xffff_ffff)
+}
+
+/// Sets each bit of mask register `k` based on the most significant bit of the
+/// corresponding packed 8-bit integer in `a`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_movepi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmovb2m))]
+pub unsafe fn _mm512_movepi8_mask(a: __m512i) -> __mmask64 {
+    let zero = i8x64::splat(0);
+    let m: i8x64 = simd_lt(a.as_i8x64(), zero);
+    simd_bitmask(m)
+}
+
+/// Sets each packed 8-bit integer in `dst` to all ones or all zeros based on
+/// the value of the corresponding bit in `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_movm_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmovm2b))]
+pub unsafe fn _mm512_movm_epi8(k: __mmask64) -> __m512i {
+    let one = i8x64::splat(-1);
+    let zero = i8x64::splat(0);
+    transmute(simd_select_bitmask(k, one, zero))
+}
+
+/// Sets each bit of mask register `k` based on the most significant bit of the
+/// corresponding packed 16-bit integer in `a`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_movepi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmovw2m))]
+pub unsafe fn _mm512_movepi16_mask(a: __m512i) -> __mmask32 {
+    let zero = i16x32::splat(0);
+    let m: i16x32 = simd_lt(a.as_i16x32(), zero);
+    simd_bitmask(m)
+}
+
+/// Sets each packed 16-bit integer in `dst` to all ones or all zeros based on
+/// the value of the corresponding bit in `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_movm_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmovm2w))]
+pub unsafe fn _mm512_movm_epi16(k: __mmask32) -> __m512i {
+    let one = i16x32::splat(-1);
+    let zero = i16x32::splat(0);
+    transmute(simd_select_bitmask(k, one, zero))
+}
+
+/// Computes the absolute values of packed signed 8-bit integers in `a`, and
+/// returns the unsigned results.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_abs_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpabsb))]
+pub unsafe fn _mm512_abs_epi8(a: __m512i) -> __m512i {
+    let a = a.as_i8x64();
+    let zero = i8x64::splat(0);
+    let sub = simd_sub(zero, a);
+    let cmp: i8x64 = simd_gt(a, zero);
+    transmute(simd_select(cmp, a, sub))
+}
+
+/// Computes the absolute values of packed signed 8-bit integers in `a`, and
+/// stores the unsigned results in `dst` using writemask `k` (elements are
+/// copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_abs_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpabsb))]
+pub unsafe fn _mm512_mask_abs_epi8(src: __m512i, k: __mmask64, a: __m512i) -> __m512i {
+    let abs = _mm512_abs_epi8(a).as_i8x64();
+    transmute(simd_select_bitmask(k, abs, src.as_i8x64()))
+}
+
+/// Computes the absolute values of packed signed 8-bit integers in `a`, and
+/// stores the unsigned results in `dst` using zeromask `k` (elements are zeroed
+/// out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_abs_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpabsb))]
+pub unsafe fn _mm512_maskz_abs_epi8(k: __mmask64, a: __m512i) -> __m512i {
+    let abs = _mm512_abs_epi8(a).as_i8x64();
+    let zero = _mm512_setzero_si512().as_i8x64();
+    transmute(simd_select_bitmask(k, abs, zero))
+}
+
+/// Computes the absolute values of packed signed 16-bit integers in `a`, and
+/// returns the unsigned results.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_abs_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpabsw))]
+pub unsafe fn _mm512_abs_epi16(a: __m512i) -> __m512i {
+    let a = a.as_i16x32();
+    let zero = i16x32::splat(0);
+    let sub = simd_sub(zero, a);
+    let cmp: i16x32 = simd_gt(a, zero);
+    transmute(simd_select(cmp, a, sub))
+}
+
+/// Computes the absolute values of packed signed 16-bit integers in `a`, and
+/// stores the unsigned results in `dst` using writemask `k` (elements are
+/// copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_abs_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpabsw))]
+pub unsafe fn _mm512_mask_abs_epi16(src: __m512i, k: __mmask32, a: __m512i) -> __m512i {
+    let abs = _mm512_abs_epi16(a).as_i16x32();
+    transmute(simd_select_bitmask(k, abs, src.as_i16x32()))
+}
+
+/// Computes the absolute values of packed signed 16-bit integers in `a`, and
+/// stores the unsigned results in `dst` using zeromask `k` (elements are zeroed
+/// out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_abs_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpabsw))]
+pub unsafe fn _mm512_maskz_abs_epi16(k: __mmask32, a: __m512i) -> __m512i {
+    let abs = _mm512_abs_epi16(a).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, abs, zero))
+}
+
+/// Adds packed 8-bit integers in `a` and `b`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_add_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpaddb))]
+pub unsafe fn _mm512_add_epi8(a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_add(a.as_i8x64(), b.as_i8x64()))
+}
+
+/// Adds packed 8-bit integers in `a` and `b`, and stores the results in `dst`
+/// using writemask `k` (elements are copied from `src` when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_add_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpaddb))]
+pub unsafe fn _mm512_mask_add_epi8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    let add = _mm512_add_epi8(a, b).as_i8x64();
+    transmute(simd_select_bitmask(k, add, src.as_i8x64()))
+}
+
+/// Adds packed 8-bit integers in `a` and `b`, and stores the results in `dst`
+/// using zeromask `k` (elements are zeroed out when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_add_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpaddb))]
+pub unsafe fn _mm512_maskz_add_epi8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    let add = _mm512_add_epi8(a, b).as_i8x64();
+    let zero = _mm512_setzero_si512().as_i8x64();
+    transmute(simd_select_bitmask(k, add, zero))
+}
+
+/// Adds packed 8-bit integers in `a` and `b`, and stores the results in `dst`
+/// using writemask `k` (elements are copied from `src` when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_add_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpaddb))]
+pub unsafe fn _mm256_mask_add_epi8(src: __m256i, k: __mmask32, a: __m256i, b: __m256i) -> __m256i {
+    let add = _mm256_add_epi8(a, b).as_i8x32();
+    transmute(simd_select_bitmask(k, add, src.as_i8x32()))
+}
+
+/// Adds packed 8-bit integers in `a` and `b`, and stores the results in `dst`
+/// using zeromask `k` (elements are zeroed out when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_add_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpaddb))]
+pub unsafe fn _mm256_maskz_add_epi8(k: __mmask32, a: __m256i, b: __m256i) -> __m256i {
+    let add = _mm256_add_epi8(a, b).as_i8x32();
+    let zero = _mm256_setzero_si256().as_i8x32();
+    transmute(simd_select_bitmask(k, add, zero))
+}
+
+/// Adds packed 8-bit integers in `a` and `b`, and stores the results in `dst`
+/// using writemask `k` (elements are copied from `src` when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_add_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpaddb))]
+pub unsafe fn _mm_mask_add_epi8(src: __m128i, k: __mmask16, a: __m128i, b: __m128i) -> __m128i {
+    let add = _mm_add_epi8(a, b).as_i8x16();
+    transmute(simd_select_bitmask(k, add, src.as_i8x16()))
+}
+
+/// Adds packed 8-bit integers in `a` and `b`, and stores the results in `dst`
+/// using zeromask `k` (elements are zeroed out when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_add_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpaddb))]
+pub unsafe fn _mm_maskz_add_epi8(k: __mmask16, a: __m128i, b: __m128i) -> __m128i {
+    let add = _mm_add_epi8(a, b).as_i8x16();
+    let zero = _mm_setzero_si128().as_i8x16();
+    transmute(simd_select_bitmask(k, add, zero))
+}
+
+/// Adds packed 16-bit integers in `a` and `b`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_add_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpaddw))]
+pub unsafe fn _mm512_add_epi16(a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_add(a.as_i16x32(), b.as_i16x32()))
+}
+
+/// Adds packed 16-bit integers in `a` and `b`, and stores the results in `dst`
+/// using writemask `k` (elements are copied from `src` when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_add_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpaddw))]
+pub unsafe fn _mm512_mask_add_epi16(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    let add = _mm512_add_epi16(a, b).as_i16x32();
+    transmute(simd_select_bitmask(k, add, src.as_i16x32()))
+}
+
+/// Adds packed 16-bit integers in `a` and `b`, and stores the results in `dst`
+/// using zeromask `k` (elements are zeroed out when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_add_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpaddw))]
+pub unsafe fn _mm512_maskz_add_epi16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    let add = _mm512_add_epi16(a, b).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, add, zero))
+}
+
+/// Adds packed 16-bit integers in `a` and `b`, and stores the results in `dst`
+/// using writemask `k` (elements are copied from `src` when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_add_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpaddw))]
+pub unsafe fn _mm256_mask_add_epi16(src: __m256i, k: __mmask16, a: __m256i, b: __m256i) -> __m256i {
+    let add = _mm256_add_epi16(a, b).as_i16x16();
+    transmute(simd_select_bitmask(k, add, src.as_i16x16()))
+}
+
+/// Adds packed 16-bit integers in `a` and `b`, and stores the results in `dst`
+/// using zeromask `k` (elements are zeroed out when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_add_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpaddw))]
+pub unsafe fn _mm256_maskz_add_epi16(k: __mmask16, a: __m256i, b: __m256i) -> __m256i {
+    let add = _mm256_add_epi16(a, b).as_i16x16();
+    let zero = _mm256_setzero_si256().as_i16x16();
+    transmute(simd_select_bitmask(k, add, zero))
+}
+
+/// Adds packed 16-bit integers in `a` and `b`, and stores the results in `dst`
+/// using writemask `k` (elements are copied from `src` when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_add_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpaddw))]
+pub unsafe fn _mm_mask_add_epi16(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i {
+    let add = _mm_add_epi16(a, b).as_i16x8();
+    transmute(simd_select_bitmask(k, add, src.as_i16x8()))
+}
+
+/// Adds packed 16-bit integers in `a` and `b`, and stores the results in `dst`
+/// using zeromask `k` (elements are zeroed out when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_add_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpaddw))]
+pub unsafe fn _mm_maskz_add_epi16(k: __mmask8, a: __m128i, b: __m128i) -> __m128i {
+    let add = _mm_add_epi16(a, b).as_i16x8();
+    let zero = _mm_setzero_si128().as_i16x8();
+    transmute(simd_select_bitmask(k, add, zero))
+}
+
+/// Subtracts packed 8-bit integers in `b` from packed 8-bit integers in `a`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_sub_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsubb))]
+pub unsafe fn _mm512_sub_epi8(a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_sub(a.as_i8x64(), b.as_i8x64()))
+}
+
+/// Subtracts packed 8-bit integers in `b` from packed 8-bit integers in `a`,
+/// and stores the results in `dst` using writemask `k` (elements are copied
+/// from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_sub_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsubb))]
+pub unsafe fn _mm512_mask_sub_epi8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    let sub = _mm512_sub_epi8(a, b).as_i8x64();
+    transmute(simd_select_bitmask(k, sub, src.as_i8x64()))
+}
+
+/// Subtracts packed 8-bit integers in `b` from packed 8-bit integers in `a`,
+/// and stores the results in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_sub_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsubb))]
+pub unsafe fn _mm512_maskz_sub_epi8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    let sub = _mm512_sub_epi8(a, b).as_i8x64();
+    let zero = _mm512_setzero_si512().as_i8x64();
+    transmute(simd_select_bitmask(k, sub, zero))
+}
+
+/// Subtracts packed 8-bit integers in `b` from packed 8-bit integers in `a`,
+/// and stores the results in `dst` using writemask `k` (elements are copied
+/// from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_sub_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpsubb))]
+pub unsafe fn _mm256_mask_sub_epi8(src: __m256i, k: __mmask32, a: __m256i, b: __m256i) -> __m256i {
+    let sub = _mm256_sub_epi8(a, b).as_i8x32();
+    transmute(simd_select_bitmask(k, sub, src.as_i8x32()))
+}
+
+/// Subtracts packed 8-bit integers in `b` from packed 8-bit integers in `a`,
+/// and stores the results in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_sub_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpsubb))]
+pub unsafe fn _mm256_maskz_sub_epi8(k: __mmask32, a: __m256i, b: __m256i) -> __m256i {
+    let sub = _mm256_sub_epi8(a, b).as_i8x32();
+    let zero = _mm256_setzero_si256().as_i8x32();
+    transmute(simd_select_bitmask(k, sub, zero))
+}
+
+/// Subtracts packed 8-bit integers in `b` from packed 8-bit integers in `a`,
+/// and stores the results in `dst` using writemask `k` (elements are copied
+/// from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_sub_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpsubb))]
+pub unsafe fn _mm_mask_sub_epi8(src: __m128i, k: __mmask16, a: __m128i, b: __m128i) -> __m128i {
+    let sub = _mm_sub_epi8(a, b).as_i8x16();
+    transmute(simd_select_bitmask(k, sub, src.as_i8x16()))
+}
+
+/// Subtracts packed 8-bit integers in `b` from packed 8-bit integers in `a`,
+/// and stores the results in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_sub_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpsubb))]
+pub unsafe fn _mm_maskz_sub_epi8(k: __mmask16, a: __m128i, b: __m128i) -> __m128i {
+    let sub = _mm_sub_epi8(a, b).as_i8x16();
+    let zero = _mm_setzero_si128().as_i8x16();
+    transmute(simd_select_bitmask(k, sub, zero))
+}
+
+/// Subtracts packed 16-bit integers in `b` from packed 16-bit integers in `a`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_sub_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsubw))]
+pub unsafe fn _mm512_sub_epi16(a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_sub(a.as_i16x32(), b.as_i16x32()))
+}
+
+/// Subtracts packed 16-bit integers in `b` from packed 16-bit integers in `a`,
+/// and stores the results in `dst` using writemask `k` (elements are copied
+/// from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_sub_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsubw))]
+pub unsafe fn _mm512_mask_sub_epi16(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    let sub = _mm512_sub_epi16(a, b).as_i16x32();
+    transmute(simd_select_bitmask(k, sub, src.as_i16x32()))
+}
+
+/// Subtracts packed 16-bit integers in `b` from packed 16-bit integers in `a`,
+/// and stores the results in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_sub_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsubw))]
+pub unsafe fn _mm512_maskz_sub_epi16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    let sub = _mm512_sub_epi16(a, b).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, sub, zero))
+}
+
+/// Subtracts packed 16-bit integers in `b` from packed 16-bit integers in `a`,
+/// and stores the results in `dst` using writemask `k` (elements are copied
+/// from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_sub_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpsubw))]
+pub unsafe fn _mm256_mask_sub_epi16(src: __m256i, k: __mmask16, a: __m256i, b: __m256i) -> __m256i {
+    let sub = _mm256_sub_epi16(a, b).as_i16x16();
+    transmute(simd_select_bitmask(k, sub, src.as_i16x16()))
+}
+
+/// Subtracts packed 16-bit integers in `b` from packed 16-bit integers in `a`,
+/// and stores the results in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_sub_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpsubw))]
+pub unsafe fn _mm256_maskz_sub_epi16(k: __mmask16, a: __m256i, b: __m256i) -> __m256i {
+    let sub = _mm256_sub_epi16(a, b).as_i16x16();
+    let zero = _mm256_setzero_si256().as_i16x16();
+    transmute(simd_select_bitmask(k, sub, zero))
+}
+
+/// Subtracts packed 16-bit integers in `b` from packed 16-bit integers in `a`,
+/// and stores the results in `dst` using writemask `k` (elements are copied
+/// from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_sub_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpsubw))]
+pub unsafe fn _mm_mask_sub_epi16(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i {
+    let sub = _mm_sub_epi16(a, b).as_i16x8();
+    transmute(simd_select_bitmask(k, sub, src.as_i16x8()))
+}
+
+/// Subtracts packed 16-bit integers in `b` from packed 16-bit integers in `a`,
+/// and stores the results in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_sub_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpsubw))]
+pub unsafe fn _mm_maskz_sub_epi16(k: __mmask8, a: __m128i, b: __m128i) -> __m128i {
+    let sub = _mm_sub_epi16(a, b).as_i16x8();
+    let zero = _mm_setzero_si128().as_i16x8();
+    transmute(simd_select_bitmask(k, sub, zero))
+}
+
+/// Adds packed signed 8-bit integers in `a` and `b` using saturation.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_adds_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpaddsb))]
+pub unsafe fn _mm512_adds_epi8(a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpaddsb(
+        a.as_i8x64(),
+        b.as_i8x64(),
+        _mm512_setzero_si512().as_i8x64(),
+        0b11111111_11111111_11111111_11111111_11111111_11111111_11111111_11111111,
+    ))
+}
+
+/// Adds packed signed 8-bit integers in `a` and `b` using saturation, and
+/// stores the results in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_adds_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpaddsb))]
+pub unsafe fn _mm512_mask_adds_epi8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpaddsb(a.as_i8x64(), b.as_i8x64(), src.as_i8x64(), k))
+}
+
+/// Adds packed signed 8-bit integers in `a` and `b` using saturation, and
+/// stores the results in `dst` using zeromask `k` (elements are zeroed out when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_adds_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpaddsb))]
+pub unsafe fn _mm512_maskz_adds_epi8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpaddsb(
+        a.as_i8x64(),
+        b.as_i8x64(),
+        _mm512_setzero_si512().as_i8x64(),
+        k,
+    ))
+}
+
+/// Adds packed signed 16-bit integers in `a` and `b` using saturation.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_adds_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpaddsw))]
+pub unsafe fn _mm512_adds_epi16(a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpaddsw(
+        a.as_i16x32(),
+        b.as_i16x32(),
+        _mm512_setzero_si512().as_i16x32(),
+        0b11111111_11111111_11111111_11111111,
+    ))
+}
+
+/// Adds packed signed 16-bit integers in `a` and `b` using saturation, and
+/// stores the results in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_adds_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpaddsw))]
+pub unsafe fn _mm512_mask_adds_epi16(
+    src: __m512i,
+    k: __mmask32,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    transmute(vpaddsw(a.as_i16x32(), b.as_i16x32(), src.as_i16x32(), k))
+}
+
+/// Adds packed signed 16-bit integers in `a` and `b` using saturation, and
+/// stores the results in `dst` using zeromask `k` (elements are zeroed out when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_adds_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpaddsw))]
+pub unsafe fn _mm512_maskz_adds_epi16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpaddsw(
+        a.as_i16x32(),
+        b.as_i16x32(),
+        _mm512_setzero_si512().as_i16x32(),
+        k,
+    ))
+}
+
+/// Adds packed unsigned 8-bit integers in `a` and `b` using saturation.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_adds_epu8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpaddusb))]
+pub unsafe fn _mm512_adds_epu8(a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpaddusb(
+        a.as_u8x64(),
+        b.as_u8x64(),
+        _mm512_setzero_si512().as_u8x64(),
+        0b11111111_11111111_11111111_11111111_11111111_11111111_11111111_11111111,
+    ))
+}
+
+/// Adds packed unsigned 8-bit integers in `a` and `b` using saturation, and
+/// stores the results in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_adds_epu8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpaddusb))]
+pub unsafe fn _mm512_mask_adds_epu8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpaddusb(a.as_u8x64(), b.as_u8x64(), src.as_u8x64(), k))
+}
+
+/// Adds packed unsigned 8-bit integers in `a` and `b` using saturation, and
+/// stores the results in `dst` using zeromask `k` (elements are zeroed out when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_adds_epu8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpaddusb))]
+pub unsafe fn _mm512_maskz_adds_epu8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpaddusb(
+        a.as_u8x64(),
+        b.as_u8x64(),
+        _mm512_setzero_si512().as_u8x64(),
+        k,
+    ))
+}
+
+/// Adds packed unsigned 16-bit integers in `a` and `b` using saturation.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_adds_epu16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpaddusw))]
+pub unsafe fn _mm512_adds_epu16(a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpaddusw(
+        a.as_u16x32(),
+        b.as_u16x32(),
+        _mm512_setzero_si512().as_u16x32(),
+        0b11111111_11111111_11111111_11111111,
+    ))
+}
+
+/// Adds packed unsigned 16-bit integers in `a` and `b` using saturation, and
+/// stores the results in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_adds_epu16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpaddusw))]
+pub unsafe fn _mm512_mask_adds_epu16(
+    src: __m512i,
+    k: __mmask32,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    transmute(vpaddusw(a.as_u16x32(), b.as_u16x32(), src.as_u16x32(), k))
+}
+
+/// Adds packed unsigned 16-bit integers in `a` and `b` using saturation, and
+/// stores the results in `dst` using zeromask `k` (elements are zeroed out when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_adds_epu16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpaddusw))]
+pub unsafe fn _mm512_maskz_adds_epu16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpaddusw(
+        a.as_u16x32(),
+        b.as_u16x32(),
+        _mm512_setzero_si512().as_u16x32(),
+        k,
+    ))
+}
+
+/// Subtracts packed signed 8-bit integers in `b` from packed signed 8-bit
+/// integers in `a` using saturation.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_subs_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsubsb))]
+pub unsafe fn _mm512_subs_epi8(a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpsubsb(
+        a.as_i8x64(),
+        b.as_i8x64(),
+        _mm512_setzero_si512().as_i8x64(),
+        0b11111111_11111111_11111111_11111111_11111111_11111111_11111111_11111111,
+    ))
+}
+
+/// Subtracts packed signed 8-bit integers in `b` from packed signed 8-bit
+/// integers in `a` using saturation, and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_subs_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsubsb))]
+pub unsafe fn _mm512_mask_subs_epi8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpsubsb(a.as_i8x64(), b.as_i8x64(), src.as_i8x64(), k))
+}
+
+/// Subtracts packed signed 8-bit integers in `b` from packed signed 8-bit
+/// integers in `a` using saturation, and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_subs_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsubsb))]
+pub unsafe fn _mm512_maskz_subs_epi8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpsubsb(
+        a.as_i8x64(),
+        b.as_i8x64(),
+        _mm512_setzero_si512().as_i8x64(),
+        k,
+    ))
+}
+
+/// Subtracts packed signed 16-bit integers in `b` from packed signed 16-bit
+/// integers in `a` using saturation.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_subs_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsubsw))]
+pub unsafe fn _mm512_subs_epi16(a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpsubsw(
+        a.as_i16x32(),
+        b.as_i16x32(),
+        _mm512_setzero_si512().as_i16x32(),
+        0b11111111_11111111_11111111_11111111,
+    ))
+}
+
+/// Subtracts packed signed 16-bit integers in `b` from packed signed 16-bit
+/// integers in `a` using saturation, and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_subs_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsubsw))]
+pub unsafe fn _mm512_mask_subs_epi16(
+    src: __m512i,
+    k: __mmask32,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    transmute(vpsubsw(a.as_i16x32(), b.as_i16x32(), src.as_i16x32(), k))
+}
+
+/// Subtracts packed signed 16-bit integers in `b` from packed signed 16-bit
+/// integers in `a` using saturation, and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_subs_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsubsw))]
+pub unsafe fn _mm512_maskz_subs_epi16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpsubsw(
+        a.as_i16x32(),
+        b.as_i16x32(),
+        _mm512_setzero_si512().as_i16x32(),
+        k,
+    ))
+}
+
+/// Subtracts packed unsigned 8-bit integers in `b` from packed unsigned 8-bit
+/// integers in `a` using saturation.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_subs_epu8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsubusb))]
+pub unsafe fn _mm512_subs_epu8(a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpsubusb(
+        a.as_u8x64(),
+        b.as_u8x64(),
+        _mm512_setzero_si512().as_u8x64(),
+        0b11111111_11111111_11111111_11111111_11111111_11111111_11111111_11111111,
+    ))
+}
+
+/// Subtracts packed unsigned 8-bit integers in `b` from packed unsigned 8-bit
+/// integers in `a` using saturation, and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_subs_epu8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsubusb))]
+pub unsafe fn _mm512_mask_subs_epu8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpsubusb(a.as_u8x64(), b.as_u8x64(), src.as_u8x64(), k))
+}
+
+/// Subtracts packed unsigned 8-bit integers in `b` from packed unsigned 8-bit
+/// integers in `a` using saturation, and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_subs_epu8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsubusb))]
+pub unsafe fn _mm512_maskz_subs_epu8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpsubusb(
+        a.as_u8x64(),
+        b.as_u8x64(),
+        _mm512_setzero_si512().as_u8x64(),
+        k,
+    ))
+}
+
+/// Subtracts packed unsigned 16-bit integers in `b` from packed unsigned 16-bit
+/// integers in `a` using saturation.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_subs_epu16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsubusw))]
+pub unsafe fn _mm512_subs_epu16(a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpsubusw(
+        a.as_u16x32(),
+        b.as_u16x32(),
+        _mm512_setzero_si512().as_u16x32(),
+        0b11111111_11111111_11111111_11111111,
+    ))
+}
+
+/// Subtracts packed unsigned 16-bit integers in `b` from packed unsigned 16-bit
+/// integers in `a` using saturation, and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_subs_epu16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsubusw))]
+pub unsafe fn _mm512_mask_subs_epu16(
+    src: __m512i,
+    k: __mmask32,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    transmute(vpsubusw(a.as_u16x32(), b.as_u16x32(), src.as_u16x32(), k))
+}
+
+/// Subtracts packed unsigned 16-bit integers in `b` from packed unsigned 16-bit
+/// integers in `a` using saturation, and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_subs_epu16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsubusw))]
+pub unsafe fn _mm512_maskz_subs_epu16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpsubusw(
+        a.as_u16x32(),
+        b.as_u16x32(),
+        _mm512_setzero_si512().as_u16x32(),
+        k,
+    ))
+}
+
+/// Multiplies the packed signed 16-bit integers in `a` and `b`, producing
+/// intermediate 32-bit integers, and returns the high 16 bits of the
+/// intermediate integers.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mulhi_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmulhw))]
+pub unsafe fn _mm512_mulhi_epi16(a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpmulhw(a.as_i16x32(), b.as_i16x32()))
+}
+
+/// Multiplies the packed signed 16-bit integers in `a` and `b`, producing
+/// intermediate 32-bit integers, and stores the high 16 bits of the
+/// intermediate integers in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_mulhi_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmulhw))]
+pub unsafe fn _mm512_mask_mulhi_epi16(
+    src: __m512i,
+    k: __mmask32,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let mul = _mm512_mulhi_epi16(a, b).as_i16x32();
+    transmute(simd_select_bitmask(k, mul, src.as_i16x32()))
+}
+
+/// Multiplies the packed signed 16-bit integers in `a` and `b`, producing
+/// intermediate 32-bit integers, and stores the high 16 bits of the
+/// intermediate integers in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_mulhi_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmulhw))]
+pub unsafe fn _mm512_maskz_mulhi_epi16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    let mul = _mm512_mulhi_epi16(a, b).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, mul, zero))
+}
+
+/// Multiplies the packed unsigned 16-bit integers in `a` and `b`, producing
+/// intermediate 32-bit integers, and returns the high 16 bits of the
+/// intermediate integers.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mulhi_epu16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmulhuw))]
+pub unsafe fn _mm512_mulhi_epu16(a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpmulhuw(a.as_u16x32(), b.as_u16x32()))
+}
+
+/// Multiplies the packed unsigned 16-bit integers in `a` and `b`, producing
+/// intermediate 32-bit integers, and stores the high 16 bits of the
+/// intermediate integers in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_mulhi_epu16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmulhuw))]
+pub unsafe fn _mm512_mask_mulhi_epu16(
+    src: __m512i,
+    k: __mmask32,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let mul = _mm512_mulhi_epu16(a, b).as_u16x32();
+    transmute(simd_select_bitmask(k, mul, src.as_u16x32()))
+}
+
+/// Multiplies the packed unsigned 16-bit integers in `a` and `b`, producing
+/// intermediate 32-bit integers, and stores the high 16 bits of the
+/// intermediate integers in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_mulhi_epu16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmulhuw))]
+pub unsafe fn _mm512_maskz_mulhi_epu16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    let mul = _mm512_mulhi_epu16(a, b).as_u16x32();
+    let zero = _mm512_setzero_si512().as_u16x32();
+    transmute(simd_select_bitmask(k, mul, zero))
+}
+
+/// Multiplies packed signed 16-bit integers in `a` and `b`, producing
+/// intermediate signed 32-bit integers. Truncates each intermediate integer to
+/// the 18 most significant bits, rounds by adding 1, and returns bits [16:1].
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mulhrs_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmulhrsw))]
+pub unsafe fn _mm512_mulhrs_epi16(a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpmulhrsw(a.as_i16x32(), b.as_i16x32()))
+}
+
+/// Multiplies packed signed 16-bit integers in `a` and `b`, producing
+/// intermediate signed 32-bit integers. Truncates each intermediate integer to
+/// the 18 most significant bits, rounds by adding 1, and stores bits [16:1] in
+/// `dst` using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_mulhrs_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmulhrsw))]
+pub unsafe fn _mm512_mask_mulhrs_epi16(
+    src: __m512i,
+    k: __mmask32,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let mul = _mm512_mulhrs_epi16(a, b).as_i16x32();
+    transmute(simd_select_bitmask(k, mul, src.as_i16x32()))
+}
+
+/// Multiplies packed signed 16-bit integers in `a` and `b`, producing
+/// intermediate signed 32-bit integers. Truncates each intermediate integer to
+/// the 18 most significant bits, rounds by adding 1, and stores bits [16:1] in
+/// `dst` using zeromask `k` (elements are zeroed out when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_mulhrs_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmulhrsw))]
+pub unsafe fn _mm512_maskz_mulhrs_epi16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    let mul = _mm512_mulhrs_epi16(a, b).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, mul, zero))
+}
+
+/// Multiplies the packed 16-bit integers in `a` and `b`, producing intermediate
+/// 32-bit integers, and returns the low 16 bits of the intermediate integers.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mullo_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmullw))]
+pub unsafe fn _mm512_mullo_epi16(a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_mul(a.as_i16x32(), b.as_i16x32()))
+}
+
+/// Multiplies the packed 16-bit integers in `a` and `b`, producing intermediate
+/// 32-bit integers, and stores the low 16 bits of the intermediate integers in
+/// `dst` using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_mullo_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmullw))]
+pub unsafe fn _mm512_mask_mullo_epi16(
+    src: __m512i,
+    k: __mmask32,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let mul = _mm512_mullo_epi16(a, b).as_i16x32();
+    transmute(simd_select_bitmask(k, mul, src.as_i16x32()))
+}
+
+/// Multiplies the packed 16-bit integers in `a` and `b`, producing intermediate
+/// 32-bit integers, and stores the low 16 bits of the intermediate integers in
+/// `dst` using zeromask `k` (elements are zeroed out when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_mullo_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmullw))]
+pub unsafe fn _mm512_maskz_mullo_epi16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    let mul = _mm512_mullo_epi16(a, b).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, mul, zero))
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b`, and returns the packed
+/// maximum values.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_max_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmaxsb))]
+pub unsafe fn _mm512_max_epi8(a: __m512i, b: __m512i) -> __m512i {
+    let a = a.as_i8x64();
+    let b = b.as_i8x64();
+    let gt: i8x64 = simd_gt(a, b);
+    transmute(simd_select(gt, a, b))
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b`, and stores the packed
+/// maximum values in `dst` using writemask `k` (elements are copied from `src`
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_max_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmaxsb))]
+pub unsafe fn _mm512_mask_max_epi8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    let max = _mm512_max_epi8(a, b).as_i8x64();
+    transmute(simd_select_bitmask(k, max, src.as_i8x64()))
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b`, and stores the packed
+/// maximum values in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_max_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmaxsb))]
+pub unsafe fn _mm512_maskz_max_epi8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    let max = _mm512_max_epi8(a, b).as_i8x64();
+    let zero = _mm512_setzero_si512().as_i8x64();
+    transmute(simd_select_bitmask(k, max, zero))
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b`, and returns the
+/// packed maximum values.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_max_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmaxsw))]
+pub unsafe fn _mm512_max_epi16(a: __m512i, b: __m512i) -> __m512i {
+    let a = a.as_i16x32();
+    let b = b.as_i16x32();
+    let gt: i16x32 = simd_gt(a, b);
+    transmute(simd_select(gt, a, b))
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b`, and stores the packed
+/// maximum values in `dst` using writemask `k` (elements are copied from `src`
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_max_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmaxsw))]
+pub unsafe fn _mm512_mask_max_epi16(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    let max = _mm512_max_epi16(a, b).as_i16x32();
+    transmute(simd_select_bitmask(k, max, src.as_i16x32()))
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b`, and stores the packed
+/// maximum values in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_max_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmaxsw))]
+pub unsafe fn _mm512_maskz_max_epi16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    let max = _mm512_max_epi16(a, b).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, max, zero))
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b`, and returns the
+/// packed maximum values.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_max_epu8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmaxub))]
+pub unsafe fn _mm512_max_epu8(a: __m512i, b: __m512i) -> __m512i {
+    let a = a.as_u8x64();
+    let b = b.as_u8x64();
+    let gt: i8x64 = simd_gt(a, b);
+    transmute(simd_select(gt, a, b))
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b`, and stores the
+/// packed maximum values in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_max_epu8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmaxub))]
+pub unsafe fn _mm512_mask_max_epu8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    let max = _mm512_max_epu8(a, b).as_i8x64();
+    transmute(simd_select_bitmask(k, max, src.as_i8x64()))
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b`, and stores the
+/// packed maximum values in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_max_epu8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmaxub))]
+pub unsafe fn _mm512_maskz_max_epu8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    let max = _mm512_max_epu8(a, b).as_i8x64();
+    let zero = _mm512_setzero_si512().as_i8x64();
+    transmute(simd_select_bitmask(k, max, zero))
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b`, and returns the
+/// packed maximum values.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_max_epu16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmaxuw))]
+pub unsafe fn _mm512_max_epu16(a: __m512i, b: __m512i) -> __m512i {
+    let a = a.as_u16x32();
+    let b = b.as_u16x32();
+    let gt: i16x32 = simd_gt(a, b);
+    transmute(simd_select(gt, a, b))
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b`, and stores the
+/// packed maximum values in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_max_epu16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmaxuw))]
+pub unsafe fn _mm512_mask_max_epu16(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    let max = _mm512_max_epu16(a, b).as_i16x32();
+    transmute(simd_select_bitmask(k, max, src.as_i16x32()))
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b`, and stores the
+/// packed maximum values in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_max_epu16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmaxuw))]
+pub unsafe fn _mm512_maskz_max_epu16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    let max = _mm512_max_epu16(a, b).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, max, zero))
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b`, and returns the packed
+/// minimum values.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_min_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpminsb))]
+pub unsafe fn _mm512_min_epi8(a: __m512i, b: __m512i) -> __m512i {
+    let a = a.as_i8x64();
+    let b = b.as_i8x64();
+    let lt: i8x64 = simd_lt(a, b);
+    transmute(simd_select(lt, a, b))
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b`, and stores the packed
+/// minimum values in `dst` using writemask `k` (elements are copied from `src`
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_min_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpminsb))]
+pub unsafe fn _mm512_mask_min_epi8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    let min = _mm512_min_epi8(a, b).as_i8x64();
+    transmute(simd_select_bitmask(k, min, src.as_i8x64()))
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b`, and stores the packed
+/// minimum values in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_min_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpminsb))]
+pub unsafe fn _mm512_maskz_min_epi8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    let min = _mm512_min_epi8(a, b).as_i8x64();
+    let zero = _mm512_setzero_si512().as_i8x64();
+    transmute(simd_select_bitmask(k, min, zero))
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b`, and returns the
+/// packed minimum values.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_min_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpminsw))]
+pub unsafe fn _mm512_min_epi16(a: __m512i, b: __m512i) -> __m512i {
+    let a = a.as_i16x32();
+    let b = b.as_i16x32();
+    let lt: i16x32 = simd_lt(a, b);
+    transmute(simd_select(lt, a, b))
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b`, and stores the packed
+/// minimum values in `dst` using writemask `k` (elements are copied from `src`
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_min_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpminsw))]
+pub unsafe fn _mm512_mask_min_epi16(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    let min = _mm512_min_epi16(a, b).as_i16x32();
+    transmute(simd_select_bitmask(k, min, src.as_i16x32()))
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b`, and stores the packed
+/// minimum values in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_min_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpminsw))]
+pub unsafe fn _mm512_maskz_min_epi16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    let min = _mm512_min_epi16(a, b).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, min, zero))
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b`, and returns the
+/// packed minimum values.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_min_epu8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpminub))]
+pub unsafe fn _mm512_min_epu8(a: __m512i, b: __m512i) -> __m512i {
+    let a = a.as_u8x64();
+    let b = b.as_u8x64();
+    let lt: i8x64 = simd_lt(a, b);
+    transmute(simd_select(lt, a, b))
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b`, and stores the
+/// packed minimum values in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_min_epu8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpminub))]
+pub unsafe fn _mm512_mask_min_epu8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    let min = _mm512_min_epu8(a, b).as_i8x64();
+    transmute(simd_select_bitmask(k, min, src.as_i8x64()))
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b`, and stores the
+/// packed minimum values in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_min_epu8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpminub))]
+pub unsafe fn _mm512_maskz_min_epu8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    let min = _mm512_min_epu8(a, b).as_i8x64();
+    let zero = _mm512_setzero_si512().as_i8x64();
+    transmute(simd_select_bitmask(k, min, zero))
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b`, and returns the
+/// packed minimum values.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_min_epu16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpminuw))]
+pub unsafe fn _mm512_min_epu16(a: __m512i, b: __m512i) -> __m512i {
+    let a = a.as_u16x32();
+    let b = b.as_u16x32();
+    let lt: i16x32 = simd_lt(a, b);
+    transmute(simd_select(lt, a, b))
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b`, and stores the
+/// packed minimum values in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_min_epu16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpminuw))]
+pub unsafe fn _mm512_mask_min_epu16(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    let min = _mm512_min_epu16(a, b).as_i16x32();
+    transmute(simd_select_bitmask(k, min, src.as_i16x32()))
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b`, and stores the
+/// packed minimum values in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_min_epu16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpminuw))]
+pub unsafe fn _mm512_maskz_min_epu16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    let min = _mm512_min_epu16(a, b).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, min, zero))
+}
+
+/// Averages packed unsigned 8-bit integers in `a` and `b`, rounding upwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_avg_epu8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpavgb))]
+pub unsafe fn _mm512_avg_epu8(a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpavgb(a.as_u8x64(), b.as_u8x64()))
+}
+
+/// Averages packed unsigned 8-bit integers in `a` and `b`, rounding upwards,
+/// and stores the results in `dst` using writemask `k` (elements are copied
+/// from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_avg_epu8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpavgb))]
+pub unsafe fn _mm512_mask_avg_epu8(src: __m512i, k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    let avg = _mm512_avg_epu8(a, b).as_u8x64();
+    transmute(simd_select_bitmask(k, avg, src.as_u8x64()))
+}
+
+/// Averages packed unsigned 8-bit integers in `a` and `b`, rounding upwards,
+/// and stores the results in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_avg_epu8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpavgb))]
+pub unsafe fn _mm512_maskz_avg_epu8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    let avg = _mm512_avg_epu8(a, b).as_u8x64();
+    let zero = _mm512_setzero_si512().as_u8x64();
+    transmute(simd_select_bitmask(k, avg, zero))
+}
+
+/// Averages packed unsigned 16-bit integers in `a` and `b`, rounding upwards.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_avg_epu16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpavgw))]
+pub unsafe fn _mm512_avg_epu16(a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpavgw(a.as_u16x32(), b.as_u16x32()))
+}
+
+/// Averages packed unsigned 16-bit integers in `a` and `b`, rounding upwards,
+/// and stores the results in `dst` using writemask `k` (elements are copied
+/// from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_avg_epu16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpavgw))]
+pub unsafe fn _mm512_mask_avg_epu16(src: __m512i, k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    let avg = _mm512_avg_epu16(a, b).as_u16x32();
+    transmute(simd_select_bitmask(k, avg, src.as_u16x32()))
+}
+
+/// Averages packed unsigned 16-bit integers in `a` and `b`, rounding upwards,
+/// and stores the results in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_avg_epu16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpavgw))]
+pub unsafe fn _mm512_maskz_avg_epu16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    let avg = _mm512_avg_epu16(a, b).as_u16x32();
+    let zero = _mm512_setzero_si512().as_u16x32();
+    transmute(simd_select_bitmask(k, avg, zero))
+}
+
+/// Multiplies packed signed 16-bit integers in `a` and `b`, producing
+/// intermediate signed 32-bit integers, and horizontally adds adjacent pairs of
+/// intermediate 32-bit integers.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_madd_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmaddwd))]
+pub unsafe fn _mm512_madd_epi16(a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpmaddwd(a.as_i16x32(), b.as_i16x32()))
+}
+
+/// Multiplies packed signed 16-bit integers in `a` and `b`, producing
+/// intermediate signed 32-bit integers, horizontally adds adjacent pairs of
+/// intermediate 32-bit integers, and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_madd_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmaddwd))]
+pub unsafe fn _mm512_mask_madd_epi16(
+    src: __m512i,
+    k: __mmask16,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let madd = _mm512_madd_epi16(a, b).as_i32x16();
+    transmute(simd_select_bitmask(k, madd, src.as_i32x16()))
+}
+
+/// Multiplies packed signed 16-bit integers in `a` and `b`, producing
+/// intermediate signed 32-bit integers, horizontally adds adjacent pairs of
+/// intermediate 32-bit integers, and stores the results in `dst` using zeromask
+/// `k` (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_madd_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmaddwd))]
+pub unsafe fn _mm512_maskz_madd_epi16(k: __mmask16, a: __m512i, b: __m512i) -> __m512i {
+    let madd = _mm512_madd_epi16(a, b).as_i32x16();
+    let zero = _mm512_setzero_si512().as_i32x16();
+    transmute(simd_select_bitmask(k, madd, zero))
+}
+
+/// Vertically multiplies each unsigned 8-bit integer from `a` with the
+/// corresponding signed 8-bit integer from `b`, producing intermediate signed
+/// 16-bit integers, and horizontally adds adjacent pairs of intermediate signed
+/// 16-bit integers using saturation.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maddubs_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmaddubsw))]
+pub unsafe fn _mm512_maddubs_epi16(a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpmaddubsw(a.as_u8x64(), b.as_i8x64()))
+}
+
+/// Vertically multiplies each unsigned 8-bit integer from `a` with the
+/// corresponding signed 8-bit integer from `b`, producing intermediate signed
+/// 16-bit integers, horizontally adds adjacent pairs of intermediate signed
+/// 16-bit integers using saturation, and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_maddubs_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmaddubsw))]
+pub unsafe fn _mm512_mask_maddubs_epi16(
+    src: __m512i,
+    k: __mmask32,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let madd = _mm512_maddubs_epi16(a, b).as_i16x32();
+    transmute(simd_select_bitmask(k, madd, src.as_i16x32()))
+}
+
+/// Vertically multiplies each unsigned 8-bit integer from `a` with the
+/// corresponding signed 8-bit integer from `b`, producing intermediate signed
+/// 16-bit integers, horizontally adds adjacent pairs of intermediate signed
+/// 16-bit integers using saturation, and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_maddubs_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmaddubsw))]
+pub unsafe fn _mm512_maskz_maddubs_epi16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    let madd = _mm512_maddubs_epi16(a, b).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, madd, zero))
+}
+
+/// Converts packed signed 16-bit integers from `a` and `b` to packed 8-bit
+/// integers using signed saturation.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_packs_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpacksswb))]
+pub unsafe fn _mm512_packs_epi16(a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpacksswb(a.as_i16x32(), b.as_i16x32()))
+}
+
+/// Converts packed signed 16-bit integers from `a` and `b` to packed 8-bit
+/// integers using signed saturation, and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_packs_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpacksswb))]
+pub unsafe fn _mm512_mask_packs_epi16(
+    src: __m512i,
+    k: __mmask64,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let pack = _mm512_packs_epi16(a, b).as_i8x64();
+    transmute(simd_select_bitmask(k, pack, src.as_i8x64()))
+}
+
+/// Converts packed signed 16-bit integers from `a` and `b` to packed 8-bit
+/// integers using signed saturation, and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_packs_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpacksswb))]
+pub unsafe fn _mm512_maskz_packs_epi16(k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    let pack = _mm512_packs_epi16(a, b).as_i8x64();
+    let zero = _mm512_setzero_si512().as_i8x64();
+    transmute(simd_select_bitmask(k, pack, zero))
+}
+
+/// Converts packed signed 32-bit integers from `a` and `b` to packed 16-bit
+/// integers using signed saturation.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_packs_epi32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpackssdw))]
+pub unsafe fn _mm512_packs_epi32(a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpackssdw(a.as_i32x16(), b.as_i32x16()))
+}
+
+/// Converts packed signed 32-bit integers from `a` and `b` to packed 16-bit
+/// integers using signed saturation, and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_packs_epi32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpackssdw))]
+pub unsafe fn _mm512_mask_packs_epi32(
+    src: __m512i,
+    k: __mmask32,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let pack = _mm512_packs_epi32(a, b).as_i16x32();
+    transmute(simd_select_bitmask(k, pack, src.as_i16x32()))
+}
+
+/// Converts packed signed 32-bit integers from `a` and `b` to packed 16-bit
+/// integers using signed saturation, and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_packs_epi32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpackssdw))]
+pub unsafe fn _mm512_maskz_packs_epi32(k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    let pack = _mm512_packs_epi32(a, b).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, pack, zero))
+}
+
+/// Converts packed signed 16-bit integers from `a` and `b` to packed 8-bit
+/// integers using unsigned saturation.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_packus_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpackuswb))]
+pub unsafe fn _mm512_packus_epi16(a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpackuswb(a.as_i16x32(), b.as_i16x32()))
+}
+
+/// Converts packed signed 16-bit integers from `a` and `b` to packed 8-bit
+/// integers using unsigned saturation, and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_packus_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpackuswb))]
+pub unsafe fn _mm512_mask_packus_epi16(
+    src: __m512i,
+    k: __mmask64,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let pack = _mm512_packus_epi16(a, b).as_u8x64();
+    transmute(simd_select_bitmask(k, pack, src.as_u8x64()))
+}
+
+/// Converts packed signed 16-bit integers from `a` and `b` to packed 8-bit
+/// integers using unsigned saturation, and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_packus_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpackuswb))]
+pub unsafe fn _mm512_maskz_packus_epi16(k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    let pack = _mm512_packus_epi16(a, b).as_u8x64();
+    let zero = _mm512_setzero_si512().as_u8x64();
+    transmute(simd_select_bitmask(k, pack, zero))
+}
+
+/// Converts packed signed 32-bit integers from `a` and `b` to packed 16-bit
+/// integers using unsigned saturation.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_packus_epi32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpackusdw))]
+pub unsafe fn _mm512_packus_epi32(a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpackusdw(a.as_i32x16(), b.as_i32x16()))
+}
+
+/// Converts packed signed 32-bit integers from `a` and `b` to packed 16-bit
+/// integers using unsigned saturation, and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_packus_epi32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpackusdw))]
+pub unsafe fn _mm512_mask_packus_epi32(
+    src: __m512i,
+    k: __mmask32,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let pack = _mm512_packus_epi32(a, b).as_u16x32();
+    transmute(simd_select_bitmask(k, pack, src.as_u16x32()))
+}
+
+/// Converts packed signed 32-bit integers from `a` and `b` to packed 16-bit
+/// integers using unsigned saturation, and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_packus_epi32)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpackusdw))]
+pub unsafe fn _mm512_maskz_packus_epi32(k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    let pack = _mm512_packus_epi32(a, b).as_u16x32();
+    let zero = _mm512_setzero_si512().as_u16x32();
+    transmute(simd_select_bitmask(k, pack, zero))
+}
+
+/// Shuffles packed 8-bit integers in `a` within 128-bit lanes using the shuffle
+/// control mask in the corresponding 8-bit element of `b`.
+///
+/// If the most significant bit of an element of `b` is set, the corresponding
+/// element of `dst` is zeroed out.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_shuffle_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpshufb))]
+pub unsafe fn _mm512_shuffle_epi8(a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpshufb(a.as_i8x64(), b.as_i8x64()))
+}
+
+/// Shuffles packed 8-bit integers in `a` within 128-bit lanes using the shuffle
+/// control mask in the corresponding 8-bit element of `b`, and stores the
+/// results in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_shuffle_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpshufb))]
+pub unsafe fn _mm512_mask_shuffle_epi8(
+    src: __m512i,
+    k: __mmask64,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let shf = _mm512_shuffle_epi8(a, b).as_i8x64();
+    transmute(simd_select_bitmask(k, shf, src.as_i8x64()))
+}
+
+/// Shuffles packed 8-bit integers in `a` within 128-bit lanes using the shuffle
+/// control mask in the corresponding 8-bit element of `b`, and stores the
+/// results in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_shuffle_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpshufb))]
+pub unsafe fn _mm512_maskz_shuffle_epi8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    let shf = _mm512_shuffle_epi8(a, b).as_i8x64();
+    let zero = _mm512_setzero_si512().as_i8x64();
+    transmute(simd_select_bitmask(k, shf, zero))
+}
+
+/// Computes the absolute differences of packed unsigned 8-bit integers in `a`
+/// and `b`, then horizontally sums each consecutive 8 differences to produce
+/// eight unsigned 16-bit integers, and packs these unsigned 16-bit integers in
+/// the low 16 bits of 64-bit elements in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_sad_epu8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsadbw))]
+pub unsafe fn _mm512_sad_epu8(a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpsadbw(a.as_u8x64(), b.as_u8x64()))
+}
+
+/// Computes the sum of absolute differences (SADs) of quadruplets of unsigned
+/// 8-bit integers in `a` compared to those in `b`, and returns the 16-bit
+/// results.
+///
+/// Four SADs are performed on four 8-bit quadruplets for each 64-bit lane. The
+/// first two SADs use the lower 8-bit quadruplet of the lane from `a`, and the
+/// last two SADs use the upper 8-bit quadruplet of the lane from `a`.
+/// Quadruplets from `b` are selected from within 128-bit lanes according to the
+/// control in `imm8`, and each SAD in each 64-bit lane uses the selected
+/// quadruplet at 8-bit offsets.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_dbsad_epu8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vdbpsadbw, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_dbsad_epu8(a: __m512i, b: __m512i, imm8: i32) -> __m512i {
+    let a = a.as_u8x64();
+    let b = b.as_u8x64();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vdbpsadbw(a, b, $imm8)
+        };
+    }
+    transmute(constify_imm8!(imm8, call))
+}
+
+/// Computes the sum of absolute differences (SADs) of quadruplets of unsigned
+/// 8-bit integers in `a` compared to those in `b`, and stores the 16-bit
+/// results in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_dbsad_epu8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vdbpsadbw, imm8 = 0))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm512_mask_dbsad_epu8(
+    src: __m512i,
+    k: __mmask32,
+    a: __m512i,
+    b: __m512i,
+    imm8: i32,
+) -> __m512i {
+    let a = a.as_u8x64();
+    let b = b.as_u8x64();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vdbpsadbw(a, b, $imm8)
+        };
+    }
+    let sad = constify_imm8!(imm8, call);
+    transmute(simd_select_bitmask(k, sad, src.as_u16x32()))
+}
+
+/// Computes the sum of absolute differences (SADs) of quadruplets of unsigned
+/// 8-bit integers in `a` compared to those in `b`, and stores the 16-bit
+/// results in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_dbsad_epu8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vdbpsadbw, imm8 = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm512_maskz_dbsad_epu8(k: __mmask32, a: __m512i, b: __m512i, imm8: i32) -> __m512i {
+    let a = a.as_u8x64();
+    let b = b.as_u8x64();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vdbpsadbw(a, b, $imm8)
+        };
+    }
+    let sad = constify_imm8!(imm8, call);
+    let zero = _mm512_setzero_si512().as_u16x32();
+    transmute(simd_select_bitmask(k, sad, zero))
+}
+
+/// Shifts packed 16-bit integers in `a` left by `imm8` while shifting in zeros.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_slli_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsllw))]
+pub unsafe fn _mm512_slli_epi16(a: __m512i, imm8: u32) -> __m512i {
+    transmute(vpslliw(a.as_i16x32(), imm8))
+}
+
+/// Shifts packed 16-bit integers in `a` left by `imm8` while shifting in zeros,
+/// and stores the results in `dst` using writemask `k` (elements are copied
+/// from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_slli_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsllw))]
+pub unsafe fn _mm512_mask_slli_epi16(src: __m512i, k: __mmask32, a: __m512i, imm8: u32) -> __m512i {
+    let shf = _mm512_slli_epi16(a, imm8).as_i16x32();
+    transmute(simd_select_bitmask(k, shf, src.as_i16x32()))
+}
+
+/// Shifts packed 16-bit integers in `a` left by `imm8` while shifting in zeros,
+/// and stores the results in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_slli_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsllw))]
+pub unsafe fn _mm512_maskz_slli_epi16(k: __mmask32, a: __m512i, imm8: u32) -> __m512i {
+    let shf = _mm512_slli_epi16(a, imm8).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, shf, zero))
+}
+
+/// Shifts packed 16-bit integers in `a` left by `count` while shifting in
+/// zeros.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_sll_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsllw))]
+pub unsafe fn _mm512_sll_epi16(a: __m512i, count: __m128i) -> __m512i {
+    transmute(vpsllw(a.as_i16x32(), count.as_i16x8()))
+}
+
+/// Shifts packed 16-bit integers in `a` left by `count` while shifting in
+/// zeros, and stores the results in `dst` using writemask `k` (elements are
+/// copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_sll_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsllw))]
+pub unsafe fn _mm512_mask_sll_epi16(
+    src: __m512i,
+    k: __mmask32,
+    a: __m512i,
+    count: __m128i,
+) -> __m512i {
+    let shf = _mm512_sll_epi16(a, count).as_i16x32();
+    transmute(simd_select_bitmask(k, shf, src.as_i16x32()))
+}
+
+/// Shifts packed 16-bit integers in `a` left by `count` while shifting in
+/// zeros, and stores the results in `dst` using zeromask `k` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_sll_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsllw))]
+pub unsafe fn _mm512_maskz_sll_epi16(k: __mmask32, a: __m512i, count: __m128i) -> __m512i {
+    let shf = _mm512_sll_epi16(a, count).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, shf, zero))
+}
+
+/// Shifts packed 16-bit integers in `a` left by the amount specified by the
+/// corresponding element in `count` while shifting in zeros.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_sllv_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsllvw))]
+pub unsafe fn _mm512_sllv_epi16(a: __m512i, count: __m512i) -> __m512i {
+    transmute(vpsllvw(a.as_i16x32(), count.as_i16x32()))
+}
+
+/// Shifts packed 16-bit integers in `a` left by the amount specified by the
+/// corresponding element in `count` while shifting in zeros, and stores the
+/// results in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_sllv_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsllvw))]
+pub unsafe fn _mm512_mask_sllv_epi16(
+    src: __m512i,
+    k: __mmask32,
+    a: __m512i,
+    count: __m512i,
+) -> __m512i {
+    let shf = _mm512_sllv_epi16(a, count).as_i16x32();
+    transmute(simd_select_bitmask(k, shf, src.as_i16x32()))
+}
+
+/// Shifts packed 16-bit integers in `a` left by the amount specified by the
+/// corresponding element in `count` while shifting in zeros, and stores the
+/// results in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_sllv_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsllvw))]
+pub unsafe fn _mm512_maskz_sllv_epi16(k: __mmask32, a: __m512i, count: __m512i) -> __m512i {
+    let shf = _mm512_sllv_epi16(a, count).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, shf, zero))
+}
+
+/// Shifts packed 16-bit integers in `a` right by `imm8` while shifting in
+/// zeros.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_srli_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsrlw))]
+pub unsafe fn _mm512_srli_epi16(a: __m512i, imm8: u32) -> __m512i {
+    transmute(vpsrliw(a.as_i16x32(), imm8))
+}
+
+/// Shifts packed 16-bit integers in `a` right by `imm8` while shifting in
+/// zeros, and stores the results in `dst` using writemask `k` (elements are
+/// copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_srli_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsrlw))]
+pub unsafe fn _mm512_mask_srli_epi16(src: __m512i, k: __mmask32, a: __m512i, imm8: u32) -> __m512i {
+    let shf = _mm512_srli_epi16(a, imm8).as_i16x32();
+    transmute(simd_select_bitmask(k, shf, src.as_i16x32()))
+}
+
+/// Shifts packed 16-bit integers in `a` right by `imm8` while shifting in
+/// zeros, and stores the results in `dst` using zeromask `k` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_srli_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsrlw))]
+pub unsafe fn _mm512_maskz_srli_epi16(k: __mmask32, a: __m512i, imm8: u32) -> __m512i {
+    let shf = _mm512_srli_epi16(a, imm8).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, shf, zero))
+}
+
+/// Shifts packed 16-bit integers in `a` right by `count` while shifting in
+/// zeros.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_srl_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsrlw))]
+pub unsafe fn _mm512_srl_epi16(a: __m512i, count: __m128i) -> __m512i {
+    transmute(vpsrlw(a.as_i16x32(), count.as_i16x8()))
+}
+
+/// Shifts packed 16-bit integers in `a` right by `count` while shifting in
+/// zeros, and stores the results in `dst` using writemask `k` (elements are
+/// copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_srl_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsrlw))]
+pub unsafe fn _mm512_mask_srl_epi16(
+    src: __m512i,
+    k: __mmask32,
+    a: __m512i,
+    count: __m128i,
+) -> __m512i {
+    let shf = _mm512_srl_epi16(a, count).as_i16x32();
+    transmute(simd_select_bitmask(k, shf, src.as_i16x32()))
+}
+
+/// Shifts packed 16-bit integers in `a` right by `count` while shifting in
+/// zeros, and stores the results in `dst` using zeromask `k` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_srl_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsrlw))]
+pub unsafe fn _mm512_maskz_srl_epi16(k: __mmask32, a: __m512i, count: __m128i) -> __m512i {
+    let shf = _mm512_srl_epi16(a, count).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, shf, zero))
+}
+
+/// Shifts packed 16-bit integers in `a` right by the amount specified by the
+/// corresponding element in `count` while shifting in zeros.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_srlv_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsrlvw))]
+pub unsafe fn _mm512_srlv_epi16(a: __m512i, count: __m512i) -> __m512i {
+    transmute(vpsrlvw(a.as_i16x32(), count.as_i16x32()))
+}
+
+/// Shifts packed 16-bit integers in `a` right by the amount specified by the
+/// corresponding element in `count` while shifting in zeros, and stores the
+/// results in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_srlv_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsrlvw))]
+pub unsafe fn _mm512_mask_srlv_epi16(
+    src: __m512i,
+    k: __mmask32,
+    a: __m512i,
+    count: __m512i,
+) -> __m512i {
+    let shf = _mm512_srlv_epi16(a, count).as_i16x32();
+    transmute(simd_select_bitmask(k, shf, src.as_i16x32()))
+}
+
+/// Shifts packed 16-bit integers in `a` right by the amount specified by the
+/// corresponding element in `count` while shifting in zeros, and stores the
+/// results in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_srlv_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsrlvw))]
+pub unsafe fn _mm512_maskz_srlv_epi16(k: __mmask32, a: __m512i, count: __m512i) -> __m512i {
+    let shf = _mm512_srlv_epi16(a, count).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, shf, zero))
+}
+
+/// Shifts packed 16-bit integers in `a` right by `imm8` while shifting in sign
+/// bits.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_srai_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsraw))]
+pub unsafe fn _mm512_srai_epi16(a: __m512i, imm8: u32) -> __m512i {
+    transmute(vpsraiw(a.as_i16x32(), imm8))
+}
+
+/// Shifts packed 16-bit integers in `a` right by `imm8` while shifting in sign
+/// bits, and stores the results in `dst` using writemask `k` (elements are
+/// copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_srai_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsraw))]
+pub unsafe fn _mm512_mask_srai_epi16(src: __m512i, k: __mmask32, a: __m512i, imm8: u32) -> __m512i {
+    let shf = _mm512_srai_epi16(a, imm8).as_i16x32();
+    transmute(simd_select_bitmask(k, shf, src.as_i16x32()))
+}
+
+/// Shifts packed 16-bit integers in `a` right by `imm8` while shifting in sign
+/// bits, and stores the results in `dst` using zeromask `k` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_srai_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsraw))]
+pub unsafe fn _mm512_maskz_srai_epi16(k: __mmask32, a: __m512i, imm8: u32) -> __m512i {
+    let shf = _mm512_srai_epi16(a, imm8).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, shf, zero))
+}
+
+/// Shifts packed 16-bit integers in `a` right by `count` while shifting in sign
+/// bits.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_sra_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsraw))]
+pub unsafe fn _mm512_sra_epi16(a: __m512i, count: __m128i) -> __m512i {
+    transmute(vpsraw(a.as_i16x32(), count.as_i16x8()))
+}
+
+/// Shifts packed 16-bit integers in `a` right by `count` while shifting in sign
+/// bits, and stores the results in `dst` using writemask `k` (elements are
+/// copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_sra_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsraw))]
+pub unsafe fn _mm512_mask_sra_epi16(
+    src: __m512i,
+    k: __mmask32,
+    a: __m512i,
+    count: __m128i,
+) -> __m512i {
+    let shf = _mm512_sra_epi16(a, count).as_i16x32();
+    transmute(simd_select_bitmask(k, shf, src.as_i16x32()))
+}
+
+/// Shifts packed 16-bit integers in `a` right by `count` while shifting in sign
+/// bits, and stores the results in `dst` using zeromask `k` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_sra_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsraw))]
+pub unsafe fn _mm512_maskz_sra_epi16(k: __mmask32, a: __m512i, count: __m128i) -> __m512i {
+    let shf = _mm512_sra_epi16(a, count).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, shf, zero))
+}
+
+/// Shifts packed 16-bit integers in `a` right by the amount specified by the
+/// corresponding element in `count` while shifting in sign bits.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_srav_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsravw))]
+pub unsafe fn _mm512_srav_epi16(a: __m512i, count: __m512i) -> __m512i {
+    transmute(vpsravw(a.as_i16x32(), count.as_i16x32()))
+}
+
+/// Shifts packed 16-bit integers in `a` right by the amount specified by the
+/// corresponding element in `count` while shifting in sign bits, and stores the
+/// results in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_srav_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsravw))]
+pub unsafe fn _mm512_mask_srav_epi16(
+    src: __m512i,
+    k: __mmask32,
+    a: __m512i,
+    count: __m512i,
+) -> __m512i {
+    let shf = _mm512_srav_epi16(a, count).as_i16x32();
+    transmute(simd_select_bitmask(k, shf, src.as_i16x32()))
+}
+
+/// Shifts packed 16-bit integers in `a` right by the amount specified by the
+/// corresponding element in `count` while shifting in sign bits, and stores the
+/// results in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_srav_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsravw))]
+pub unsafe fn _mm512_maskz_srav_epi16(k: __mmask32, a: __m512i, count: __m512i) -> __m512i {
+    let shf = _mm512_srav_epi16(a, count).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, shf, zero))
+}
+
+/// Blends packed 8-bit integers from `a` and `b` using control mask `k`, and
+/// returns the results. Elements are taken from `b` when the corresponding mask
+/// bit is set, and from `a` otherwise.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_blend_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpblendmb))]
+pub unsafe fn _mm512_mask_blend_epi8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_select_bitmask(k, b.as_i8x64(), a.as_i8x64()))
+}
+
+/// Blends packed 8-bit integers from `a` and `b` using control mask `k`, and
+/// returns the results. Elements are taken from `b` when the corresponding mask
+/// bit is set, and from `a` otherwise.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_blend_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpblendmb))]
+pub unsafe fn _mm256_mask_blend_epi8(k: __mmask32, a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_select_bitmask(k, b.as_i8x32(), a.as_i8x32()))
+}
+
+/// Blends packed 8-bit integers from `a` and `b` using control mask `k`, and
+/// returns the results. Elements are taken from `b` when the corresponding mask
+/// bit is set, and from `a` otherwise.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_blend_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpblendmb))]
+pub unsafe fn _mm_mask_blend_epi8(k: __mmask16, a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_select_bitmask(k, b.as_i8x16(), a.as_i8x16()))
+}
+
+/// Blends packed 16-bit integers from `a` and `b` using control mask `k`, and
+/// returns the results. Elements are taken from `b` when the corresponding mask
+/// bit is set, and from `a` otherwise.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_blend_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpblendmw))]
+pub unsafe fn _mm512_mask_blend_epi16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_select_bitmask(k, b.as_i16x32(), a.as_i16x32()))
+}
+
+/// Blends packed 16-bit integers from `a` and `b` using control mask `k`, and
+/// returns the results. Elements are taken from `b` when the corresponding mask
+/// bit is set, and from `a` otherwise.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_blend_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpblendmw))]
+pub unsafe fn _mm256_mask_blend_epi16(k: __mmask16, a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_select_bitmask(k, b.as_i16x16(), a.as_i16x16()))
+}
+
+/// Blends packed 16-bit integers from `a` and `b` using control mask `k`, and
+/// returns the results. Elements are taken from `b` when the corresponding mask
+/// bit is set, and from `a` otherwise.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_blend_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpblendmw))]
+pub unsafe fn _mm_mask_blend_epi16(k: __mmask8, a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_select_bitmask(k, b.as_i16x8(), a.as_i16x8()))
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` based on the comparison
+/// operand specified by `imm8`, and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmp_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_cmp_epi8_mask(a: __m512i, b: __m512i, imm8: _MM_CMPINT_ENUM) -> __mmask64 {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vpcmpb(
+                a.as_i8x64(),
+                b.as_i8x64(),
+                $imm3,
+                0b11111111_11111111_11111111_11111111_11111111_11111111_11111111_11111111,
+            )
+        };
+    }
+    constify_imm3!(imm8, call)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` based on the comparison
+/// operand specified by `imm8`, and stores the results in mask vector `k` using
+/// zeromask `k1` (elements are zeroed out when the corresponding mask bit is
+/// not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmp_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm512_mask_cmp_epi8_mask(
+    k1: __mmask64,
+    a: __m512i,
+    b: __m512i,
+    imm8: _MM_CMPINT_ENUM,
+) -> __mmask64 {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vpcmpb(a.as_i8x64(), b.as_i8x64(), $imm3, k1)
+        };
+    }
+    constify_imm3!(imm8, call)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` based on the comparison
+/// operand specified by `imm8`, and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmp_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm256_cmp_epi8_mask(a: __m256i, b: __m256i, imm8: _MM_CMPINT_ENUM) -> __mmask32 {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vpcmpb256(
+                a.as_i8x32(),
+                b.as_i8x32(),
+                $imm3,
+                0b11111111_11111111_11111111_11111111,
+            )
+        };
+    }
+    constify_imm3!(imm8, call)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` based on the comparison
+/// operand specified by `imm8`, and stores the results in mask vector `k` using
+/// zeromask `k1` (elements are zeroed out when the corresponding mask bit is
+/// not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmp_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm256_mask_cmp_epi8_mask(
+    k1: __mmask32,
+    a: __m256i,
+    b: __m256i,
+    imm8: _MM_CMPINT_ENUM,
+) -> __mmask32 {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vpcmpb256(a.as_i8x32(), b.as_i8x32(), $imm3, k1)
+        };
+    }
+    constify_imm3!(imm8, call)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` based on the comparison
+/// operand specified by `imm8`, and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmp_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_cmp_epi8_mask(a: __m128i, b: __m128i, imm8: _MM_CMPINT_ENUM) -> __mmask16 {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vpcmpb128(a.as_i8x16(), b.as_i8x16(), $imm3, 0b11111111_11111111)
+        };
+    }
+    constify_imm3!(imm8, call)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` based on the comparison
+/// operand specified by `imm8`, and stores the results in mask vector `k` using
+/// zeromask `k1` (elements are zeroed out when the corresponding mask bit is
+/// not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmp_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm_mask_cmp_epi8_mask(
+    k1: __mmask16,
+    a: __m128i,
+    b: __m128i,
+    imm8: _MM_CMPINT_ENUM,
+) -> __mmask16 {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vpcmpb128(a.as_i8x16(), b.as_i8x16(), $imm3, k1)
+        };
+    }
+    constify_imm3!(imm8, call)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for equality, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmpeq_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpeq_epi8_mask(a: __m512i, b: __m512i) -> __mmask64 {
+    _mm512_cmp_epi8_mask(a, b, _MM_CMPINT_EQ)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for equality, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmpeq_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_mask_cmpeq_epi8_mask(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64 {
+    _mm512_mask_cmp_epi8_mask(k1, a, b, _MM_CMPINT_EQ)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for equality, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmpeq_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_cmpeq_epi8_mask(a: __m256i, b: __m256i) -> __mmask32 {
+    _mm256_cmp_epi8_mask(a, b, _MM_CMPINT_EQ)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for equality, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmpeq_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_mask_cmpeq_epi8_mask(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32 {
+    _mm256_mask_cmp_epi8_mask(k1, a, b, _MM_CMPINT_EQ)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for equality, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmpeq_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_cmpeq_epi8_mask(a: __m128i, b: __m128i) -> __mmask16 {
+    _mm_cmp_epi8_mask(a, b, _MM_CMPINT_EQ)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for equality, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmpeq_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_mask_cmpeq_epi8_mask(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16 {
+    _mm_mask_cmp_epi8_mask(k1, a, b, _MM_CMPINT_EQ)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for
+/// greater-than-or-equal, and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmpge_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpge_epi8_mask(a: __m512i, b: __m512i) -> __mmask64 {
+    _mm512_cmp_epi8_mask(a, b, _MM_CMPINT_NLT)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for
+/// greater-than-or-equal, and stores the results in mask vector `k` using
+/// zeromask `k1` (elements are zeroed out when the corresponding mask bit is
+/// not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmpge_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_mask_cmpge_epi8_mask(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64 {
+    _mm512_mask_cmp_epi8_mask(k1, a, b, _MM_CMPINT_NLT)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for
+/// greater-than-or-equal, and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmpge_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_cmpge_epi8_mask(a: __m256i, b: __m256i) -> __mmask32 {
+    _mm256_cmp_epi8_mask(a, b, _MM_CMPINT_NLT)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for
+/// greater-than-or-equal, and stores the results in mask vector `k` using
+/// zeromask `k1` (elements are zeroed out when the corresponding mask bit is
+/// not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmpge_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_mask_cmpge_epi8_mask(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32 {
+    _mm256_mask_cmp_epi8_mask(k1, a, b, _MM_CMPINT_NLT)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for
+/// greater-than-or-equal, and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmpge_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_cmpge_epi8_mask(a: __m128i, b: __m128i) -> __mmask16 {
+    _mm_cmp_epi8_mask(a, b, _MM_CMPINT_NLT)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for
+/// greater-than-or-equal, and stores the results in mask vector `k` using
+/// zeromask `k1` (elements are zeroed out when the corresponding mask bit is
+/// not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmpge_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_mask_cmpge_epi8_mask(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16 {
+    _mm_mask_cmp_epi8_mask(k1, a, b, _MM_CMPINT_NLT)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for greater-than, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmpgt_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpgt_epi8_mask(a: __m512i, b: __m512i) -> __mmask64 {
+    _mm512_cmp_epi8_mask(a, b, _MM_CMPINT_NLE)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for greater-than, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmpgt_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_mask_cmpgt_epi8_mask(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64 {
+    _mm512_mask_cmp_epi8_mask(k1, a, b, _MM_CMPINT_NLE)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for greater-than, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmpgt_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_cmpgt_epi8_mask(a: __m256i, b: __m256i) -> __mmask32 {
+    _mm256_cmp_epi8_mask(a, b, _MM_CMPINT_NLE)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for greater-than, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmpgt_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_mask_cmpgt_epi8_mask(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32 {
+    _mm256_mask_cmp_epi8_mask(k1, a, b, _MM_CMPINT_NLE)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for greater-than, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmpgt_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_cmpgt_epi8_mask(a: __m128i, b: __m128i) -> __mmask16 {
+    _mm_cmp_epi8_mask(a, b, _MM_CMPINT_NLE)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for greater-than, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmpgt_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_mask_cmpgt_epi8_mask(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16 {
+    _mm_mask_cmp_epi8_mask(k1, a, b, _MM_CMPINT_NLE)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for less-than-or-equal,
+/// and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmple_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmple_epi8_mask(a: __m512i, b: __m512i) -> __mmask64 {
+    _mm512_cmp_epi8_mask(a, b, _MM_CMPINT_LE)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for less-than-or-equal,
+/// and stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmple_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_mask_cmple_epi8_mask(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64 {
+    _mm512_mask_cmp_epi8_mask(k1, a, b, _MM_CMPINT_LE)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for less-than-or-equal,
+/// and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmple_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_cmple_epi8_mask(a: __m256i, b: __m256i) -> __mmask32 {
+    _mm256_cmp_epi8_mask(a, b, _MM_CMPINT_LE)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for less-than-or-equal,
+/// and stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmple_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_mask_cmple_epi8_mask(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32 {
+    _mm256_mask_cmp_epi8_mask(k1, a, b, _MM_CMPINT_LE)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for less-than-or-equal,
+/// and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmple_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_cmple_epi8_mask(a: __m128i, b: __m128i) -> __mmask16 {
+    _mm_cmp_epi8_mask(a, b, _MM_CMPINT_LE)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for less-than-or-equal,
+/// and stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmple_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_mask_cmple_epi8_mask(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16 {
+    _mm_mask_cmp_epi8_mask(k1, a, b, _MM_CMPINT_LE)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for less-than, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmplt_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmplt_epi8_mask(a: __m512i, b: __m512i) -> __mmask64 {
+    _mm512_cmp_epi8_mask(a, b, _MM_CMPINT_LT)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for less-than, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmplt_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_mask_cmplt_epi8_mask(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64 {
+    _mm512_mask_cmp_epi8_mask(k1, a, b, _MM_CMPINT_LT)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for less-than, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmplt_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_cmplt_epi8_mask(a: __m256i, b: __m256i) -> __mmask32 {
+    _mm256_cmp_epi8_mask(a, b, _MM_CMPINT_LT)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for less-than, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmplt_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_mask_cmplt_epi8_mask(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32 {
+    _mm256_mask_cmp_epi8_mask(k1, a, b, _MM_CMPINT_LT)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for less-than, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmplt_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_cmplt_epi8_mask(a: __m128i, b: __m128i) -> __mmask16 {
+    _mm_cmp_epi8_mask(a, b, _MM_CMPINT_LT)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for less-than, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmplt_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_mask_cmplt_epi8_mask(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16 {
+    _mm_mask_cmp_epi8_mask(k1, a, b, _MM_CMPINT_LT)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for not-equal, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmpneq_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpneq_epi8_mask(a: __m512i, b: __m512i) -> __mmask64 {
+    _mm512_cmp_epi8_mask(a, b, _MM_CMPINT_NE)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for not-equal, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmpneq_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_mask_cmpneq_epi8_mask(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64 {
+    _mm512_mask_cmp_epi8_mask(k1, a, b, _MM_CMPINT_NE)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for not-equal, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmpneq_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_cmpneq_epi8_mask(a: __m256i, b: __m256i) -> __mmask32 {
+    _mm256_cmp_epi8_mask(a, b, _MM_CMPINT_NE)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for not-equal, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmpneq_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_mask_cmpneq_epi8_mask(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32 {
+    _mm256_mask_cmp_epi8_mask(k1, a, b, _MM_CMPINT_NE)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for not-equal, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmpneq_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_cmpneq_epi8_mask(a: __m128i, b: __m128i) -> __mmask16 {
+    _mm_cmp_epi8_mask(a, b, _MM_CMPINT_NE)
+}
+
+/// Compares packed signed 8-bit integers in `a` and `b` for not-equal, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmpneq_epi8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_mask_cmpneq_epi8_mask(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16 {
+    _mm_mask_cmp_epi8_mask(k1, a, b, _MM_CMPINT_NE)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` based on the
+/// comparison operand specified by `imm8`, and stores the results in mask
+/// vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmp_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_cmp_epi16_mask(a: __m512i, b: __m512i, imm8: _MM_CMPINT_ENUM) -> __mmask32 {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vpcmpw(
+                a.as_i16x32(),
+                b.as_i16x32(),
+                $imm3,
+                0b11111111_11111111_11111111_11111111,
+            )
+        };
+    }
+    constify_imm3!(imm8, call)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` based on the
+/// comparison operand specified by `imm8`, and stores the results in mask
+/// vector `k` using zeromask `k1` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmp_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm512_mask_cmp_epi16_mask(
+    k1: __mmask32,
+    a: __m512i,
+    b: __m512i,
+    imm8: _MM_CMPINT_ENUM,
+) -> __mmask32 {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vpcmpw(a.as_i16x32(), b.as_i16x32(), $imm3, k1)
+        };
+    }
+    constify_imm3!(imm8, call)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` based on the
+/// comparison operand specified by `imm8`, and stores the results in mask
+/// vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmp_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm256_cmp_epi16_mask(a: __m256i, b: __m256i, imm8: _MM_CMPINT_ENUM) -> __mmask16 {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vpcmpw256(a.as_i16x16(), b.as_i16x16(), $imm3, 0b11111111_11111111)
+        };
+    }
+    constify_imm3!(imm8, call)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` based on the
+/// comparison operand specified by `imm8`, and stores the results in mask
+/// vector `k` using zeromask `k1` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmp_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm256_mask_cmp_epi16_mask(
+    k1: __mmask16,
+    a: __m256i,
+    b: __m256i,
+    imm8: _MM_CMPINT_ENUM,
+) -> __mmask16 {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vpcmpw256(a.as_i16x16(), b.as_i16x16(), $imm3, k1)
+        };
+    }
+    constify_imm3!(imm8, call)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` based on the
+/// comparison operand specified by `imm8`, and stores the results in mask
+/// vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmp_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_cmp_epi16_mask(a: __m128i, b: __m128i, imm8: _MM_CMPINT_ENUM) -> __mmask8 {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vpcmpw128(a.as_i16x8(), b.as_i16x8(), $imm3, 0b11111111)
+        };
+    }
+    constify_imm3!(imm8, call)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` based on the
+/// comparison operand specified by `imm8`, and stores the results in mask
+/// vector `k` using zeromask `k1` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmp_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm_mask_cmp_epi16_mask(
+    k1: __mmask8,
+    a: __m128i,
+    b: __m128i,
+    imm8: _MM_CMPINT_ENUM,
+) -> __mmask8 {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vpcmpw128(a.as_i16x8(), b.as_i16x8(), $imm3, k1)
+        };
+    }
+    constify_imm3!(imm8, call)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for equality, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmpeq_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpeq_epi16_mask(a: __m512i, b: __m512i) -> __mmask32 {
+    _mm512_cmp_epi16_mask(a, b, _MM_CMPINT_EQ)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for equality, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmpeq_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_mask_cmpeq_epi16_mask(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32 {
+    _mm512_mask_cmp_epi16_mask(k1, a, b, _MM_CMPINT_EQ)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for equality, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmpeq_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_cmpeq_epi16_mask(a: __m256i, b: __m256i) -> __mmask16 {
+    _mm256_cmp_epi16_mask(a, b, _MM_CMPINT_EQ)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for equality, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmpeq_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_mask_cmpeq_epi16_mask(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16 {
+    _mm256_mask_cmp_epi16_mask(k1, a, b, _MM_CMPINT_EQ)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for equality, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmpeq_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_cmpeq_epi16_mask(a: __m128i, b: __m128i) -> __mmask8 {
+    _mm_cmp_epi16_mask(a, b, _MM_CMPINT_EQ)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for equality, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmpeq_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_mask_cmpeq_epi16_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8 {
+    _mm_mask_cmp_epi16_mask(k1, a, b, _MM_CMPINT_EQ)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for
+/// greater-than-or-equal, and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmpge_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpge_epi16_mask(a: __m512i, b: __m512i) -> __mmask32 {
+    _mm512_cmp_epi16_mask(a, b, _MM_CMPINT_NLT)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for
+/// greater-than-or-equal, and stores the results in mask vector `k` using
+/// zeromask `k1` (elements are zeroed out when the corresponding mask bit is
+/// not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmpge_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_mask_cmpge_epi16_mask(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32 {
+    _mm512_mask_cmp_epi16_mask(k1, a, b, _MM_CMPINT_NLT)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for
+/// greater-than-or-equal, and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmpge_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_cmpge_epi16_mask(a: __m256i, b: __m256i) -> __mmask16 {
+    _mm256_cmp_epi16_mask(a, b, _MM_CMPINT_NLT)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for
+/// greater-than-or-equal, and stores the results in mask vector `k` using
+/// zeromask `k1` (elements are zeroed out when the corresponding mask bit is
+/// not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmpge_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_mask_cmpge_epi16_mask(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16 {
+    _mm256_mask_cmp_epi16_mask(k1, a, b, _MM_CMPINT_NLT)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for
+/// greater-than-or-equal, and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmpge_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_cmpge_epi16_mask(a: __m128i, b: __m128i) -> __mmask8 {
+    _mm_cmp_epi16_mask(a, b, _MM_CMPINT_NLT)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for
+/// greater-than-or-equal, and stores the results in mask vector `k` using
+/// zeromask `k1` (elements are zeroed out when the corresponding mask bit is
+/// not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmpge_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_mask_cmpge_epi16_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8 {
+    _mm_mask_cmp_epi16_mask(k1, a, b, _MM_CMPINT_NLT)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for greater-than, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmpgt_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpgt_epi16_mask(a: __m512i, b: __m512i) -> __mmask32 {
+    _mm512_cmp_epi16_mask(a, b, _MM_CMPINT_NLE)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for greater-than, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmpgt_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_mask_cmpgt_epi16_mask(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32 {
+    _mm512_mask_cmp_epi16_mask(k1, a, b, _MM_CMPINT_NLE)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for greater-than, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmpgt_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_cmpgt_epi16_mask(a: __m256i, b: __m256i) -> __mmask16 {
+    _mm256_cmp_epi16_mask(a, b, _MM_CMPINT_NLE)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for greater-than, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmpgt_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_mask_cmpgt_epi16_mask(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16 {
+    _mm256_mask_cmp_epi16_mask(k1, a, b, _MM_CMPINT_NLE)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for greater-than, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmpgt_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_cmpgt_epi16_mask(a: __m128i, b: __m128i) -> __mmask8 {
+    _mm_cmp_epi16_mask(a, b, _MM_CMPINT_NLE)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for greater-than, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmpgt_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_mask_cmpgt_epi16_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8 {
+    _mm_mask_cmp_epi16_mask(k1, a, b, _MM_CMPINT_NLE)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for
+/// less-than-or-equal, and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmple_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmple_epi16_mask(a: __m512i, b: __m512i) -> __mmask32 {
+    _mm512_cmp_epi16_mask(a, b, _MM_CMPINT_LE)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for
+/// less-than-or-equal, and stores the results in mask vector `k` using zeromask
+/// `k1` (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmple_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_mask_cmple_epi16_mask(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32 {
+    _mm512_mask_cmp_epi16_mask(k1, a, b, _MM_CMPINT_LE)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for
+/// less-than-or-equal, and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmple_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_cmple_epi16_mask(a: __m256i, b: __m256i) -> __mmask16 {
+    _mm256_cmp_epi16_mask(a, b, _MM_CMPINT_LE)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for
+/// less-than-or-equal, and stores the results in mask vector `k` using zeromask
+/// `k1` (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmple_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_mask_cmple_epi16_mask(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16 {
+    _mm256_mask_cmp_epi16_mask(k1, a, b, _MM_CMPINT_LE)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for
+/// less-than-or-equal, and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmple_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_cmple_epi16_mask(a: __m128i, b: __m128i) -> __mmask8 {
+    _mm_cmp_epi16_mask(a, b, _MM_CMPINT_LE)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for
+/// less-than-or-equal, and stores the results in mask vector `k` using zeromask
+/// `k1` (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmple_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_mask_cmple_epi16_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8 {
+    _mm_mask_cmp_epi16_mask(k1, a, b, _MM_CMPINT_LE)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for less-than, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmplt_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmplt_epi16_mask(a: __m512i, b: __m512i) -> __mmask32 {
+    _mm512_cmp_epi16_mask(a, b, _MM_CMPINT_LT)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for less-than, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmplt_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_mask_cmplt_epi16_mask(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32 {
+    _mm512_mask_cmp_epi16_mask(k1, a, b, _MM_CMPINT_LT)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for less-than, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmplt_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_cmplt_epi16_mask(a: __m256i, b: __m256i) -> __mmask16 {
+    _mm256_cmp_epi16_mask(a, b, _MM_CMPINT_LT)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for less-than, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmplt_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_mask_cmplt_epi16_mask(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16 {
+    _mm256_mask_cmp_epi16_mask(k1, a, b, _MM_CMPINT_LT)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for less-than, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmplt_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_cmplt_epi16_mask(a: __m128i, b: __m128i) -> __mmask8 {
+    _mm_cmp_epi16_mask(a, b, _MM_CMPINT_LT)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for less-than, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmplt_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_mask_cmplt_epi16_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8 {
+    _mm_mask_cmp_epi16_mask(k1, a, b, _MM_CMPINT_LT)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for not-equal, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmpneq_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpneq_epi16_mask(a: __m512i, b: __m512i) -> __mmask32 {
+    _mm512_cmp_epi16_mask(a, b, _MM_CMPINT_NE)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for not-equal, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmpneq_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_mask_cmpneq_epi16_mask(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32 {
+    _mm512_mask_cmp_epi16_mask(k1, a, b, _MM_CMPINT_NE)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for not-equal, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmpneq_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_cmpneq_epi16_mask(a: __m256i, b: __m256i) -> __mmask16 {
+    _mm256_cmp_epi16_mask(a, b, _MM_CMPINT_NE)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for not-equal, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmpneq_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_mask_cmpneq_epi16_mask(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16 {
+    _mm256_mask_cmp_epi16_mask(k1, a, b, _MM_CMPINT_NE)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for not-equal, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmpneq_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_cmpneq_epi16_mask(a: __m128i, b: __m128i) -> __mmask8 {
+    _mm_cmp_epi16_mask(a, b, _MM_CMPINT_NE)
+}
+
+/// Compares packed signed 16-bit integers in `a` and `b` for not-equal, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmpneq_epi16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_mask_cmpneq_epi16_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8 {
+    _mm_mask_cmp_epi16_mask(k1, a, b, _MM_CMPINT_NE)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` based on the
+/// comparison operand specified by `imm8`, and stores the results in mask
+/// vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmp_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_cmp_epu8_mask(a: __m512i, b: __m512i, imm8: _MM_CMPINT_ENUM) -> __mmask64 {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vpucmpb(
+                a.as_u8x64(),
+                b.as_u8x64(),
+                $imm3,
+                0b11111111_11111111_11111111_11111111_11111111_11111111_11111111_11111111,
+            )
+        };
+    }
+    constify_imm3!(imm8, call)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` based on the
+/// comparison operand specified by `imm8`, and stores the results in mask
+/// vector `k` using zeromask `k1` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmp_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm512_mask_cmp_epu8_mask(
+    k1: __mmask64,
+    a: __m512i,
+    b: __m512i,
+    imm8: _MM_CMPINT_ENUM,
+) -> __mmask64 {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vpucmpb(a.as_u8x64(), b.as_u8x64(), $imm3, k1)
+        };
+    }
+    constify_imm3!(imm8, call)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` based on the
+/// comparison operand specified by `imm8`, and stores the results in mask
+/// vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmp_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm256_cmp_epu8_mask(a: __m256i, b: __m256i, imm8: _MM_CMPINT_ENUM) -> __mmask32 {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vpucmpb256(
+                a.as_u8x32(),
+                b.as_u8x32(),
+                $imm3,
+                0b11111111_11111111_11111111_11111111,
+            )
+        };
+    }
+    constify_imm3!(imm8, call)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` based on the
+/// comparison operand specified by `imm8`, and stores the results in mask
+/// vector `k` using zeromask `k1` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmp_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm256_mask_cmp_epu8_mask(
+    k1: __mmask32,
+    a: __m256i,
+    b: __m256i,
+    imm8: _MM_CMPINT_ENUM,
+) -> __mmask32 {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vpucmpb256(a.as_u8x32(), b.as_u8x32(), $imm3, k1)
+        };
+    }
+    constify_imm3!(imm8, call)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` based on the
+/// comparison operand specified by `imm8`, and stores the results in mask
+/// vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmp_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_cmp_epu8_mask(a: __m128i, b: __m128i, imm8: _MM_CMPINT_ENUM) -> __mmask16 {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vpucmpb128(a.as_u8x16(), b.as_u8x16(), $imm3, 0b11111111_11111111)
+        };
+    }
+    constify_imm3!(imm8, call)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` based on the
+/// comparison operand specified by `imm8`, and stores the results in mask
+/// vector `k` using zeromask `k1` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmp_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm_mask_cmp_epu8_mask(
+    k1: __mmask16,
+    a: __m128i,
+    b: __m128i,
+    imm8: _MM_CMPINT_ENUM,
+) -> __mmask16 {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vpucmpb128(a.as_u8x16(), b.as_u8x16(), $imm3, k1)
+        };
+    }
+    constify_imm3!(imm8, call)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for equality, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmpeq_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpeq_epu8_mask(a: __m512i, b: __m512i) -> __mmask64 {
+    _mm512_cmp_epu8_mask(a, b, _MM_CMPINT_EQ)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for equality, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmpeq_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_mask_cmpeq_epu8_mask(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64 {
+    _mm512_mask_cmp_epu8_mask(k1, a, b, _MM_CMPINT_EQ)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for equality, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmpeq_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_cmpeq_epu8_mask(a: __m256i, b: __m256i) -> __mmask32 {
+    _mm256_cmp_epu8_mask(a, b, _MM_CMPINT_EQ)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for equality, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmpeq_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_mask_cmpeq_epu8_mask(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32 {
+    _mm256_mask_cmp_epu8_mask(k1, a, b, _MM_CMPINT_EQ)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for equality, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmpeq_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_cmpeq_epu8_mask(a: __m128i, b: __m128i) -> __mmask16 {
+    _mm_cmp_epu8_mask(a, b, _MM_CMPINT_EQ)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for equality, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmpeq_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_mask_cmpeq_epu8_mask(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16 {
+    _mm_mask_cmp_epu8_mask(k1, a, b, _MM_CMPINT_EQ)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for
+/// greater-than-or-equal, and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmpge_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpge_epu8_mask(a: __m512i, b: __m512i) -> __mmask64 {
+    _mm512_cmp_epu8_mask(a, b, _MM_CMPINT_NLT)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for
+/// greater-than-or-equal, and stores the results in mask vector `k` using
+/// zeromask `k1` (elements are zeroed out when the corresponding mask bit is
+/// not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmpge_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_mask_cmpge_epu8_mask(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64 {
+    _mm512_mask_cmp_epu8_mask(k1, a, b, _MM_CMPINT_NLT)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for
+/// greater-than-or-equal, and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmpge_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_cmpge_epu8_mask(a: __m256i, b: __m256i) -> __mmask32 {
+    _mm256_cmp_epu8_mask(a, b, _MM_CMPINT_NLT)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for
+/// greater-than-or-equal, and stores the results in mask vector `k` using
+/// zeromask `k1` (elements are zeroed out when the corresponding mask bit is
+/// not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmpge_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_mask_cmpge_epu8_mask(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32 {
+    _mm256_mask_cmp_epu8_mask(k1, a, b, _MM_CMPINT_NLT)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for
+/// greater-than-or-equal, and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmpge_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_cmpge_epu8_mask(a: __m128i, b: __m128i) -> __mmask16 {
+    _mm_cmp_epu8_mask(a, b, _MM_CMPINT_NLT)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for
+/// greater-than-or-equal, and stores the results in mask vector `k` using
+/// zeromask `k1` (elements are zeroed out when the corresponding mask bit is
+/// not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmpge_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_mask_cmpge_epu8_mask(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16 {
+    _mm_mask_cmp_epu8_mask(k1, a, b, _MM_CMPINT_NLT)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for greater-than, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmpgt_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpgt_epu8_mask(a: __m512i, b: __m512i) -> __mmask64 {
+    _mm512_cmp_epu8_mask(a, b, _MM_CMPINT_NLE)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for greater-than, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmpgt_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_mask_cmpgt_epu8_mask(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64 {
+    _mm512_mask_cmp_epu8_mask(k1, a, b, _MM_CMPINT_NLE)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for greater-than, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmpgt_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_cmpgt_epu8_mask(a: __m256i, b: __m256i) -> __mmask32 {
+    _mm256_cmp_epu8_mask(a, b, _MM_CMPINT_NLE)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for greater-than, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmpgt_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_mask_cmpgt_epu8_mask(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32 {
+    _mm256_mask_cmp_epu8_mask(k1, a, b, _MM_CMPINT_NLE)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for greater-than, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmpgt_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_cmpgt_epu8_mask(a: __m128i, b: __m128i) -> __mmask16 {
+    _mm_cmp_epu8_mask(a, b, _MM_CMPINT_NLE)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for greater-than, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmpgt_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_mask_cmpgt_epu8_mask(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16 {
+    _mm_mask_cmp_epu8_mask(k1, a, b, _MM_CMPINT_NLE)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for
+/// less-than-or-equal, and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmple_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmple_epu8_mask(a: __m512i, b: __m512i) -> __mmask64 {
+    _mm512_cmp_epu8_mask(a, b, _MM_CMPINT_LE)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for
+/// less-than-or-equal, and stores the results in mask vector `k` using zeromask
+/// `k1` (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmple_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_mask_cmple_epu8_mask(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64 {
+    _mm512_mask_cmp_epu8_mask(k1, a, b, _MM_CMPINT_LE)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for
+/// less-than-or-equal, and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmple_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_cmple_epu8_mask(a: __m256i, b: __m256i) -> __mmask32 {
+    _mm256_cmp_epu8_mask(a, b, _MM_CMPINT_LE)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for
+/// less-than-or-equal, and stores the results in mask vector `k` using zeromask
+/// `k1` (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmple_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_mask_cmple_epu8_mask(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32 {
+    _mm256_mask_cmp_epu8_mask(k1, a, b, _MM_CMPINT_LE)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for
+/// less-than-or-equal, and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmple_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_cmple_epu8_mask(a: __m128i, b: __m128i) -> __mmask16 {
+    _mm_cmp_epu8_mask(a, b, _MM_CMPINT_LE)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for
+/// less-than-or-equal, and stores the results in mask vector `k` using zeromask
+/// `k1` (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmple_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_mask_cmple_epu8_mask(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16 {
+    _mm_mask_cmp_epu8_mask(k1, a, b, _MM_CMPINT_LE)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for less-than, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmplt_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmplt_epu8_mask(a: __m512i, b: __m512i) -> __mmask64 {
+    _mm512_cmp_epu8_mask(a, b, _MM_CMPINT_LT)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for less-than, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmplt_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_mask_cmplt_epu8_mask(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64 {
+    _mm512_mask_cmp_epu8_mask(k1, a, b, _MM_CMPINT_LT)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for less-than, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmplt_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_cmplt_epu8_mask(a: __m256i, b: __m256i) -> __mmask32 {
+    _mm256_cmp_epu8_mask(a, b, _MM_CMPINT_LT)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for less-than, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmplt_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_mask_cmplt_epu8_mask(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32 {
+    _mm256_mask_cmp_epu8_mask(k1, a, b, _MM_CMPINT_LT)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for less-than, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmplt_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_cmplt_epu8_mask(a: __m128i, b: __m128i) -> __mmask16 {
+    _mm_cmp_epu8_mask(a, b, _MM_CMPINT_LT)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for less-than, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmplt_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_mask_cmplt_epu8_mask(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16 {
+    _mm_mask_cmp_epu8_mask(k1, a, b, _MM_CMPINT_LT)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for not-equal, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmpneq_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpneq_epu8_mask(a: __m512i, b: __m512i) -> __mmask64 {
+    _mm512_cmp_epu8_mask(a, b, _MM_CMPINT_NE)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for not-equal, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmpneq_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_mask_cmpneq_epu8_mask(k1: __mmask64, a: __m512i, b: __m512i) -> __mmask64 {
+    _mm512_mask_cmp_epu8_mask(k1, a, b, _MM_CMPINT_NE)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for not-equal, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmpneq_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_cmpneq_epu8_mask(a: __m256i, b: __m256i) -> __mmask32 {
+    _mm256_cmp_epu8_mask(a, b, _MM_CMPINT_NE)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for not-equal, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmpneq_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_mask_cmpneq_epu8_mask(k1: __mmask32, a: __m256i, b: __m256i) -> __mmask32 {
+    _mm256_mask_cmp_epu8_mask(k1, a, b, _MM_CMPINT_NE)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for not-equal, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmpneq_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_cmpneq_epu8_mask(a: __m128i, b: __m128i) -> __mmask16 {
+    _mm_cmp_epu8_mask(a, b, _MM_CMPINT_NE)
+}
+
+/// Compares packed unsigned 8-bit integers in `a` and `b` for not-equal, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmpneq_epu8_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_mask_cmpneq_epu8_mask(k1: __mmask16, a: __m128i, b: __m128i) -> __mmask16 {
+    _mm_mask_cmp_epu8_mask(k1, a, b, _MM_CMPINT_NE)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` based on the
+/// comparison operand specified by `imm8`, and stores the results in mask
+/// vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmp_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_cmp_epu16_mask(a: __m512i, b: __m512i, imm8: _MM_CMPINT_ENUM) -> __mmask32 {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vpucmpw(
+                a.as_u16x32(),
+                b.as_u16x32(),
+                $imm3,
+                0b11111111_11111111_11111111_11111111,
+            )
+        };
+    }
+    constify_imm3!(imm8, call)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` based on the
+/// comparison operand specified by `imm8`, and stores the results in mask
+/// vector `k` using zeromask `k1` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmp_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm512_mask_cmp_epu16_mask(
+    k1: __mmask32,
+    a: __m512i,
+    b: __m512i,
+    imm8: _MM_CMPINT_ENUM,
+) -> __mmask32 {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vpucmpw(a.as_u16x32(), b.as_u16x32(), $imm3, k1)
+        };
+    }
+    constify_imm3!(imm8, call)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` based on the
+/// comparison operand specified by `imm8`, and stores the results in mask
+/// vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmp_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm256_cmp_epu16_mask(a: __m256i, b: __m256i, imm8: _MM_CMPINT_ENUM) -> __mmask16 {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vpucmpw256(a.as_u16x16(), b.as_u16x16(), $imm3, 0b11111111_11111111)
+        };
+    }
+    constify_imm3!(imm8, call)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` based on the
+/// comparison operand specified by `imm8`, and stores the results in mask
+/// vector `k` using zeromask `k1` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmp_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm256_mask_cmp_epu16_mask(
+    k1: __mmask16,
+    a: __m256i,
+    b: __m256i,
+    imm8: _MM_CMPINT_ENUM,
+) -> __mmask16 {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vpucmpw256(a.as_u16x16(), b.as_u16x16(), $imm3, k1)
+        };
+    }
+    constify_imm3!(imm8, call)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` based on the
+/// comparison operand specified by `imm8`, and stores the results in mask
+/// vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmp_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_cmp_epu16_mask(a: __m128i, b: __m128i, imm8: _MM_CMPINT_ENUM) -> __mmask8 {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vpucmpw128(a.as_u16x8(), b.as_u16x8(), $imm3, 0b11111111)
+        };
+    }
+    constify_imm3!(imm8, call)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` based on the
+/// comparison operand specified by `imm8`, and stores the results in mask
+/// vector `k` using zeromask `k1` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmp_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm_mask_cmp_epu16_mask(
+    k1: __mmask8,
+    a: __m128i,
+    b: __m128i,
+    imm8: _MM_CMPINT_ENUM,
+) -> __mmask8 {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vpucmpw128(a.as_u16x8(), b.as_u16x8(), $imm3, k1)
+        };
+    }
+    constify_imm3!(imm8, call)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for equality, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmpeq_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpeq_epu16_mask(a: __m512i, b: __m512i) -> __mmask32 {
+    _mm512_cmp_epu16_mask(a, b, _MM_CMPINT_EQ)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for equality, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmpeq_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_mask_cmpeq_epu16_mask(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32 {
+    _mm512_mask_cmp_epu16_mask(k1, a, b, _MM_CMPINT_EQ)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for equality, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmpeq_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_cmpeq_epu16_mask(a: __m256i, b: __m256i) -> __mmask16 {
+    _mm256_cmp_epu16_mask(a, b, _MM_CMPINT_EQ)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for equality, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmpeq_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_mask_cmpeq_epu16_mask(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16 {
+    _mm256_mask_cmp_epu16_mask(k1, a, b, _MM_CMPINT_EQ)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for equality, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmpeq_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_cmpeq_epu16_mask(a: __m128i, b: __m128i) -> __mmask8 {
+    _mm_cmp_epu16_mask(a, b, _MM_CMPINT_EQ)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for equality, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmpeq_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_mask_cmpeq_epu16_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8 {
+    _mm_mask_cmp_epu16_mask(k1, a, b, _MM_CMPINT_EQ)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for
+/// greater-than-or-equal, and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmpge_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpge_epu16_mask(a: __m512i, b: __m512i) -> __mmask32 {
+    _mm512_cmp_epu16_mask(a, b, _MM_CMPINT_NLT)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for
+/// greater-than-or-equal, and stores the results in mask vector `k` using
+/// zeromask `k1` (elements are zeroed out when the corresponding mask bit is
+/// not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmpge_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_mask_cmpge_epu16_mask(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32 {
+    _mm512_mask_cmp_epu16_mask(k1, a, b, _MM_CMPINT_NLT)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for
+/// greater-than-or-equal, and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmpge_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_cmpge_epu16_mask(a: __m256i, b: __m256i) -> __mmask16 {
+    _mm256_cmp_epu16_mask(a, b, _MM_CMPINT_NLT)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for
+/// greater-than-or-equal, and stores the results in mask vector `k` using
+/// zeromask `k1` (elements are zeroed out when the corresponding mask bit is
+/// not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmpge_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_mask_cmpge_epu16_mask(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16 {
+    _mm256_mask_cmp_epu16_mask(k1, a, b, _MM_CMPINT_NLT)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for
+/// greater-than-or-equal, and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmpge_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_cmpge_epu16_mask(a: __m128i, b: __m128i) -> __mmask8 {
+    _mm_cmp_epu16_mask(a, b, _MM_CMPINT_NLT)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for
+/// greater-than-or-equal, and stores the results in mask vector `k` using
+/// zeromask `k1` (elements are zeroed out when the corresponding mask bit is
+/// not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmpge_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_mask_cmpge_epu16_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8 {
+    _mm_mask_cmp_epu16_mask(k1, a, b, _MM_CMPINT_NLT)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for greater-than,
+/// and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmpgt_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpgt_epu16_mask(a: __m512i, b: __m512i) -> __mmask32 {
+    _mm512_cmp_epu16_mask(a, b, _MM_CMPINT_NLE)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for greater-than,
+/// and stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmpgt_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_mask_cmpgt_epu16_mask(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32 {
+    _mm512_mask_cmp_epu16_mask(k1, a, b, _MM_CMPINT_NLE)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for greater-than,
+/// and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmpgt_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_cmpgt_epu16_mask(a: __m256i, b: __m256i) -> __mmask16 {
+    _mm256_cmp_epu16_mask(a, b, _MM_CMPINT_NLE)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for greater-than,
+/// and stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmpgt_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_mask_cmpgt_epu16_mask(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16 {
+    _mm256_mask_cmp_epu16_mask(k1, a, b, _MM_CMPINT_NLE)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for greater-than,
+/// and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmpgt_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_cmpgt_epu16_mask(a: __m128i, b: __m128i) -> __mmask8 {
+    _mm_cmp_epu16_mask(a, b, _MM_CMPINT_NLE)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for greater-than,
+/// and stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmpgt_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_mask_cmpgt_epu16_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8 {
+    _mm_mask_cmp_epu16_mask(k1, a, b, _MM_CMPINT_NLE)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for
+/// less-than-or-equal, and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmple_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmple_epu16_mask(a: __m512i, b: __m512i) -> __mmask32 {
+    _mm512_cmp_epu16_mask(a, b, _MM_CMPINT_LE)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for
+/// less-than-or-equal, and stores the results in mask vector `k` using zeromask
+/// `k1` (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmple_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_mask_cmple_epu16_mask(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32 {
+    _mm512_mask_cmp_epu16_mask(k1, a, b, _MM_CMPINT_LE)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for
+/// less-than-or-equal, and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmple_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_cmple_epu16_mask(a: __m256i, b: __m256i) -> __mmask16 {
+    _mm256_cmp_epu16_mask(a, b, _MM_CMPINT_LE)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for
+/// less-than-or-equal, and stores the results in mask vector `k` using zeromask
+/// `k1` (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmple_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_mask_cmple_epu16_mask(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16 {
+    _mm256_mask_cmp_epu16_mask(k1, a, b, _MM_CMPINT_LE)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for
+/// less-than-or-equal, and stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmple_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_cmple_epu16_mask(a: __m128i, b: __m128i) -> __mmask8 {
+    _mm_cmp_epu16_mask(a, b, _MM_CMPINT_LE)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for
+/// less-than-or-equal, and stores the results in mask vector `k` using zeromask
+/// `k1` (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmple_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_mask_cmple_epu16_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8 {
+    _mm_mask_cmp_epu16_mask(k1, a, b, _MM_CMPINT_LE)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for less-than, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmplt_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmplt_epu16_mask(a: __m512i, b: __m512i) -> __mmask32 {
+    _mm512_cmp_epu16_mask(a, b, _MM_CMPINT_LT)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for less-than, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmplt_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_mask_cmplt_epu16_mask(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32 {
+    _mm512_mask_cmp_epu16_mask(k1, a, b, _MM_CMPINT_LT)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for less-than, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmplt_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_cmplt_epu16_mask(a: __m256i, b: __m256i) -> __mmask16 {
+    _mm256_cmp_epu16_mask(a, b, _MM_CMPINT_LT)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for less-than, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmplt_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_mask_cmplt_epu16_mask(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16 {
+    _mm256_mask_cmp_epu16_mask(k1, a, b, _MM_CMPINT_LT)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for less-than, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmplt_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_cmplt_epu16_mask(a: __m128i, b: __m128i) -> __mmask8 {
+    _mm_cmp_epu16_mask(a, b, _MM_CMPINT_LT)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for less-than, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmplt_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_mask_cmplt_epu16_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8 {
+    _mm_mask_cmp_epu16_mask(k1, a, b, _MM_CMPINT_LT)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for not-equal, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmpneq_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpneq_epu16_mask(a: __m512i, b: __m512i) -> __mmask32 {
+    _mm512_cmp_epu16_mask(a, b, _MM_CMPINT_NE)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for not-equal, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmpneq_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_mask_cmpneq_epu16_mask(k1: __mmask32, a: __m512i, b: __m512i) -> __mmask32 {
+    _mm512_mask_cmp_epu16_mask(k1, a, b, _MM_CMPINT_NE)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for not-equal, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmpneq_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_cmpneq_epu16_mask(a: __m256i, b: __m256i) -> __mmask16 {
+    _mm256_cmp_epu16_mask(a, b, _MM_CMPINT_NE)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for not-equal, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmpneq_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm256_mask_cmpneq_epu16_mask(k1: __mmask16, a: __m256i, b: __m256i) -> __mmask16 {
+    _mm256_mask_cmp_epu16_mask(k1, a, b, _MM_CMPINT_NE)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for not-equal, and
+/// stores the results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmpneq_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_cmpneq_epu16_mask(a: __m128i, b: __m128i) -> __mmask8 {
+    _mm_cmp_epu16_mask(a, b, _MM_CMPINT_NE)
+}
+
+/// Compares packed unsigned 16-bit integers in `a` and `b` for not-equal, and
+/// stores the results in mask vector `k` using zeromask `k1` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmpneq_epu16_mask)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm_mask_cmpneq_epu16_mask(k1: __mmask8, a: __m128i, b: __m128i) -> __mmask8 {
+    _mm_mask_cmp_epu16_mask(k1, a, b, _MM_CMPINT_NE)
+}
+
+/// Converts packed 16-bit integers in `a` to packed 8-bit integers with
+/// truncation, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtepi16_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmovwb))]
+pub unsafe fn _mm512_cvtepi16_epi8(a: __m512i) -> __m256i {
+    transmute::<i8x32, _>(simd_cast(a.as_i16x32()))
+}
+
+/// Converts packed 16-bit integers in `a` to packed 8-bit integers with
+/// truncation, and stores the results in `dst` using writemask `k` (elements
+/// are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtepi16_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmovwb))]
+pub unsafe fn _mm512_mask_cvtepi16_epi8(src: __m256i, k: __mmask32, a: __m512i) -> __m256i {
+    let convert: i8x32 = simd_cast(a.as_i16x32());
+    transmute(simd_select_bitmask(k, convert, src.as_i8x32()))
+}
+
+/// Converts packed 16-bit integers in `a` to packed 8-bit integers with
+/// truncation, and stores the results in `dst` using zeromask `k` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtepi16_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmovwb))]
+pub unsafe fn _mm512_maskz_cvtepi16_epi8(k: __mmask32, a: __m512i) -> __m256i {
+    let convert: i8x32 = simd_cast(a.as_i16x32());
+    let zero = _mm256_setzero_si256().as_i8x32();
+    transmute(simd_select_bitmask(k, convert, zero))
+}
+
+/// Loads packed 8-bit integers from memory into `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_loadu_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vmovdqu8))]
+pub unsafe fn _mm512_mask_loadu_epi8(src: __m512i, k: __mmask64, mem_addr: *const u8) -> __m512i {
+    transmute(loaddqu8(mem_addr, src.as_i8x64(), k))
+}
+
+/// Loads packed 8-bit integers from memory into `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_loadu_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vmovdqu8))]
+pub unsafe fn _mm512_maskz_loadu_epi8(k: __mmask64, mem_addr: *const u8) -> __m512i {
+    transmute(loaddqu8(mem_addr, _mm512_setzero_si512().as_i8x64(), k))
+}
+
+/// Stores packed 8-bit integers from `a` into memory using writemask `k`
+/// (elements are not written when the corresponding mask bit is not set).
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_storeu_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vmovdqu8))]
+pub unsafe fn _mm512_mask_storeu_epi8(mem_addr: *mut u8, k: __mmask64, a: __m512i) {
+    storedqu8(mem_addr, a.as_i8x64(), k)
+}
+
+/// Loads packed 8-bit integers from memory into `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_loadu_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu8))]
+pub unsafe fn _mm256_mask_loadu_epi8(src: __m256i, k: __mmask32, mem_addr: *const u8) -> __m256i {
+    transmute(loaddqu8_256(mem_addr, src.as_i8x32(), k))
+}
+
+/// Loads packed 8-bit integers from memory into `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_loadu_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu8))]
+pub unsafe fn _mm256_maskz_loadu_epi8(k: __mmask32, mem_addr: *const u8) -> __m256i {
+    transmute(loaddqu8_256(mem_addr, _mm256_setzero_si256().as_i8x32(), k))
+}
+
+/// Stores packed 8-bit integers from `a` into memory using writemask `k`
+/// (elements are not written when the corresponding mask bit is not set).
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_storeu_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu8))]
+pub unsafe fn _mm256_mask_storeu_epi8(mem_addr: *mut u8, k: __mmask32, a: __m256i) {
+    storedqu8_256(mem_addr, a.as_i8x32(), k)
+}
+
+/// Loads packed 8-bit integers from memory into `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_loadu_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu8))]
+pub unsafe fn _mm_mask_loadu_epi8(src: __m128i, k: __mmask16, mem_addr: *const u8) -> __m128i {
+    transmute(loaddqu8_128(mem_addr, src.as_i8x16(), k))
+}
+
+/// Loads packed 8-bit integers from memory into `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_loadu_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu8))]
+pub unsafe fn _mm_maskz_loadu_epi8(k: __mmask16, mem_addr: *const u8) -> __m128i {
+    transmute(loaddqu8_128(mem_addr, _mm_setzero_si128().as_i8x16(), k))
+}
+
+/// Stores packed 8-bit integers from `a` into memory using writemask `k`
+/// (elements are not written when the corresponding mask bit is not set).
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_storeu_epi8)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu8))]
+pub unsafe fn _mm_mask_storeu_epi8(mem_addr: *mut u8, k: __mmask16, a: __m128i) {
+    storedqu8_128(mem_addr, a.as_i8x16(), k)
+}
+
+/// Loads packed 16-bit integers from memory into `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_loadu_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vmovdqu16))]
+pub unsafe fn _mm512_mask_loadu_epi16(src: __m512i, k: __mmask32, mem_addr: *const u8) -> __m512i {
+    transmute(loaddqu16(mem_addr, src.as_i16x32(), k))
+}
+
+/// Loads packed 16-bit integers from memory into `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_loadu_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vmovdqu16))]
+pub unsafe fn _mm512_maskz_loadu_epi16(k: __mmask32, mem_addr: *const u8) -> __m512i {
+    transmute(loaddqu16(mem_addr, _mm512_setzero_si512().as_i16x32(), k))
+}
+
+/// Stores packed 16-bit integers from `a` into memory using writemask `k`
+/// (elements are not written when the corresponding mask bit is not set).
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_storeu_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vmovdqu16))]
+pub unsafe fn _mm512_mask_storeu_epi16(mem_addr: *mut u8, k: __mmask32, a: __m512i) {
+    storedqu16(mem_addr, a.as_i16x32(), k)
+}
+
+/// Loads packed 16-bit integers from memory into `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_loadu_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu16))]
+pub unsafe fn _mm256_mask_loadu_epi16(src: __m256i, k: __mmask16, mem_addr: *const u8) -> __m256i {
+    transmute(loaddqu16_256(mem_addr, src.as_i16x16(), k))
+}
+
+/// Loads packed 16-bit integers from memory into `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_loadu_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu16))]
+pub unsafe fn _mm256_maskz_loadu_epi16(k: __mmask16, mem_addr: *const u8) -> __m256i {
+    transmute(loaddqu16_256(
+        mem_addr,
+        _mm256_setzero_si256().as_i16x16(),
+        k,
+    ))
+}
+
+/// Stores packed 16-bit integers from `a` into memory using writemask `k`
+/// (elements are not written when the corresponding mask bit is not set).
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_storeu_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu16))]
+pub unsafe fn _mm256_mask_storeu_epi16(mem_addr: *mut u8, k: __mmask16, a: __m256i) {
+    storedqu16_256(mem_addr, a.as_i16x16(), k)
+}
+
+/// Loads packed 16-bit integers from memory into `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_loadu_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu16))]
+pub unsafe fn _mm_mask_loadu_epi16(src: __m128i, k: __mmask8, mem_addr: *const u8) -> __m128i {
+    transmute(loaddqu16_128(mem_addr, src.as_i16x8(), k))
+}
+
+/// Loads packed 16-bit integers from memory into `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_loadu_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu16))]
+pub unsafe fn _mm_maskz_loadu_epi16(k: __mmask8, mem_addr: *const u8) -> __m128i {
+    transmute(loaddqu16_128(mem_addr, _mm_setzero_si128().as_i16x8(), k))
+}
+
+/// Stores packed 16-bit integers from `a` into memory using writemask `k`
+/// (elements are not written when the corresponding mask bit is not set).
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_storeu_epi16)
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovdqu16))]
+pub unsafe fn _mm_mask_storeu_epi16(mem_addr: *mut u8, k: __mmask8, a: __m128i) {
+    storedqu16_128(mem_addr, a.as_i16x8(), k)
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.avx512.mask.padds.b.512"]
+    fn vpaddsb(a: i8x64, b: i8x64, src: i8x64, mask: __mmask64) -> i8x64;
+    #[link_name = "llvm.x86.avx512.mask.padds.w.512"]
+    fn vpaddsw(a: i16x32, b: i16x32, src: i16x32, mask: __mmask32) -> i16x32;
+    #[link_name = "llvm.x86.avx512.mask.paddus.b.512"]
+    fn vpaddusb(a: u8x64, b: u8x64, src: u8x64, mask: __mmask64) -> u8x64;
+    #[link_name = "llvm.x86.avx512.mask.paddus.w.512"]
+    fn vpaddusw(a: u16x32, b: u16x32, src: u16x32, mask: __mmask32) -> u16x32;
+    #[link_name = "llvm.x86.avx512.mask.psubs.b.512"]
+    fn vpsubsb(a: i8x64, b: i8x64, src: i8x64, mask: __mmask64) -> i8x64;
+    #[link_name = "llvm.x86.avx512.mask.psubs.w.512"]
+    fn vpsubsw(a: i16x32, b: i16x32, src: i16x32, mask: __mmask32) -> i16x32;
+    #[link_name = "llvm.x86.avx512.mask.psubus.b.512"]
+    fn vpsubusb(a: u8x64, b: u8x64, src: u8x64, mask: __mmask64) -> u8x64;
+    #[link_name = "llvm.x86.avx512.mask.psubus.w.512"]
+    fn vpsubusw(a: u16x32, b: u16x32, src: u16x32, mask: __mmask32) -> u16x32;
+    #[link_name = "llvm.x86.avx512.pmulh.w.512"]
+    fn vpmulhw(a: i16x32, b: i16x32) -> i16x32;
+    #[link_name = "llvm.x86.avx512.pmulhu.w.512"]
+    fn vpmulhuw(a: u16x32, b: u16x32) -> u16x32;
+    #[link_name = "llvm.x86.avx512.pmul.hr.sw.512"]
+    fn vpmulhrsw(a: i16x32, b: i16x32) -> i16x32;
+    #[link_name = "llvm.x86.avx512.pavg.b.512"]
+    fn vpavgb(a: u8x64, b: u8x64) -> u8x64;
+    #[link_name = "llvm.x86.avx512.pavg.w.512"]
+    fn vpavgw(a: u16x32, b: u16x32) -> u16x32;
+    #[link_name = "llvm.x86.avx512.pmaddw.d.512"]
+    fn vpmaddwd(a: i16x32, b: i16x32) -> i32x16;
+    #[link_name = "llvm.x86.avx512.pmaddubs.w.512"]
+    fn vpmaddubsw(a: u8x64, b: i8x64) -> i16x32;
+    #[link_name = "llvm.x86.avx512.packsswb.512"]
+    fn vpacksswb(a: i16x32, b: i16x32) -> i8x64;
+    #[link_name = "llvm.x86.avx512.packssdw.512"]
+    fn vpackssdw(a: i32x16, b: i32x16) -> i16x32;
+    #[link_name = "llvm.x86.avx512.packuswb.512"]
+    fn vpackuswb(a: i16x32, b: i16x32) -> u8x64;
+    #[link_name = "llvm.x86.avx512.packusdw.512"]
+    fn vpackusdw(a: i32x16, b: i32x16) -> u16x32;
+    #[link_name = "llvm.x86.avx512.pshuf.b.512"]
+    fn vpshufb(a: i8x64, b: i8x64) -> i8x64;
+    #[link_name = "llvm.x86.avx512.psad.bw.512"]
+    fn vpsadbw(a: u8x64, b: u8x64) -> u64x8;
+    #[link_name = "llvm.x86.avx512.dbpsadbw.512"]
+    fn vdbpsadbw(a: u8x64, b: u8x64, imm8: i32) -> u16x32;
+    #[link_name = "llvm.x86.avx512.pslli.w.512"]
+    fn vpslliw(a: i16x32, imm8: u32) -> i16x32;
+    #[link_name = "llvm.x86.avx512.psll.w.512"]
+    fn vpsllw(a: i16x32, count: i16x8) -> i16x32;
+    #[link_name = "llvm.x86.avx512.psllv.w.512"]
+    fn vpsllvw(a: i16x32, count: i16x32) -> i16x32;
+    #[link_name = "llvm.x86.avx512.psrli.w.512"]
+    fn vpsrliw(a: i16x32, imm8: u32) -> i16x32;
+    #[link_name = "llvm.x86.avx512.psrl.w.512"]
+    fn vpsrlw(a: i16x32, count: i16x8) -> i16x32;
+    #[link_name = "llvm.x86.avx512.psrlv.w.512"]
+    fn vpsrlvw(a: i16x32, count: i16x32) -> i16x32;
+    #[link_name = "llvm.x86.avx512.psrai.w.512"]
+    fn vpsraiw(a: i16x32, imm8: u32) -> i16x32;
+    #[link_name = "llvm.x86.avx512.psra.w.512"]
+    fn vpsraw(a: i16x32, count: i16x8) -> i16x32;
+    #[link_name = "llvm.x86.avx512.psrav.w.512"]
+    fn vpsravw(a: i16x32, count: i16x32) -> i16x32;
+    #[link_name = "llvm.x86.avx512.mask.cmp.b.512"]
+    fn vpcmpb(a: i8x64, b: i8x64, op: i32, m: __mmask64) -> __mmask64;
+    #[link_name = "llvm.x86.avx512.mask.cmp.w.512"]
+    fn vpcmpw(a: i16x32, b: i16x32, op: i32, m: __mmask32) -> __mmask32;
+    #[link_name = "llvm.x86.avx512.mask.ucmp.b.512"]
+    fn vpucmpb(a: u8x64, b: u8x64, op: i32, m: __mmask64) -> __mmask64;
+    #[link_name = "llvm.x86.avx512.mask.ucmp.w.512"]
+    fn vpucmpw(a: u16x32, b: u16x32, op: i32, m: __mmask32) -> __mmask32;
+    #[link_name = "llvm.x86.avx512.mask.cmp.b.256"]
+    fn vpcmpb256(a: i8x32, b: i8x32, op: i32, m: __mmask32) -> __mmask32;
+    #[link_name = "llvm.x86.avx512.mask.cmp.b.128"]
+    fn vpcmpb128(a: i8x16, b: i8x16, op: i32, m: __mmask16) -> __mmask16;
+    #[link_name = "llvm.x86.avx512.mask.ucmp.b.256"]
+    fn vpucmpb256(a: u8x32, b: u8x32, op: i32, m: __mmask32) -> __mmask32;
+    #[link_name = "llvm.x86.avx512.mask.ucmp.b.128"]
+    fn vpucmpb128(a: u8x16, b: u8x16, op: i32, m: __mmask16) -> __mmask16;
+    #[link_name = "llvm.x86.avx512.mask.cmp.w.256"]
+    fn vpcmpw256(a: i16x16, b: i16x16, op: i32, m: __mmask16) -> __mmask16;
+    #[link_name = "llvm.x86.avx512.mask.cmp.w.128"]
+    fn vpcmpw128(a: i16x8, b: i16x8, op: i32, m: __mmask8) -> __mmask8;
+    #[link_name = "llvm.x86.avx512.mask.ucmp.w.256"]
+    fn vpucmpw256(a: u16x16, b: u16x16, op: i32, m: __mmask16) -> __mmask16;
+    #[link_name = "llvm.x86.avx512.mask.ucmp.w.128"]
+    fn vpucmpw128(a: u16x8, b: u16x8, op: i32, m: __mmask8) -> __mmask8;
+    #[link_name = "llvm.x86.avx512.mask.loadu.b.512"]
+    fn loaddqu8(mem_addr: *const u8, src: i8x64, k: __mmask64) -> i8x64;
+    #[link_name = "llvm.x86.avx512.mask.storeu.b.512"]
+    fn storedqu8(mem_addr: *mut u8, a: i8x64, k: __mmask64);
+    #[link_name = "llvm.x86.avx512.mask.loadu.b.256"]
+    fn loaddqu8_256(mem_addr: *const u8, src: i8x32, k: __mmask32) -> i8x32;
+    #[link_name = "llvm.x86.avx512.mask.storeu.b.256"]
+    fn storedqu8_256(mem_addr: *mut u8, a: i8x32, k: __mmask32);
+    #[link_name = "llvm.x86.avx512.mask.loadu.b.128"]
+    fn loaddqu8_128(mem_addr: *const u8, src: i8x16, k: __mmask16) -> i8x16;
+    #[link_name = "llvm.x86.avx512.mask.storeu.b.128"]
+    fn storedqu8_128(mem_addr: *mut u8, a: i8x16, k: __mmask16);
+    #[link_name = "llvm.x86.avx512.mask.loadu.w.512"]
+    fn loaddqu16(mem_addr: *const u8, src: i16x32, k: __mmask32) -> i16x32;
+    #[link_name = "llvm.x86.avx512.mask.storeu.w.512"]
+    fn storedqu16(mem_addr: *mut u8, a: i16x32, k: __mmask32);
+    #[link_name = "llvm.x86.avx512.mask.loadu.w.256"]
+    fn loaddqu16_256(mem_addr: *const u8, src: i16x16, k: __mmask16) -> i16x16;
+    #[link_name = "llvm.x86.avx512.mask.storeu.w.256"]
+    fn storedqu16_256(mem_addr: *mut u8, a: i16x16, k: __mmask16);
+    #[link_name = "llvm.x86.avx512.mask.loadu.w.128"]
+    fn loaddqu16_128(mem_addr: *const u8, src: i16x8, k: __mmask8) -> i16x8;
+    #[link_name = "llvm.x86.avx512.mask.storeu.w.128"]
+    fn storedqu16_128(mem_addr: *mut u8, a: i16x8, k: __mmask8);
+}
+
+#[cfg(test)]
+mod tests {
+    use stdsimd_test::simd_test;
+
+    use crate::core_arch::x86::*;
+    use std::mem::transmute;
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kand_mask32() {
+        let a: __mmask32 = 0b11001010_01010011_10010110_11100001;
+        let b: __mmask32 = 0b10100110_10011100_01011010_00111100;
+        let r = _kand_mask32(a, b);
+        let e: __mmask32 = 0b10000010_00010000_00010010_00100000;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kandn_mask32() {
+        let a: __mmask32 = 0b11001010_01010011_10010110_11100001;
+        let b: __mmask32 = 0b10100110_10011100_01011010_00111100;
+        let r = _kandn_mask32(a, b);
+        let e: __mmask32 = 0b00100100_10001100_01001000_00011100;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kor_mask32() {
+        let a: __mmask32 = 0b11001010_01010011_10010110_11100001;
+        let b: __mmask32 = 0b10100110_10011100_01011010_00111100;
+        let r = _kor_mask32(a, b);
+        let e: __mmask32 = 0b11101110_11011111_11011110_11111101;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kxnor_mask32() {
+        let a: __mmask32 = 0b11001010_01010011_10010110_11100001;
+        let b: __mmask32 = 0b10100110_10011100_01011010_00111100;
+        let r = _kxnor_mask32(a, b);
+        let e: __mmask32 = 0b10010011_00110000_00110011_00100010;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kxor_mask32() {
+        let a: __mmask32 = 0b11001010_01010011_10010110_11100001;
+        let b: __mmask32 = 0b10100110_10011100_01011010_00111100;
+        let r = _kxor_mask32(a, b);
+        let e: __mmask32 = 0b01101100_11001111_11001100_11011101;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_knot_mask32() {
+        let a: __mmask32 = 0b11001010_01010011_10010110_11100001;
+        let r = _knot_mask32(a);
+        let e: __mmask32 = 0b00110101_10101100_01101001_00011110;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kshiftli_mask32() {
+        let a: __mmask32 = 0b11001010_01010011_10010110_11100001;
+        let r = _kshiftli_mask32(a, 3);
+        let e: __mmask32 = 0b01010010_10011100_10110111_00001000;
+        assert_eq!(r, e);
+        let r = _kshiftli_mask32(a, 32);
+        let e: __mmask32 = 0b00000000_00000000_00000000_00000000;
+        assert_eq!(r, e);
+        let r = _kshiftli_mask32(a, 255);
+        let e: __mmask32 = 0b00000000_00000000_00000000_00000000;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kshiftri_mask32() {
+        let a: __mmask32 = 0b11001010_01010011_10010110_11100001;
+        let r = _kshiftri_mask32(a, 3);
+        let e: __mmask32 = 0b00011001_01001010_01110010_11011100;
+        assert_eq!(r, e);
+        let r = _kshiftri_mask32(a, 32);
+        let e: __mmask32 = 0b00000000_00000000_00000000_00000000;
+        assert_eq!(r, e);
+        let r = _kshiftri_mask32(a, 255);
+        let e: __mmask32 = 0b00000000_00000000_00000000_00000000;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kortestz_mask32_u8() {
+        let a: __mmask32 = 0b11001010_01010011_10010110_11100001;
+        let b: __mmask32 = 0b00110101_10101100_01101001_00011110;
+        assert_eq!(_kortestz_mask32_u8(a, b), 0);
+        assert_eq!(_kortestz_mask32_u8(0, 0), 1);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kortestc_mask32_u8() {
+        let a: __mmask32 = 0b11001010_01010011_10010110_11100001;
+        let b: __mmask32 = 0b00110101_10101100_01101001_00011110;
+        assert_eq!(_kortestc_mask32_u8(a, b), 1);
+        assert_eq!(_kortestc_mask32_u8(a, 0), 0);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kortest_mask32_u8() {
+        let a: __mmask32 = 0b11001010_01010011_10010110_11100001;
+        let b: __mmask32 = 0b00110101_10101100_01101001_00011110;
+        let mut all_ones: u8 = 0;
+        let r = _kortest_mask32_u8(a, b, &mut all_ones);
+        assert_eq!(r, 0);
+        assert_eq!(all_ones, 1);
+        let r = _kortest_mask32_u8(0, 0, &mut all_ones);
+        assert_eq!(r, 1);
+        assert_eq!(all_ones, 0);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_ktestz_mask32_u8() {
+        let a: __mmask32 = 0b11001010_01010011_10010110_11100001;
+        let b: __mmask32 = 0b00110101_10101100_01101001_00011110;
+        assert_eq!(_ktestz_mask32_u8(a, b), 1);
+        assert_eq!(_ktestz_mask32_u8(a, a), 0);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_ktestc_mask32_u8() {
+        let a: __mmask32 = 0b11001010_01010011_10010110_11100001;
+        let b: __mmask32 = 0b00110101_10101100_01101001_00011110;
+        assert_eq!(_ktestc_mask32_u8(a, a), 1);
+        assert_eq!(_ktestc_mask32_u8(a, b), 0);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_ktest_mask32_u8() {
+        let a: __mmask32 = 0b11001010_01010011_10010110_11100001;
+        let b: __mmask32 = 0b00110101_10101100_01101001_00011110;
+        let mut and_not: u8 = 0;
+        let r = _ktest_mask32_u8(a, b, &mut and_not);
+        assert_eq!(r, 1);
+        assert_eq!(and_not, 0);
+        let r = _ktest_mask32_u8(a, a, &mut and_not);
+        assert_eq!(r, 0);
+        assert_eq!(and_not, 1);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_cvtmask32_u32() {
+        let a: __mmask32 = 0b11001010_01010011_10010110_11100001;
+        let r = _cvtmask32_u32(a);
+        assert_eq!(r, 0b11001010_01010011_10010110_11100001);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_cvtu32_mask32() {
+        let r = _cvtu32_mask32(0b11001010_01010011_10010110_11100001);
+        let e: __mmask32 = 0b11001010_01010011_10010110_11100001;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_load_mask32() {
+        let a: __mmask32 = 0b11001010_01010011_10010110_11100001;
+        let r = _load_mask32(&a);
+        assert_eq!(r, a);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_store_mask32() {
+        let a: __mmask32 = 0b11001010_01010011_10010110_11100001;
+        let mut r: __mmask32 = 0;
+        _store_mask32(&mut r, a);
+        assert_eq!(r, a);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kand_mask64() {
+        let a: __mmask64 =
+            0b11001010_01010011_10010110_11100001_00001111_00111100_10100101_10100101;
+        let b: __mmask64 =
+            0b10100110_10011100_01011010_00111100_11110000_00001111_00111100_11000011;
+        let r = _kand_mask64(a, b);
+        let e: __mmask64 =
+            0b10000010_00010000_00010010_00100000_00000000_00001100_00100100_10000001;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kandn_mask64() {
+        let a: __mmask64 =
+            0b11001010_01010011_10010110_11100001_00001111_00111100_10100101_10100101;
+        let b: __mmask64 =
+            0b10100110_10011100_01011010_00111100_11110000_00001111_00111100_11000011;
+        let r = _kandn_mask64(a, b);
+        let e: __mmask64 =
+            0b00100100_10001100_01001000_00011100_11110000_00000011_00011000_01000010;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kor_mask64() {
+        let a: __mmask64 =
+            0b11001010_01010011_10010110_11100001_00001111_00111100_10100101_10100101;
+        let b: __mmask64 =
+            0b10100110_10011100_01011010_00111100_11110000_00001111_00111100_11000011;
+        let r = _kor_mask64(a, b);
+        let e: __mmask64 =
+            0b11101110_11011111_11011110_11111101_11111111_00111111_10111101_11100111;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kxnor_mask64() {
+        let a: __mmask64 =
+            0b11001010_01010011_10010110_11100001_00001111_00111100_10100101_10100101;
+        let b: __mmask64 =
+            0b10100110_10011100_01011010_00111100_11110000_00001111_00111100_11000011;
+        let r = _kxnor_mask64(a, b);
+        let e: __mmask64 =
+            0b10010011_00110000_00110011_00100010_00000000_11001100_01100110_10011001;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kxor_mask64() {
+        let a: __mmask64 =
+            0b11001010_01010011_10010110_11100001_00001111_00111100_10100101_10100101;
+        let b: __mmask64 =
+            0b10100110_10011100_01011010_00111100_11110000_00001111_00111100_11000011;
+        let r = _kxor_mask64(a, b);
+        let e: __mmask64 =
+            0b01101100_11001111_11001100_11011101_11111111_00110011_10011001_01100110;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_knot_mask64() {
+        let a: __mmask64 =
+            0b11001010_01010011_10010110_11100001_00001111_00111100_10100101_10100101;
+        let r = _knot_mask64(a);
+        let e: __mmask64 =
+            0b00110101_10101100_01101001_00011110_11110000_11000011_01011010_01011010;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kshiftli_mask64() {
+        let a: __mmask64 =
+            0b11001010_01010011_10010110_11100001_00001111_00111100_10100101_10100101;
+        let r = _kshiftli_mask64(a, 3);
+        let e: __mmask64 =
+            0b01010010_10011100_10110111_00001000_01111001_11100101_00101101_00101000;
+        assert_eq!(r, e);
+        let r = _kshiftli_mask64(a, 64);
+        let e: __mmask64 =
+            0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000;
+        assert_eq!(r, e);
+        let r = _kshiftli_mask64(a, 255);
+        let e: __mmask64 =
+            0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kshiftri_mask64() {
+        let a: __mmask64 =
+            0b11001010_01010011_10010110_11100001_00001111_00111100_10100101_10100101;
+        let r = _kshiftri_mask64(a, 3);
+        let e: __mmask64 =
+            0b00011001_01001010_01110010_11011100_00100001_11100111_10010100_10110100;
+        assert_eq!(r, e);
+        let r = _kshiftri_mask64(a, 64);
+        let e: __mmask64 =
+            0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000;
+        assert_eq!(r, e);
+        let r = _kshiftri_mask64(a, 255);
+        let e: __mmask64 =
+            0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kortestz_mask64_u8() {
+        let a: __mmask64 =
+            0b11001010_01010011_10010110_11100001_00001111_00111100_10100101_10100101;
+        let b: __mmask64 =
+            0b00110101_10101100_01101001_00011110_11110000_11000011_01011010_01011010;
+        assert_eq!(_kortestz_mask64_u8(a, b), 0);
+        assert_eq!(_kortestz_mask64_u8(0, 0), 1);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kortestc_mask64_u8() {
+        let a: __mmask64 =
+            0b11001010_01010011_10010110_11100001_00001111_00111100_10100101_10100101;
+        let b: __mmask64 =
+            0b00110101_10101100_01101001_00011110_11110000_11000011_01011010_01011010;
+        assert_eq!(_kortestc_mask64_u8(a, b), 1);
+        assert_eq!(_kortestc_mask64_u8(a, 0), 0);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_kortest_mask64_u8() {
+        let a: __mmask64 =
+            0b11001010_01010011_10010110_11100001_00001111_00111100_10100101_10100101;
+        let b: __mmask64 =
+            0b00110101_10101100_01101001_00011110_11110000_11000011_01011010_01011010;
+        let mut all_ones: u8 = 0;
+        let r = _kortest_mask64_u8(a, b, &mut all_ones);
+        assert_eq!(r, 0);
+        assert_eq!(all_ones, 1);
+        let r = _kortest_mask64_u8(0, 0, &mut all_ones);
+        assert_eq!(r, 1);
+        assert_eq!(all_ones, 0);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_ktestz_mask64_u8() {
+        let a: __mmask64 =
+            0b11001010_01010011_10010110_11100001_00001111_00111100_10100101_10100101;
+        let b: __mmask64 =
+            0b00110101_10101100_01101001_00011110_11110000_11000011_01011010_01011010;
+        assert_eq!(_ktestz_mask64_u8(a, b), 1);
+        assert_eq!(_ktestz_mask64_u8(a, a), 0);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_ktestc_mask64_u8() {
+        let a: __mmask64 =
+            0b11001010_01010011_10010110_11100001_00001111_00111100_10100101_10100101;
+        let b: __mmask64 =
+            0b00110101_10101100_01101001_00011110_11110000_11000011_01011010_01011010;
+        assert_eq!(_ktestc_mask64_u8(a, a), 1);
+        assert_eq!(_ktestc_mask64_u8(a, b), 0);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_ktest_mask64_u8() {
+        let a: __mmask64 =
+            0b11001010_01010011_10010110_11100001_00001111_00111100_10100101_10100101;
+        let b: __mmask64 =
+            0b00110101_10101100_01101001_00011110_11110000_11000011_01011010_01011010;
+        let mut and_not: u8 = 0;
+        let r = _ktest_mask64_u8(a, b, &mut and_not);
+        assert_eq!(r, 1);
+        assert_eq!(and_not, 0);
+        let r = _ktest_mask64_u8(a, a, &mut and_not);
+        assert_eq!(r, 0);
+        assert_eq!(and_not, 1);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_load_mask64() {
+        let a: __mmask64 =
+            0b11001010_01010011_10010110_11100001_00001111_00111100_10100101_10100101;
+        let r = _load_mask64(&a);
+        assert_eq!(r, a);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_store_mask64() {
+        let a: __mmask64 =
+            0b11001010_01010011_10010110_11100001_00001111_00111100_10100101_10100101;
+        let mut r: __mmask64 = 0;
+        _store_mask64(&mut r, a);
+        assert_eq!(r, a);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_kunpackw() {
+        let a: __mmask32 = 0b11001010_01010011_10010110_11100001;
+        let b: __mmask32 = 0b10100110_10011100_01011010_00111100;
+        let r = _mm512_kunpackw(a, b);
+        let e: __mmask32 = 0b10010110_11100001_01011010_00111100;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_kunpackd() {
+        let a: __mmask64 =
+            0b11001010_01010011_10010110_11100001_00001111_00111100_10100101_10100101;
+        let b: __mmask64 =
+            0b10100110_10011100_01011010_00111100_11110000_00001111_00111100_11000011;
+        let r = _mm512_kunpackd(a, b);
+        let e: __mmask64 =
+            0b00001111_00111100_10100101_10100101_11110000_00001111_00111100_11000011;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_movepi8_mask() {
+        let a = _mm512_set1_epi32(0x8000_ff01_u32 as i32);
+        let r = _mm512_movepi8_mask(a);
+        let e: __mmask64 = 0xaaaa_aaaa_aaaa_aaaa;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_movm_epi8() {
+        let k: __mmask64 = 0x5555_5555_5555_5555;
+        let r = _mm512_movm_epi8(k);
+        let e = _mm512_set1_epi32(0x00ff_00ff);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_movepi16_mask() {
+        let a = _mm512_set1_epi32(0x8000_7fff_u32 as i32);
+        let r = _mm512_movepi16_mask(a);
+        let e: __mmask32 = 0xaaaa_aaaa;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_movm_epi16() {
+        let k: __mmask32 = 0x5555_5555;
+        let r = _mm512_movm_epi16(k);
+        let e = _mm512_set1_epi32(0x0000_ffff);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_abs_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+        );
+        let r = _mm512_abs_epi8(a);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            7, 32, 50, 50, 3, 3, 2, 2, 32, 100, 100, -128, 127, 1, 1, 0,
+            7, 32, 50, 50, 3, 3, 2, 2, 32, 100, 100, -128, 127, 1, 1, 0,
+            7, 32, 50, 50, 3, 3, 2, 2, 32, 100, 100, -128, 127, 1, 1, 0,
+            7, 32, 50, 50, 3, 3, 2, 2, 32, 100, 100, -128, 127, 1, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_abs_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+        );
+        let r = _mm512_mask_abs_epi8(a, 0, a);
+        assert_eq_m512i(r, a);
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_mask_abs_epi8(a, k, a);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, 50, 50, 3, 3, 2, 2, 32, 100, 100, -128, 127, 1, 1, 0,
+            7, 32, 50, 50, 3, 3, 2, 2, 32, 100, 100, -128, 127, 1, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_abs_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+        );
+        let r = _mm512_maskz_abs_epi8(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_maskz_abs_epi8(k, a);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            7, 32, 50, 50, 3, 3, 2, 2, 32, 100, 100, -128, 127, 1, 1, 0,
+            7, 32, 50, 50, 3, 3, 2, 2, 32, 100, 100, -128, 127, 1, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_abs_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        let r = _mm512_abs_epi16(a);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            7, 32, 1000, 1000, 3, 3, 2, 2,
+            32, 100, 100, -32768, 32767, 1, 1, 0,
+            7, 32, 1000, 1000, 3, 3, 2, 2,
+            32, 100, 100, -32768, 32767, 1, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_abs_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        let r = _mm512_mask_abs_epi16(a, 0, a);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_abs_epi16(a, 0b00000000_00000000_11111111_11111111, a);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, 1000, 1000, 3, 3, 2, 2,
+            32, 100, 100, -32768, 32767, 1, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_abs_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        let r = _mm512_maskz_abs_epi16(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_abs_epi16(0b00000000_00000000_11111111_11111111, a);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            7, 32, 1000, 1000, 3, 3, 2, 2,
+            32, 100, 100, -32768, 32767, 1, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_add_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_add_epi8(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            -1, 25, -56, 45, -7, 0, -4, 1, -24, -93, 106, -123, -125, 2, 3, 1,
+            -1, 25, -56, 45, -7, 0, -4, 1, -24, -93, 106, -123, -125, 2, 3, 1,
+            -1, 25, -56, 45, -7, 0, -4, 1, -24, -93, 106, -123, -125, 2, 3, 1,
+            -1, 25, -56, 45, -7, 0, -4, 1, -24, -93, 106, -123, -125, 2, 3, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_add_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_mask_add_epi8(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_mask_add_epi8(a, k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -1, 25, -56, 45, -7, 0, -4, 1, -24, -93, 106, -123, -125, 2, 3, 1,
+            -1, 25, -56, 45, -7, 0, -4, 1, -24, -93, 106, -123, -125, 2, 3, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_add_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_maskz_add_epi8(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_maskz_add_epi8(k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            -1, 25, -56, 45, -7, 0, -4, 1, -24, -93, 106, -123, -125, 2, 3, 1,
+            -1, 25, -56, 45, -7, 0, -4, 1, -24, -93, 106, -123, -125, 2, 3, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_add_epi8() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 2, -2, 100, -100, 13, 7, -7, 50, -50, 3, -3, 9, -9,
+            0, 1, -1, 2, -2, 100, -100, 13, 7, -7, 50, -50, 3, -3, 9, -9,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            3, -3, 7, -7, 5, 50, 9, -13, 1, 1, -1, -1, 8, 8, -8, -8,
+            3, -3, 7, -7, 5, 50, 9, -13, 1, 1, -1, -1, 8, 8, -8, -8,
+        );
+        let r = _mm256_mask_add_epi8(a, 0, a, b);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_add_epi8(a, 0b01101001_10010110_11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            3, 1, 6, 2, 3, -106, -100, 13, 7, -6, 50, -51, 3, -3, 1, -17,
+            0, -2, 6, 2, 3, 100, -100, 0, 8, -7, 50, -51, 3, 5, 1, -9,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_maskz_add_epi8() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 2, -2, 100, -100, 13, 7, -7, 50, -50, 3, -3, 9, -9,
+            0, 1, -1, 2, -2, 100, -100, 13, 7, -7, 50, -50, 3, -3, 9, -9,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            3, -3, 7, -7, 5, 50, 9, -13, 1, 1, -1, -1, 8, 8, -8, -8,
+            3, -3, 7, -7, 5, 50, 9, -13, 1, 1, -1, -1, 8, 8, -8, -8,
+        );
+        let r = _mm256_maskz_add_epi8(0, a, b);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_add_epi8(0b01101001_10010110_11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            3, 0, 6, 0, 3, -106, 0, 0, 0, -6, 0, -51, 0, 0, 1, -17,
+            0, -2, 6, 0, 3, 0, 0, 0, 8, 0, 0, -51, 0, 5, 1, 0,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_add_epi8() {
+        let a = _mm_setr_epi8(0, 1, -1, 2, -2, 100, -100, 13, 7, -7, 50, -50, 3, -3, 9, -9);
+        let b = _mm_setr_epi8(3, -3, 7, -7, 5, 50, 9, -13, 1, 1, -1, -1, 8, 8, -8, -8);
+        let r = _mm_mask_add_epi8(a, 0, a, b);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_add_epi8(a, 0b11001010_00110101, a, b);
+        let e = _mm_setr_epi8(3, 1, 6, 2, 3, -106, -100, 13, 7, -6, 50, -51, 3, -3, 1, -17);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_maskz_add_epi8() {
+        let a = _mm_setr_epi8(0, 1, -1, 2, -2, 100, -100, 13, 7, -7, 50, -50, 3, -3, 9, -9);
+        let b = _mm_setr_epi8(3, -3, 7, -7, 5, 50, 9, -13, 1, 1, -1, -1, 8, 8, -8, -8);
+        let r = _mm_maskz_add_epi8(0, a, b);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_add_epi8(0b11001010_00110101, a, b);
+        let e = _mm_setr_epi8(3, 0, 6, 0, 3, -106, 0, 0, 0, -6, 0, -51, 0, 0, 1, -17);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_add_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_add_epi16(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            -1, 25, -1006, 995, -7, 0, -4, 1,
+            -24, -93, 106, -32763, -32765, 2, 3, 1,
+            -1, 25, -1006, 995, -7, 0, -4, 1,
+            -24, -93, 106, -32763, -32765, 2, 3, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_add_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_mask_add_epi16(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_add_epi16(a, 0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            -1, 25, -1006, 995, -7, 0, -4, 1,
+            -24, -93, 106, -32763, -32765, 2, 3, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_add_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_maskz_add_epi16(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_add_epi16(0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            -1, 25, -1006, 995, -7, 0, -4, 1,
+            -24, -93, 106, -32763, -32765, 2, 3, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_add_epi16() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 2, -2, 100, -100, 13,
+            7, -7, 50, -50, 3, -3, 9, -9,
+        );
+        let b = _mm256_setr_epi16(3, -3, 7, -7, 5, 50, 9, -13, 1, 1, -1, -1, 8, 8, -8, -8);
+        let r = _mm256_mask_add_epi16(a, 0, a, b);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_add_epi16(a, 0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi16(
+            3, 1, 6, 2, 3, 150, -100, 13,
+            7, -6, 50, -51, 3, -3, 1, -17,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_maskz_add_epi16() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 2, -2, 100, -100, 13,
+            7, -7, 50, -50, 3, -3, 9, -9,
+        );
+        let b = _mm256_setr_epi16(3, -3, 7, -7, 5, 50, 9, -13, 1, 1, -1, -1, 8, 8, -8, -8);
+        let r = _mm256_maskz_add_epi16(0, a, b);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_add_epi16(0b11001010_00110101, a, b);
+        let e = _mm256_setr_epi16(3, 0, 6, 0, 3, 150, 0, 0, 0, -6, 0, -51, 0, 0, 1, -17);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_add_epi16() {
+        let a = _mm_setr_epi16(0, 1, -1, 2, -2, 100, -100, 13);
+        let b = _mm_setr_epi16(3, -3, 7, -7, 5, 50, 9, -13);
+        let r = _mm_mask_add_epi16(a, 0, a, b);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_add_epi16(a, 0b00110101, a, b);
+        let e = _mm_setr_epi16(3, 1, 6, 2, 3, 150, -100, 13);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_maskz_add_epi16() {
+        let a = _mm_setr_epi16(0, 1, -1, 2, -2, 100, -100, 13);
+        let b = _mm_setr_epi16(3, -3, 7, -7, 5, 50, 9, -13);
+        let r = _mm_maskz_add_epi16(0, a, b);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_add_epi16(0b00110101, a, b);
+        let e = _mm_setr_epi16(3, 0, 6, 0, 3, 150, 0, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_sub_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_sub_epi8(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            15, 39, -44, 55, 1, 6, 0, 3, -40, -107, 94, 123, 123, -4, -1, -1,
+            15, 39, -44, 55, 1, 6, 0, 3, -40, -107, 94, 123, 123, -4, -1, -1,
+            15, 39, -44, 55, 1, 6, 0, 3, -40, -107, 94, 123, 123, -4, -1, -1,
+            15, 39, -44, 55, 1, 6, 0, 3, -40, -107, 94, 123, 123, -4, -1, -1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_sub_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_mask_sub_epi8(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_mask_sub_epi8(a, k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            15, 39, -44, 55, 1, 6, 0, 3, -40, -107, 94, 123, 123, -4, -1, -1,
+            15, 39, -44, 55, 1, 6, 0, 3, -40, -107, 94, 123, 123, -4, -1, -1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_sub_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_maskz_sub_epi8(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_maskz_sub_epi8(k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            15, 39, -44, 55, 1, 6, 0, 3, -40, -107, 94, 123, 123, -4, -1, -1,
+            15, 39, -44, 55, 1, 6, 0, 3, -40, -107, 94, 123, 123, -4, -1, -1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_sub_epi8() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 2, -2, 100, -100, 13, 7, -7, 50, -50, 3, -3, 9, -9,
+            0, 1, -1, 2, -2, 100, -100, 13, 7, -7, 50, -50, 3, -3, 9, -9,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            3, -3, 7, -7, 5, 50, 9, -13, 1, 1, -1, -1, 8, 8, -8, -8,
+            3, -3, 7, -7, 5, 50, 9, -13, 1, 1, -1, -1, 8, 8, -8, -8,
+        );
+        let r = _mm256_mask_sub_epi8(a, 0, a, b);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_sub_epi8(a, 0b01101001_10010110_11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            -3, 1, -8, 2, -7, 50, -100, 13, 7, -8, 50, -49, 3, -3, 17, -1,
+            0, 4, -8, 2, -7, 100, -100, 26, 6, -7, 50, -49, 3, -11, 17, -9,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_maskz_sub_epi8() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 2, -2, 100, -100, 13, 7, -7, 50, -50, 3, -3, 9, -9,
+            0, 1, -1, 2, -2, 100, -100, 13, 7, -7, 50, -50, 3, -3, 9, -9,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            3, -3, 7, -7, 5, 50, 9, -13, 1, 1, -1, -1, 8, 8, -8, -8,
+            3, -3, 7, -7, 5, 50, 9, -13, 1, 1, -1, -1, 8, 8, -8, -8,
+        );
+        let r = _mm256_maskz_sub_epi8(0, a, b);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_sub_epi8(0b01101001_10010110_11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            -3, 0, -8, 0, -7, 50, 0, 0, 0, -8, 0, -49, 0, 0, 17, -1,
+            0, 4, -8, 0, -7, 0, 0, 26, 6, 0, 0, -49, 0, -11, 17, 0,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_sub_epi8() {
+        let a = _mm_setr_epi8(0, 1, -1, 2, -2, 100, -100, 13, 7, -7, 50, -50, 3, -3, 9, -9);
+        let b = _mm_setr_epi8(3, -3, 7, -7, 5, 50, 9, -13, 1, 1, -1, -1, 8, 8, -8, -8);
+        let r = _mm_mask_sub_epi8(a, 0, a, b);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_sub_epi8(a, 0b11001010_00110101, a, b);
+        let e = _mm_setr_epi8(
+            -3, 1, -8, 2, -7, 50, -100, 13, 7, -8, 50, -49, 3, -3, 17, -1,
+        );
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_maskz_sub_epi8() {
+        let a = _mm_setr_epi8(0, 1, -1, 2, -2, 100, -100, 13, 7, -7, 50, -50, 3, -3, 9, -9);
+        let b = _mm_setr_epi8(3, -3, 7, -7, 5, 50, 9, -13, 1, 1, -1, -1, 8, 8, -8, -8);
+        let r = _mm_maskz_sub_epi8(0, a, b);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_sub_epi8(0b11001010_00110101, a, b);
+        let e = _mm_setr_epi8(-3, 0, -8, 0, -7, 50, 0, 0, 0, -8, 0, -49, 0, 0, 17, -1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_sub_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_sub_epi16(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            15, 39, -994, 1005, 1, 6, 0, 3,
+            -40, -107, 94, 32763, 32763, -4, -1, -1,
+            15, 39, -994, 1005, 1, 6, 0, 3,
+            -40, -107, 94, 32763, 32763, -4, -1, -1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_sub_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_mask_sub_epi16(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_sub_epi16(a, 0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            15, 39, -994, 1005, 1, 6, 0, 3,
+            -40, -107, 94, 32763, 32763, -4, -1, -1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_sub_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_maskz_sub_epi16(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_sub_epi16(0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            15, 39, -994, 1005, 1, 6, 0, 3,
+            -40, -107, 94, 32763, 32763, -4, -1, -1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_sub_epi16() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 2, -2, 100, -100, 13,
+            7, -7, 50, -50, 3, -3, 9, -9,
+        );
+        let b = _mm256_setr_epi16(3, -3, 7, -7, 5, 50, 9, -13, 1, 1, -1, -1, 8, 8, -8, -8);
+        let r = _mm256_mask_sub_epi16(a, 0, a, b);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_sub_epi16(a, 0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi16(
+            -3, 1, -8, 2, -7, 50, -100, 13,
+            7, -8, 50, -49, 3, -3, 17, -1,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_maskz_sub_epi16() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 2, -2, 100, -100, 13,
+            7, -7, 50, -50, 3, -3, 9, -9,
+        );
+        let b = _mm256_setr_epi16(3, -3, 7, -7, 5, 50, 9, -13, 1, 1, -1, -1, 8, 8, -8, -8);
+        let r = _mm256_maskz_sub_epi16(0, a, b);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_sub_epi16(0b11001010_00110101, a, b);
+        let e = _mm256_setr_epi16(-3, 0, -8, 0, -7, 50, 0, 0, 0, -8, 0, -49, 0, 0, 17, -1);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_sub_epi16() {
+        let a = _mm_setr_epi16(0, 1, -1, 2, -2, 100, -100, 13);
+        let b = _mm_setr_epi16(3, -3, 7, -7, 5, 50, 9, -13);
+        let r = _mm_mask_sub_epi16(a, 0, a, b);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_sub_epi16(a, 0b00110101, a, b);
+        let e = _mm_setr_epi16(-3, 1, -8, 2, -7, 50, -100, 13);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_maskz_sub_epi16() {
+        let a = _mm_setr_epi16(0, 1, -1, 2, -2, 100, -100, 13);
+        let b = _mm_setr_epi16(3, -3, 7, -7, 5, 50, 9, -13);
+        let r = _mm_maskz_sub_epi16(0, a, b);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_sub_epi16(0b00110101, a, b);
+        let e = _mm_setr_epi16(-3, 0, -8, 0, -7, 50, 0, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_adds_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+        );
+        let r = _mm512_adds_epi8(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            -128, 127, 10, -10, -128, 127, -128, 127, -128, -128, 127, -128, 127, -128, 127, 1,
+            -128, 127, 10, -10, -128, 127, -128, 127, -128, -128, 127, -128, 127, -128, 127, 1,
+            -128, 127, 10, -10, -128, 127, -128, 127, -128, -128, 127, -128, 127, -128, 127, 1,
+            -128, 127, 10, -10, -128, 127, -128, 127, -128, -128, 127, -128, 127, -128, 127, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_adds_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+        );
+        let r = _mm512_mask_adds_epi8(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_mask_adds_epi8(a, k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, 10, -10, -128, 127, -128, 127, -128, -128, 127, -128, 127, -128, 127, 1,
+            -128, 127, 10, -10, -128, 127, -128, 127, -128, -128, 127, -128, 127, -128, 127, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_adds_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+        );
+        let r = _mm512_maskz_adds_epi8(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_maskz_adds_epi8(k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            -128, 127, 10, -10, -128, 127, -128, 127, -128, -128, 127, -128, 127, -128, 127, 1,
+            -128, 127, 10, -10, -128, 127, -128, 127, -128, -128, 127, -128, 127, -128, 127, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_adds_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 32767, 2000, -2000, -32766, 32766, -32768, 32767,
+            -30000, -30000, 30000, -1, 1, -32768, 32767, 1,
+            -32768, 32767, 2000, -2000, -32766, 32766, -32768, 32767,
+            -30000, -30000, 30000, -1, 1, -32768, 32767, 1,
+        );
+        let r = _mm512_adds_epi16(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            -32768, 32767, 1000, -1000, -32768, 32767, -32768, 32767,
+            -30032, -32768, 32767, -32768, 32767, -32768, 32767, 1,
+            -32768, 32767, 1000, -1000, -32768, 32767, -32768, 32767,
+            -30032, -32768, 32767, -32768, 32767, -32768, 32767, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_adds_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 32767, 2000, -2000, -32766, 32766, -32768, 32767,
+            -30000, -30000, 30000, -1, 1, -32768, 32767, 1,
+            -32768, 32767, 2000, -2000, -32766, 32766, -32768, 32767,
+            -30000, -30000, 30000, -1, 1, -32768, 32767, 1,
+        );
+        let r = _mm512_mask_adds_epi16(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_adds_epi16(a, 0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+            -32768, 32767, 1000, -1000, -32768, 32767, -32768, 32767,
+            -30032, -32768, 32767, -32768, 32767, -32768, 32767, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_adds_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 32767, 2000, -2000, -32766, 32766, -32768, 32767,
+            -30000, -30000, 30000, -1, 1, -32768, 32767, 1,
+            -32768, 32767, 2000, -2000, -32766, 32766, -32768, 32767,
+            -30000, -30000, 30000, -1, 1, -32768, 32767, 1,
+        );
+        let r = _mm512_maskz_adds_epi16(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_adds_epi16(0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            -32768, 32767, 1000, -1000, -32768, 32767, -32768, 32767,
+            -30032, -32768, 32767, -32768, 32767, -32768, 32767, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_adds_epu8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+        );
+        let r = _mm512_adds_epu8(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            -1, -2, -1, -10, -1, -127, -1, -127, -1, -1, -56, -1, -128, -1, -128, 1,
+            -1, -2, -1, -10, -1, -127, -1, -127, -1, -1, -56, -1, -128, -1, -128, 1,
+            -1, -2, -1, -10, -1, -127, -1, -127, -1, -1, -56, -1, -128, -1, -128, 1,
+            -1, -2, -1, -10, -1, -127, -1, -127, -1, -1, -56, -1, -128, -1, -128, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_adds_epu8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+        );
+        let r = _mm512_mask_adds_epu8(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_mask_adds_epu8(a, k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -1, -2, -1, -10, -1, -127, -1, -127, -1, -1, -56, -1, -128, -1, -128, 1,
+            -1, -2, -1, -10, -1, -127, -1, -127, -1, -1, -56, -1, -128, -1, -128, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_adds_epu8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+        );
+        let r = _mm512_maskz_adds_epu8(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_maskz_adds_epu8(k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            -1, -2, -1, -10, -1, -127, -1, -127, -1, -1, -56, -1, -128, -1, -128, 1,
+            -1, -2, -1, -10, -1, -127, -1, -127, -1, -1, -56, -1, -128, -1, -128, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_adds_epu16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 32767, 2000, -2000, -32766, 32766, -32768, 32767,
+            -30000, -30000, 30000, -1, 1, -32768, 32767, 1,
+            -32768, 32767, 2000, -2000, -32766, 32766, -32768, 32767,
+            -30000, -30000, 30000, -1, 1, -32768, 32767, 1,
+        );
+        let r = _mm512_adds_epu16(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            -1, -2, -1, -1000, -1, -32767, -1, -32767,
+            -1, -1, -5536, -1, -32768, -1, -32768, 1,
+            -1, -2, -1, -1000, -1, -32767, -1, -32767,
+            -1, -1, -5536, -1, -32768, -1, -32768, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_adds_epu16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 32767, 2000, -2000, -32766, 32766, -32768, 32767,
+            -30000, -30000, 30000, -1, 1, -32768, 32767, 1,
+            -32768, 32767, 2000, -2000, -32766, 32766, -32768, 32767,
+            -30000, -30000, 30000, -1, 1, -32768, 32767, 1,
+        );
+        let r = _mm512_mask_adds_epu16(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_adds_epu16(a, 0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+            -1, -2, -1, -1000, -1, -32767, -1, -32767,
+            -1, -1, -5536, -1, -32768, -1, -32768, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_adds_epu16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 32767, 2000, -2000, -32766, 32766, -32768, 32767,
+            -30000, -30000, 30000, -1, 1, -32768, 32767, 1,
+            -32768, 32767, 2000, -2000, -32766, 32766, -32768, 32767,
+            -30000, -30000, 30000, -1, 1, -32768, 32767, 1,
+        );
+        let r = _mm512_maskz_adds_epu16(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_adds_epu16(0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            -1, -2, -1, -1000, -1, -32767, -1, -32767,
+            -1, -1, -5536, -1, -32768, -1, -32768, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_subs_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+        );
+        let r = _mm512_subs_epi8(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            0, 0, -110, 110, 123, -123, 126, -125, 68, 0, 0, -127, 126, 127, -126, -1,
+            0, 0, -110, 110, 123, -123, 126, -125, 68, 0, 0, -127, 126, 127, -126, -1,
+            0, 0, -110, 110, 123, -123, 126, -125, 68, 0, 0, -127, 126, 127, -126, -1,
+            0, 0, -110, 110, 123, -123, 126, -125, 68, 0, 0, -127, 126, 127, -126, -1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_subs_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+        );
+        let r = _mm512_mask_subs_epi8(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_mask_subs_epi8(a, k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            0, 0, -110, 110, 123, -123, 126, -125, 68, 0, 0, -127, 126, 127, -126, -1,
+            0, 0, -110, 110, 123, -123, 126, -125, 68, 0, 0, -127, 126, 127, -126, -1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_subs_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+        );
+        let r = _mm512_maskz_subs_epi8(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_maskz_subs_epi8(k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, -110, 110, 123, -123, 126, -125, 68, 0, 0, -127, 126, 127, -126, -1,
+            0, 0, -110, 110, 123, -123, 126, -125, 68, 0, 0, -127, 126, 127, -126, -1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_subs_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 32767, 2000, -2000, -32766, 32766, -32768, 32767,
+            -30000, -30000, 30000, -1, 1, -32768, 32767, 1,
+            -32768, 32767, 2000, -2000, -32766, 32766, -32768, 32767,
+            -30000, -30000, 30000, -1, 1, -32768, 32767, 1,
+        );
+        let r = _mm512_subs_epi16(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, -3000, 3000, 32763, -32763, 32766, -32765,
+            29968, 0, 0, -32767, 32766, 32767, -32766, -1,
+            0, 0, -3000, 3000, 32763, -32763, 32766, -32765,
+            29968, 0, 0, -32767, 32766, 32767, -32766, -1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_subs_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 32767, 2000, -2000, -32766, 32766, -32768, 32767,
+            -30000, -30000, 30000, -1, 1, -32768, 32767, 1,
+            -32768, 32767, 2000, -2000, -32766, 32766, -32768, 32767,
+            -30000, -30000, 30000, -1, 1, -32768, 32767, 1,
+        );
+        let r = _mm512_mask_subs_epi16(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_subs_epi16(a, 0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+            0, 0, -3000, 3000, 32763, -32763, 32766, -32765,
+            29968, 0, 0, -32767, 32766, 32767, -32766, -1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_subs_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 32767, 2000, -2000, -32766, 32766, -32768, 32767,
+            -30000, -30000, 30000, -1, 1, -32768, 32767, 1,
+            -32768, 32767, 2000, -2000, -32766, 32766, -32768, 32767,
+            -30000, -30000, 30000, -1, 1, -32768, 32767, 1,
+        );
+        let r = _mm512_maskz_subs_epi16(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_subs_epi16(0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, -3000, 3000, 32763, -32763, 32766, -32765,
+            29968, 0, 0, -32767, 32766, 32767, -32766, -1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_subs_epu8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+        );
+        let r = _mm512_subs_epu8(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            0, 0, -110, 0, 123, 0, 126, 0, 68, 0, 0, 0, 126, 127, 0, 0,
+            0, 0, -110, 0, 123, 0, 126, 0, 68, 0, 0, 0, 126, 127, 0, 0,
+            0, 0, -110, 0, 123, 0, 126, 0, 68, 0, 0, 0, 126, 127, 0, 0,
+            0, 0, -110, 0, 123, 0, 126, 0, 68, 0, 0, 0, 126, 127, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_subs_epu8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+        );
+        let r = _mm512_mask_subs_epu8(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_mask_subs_epu8(a, k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            0, 0, -110, 0, 123, 0, 126, 0, 68, 0, 0, 0, 126, 127, 0, 0,
+            0, 0, -110, 0, 123, 0, 126, 0, 68, 0, 0, 0, 126, 127, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_subs_epu8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, 127, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+            -128, 127, 60, -60, -126, 126, -128, 127, -100, -100, 100, -1, 1, -128, 127, 1,
+        );
+        let r = _mm512_maskz_subs_epu8(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_maskz_subs_epu8(k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, -110, 0, 123, 0, 126, 0, 68, 0, 0, 0, 126, 127, 0, 0,
+            0, 0, -110, 0, 123, 0, 126, 0, 68, 0, 0, 0, 126, 127, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_subs_epu16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 32767, 2000, -2000, -32766, 32766, -32768, 32767,
+            -30000, -30000, 30000, -1, 1, -32768, 32767, 1,
+            -32768, 32767, 2000, -2000, -32766, 32766, -32768, 32767,
+            -30000, -30000, 30000, -1, 1, -32768, 32767, 1,
+        );
+        let r = _mm512_subs_epu16(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, -3000, 0, 32763, 0, 32766, 0,
+            29968, 0, 0, 0, 32766, 32767, 0, 0,
+            0, 0, -3000, 0, 32763, 0, 32766, 0,
+            29968, 0, 0, 0, 32766, 32767, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_subs_epu16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 32767, 2000, -2000, -32766, 32766, -32768, 32767,
+            -30000, -30000, 30000, -1, 1, -32768, 32767, 1,
+            -32768, 32767, 2000, -2000, -32766, 32766, -32768, 32767,
+            -30000, -30000, 30000, -1, 1, -32768, 32767, 1,
+        );
+        let r = _mm512_mask_subs_epu16(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_subs_epu16(a, 0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+            0, 0, -3000, 0, 32763, 0, 32766, 0,
+            29968, 0, 0, 0, 32766, 32767, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_subs_epu16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+            -32768, 32767, -1000, 1000, -3, 3, -2, 2,
+            -32, -30000, 30000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 32767, 2000, -2000, -32766, 32766, -32768, 32767,
+            -30000, -30000, 30000, -1, 1, -32768, 32767, 1,
+            -32768, 32767, 2000, -2000, -32766, 32766, -32768, 32767,
+            -30000, -30000, 30000, -1, 1, -32768, 32767, 1,
+        );
+        let r = _mm512_maskz_subs_epu16(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_subs_epu16(0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, -3000, 0, 32763, 0, 32766, 0,
+            29968, 0, 0, 0, 32766, 32767, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mulhi_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+        );
+        let r = _mm512_mulhi_epi16(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            -4, 0, 15, 0, 0, -2, -2, -1,
+            2325, -31, -16, 16384, 16383, 0, 0, 0,
+            -4, 0, 15, 0, 0, -2, -2, -1,
+            2325, -31, -16, 16384, 16383, 0, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_mulhi_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+        );
+        let r = _mm512_mask_mulhi_epi16(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_mulhi_epi16(a, 0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+            -4, 0, 15, 0, 0, -2, -2, -1,
+            2325, -31, -16, 16384, 16383, 0, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_mulhi_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+        );
+        let r = _mm512_maskz_mulhi_epi16(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_mulhi_epi16(0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            -4, 0, 15, 0, 0, -2, -2, -1,
+            2325, -31, -16, 16384, 16383, 0, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mulhi_epu16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+        );
+        let r = _mm512_mulhi_epu16(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            3, 0, 15, -20003, 0, 298, 298, 299,
+            2325, 1969, 984, 16384, 16383, -4, 0, 0,
+            3, 0, 15, -20003, 0, 298, 298, 299,
+            2325, 1969, 984, 16384, 16383, -4, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_mulhi_epu16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+        );
+        let r = _mm512_mask_mulhi_epu16(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_mulhi_epu16(a, 0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+            3, 0, 15, -20003, 0, 298, 298, 299,
+            2325, 1969, 984, 16384, 16383, -4, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_mulhi_epu16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+        );
+        let r = _mm512_maskz_mulhi_epu16(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_mulhi_epu16(0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            3, 0, 15, -20003, 0, 298, 298, 299,
+            2325, 1969, 984, 16384, 16383, -4, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mulhrs_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+        );
+        let r = _mm512_mulhrs_epi16(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            -7, 2, 32, 2, 2, -3, -3, 0,
+            4651, -61, -31, -32768, 32766, 0, 0, 0,
+            -7, 2, 32, 2, 2, -3, -3, 0,
+            4651, -61, -31, -32768, 32766, 0, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_mulhrs_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+        );
+        let r = _mm512_mask_mulhrs_epi16(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_mulhrs_epi16(a, 0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+            -7, 2, 32, 2, 2, -3, -3, 0,
+            4651, -61, -31, -32768, 32766, 0, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_mulhrs_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+        );
+        let r = _mm512_maskz_mulhrs_epi16(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_mulhrs_epi16(0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            -7, 2, 32, 2, 2, -3, -3, 0,
+            4651, -61, -31, -32768, 32766, 0, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mullo_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+        );
+        let r = _mm512_mullo_epi16(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            -32768, -256, -32, -5536, -5536, -24464, -24464, -600,
+            27825, 31616, -16960, 0, 1, 3, 2, 0,
+            -32768, -256, -32, -5536, -5536, -24464, -24464, -600,
+            27825, 31616, -16960, 0, 1, 3, 2, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_mullo_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+        );
+        let r = _mm512_mask_mullo_epi16(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_mullo_epi16(a, 0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+            -32768, -256, -32, -5536, -5536, -24464, -24464, -600,
+            27825, 31616, -16960, 0, 1, 3, 2, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_mullo_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+        );
+        let r = _mm512_maskz_mullo_epi16(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_mullo_epi16(0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            -32768, -256, -32, -5536, -5536, -24464, -24464, -600,
+            27825, 31616, -16960, 0, 1, 3, 2, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_max_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let r = _mm512_max_epi8(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            13, 100, 100, -128, 127, 1, 1, 0, 13, 100, 100, -128, 127, 1, 1, 0,
+            13, 100, 100, -128, 127, 1, 1, 0, 13, 100, 100, -128, 127, 1, 1, 0,
+            13, 100, 100, -128, 127, 1, 1, 0, 13, 100, 100, -128, 127, 1, 1, 0,
+            13, 100, 100, -128, 127, 1, 1, 0, 13, 100, 100, -128, 127, 1, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_max_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let r = _mm512_mask_max_epi8(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_mask_max_epi8(a, k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 100, -128, 127, 1, 1, 0, 13, 100, 100, -128, 127, 1, 1, 0,
+            13, 100, 100, -128, 127, 1, 1, 0, 13, 100, 100, -128, 127, 1, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_max_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let r = _mm512_maskz_max_epi8(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_maskz_max_epi8(k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            13, 100, 100, -128, 127, 1, 1, 0, 13, 100, 100, -128, 127, 1, 1, 0,
+            13, 100, 100, -128, 127, 1, 1, 0, 13, 100, 100, -128, 127, 1, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_max_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let r = _mm512_max_epi16(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            13, 100, 100, -32768, 32767, 1, 1, 0,
+            13, 100, 100, -32768, 32767, 1, 1, 0,
+            13, 100, 100, -32768, 32767, 1, 1, 0,
+            13, 100, 100, -32768, 32767, 1, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_max_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let r = _mm512_mask_max_epi16(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_max_epi16(a, 0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 100, -32768, 32767, 1, 1, 0,
+            13, 100, 100, -32768, 32767, 1, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_max_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let r = _mm512_maskz_max_epi16(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_max_epi16(0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            13, 100, 100, -32768, 32767, 1, 1, 0,
+            13, 100, 100, -32768, 32767, 1, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_max_epu8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let r = _mm512_max_epu8(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, -1, 0, 13, -100, 100, -128, 127, -1, -1, 0,
+            13, -100, 100, -128, 127, -1, -1, 0, 13, -100, 100, -128, 127, -1, -1, 0,
+            13, -100, 100, -128, 127, -1, -1, 0, 13, -100, 100, -128, 127, -1, -1, 0,
+            13, -100, 100, -128, 127, -1, -1, 0, 13, -100, 100, -128, 127, -1, -1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_max_epu8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let r = _mm512_mask_max_epu8(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_mask_max_epu8(a, k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, -100, 100, -128, 127, -1, -1, 0, 13, -100, 100, -128, 127, -1, -1, 0,
+            13, -100, 100, -128, 127, -1, -1, 0, 13, -100, 100, -128, 127, -1, -1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_max_epu8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let r = _mm512_maskz_max_epu8(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_maskz_max_epu8(k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            13, -100, 100, -128, 127, -1, -1, 0, 13, -100, 100, -128, 127, -1, -1, 0,
+            13, -100, 100, -128, 127, -1, -1, 0, 13, -100, 100, -128, 127, -1, -1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_max_epu16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let r = _mm512_max_epu16(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, -1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_max_epu16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let r = _mm512_mask_max_epu16(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_max_epu16(a, 0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, -100, 100, -32768, 32767, -1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, -1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_max_epu16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let r = _mm512_maskz_max_epu16(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_max_epu16(0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            13, -100, 100, -32768, 32767, -1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, -1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_min_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let r = _mm512_min_epi8(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            13, -100, 99, -128, 13, -1, -1, 0, 13, -100, 99, -128, 13, -1, -1, 0,
+            13, -100, 99, -128, 13, -1, -1, 0, 13, -100, 99, -128, 13, -1, -1, 0,
+            13, -100, 99, -128, 13, -1, -1, 0, 13, -100, 99, -128, 13, -1, -1, 0,
+            13, -100, 99, -128, 13, -1, -1, 0, 13, -100, 99, -128, 13, -1, -1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_min_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let r = _mm512_mask_min_epi8(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_mask_min_epi8(a, k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, -100, 99, -128, 13, -1, -1, 0, 13, -100, 99, -128, 13, -1, -1, 0,
+            13, -100, 99, -128, 13, -1, -1, 0, 13, -100, 99, -128, 13, -1, -1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_min_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let r = _mm512_maskz_min_epi8(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_maskz_min_epi8(k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            13, -100, 99, -128, 13, -1, -1, 0, 13, -100, 99, -128, 13, -1, -1, 0,
+            13, -100, 99, -128, 13, -1, -1, 0, 13, -100, 99, -128, 13, -1, -1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_min_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let r = _mm512_min_epi16(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            13, -100, 99, -32768, 13, -1, -1, 0,
+            13, -100, 99, -32768, 13, -1, -1, 0,
+            13, -100, 99, -32768, 13, -1, -1, 0,
+            13, -100, 99, -32768, 13, -1, -1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_min_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let r = _mm512_mask_min_epi16(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_min_epi16(a, 0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, -100, 99, -32768, 13, -1, -1, 0,
+            13, -100, 99, -32768, 13, -1, -1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_min_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let r = _mm512_maskz_min_epi16(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_min_epi16(0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            13, -100, 99, -32768, 13, -1, -1, 0,
+            13, -100, 99, -32768, 13, -1, -1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_min_epu8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let r = _mm512_min_epu8(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, 1, 0, 13, 100, 99, -128, 13, 1, 1, 0,
+            13, 100, 99, -128, 13, 1, 1, 0, 13, 100, 99, -128, 13, 1, 1, 0,
+            13, 100, 99, -128, 13, 1, 1, 0, 13, 100, 99, -128, 13, 1, 1, 0,
+            13, 100, 99, -128, 13, 1, 1, 0, 13, 100, 99, -128, 13, 1, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_min_epu8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let r = _mm512_mask_min_epu8(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_mask_min_epu8(a, k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, 1, 0, 13, 100, 99, -128, 13, 1, 1, 0,
+            13, 100, 99, -128, 13, 1, 1, 0, 13, 100, 99, -128, 13, 1, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_min_epu8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let r = _mm512_maskz_min_epu8(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_maskz_min_epu8(k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            13, 100, 99, -128, 13, 1, 1, 0, 13, 100, 99, -128, 13, 1, 1, 0,
+            13, 100, 99, -128, 13, 1, 1, 0, 13, 100, 99, -128, 13, 1, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_min_epu16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let r = _mm512_min_epu16(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, 1, 0,
+            13, 100, 99, -32768, 13, 1, 1, 0,
+            13, 100, 99, -32768, 13, 1, 1, 0,
+            13, 100, 99, -32768, 13, 1, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_min_epu16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let r = _mm512_mask_min_epu16(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_min_epu16(a, 0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, 1, 0,
+            13, 100, 99, -32768, 13, 1, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_min_epu16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let r = _mm512_maskz_min_epu16(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_min_epu16(0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            13, 100, 99, -32768, 13, 1, 1, 0,
+            13, 100, 99, -32768, 13, 1, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_avg_epu8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_avg_epu8(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            -128, -115, -28, -105, -3, -128, -2, -127, 116, 82, 53, 67, 66, -127, 2, 1,
+            -128, -115, -28, -105, -3, -128, -2, -127, 116, 82, 53, 67, 66, -127, 2, 1,
+            -128, -115, -28, -105, -3, -128, -2, -127, 116, 82, 53, 67, 66, -127, 2, 1,
+            -128, -115, -28, -105, -3, -128, -2, -127, 116, 82, 53, 67, 66, -127, 2, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_avg_epu8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_mask_avg_epu8(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_mask_avg_epu8(a, k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            -128, -115, -28, -105, -3, -128, -2, -127, 116, 82, 53, 67, 66, -127, 2, 1,
+            -128, -115, -28, -105, -3, -128, -2, -127, 116, 82, 53, 67, 66, -127, 2, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_avg_epu8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+            7, 32, -50, 50, -3, 3, -2, 2, -32, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_maskz_avg_epu8(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_maskz_avg_epu8(k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            -128, -115, -28, -105, -3, -128, -2, -127, 116, 82, 53, 67, 66, -127, 2, 1,
+            -128, -115, -28, -105, -3, -128, -2, -127, 116, 82, 53, 67, 66, -127, 2, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_avg_epu16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_avg_epu16(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            -32768, -32755, -503, -32270, -3, -32768, -2, -32767,
+            32756, 32722, 53, 16387, 16386, -32767, 2, 1,
+            -32768, -32755, -503, -32270, -3, -32768, -2, -32767,
+            32756, 32722, 53, 16387, 16386, -32767, 2, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_avg_epu16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_mask_avg_epu16(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_avg_epu16(a, 0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            -32768, -32755, -503, -32270, -3, -32768, -2, -32767,
+            32756, 32722, 53, 16387, 16386, -32767, 2, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_avg_epu16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+            -8, -7, -6, -5, -4, -3, -2, -1, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_maskz_avg_epu16(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_avg_epu16(0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            -32768, -32755, -503, -32270, -3, -32768, -2, -32767,
+            32756, 32722, 53, 16387, 16386, -32767, 2, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_madd_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+        );
+        let r = _mm512_madd_epi16(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi32(
+            -164096, 1108544, -30000, -90600,
+            150399025, 1072741824, 1073676292, 2,
+            -164096, 1108544, -30000, -90600,
+            150399025, 1072741824, 1073676292, 2,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_madd_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+        );
+        let r = _mm512_mask_madd_epi16(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_madd_epi16(a, 0b00000000_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi32(
+            459007, 2142688, 1310785236, 19726334,
+            809106456, 65568768, 2147483647, 65536,
+            -164096, 1108544, -30000, -90600,
+            150399025, 1072741824, 1073676292, 2,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_madd_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+            7, 255, 32, -20000, 20000, -300, 300, -2,
+            12345, -1000, 1000, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+            -32768, 256, 32767, -3, 3, 300, -300, 300,
+            12345, 2000, -1000, -32768, 32767, -3, 2, 1,
+        );
+        let r = _mm512_maskz_madd_epi16(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_madd_epi16(0b00000000_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi32(
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+            -164096, 1108544, -30000, -90600,
+            150399025, 1072741824, 1073676292, 2,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maddubs_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            32, 3, 100, -56, -128, -1, 1, 0, 32, 3, 100, -56, -128, -1, 1, 0,
+            32, 3, 100, -56, -128, -1, 1, 0, 32, 3, 100, -56, -128, -1, 1, 0,
+            32, 3, 100, -56, -128, -1, 1, 0, 32, 3, 100, -56, -128, -1, 1, 0,
+            32, 3, 100, -56, -128, -1, 1, 0, 32, 3, 100, -56, -128, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            8, -3, -100, -128, 127, 127, 2, 1, 8, -3, -100, -128, 127, 127, 2, 1,
+            8, -3, -100, -128, 127, 127, 2, 1, 8, -3, -100, -128, 127, 127, 2, 1,
+            8, -3, -100, -128, 127, 127, 2, 1, 8, -3, -100, -128, 127, 127, 2, 1,
+            8, -3, -100, -128, 127, 127, 2, 1, 8, -3, -100, -128, 127, 127, 2, 1,
+        );
+        let r = _mm512_maddubs_epi16(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            247, -32768, 32767, 2, 247, -32768, 32767, 2,
+            247, -32768, 32767, 2, 247, -32768, 32767, 2,
+            247, -32768, 32767, 2, 247, -32768, 32767, 2,
+            247, -32768, 32767, 2, 247, -32768, 32767, 2,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_maddubs_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            32, 3, 100, -56, -128, -1, 1, 0, 32, 3, 100, -56, -128, -1, 1, 0,
+            32, 3, 100, -56, -128, -1, 1, 0, 32, 3, 100, -56, -128, -1, 1, 0,
+            32, 3, 100, -56, -128, -1, 1, 0, 32, 3, 100, -56, -128, -1, 1, 0,
+            32, 3, 100, -56, -128, -1, 1, 0, 32, 3, 100, -56, -128, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            8, -3, -100, -128, 127, 127, 2, 1, 8, -3, -100, -128, 127, 127, 2, 1,
+            8, -3, -100, -128, 127, 127, 2, 1, 8, -3, -100, -128, 127, 127, 2, 1,
+            8, -3, -100, -128, 127, 127, 2, 1, 8, -3, -100, -128, 127, 127, 2, 1,
+            8, -3, -100, -128, 127, 127, 2, 1, 8, -3, -100, -128, 127, 127, 2, 1,
+        );
+        let r = _mm512_mask_maddubs_epi16(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_maddubs_epi16(a, 0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            8195, 25800, -32513, 256, 8195, 25800, -32513, 256,
+            8195, 25800, -32513, 256, 8195, 25800, -32513, 256,
+            247, -32768, 32767, 2, 247, -32768, 32767, 2,
+            247, -32768, 32767, 2, 247, -32768, 32767, 2,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_maddubs_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            32, 3, 100, -56, -128, -1, 1, 0, 32, 3, 100, -56, -128, -1, 1, 0,
+            32, 3, 100, -56, -128, -1, 1, 0, 32, 3, 100, -56, -128, -1, 1, 0,
+            32, 3, 100, -56, -128, -1, 1, 0, 32, 3, 100, -56, -128, -1, 1, 0,
+            32, 3, 100, -56, -128, -1, 1, 0, 32, 3, 100, -56, -128, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            8, -3, -100, -128, 127, 127, 2, 1, 8, -3, -100, -128, 127, 127, 2, 1,
+            8, -3, -100, -128, 127, 127, 2, 1, 8, -3, -100, -128, 127, 127, 2, 1,
+            8, -3, -100, -128, 127, 127, 2, 1, 8, -3, -100, -128, 127, 127, 2, 1,
+            8, -3, -100, -128, 127, 127, 2, 1, 8, -3, -100, -128, 127, 127, 2, 1,
+        );
+        let r = _mm512_maskz_maddubs_epi16(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_maddubs_epi16(0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            247, -32768, 32767, 2, 247, -32768, 32767, 2,
+            247, -32768, 32767, 2, 247, -32768, 32767, 2,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_packs_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, -100, 100, -300, 300, 32767, -32768, 256,
+            255, -129, 128, -128, 127, -1, 1, 0,
+            7, -100, 100, -300, 300, 32767, -32768, 256,
+            255, -129, 128, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -4, 4, -8, 8, -16, 16, -64, 64,
+            -42, 42, -1000, 1000, 129, 126, -2, 2,
+            -4, 4, -8, 8, -16, 16, -64, 64,
+            -42, 42, -1000, 1000, 129, 126, -2, 2,
+        );
+        let r = _mm512_packs_epi16(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            -4, 4, -8, 8, -16, 16, -64, 64, 7, -100, 100, -128, 127, 127, -128, 127,
+            -42, 42, -128, 127, 127, 126, -2, 2, 127, -128, 127, -128, 127, -1, 1, 0,
+            -4, 4, -8, 8, -16, 16, -64, 64, 7, -100, 100, -128, 127, 127, -128, 127,
+            -42, 42, -128, 127, 127, 126, -2, 2, 127, -128, 127, -128, 127, -1, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_packs_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, -100, 100, -300, 300, 32767, -32768, 256,
+            255, -129, 128, -128, 127, -1, 1, 0,
+            7, -100, 100, -300, 300, 32767, -32768, 256,
+            255, -129, 128, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -4, 4, -8, 8, -16, 16, -64, 64,
+            -42, 42, -1000, 1000, 129, 126, -2, 2,
+            -4, 4, -8, 8, -16, 16, -64, 64,
+            -42, 42, -1000, 1000, 129, 126, -2, 2,
+        );
+        let r = _mm512_mask_packs_epi16(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_mask_packs_epi16(a, k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            0, 7, -1, -100, 0, 100, -2, -44, 1, 44, 127, -1, -128, 0, 1, 0,
+            0, -1, -1, 127, 0, -128, -1, -128, 0, 127, -1, -1, 0, 1, 0, 0,
+            -4, 4, -8, 8, -16, 16, -64, 64, 7, -100, 100, -128, 127, 127, -128, 127,
+            -42, 42, -128, 127, 127, 126, -2, 2, 127, -128, 127, -128, 127, -1, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_packs_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, -100, 100, -300, 300, 32767, -32768, 256,
+            255, -129, 128, -128, 127, -1, 1, 0,
+            7, -100, 100, -300, 300, 32767, -32768, 256,
+            255, -129, 128, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -4, 4, -8, 8, -16, 16, -64, 64,
+            -42, 42, -1000, 1000, 129, 126, -2, 2,
+            -4, 4, -8, 8, -16, 16, -64, 64,
+            -42, 42, -1000, 1000, 129, 126, -2, 2,
+        );
+        let r = _mm512_maskz_packs_epi16(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_maskz_packs_epi16(k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            -4, 4, -8, 8, -16, 16, -64, 64, 7, -100, 100, -128, 127, 127, -128, 127,
+            -42, 42, -128, 127, 127, 126, -2, 2, 127, -128, 127, -128, 127, -1, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_packs_epi32() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi32(
+            7, -100, 100, -300,
+            300, 2147483647, -2147483648, 65536,
+            65535, -32769, 32768, -32768,
+            32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi32(
+            -4, 4, -8, 8,
+            -16, 16, -64, 64,
+            -42, 42, -1000, 1000,
+            -40000, 40000, -2, 2,
+        );
+        let r = _mm512_packs_epi32(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            -4, 4, -8, 8, 7, -100, 100, -300,
+            -16, 16, -64, 64, 300, 32767, -32768, 32767,
+            -42, 42, -1000, 1000, 32767, -32768, 32767, -32768,
+            -32768, 32767, -2, 2, 32767, -1, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_packs_epi32() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi32(
+            7, -100, 100, -300,
+            300, 2147483647, -2147483648, 65536,
+            65535, -32769, 32768, -32768,
+            32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi32(
+            -4, 4, -8, 8,
+            -16, 16, -64, 64,
+            -42, 42, -1000, 1000,
+            -40000, 40000, -2, 2,
+        );
+        let r = _mm512_mask_packs_epi32(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_packs_epi32(a, 0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 7, -1, -100, 0, 100, -1, -300,
+            0, 300, 32767, -1, -32768, 0, 1, 0,
+            -42, 42, -1000, 1000, 32767, -32768, 32767, -32768,
+            -32768, 32767, -2, 2, 32767, -1, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_packs_epi32() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi32(
+            7, -100, 100, -300,
+            300, 2147483647, -2147483648, 65536,
+            65535, -32769, 32768, -32768,
+            32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi32(
+            -4, 4, -8, 8,
+            -16, 16, -64, 64,
+            -42, 42, -1000, 1000,
+            -40000, 40000, -2, 2,
+        );
+        let r = _mm512_maskz_packs_epi32(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_packs_epi32(0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            -42, 42, -1000, 1000, 32767, -32768, 32767, -32768,
+            -32768, 32767, -2, 2, 32767, -1, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_packus_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, -100, 100, -300, 300, 32767, -32768, 256,
+            255, -129, 128, -128, 127, -1, 1, 0,
+            7, -100, 100, -300, 300, 32767, -32768, 256,
+            255, -129, 128, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -4, 4, -8, 8, -16, 16, -64, 64,
+            -42, 42, -1000, 1000, 129, 126, -2, 2,
+            -4, 4, -8, 8, -16, 16, -64, 64,
+            -42, 42, -1000, 1000, 129, 126, -2, 2,
+        );
+        let r = _mm512_packus_epi16(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            0, 4, 0, 8, 0, 16, 0, 64, 7, 0, 100, 0, -1, -1, 0, -1,
+            0, 42, 0, -1, -127, 126, 0, 2, -1, 0, -128, 0, 127, 0, 1, 0,
+            0, 4, 0, 8, 0, 16, 0, 64, 7, 0, 100, 0, -1, -1, 0, -1,
+            0, 42, 0, -1, -127, 126, 0, 2, -1, 0, -128, 0, 127, 0, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_packus_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, -100, 100, -300, 300, 32767, -32768, 256,
+            255, -129, 128, -128, 127, -1, 1, 0,
+            7, -100, 100, -300, 300, 32767, -32768, 256,
+            255, -129, 128, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -4, 4, -8, 8, -16, 16, -64, 64,
+            -42, 42, -1000, 1000, 129, 126, -2, 2,
+            -4, 4, -8, 8, -16, 16, -64, 64,
+            -42, 42, -1000, 1000, 129, 126, -2, 2,
+        );
+        let r = _mm512_mask_packus_epi16(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_mask_packus_epi16(a, k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            0, 7, -1, -100, 0, 100, -2, -44, 1, 44, 127, -1, -128, 0, 1, 0,
+            0, -1, -1, 127, 0, -128, -1, -128, 0, 127, -1, -1, 0, 1, 0, 0,
+            0, 4, 0, 8, 0, 16, 0, 64, 7, 0, 100, 0, -1, -1, 0, -1,
+            0, 42, 0, -1, -127, 126, 0, 2, -1, 0, -128, 0, 127, 0, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_packus_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, -100, 100, -300, 300, 32767, -32768, 256,
+            255, -129, 128, -128, 127, -1, 1, 0,
+            7, -100, 100, -300, 300, 32767, -32768, 256,
+            255, -129, 128, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            -4, 4, -8, 8, -16, 16, -64, 64,
+            -42, 42, -1000, 1000, 129, 126, -2, 2,
+            -4, 4, -8, 8, -16, 16, -64, 64,
+            -42, 42, -1000, 1000, 129, 126, -2, 2,
+        );
+        let r = _mm512_maskz_packus_epi16(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_maskz_packus_epi16(k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 4, 0, 8, 0, 16, 0, 64, 7, 0, 100, 0, -1, -1, 0, -1,
+            0, 42, 0, -1, -127, 126, 0, 2, -1, 0, -128, 0, 127, 0, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_packus_epi32() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi32(
+            7, -100, 100, -300,
+            300, 2147483647, -2147483648, 65536,
+            65535, -32769, 32768, -32768,
+            32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi32(
+            -4, 4, -8, 8,
+            -16, 16, -64, 64,
+            -42, 42, -1000, 1000,
+            -40000, 40000, -2, 2,
+        );
+        let r = _mm512_packus_epi32(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 4, 0, 8, 7, 0, 100, 0,
+            0, 16, 0, 64, 300, -1, 0, -1,
+            0, 42, 0, 1000, -1, 0, -32768, 0,
+            0, -25536, 0, 2, 32767, 0, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_packus_epi32() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi32(
+            7, -100, 100, -300,
+            300, 2147483647, -2147483648, 65536,
+            65535, -32769, 32768, -32768,
+            32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi32(
+            -4, 4, -8, 8,
+            -16, 16, -64, 64,
+            -42, 42, -1000, 1000,
+            -40000, 40000, -2, 2,
+        );
+        let r = _mm512_mask_packus_epi32(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_packus_epi32(a, 0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 7, -1, -100, 0, 100, -1, -300,
+            0, 300, 32767, -1, -32768, 0, 1, 0,
+            0, 42, 0, 1000, -1, 0, -32768, 0,
+            0, -25536, 0, 2, 32767, 0, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_packus_epi32() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi32(
+            7, -100, 100, -300,
+            300, 2147483647, -2147483648, 65536,
+            65535, -32769, 32768, -32768,
+            32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi32(
+            -4, 4, -8, 8,
+            -16, 16, -64, 64,
+            -42, 42, -1000, 1000,
+            -40000, 40000, -2, 2,
+        );
+        let r = _mm512_maskz_packus_epi32(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_packus_epi32(0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 42, 0, 1000, -1, 0, -32768, 0,
+            0, -25536, 0, 2, 32767, 0, 1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_shuffle_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            63, 62, 61, 60, 59, 58, 57, 56, 55, 54, 53, 52, 51, 50, 49, 48,
+            47, 46, 45, 44, 43, 42, 41, 40, 39, 38, 37, 36, 35, 34, 33, 32,
+            31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17, 16,
+            15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -128, 6, 1, 12, 7, 2, 13, 8, -128, 14, 9, 4, 15, 10, 5, 0,
+            -128, 6, 1, 12, 7, 2, 13, 8, -128, 14, 9, 4, 15, 10, 5, 0,
+            -128, 6, 1, 12, 7, 2, 13, 8, -128, 14, 9, 4, 15, 10, 5, 0,
+            -128, 6, 1, 12, 7, 2, 13, 8, -128, 14, 9, 4, 15, 10, 5, 0,
+        );
+        let r = _mm512_shuffle_epi8(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            0, 54, 49, 60, 55, 50, 61, 56, 0, 62, 57, 52, 63, 58, 53, 48,
+            0, 38, 33, 44, 39, 34, 45, 40, 0, 46, 41, 36, 47, 42, 37, 32,
+            0, 22, 17, 28, 23, 18, 29, 24, 0, 30, 25, 20, 31, 26, 21, 16,
+            0, 6, 1, 12, 7, 2, 13, 8, 0, 14, 9, 4, 15, 10, 5, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_shuffle_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            63, 62, 61, 60, 59, 58, 57, 56, 55, 54, 53, 52, 51, 50, 49, 48,
+            47, 46, 45, 44, 43, 42, 41, 40, 39, 38, 37, 36, 35, 34, 33, 32,
+            31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17, 16,
+            15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -128, 6, 1, 12, 7, 2, 13, 8, -128, 14, 9, 4, 15, 10, 5, 0,
+            -128, 6, 1, 12, 7, 2, 13, 8, -128, 14, 9, 4, 15, 10, 5, 0,
+            -128, 6, 1, 12, 7, 2, 13, 8, -128, 14, 9, 4, 15, 10, 5, 0,
+            -128, 6, 1, 12, 7, 2, 13, 8, -128, 14, 9, 4, 15, 10, 5, 0,
+        );
+        let r = _mm512_mask_shuffle_epi8(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_mask_shuffle_epi8(a, k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            63, 62, 61, 60, 59, 58, 57, 56, 55, 54, 53, 52, 51, 50, 49, 48,
+            47, 46, 45, 44, 43, 42, 41, 40, 39, 38, 37, 36, 35, 34, 33, 32,
+            0, 22, 17, 28, 23, 18, 29, 24, 0, 30, 25, 20, 31, 26, 21, 16,
+            0, 6, 1, 12, 7, 2, 13, 8, 0, 14, 9, 4, 15, 10, 5, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_shuffle_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            63, 62, 61, 60, 59, 58, 57, 56, 55, 54, 53, 52, 51, 50, 49, 48,
+            47, 46, 45, 44, 43, 42, 41, 40, 39, 38, 37, 36, 35, 34, 33, 32,
+            31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17, 16,
+            15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -128, 6, 1, 12, 7, 2, 13, 8, -128, 14, 9, 4, 15, 10, 5, 0,
+            -128, 6, 1, 12, 7, 2, 13, 8, -128, 14, 9, 4, 15, 10, 5, 0,
+            -128, 6, 1, 12, 7, 2, 13, 8, -128, 14, 9, 4, 15, 10, 5, 0,
+            -128, 6, 1, 12, 7, 2, 13, 8, -128, 14, 9, 4, 15, 10, 5, 0,
+        );
+        let r = _mm512_maskz_shuffle_epi8(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_maskz_shuffle_epi8(k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 22, 17, 28, 23, 18, 29, 24, 0, 30, 25, 20, 31, 26, 21, 16,
+            0, 6, 1, 12, 7, 2, 13, 8, 0, 14, 9, 4, 15, 10, 5, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_sad_epu8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            30, 20, 10, -128, -1, 0, 4, 2, 30, 20, 10, -128, -1, 0, 4, 2,
+            30, 20, 10, -128, -1, 0, 4, 2, 30, 20, 10, -128, -1, 0, 4, 2,
+            30, 20, 10, -128, -1, 0, 4, 2, 30, 20, 10, -128, -1, 0, 4, 2,
+            30, 20, 10, -128, -1, 0, 4, 2, 30, 20, 10, -128, -1, 0, 4, 2,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            0, 10, 20, -128, 0, -1, 2, 4, 0, 10, 20, -128, 0, -1, 2, 4,
+            0, 10, 20, -128, 0, -1, 2, 4, 0, 10, 20, -128, 0, -1, 2, 4,
+            0, 10, 20, -128, 0, -1, 2, 4, 0, 10, 20, -128, 0, -1, 2, 4,
+            0, 10, 20, -128, 0, -1, 2, 4, 0, 10, 20, -128, 0, -1, 2, 4,
+        );
+        let r = _mm512_sad_epu8(a, b);
+        let e = _mm512_set1_epi64(564);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_dbsad_epu8() {
+        let a = _mm512_set1_epi8(2);
+        let b = _mm512_set1_epi8(4);
+        let r = _mm512_dbsad_epu8(a, b, 0);
+        let e = _mm512_set1_epi16(8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_dbsad_epu8() {
+        let a = _mm512_set1_epi8(2);
+        let b = _mm512_set1_epi8(4);
+        let r = _mm512_mask_dbsad_epu8(a, 0, a, b, 0);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_dbsad_epu8(a, 0b00000000_00000000_11111111_11111111, a, b, 0);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            514, 514, 514, 514, 514, 514, 514, 514, 514, 514, 514, 514, 514, 514, 514, 514,
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_dbsad_epu8() {
+        let a = _mm512_set1_epi8(2);
+        let b = _mm512_set1_epi8(4);
+        let r = _mm512_maskz_dbsad_epu8(0, a, b, 0);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_dbsad_epu8(0b00000000_00000000_11111111_11111111, a, b, 0);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_slli_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        let r = _mm512_slli_epi16(a, 2);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            28, 128, -4000, 4000, -12, 12, -8, 8,
+            -128, -400, 400, 0, -4, -4, 4, 0,
+            28, 128, -4000, 4000, -12, 12, -8, 8,
+            -128, -400, 400, 0, -4, -4, 4, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_slli_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        let r = _mm512_mask_slli_epi16(a, 0, a, 2);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_slli_epi16(a, 0b00000000_00000000_11111111_11111111, a, 2);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            28, 128, -4000, 4000, -12, 12, -8, 8,
+            -128, -400, 400, 0, -4, -4, 4, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_slli_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        let r = _mm512_maskz_slli_epi16(0, a, 2);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_slli_epi16(0b00000000_00000000_11111111_11111111, a, 2);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            28, 128, -4000, 4000, -12, 12, -8, 8,
+            -128, -400, 400, 0, -4, -4, 4, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_sll_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        let r = _mm512_sll_epi16(a, _mm_set_epi64x(0, 3));
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            56, 256, -8000, 8000, -24, 24, -16, 16,
+            -256, -800, 800, 0, -8, -8, 8, 0,
+            56, 256, -8000, 8000, -24, 24, -16, 16,
+            -256, -800, 800, 0, -8, -8, 8, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_sll_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        let r = _mm512_mask_sll_epi16(a, 0, a, _mm_set_epi64x(0, 3));
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_sll_epi16(
+            a,
+            0b00000000_00000000_11111111_11111111,
+            a,
+            _mm_set_epi64x(0, 3),
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            56, 256, -8000, 8000, -24, 24, -16, 16,
+            -256, -800, 800, 0, -8, -8, 8, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_sll_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        let r = _mm512_maskz_sll_epi16(0, a, _mm_set_epi64x(0, 3));
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_sll_epi16(
+            0b00000000_00000000_11111111_11111111,
+            a,
+            _mm_set_epi64x(0, 3),
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            56, 256, -8000, 8000, -24, 24, -16, 16,
+            -256, -800, 800, 0, -8, -8, 8, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_sllv_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let count = _mm512_set_epi16(
+            1000, 100, 9, 10, 11, 12, 13, 14, 15, 5, 4, 3, 2, 1, 17, 16,
+            15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0,
+        );
+        let r = _mm512_sllv_epi16(a, count);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 12288, -24576, -6144, 12288, -16384, -32768,
+            0, -3200, 1600, 0, -4, -2, 0, 0,
+            -32768, 0, 0, -32768, -6144, 3072, -1024, 512,
+            -4096, -6400, 3200, 0, -8, -4, 2, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_sllv_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let count = _mm512_set_epi16(
+            1000, 100, 9, 10, 11, 12, 13, 14, 15, 5, 4, 3, 2, 1, 17, 16,
+            15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0,
+        );
+        let r = _mm512_mask_sllv_epi16(a, 0, a, count);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_sllv_epi16(a, 0b00000000_00000000_11111111_11111111, a, count);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            -32768, 0, 0, -32768, -6144, 3072, -1024, 512,
+            -4096, -6400, 3200, 0, -8, -4, 2, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_sllv_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let count = _mm512_set_epi16(
+            1000, 100, 9, 10, 11, 12, 13, 14, 15, 5, 4, 3, 2, 1, 17, 16,
+            15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0,
+        );
+        let r = _mm512_maskz_sllv_epi16(0, a, count);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_sllv_epi16(0b00000000_00000000_11111111_11111111, a, count);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            -32768, 0, 0, -32768, -6144, 3072, -1024, 512,
+            -4096, -6400, 3200, 0, -8, -4, 2, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_srli_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        let r = _mm512_srli_epi16(a, 2);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            1, 8, 16134, 250, 16383, 0, 16383, 0,
+            16376, 16359, 25, 8192, 8191, 16383, 0, 0,
+            1, 8, 16134, 250, 16383, 0, 16383, 0,
+            16376, 16359, 25, 8192, 8191, 16383, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_srli_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        let r = _mm512_mask_srli_epi16(a, 0, a, 2);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_srli_epi16(a, 0b00000000_00000000_11111111_11111111, a, 2);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            1, 8, 16134, 250, 16383, 0, 16383, 0,
+            16376, 16359, 25, 8192, 8191, 16383, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_srli_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        let r = _mm512_maskz_srli_epi16(0, a, 2);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_srli_epi16(0b00000000_00000000_11111111_11111111, a, 2);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            1, 8, 16134, 250, 16383, 0, 16383, 0,
+            16376, 16359, 25, 8192, 8191, 16383, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_srl_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        let r = _mm512_srl_epi16(a, _mm_set_epi64x(0, 3));
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 4, 8067, 125, 8191, 0, 8191, 0, 8188, 8179, 12, 4096, 4095, 8191, 0, 0,
+            0, 4, 8067, 125, 8191, 0, 8191, 0, 8188, 8179, 12, 4096, 4095, 8191, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_srl_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        let r = _mm512_mask_srl_epi16(a, 0, a, _mm_set_epi64x(0, 3));
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_srl_epi16(
+            a,
+            0b00000000_00000000_11111111_11111111,
+            a,
+            _mm_set_epi64x(0, 3),
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            0, 4, 8067, 125, 8191, 0, 8191, 0,
+            8188, 8179, 12, 4096, 4095, 8191, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_srl_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        let r = _mm512_maskz_srl_epi16(0, a, _mm_set_epi64x(0, 3));
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_srl_epi16(
+            0b00000000_00000000_11111111_11111111,
+            a,
+            _mm_set_epi64x(0, 3),
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 4, 8067, 125, 8191, 0, 8191, 0, 8188, 8179, 12, 4096, 4095, 8191, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_srlv_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let count = _mm512_set_epi16(
+            1000, 100, 9, 10, 11, 12, 13, 14, 15, 5, 4, 3, 2, 1, 17, 16,
+            15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0,
+        );
+        let r = _mm512_srlv_epi16(a, count);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 126, 0, 31, 0, 7, 0,
+            1, 2044, 6, 4096, 8191, 32767, 0, 0,
+            0, 0, 7, 0, 31, 0, 127, 0,
+            511, 1022, 3, 2048, 4095, 16383, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_srlv_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let count = _mm512_set_epi16(
+            1000, 100, 9, 10, 11, 12, 13, 14, 15, 5, 4, 3, 2, 1, 17, 16,
+            15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0,
+        );
+        let r = _mm512_mask_srlv_epi16(a, 0, a, count);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_srlv_epi16(a, 0b00000000_00000000_11111111_11111111, a, count);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            0, 0, 7, 0, 31, 0, 127, 0,
+            511, 1022, 3, 2048, 4095, 16383, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_srlv_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let count = _mm512_set_epi16(
+            1000, 100, 9, 10, 11, 12, 13, 14, 15, 5, 4, 3, 2, 1, 17, 16,
+            15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0,
+        );
+        let r = _mm512_maskz_srlv_epi16(0, a, count);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_srlv_epi16(0b00000000_00000000_11111111_11111111, a, count);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 7, 0, 31, 0, 127, 0,
+            511, 1022, 3, 2048, 4095, 16383, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_srai_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        let r = _mm512_srai_epi16(a, 2);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            1, 8, -250, 250, -1, 0, -1, 0,
+            -8, -25, 25, -8192, 8191, -1, 0, 0,
+            1, 8, -250, 250, -1, 0, -1, 0,
+            -8, -25, 25, -8192, 8191, -1, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_srai_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        let r = _mm512_mask_srai_epi16(a, 0, a, 2);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_srai_epi16(a, 0b00000000_00000000_11111111_11111111, a, 2);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            1, 8, -250, 250, -1, 0, -1, 0,
+            -8, -25, 25, -8192, 8191, -1, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_srai_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        let r = _mm512_maskz_srai_epi16(0, a, 2);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_srai_epi16(0b00000000_00000000_11111111_11111111, a, 2);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            1, 8, -250, 250, -1, 0, -1, 0,
+            -8, -25, 25, -8192, 8191, -1, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_sra_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        let r = _mm512_sra_epi16(a, _mm_set_epi64x(0, 3));
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 4, -125, 125, -1, 0, -1, 0,
+            -4, -13, 12, -4096, 4095, -1, 0, 0,
+            0, 4, -125, 125, -1, 0, -1, 0,
+            -4, -13, 12, -4096, 4095, -1, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_sra_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        let r = _mm512_mask_sra_epi16(a, 0, a, _mm_set_epi64x(0, 3));
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_sra_epi16(
+            a,
+            0b00000000_00000000_11111111_11111111,
+            a,
+            _mm_set_epi64x(0, 3),
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            0, 4, -125, 125, -1, 0, -1, 0,
+            -4, -13, 12, -4096, 4095, -1, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_sra_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        let r = _mm512_maskz_sra_epi16(0, a, _mm_set_epi64x(0, 3));
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_sra_epi16(
+            0b00000000_00000000_11111111_11111111,
+            a,
+            _mm_set_epi64x(0, 3),
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 4, -125, 125, -1, 0, -1, 0,
+            -4, -13, 12, -4096, 4095, -1, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_srav_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let count = _mm512_set_epi16(
+            1000, 100, 9, 10, 11, 12, 13, 14, 15, 5, 4, 3, 2, 1, 17, 16,
+            15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0,
+        );
+        let r = _mm512_srav_epi16(a, count);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, -2, 0, -1, 0, -1, 0,
+            -1, -4, 6, -4096, 8191, -1, 0, 0,
+            0, 0, -1, 0, -1, 0, -1, 0,
+            -1, -2, 3, -2048, 4095, -1, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_srav_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let count = _mm512_set_epi16(
+            1000, 100, 9, 10, 11, 12, 13, 14, 15, 5, 4, 3, 2, 1, 17, 16,
+            15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0,
+        );
+        let r = _mm512_mask_srav_epi16(a, 0, a, count);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_srav_epi16(a, 0b00000000_00000000_11111111_11111111, a, count);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            0, 0, -1, 0, -1, 0, -1, 0,
+            -1, -2, 3, -2048, 4095, -1, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_srav_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+            7, 32, -1000, 1000, -3, 3, -2, 2,
+            -32, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let count = _mm512_set_epi16(
+            1000, 100, 9, 10, 11, 12, 13, 14, 15, 5, 4, 3, 2, 1, 17, 16,
+            15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0,
+        );
+        let r = _mm512_maskz_srav_epi16(0, a, count);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_srav_epi16(0b00000000_00000000_11111111_11111111, a, count);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, -1, 0, -1, 0, -1, 0,
+            -1, -2, 3, -2048, 4095, -1, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_blend_epi8() {
+        let a = _mm512_set1_epi8(1);
+        let b = _mm512_set1_epi8(2);
+        let k: __mmask64 =
+            0b00000000_00000000_00000000_00000000_11111111_11111111_11111111_11111111;
+        let r = _mm512_mask_blend_epi8(k, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_blend_epi8() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+            16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            -1, -2, -3, -4, -5, -6, -7, -8, -9, -10, -11, -12, -13, -14, -15, -16,
+            -17, -18, -19, -20, -21, -22, -23, -24, -25, -26, -27, -28, -29, -30, -31, -32,
+        );
+        let r = _mm256_mask_blend_epi8(0b01101001_10010110_11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            -1, 1, -3, 3, -5, -6, 6, 7, 8, -10, 10, -12, 12, 13, -15, -16,
+            16, -18, -19, 19, -21, 21, 22, -24, -25, 25, 26, -28, 28, -30, -31, 31,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_blend_epi8() {
+        let a = _mm_setr_epi8(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            -1, -2, -3, -4, -5, -6, -7, -8,
+            -9, -10, -11, -12, -13, -14, -15, -16,
+        );
+        let r = _mm_mask_blend_epi8(0b11001010_00110101, a, b);
+        let e = _mm_setr_epi8(
+            -1, 1, -3, 3, -5, -6, 6, 7, 8, -10, 10, -12, 12, 13, -15, -16,
+        );
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_blend_epi16() {
+        let a = _mm512_set1_epi16(1);
+        let b = _mm512_set1_epi16(2);
+        let r = _mm512_mask_blend_epi16(0b00000000_00000000_11111111_11111111, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_blend_epi16() {
+        let a = _mm256_setr_epi16(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            -1, -2, -3, -4, -5, -6, -7, -8,
+            -9, -10, -11, -12, -13, -14, -15, -16,
+        );
+        let r = _mm256_mask_blend_epi16(0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi16(
+            -1, 1, -3, 3, -5, -6, 6, 7,
+            8, -10, 10, -12, 12, 13, -15, -16,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_blend_epi16() {
+        let a = _mm_setr_epi16(0, 1, 2, 3, 4, 5, 6, 7);
+        let b = _mm_setr_epi16(-1, -2, -3, -4, -5, -6, -7, -8);
+        let r = _mm_mask_blend_epi16(0b00110101, a, b);
+        let e = _mm_setr_epi16(-1, 1, -3, 3, -5, -6, 6, 7);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmp_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmp_epi8_mask(a, b, _MM_CMPINT_LT);
+        assert_eq!(
+            m,
+            0b00101010_01000100_00101010_01000100_00101010_01000100_00101010_01000100
+        );
+        let m = _mm512_cmp_epi8_mask(a, b, _MM_CMPINT_NE);
+        assert_eq!(
+            m,
+            0b01101110_01101110_01101110_01101110_01101110_01101110_01101110_01101110
+        );
+        let m = _mm512_cmp_epi8_mask(a, b, _MM_CMPINT_NLE);
+        assert_eq!(
+            m,
+            0b01000100_00101010_01000100_00101010_01000100_00101010_01000100_00101010
+        );
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmp_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010_01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmp_epi8_mask(mask, a, b, _MM_CMPINT_LT);
+        assert_eq!(
+            r,
+            0b00101010_01000000_00101010_01000000_00101010_01000000_00101010_01000000
+        );
+        let r = _mm512_mask_cmp_epi8_mask(mask, a, b, _MM_CMPINT_NE);
+        assert_eq!(
+            r,
+            0b01101010_01101010_01101010_01101010_01101010_01101010_01101010_01101010
+        );
+        let r = _mm512_mask_cmp_epi8_mask(mask, a, b, _MM_CMPINT_NLE);
+        assert_eq!(
+            r,
+            0b01000000_00101010_01000000_00101010_01000000_00101010_01000000_00101010
+        );
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmp_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let m = _mm256_cmp_epi8_mask(a, b, _MM_CMPINT_LT);
+        assert_eq!(m, 0b00101010_01000100_00101010_01000100);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmp_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let k1 = 0b01101001_10010110_11001010_00110101;
+        let r = _mm256_mask_cmp_epi8_mask(k1, a, b, _MM_CMPINT_LT);
+        assert_eq!(r, 0b00101000_00000100_00001010_00000100);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmp_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let m = _mm_cmp_epi8_mask(a, b, _MM_CMPINT_LT);
+        assert_eq!(m, 0b00101010_01000100);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmp_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm_mask_cmp_epi8_mask(k1, a, b, _MM_CMPINT_LT);
+        assert_eq!(r, 0b00001010_00000100);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmpeq_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmpeq_epi8_mask(a, b);
+        assert_eq!(
+            m,
+            0b10010001_10010001_10010001_10010001_10010001_10010001_10010001_10010001
+        );
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmpeq_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010_01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmpeq_epi8_mask(mask, a, b);
+        assert_eq!(
+            r,
+            0b00010000_00010000_00010000_00010000_00010000_00010000_00010000_00010000
+        );
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmpeq_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let m = _mm256_cmpeq_epi8_mask(a, b);
+        assert_eq!(m, 0b10010001_10011001_10010001_10011001);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmpeq_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let k1 = 0b01101001_10010110_11001010_00110101;
+        let r = _mm256_mask_cmpeq_epi8_mask(k1, a, b);
+        assert_eq!(r, 0b00000001_10010000_10000000_00010001);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmpeq_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let m = _mm_cmpeq_epi8_mask(a, b);
+        assert_eq!(m, 0b10010001_10011001);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmpeq_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm_mask_cmpeq_epi8_mask(k1, a, b);
+        assert_eq!(r, 0b10000000_00010001);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmpge_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmpge_epi8_mask(a, b);
+        assert_eq!(
+            m,
+            0b11010101_10111011_11010101_10111011_11010101_10111011_11010101_10111011
+        );
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmpge_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010_01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmpge_epi8_mask(mask, a, b);
+        assert_eq!(
+            r,
+            0b01010000_00111010_01010000_00111010_01010000_00111010_01010000_00111010
+        );
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmpge_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let m = _mm256_cmpge_epi8_mask(a, b);
+        assert_eq!(m, 0b11010101_10111011_11010101_10111011);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmpge_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let k1 = 0b01101001_10010110_11001010_00110101;
+        let r = _mm256_mask_cmpge_epi8_mask(k1, a, b);
+        assert_eq!(r, 0b01000001_10010010_11000000_00110001);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmpge_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let m = _mm_cmpge_epi8_mask(a, b);
+        assert_eq!(m, 0b11010101_10111011);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmpge_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm_mask_cmpge_epi8_mask(k1, a, b);
+        assert_eq!(r, 0b11000000_00110001);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmpgt_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmpgt_epi8_mask(a, b);
+        assert_eq!(
+            m,
+            0b01000100_00101010_01000100_00101010_01000100_00101010_01000100_00101010
+        );
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmpgt_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010_01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmpgt_epi8_mask(mask, a, b);
+        assert_eq!(
+            r,
+            0b01000000_00101010_01000000_00101010_01000000_00101010_01000000_00101010
+        );
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmpgt_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let m = _mm256_cmpgt_epi8_mask(a, b);
+        assert_eq!(m, 0b01000100_00100010_01000100_00100010);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmpgt_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let k1 = 0b01101001_10010110_11001010_00110101;
+        let r = _mm256_mask_cmpgt_epi8_mask(k1, a, b);
+        assert_eq!(r, 0b01000000_00000010_01000000_00100000);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmpgt_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let m = _mm_cmpgt_epi8_mask(a, b);
+        assert_eq!(m, 0b01000100_00100010);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmpgt_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm_mask_cmpgt_epi8_mask(k1, a, b);
+        assert_eq!(r, 0b01000000_00100000);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmple_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmple_epi8_mask(a, b);
+        assert_eq!(
+            m,
+            0b10111011_11010101_10111011_11010101_10111011_11010101_10111011_11010101
+        );
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmple_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010_01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmple_epi8_mask(mask, a, b);
+        assert_eq!(
+            r,
+            0b00111010_01010000_00111010_01010000_00111010_01010000_00111010_01010000
+        );
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmple_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let m = _mm256_cmple_epi8_mask(a, b);
+        assert_eq!(m, 0b10111011_11011101_10111011_11011101);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmple_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let k1 = 0b01101001_10010110_11001010_00110101;
+        let r = _mm256_mask_cmple_epi8_mask(k1, a, b);
+        assert_eq!(r, 0b00101001_10010100_10001010_00010101);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmple_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let m = _mm_cmple_epi8_mask(a, b);
+        assert_eq!(m, 0b10111011_11011101);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmple_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm_mask_cmple_epi8_mask(k1, a, b);
+        assert_eq!(r, 0b10001010_00010101);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmplt_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmplt_epi8_mask(a, b);
+        assert_eq!(
+            m,
+            0b00101010_01000100_00101010_01000100_00101010_01000100_00101010_01000100
+        );
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmplt_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010_01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmplt_epi8_mask(mask, a, b);
+        assert_eq!(
+            r,
+            0b00101010_01000000_00101010_01000000_00101010_01000000_00101010_01000000
+        );
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmplt_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let m = _mm256_cmplt_epi8_mask(a, b);
+        assert_eq!(m, 0b00101010_01000100_00101010_01000100);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmplt_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let k1 = 0b01101001_10010110_11001010_00110101;
+        let r = _mm256_mask_cmplt_epi8_mask(k1, a, b);
+        assert_eq!(r, 0b00101000_00000100_00001010_00000100);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmplt_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let m = _mm_cmplt_epi8_mask(a, b);
+        assert_eq!(m, 0b00101010_01000100);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmplt_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm_mask_cmplt_epi8_mask(k1, a, b);
+        assert_eq!(r, 0b00001010_00000100);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmpneq_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmpneq_epi8_mask(a, b);
+        assert_eq!(
+            m,
+            0b01101110_01101110_01101110_01101110_01101110_01101110_01101110_01101110
+        );
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmpneq_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010_01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmpneq_epi8_mask(mask, a, b);
+        assert_eq!(
+            r,
+            0b01101010_01101010_01101010_01101010_01101010_01101010_01101010_01101010
+        );
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmpneq_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let m = _mm256_cmpneq_epi8_mask(a, b);
+        assert_eq!(m, 0b01101110_01100110_01101110_01100110);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmpneq_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let k1 = 0b01101001_10010110_11001010_00110101;
+        let r = _mm256_mask_cmpneq_epi8_mask(k1, a, b);
+        assert_eq!(r, 0b01101000_00000110_01001010_00100100);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmpneq_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let m = _mm_cmpneq_epi8_mask(a, b);
+        assert_eq!(m, 0b01101110_01100110);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmpneq_epi8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm_mask_cmpneq_epi8_mask(k1, a, b);
+        assert_eq!(r, 0b01001010_00100100);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmp_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmp_epi16_mask(a, b, _MM_CMPINT_LT);
+        assert_eq!(m, 0b00101010_01000100_00101010_01000100);
+        let m = _mm512_cmp_epi16_mask(a, b, _MM_CMPINT_NE);
+        assert_eq!(m, 0b01101110_01101110_01101110_01101110);
+        let m = _mm512_cmp_epi16_mask(a, b, _MM_CMPINT_NLE);
+        assert_eq!(m, 0b01000100_00101010_01000100_00101010);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmp_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmp_epi16_mask(mask, a, b, _MM_CMPINT_LT);
+        assert_eq!(r, 0b00101010_01000000_00101010_01000000);
+        let r = _mm512_mask_cmp_epi16_mask(mask, a, b, _MM_CMPINT_NE);
+        assert_eq!(r, 0b01101010_01101010_01101010_01101010);
+        let r = _mm512_mask_cmp_epi16_mask(mask, a, b, _MM_CMPINT_NLE);
+        assert_eq!(r, 0b01000000_00101010_01000000_00101010);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmp_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let m = _mm256_cmp_epi16_mask(a, b, _MM_CMPINT_LT);
+        assert_eq!(m, 0b00101010_01000100);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmp_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm256_mask_cmp_epi16_mask(k1, a, b, _MM_CMPINT_LT);
+        assert_eq!(r, 0b00001010_00000100);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmp_epi16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let m = _mm_cmp_epi16_mask(a, b, _MM_CMPINT_LT);
+        assert_eq!(m, 0b01000100);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmp_epi16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let k1 = 0b00110101;
+        let r = _mm_mask_cmp_epi16_mask(k1, a, b, _MM_CMPINT_LT);
+        assert_eq!(r, 0b00000100);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmpeq_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmpeq_epi16_mask(a, b);
+        assert_eq!(m, 0b10010001_10010001_10010001_10010001);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmpeq_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmpeq_epi16_mask(mask, a, b);
+        assert_eq!(r, 0b00010000_00010000_00010000_00010000);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmpeq_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let m = _mm256_cmpeq_epi16_mask(a, b);
+        assert_eq!(m, 0b10010001_10011001);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmpeq_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm256_mask_cmpeq_epi16_mask(k1, a, b);
+        assert_eq!(r, 0b10000000_00010001);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmpeq_epi16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let m = _mm_cmpeq_epi16_mask(a, b);
+        assert_eq!(m, 0b10011001);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmpeq_epi16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let k1 = 0b00110101;
+        let r = _mm_mask_cmpeq_epi16_mask(k1, a, b);
+        assert_eq!(r, 0b00010001);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmpge_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmpge_epi16_mask(a, b);
+        assert_eq!(m, 0b11010101_10111011_11010101_10111011);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmpge_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmpge_epi16_mask(mask, a, b);
+        assert_eq!(r, 0b01010000_00111010_01010000_00111010);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmpge_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let m = _mm256_cmpge_epi16_mask(a, b);
+        assert_eq!(m, 0b11010101_10111011);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmpge_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm256_mask_cmpge_epi16_mask(k1, a, b);
+        assert_eq!(r, 0b11000000_00110001);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmpge_epi16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let m = _mm_cmpge_epi16_mask(a, b);
+        assert_eq!(m, 0b10111011);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmpge_epi16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let k1 = 0b00110101;
+        let r = _mm_mask_cmpge_epi16_mask(k1, a, b);
+        assert_eq!(r, 0b00110001);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmpgt_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmpgt_epi16_mask(a, b);
+        assert_eq!(m, 0b01000100_00101010_01000100_00101010);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmpgt_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmpgt_epi16_mask(mask, a, b);
+        assert_eq!(r, 0b01000000_00101010_01000000_00101010);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmpgt_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let m = _mm256_cmpgt_epi16_mask(a, b);
+        assert_eq!(m, 0b01000100_00100010);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmpgt_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm256_mask_cmpgt_epi16_mask(k1, a, b);
+        assert_eq!(r, 0b01000000_00100000);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmpgt_epi16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let m = _mm_cmpgt_epi16_mask(a, b);
+        assert_eq!(m, 0b00100010);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmpgt_epi16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let k1 = 0b00110101;
+        let r = _mm_mask_cmpgt_epi16_mask(k1, a, b);
+        assert_eq!(r, 0b00100000);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmple_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmple_epi16_mask(a, b);
+        assert_eq!(m, 0b10111011_11010101_10111011_11010101);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmple_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmple_epi16_mask(mask, a, b);
+        assert_eq!(r, 0b00111010_01010000_00111010_01010000);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmple_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let m = _mm256_cmple_epi16_mask(a, b);
+        assert_eq!(m, 0b10111011_11011101);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmple_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm256_mask_cmple_epi16_mask(k1, a, b);
+        assert_eq!(r, 0b10001010_00010101);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmple_epi16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let m = _mm_cmple_epi16_mask(a, b);
+        assert_eq!(m, 0b11011101);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmple_epi16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let k1 = 0b00110101;
+        let r = _mm_mask_cmple_epi16_mask(k1, a, b);
+        assert_eq!(r, 0b00010101);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmplt_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmplt_epi16_mask(a, b);
+        assert_eq!(m, 0b00101010_01000100_00101010_01000100);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmplt_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmplt_epi16_mask(mask, a, b);
+        assert_eq!(r, 0b00101010_01000000_00101010_01000000);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmplt_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let m = _mm256_cmplt_epi16_mask(a, b);
+        assert_eq!(m, 0b00101010_01000100);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmplt_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm256_mask_cmplt_epi16_mask(k1, a, b);
+        assert_eq!(r, 0b00001010_00000100);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmplt_epi16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let m = _mm_cmplt_epi16_mask(a, b);
+        assert_eq!(m, 0b01000100);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmplt_epi16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let k1 = 0b00110101;
+        let r = _mm_mask_cmplt_epi16_mask(k1, a, b);
+        assert_eq!(r, 0b00000100);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmpneq_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmpneq_epi16_mask(a, b);
+        assert_eq!(m, 0b01101110_01101110_01101110_01101110);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmpneq_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmpneq_epi16_mask(mask, a, b);
+        assert_eq!(r, 0b01101010_01101010_01101010_01101010);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmpneq_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let m = _mm256_cmpneq_epi16_mask(a, b);
+        assert_eq!(m, 0b01101110_01100110);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmpneq_epi16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm256_mask_cmpneq_epi16_mask(k1, a, b);
+        assert_eq!(r, 0b01001010_00100100);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmpneq_epi16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let m = _mm_cmpneq_epi16_mask(a, b);
+        assert_eq!(m, 0b01100110);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmpneq_epi16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let k1 = 0b00110101;
+        let r = _mm_mask_cmpneq_epi16_mask(k1, a, b);
+        assert_eq!(r, 0b00100100);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmp_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmp_epu8_mask(a, b, _MM_CMPINT_LT);
+        assert_eq!(
+            m,
+            0b01101100_00000010_01101100_00000010_01101100_00000010_01101100_00000010
+        );
+        let m = _mm512_cmp_epu8_mask(a, b, _MM_CMPINT_NE);
+        assert_eq!(
+            m,
+            0b01101110_01101110_01101110_01101110_01101110_01101110_01101110_01101110
+        );
+        let m = _mm512_cmp_epu8_mask(a, b, _MM_CMPINT_NLE);
+        assert_eq!(
+            m,
+            0b00000010_01101100_00000010_01101100_00000010_01101100_00000010_01101100
+        );
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmp_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010_01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmp_epu8_mask(mask, a, b, _MM_CMPINT_LT);
+        assert_eq!(
+            r,
+            0b01101000_00000010_01101000_00000010_01101000_00000010_01101000_00000010
+        );
+        let r = _mm512_mask_cmp_epu8_mask(mask, a, b, _MM_CMPINT_NE);
+        assert_eq!(
+            r,
+            0b01101010_01101010_01101010_01101010_01101010_01101010_01101010_01101010
+        );
+        let r = _mm512_mask_cmp_epu8_mask(mask, a, b, _MM_CMPINT_NLE);
+        assert_eq!(
+            r,
+            0b00000010_01101000_00000010_01101000_00000010_01101000_00000010_01101000
+        );
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmp_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let m = _mm256_cmp_epu8_mask(a, b, _MM_CMPINT_LT);
+        assert_eq!(m, 0b01101010_00000100_01101100_00000010);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmp_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let k1 = 0b01101001_10010110_11001010_00110101;
+        let r = _mm256_mask_cmp_epu8_mask(k1, a, b, _MM_CMPINT_LT);
+        assert_eq!(r, 0b01101000_00000100_01001000_00000000);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmp_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let m = _mm_cmp_epu8_mask(a, b, _MM_CMPINT_LT);
+        assert_eq!(m, 0b01101100_00000010);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmp_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm_mask_cmp_epu8_mask(k1, a, b, _MM_CMPINT_LT);
+        assert_eq!(r, 0b01001000_00000000);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmpeq_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmpeq_epu8_mask(a, b);
+        assert_eq!(
+            m,
+            0b10010001_10010001_10010001_10010001_10010001_10010001_10010001_10010001
+        );
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmpeq_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010_01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmpeq_epu8_mask(mask, a, b);
+        assert_eq!(
+            r,
+            0b00010000_00010000_00010000_00010000_00010000_00010000_00010000_00010000
+        );
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmpeq_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let m = _mm256_cmpeq_epu8_mask(a, b);
+        assert_eq!(m, 0b10010001_10011001_10010001_10011001);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmpeq_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let k1 = 0b01101001_10010110_11001010_00110101;
+        let r = _mm256_mask_cmpeq_epu8_mask(k1, a, b);
+        assert_eq!(r, 0b00000001_10010000_10000000_00010001);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmpeq_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let m = _mm_cmpeq_epu8_mask(a, b);
+        assert_eq!(m, 0b10010001_10011001);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmpeq_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm_mask_cmpeq_epu8_mask(k1, a, b);
+        assert_eq!(r, 0b10000000_00010001);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmpge_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmpge_epu8_mask(a, b);
+        assert_eq!(
+            m,
+            0b10010011_11111101_10010011_11111101_10010011_11111101_10010011_11111101
+        );
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmpge_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010_01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmpge_epu8_mask(mask, a, b);
+        assert_eq!(
+            r,
+            0b00010010_01111000_00010010_01111000_00010010_01111000_00010010_01111000
+        );
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmpge_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let m = _mm256_cmpge_epu8_mask(a, b);
+        assert_eq!(m, 0b10010101_11111011_10010011_11111101);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmpge_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let k1 = 0b01101001_10010110_11001010_00110101;
+        let r = _mm256_mask_cmpge_epu8_mask(k1, a, b);
+        assert_eq!(r, 0b00000001_10010010_10000010_00110101);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmpge_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let m = _mm_cmpge_epu8_mask(a, b);
+        assert_eq!(m, 0b10010011_11111101);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmpge_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm_mask_cmpge_epu8_mask(k1, a, b);
+        assert_eq!(r, 0b10000010_00110101);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmpgt_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmpgt_epu8_mask(a, b);
+        assert_eq!(
+            m,
+            0b00000010_01101100_00000010_01101100_00000010_01101100_00000010_01101100
+        );
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmpgt_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010_01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmpgt_epu8_mask(mask, a, b);
+        assert_eq!(
+            r,
+            0b00000010_01101000_00000010_01101000_00000010_01101000_00000010_01101000
+        );
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmpgt_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let m = _mm256_cmpgt_epu8_mask(a, b);
+        assert_eq!(m, 0b00000100_01100010_00000010_01100100);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmpgt_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let k1 = 0b01101001_10010110_11001010_00110101;
+        let r = _mm256_mask_cmpgt_epu8_mask(k1, a, b);
+        assert_eq!(r, 0b00000000_00000010_00000010_00100100);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmpgt_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let m = _mm_cmpgt_epu8_mask(a, b);
+        assert_eq!(m, 0b00000010_01100100);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmpgt_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm_mask_cmpgt_epu8_mask(k1, a, b);
+        assert_eq!(r, 0b00000010_00100100);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmple_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmple_epu8_mask(a, b);
+        assert_eq!(
+            m,
+            0b11111101_10010011_11111101_10010011_11111101_10010011_11111101_10010011
+        );
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmple_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010_01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmple_epu8_mask(mask, a, b);
+        assert_eq!(
+            r,
+            0b01111000_00010010_01111000_00010010_01111000_00010010_01111000_00010010
+        );
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmple_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let m = _mm256_cmple_epu8_mask(a, b);
+        assert_eq!(m, 0b11111011_10011101_11111101_10011011);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmple_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let k1 = 0b01101001_10010110_11001010_00110101;
+        let r = _mm256_mask_cmple_epu8_mask(k1, a, b);
+        assert_eq!(r, 0b01101001_10010100_11001000_00010001);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmple_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let m = _mm_cmple_epu8_mask(a, b);
+        assert_eq!(m, 0b11111101_10011011);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmple_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm_mask_cmple_epu8_mask(k1, a, b);
+        assert_eq!(r, 0b11001000_00010001);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmplt_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmplt_epu8_mask(a, b);
+        assert_eq!(
+            m,
+            0b01101100_00000010_01101100_00000010_01101100_00000010_01101100_00000010
+        );
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmplt_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010_01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmplt_epu8_mask(mask, a, b);
+        assert_eq!(
+            r,
+            0b01101000_00000010_01101000_00000010_01101000_00000010_01101000_00000010
+        );
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmplt_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let m = _mm256_cmplt_epu8_mask(a, b);
+        assert_eq!(m, 0b01101010_00000100_01101100_00000010);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmplt_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let k1 = 0b01101001_10010110_11001010_00110101;
+        let r = _mm256_mask_cmplt_epu8_mask(k1, a, b);
+        assert_eq!(r, 0b01101000_00000100_01001000_00000000);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmplt_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let m = _mm_cmplt_epu8_mask(a, b);
+        assert_eq!(m, 0b01101100_00000010);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmplt_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm_mask_cmplt_epu8_mask(k1, a, b);
+        assert_eq!(r, 0b01001000_00000000);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmpneq_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmpneq_epu8_mask(a, b);
+        assert_eq!(
+            m,
+            0b01101110_01101110_01101110_01101110_01101110_01101110_01101110_01101110
+        );
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmpneq_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+            13, 100, 99, -128, 13, 1, -1, 0, 13, -100, 100, -128, 127, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+            13, -100, 100, -128, 127, -1, 1, 0, 13, 100, 99, -128, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010_01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmpneq_epu8_mask(mask, a, b);
+        assert_eq!(
+            r,
+            0b01101010_01101010_01101010_01101010_01101010_01101010_01101010_01101010
+        );
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmpneq_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let m = _mm256_cmpneq_epu8_mask(a, b);
+        assert_eq!(m, 0b01101110_01100110_01101110_01100110);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmpneq_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            3, 4, 2, 16,
+            std::i8::MIN, 103, -97, 16,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+            3, 2, 4, 16,
+            std::i8::MIN, 102, 103, 16,
+            3, 4, 2, std::i8::MAX,
+            std::i8::MIN, 103, -97, 16,
+        );
+        let k1 = 0b01101001_10010110_11001010_00110101;
+        let r = _mm256_mask_cmpneq_epu8_mask(k1, a, b);
+        assert_eq!(r, 0b01101000_00000110_01001010_00100100);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmpneq_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let m = _mm_cmpneq_epu8_mask(a, b);
+        assert_eq!(m, 0b01101110_01100110);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmpneq_epu8_mask() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            0, 1, -1, 13,
+            std::i8::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            0, -1, 1, 13,
+            std::i8::MIN, 99, 100, 13,
+            0, 1, -1, std::i8::MAX,
+            std::i8::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm_mask_cmpneq_epu8_mask(k1, a, b);
+        assert_eq!(r, 0b01001010_00100100);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmp_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmp_epu16_mask(a, b, _MM_CMPINT_LT);
+        assert_eq!(m, 0b01101100_00000010_01101100_00000010);
+        let m = _mm512_cmp_epu16_mask(a, b, _MM_CMPINT_NE);
+        assert_eq!(m, 0b01101110_01101110_01101110_01101110);
+        let m = _mm512_cmp_epu16_mask(a, b, _MM_CMPINT_NLE);
+        assert_eq!(m, 0b00000010_01101100_00000010_01101100);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmp_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmp_epu16_mask(mask, a, b, _MM_CMPINT_LT);
+        assert_eq!(r, 0b01101000_00000010_01101000_00000010);
+        let r = _mm512_mask_cmp_epu16_mask(mask, a, b, _MM_CMPINT_NE);
+        assert_eq!(r, 0b01101010_01101010_01101010_01101010);
+        let r = _mm512_mask_cmp_epu16_mask(mask, a, b, _MM_CMPINT_NLE);
+        assert_eq!(r, 0b00000010_01101000_00000010_01101000);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmp_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let m = _mm256_cmp_epu16_mask(a, b, _MM_CMPINT_LT);
+        assert_eq!(m, 0b01101100_00000010);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmp_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm256_mask_cmp_epu16_mask(k1, a, b, _MM_CMPINT_LT);
+        assert_eq!(r, 0b01001000_00000000);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmp_epu16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let m = _mm_cmp_epu16_mask(a, b, _MM_CMPINT_LT);
+        assert_eq!(m, 0b00000010);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmp_epu16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let k1 = 0b00110101;
+        let r = _mm_mask_cmp_epu16_mask(k1, a, b, _MM_CMPINT_LT);
+        assert_eq!(r, 0b00000000);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmpeq_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmpeq_epu16_mask(a, b);
+        assert_eq!(m, 0b10010001_10010001_10010001_10010001);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmpeq_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmpeq_epu16_mask(mask, a, b);
+        assert_eq!(r, 0b00010000_00010000_00010000_00010000);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmpeq_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let m = _mm256_cmpeq_epu16_mask(a, b);
+        assert_eq!(m, 0b10010001_10011001);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmpeq_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm256_mask_cmpeq_epu16_mask(k1, a, b);
+        assert_eq!(r, 0b10000000_00010001);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmpeq_epu16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let m = _mm_cmpeq_epu16_mask(a, b);
+        assert_eq!(m, 0b10011001);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmpeq_epu16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let k1 = 0b00110101;
+        let r = _mm_mask_cmpeq_epu16_mask(k1, a, b);
+        assert_eq!(r, 0b00010001);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmpge_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmpge_epu16_mask(a, b);
+        assert_eq!(m, 0b10010011_11111101_10010011_11111101);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmpge_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmpge_epu16_mask(mask, a, b);
+        assert_eq!(r, 0b00010010_01111000_00010010_01111000);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmpge_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let m = _mm256_cmpge_epu16_mask(a, b);
+        assert_eq!(m, 0b10010011_11111101);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmpge_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm256_mask_cmpge_epu16_mask(k1, a, b);
+        assert_eq!(r, 0b10000010_00110101);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmpge_epu16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let m = _mm_cmpge_epu16_mask(a, b);
+        assert_eq!(m, 0b11111101);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmpge_epu16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let k1 = 0b00110101;
+        let r = _mm_mask_cmpge_epu16_mask(k1, a, b);
+        assert_eq!(r, 0b00110101);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmpgt_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmpgt_epu16_mask(a, b);
+        assert_eq!(m, 0b00000010_01101100_00000010_01101100);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmpgt_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmpgt_epu16_mask(mask, a, b);
+        assert_eq!(r, 0b00000010_01101000_00000010_01101000);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmpgt_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let m = _mm256_cmpgt_epu16_mask(a, b);
+        assert_eq!(m, 0b00000010_01100100);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmpgt_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm256_mask_cmpgt_epu16_mask(k1, a, b);
+        assert_eq!(r, 0b00000010_00100100);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmpgt_epu16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let m = _mm_cmpgt_epu16_mask(a, b);
+        assert_eq!(m, 0b01100100);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmpgt_epu16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let k1 = 0b00110101;
+        let r = _mm_mask_cmpgt_epu16_mask(k1, a, b);
+        assert_eq!(r, 0b00100100);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmple_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmple_epu16_mask(a, b);
+        assert_eq!(m, 0b11111101_10010011_11111101_10010011);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmple_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmple_epu16_mask(mask, a, b);
+        assert_eq!(r, 0b01111000_00010010_01111000_00010010);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmple_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let m = _mm256_cmple_epu16_mask(a, b);
+        assert_eq!(m, 0b11111101_10011011);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmple_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm256_mask_cmple_epu16_mask(k1, a, b);
+        assert_eq!(r, 0b11001000_00010001);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmple_epu16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let m = _mm_cmple_epu16_mask(a, b);
+        assert_eq!(m, 0b10011011);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmple_epu16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let k1 = 0b00110101;
+        let r = _mm_mask_cmple_epu16_mask(k1, a, b);
+        assert_eq!(r, 0b00010001);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmplt_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmplt_epu16_mask(a, b);
+        assert_eq!(m, 0b01101100_00000010_01101100_00000010);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmplt_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmplt_epu16_mask(mask, a, b);
+        assert_eq!(r, 0b01101000_00000010_01101000_00000010);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmplt_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let m = _mm256_cmplt_epu16_mask(a, b);
+        assert_eq!(m, 0b01101100_00000010);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmplt_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm256_mask_cmplt_epu16_mask(k1, a, b);
+        assert_eq!(r, 0b01001000_00000000);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmplt_epu16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let m = _mm_cmplt_epu16_mask(a, b);
+        assert_eq!(m, 0b00000010);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmplt_epu16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let k1 = 0b00110101;
+        let r = _mm_mask_cmplt_epu16_mask(k1, a, b);
+        assert_eq!(r, 0b00000000);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cmpneq_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let m = _mm512_cmpneq_epu16_mask(a, b);
+        assert_eq!(m, 0b01101110_01101110_01101110_01101110);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cmpneq_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi16(
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+            13, -100, 100, -32768, 32767, -1, 1, 0,
+            13, 100, 99, -32768, 13, 1, -1, 0,
+        );
+        let mask = 0b01111010_01111010_01111010_01111010;
+        let r = _mm512_mask_cmpneq_epu16_mask(mask, a, b);
+        assert_eq!(r, 0b01101010_01101010_01101010_01101010);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_cmpneq_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let m = _mm256_cmpneq_epu16_mask(a, b);
+        assert_eq!(m, 0b01101110_01100110);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_cmpneq_epu16_mask() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi16(
+            0, 1, -1, 13,
+            std::i16::MIN, 100, -100, 13,
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi16(
+            0, -1, 1, 13,
+            std::i16::MIN, 99, 100, 13,
+            0, 1, -1, std::i16::MAX,
+            std::i16::MIN, 100, -100, 13,
+        );
+        let k1 = 0b11001010_00110101;
+        let r = _mm256_mask_cmpneq_epu16_mask(k1, a, b);
+        assert_eq!(r, 0b01001010_00100100);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_cmpneq_epu16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let m = _mm_cmpneq_epu16_mask(a, b);
+        assert_eq!(m, 0b01100110);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_cmpneq_epu16_mask() {
+        let a = _mm_setr_epi16(0, 1, -1, 13, std::i16::MIN, 100, -100, 13);
+        let b = _mm_setr_epi16(0, -1, 1, 13, std::i16::MIN, 99, 100, 13);
+        let k1 = 0b00110101;
+        let r = _mm_mask_cmpneq_epu16_mask(k1, a, b);
+        assert_eq!(r, 0b00100100);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_cvtepi16_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, -100, 100, -300, 300, 32767, -32768, 256,
+            255, -129, 128, -128, 127, -1, 1, 0,
+            7, -100, 100, -300, 300, 32767, -32768, 256,
+            255, -129, 128, -128, 127, -1, 1, 0,
+        );
+        let r = _mm512_cvtepi16_epi8(a);
+        #[rustfmt::skip]
+        let e = _mm256_set_epi8(
+            7, -100, 100, -44, 44, -1, 0, 0, -1, 127, -128, -128, 127, -1, 1, 0,
+            7, -100, 100, -44, 44, -1, 0, 0, -1, 127, -128, -128, 127, -1, 1, 0,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_cvtepi16_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, -100, 100, -300, 300, 32767, -32768, 256,
+            255, -129, 128, -128, 127, -1, 1, 0,
+            7, -100, 100, -300, 300, 32767, -32768, 256,
+            255, -129, 128, -128, 127, -1, 1, 0,
+        );
+        let src = _mm256_set1_epi8(2);
+        let r = _mm512_mask_cvtepi16_epi8(src, 0, a);
+        assert_eq_m256i(r, src);
+        let r = _mm512_mask_cvtepi16_epi8(src, 0b00000000_00000000_11111111_11111111, a);
+        #[rustfmt::skip]
+        let e = _mm256_set_epi8(
+            2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
+            7, -100, 100, -44, 44, -1, 0, 0, -1, 127, -128, -128, 127, -1, 1, 0,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_cvtepi16_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            7, -100, 100, -300, 300, 32767, -32768, 256,
+            255, -129, 128, -128, 127, -1, 1, 0,
+            7, -100, 100, -300, 300, 32767, -32768, 256,
+            255, -129, 128, -128, 127, -1, 1, 0,
+        );
+        let r = _mm512_maskz_cvtepi16_epi8(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm512_maskz_cvtepi16_epi8(0b00000000_00000000_11111111_11111111, a);
+        #[rustfmt::skip]
+        let e = _mm256_set_epi8(
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            7, -100, 100, -44, 44, -1, 0, 0, -1, 127, -128, -128, 127, -1, 1, 0,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_loadu_epi8() {
+        let a: [i8; 64] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46,
+            47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64,
+        ];
+        let p = a.as_ptr() as *const u8;
+        let src = _mm512_set1_epi8(-1);
+        let m = 0b11001010_11001010_11001010_11001010_11001010_11001010_11001010_11001010;
+        let r = _mm512_mask_loadu_epi8(src, m, p);
+        let e: [i8; 64] = [
+            -1, 2, -1, 4, -1, -1, 7, 8, -1, 10, -1, 12, -1, -1, 15, 16, -1, 18, -1, 20, -1, -1, 23,
+            24, -1, 26, -1, 28, -1, -1, 31, 32, -1, 34, -1, 36, -1, -1, 39, 40, -1, 42, -1, 44, -1,
+            -1, 47, 48, -1, 50, -1, 52, -1, -1, 55, 56, -1, 58, -1, 60, -1, -1, 63, 64,
+        ];
+        assert_eq_m512i(r, transmute(e));
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_loadu_epi8() {
+        let a: [i8; 64] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46,
+            47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64,
+        ];
+        let p = a.as_ptr() as *const u8;
+        let m = 0b11001010_11001010_11001010_11001010_11001010_11001010_11001010_11001010;
+        let r = _mm512_maskz_loadu_epi8(m, p);
+        let e: [i8; 64] = [
+            0, 2, 0, 4, 0, 0, 7, 8, 0, 10, 0, 12, 0, 0, 15, 16, 0, 18, 0, 20, 0, 0, 23, 24, 0, 26,
+            0, 28, 0, 0, 31, 32, 0, 34, 0, 36, 0, 0, 39, 40, 0, 42, 0, 44, 0, 0, 47, 48, 0, 50, 0,
+            52, 0, 0, 55, 56, 0, 58, 0, 60, 0, 0, 63, 64,
+        ];
+        assert_eq_m512i(r, transmute(e));
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_storeu_epi8() {
+        let a: [i8; 64] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46,
+            47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64,
+        ];
+        let m = 0b11001010_11001010_11001010_11001010_11001010_11001010_11001010_11001010;
+        let mut r = [0_i8; 64];
+        _mm512_mask_storeu_epi8(r.as_mut_ptr() as *mut u8, m, transmute(a));
+        let e: [i8; 64] = [
+            0, 2, 0, 4, 0, 0, 7, 8, 0, 10, 0, 12, 0, 0, 15, 16, 0, 18, 0, 20, 0, 0, 23, 24, 0, 26,
+            0, 28, 0, 0, 31, 32, 0, 34, 0, 36, 0, 0, 39, 40, 0, 42, 0, 44, 0, 0, 47, 48, 0, 50, 0,
+            52, 0, 0, 55, 56, 0, 58, 0, 60, 0, 0, 63, 64,
+        ];
+        assert_eq!(&r[..], &e[..]);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_loadu_epi8() {
+        let a: [i8; 32] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32,
+        ];
+        let p = a.as_ptr() as *const u8;
+        let src = _mm256_set1_epi8(-1);
+        let m = 0b11001010_11001010_11001010_11001010;
+        let r = _mm256_mask_loadu_epi8(src, m, p);
+        let e: [i8; 32] = [
+            -1, 2, -1, 4, -1, -1, 7, 8, -1, 10, -1, 12, -1, -1, 15, 16, -1, 18, -1, 20, -1, -1, 23,
+            24, -1, 26, -1, 28, -1, -1, 31, 32,
+        ];
+        assert_eq_m256i(r, transmute(e));
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_maskz_loadu_epi8() {
+        let a: [i8; 32] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32,
+        ];
+        let p = a.as_ptr() as *const u8;
+        let m = 0b11001010_11001010_11001010_11001010;
+        let r = _mm256_maskz_loadu_epi8(m, p);
+        let e: [i8; 32] = [
+            0, 2, 0, 4, 0, 0, 7, 8, 0, 10, 0, 12, 0, 0, 15, 16, 0, 18, 0, 20, 0, 0, 23, 24, 0, 26,
+            0, 28, 0, 0, 31, 32,
+        ];
+        assert_eq_m256i(r, transmute(e));
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_storeu_epi8() {
+        let a: [i8; 32] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32,
+        ];
+        let m = 0b11001010_11001010_11001010_11001010;
+        let mut r = [0_i8; 32];
+        _mm256_mask_storeu_epi8(r.as_mut_ptr() as *mut u8, m, transmute(a));
+        let e: [i8; 32] = [
+            0, 2, 0, 4, 0, 0, 7, 8, 0, 10, 0, 12, 0, 0, 15, 16, 0, 18, 0, 20, 0, 0, 23, 24, 0, 26,
+            0, 28, 0, 0, 31, 32,
+        ];
+        assert_eq!(&r[..], &e[..]);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_loadu_epi8() {
+        let a: [i8; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        let p = a.as_ptr() as *const u8;
+        let src = _mm_set1_epi8(-1);
+        let m = 0b11001010_11001010;
+        let r = _mm_mask_loadu_epi8(src, m, p);
+        let e: [i8; 16] = [-1, 2, -1, 4, -1, -1, 7, 8, -1, 10, -1, 12, -1, -1, 15, 16];
+        assert_eq_m128i(r, transmute(e));
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_maskz_loadu_epi8() {
+        let a: [i8; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        let p = a.as_ptr() as *const u8;
+        let m = 0b11001010_11001010;
+        let r = _mm_maskz_loadu_epi8(m, p);
+        let e: [i8; 16] = [0, 2, 0, 4, 0, 0, 7, 8, 0, 10, 0, 12, 0, 0, 15, 16];
+        assert_eq_m128i(r, transmute(e));
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_storeu_epi8() {
+        let a: [i8; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        let m = 0b11001010_11001010;
+        let mut r = [0_i8; 16];
+        _mm_mask_storeu_epi8(r.as_mut_ptr() as *mut u8, m, transmute(a));
+        let e: [i8; 16] = [0, 2, 0, 4, 0, 0, 7, 8, 0, 10, 0, 12, 0, 0, 15, 16];
+        assert_eq!(&r[..], &e[..]);
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_loadu_epi16() {
+        let a: [i16; 32] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32,
+        ];
+        let p = a.as_ptr() as *const u8;
+        let src = _mm512_set1_epi16(-1);
+        let m = 0b11001010_11001010_11001010_11001010;
+        let r = _mm512_mask_loadu_epi16(src, m, p);
+        let e: [i16; 32] = [
+            -1, 2, -1, 4, -1, -1, 7, 8, -1, 10, -1, 12, -1, -1, 15, 16, -1, 18, -1, 20, -1, -1, 23,
+            24, -1, 26, -1, 28, -1, -1, 31, 32,
+        ];
+        assert_eq_m512i(r, transmute(e));
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_maskz_loadu_epi16() {
+        let a: [i16; 32] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32,
+        ];
+        let p = a.as_ptr() as *const u8;
+        let m = 0b11001010_11001010_11001010_11001010;
+        let r = _mm512_maskz_loadu_epi16(m, p);
+        let e: [i16; 32] = [
+            0, 2, 0, 4, 0, 0, 7, 8, 0, 10, 0, 12, 0, 0, 15, 16, 0, 18, 0, 20, 0, 0, 23, 24, 0, 26,
+            0, 28, 0, 0, 31, 32,
+        ];
+        assert_eq_m512i(r, transmute(e));
+    }
+
+    #[simd_test(enable = "avx512bw")]
+    unsafe fn test_mm512_mask_storeu_epi16() {
+        let a: [i16; 32] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32,
+        ];
+        let m = 0b11001010_11001010_11001010_11001010;
+        let mut r = [0_i16; 32];
+        _mm512_mask_storeu_epi16(r.as_mut_ptr() as *mut u8, m, transmute(a));
+        let e: [i16; 32] = [
+            0, 2, 0, 4, 0, 0, 7, 8, 0, 10, 0, 12, 0, 0, 15, 16, 0, 18, 0, 20, 0, 0, 23, 24, 0, 26,
+            0, 28, 0, 0, 31, 32,
+        ];
+        assert_eq!(&r[..], &e[..]);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_loadu_epi16() {
+        let a: [i16; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        let p = a.as_ptr() as *const u8;
+        let src = _mm256_set1_epi16(-1);
+        let m = 0b11001010_11001010;
+        let r = _mm256_mask_loadu_epi16(src, m, p);
+        let e: [i16; 16] = [-1, 2, -1, 4, -1, -1, 7, 8, -1, 10, -1, 12, -1, -1, 15, 16];
+        assert_eq_m256i(r, transmute(e));
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_maskz_loadu_epi16() {
+        let a: [i16; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        let p = a.as_ptr() as *const u8;
+        let m = 0b11001010_11001010;
+        let r = _mm256_maskz_loadu_epi16(m, p);
+        let e: [i16; 16] = [0, 2, 0, 4, 0, 0, 7, 8, 0, 10, 0, 12, 0, 0, 15, 16];
+        assert_eq_m256i(r, transmute(e));
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_storeu_epi16() {
+        let a: [i16; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        let m = 0b11001010_11001010;
+        let mut r = [0_i16; 16];
+        _mm256_mask_storeu_epi16(r.as_mut_ptr() as *mut u8, m, transmute(a));
+        let e: [i16; 16] = [0, 2, 0, 4, 0, 0, 7, 8, 0, 10, 0, 12, 0, 0, 15, 16];
+        assert_eq!(&r[..], &e[..]);
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_loadu_epi16() {
+        let a: [i16; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+        let p = a.as_ptr() as *const u8;
+        let src = _mm_set1_epi16(-1);
+        let m = 0b11001010;
+        let r = _mm_mask_loadu_epi16(src, m, p);
+        let e: [i16; 8] = [-1, 2, -1, 4, -1, -1, 7, 8];
+        assert_eq_m128i(r, transmute(e));
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_maskz_loadu_epi16() {
+        let a: [i16; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+        let p = a.as_ptr() as *const u8;
+        let m = 0b11001010;
+        let r = _mm_maskz_loadu_epi16(m, p);
+        let e: [i16; 8] = [0, 2, 0, 4, 0, 0, 7, 8];
+        assert_eq_m128i(r, transmute(e));
+    }
+
+    #[simd_test(enable = "avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_storeu_epi16() {
+        let a: [i16; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+        let m = 0b11001010;
+        let mut r = [0_i16; 8];
+        _mm_mask_storeu_epi16(r.as_mut_ptr() as *mut u8, m, transmute(a));
+        let e: [i16; 8] = [0, 2, 0, 4, 0, 0, 7, 8];
+        assert_eq!(&r[..], &e[..]);
+    }
+}
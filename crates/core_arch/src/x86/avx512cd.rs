@@ -0,0 +1,1024 @@
+use crate::{
+    core_arch::{simd::*, simd_llvm::*, x86::*},
+    mem::transmute,
+};
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Tests each 32-bit element of `a` for equality with all other elements in `a`
+/// closer to the least significant bit. Each element's comparison forms a zero
+/// extended bit vector in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_conflict_epi32)
+#[inline]
+#[target_feature(enable = "avx512cd")]
+#[cfg_attr(test, assert_instr(vpconflictd))]
+pub unsafe fn _mm512_conflict_epi32(a: __m512i) -> __m512i {
+    transmute(vpconflictd_512(a.as_i32x16()))
+}
+
+/// Tests each 32-bit element of `a` for equality with all other elements in `a`
+/// closer to the least significant bit. Each element's comparison forms a zero
+/// extended bit vector in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_conflict_epi32)
+#[inline]
+#[target_feature(enable = "avx512cd")]
+#[cfg_attr(test, assert_instr(vpconflictd))]
+pub unsafe fn _mm512_mask_conflict_epi32(src: __m512i, k: __mmask16, a: __m512i) -> __m512i {
+    let conflict = _mm512_conflict_epi32(a).as_i32x16();
+    transmute(simd_select_bitmask(k, conflict, src.as_i32x16()))
+}
+
+/// Tests each 32-bit element of `a` for equality with all other elements in `a`
+/// closer to the least significant bit. Each element's comparison forms a zero
+/// extended bit vector in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_conflict_epi32)
+#[inline]
+#[target_feature(enable = "avx512cd")]
+#[cfg_attr(test, assert_instr(vpconflictd))]
+pub unsafe fn _mm512_maskz_conflict_epi32(k: __mmask16, a: __m512i) -> __m512i {
+    let conflict = _mm512_conflict_epi32(a).as_i32x16();
+    let zero = _mm512_setzero_si512().as_i32x16();
+    transmute(simd_select_bitmask(k, conflict, zero))
+}
+
+/// Tests each 32-bit element of `a` for equality with all other elements in `a`
+/// closer to the least significant bit. Each element's comparison forms a zero
+/// extended bit vector in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_conflict_epi32)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vpconflictd))]
+pub unsafe fn _mm256_conflict_epi32(a: __m256i) -> __m256i {
+    transmute(vpconflictd_256(a.as_i32x8()))
+}
+
+/// Tests each 32-bit element of `a` for equality with all other elements in `a`
+/// closer to the least significant bit. Each element's comparison forms a zero
+/// extended bit vector in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_conflict_epi32)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vpconflictd))]
+pub unsafe fn _mm256_mask_conflict_epi32(src: __m256i, k: __mmask8, a: __m256i) -> __m256i {
+    let conflict = _mm256_conflict_epi32(a).as_i32x8();
+    transmute(simd_select_bitmask(k, conflict, src.as_i32x8()))
+}
+
+/// Tests each 32-bit element of `a` for equality with all other elements in `a`
+/// closer to the least significant bit. Each element's comparison forms a zero
+/// extended bit vector in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_conflict_epi32)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vpconflictd))]
+pub unsafe fn _mm256_maskz_conflict_epi32(k: __mmask8, a: __m256i) -> __m256i {
+    let conflict = _mm256_conflict_epi32(a).as_i32x8();
+    let zero = _mm256_setzero_si256().as_i32x8();
+    transmute(simd_select_bitmask(k, conflict, zero))
+}
+
+/// Tests each 32-bit element of `a` for equality with all other elements in `a`
+/// closer to the least significant bit. Each element's comparison forms a zero
+/// extended bit vector in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_conflict_epi32)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vpconflictd))]
+pub unsafe fn _mm_conflict_epi32(a: __m128i) -> __m128i {
+    transmute(vpconflictd_128(a.as_i32x4()))
+}
+
+/// Tests each 32-bit element of `a` for equality with all other elements in `a`
+/// closer to the least significant bit. Each element's comparison forms a zero
+/// extended bit vector in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_conflict_epi32)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vpconflictd))]
+pub unsafe fn _mm_mask_conflict_epi32(src: __m128i, k: __mmask8, a: __m128i) -> __m128i {
+    let conflict = _mm_conflict_epi32(a).as_i32x4();
+    transmute(simd_select_bitmask(k, conflict, src.as_i32x4()))
+}
+
+/// Tests each 32-bit element of `a` for equality with all other elements in `a`
+/// closer to the least significant bit. Each element's comparison forms a zero
+/// extended bit vector in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_conflict_epi32)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vpconflictd))]
+pub unsafe fn _mm_maskz_conflict_epi32(k: __mmask8, a: __m128i) -> __m128i {
+    let conflict = _mm_conflict_epi32(a).as_i32x4();
+    let zero = _mm_setzero_si128().as_i32x4();
+    transmute(simd_select_bitmask(k, conflict, zero))
+}
+
+/// Tests each 64-bit element of `a` for equality with all other elements in `a`
+/// closer to the least significant bit. Each element's comparison forms a zero
+/// extended bit vector in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_conflict_epi64)
+#[inline]
+#[target_feature(enable = "avx512cd")]
+#[cfg_attr(test, assert_instr(vpconflictq))]
+pub unsafe fn _mm512_conflict_epi64(a: __m512i) -> __m512i {
+    transmute(vpconflictq_512(a.as_i64x8()))
+}
+
+/// Tests each 64-bit element of `a` for equality with all other elements in `a`
+/// closer to the least significant bit. Each element's comparison forms a zero
+/// extended bit vector in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_conflict_epi64)
+#[inline]
+#[target_feature(enable = "avx512cd")]
+#[cfg_attr(test, assert_instr(vpconflictq))]
+pub unsafe fn _mm512_mask_conflict_epi64(src: __m512i, k: __mmask8, a: __m512i) -> __m512i {
+    let conflict = _mm512_conflict_epi64(a).as_i64x8();
+    transmute(simd_select_bitmask(k, conflict, src.as_i64x8()))
+}
+
+/// Tests each 64-bit element of `a` for equality with all other elements in `a`
+/// closer to the least significant bit. Each element's comparison forms a zero
+/// extended bit vector in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_conflict_epi64)
+#[inline]
+#[target_feature(enable = "avx512cd")]
+#[cfg_attr(test, assert_instr(vpconflictq))]
+pub unsafe fn _mm512_maskz_conflict_epi64(k: __mmask8, a: __m512i) -> __m512i {
+    let conflict = _mm512_conflict_epi64(a).as_i64x8();
+    let zero = _mm512_setzero_si512().as_i64x8();
+    transmute(simd_select_bitmask(k, conflict, zero))
+}
+
+/// Tests each 64-bit element of `a` for equality with all other elements in `a`
+/// closer to the least significant bit. Each element's comparison forms a zero
+/// extended bit vector in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_conflict_epi64)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vpconflictq))]
+pub unsafe fn _mm256_conflict_epi64(a: __m256i) -> __m256i {
+    transmute(vpconflictq_256(a.as_i64x4()))
+}
+
+/// Tests each 64-bit element of `a` for equality with all other elements in `a`
+/// closer to the least significant bit. Each element's comparison forms a zero
+/// extended bit vector in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_conflict_epi64)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vpconflictq))]
+pub unsafe fn _mm256_mask_conflict_epi64(src: __m256i, k: __mmask8, a: __m256i) -> __m256i {
+    let conflict = _mm256_conflict_epi64(a).as_i64x4();
+    transmute(simd_select_bitmask(k, conflict, src.as_i64x4()))
+}
+
+/// Tests each 64-bit element of `a` for equality with all other elements in `a`
+/// closer to the least significant bit. Each element's comparison forms a zero
+/// extended bit vector in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_conflict_epi64)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vpconflictq))]
+pub unsafe fn _mm256_maskz_conflict_epi64(k: __mmask8, a: __m256i) -> __m256i {
+    let conflict = _mm256_conflict_epi64(a).as_i64x4();
+    let zero = _mm256_setzero_si256().as_i64x4();
+    transmute(simd_select_bitmask(k, conflict, zero))
+}
+
+/// Tests each 64-bit element of `a` for equality with all other elements in `a`
+/// closer to the least significant bit. Each element's comparison forms a zero
+/// extended bit vector in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_conflict_epi64)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vpconflictq))]
+pub unsafe fn _mm_conflict_epi64(a: __m128i) -> __m128i {
+    transmute(vpconflictq_128(a.as_i64x2()))
+}
+
+/// Tests each 64-bit element of `a` for equality with all other elements in `a`
+/// closer to the least significant bit. Each element's comparison forms a zero
+/// extended bit vector in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_conflict_epi64)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vpconflictq))]
+pub unsafe fn _mm_mask_conflict_epi64(src: __m128i, k: __mmask8, a: __m128i) -> __m128i {
+    let conflict = _mm_conflict_epi64(a).as_i64x2();
+    transmute(simd_select_bitmask(k, conflict, src.as_i64x2()))
+}
+
+/// Tests each 64-bit element of `a` for equality with all other elements in `a`
+/// closer to the least significant bit. Each element's comparison forms a zero
+/// extended bit vector in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_conflict_epi64)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vpconflictq))]
+pub unsafe fn _mm_maskz_conflict_epi64(k: __mmask8, a: __m128i) -> __m128i {
+    let conflict = _mm_conflict_epi64(a).as_i64x2();
+    let zero = _mm_setzero_si128().as_i64x2();
+    transmute(simd_select_bitmask(k, conflict, zero))
+}
+
+/// Counts the number of leading zero bits in each packed 32-bit integer in `a`,
+/// and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_lzcnt_epi32)
+#[inline]
+#[target_feature(enable = "avx512cd")]
+#[cfg_attr(test, assert_instr(vplzcntd))]
+pub unsafe fn _mm512_lzcnt_epi32(a: __m512i) -> __m512i {
+    transmute(vplzcntd_512(a.as_i32x16(), false))
+}
+
+/// Counts the number of leading zero bits in each packed 32-bit integer in `a`,
+/// and stores the results in `dst` using writemask `k` (elements are copied
+/// from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_lzcnt_epi32)
+#[inline]
+#[target_feature(enable = "avx512cd")]
+#[cfg_attr(test, assert_instr(vplzcntd))]
+pub unsafe fn _mm512_mask_lzcnt_epi32(src: __m512i, k: __mmask16, a: __m512i) -> __m512i {
+    let lzcnt = _mm512_lzcnt_epi32(a).as_i32x16();
+    transmute(simd_select_bitmask(k, lzcnt, src.as_i32x16()))
+}
+
+/// Counts the number of leading zero bits in each packed 32-bit integer in `a`,
+/// and stores the results in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_lzcnt_epi32)
+#[inline]
+#[target_feature(enable = "avx512cd")]
+#[cfg_attr(test, assert_instr(vplzcntd))]
+pub unsafe fn _mm512_maskz_lzcnt_epi32(k: __mmask16, a: __m512i) -> __m512i {
+    let lzcnt = _mm512_lzcnt_epi32(a).as_i32x16();
+    let zero = _mm512_setzero_si512().as_i32x16();
+    transmute(simd_select_bitmask(k, lzcnt, zero))
+}
+
+/// Counts the number of leading zero bits in each packed 32-bit integer in `a`,
+/// and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_lzcnt_epi32)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vplzcntd))]
+pub unsafe fn _mm256_lzcnt_epi32(a: __m256i) -> __m256i {
+    transmute(vplzcntd_256(a.as_i32x8(), false))
+}
+
+/// Counts the number of leading zero bits in each packed 32-bit integer in `a`,
+/// and stores the results in `dst` using writemask `k` (elements are copied
+/// from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_lzcnt_epi32)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vplzcntd))]
+pub unsafe fn _mm256_mask_lzcnt_epi32(src: __m256i, k: __mmask8, a: __m256i) -> __m256i {
+    let lzcnt = _mm256_lzcnt_epi32(a).as_i32x8();
+    transmute(simd_select_bitmask(k, lzcnt, src.as_i32x8()))
+}
+
+/// Counts the number of leading zero bits in each packed 32-bit integer in `a`,
+/// and stores the results in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_lzcnt_epi32)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vplzcntd))]
+pub unsafe fn _mm256_maskz_lzcnt_epi32(k: __mmask8, a: __m256i) -> __m256i {
+    let lzcnt = _mm256_lzcnt_epi32(a).as_i32x8();
+    let zero = _mm256_setzero_si256().as_i32x8();
+    transmute(simd_select_bitmask(k, lzcnt, zero))
+}
+
+/// Counts the number of leading zero bits in each packed 32-bit integer in `a`,
+/// and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_lzcnt_epi32)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vplzcntd))]
+pub unsafe fn _mm_lzcnt_epi32(a: __m128i) -> __m128i {
+    transmute(vplzcntd_128(a.as_i32x4(), false))
+}
+
+/// Counts the number of leading zero bits in each packed 32-bit integer in `a`,
+/// and stores the results in `dst` using writemask `k` (elements are copied
+/// from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_lzcnt_epi32)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vplzcntd))]
+pub unsafe fn _mm_mask_lzcnt_epi32(src: __m128i, k: __mmask8, a: __m128i) -> __m128i {
+    let lzcnt = _mm_lzcnt_epi32(a).as_i32x4();
+    transmute(simd_select_bitmask(k, lzcnt, src.as_i32x4()))
+}
+
+/// Counts the number of leading zero bits in each packed 32-bit integer in `a`,
+/// and stores the results in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_lzcnt_epi32)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vplzcntd))]
+pub unsafe fn _mm_maskz_lzcnt_epi32(k: __mmask8, a: __m128i) -> __m128i {
+    let lzcnt = _mm_lzcnt_epi32(a).as_i32x4();
+    let zero = _mm_setzero_si128().as_i32x4();
+    transmute(simd_select_bitmask(k, lzcnt, zero))
+}
+
+/// Counts the number of leading zero bits in each packed 64-bit integer in `a`,
+/// and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_lzcnt_epi64)
+#[inline]
+#[target_feature(enable = "avx512cd")]
+#[cfg_attr(test, assert_instr(vplzcntq))]
+pub unsafe fn _mm512_lzcnt_epi64(a: __m512i) -> __m512i {
+    transmute(vplzcntq_512(a.as_i64x8(), false))
+}
+
+/// Counts the number of leading zero bits in each packed 64-bit integer in `a`,
+/// and stores the results in `dst` using writemask `k` (elements are copied
+/// from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_lzcnt_epi64)
+#[inline]
+#[target_feature(enable = "avx512cd")]
+#[cfg_attr(test, assert_instr(vplzcntq))]
+pub unsafe fn _mm512_mask_lzcnt_epi64(src: __m512i, k: __mmask8, a: __m512i) -> __m512i {
+    let lzcnt = _mm512_lzcnt_epi64(a).as_i64x8();
+    transmute(simd_select_bitmask(k, lzcnt, src.as_i64x8()))
+}
+
+/// Counts the number of leading zero bits in each packed 64-bit integer in `a`,
+/// and stores the results in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_lzcnt_epi64)
+#[inline]
+#[target_feature(enable = "avx512cd")]
+#[cfg_attr(test, assert_instr(vplzcntq))]
+pub unsafe fn _mm512_maskz_lzcnt_epi64(k: __mmask8, a: __m512i) -> __m512i {
+    let lzcnt = _mm512_lzcnt_epi64(a).as_i64x8();
+    let zero = _mm512_setzero_si512().as_i64x8();
+    transmute(simd_select_bitmask(k, lzcnt, zero))
+}
+
+/// Counts the number of leading zero bits in each packed 64-bit integer in `a`,
+/// and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_lzcnt_epi64)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vplzcntq))]
+pub unsafe fn _mm256_lzcnt_epi64(a: __m256i) -> __m256i {
+    transmute(vplzcntq_256(a.as_i64x4(), false))
+}
+
+/// Counts the number of leading zero bits in each packed 64-bit integer in `a`,
+/// and stores the results in `dst` using writemask `k` (elements are copied
+/// from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_lzcnt_epi64)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vplzcntq))]
+pub unsafe fn _mm256_mask_lzcnt_epi64(src: __m256i, k: __mmask8, a: __m256i) -> __m256i {
+    let lzcnt = _mm256_lzcnt_epi64(a).as_i64x4();
+    transmute(simd_select_bitmask(k, lzcnt, src.as_i64x4()))
+}
+
+/// Counts the number of leading zero bits in each packed 64-bit integer in `a`,
+/// and stores the results in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_lzcnt_epi64)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vplzcntq))]
+pub unsafe fn _mm256_maskz_lzcnt_epi64(k: __mmask8, a: __m256i) -> __m256i {
+    let lzcnt = _mm256_lzcnt_epi64(a).as_i64x4();
+    let zero = _mm256_setzero_si256().as_i64x4();
+    transmute(simd_select_bitmask(k, lzcnt, zero))
+}
+
+/// Counts the number of leading zero bits in each packed 64-bit integer in `a`,
+/// and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_lzcnt_epi64)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vplzcntq))]
+pub unsafe fn _mm_lzcnt_epi64(a: __m128i) -> __m128i {
+    transmute(vplzcntq_128(a.as_i64x2(), false))
+}
+
+/// Counts the number of leading zero bits in each packed 64-bit integer in `a`,
+/// and stores the results in `dst` using writemask `k` (elements are copied
+/// from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_lzcnt_epi64)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vplzcntq))]
+pub unsafe fn _mm_mask_lzcnt_epi64(src: __m128i, k: __mmask8, a: __m128i) -> __m128i {
+    let lzcnt = _mm_lzcnt_epi64(a).as_i64x2();
+    transmute(simd_select_bitmask(k, lzcnt, src.as_i64x2()))
+}
+
+/// Counts the number of leading zero bits in each packed 64-bit integer in `a`,
+/// and stores the results in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_lzcnt_epi64)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vplzcntq))]
+pub unsafe fn _mm_maskz_lzcnt_epi64(k: __mmask8, a: __m128i) -> __m128i {
+    let lzcnt = _mm_lzcnt_epi64(a).as_i64x2();
+    let zero = _mm_setzero_si128().as_i64x2();
+    transmute(simd_select_bitmask(k, lzcnt, zero))
+}
+
+/// Broadcasts the low 8 bits from input mask `k` to all 64-bit elements of
+/// `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_broadcastmb_epi64)
+#[inline]
+#[target_feature(enable = "avx512cd")]
+#[cfg_attr(test, assert_instr(vpbroadcast))]
+pub unsafe fn _mm512_broadcastmb_epi64(k: __mmask8) -> __m512i {
+    _mm512_set1_epi64(k as i64)
+}
+
+/// Broadcasts the low 8 bits from input mask `k` to all 64-bit elements of
+/// `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_broadcastmb_epi64)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vpbroadcast))]
+pub unsafe fn _mm256_broadcastmb_epi64(k: __mmask8) -> __m256i {
+    _mm256_set1_epi64x(k as i64)
+}
+
+/// Broadcasts the low 8 bits from input mask `k` to all 64-bit elements of
+/// `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_broadcastmb_epi64)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vpbroadcast))]
+pub unsafe fn _mm_broadcastmb_epi64(k: __mmask8) -> __m128i {
+    _mm_set1_epi64x(k as i64)
+}
+
+/// Broadcasts the low 16 bits from input mask `k` to all 32-bit elements of
+/// `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_broadcastmw_epi32)
+#[inline]
+#[target_feature(enable = "avx512cd")]
+#[cfg_attr(test, assert_instr(vpbroadcast))]
+pub unsafe fn _mm512_broadcastmw_epi32(k: __mmask16) -> __m512i {
+    _mm512_set1_epi32(k as i32)
+}
+
+/// Broadcasts the low 16 bits from input mask `k` to all 32-bit elements of
+/// `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_broadcastmw_epi32)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vpbroadcast))]
+pub unsafe fn _mm256_broadcastmw_epi32(k: __mmask16) -> __m256i {
+    _mm256_set1_epi32(k as i32)
+}
+
+/// Broadcasts the low 16 bits from input mask `k` to all 32-bit elements of
+/// `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_broadcastmw_epi32)
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vpbroadcast))]
+pub unsafe fn _mm_broadcastmw_epi32(k: __mmask16) -> __m128i {
+    _mm_set1_epi32(k as i32)
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.avx512.conflict.d.512"]
+    fn vpconflictd_512(a: i32x16) -> i32x16;
+    #[link_name = "llvm.x86.avx512.conflict.d.256"]
+    fn vpconflictd_256(a: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx512.conflict.d.128"]
+    fn vpconflictd_128(a: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.avx512.conflict.q.512"]
+    fn vpconflictq_512(a: i64x8) -> i64x8;
+    #[link_name = "llvm.x86.avx512.conflict.q.256"]
+    fn vpconflictq_256(a: i64x4) -> i64x4;
+    #[link_name = "llvm.x86.avx512.conflict.q.128"]
+    fn vpconflictq_128(a: i64x2) -> i64x2;
+    #[link_name = "llvm.ctlz.v16i32"]
+    fn vplzcntd_512(a: i32x16, nonzero: bool) -> i32x16;
+    #[link_name = "llvm.ctlz.v8i32"]
+    fn vplzcntd_256(a: i32x8, nonzero: bool) -> i32x8;
+    #[link_name = "llvm.ctlz.v4i32"]
+    fn vplzcntd_128(a: i32x4, nonzero: bool) -> i32x4;
+    #[link_name = "llvm.ctlz.v8i64"]
+    fn vplzcntq_512(a: i64x8, nonzero: bool) -> i64x8;
+    #[link_name = "llvm.ctlz.v4i64"]
+    fn vplzcntq_256(a: i64x4, nonzero: bool) -> i64x4;
+    #[link_name = "llvm.ctlz.v2i64"]
+    fn vplzcntq_128(a: i64x2, nonzero: bool) -> i64x2;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use std::mem::transmute;
+    use stdsimd_test::simd_test;
+
+    #[simd_test(enable = "avx512cd")]
+    unsafe fn test_mm512_conflict_epi32() {
+        let a = _mm512_setr_epi32(1, 2, 1, 3, 2, 1, 7, 3, 1, 2, 9, 3, 4, 1, 4, 2);
+        let r = _mm512_conflict_epi32(a);
+        let e = _mm512_setr_epi32(0, 0, 1, 0, 2, 5, 0, 8, 37, 18, 0, 136, 0, 293, 4096, 530);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd")]
+    unsafe fn test_mm512_mask_conflict_epi32() {
+        let a = _mm512_setr_epi32(1, 2, 1, 3, 2, 1, 7, 3, 1, 2, 9, 3, 4, 1, 4, 2);
+        let src = _mm512_set1_epi32(-1);
+        let r = _mm512_mask_conflict_epi32(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_conflict_epi32(src, 0b01010101_10011001, a);
+        #[rustfmt::skip]
+        let e = _mm512_setr_epi32(
+            0, -1, -1, 0, 2, -1, -1, 8,
+            37, -1, 0, -1, 0, -1, 4096, -1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd")]
+    unsafe fn test_mm512_maskz_conflict_epi32() {
+        let a = _mm512_setr_epi32(1, 2, 1, 3, 2, 1, 7, 3, 1, 2, 9, 3, 4, 1, 4, 2);
+        let r = _mm512_maskz_conflict_epi32(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_conflict_epi32(0b01010101_10011001, a);
+        let e = _mm512_setr_epi32(0, 0, 0, 0, 2, 0, 0, 8, 37, 0, 0, 0, 0, 0, 4096, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm256_conflict_epi32() {
+        let a = _mm256_setr_epi32(1, 2, 1, 3, 2, 1, 7, 3);
+        let r = _mm256_conflict_epi32(a);
+        let e = _mm256_setr_epi32(0, 0, 1, 0, 2, 5, 0, 8);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm256_mask_conflict_epi32() {
+        let a = _mm256_setr_epi32(1, 2, 1, 3, 2, 1, 7, 3);
+        let src = _mm256_set1_epi32(-1);
+        let r = _mm256_mask_conflict_epi32(src, 0, a);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_conflict_epi32(src, 0b01101001, a);
+        let e = _mm256_setr_epi32(0, -1, -1, 0, -1, 5, 0, -1);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm256_maskz_conflict_epi32() {
+        let a = _mm256_setr_epi32(1, 2, 1, 3, 2, 1, 7, 3);
+        let r = _mm256_maskz_conflict_epi32(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_conflict_epi32(0b01101001, a);
+        let e = _mm256_setr_epi32(0, 0, 0, 0, 0, 5, 0, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm_conflict_epi32() {
+        let a = _mm_setr_epi32(1, 2, 1, 3);
+        let r = _mm_conflict_epi32(a);
+        let e = _mm_setr_epi32(0, 0, 1, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm_mask_conflict_epi32() {
+        let a = _mm_setr_epi32(1, 2, 1, 3);
+        let src = _mm_set1_epi32(-1);
+        let r = _mm_mask_conflict_epi32(src, 0, a);
+        assert_eq_m128i(r, src);
+        let r = _mm_mask_conflict_epi32(src, 0b00001001, a);
+        let e = _mm_setr_epi32(0, -1, -1, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm_maskz_conflict_epi32() {
+        let a = _mm_setr_epi32(1, 2, 1, 3);
+        let r = _mm_maskz_conflict_epi32(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_conflict_epi32(0b00001001, a);
+        let e = _mm_set1_epi32(0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd")]
+    unsafe fn test_mm512_conflict_epi64() {
+        let a = _mm512_setr_epi64(1, 2, 1, 3, 2, 1, 7, 3);
+        let r = _mm512_conflict_epi64(a);
+        let e = _mm512_setr_epi64(0, 0, 1, 0, 2, 5, 0, 8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd")]
+    unsafe fn test_mm512_mask_conflict_epi64() {
+        let a = _mm512_setr_epi64(1, 2, 1, 3, 2, 1, 7, 3);
+        let src = _mm512_set1_epi64(-1);
+        let r = _mm512_mask_conflict_epi64(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_conflict_epi64(src, 0b01101001, a);
+        let e = _mm512_setr_epi64(0, -1, -1, 0, -1, 5, 0, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd")]
+    unsafe fn test_mm512_maskz_conflict_epi64() {
+        let a = _mm512_setr_epi64(1, 2, 1, 3, 2, 1, 7, 3);
+        let r = _mm512_maskz_conflict_epi64(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_conflict_epi64(0b01101001, a);
+        let e = _mm512_setr_epi64(0, 0, 0, 0, 0, 5, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm256_conflict_epi64() {
+        let a = _mm256_setr_epi64x(1, 2, 1, 3);
+        let r = _mm256_conflict_epi64(a);
+        let e = _mm256_setr_epi64x(0, 0, 1, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm256_mask_conflict_epi64() {
+        let a = _mm256_setr_epi64x(1, 2, 1, 3);
+        let src = _mm256_set1_epi64x(-1);
+        let r = _mm256_mask_conflict_epi64(src, 0, a);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_conflict_epi64(src, 0b00001001, a);
+        let e = _mm256_setr_epi64x(0, -1, -1, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm256_maskz_conflict_epi64() {
+        let a = _mm256_setr_epi64x(1, 2, 1, 3);
+        let r = _mm256_maskz_conflict_epi64(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_conflict_epi64(0b00001001, a);
+        let e = _mm256_set1_epi64x(0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm_conflict_epi64() {
+        let a = _mm_set1_epi64x(7);
+        let r = _mm_conflict_epi64(a);
+        let e = _mm_setr_epi64x(0, 1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm_mask_conflict_epi64() {
+        let a = _mm_set1_epi64x(7);
+        let src = _mm_set1_epi64x(-1);
+        let r = _mm_mask_conflict_epi64(src, 0, a);
+        assert_eq_m128i(r, src);
+        let r = _mm_mask_conflict_epi64(src, 0b00000001, a);
+        let e = _mm_setr_epi64x(0, -1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm_maskz_conflict_epi64() {
+        let a = _mm_set1_epi64x(7);
+        let r = _mm_maskz_conflict_epi64(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_conflict_epi64(0b00000001, a);
+        let e = _mm_set1_epi64x(0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd")]
+    unsafe fn test_mm512_lzcnt_epi32() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi32(
+            0, 1, 2, 3, 15, 16, 255, 256,
+            2147483647, -1, 1048576, 4096, 7, 8, 65535, 65536,
+        );
+        let r = _mm512_lzcnt_epi32(a);
+        #[rustfmt::skip]
+        let e = _mm512_setr_epi32(
+            32, 31, 30, 30, 28, 27, 24, 23,
+            1, 0, 11, 19, 29, 28, 16, 15,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd")]
+    unsafe fn test_mm512_mask_lzcnt_epi32() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi32(
+            0, 1, 2, 3, 15, 16, 255, 256,
+            2147483647, -1, 1048576, 4096, 7, 8, 65535, 65536,
+        );
+        let src = _mm512_set1_epi32(-1);
+        let r = _mm512_mask_lzcnt_epi32(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_lzcnt_epi32(src, 0b01010101_10011001, a);
+        #[rustfmt::skip]
+        let e = _mm512_setr_epi32(
+            32, -1, -1, 30, 28, -1, -1, 23,
+            1, -1, 11, -1, 29, -1, 16, -1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd")]
+    unsafe fn test_mm512_maskz_lzcnt_epi32() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi32(
+            0, 1, 2, 3, 15, 16, 255, 256,
+            2147483647, -1, 1048576, 4096, 7, 8, 65535, 65536,
+        );
+        let r = _mm512_maskz_lzcnt_epi32(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_lzcnt_epi32(0b01010101_10011001, a);
+        let e = _mm512_setr_epi32(32, 0, 0, 30, 28, 0, 0, 23, 1, 0, 11, 0, 29, 0, 16, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm256_lzcnt_epi32() {
+        let a = _mm256_setr_epi32(0, 1, 2, 3, 15, 16, 255, 256);
+        let r = _mm256_lzcnt_epi32(a);
+        let e = _mm256_setr_epi32(32, 31, 30, 30, 28, 27, 24, 23);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm256_mask_lzcnt_epi32() {
+        let a = _mm256_setr_epi32(0, 1, 2, 3, 15, 16, 255, 256);
+        let src = _mm256_set1_epi32(-1);
+        let r = _mm256_mask_lzcnt_epi32(src, 0, a);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_lzcnt_epi32(src, 0b01101001, a);
+        let e = _mm256_setr_epi32(32, -1, -1, 30, -1, 27, 24, -1);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm256_maskz_lzcnt_epi32() {
+        let a = _mm256_setr_epi32(0, 1, 2, 3, 15, 16, 255, 256);
+        let r = _mm256_maskz_lzcnt_epi32(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_lzcnt_epi32(0b01101001, a);
+        let e = _mm256_setr_epi32(32, 0, 0, 30, 0, 27, 24, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm_lzcnt_epi32() {
+        let a = _mm_setr_epi32(0, 1, 2, 3);
+        let r = _mm_lzcnt_epi32(a);
+        let e = _mm_setr_epi32(32, 31, 30, 30);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm_mask_lzcnt_epi32() {
+        let a = _mm_setr_epi32(0, 1, 2, 3);
+        let src = _mm_set1_epi32(-1);
+        let r = _mm_mask_lzcnt_epi32(src, 0, a);
+        assert_eq_m128i(r, src);
+        let r = _mm_mask_lzcnt_epi32(src, 0b00001001, a);
+        let e = _mm_setr_epi32(32, -1, -1, 30);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm_maskz_lzcnt_epi32() {
+        let a = _mm_setr_epi32(0, 1, 2, 3);
+        let r = _mm_maskz_lzcnt_epi32(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_lzcnt_epi32(0b00001001, a);
+        let e = _mm_setr_epi32(32, 0, 0, 30);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd")]
+    unsafe fn test_mm512_lzcnt_epi64() {
+        let a = _mm512_setr_epi64(0, 1, 2, 4294967296, -1, 255, 4611686018427387909, 65536);
+        let r = _mm512_lzcnt_epi64(a);
+        let e = _mm512_setr_epi64(64, 63, 62, 31, 0, 56, 1, 47);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd")]
+    unsafe fn test_mm512_mask_lzcnt_epi64() {
+        let a = _mm512_setr_epi64(0, 1, 2, 4294967296, -1, 255, 4611686018427387909, 65536);
+        let src = _mm512_set1_epi64(-1);
+        let r = _mm512_mask_lzcnt_epi64(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_lzcnt_epi64(src, 0b01101001, a);
+        let e = _mm512_setr_epi64(64, -1, -1, 31, -1, 56, 1, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd")]
+    unsafe fn test_mm512_maskz_lzcnt_epi64() {
+        let a = _mm512_setr_epi64(0, 1, 2, 4294967296, -1, 255, 4611686018427387909, 65536);
+        let r = _mm512_maskz_lzcnt_epi64(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_lzcnt_epi64(0b01101001, a);
+        let e = _mm512_setr_epi64(64, 0, 0, 31, 0, 56, 1, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm256_lzcnt_epi64() {
+        let a = _mm256_setr_epi64x(0, 1, 2, 4294967296);
+        let r = _mm256_lzcnt_epi64(a);
+        let e = _mm256_setr_epi64x(64, 63, 62, 31);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm256_mask_lzcnt_epi64() {
+        let a = _mm256_setr_epi64x(0, 1, 2, 4294967296);
+        let src = _mm256_set1_epi64x(-1);
+        let r = _mm256_mask_lzcnt_epi64(src, 0, a);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_lzcnt_epi64(src, 0b00001001, a);
+        let e = _mm256_setr_epi64x(64, -1, -1, 31);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm256_maskz_lzcnt_epi64() {
+        let a = _mm256_setr_epi64x(0, 1, 2, 4294967296);
+        let r = _mm256_maskz_lzcnt_epi64(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_lzcnt_epi64(0b00001001, a);
+        let e = _mm256_setr_epi64x(64, 0, 0, 31);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm_lzcnt_epi64() {
+        let a = _mm_setr_epi64x(0, 1);
+        let r = _mm_lzcnt_epi64(a);
+        let e = _mm_setr_epi64x(64, 63);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm_mask_lzcnt_epi64() {
+        let a = _mm_setr_epi64x(0, 1);
+        let src = _mm_set1_epi64x(-1);
+        let r = _mm_mask_lzcnt_epi64(src, 0, a);
+        assert_eq_m128i(r, src);
+        let r = _mm_mask_lzcnt_epi64(src, 0b00000001, a);
+        let e = _mm_setr_epi64x(64, -1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm_maskz_lzcnt_epi64() {
+        let a = _mm_setr_epi64x(0, 1);
+        let r = _mm_maskz_lzcnt_epi64(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_lzcnt_epi64(0b00000001, a);
+        let e = _mm_setr_epi64x(64, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd")]
+    unsafe fn test_mm512_broadcastmb_epi64() {
+        let a: __mmask8 = 0b01101001;
+        let r = _mm512_broadcastmb_epi64(a);
+        let e = _mm512_set1_epi64(0b01101001);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm256_broadcastmb_epi64() {
+        let a: __mmask8 = 0b01101001;
+        let r = _mm256_broadcastmb_epi64(a);
+        let e = _mm256_set1_epi64x(0b01101001);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm_broadcastmb_epi64() {
+        let a: __mmask8 = 0b01101001;
+        let r = _mm_broadcastmb_epi64(a);
+        let e = _mm_set1_epi64x(0b01101001);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd")]
+    unsafe fn test_mm512_broadcastmw_epi32() {
+        let a: __mmask16 = 0b11001010_01101001;
+        let r = _mm512_broadcastmw_epi32(a);
+        let e = _mm512_set1_epi32(0b11001010_01101001);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm256_broadcastmw_epi32() {
+        let a: __mmask16 = 0b11001010_01101001;
+        let r = _mm256_broadcastmw_epi32(a);
+        let e = _mm256_set1_epi32(0b11001010_01101001);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm_broadcastmw_epi32() {
+        let a: __mmask16 = 0b11001010_01101001;
+        let r = _mm_broadcastmw_epi32(a);
+        let e = _mm_set1_epi32(0b11001010_01101001);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512cd,avx512vl")]
+    unsafe fn test_mm256_conflict_epi32_histogram() {
+        // Gathers the current bucket counts with AVX2, then uses the conflict
+        // bits to fold duplicate indices so the scalar scatter never loses an
+        // increment.
+        let mut hist = [10i32; 8];
+        let idx = _mm256_setr_epi32(1, 3, 1, 0, 3, 1, 5, 0);
+        let counts = _mm256_i32gather_epi32(hist.as_ptr(), idx, 4);
+        let conflicts = _mm256_conflict_epi32(idx);
+        let e = _mm256_setr_epi32(0, 0, 0b1, 0, 0b10, 0b101, 0, 0b1000);
+        assert_eq_m256i(conflicts, e);
+        // Each lane adds one plus the number of earlier lanes with the same
+        // index, so the last occurrence of every index carries the total.
+        let counts: [i32; 8] = transmute(counts);
+        let conflicts: [u32; 8] = transmute(conflicts);
+        let idx: [i32; 8] = transmute(idx);
+        for i in 0..8 {
+            hist[idx[i] as usize] = counts[i] + conflicts[i].count_ones() as i32 + 1;
+        }
+        assert_eq!(hist, [12, 13, 10, 12, 10, 11, 10, 10]);
+    }
+}
@@ -0,0 +1,3960 @@
+use crate::{
+    core_arch::{simd::*, simd_llvm::*, x86::*},
+    mem::transmute,
+};
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Computes the bitwise AND of 8-bit masks `a` and `b`, and returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kand_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(and))] // not kandb: LLVM keeps the mask in a GPR
+pub unsafe fn _kand_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    a & b
+}
+
+/// Computes the bitwise NOT of 8-bit mask `a` and then AND with `b`, and
+/// returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kandn_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(not))] // not kandnb: LLVM keeps the mask in a GPR
+pub unsafe fn _kandn_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    !a & b
+}
+
+/// Computes the bitwise OR of 8-bit masks `a` and `b`, and returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kor_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(or))] // not korb: LLVM keeps the mask in a GPR
+pub unsafe fn _kor_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    a | b
+}
+
+/// Computes the bitwise XNOR of 8-bit masks `a` and `b`, and returns the
+/// result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kxnor_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(xor))] // not kxnorb: LLVM keeps the mask in a GPR
+pub unsafe fn _kxnor_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    !(a ^ b)
+}
+
+/// Computes the bitwise XOR of 8-bit masks `a` and `b`, and returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kxor_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(xor))] // not kxorb: LLVM keeps the mask in a GPR
+pub unsafe fn _kxor_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    a ^ b
+}
+
+/// Computes the bitwise NOT of 8-bit mask `a`, and returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_knot_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(not))] // not knotb: LLVM keeps the mask in a GPR
+pub unsafe fn _knot_mask8(a: __mmask8) -> __mmask8 {
+    !a
+}
+
+/// Shifts the bits of 8-bit mask `a` left by `count` while shifting in zeros,
+/// and returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kshiftli_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(shl, count = 5))] // not kshiftlb: LLVM keeps the mask in a GPR
+#[rustc_args_required_const(1)]
+pub unsafe fn _kshiftli_mask8(a: __mmask8, count: u32) -> __mmask8 {
+    a.checked_shl(count).unwrap_or(0)
+}
+
+/// Shifts the bits of 8-bit mask `a` right by `count` while shifting in zeros,
+/// and returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kshiftri_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(shr, count = 3))] // not kshiftrb: LLVM keeps the mask in a GPR
+#[rustc_args_required_const(1)]
+pub unsafe fn _kshiftri_mask8(a: __mmask8, count: u32) -> __mmask8 {
+    a.checked_shr(count).unwrap_or(0)
+}
+
+/// Computes the bitwise OR of 8-bit masks `a` and `b`. If the result is all
+/// zeros, returns 1, otherwise returns 0.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortestz_mask8_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(or))] // not kortestb: LLVM keeps the mask in a GPR
+pub unsafe fn _kortestz_mask8_u8(a: __mmask8, b: __mmask8) -> u8 {
+    ((a | b) == 0) as u8
+}
+
+/// Computes the bitwise OR of 8-bit masks `a` and `b`. If the result is all
+/// ones, returns 1, otherwise returns 0.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortestc_mask8_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(or))] // not kortestb: LLVM keeps the mask in a GPR
+pub unsafe fn _kortestc_mask8_u8(a: __mmask8, b: __mmask8) -> u8 {
+    ((a | b) == !0) as u8
+}
+
+/// Computes the bitwise OR of 8-bit masks `a` and `b`. If the result is all
+/// zeros, returns 1, otherwise returns 0. If the result is all ones, stores 1
+/// in `all_ones`, otherwise stores 0 in `all_ones`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_kortest_mask8_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(or))] // not kortestb: LLVM keeps the mask in a GPR
+pub unsafe fn _kortest_mask8_u8(a: __mmask8, b: __mmask8, all_ones: *mut u8) -> u8 {
+    *all_ones = ((a | b) == !0) as u8;
+    ((a | b) == 0) as u8
+}
+
+/// Computes the bitwise AND of 8-bit masks `a` and `b`. If the result is all
+/// zeros, returns 1, otherwise returns 0.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktestz_mask8_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(test))] // not ktestb: LLVM keeps the mask in a GPR
+pub unsafe fn _ktestz_mask8_u8(a: __mmask8, b: __mmask8) -> u8 {
+    ((a & b) == 0) as u8
+}
+
+/// Computes the bitwise NOT of 8-bit mask `a` and then AND with `b`. If the
+/// result is all zeros, returns 1, otherwise returns 0.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktestc_mask8_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(and))] // not ktestb: LLVM keeps the mask in a GPR
+pub unsafe fn _ktestc_mask8_u8(a: __mmask8, b: __mmask8) -> u8 {
+    ((!a & b) == 0) as u8
+}
+
+/// Computes the bitwise AND of 8-bit masks `a` and `b`. If the result is all
+/// zeros, returns 1, otherwise returns 0. Computes the bitwise NOT of `a` and
+/// then AND with `b`. If the result is all zeros, stores 1 in `and_not`,
+/// otherwise stores 0 in `and_not`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktest_mask8_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(and))] // not ktestb: LLVM keeps the mask in a GPR
+pub unsafe fn _ktest_mask8_u8(a: __mmask8, b: __mmask8, and_not: *mut u8) -> u8 {
+    *and_not = ((!a & b) == 0) as u8;
+    ((a & b) == 0) as u8
+}
+
+/// Computes the bitwise AND of 16-bit masks `a` and `b`. If the result is all
+/// zeros, returns 1, otherwise returns 0.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktestz_mask16_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(test))] // not ktestw: LLVM keeps the mask in a GPR
+pub unsafe fn _ktestz_mask16_u8(a: __mmask16, b: __mmask16) -> u8 {
+    ((a & b) == 0) as u8
+}
+
+/// Computes the bitwise NOT of 16-bit mask `a` and then AND with `b`. If the
+/// result is all zeros, returns 1, otherwise returns 0.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktestc_mask16_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(and))] // not ktestw: LLVM keeps the mask in a GPR
+pub unsafe fn _ktestc_mask16_u8(a: __mmask16, b: __mmask16) -> u8 {
+    ((!a & b) == 0) as u8
+}
+
+/// Computes the bitwise AND of 16-bit masks `a` and `b`. If the result is all
+/// zeros, returns 1, otherwise returns 0. Computes the bitwise NOT of `a` and
+/// then AND with `b`. If the result is all zeros, stores 1 in `and_not`,
+/// otherwise stores 0 in `and_not`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_ktest_mask16_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(and))] // not ktestw: LLVM keeps the mask in a GPR
+pub unsafe fn _ktest_mask16_u8(a: __mmask16, b: __mmask16, and_not: *mut u8) -> u8 {
+    *and_not = ((!a & b) == 0) as u8;
+    ((a & b) == 0) as u8
+}
+
+/// Converts 8-bit mask `a` into an integer value, and returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_cvtmask8_u32)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(mov))] // not kmovb: LLVM keeps the mask in a GPR
+pub unsafe fn _cvtmask8_u32(a: __mmask8) -> u32 {
+    a as u32
+}
+
+/// Converts integer value `a` into a 8-bit mask, and returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_cvtu32_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(mov))] // not kmovb: LLVM keeps the mask in a GPR
+pub unsafe fn _cvtu32_mask8(a: u32) -> __mmask8 {
+    a as __mmask8
+}
+
+/// Loads 8-bit mask from memory, and returns the result.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_load_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(mov))] // not kmovb: LLVM keeps the mask in a GPR
+pub unsafe fn _load_mask8(mem_addr: *const __mmask8) -> __mmask8 {
+    *mem_addr
+}
+
+/// Stores 8-bit mask `a` into memory.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_store_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(mov))] // not kmovb: LLVM keeps the mask in a GPR
+pub unsafe fn _store_mask8(mem_addr: *mut __mmask8, a: __mmask8) {
+    *mem_addr = a;
+}
+
+/// Sets each bit of mask register `k` based on the most significant bit of the
+/// corresponding packed 32-bit integer in `a`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_movepi32_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vpmovd2m))]
+pub unsafe fn _mm512_movepi32_mask(a: __m512i) -> __mmask16 {
+    let zero = i32x16::splat(0);
+    let m: i32x16 = simd_lt(a.as_i32x16(), zero);
+    simd_bitmask(m)
+}
+
+/// Sets each packed 32-bit integer in `dst` to all ones or all zeros based on
+/// the value of the corresponding bit in `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_movm_epi32)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vpmovm2d))]
+pub unsafe fn _mm512_movm_epi32(k: __mmask16) -> __m512i {
+    let one = i32x16::splat(-1);
+    let zero = i32x16::splat(0);
+    transmute(simd_select_bitmask(k, one, zero))
+}
+
+/// Sets each bit of mask register `k` based on the most significant bit of the
+/// corresponding packed 64-bit integer in `a`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_movepi64_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vpmovq2m))]
+pub unsafe fn _mm512_movepi64_mask(a: __m512i) -> __mmask8 {
+    let zero = i64x8::splat(0);
+    let m: i64x8 = simd_lt(a.as_i64x8(), zero);
+    simd_bitmask(m)
+}
+
+/// Sets each packed 64-bit integer in `dst` to all ones or all zeros based on
+/// the value of the corresponding bit in `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_movm_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vpmovm2q))]
+pub unsafe fn _mm512_movm_epi64(k: __mmask8) -> __m512i {
+    let one = i64x8::splat(-1);
+    let zero = i64x8::splat(0);
+    transmute(simd_select_bitmask(k, one, zero))
+}
+
+/// Multiplies the packed 64-bit integers in `a` and `b`, producing intermediate
+/// 128-bit integers, and returns the low 64 bits of the intermediate integers.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm512_mullo_epi64(a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_mul(a.as_i64x8(), b.as_i64x8()))
+}
+
+/// Multiplies the packed 64-bit integers in `a` and `b`, producing intermediate
+/// 128-bit integers, and stores the low 64 bits of the intermediate integers in
+/// `dst` using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm512_mask_mullo_epi64(
+    src: __m512i,
+    k: __mmask8,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let mul = _mm512_mullo_epi64(a, b).as_i64x8();
+    transmute(simd_select_bitmask(k, mul, src.as_i64x8()))
+}
+
+/// Multiplies the packed 64-bit integers in `a` and `b`, producing intermediate
+/// 128-bit integers, and stores the low 64 bits of the intermediate integers in
+/// `dst` using zeromask `k` (elements are zeroed out when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm512_maskz_mullo_epi64(k: __mmask8, a: __m512i, b: __m512i) -> __m512i {
+    let mul = _mm512_mullo_epi64(a, b).as_i64x8();
+    let zero = _mm512_setzero_si512().as_i64x8();
+    transmute(simd_select_bitmask(k, mul, zero))
+}
+
+/// Multiplies the packed 64-bit integers in `a` and `b`, producing intermediate
+/// 128-bit integers, and returns the low 64 bits of the intermediate integers.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm256_mullo_epi64(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_mul(a.as_i64x4(), b.as_i64x4()))
+}
+
+/// Multiplies the packed 64-bit integers in `a` and `b`, producing intermediate
+/// 128-bit integers, and stores the low 64 bits of the intermediate integers in
+/// `dst` using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm256_mask_mullo_epi64(
+    src: __m256i,
+    k: __mmask8,
+    a: __m256i,
+    b: __m256i,
+) -> __m256i {
+    let mul = _mm256_mullo_epi64(a, b).as_i64x4();
+    transmute(simd_select_bitmask(k, mul, src.as_i64x4()))
+}
+
+/// Multiplies the packed 64-bit integers in `a` and `b`, producing intermediate
+/// 128-bit integers, and stores the low 64 bits of the intermediate integers in
+/// `dst` using zeromask `k` (elements are zeroed out when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm256_maskz_mullo_epi64(k: __mmask8, a: __m256i, b: __m256i) -> __m256i {
+    let mul = _mm256_mullo_epi64(a, b).as_i64x4();
+    let zero = _mm256_setzero_si256().as_i64x4();
+    transmute(simd_select_bitmask(k, mul, zero))
+}
+
+/// Multiplies the packed 64-bit integers in `a` and `b`, producing intermediate
+/// 128-bit integers, and returns the low 64 bits of the intermediate integers.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm_mullo_epi64(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_mul(a.as_i64x2(), b.as_i64x2()))
+}
+
+/// Multiplies the packed 64-bit integers in `a` and `b`, producing intermediate
+/// 128-bit integers, and stores the low 64 bits of the intermediate integers in
+/// `dst` using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm_mask_mullo_epi64(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i {
+    let mul = _mm_mullo_epi64(a, b).as_i64x2();
+    transmute(simd_select_bitmask(k, mul, src.as_i64x2()))
+}
+
+/// Multiplies the packed 64-bit integers in `a` and `b`, producing intermediate
+/// 128-bit integers, and stores the low 64 bits of the intermediate integers in
+/// `dst` using zeromask `k` (elements are zeroed out when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm_maskz_mullo_epi64(k: __mmask8, a: __m128i, b: __m128i) -> __m128i {
+    let mul = _mm_mullo_epi64(a, b).as_i64x2();
+    let zero = _mm_setzero_si128().as_i64x2();
+    transmute(simd_select_bitmask(k, mul, zero))
+}
+
+/// Converts packed 64-bit integers in `a` to packed double-precision (64-bit)
+/// floating-point elements, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm512_cvtepi64_pd(a: __m512i) -> __m512d {
+    simd_cast(a.as_i64x8())
+}
+
+/// Converts packed 64-bit integers in `a` to packed double-precision (64-bit)
+/// floating-point elements, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm512_mask_cvtepi64_pd(src: __m512d, k: __mmask8, a: __m512i) -> __m512d {
+    let convert: __m512d = simd_cast(a.as_i64x8());
+    simd_select_bitmask(k, convert, src)
+}
+
+/// Converts packed 64-bit integers in `a` to packed double-precision (64-bit)
+/// floating-point elements, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm512_maskz_cvtepi64_pd(k: __mmask8, a: __m512i) -> __m512d {
+    let convert: __m512d = simd_cast(a.as_i64x8());
+    simd_select_bitmask(k, convert, _mm512_setzero_pd())
+}
+
+/// Converts packed 64-bit integers in `a` to packed double-precision (64-bit)
+/// floating-point elements, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm256_cvtepi64_pd(a: __m256i) -> __m256d {
+    simd_cast(a.as_i64x4())
+}
+
+/// Converts packed 64-bit integers in `a` to packed double-precision (64-bit)
+/// floating-point elements, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm256_mask_cvtepi64_pd(src: __m256d, k: __mmask8, a: __m256i) -> __m256d {
+    let convert = _mm256_cvtepi64_pd(a);
+    simd_select_bitmask(k, convert, src)
+}
+
+/// Converts packed 64-bit integers in `a` to packed double-precision (64-bit)
+/// floating-point elements, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm256_maskz_cvtepi64_pd(k: __mmask8, a: __m256i) -> __m256d {
+    let convert = _mm256_cvtepi64_pd(a);
+    simd_select_bitmask(k, convert, _mm256_setzero_pd())
+}
+
+/// Converts packed 64-bit integers in `a` to packed double-precision (64-bit)
+/// floating-point elements, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm_cvtepi64_pd(a: __m128i) -> __m128d {
+    simd_cast(a.as_i64x2())
+}
+
+/// Converts packed 64-bit integers in `a` to packed double-precision (64-bit)
+/// floating-point elements, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm_mask_cvtepi64_pd(src: __m128d, k: __mmask8, a: __m128i) -> __m128d {
+    let convert = _mm_cvtepi64_pd(a);
+    simd_select_bitmask(k, convert, src)
+}
+
+/// Converts packed 64-bit integers in `a` to packed double-precision (64-bit)
+/// floating-point elements, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm_maskz_cvtepi64_pd(k: __mmask8, a: __m128i) -> __m128d {
+    let convert = _mm_cvtepi64_pd(a);
+    simd_select_bitmask(k, convert, _mm_setzero_pd())
+}
+
+/// Converts packed 64-bit integers in `a` to packed single-precision (32-bit)
+/// floating-point elements, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps))]
+pub unsafe fn _mm512_cvtepi64_ps(a: __m512i) -> __m256 {
+    simd_cast(a.as_i64x8())
+}
+
+/// Converts packed 64-bit integers in `a` to packed single-precision (32-bit)
+/// floating-point elements, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps))]
+pub unsafe fn _mm512_mask_cvtepi64_ps(src: __m256, k: __mmask8, a: __m512i) -> __m256 {
+    let convert: __m256 = simd_cast(a.as_i64x8());
+    simd_select_bitmask(k, convert, src)
+}
+
+/// Converts packed 64-bit integers in `a` to packed single-precision (32-bit)
+/// floating-point elements, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps))]
+pub unsafe fn _mm512_maskz_cvtepi64_ps(k: __mmask8, a: __m512i) -> __m256 {
+    let convert: __m256 = simd_cast(a.as_i64x8());
+    simd_select_bitmask(k, convert, _mm256_setzero_ps())
+}
+
+/// Converts packed unsigned 64-bit integers in `a` to packed double-precision
+/// (64-bit) floating-point elements, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd))]
+pub unsafe fn _mm512_cvtepu64_pd(a: __m512i) -> __m512d {
+    simd_cast(a.as_u64x8())
+}
+
+/// Converts packed unsigned 64-bit integers in `a` to packed double-precision
+/// (64-bit) floating-point elements, and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd))]
+pub unsafe fn _mm512_mask_cvtepu64_pd(src: __m512d, k: __mmask8, a: __m512i) -> __m512d {
+    let convert: __m512d = simd_cast(a.as_u64x8());
+    simd_select_bitmask(k, convert, src)
+}
+
+/// Converts packed unsigned 64-bit integers in `a` to packed double-precision
+/// (64-bit) floating-point elements, and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd))]
+pub unsafe fn _mm512_maskz_cvtepu64_pd(k: __mmask8, a: __m512i) -> __m512d {
+    let convert: __m512d = simd_cast(a.as_u64x8());
+    simd_select_bitmask(k, convert, _mm512_setzero_pd())
+}
+
+/// Converts packed unsigned 64-bit integers in `a` to packed single-precision
+/// (32-bit) floating-point elements, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps))]
+pub unsafe fn _mm512_cvtepu64_ps(a: __m512i) -> __m256 {
+    simd_cast(a.as_u64x8())
+}
+
+/// Converts packed unsigned 64-bit integers in `a` to packed single-precision
+/// (32-bit) floating-point elements, and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps))]
+pub unsafe fn _mm512_mask_cvtepu64_ps(src: __m256, k: __mmask8, a: __m512i) -> __m256 {
+    let convert: __m256 = simd_cast(a.as_u64x8());
+    simd_select_bitmask(k, convert, src)
+}
+
+/// Converts packed unsigned 64-bit integers in `a` to packed single-precision
+/// (32-bit) floating-point elements, and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps))]
+pub unsafe fn _mm512_maskz_cvtepu64_ps(k: __mmask8, a: __m512i) -> __m256 {
+    let convert: __m256 = simd_cast(a.as_u64x8());
+    simd_select_bitmask(k, convert, _mm256_setzero_ps())
+}
+
+/// Converts packed double-precision (64-bit) floating-point elements in `a` to
+/// packed 64-bit integers, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq))]
+pub unsafe fn _mm512_cvtpd_epi64(a: __m512d) -> __m512i {
+    transmute(vcvtpd2qq(
+        a,
+        _mm512_setzero_si512().as_i64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Converts packed double-precision (64-bit) floating-point elements in `a` to
+/// packed 64-bit integers, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq))]
+pub unsafe fn _mm512_mask_cvtpd_epi64(src: __m512i, k: __mmask8, a: __m512d) -> __m512i {
+    transmute(vcvtpd2qq(a, src.as_i64x8(), k, _MM_FROUND_CUR_DIRECTION))
+}
+
+/// Converts packed double-precision (64-bit) floating-point elements in `a` to
+/// packed 64-bit integers, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq))]
+pub unsafe fn _mm512_maskz_cvtpd_epi64(k: __mmask8, a: __m512d) -> __m512i {
+    transmute(vcvtpd2qq(
+        a,
+        _mm512_setzero_si512().as_i64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed 64-bit integers, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2qq))]
+pub unsafe fn _mm512_cvtps_epi64(a: __m256) -> __m512i {
+    transmute(vcvtps2qq(
+        a,
+        _mm512_setzero_si512().as_i64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed 64-bit integers, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2qq))]
+pub unsafe fn _mm512_mask_cvtps_epi64(src: __m512i, k: __mmask8, a: __m256) -> __m512i {
+    transmute(vcvtps2qq(a, src.as_i64x8(), k, _MM_FROUND_CUR_DIRECTION))
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed 64-bit integers, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2qq))]
+pub unsafe fn _mm512_maskz_cvtps_epi64(k: __mmask8, a: __m256) -> __m512i {
+    transmute(vcvtps2qq(
+        a,
+        _mm512_setzero_si512().as_i64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Converts packed double-precision (64-bit) floating-point elements in `a` to
+/// packed unsigned 64-bit integers, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq))]
+pub unsafe fn _mm512_cvtpd_epu64(a: __m512d) -> __m512i {
+    transmute(vcvtpd2uqq(
+        a,
+        _mm512_setzero_si512().as_u64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Converts packed double-precision (64-bit) floating-point elements in `a` to
+/// packed unsigned 64-bit integers, and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq))]
+pub unsafe fn _mm512_mask_cvtpd_epu64(src: __m512i, k: __mmask8, a: __m512d) -> __m512i {
+    transmute(vcvtpd2uqq(a, src.as_u64x8(), k, _MM_FROUND_CUR_DIRECTION))
+}
+
+/// Converts packed double-precision (64-bit) floating-point elements in `a` to
+/// packed unsigned 64-bit integers, and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq))]
+pub unsafe fn _mm512_maskz_cvtpd_epu64(k: __mmask8, a: __m512d) -> __m512i {
+    transmute(vcvtpd2uqq(
+        a,
+        _mm512_setzero_si512().as_u64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed unsigned 64-bit integers, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq))]
+pub unsafe fn _mm512_cvtps_epu64(a: __m256) -> __m512i {
+    transmute(vcvtps2uqq(
+        a,
+        _mm512_setzero_si512().as_u64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed unsigned 64-bit integers, and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq))]
+pub unsafe fn _mm512_mask_cvtps_epu64(src: __m512i, k: __mmask8, a: __m256) -> __m512i {
+    transmute(vcvtps2uqq(a, src.as_u64x8(), k, _MM_FROUND_CUR_DIRECTION))
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed unsigned 64-bit integers, and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq))]
+pub unsafe fn _mm512_maskz_cvtps_epu64(k: __mmask8, a: __m256) -> __m512i {
+    transmute(vcvtps2uqq(
+        a,
+        _mm512_setzero_si512().as_u64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Converts packed double-precision (64-bit) floating-point elements in `a` to
+/// packed 64-bit integers with truncation, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvttpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq))]
+pub unsafe fn _mm512_cvttpd_epi64(a: __m512d) -> __m512i {
+    transmute(vcvttpd2qq(
+        a,
+        _mm512_setzero_si512().as_i64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Converts packed double-precision (64-bit) floating-point elements in `a` to
+/// packed 64-bit integers with truncation, and stores the results in `dst`
+/// using writemask `k` (elements are copied from `src` when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvttpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq))]
+pub unsafe fn _mm512_mask_cvttpd_epi64(src: __m512i, k: __mmask8, a: __m512d) -> __m512i {
+    transmute(vcvttpd2qq(a, src.as_i64x8(), k, _MM_FROUND_CUR_DIRECTION))
+}
+
+/// Converts packed double-precision (64-bit) floating-point elements in `a` to
+/// packed 64-bit integers with truncation, and stores the results in `dst`
+/// using zeromask `k` (elements are zeroed out when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvttpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq))]
+pub unsafe fn _mm512_maskz_cvttpd_epi64(k: __mmask8, a: __m512d) -> __m512i {
+    transmute(vcvttpd2qq(
+        a,
+        _mm512_setzero_si512().as_i64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed 64-bit integers with truncation, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvttps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2qq))]
+pub unsafe fn _mm512_cvttps_epi64(a: __m256) -> __m512i {
+    transmute(vcvttps2qq(
+        a,
+        _mm512_setzero_si512().as_i64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed 64-bit integers with truncation, and stores the results in `dst`
+/// using writemask `k` (elements are copied from `src` when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvttps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2qq))]
+pub unsafe fn _mm512_mask_cvttps_epi64(src: __m512i, k: __mmask8, a: __m256) -> __m512i {
+    transmute(vcvttps2qq(a, src.as_i64x8(), k, _MM_FROUND_CUR_DIRECTION))
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed 64-bit integers with truncation, and stores the results in `dst`
+/// using zeromask `k` (elements are zeroed out when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvttps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2qq))]
+pub unsafe fn _mm512_maskz_cvttps_epi64(k: __mmask8, a: __m256) -> __m512i {
+    transmute(vcvttps2qq(
+        a,
+        _mm512_setzero_si512().as_i64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Converts packed double-precision (64-bit) floating-point elements in `a` to
+/// packed unsigned 64-bit integers with truncation, and stores the results in
+/// `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvttpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq))]
+pub unsafe fn _mm512_cvttpd_epu64(a: __m512d) -> __m512i {
+    transmute(vcvttpd2uqq(
+        a,
+        _mm512_setzero_si512().as_u64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Converts packed double-precision (64-bit) floating-point elements in `a` to
+/// packed unsigned 64-bit integers with truncation, and stores the results in
+/// `dst` using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvttpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq))]
+pub unsafe fn _mm512_mask_cvttpd_epu64(src: __m512i, k: __mmask8, a: __m512d) -> __m512i {
+    transmute(vcvttpd2uqq(a, src.as_u64x8(), k, _MM_FROUND_CUR_DIRECTION))
+}
+
+/// Converts packed double-precision (64-bit) floating-point elements in `a` to
+/// packed unsigned 64-bit integers with truncation, and stores the results in
+/// `dst` using zeromask `k` (elements are zeroed out when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvttpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq))]
+pub unsafe fn _mm512_maskz_cvttpd_epu64(k: __mmask8, a: __m512d) -> __m512i {
+    transmute(vcvttpd2uqq(
+        a,
+        _mm512_setzero_si512().as_u64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed unsigned 64-bit integers with truncation, and stores the results in
+/// `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvttps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq))]
+pub unsafe fn _mm512_cvttps_epu64(a: __m256) -> __m512i {
+    transmute(vcvttps2uqq(
+        a,
+        _mm512_setzero_si512().as_u64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed unsigned 64-bit integers with truncation, and stores the results in
+/// `dst` using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvttps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq))]
+pub unsafe fn _mm512_mask_cvttps_epu64(src: __m512i, k: __mmask8, a: __m256) -> __m512i {
+    transmute(vcvttps2uqq(a, src.as_u64x8(), k, _MM_FROUND_CUR_DIRECTION))
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed unsigned 64-bit integers with truncation, and stores the results in
+/// `dst` using zeromask `k` (elements are zeroed out when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvttps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq))]
+pub unsafe fn _mm512_maskz_cvttps_epu64(k: __mmask8, a: __m256) -> __m512i {
+    transmute(vcvttps2uqq(
+        a,
+        _mm512_setzero_si512().as_u64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Calculates the max, min, absolute max, or absolute min (depending on control
+/// in `imm8`) for packed single-precision (32-bit) floating-point elements in
+/// `a` and `b`, and stores the results in `dst`.
+///
+/// `imm8[1:0]` specifies the operation: 00 = min, 01 = max, 10 = absolute min,
+/// 11 = absolute max. `imm8[3:2]` specifies the sign control: 00 = sign from
+/// `a`, 01 = sign from the compare result, 10 = clear sign bit, 11 = set sign
+/// bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_range_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangeps, imm8 = 5))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_range_ps(a: __m512, b: __m512, imm8: i32) -> __m512 {
+    macro_rules! call {
+        ($imm4:expr) => {
+            vrangeps(
+                a,
+                b,
+                $imm4,
+                _mm512_setzero_ps(),
+                0b11111111_11111111,
+                _MM_FROUND_CUR_DIRECTION,
+            )
+        };
+    }
+    constify_imm4!(imm8, call)
+}
+
+/// Calculates the max, min, absolute max, or absolute min (depending on control
+/// in `imm8`) for packed single-precision (32-bit) floating-point elements in
+/// `a` and `b`, and stores the results in `dst` using writemask `k` (elements
+/// are copied from `src` when the corresponding mask bit is not set).
+///
+/// `imm8[1:0]` specifies the operation: 00 = min, 01 = max, 10 = absolute min,
+/// 11 = absolute max. `imm8[3:2]` specifies the sign control: 00 = sign from
+/// `a`, 01 = sign from the compare result, 10 = clear sign bit, 11 = set sign
+/// bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_range_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangeps, imm8 = 5))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm512_mask_range_ps(
+    src: __m512,
+    k: __mmask16,
+    a: __m512,
+    b: __m512,
+    imm8: i32,
+) -> __m512 {
+    macro_rules! call {
+        ($imm4:expr) => {
+            vrangeps(a, b, $imm4, src, k, _MM_FROUND_CUR_DIRECTION)
+        };
+    }
+    constify_imm4!(imm8, call)
+}
+
+/// Calculates the max, min, absolute max, or absolute min (depending on control
+/// in `imm8`) for packed single-precision (32-bit) floating-point elements in
+/// `a` and `b`, and stores the results in `dst` using zeromask `k` (elements
+/// are zeroed out when the corresponding mask bit is not set).
+///
+/// `imm8[1:0]` specifies the operation: 00 = min, 01 = max, 10 = absolute min,
+/// 11 = absolute max. `imm8[3:2]` specifies the sign control: 00 = sign from
+/// `a`, 01 = sign from the compare result, 10 = clear sign bit, 11 = set sign
+/// bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_range_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangeps, imm8 = 5))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm512_maskz_range_ps(k: __mmask16, a: __m512, b: __m512, imm8: i32) -> __m512 {
+    macro_rules! call {
+        ($imm4:expr) => {
+            vrangeps(
+                a,
+                b,
+                $imm4,
+                _mm512_setzero_ps(),
+                k,
+                _MM_FROUND_CUR_DIRECTION,
+            )
+        };
+    }
+    constify_imm4!(imm8, call)
+}
+
+/// Calculates the max, min, absolute max, or absolute min (depending on control
+/// in `imm8`) for packed double-precision (64-bit) floating-point elements in
+/// `a` and `b`, and stores the results in `dst`.
+///
+/// `imm8[1:0]` specifies the operation: 00 = min, 01 = max, 10 = absolute min,
+/// 11 = absolute max. `imm8[3:2]` specifies the sign control: 00 = sign from
+/// `a`, 01 = sign from the compare result, 10 = clear sign bit, 11 = set sign
+/// bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_range_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangepd, imm8 = 5))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_range_pd(a: __m512d, b: __m512d, imm8: i32) -> __m512d {
+    macro_rules! call {
+        ($imm4:expr) => {
+            vrangepd(
+                a,
+                b,
+                $imm4,
+                _mm512_setzero_pd(),
+                0b11111111,
+                _MM_FROUND_CUR_DIRECTION,
+            )
+        };
+    }
+    constify_imm4!(imm8, call)
+}
+
+/// Calculates the max, min, absolute max, or absolute min (depending on control
+/// in `imm8`) for packed double-precision (64-bit) floating-point elements in
+/// `a` and `b`, and stores the results in `dst` using writemask `k` (elements
+/// are copied from `src` when the corresponding mask bit is not set).
+///
+/// `imm8[1:0]` specifies the operation: 00 = min, 01 = max, 10 = absolute min,
+/// 11 = absolute max. `imm8[3:2]` specifies the sign control: 00 = sign from
+/// `a`, 01 = sign from the compare result, 10 = clear sign bit, 11 = set sign
+/// bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_range_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangepd, imm8 = 5))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm512_mask_range_pd(
+    src: __m512d,
+    k: __mmask8,
+    a: __m512d,
+    b: __m512d,
+    imm8: i32,
+) -> __m512d {
+    macro_rules! call {
+        ($imm4:expr) => {
+            vrangepd(a, b, $imm4, src, k, _MM_FROUND_CUR_DIRECTION)
+        };
+    }
+    constify_imm4!(imm8, call)
+}
+
+/// Calculates the max, min, absolute max, or absolute min (depending on control
+/// in `imm8`) for packed double-precision (64-bit) floating-point elements in
+/// `a` and `b`, and stores the results in `dst` using zeromask `k` (elements
+/// are zeroed out when the corresponding mask bit is not set).
+///
+/// `imm8[1:0]` specifies the operation: 00 = min, 01 = max, 10 = absolute min,
+/// 11 = absolute max. `imm8[3:2]` specifies the sign control: 00 = sign from
+/// `a`, 01 = sign from the compare result, 10 = clear sign bit, 11 = set sign
+/// bit.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_range_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangepd, imm8 = 5))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm512_maskz_range_pd(k: __mmask8, a: __m512d, b: __m512d, imm8: i32) -> __m512d {
+    macro_rules! call {
+        ($imm4:expr) => {
+            vrangepd(
+                a,
+                b,
+                $imm4,
+                _mm512_setzero_pd(),
+                k,
+                _MM_FROUND_CUR_DIRECTION,
+            )
+        };
+    }
+    constify_imm4!(imm8, call)
+}
+
+/// Extracts the reduced argument of packed single-precision (32-bit)
+/// floating-point elements in `a` by the number of bits specified by `imm8`,
+/// and stores the results in `dst`.
+///
+/// The reduced argument is `a - round(a * 2^M) / 2^M`, where `M` is
+/// `imm8[7:4]`. `imm8[1:0]` selects the rounding mode, unless `imm8[2]` is set,
+/// in which case `MXCSR.RC` is used.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_reduce_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreduceps, imm8 = 0))]
+#[rustc_args_required_const(1)]
+pub unsafe fn _mm512_reduce_ps(a: __m512, imm8: i32) -> __m512 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            vreduceps(
+                a,
+                $imm8,
+                _mm512_setzero_ps(),
+                0b11111111_11111111,
+                _MM_FROUND_CUR_DIRECTION,
+            )
+        };
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Extracts the reduced argument of packed single-precision (32-bit)
+/// floating-point elements in `a` by the number of bits specified by `imm8`,
+/// and stores the results in `dst` using writemask `k` (elements are copied
+/// from `src` when the corresponding mask bit is not set).
+///
+/// The reduced argument is `a - round(a * 2^M) / 2^M`, where `M` is
+/// `imm8[7:4]`. `imm8[1:0]` selects the rounding mode, unless `imm8[2]` is set,
+/// in which case `MXCSR.RC` is used.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_reduce_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreduceps, imm8 = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm512_mask_reduce_ps(src: __m512, k: __mmask16, a: __m512, imm8: i32) -> __m512 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            vreduceps(a, $imm8, src, k, _MM_FROUND_CUR_DIRECTION)
+        };
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Extracts the reduced argument of packed single-precision (32-bit)
+/// floating-point elements in `a` by the number of bits specified by `imm8`,
+/// and stores the results in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// The reduced argument is `a - round(a * 2^M) / 2^M`, where `M` is
+/// `imm8[7:4]`. `imm8[1:0]` selects the rounding mode, unless `imm8[2]` is set,
+/// in which case `MXCSR.RC` is used.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_reduce_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreduceps, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_maskz_reduce_ps(k: __mmask16, a: __m512, imm8: i32) -> __m512 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            vreduceps(a, $imm8, _mm512_setzero_ps(), k, _MM_FROUND_CUR_DIRECTION)
+        };
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Extracts the reduced argument of packed double-precision (64-bit)
+/// floating-point elements in `a` by the number of bits specified by `imm8`,
+/// and stores the results in `dst`.
+///
+/// The reduced argument is `a - round(a * 2^M) / 2^M`, where `M` is
+/// `imm8[7:4]`. `imm8[1:0]` selects the rounding mode, unless `imm8[2]` is set,
+/// in which case `MXCSR.RC` is used.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_reduce_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducepd, imm8 = 0))]
+#[rustc_args_required_const(1)]
+pub unsafe fn _mm512_reduce_pd(a: __m512d, imm8: i32) -> __m512d {
+    macro_rules! call {
+        ($imm8:expr) => {
+            vreducepd(
+                a,
+                $imm8,
+                _mm512_setzero_pd(),
+                0b11111111,
+                _MM_FROUND_CUR_DIRECTION,
+            )
+        };
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Extracts the reduced argument of packed double-precision (64-bit)
+/// floating-point elements in `a` by the number of bits specified by `imm8`,
+/// and stores the results in `dst` using writemask `k` (elements are copied
+/// from `src` when the corresponding mask bit is not set).
+///
+/// The reduced argument is `a - round(a * 2^M) / 2^M`, where `M` is
+/// `imm8[7:4]`. `imm8[1:0]` selects the rounding mode, unless `imm8[2]` is set,
+/// in which case `MXCSR.RC` is used.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_reduce_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducepd, imm8 = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm512_mask_reduce_pd(src: __m512d, k: __mmask8, a: __m512d, imm8: i32) -> __m512d {
+    macro_rules! call {
+        ($imm8:expr) => {
+            vreducepd(a, $imm8, src, k, _MM_FROUND_CUR_DIRECTION)
+        };
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Extracts the reduced argument of packed double-precision (64-bit)
+/// floating-point elements in `a` by the number of bits specified by `imm8`,
+/// and stores the results in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// The reduced argument is `a - round(a * 2^M) / 2^M`, where `M` is
+/// `imm8[7:4]`. `imm8[1:0]` selects the rounding mode, unless `imm8[2]` is set,
+/// in which case `MXCSR.RC` is used.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_reduce_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducepd, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_maskz_reduce_pd(k: __mmask8, a: __m512d, imm8: i32) -> __m512d {
+    macro_rules! call {
+        ($imm8:expr) => {
+            vreducepd(a, $imm8, _mm512_setzero_pd(), k, _MM_FROUND_CUR_DIRECTION)
+        };
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Tests packed single-precision (32-bit) floating-point elements in `a` for
+/// special categories specified by `imm8`, and stores the results in mask
+/// vector `k`.
+///
+/// `imm8` can be a combination of: 0x01 = QNaN, 0x02 = positive zero, 0x04 =
+/// negative zero, 0x08 = positive infinity, 0x10 = negative infinity, 0x20 =
+/// denormal, 0x40 = negative finite, 0x80 = SNaN.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_fpclass_ps_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vfpclassps, imm8 = 0x18))]
+#[rustc_args_required_const(1)]
+pub unsafe fn _mm512_fpclass_ps_mask(a: __m512, imm8: i32) -> __mmask16 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            vfpclassps(a, $imm8, 0b11111111_11111111)
+        };
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Tests packed single-precision (32-bit) floating-point elements in `a` for
+/// special categories specified by `imm8`, and stores the results in mask
+/// vector `k` using zeromask `k1` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// `imm8` can be a combination of: 0x01 = QNaN, 0x02 = positive zero, 0x04 =
+/// negative zero, 0x08 = positive infinity, 0x10 = negative infinity, 0x20 =
+/// denormal, 0x40 = negative finite, 0x80 = SNaN.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_fpclass_ps_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vfpclassps, imm8 = 0x18))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_mask_fpclass_ps_mask(k1: __mmask16, a: __m512, imm8: i32) -> __mmask16 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            vfpclassps(a, $imm8, k1)
+        };
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Tests packed double-precision (64-bit) floating-point elements in `a` for
+/// special categories specified by `imm8`, and stores the results in mask
+/// vector `k`.
+///
+/// `imm8` can be a combination of: 0x01 = QNaN, 0x02 = positive zero, 0x04 =
+/// negative zero, 0x08 = positive infinity, 0x10 = negative infinity, 0x20 =
+/// denormal, 0x40 = negative finite, 0x80 = SNaN.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_fpclass_pd_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vfpclasspd, imm8 = 0x18))]
+#[rustc_args_required_const(1)]
+pub unsafe fn _mm512_fpclass_pd_mask(a: __m512d, imm8: i32) -> __mmask8 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            vfpclasspd(a, $imm8, 0b11111111)
+        };
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Tests packed double-precision (64-bit) floating-point elements in `a` for
+/// special categories specified by `imm8`, and stores the results in mask
+/// vector `k` using zeromask `k1` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// `imm8` can be a combination of: 0x01 = QNaN, 0x02 = positive zero, 0x04 =
+/// negative zero, 0x08 = positive infinity, 0x10 = negative infinity, 0x20 =
+/// denormal, 0x40 = negative finite, 0x80 = SNaN.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_fpclass_pd_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vfpclasspd, imm8 = 0x18))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_mask_fpclass_pd_mask(k1: __mmask8, a: __m512d, imm8: i32) -> __mmask8 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            vfpclasspd(a, $imm8, k1)
+        };
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Computes the bitwise AND of packed double-precision (64-bit) floating-point
+/// elements in `a` and `b`, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_and_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandp))]
+pub unsafe fn _mm512_and_pd(a: __m512d, b: __m512d) -> __m512d {
+    let a = transmute::<_, u64x8>(a);
+    let b = transmute::<_, u64x8>(b);
+    transmute(simd_and(a, b))
+}
+
+/// Computes the bitwise AND of packed double-precision (64-bit) floating-point
+/// elements in `a` and `b`, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_and_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandp))]
+pub unsafe fn _mm512_mask_and_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let and = _mm512_and_pd(a, b);
+    simd_select_bitmask(k, and, src)
+}
+
+/// Computes the bitwise AND of packed double-precision (64-bit) floating-point
+/// elements in `a` and `b`, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_and_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandp))]
+pub unsafe fn _mm512_maskz_and_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let and = _mm512_and_pd(a, b);
+    let zero = _mm512_setzero_pd();
+    simd_select_bitmask(k, and, zero)
+}
+
+/// Computes the bitwise AND of packed single-precision (32-bit) floating-point
+/// elements in `a` and `b`, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_and_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandp))]
+pub unsafe fn _mm512_and_ps(a: __m512, b: __m512) -> __m512 {
+    let a = transmute::<_, u32x16>(a);
+    let b = transmute::<_, u32x16>(b);
+    transmute(simd_and(a, b))
+}
+
+/// Computes the bitwise AND of packed single-precision (32-bit) floating-point
+/// elements in `a` and `b`, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_and_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandp))]
+pub unsafe fn _mm512_mask_and_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let and = _mm512_and_ps(a, b);
+    simd_select_bitmask(k, and, src)
+}
+
+/// Computes the bitwise AND of packed single-precision (32-bit) floating-point
+/// elements in `a` and `b`, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_and_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandp))]
+pub unsafe fn _mm512_maskz_and_ps(k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let and = _mm512_and_ps(a, b);
+    let zero = _mm512_setzero_ps();
+    simd_select_bitmask(k, and, zero)
+}
+
+/// Computes the bitwise NOT of packed double-precision (64-bit) floating-point
+/// elements in `a` and then AND with `b`, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_andnot_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandnp))]
+pub unsafe fn _mm512_andnot_pd(a: __m512d, b: __m512d) -> __m512d {
+    let a = transmute::<_, u64x8>(a);
+    let b = transmute::<_, u64x8>(b);
+    let all_ones = u64x8::splat(!0);
+    transmute(simd_and(simd_xor(a, all_ones), b))
+}
+
+/// Computes the bitwise NOT of packed double-precision (64-bit) floating-point
+/// elements in `a` and then AND with `b`, and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_andnot_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandnp))]
+pub unsafe fn _mm512_mask_andnot_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let andnot = _mm512_andnot_pd(a, b);
+    simd_select_bitmask(k, andnot, src)
+}
+
+/// Computes the bitwise NOT of packed double-precision (64-bit) floating-point
+/// elements in `a` and then AND with `b`, and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_andnot_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandnp))]
+pub unsafe fn _mm512_maskz_andnot_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let andnot = _mm512_andnot_pd(a, b);
+    let zero = _mm512_setzero_pd();
+    simd_select_bitmask(k, andnot, zero)
+}
+
+/// Computes the bitwise NOT of packed single-precision (32-bit) floating-point
+/// elements in `a` and then AND with `b`, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_andnot_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandnp))]
+pub unsafe fn _mm512_andnot_ps(a: __m512, b: __m512) -> __m512 {
+    let a = transmute::<_, u32x16>(a);
+    let b = transmute::<_, u32x16>(b);
+    let all_ones = u32x16::splat(!0);
+    transmute(simd_and(simd_xor(a, all_ones), b))
+}
+
+/// Computes the bitwise NOT of packed single-precision (32-bit) floating-point
+/// elements in `a` and then AND with `b`, and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_andnot_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandnp))]
+pub unsafe fn _mm512_mask_andnot_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let andnot = _mm512_andnot_ps(a, b);
+    simd_select_bitmask(k, andnot, src)
+}
+
+/// Computes the bitwise NOT of packed single-precision (32-bit) floating-point
+/// elements in `a` and then AND with `b`, and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_andnot_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandnp))]
+pub unsafe fn _mm512_maskz_andnot_ps(k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let andnot = _mm512_andnot_ps(a, b);
+    let zero = _mm512_setzero_ps();
+    simd_select_bitmask(k, andnot, zero)
+}
+
+/// Computes the bitwise OR of packed double-precision (64-bit) floating-point
+/// elements in `a` and `b`, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_or_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vorp))]
+pub unsafe fn _mm512_or_pd(a: __m512d, b: __m512d) -> __m512d {
+    let a = transmute::<_, u64x8>(a);
+    let b = transmute::<_, u64x8>(b);
+    transmute(simd_or(a, b))
+}
+
+/// Computes the bitwise OR of packed double-precision (64-bit) floating-point
+/// elements in `a` and `b`, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_or_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vorp))]
+pub unsafe fn _mm512_mask_or_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let or = _mm512_or_pd(a, b);
+    simd_select_bitmask(k, or, src)
+}
+
+/// Computes the bitwise OR of packed double-precision (64-bit) floating-point
+/// elements in `a` and `b`, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_or_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vorp))]
+pub unsafe fn _mm512_maskz_or_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let or = _mm512_or_pd(a, b);
+    let zero = _mm512_setzero_pd();
+    simd_select_bitmask(k, or, zero)
+}
+
+/// Computes the bitwise OR of packed single-precision (32-bit) floating-point
+/// elements in `a` and `b`, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_or_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vorp))]
+pub unsafe fn _mm512_or_ps(a: __m512, b: __m512) -> __m512 {
+    let a = transmute::<_, u32x16>(a);
+    let b = transmute::<_, u32x16>(b);
+    transmute(simd_or(a, b))
+}
+
+/// Computes the bitwise OR of packed single-precision (32-bit) floating-point
+/// elements in `a` and `b`, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_or_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vorp))]
+pub unsafe fn _mm512_mask_or_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let or = _mm512_or_ps(a, b);
+    simd_select_bitmask(k, or, src)
+}
+
+/// Computes the bitwise OR of packed single-precision (32-bit) floating-point
+/// elements in `a` and `b`, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_or_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vorp))]
+pub unsafe fn _mm512_maskz_or_ps(k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let or = _mm512_or_ps(a, b);
+    let zero = _mm512_setzero_ps();
+    simd_select_bitmask(k, or, zero)
+}
+
+/// Computes the bitwise XOR of packed double-precision (64-bit) floating-point
+/// elements in `a` and `b`, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_xor_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vxorp))]
+pub unsafe fn _mm512_xor_pd(a: __m512d, b: __m512d) -> __m512d {
+    let a = transmute::<_, u64x8>(a);
+    let b = transmute::<_, u64x8>(b);
+    transmute(simd_xor(a, b))
+}
+
+/// Computes the bitwise XOR of packed double-precision (64-bit) floating-point
+/// elements in `a` and `b`, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_xor_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vxorp))]
+pub unsafe fn _mm512_mask_xor_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let xor = _mm512_xor_pd(a, b);
+    simd_select_bitmask(k, xor, src)
+}
+
+/// Computes the bitwise XOR of packed double-precision (64-bit) floating-point
+/// elements in `a` and `b`, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_xor_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vxorp))]
+pub unsafe fn _mm512_maskz_xor_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let xor = _mm512_xor_pd(a, b);
+    let zero = _mm512_setzero_pd();
+    simd_select_bitmask(k, xor, zero)
+}
+
+/// Computes the bitwise XOR of packed single-precision (32-bit) floating-point
+/// elements in `a` and `b`, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_xor_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vxorp))]
+pub unsafe fn _mm512_xor_ps(a: __m512, b: __m512) -> __m512 {
+    let a = transmute::<_, u32x16>(a);
+    let b = transmute::<_, u32x16>(b);
+    transmute(simd_xor(a, b))
+}
+
+/// Computes the bitwise XOR of packed single-precision (32-bit) floating-point
+/// elements in `a` and `b`, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_xor_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vxorp))]
+pub unsafe fn _mm512_mask_xor_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let xor = _mm512_xor_ps(a, b);
+    simd_select_bitmask(k, xor, src)
+}
+
+/// Computes the bitwise XOR of packed single-precision (32-bit) floating-point
+/// elements in `a` and `b`, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_xor_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vxorp))]
+pub unsafe fn _mm512_maskz_xor_ps(k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let xor = _mm512_xor_ps(a, b);
+    let zero = _mm512_setzero_ps();
+    simd_select_bitmask(k, xor, zero)
+}
+
+/// Broadcasts the lower 2 packed single-precision (32-bit) floating-point
+/// elements from `a` to all elements of `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_broadcast_f32x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vbroadcast))]
+pub unsafe fn _mm512_broadcast_f32x2(a: __m128) -> __m512 {
+    simd_shuffle16(a, a, [0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1])
+}
+
+/// Broadcasts the lower 2 packed single-precision (32-bit) floating-point
+/// elements from `a` to all elements of `dst`. The results are stored in `dst`
+/// using writemask `k` (elements are copied from `src` when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_broadcast_f32x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vbroadcastf32x2))]
+pub unsafe fn _mm512_mask_broadcast_f32x2(src: __m512, k: __mmask16, a: __m128) -> __m512 {
+    let r = _mm512_broadcast_f32x2(a);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Broadcasts the lower 2 packed single-precision (32-bit) floating-point
+/// elements from `a` to all elements of `dst`. The results are stored in `dst`
+/// using zeromask `k` (elements are zeroed out when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_broadcast_f32x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vbroadcastf32x2))]
+pub unsafe fn _mm512_maskz_broadcast_f32x2(k: __mmask16, a: __m128) -> __m512 {
+    let r = _mm512_broadcast_f32x2(a);
+    let zero = _mm512_setzero_ps();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Broadcasts the lower 2 packed 32-bit integers from `a` to all elements of
+/// `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_broadcast_i32x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vbroadcast))]
+pub unsafe fn _mm512_broadcast_i32x2(a: __m128i) -> __m512i {
+    let a = a.as_i32x4();
+    let ret: i32x16 = simd_shuffle16(a, a, [0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1]);
+    transmute(ret)
+}
+
+/// Broadcasts the lower 2 packed 32-bit integers from `a` to all elements of
+/// `dst`. The results are stored in `dst` using writemask `k` (elements are
+/// copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_broadcast_i32x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vbroadcasti32x2))]
+pub unsafe fn _mm512_mask_broadcast_i32x2(src: __m512i, k: __mmask16, a: __m128i) -> __m512i {
+    let r = _mm512_broadcast_i32x2(a).as_i32x16();
+    transmute(simd_select_bitmask(k, r, src.as_i32x16()))
+}
+
+/// Broadcasts the lower 2 packed 32-bit integers from `a` to all elements of
+/// `dst`. The results are stored in `dst` using zeromask `k` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_broadcast_i32x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vbroadcasti32x2))]
+pub unsafe fn _mm512_maskz_broadcast_i32x2(k: __mmask16, a: __m128i) -> __m512i {
+    let r = _mm512_broadcast_i32x2(a).as_i32x16();
+    let zero = _mm512_setzero_si512().as_i32x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Broadcasts the 2 packed double-precision (64-bit) floating-point elements
+/// from `a` to all elements of `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_broadcast_f64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vbroadcast))]
+pub unsafe fn _mm512_broadcast_f64x2(a: __m128d) -> __m512d {
+    simd_shuffle8(a, a, [0, 1, 0, 1, 0, 1, 0, 1])
+}
+
+/// Broadcasts the 2 packed double-precision (64-bit) floating-point elements
+/// from `a` to all elements of `dst`. The results are stored in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_broadcast_f64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vbroadcastf64x2))]
+pub unsafe fn _mm512_mask_broadcast_f64x2(src: __m512d, k: __mmask8, a: __m128d) -> __m512d {
+    let r = _mm512_broadcast_f64x2(a);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Broadcasts the 2 packed double-precision (64-bit) floating-point elements
+/// from `a` to all elements of `dst`. The results are stored in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_broadcast_f64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vbroadcastf64x2))]
+pub unsafe fn _mm512_maskz_broadcast_f64x2(k: __mmask8, a: __m128d) -> __m512d {
+    let r = _mm512_broadcast_f64x2(a);
+    let zero = _mm512_setzero_pd();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Broadcasts the 2 packed 64-bit integers from `a` to all elements of `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_broadcast_i64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vbroadcast))]
+pub unsafe fn _mm512_broadcast_i64x2(a: __m128i) -> __m512i {
+    let a = a.as_i64x2();
+    let ret: i64x8 = simd_shuffle8(a, a, [0, 1, 0, 1, 0, 1, 0, 1]);
+    transmute(ret)
+}
+
+/// Broadcasts the 2 packed 64-bit integers from `a` to all elements of `dst`.
+/// The results are stored in `dst` using writemask `k` (elements are copied
+/// from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_broadcast_i64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vbroadcasti64x2))]
+pub unsafe fn _mm512_mask_broadcast_i64x2(src: __m512i, k: __mmask8, a: __m128i) -> __m512i {
+    let r = _mm512_broadcast_i64x2(a).as_i64x8();
+    transmute(simd_select_bitmask(k, r, src.as_i64x8()))
+}
+
+/// Broadcasts the 2 packed 64-bit integers from `a` to all elements of `dst`.
+/// The results are stored in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_broadcast_i64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vbroadcasti64x2))]
+pub unsafe fn _mm512_maskz_broadcast_i64x2(k: __mmask8, a: __m128i) -> __m512i {
+    let r = _mm512_broadcast_i64x2(a).as_i64x8();
+    let zero = _mm512_setzero_si512().as_i64x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Extracts 128 bits (composed of 2 packed double-precision (64-bit)
+/// floating-point elements) from `a`, selected with `imm8`, and stores the
+/// result in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_extractf64x2_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vextractf64x2, imm8 = 3))]
+#[rustc_args_required_const(1)]
+pub unsafe fn _mm512_extractf64x2_pd(a: __m512d, imm8: i32) -> __m128d {
+    match imm8 & 0b11 {
+        0 => simd_shuffle2(a, a, [0, 1]),
+        1 => simd_shuffle2(a, a, [2, 3]),
+        2 => simd_shuffle2(a, a, [4, 5]),
+        _ => simd_shuffle2(a, a, [6, 7]),
+    }
+}
+
+/// Extracts 128 bits (composed of 2 packed double-precision (64-bit)
+/// floating-point elements) from `a`, selected with `imm8`, and stores the
+/// result in `dst` using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_extractf64x2_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vextractf64x2, imm8 = 3))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm512_mask_extractf64x2_pd(
+    src: __m128d,
+    k: __mmask8,
+    a: __m512d,
+    imm8: i32,
+) -> __m128d {
+    macro_rules! call {
+        ($imm2:expr) => {
+            _mm512_extractf64x2_pd(a, $imm2)
+        };
+    }
+    let r = constify_imm2!(imm8, call);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Extracts 128 bits (composed of 2 packed double-precision (64-bit)
+/// floating-point elements) from `a`, selected with `imm8`, and stores the
+/// result in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_extractf64x2_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vextractf64x2, imm8 = 3))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_maskz_extractf64x2_pd(k: __mmask8, a: __m512d, imm8: i32) -> __m128d {
+    macro_rules! call {
+        ($imm2:expr) => {
+            _mm512_extractf64x2_pd(a, $imm2)
+        };
+    }
+    let r = constify_imm2!(imm8, call);
+    simd_select_bitmask(k, r, _mm_setzero_pd())
+}
+
+/// Copies `a` to `dst`, then inserts 128 bits (composed of 2 packed
+/// double-precision (64-bit) floating-point elements) from `b` into `dst` at
+/// the location specified by `imm8`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_insertf64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vinsertf64x2, imm8 = 3))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_insertf64x2(a: __m512d, b: __m128d, imm8: i32) -> __m512d {
+    let b: __m512d = simd_shuffle8(b, b, [0, 1, 0, 1, 0, 1, 0, 1]);
+    match imm8 & 0b11 {
+        0 => simd_shuffle8(a, b, [8, 9, 2, 3, 4, 5, 6, 7]),
+        1 => simd_shuffle8(a, b, [0, 1, 10, 11, 4, 5, 6, 7]),
+        2 => simd_shuffle8(a, b, [0, 1, 2, 3, 12, 13, 6, 7]),
+        _ => simd_shuffle8(a, b, [0, 1, 2, 3, 4, 5, 14, 15]),
+    }
+}
+
+/// Copies `a` to `tmp`, then inserts 128 bits (composed of 2 packed
+/// double-precision (64-bit) floating-point elements) from `b` into `tmp` at
+/// the location specified by `imm8`. The results are stored in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_insertf64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vinsertf64x2, imm8 = 3))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm512_mask_insertf64x2(
+    src: __m512d,
+    k: __mmask8,
+    a: __m512d,
+    b: __m128d,
+    imm8: i32,
+) -> __m512d {
+    macro_rules! call {
+        ($imm2:expr) => {
+            _mm512_insertf64x2(a, b, $imm2)
+        };
+    }
+    let r = constify_imm2!(imm8, call);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Copies `a` to `tmp`, then inserts 128 bits (composed of 2 packed
+/// double-precision (64-bit) floating-point elements) from `b` into `tmp` at
+/// the location specified by `imm8`. The results are stored in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_insertf64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vinsertf64x2, imm8 = 3))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm512_maskz_insertf64x2(k: __mmask8, a: __m512d, b: __m128d, imm8: i32) -> __m512d {
+    macro_rules! call {
+        ($imm2:expr) => {
+            _mm512_insertf64x2(a, b, $imm2)
+        };
+    }
+    let r = constify_imm2!(imm8, call);
+    simd_select_bitmask(k, r, _mm512_setzero_pd())
+}
+
+/// Extracts 128 bits (composed of 2 packed 64-bit integers) from `a`, selected
+/// with `imm8`, and stores the result in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_extracti64x2_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vextracti64x2, imm8 = 3))]
+#[rustc_args_required_const(1)]
+pub unsafe fn _mm512_extracti64x2_epi64(a: __m512i, imm8: i32) -> __m128i {
+    let a = a.as_i64x8();
+    let r: i64x2 = match imm8 & 0b11 {
+        0 => simd_shuffle2(a, a, [0, 1]),
+        1 => simd_shuffle2(a, a, [2, 3]),
+        2 => simd_shuffle2(a, a, [4, 5]),
+        _ => simd_shuffle2(a, a, [6, 7]),
+    };
+    transmute(r)
+}
+
+/// Extracts 128 bits (composed of 2 packed 64-bit integers) from `a`, selected
+/// with `imm8`, and stores the result in `dst` using writemask `k` (elements
+/// are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_extracti64x2_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vextracti64x2, imm8 = 3))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm512_mask_extracti64x2_epi64(
+    src: __m128i,
+    k: __mmask8,
+    a: __m512i,
+    imm8: i32,
+) -> __m128i {
+    macro_rules! call {
+        ($imm2:expr) => {
+            _mm512_extracti64x2_epi64(a, $imm2)
+        };
+    }
+    let r = constify_imm2!(imm8, call).as_i64x2();
+    transmute(simd_select_bitmask(k, r, src.as_i64x2()))
+}
+
+/// Extracts 128 bits (composed of 2 packed 64-bit integers) from `a`, selected
+/// with `imm8`, and stores the result in `dst` using zeromask `k` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_extracti64x2_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vextracti64x2, imm8 = 3))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_maskz_extracti64x2_epi64(k: __mmask8, a: __m512i, imm8: i32) -> __m128i {
+    macro_rules! call {
+        ($imm2:expr) => {
+            _mm512_extracti64x2_epi64(a, $imm2)
+        };
+    }
+    let r = constify_imm2!(imm8, call).as_i64x2();
+    let zero = _mm_setzero_si128().as_i64x2();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Copies `a` to `dst`, then inserts 128 bits (composed of 2 packed 64-bit
+/// integers) from `b` into `dst` at the location specified by `imm8`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_inserti64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vinserti64x2, imm8 = 3))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_inserti64x2(a: __m512i, b: __m128i, imm8: i32) -> __m512i {
+    let a = a.as_i64x8();
+    let b: i64x8 = simd_shuffle8(b.as_i64x2(), b.as_i64x2(), [0, 1, 0, 1, 0, 1, 0, 1]);
+    let r: i64x8 = match imm8 & 0b11 {
+        0 => simd_shuffle8(a, b, [8, 9, 2, 3, 4, 5, 6, 7]),
+        1 => simd_shuffle8(a, b, [0, 1, 10, 11, 4, 5, 6, 7]),
+        2 => simd_shuffle8(a, b, [0, 1, 2, 3, 12, 13, 6, 7]),
+        _ => simd_shuffle8(a, b, [0, 1, 2, 3, 4, 5, 14, 15]),
+    };
+    transmute(r)
+}
+
+/// Copies `a` to `tmp`, then inserts 128 bits (composed of 2 packed 64-bit
+/// integers) from `b` into `tmp` at the location specified by `imm8`. The
+/// results are stored in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_inserti64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vinserti64x2, imm8 = 3))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm512_mask_inserti64x2(
+    src: __m512i,
+    k: __mmask8,
+    a: __m512i,
+    b: __m128i,
+    imm8: i32,
+) -> __m512i {
+    macro_rules! call {
+        ($imm2:expr) => {
+            _mm512_inserti64x2(a, b, $imm2)
+        };
+    }
+    let r = constify_imm2!(imm8, call).as_i64x8();
+    transmute(simd_select_bitmask(k, r, src.as_i64x8()))
+}
+
+/// Copies `a` to `tmp`, then inserts 128 bits (composed of 2 packed 64-bit
+/// integers) from `b` into `tmp` at the location specified by `imm8`. The
+/// results are stored in `dst` using zeromask `k` (elements are zeroed out when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_inserti64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vinserti64x2, imm8 = 3))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm512_maskz_inserti64x2(k: __mmask8, a: __m512i, b: __m128i, imm8: i32) -> __m512i {
+    macro_rules! call {
+        ($imm2:expr) => {
+            _mm512_inserti64x2(a, b, $imm2)
+        };
+    }
+    let r = constify_imm2!(imm8, call).as_i64x8();
+    let zero = _mm512_setzero_si512().as_i64x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.avx512.mask.cvtpd2qq.512"]
+    fn vcvtpd2qq(a: __m512d, src: i64x8, mask: __mmask8, rounding: i32) -> i64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvtps2qq.512"]
+    fn vcvtps2qq(a: __m256, src: i64x8, mask: __mmask8, rounding: i32) -> i64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvtpd2uqq.512"]
+    fn vcvtpd2uqq(a: __m512d, src: u64x8, mask: __mmask8, rounding: i32) -> u64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvtps2uqq.512"]
+    fn vcvtps2uqq(a: __m256, src: u64x8, mask: __mmask8, rounding: i32) -> u64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvttpd2qq.512"]
+    fn vcvttpd2qq(a: __m512d, src: i64x8, mask: __mmask8, rounding: i32) -> i64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvttps2qq.512"]
+    fn vcvttps2qq(a: __m256, src: i64x8, mask: __mmask8, rounding: i32) -> i64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvttpd2uqq.512"]
+    fn vcvttpd2uqq(a: __m512d, src: u64x8, mask: __mmask8, rounding: i32) -> u64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvttps2uqq.512"]
+    fn vcvttps2uqq(a: __m256, src: u64x8, mask: __mmask8, rounding: i32) -> u64x8;
+    #[link_name = "llvm.x86.avx512.mask.range.ps.512"]
+    fn vrangeps(a: __m512, b: __m512, imm8: i32, src: __m512, mask: __mmask16, sae: i32) -> __m512;
+    #[link_name = "llvm.x86.avx512.mask.range.pd.512"]
+    fn vrangepd(
+        a: __m512d,
+        b: __m512d,
+        imm8: i32,
+        src: __m512d,
+        mask: __mmask8,
+        sae: i32,
+    ) -> __m512d;
+    #[link_name = "llvm.x86.avx512.mask.reduce.ps.512"]
+    fn vreduceps(a: __m512, imm8: i32, src: __m512, mask: __mmask16, sae: i32) -> __m512;
+    #[link_name = "llvm.x86.avx512.mask.reduce.pd.512"]
+    fn vreducepd(a: __m512d, imm8: i32, src: __m512d, mask: __mmask8, sae: i32) -> __m512d;
+    #[link_name = "llvm.x86.avx512.mask.fpclass.ps.512"]
+    fn vfpclassps(a: __m512, imm8: i32, mask: __mmask16) -> __mmask16;
+    #[link_name = "llvm.x86.avx512.mask.fpclass.pd.512"]
+    fn vfpclasspd(a: __m512d, imm8: i32, mask: __mmask8) -> __mmask8;
+}
+
+#[cfg(test)]
+mod tests {
+    use stdsimd_test::simd_test;
+
+    use crate::core_arch::x86::*;
+    use std::mem::transmute;
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kand_mask8() {
+        let a: __mmask8 = 0b11001010;
+        let b: __mmask8 = 0b10100110;
+        let r = _kand_mask8(a, b);
+        let e: __mmask8 = 0b10000010;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kandn_mask8() {
+        let a: __mmask8 = 0b11001010;
+        let b: __mmask8 = 0b10100110;
+        let r = _kandn_mask8(a, b);
+        let e: __mmask8 = 0b00100100;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kor_mask8() {
+        let a: __mmask8 = 0b11001010;
+        let b: __mmask8 = 0b10100110;
+        let r = _kor_mask8(a, b);
+        let e: __mmask8 = 0b11101110;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kxnor_mask8() {
+        let a: __mmask8 = 0b11001010;
+        let b: __mmask8 = 0b10100110;
+        let r = _kxnor_mask8(a, b);
+        let e: __mmask8 = 0b10010011;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kxor_mask8() {
+        let a: __mmask8 = 0b11001010;
+        let b: __mmask8 = 0b10100110;
+        let r = _kxor_mask8(a, b);
+        let e: __mmask8 = 0b01101100;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_knot_mask8() {
+        let a: __mmask8 = 0b11001010;
+        let r = _knot_mask8(a);
+        let e: __mmask8 = 0b00110101;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kshiftli_mask8() {
+        let a: __mmask8 = 0b11001010;
+        let r = _kshiftli_mask8(a, 3);
+        let e: __mmask8 = 0b01010000;
+        assert_eq!(r, e);
+        let r = _kshiftli_mask8(a, 8);
+        let e: __mmask8 = 0b00000000;
+        assert_eq!(r, e);
+        let r = _kshiftli_mask8(a, 255);
+        let e: __mmask8 = 0b00000000;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kshiftri_mask8() {
+        let a: __mmask8 = 0b11001010;
+        let r = _kshiftri_mask8(a, 3);
+        let e: __mmask8 = 0b00011001;
+        assert_eq!(r, e);
+        let r = _kshiftri_mask8(a, 8);
+        let e: __mmask8 = 0b00000000;
+        assert_eq!(r, e);
+        let r = _kshiftri_mask8(a, 255);
+        let e: __mmask8 = 0b00000000;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kortestz_mask8_u8() {
+        let a: __mmask8 = 0b11001010;
+        let b: __mmask8 = 0b00110101;
+        assert_eq!(_kortestz_mask8_u8(a, b), 0);
+        assert_eq!(_kortestz_mask8_u8(0, 0), 1);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kortestc_mask8_u8() {
+        let a: __mmask8 = 0b11001010;
+        let b: __mmask8 = 0b00110101;
+        assert_eq!(_kortestc_mask8_u8(a, b), 1);
+        assert_eq!(_kortestc_mask8_u8(a, 0), 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kortest_mask8_u8() {
+        let a: __mmask8 = 0b11001010;
+        let b: __mmask8 = 0b00110101;
+        let mut all_ones: u8 = 0;
+        let r = _kortest_mask8_u8(a, b, &mut all_ones);
+        assert_eq!(r, 0);
+        assert_eq!(all_ones, 1);
+        let r = _kortest_mask8_u8(0, 0, &mut all_ones);
+        assert_eq!(r, 1);
+        assert_eq!(all_ones, 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_ktestz_mask8_u8() {
+        let a: __mmask8 = 0b11001010;
+        let b: __mmask8 = 0b00110101;
+        assert_eq!(_ktestz_mask8_u8(a, b), 1);
+        assert_eq!(_ktestz_mask8_u8(a, a), 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_ktestc_mask8_u8() {
+        let a: __mmask8 = 0b11001010;
+        let b: __mmask8 = 0b00110101;
+        assert_eq!(_ktestc_mask8_u8(a, a), 1);
+        assert_eq!(_ktestc_mask8_u8(a, b), 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_ktest_mask8_u8() {
+        let a: __mmask8 = 0b11001010;
+        let b: __mmask8 = 0b00110101;
+        let mut and_not: u8 = 0;
+        let r = _ktest_mask8_u8(a, b, &mut and_not);
+        assert_eq!(r, 1);
+        assert_eq!(and_not, 0);
+        let r = _ktest_mask8_u8(a, a, &mut and_not);
+        assert_eq!(r, 0);
+        assert_eq!(and_not, 1);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_ktestz_mask16_u8() {
+        let a: __mmask16 = 0b11001010_01010011;
+        let b: __mmask16 = 0b00110101_10101100;
+        assert_eq!(_ktestz_mask16_u8(a, b), 1);
+        assert_eq!(_ktestz_mask16_u8(a, a), 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_ktestc_mask16_u8() {
+        let a: __mmask16 = 0b11001010_01010011;
+        let b: __mmask16 = 0b00110101_10101100;
+        assert_eq!(_ktestc_mask16_u8(a, a), 1);
+        assert_eq!(_ktestc_mask16_u8(a, b), 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_ktest_mask16_u8() {
+        let a: __mmask16 = 0b11001010_01010011;
+        let b: __mmask16 = 0b00110101_10101100;
+        let mut and_not: u8 = 0;
+        let r = _ktest_mask16_u8(a, b, &mut and_not);
+        assert_eq!(r, 1);
+        assert_eq!(and_not, 0);
+        let r = _ktest_mask16_u8(a, a, &mut and_not);
+        assert_eq!(r, 0);
+        assert_eq!(and_not, 1);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_cvtmask8_u32() {
+        let a: __mmask8 = 0b11001010;
+        let r = _cvtmask8_u32(a);
+        assert_eq!(r, 0b11001010);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_cvtu32_mask8() {
+        let r = _cvtu32_mask8(0b11001010);
+        let e: __mmask8 = 0b11001010;
+        assert_eq!(r, e);
+        let r = _cvtu32_mask8(0xffff_ffff);
+        let e: __mmask8 = 0b11111111;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_load_mask8() {
+        let a: __mmask8 = 0b11001010;
+        let r = _load_mask8(&a);
+        assert_eq!(r, a);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_store_mask8() {
+        let a: __mmask8 = 0b11001010;
+        let mut r: __mmask8 = 0;
+        _store_mask8(&mut r, a);
+        assert_eq!(r, a);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_movepi32_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi32(
+            0, -1, 1, std::i32::MIN,
+            std::i32::MAX, -100, 100, 13,
+            0, -1, 1, std::i32::MIN,
+            std::i32::MAX, -100, 100, 13,
+        );
+        let r = _mm512_movepi32_mask(a);
+        let e: __mmask16 = 0b00101010_00101010;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_movm_epi32() {
+        let k: __mmask16 = 0b01111010_01111010;
+        let r = _mm512_movm_epi32(k);
+        #[rustfmt::skip]
+        let e = _mm512_setr_epi32(
+            0, -1, 0, -1,
+            -1, -1, -1, 0,
+            0, -1, 0, -1,
+            -1, -1, -1, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_movepi64_mask() {
+        let a = _mm512_setr_epi64(0, -1, 1, std::i64::MIN, std::i64::MAX, -100, 100, 13);
+        let r = _mm512_movepi64_mask(a);
+        let e: __mmask8 = 0b00101010;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_movm_epi64() {
+        let k: __mmask8 = 0b01111010;
+        let r = _mm512_movm_epi64(k);
+        let e = _mm512_setr_epi64(0, -1, 0, -1, -1, -1, -1, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mullo_epi64() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi64(
+            0, 1, -1, 1099511627776,
+            -1099511627776, 3, 4611686018427387904, 123456789,
+        );
+        let b = _mm512_setr_epi64(5, -7, -1, 1073741824, 3, -4, 4, 987654321);
+        let r = _mm512_mullo_epi64(a, b);
+        let e = _mm512_setr_epi64(0, -7, 1, 0, -3298534883328, -12, 0, 121932631112635269);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_mullo_epi64() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi64(
+            0, 1, -1, 1099511627776,
+            -1099511627776, 3, 4611686018427387904, 123456789,
+        );
+        let b = _mm512_setr_epi64(5, -7, -1, 1073741824, 3, -4, 4, 987654321);
+        let src = _mm512_setr_epi64(-1, -1, -1, -1, -1, -1, -1, -1);
+        let r = _mm512_mask_mullo_epi64(src, 0b01101001, a, b);
+        let e = _mm512_setr_epi64(0, -1, -1, 0, -1, -12, 0, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_mullo_epi64() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi64(
+            0, 1, -1, 1099511627776,
+            -1099511627776, 3, 4611686018427387904, 123456789,
+        );
+        let b = _mm512_setr_epi64(5, -7, -1, 1073741824, 3, -4, 4, 987654321);
+        let r = _mm512_maskz_mullo_epi64(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_mullo_epi64(0b01101001, a, b);
+        let e = _mm512_setr_epi64(0, 0, 0, 0, 0, -12, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mullo_epi64() {
+        let a = _mm256_setr_epi64x(0, 1, -1, 1099511627776);
+        let b = _mm256_setr_epi64x(5, -7, -1, 1073741824);
+        let r = _mm256_mullo_epi64(a, b);
+        let e = _mm256_setr_epi64x(0, -7, 1, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_mullo_epi64() {
+        let a = _mm256_setr_epi64x(0, 1, -1, 1099511627776);
+        let b = _mm256_setr_epi64x(5, -7, -1, 1073741824);
+        let r = _mm256_mask_mullo_epi64(a, 0, a, b);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_mullo_epi64(a, 0b00001010, a, b);
+        let e = _mm256_setr_epi64x(0, -7, -1, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_mullo_epi64() {
+        let a = _mm256_setr_epi64x(0, 1, -1, 1099511627776);
+        let b = _mm256_setr_epi64x(5, -7, -1, 1073741824);
+        let r = _mm256_maskz_mullo_epi64(0, a, b);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_mullo_epi64(0b00001010, a, b);
+        let e = _mm256_setr_epi64x(0, -7, 0, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mullo_epi64() {
+        let a = _mm_setr_epi64x(0, 1);
+        let b = _mm_setr_epi64x(5, -7);
+        let r = _mm_mullo_epi64(a, b);
+        let e = _mm_setr_epi64x(0, -7);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_mullo_epi64() {
+        let a = _mm_setr_epi64x(0, 1);
+        let b = _mm_setr_epi64x(5, -7);
+        let r = _mm_mask_mullo_epi64(a, 0, a, b);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_mullo_epi64(a, 0b00000010, a, b);
+        let e = _mm_setr_epi64x(0, -7);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_mullo_epi64() {
+        let a = _mm_setr_epi64x(0, 1);
+        let b = _mm_setr_epi64x(5, -7);
+        let r = _mm_maskz_mullo_epi64(0, a, b);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_mullo_epi64(0b00000010, a, b);
+        let e = _mm_setr_epi64x(0, -7);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtepi64_pd() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi64(
+            0, 1, -1, 1099511627776,
+            -1099511627776, 123456, -7, 9007199254740992,
+        );
+        let r = _mm512_cvtepi64_pd(a);
+        #[rustfmt::skip]
+        let e = _mm512_setr_pd(
+            0., 1., -1., 1099511627776.,
+            -1099511627776., 123456., -7., 9007199254740992.,
+        );
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtepi64_pd() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi64(
+            0, 1, -1, 1099511627776,
+            -1099511627776, 123456, -7, 9007199254740992,
+        );
+        let src = _mm512_setr_pd(-1., -1., -1., -1., -1., -1., -1., -1.);
+        let r = _mm512_mask_cvtepi64_pd(src, 0b01101001, a);
+        let e = _mm512_setr_pd(0., -1., -1., 1099511627776., -1., 123456., -7., -1.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtepi64_pd() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi64(
+            0, 1, -1, 1099511627776,
+            -1099511627776, 123456, -7, 9007199254740992,
+        );
+        let r = _mm512_maskz_cvtepi64_pd(0, a);
+        assert_eq_m512d(r, _mm512_setzero_pd());
+        let r = _mm512_maskz_cvtepi64_pd(0b01101001, a);
+        let e = _mm512_setr_pd(0., 0., 0., 1099511627776., 0., 123456., -7., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_cvtepi64_pd() {
+        let a = _mm256_setr_epi64x(0, 1, -1, 1099511627776);
+        let r = _mm256_cvtepi64_pd(a);
+        let e = _mm256_setr_pd(0., 1., -1., 1099511627776.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_cvtepi64_pd() {
+        let a = _mm256_setr_epi64x(0, 1, -1, 1099511627776);
+        let src = _mm256_setr_pd(-1., -1., -1., -1.);
+        let r = _mm256_mask_cvtepi64_pd(src, 0, a);
+        assert_eq_m256d(r, src);
+        let r = _mm256_mask_cvtepi64_pd(src, 0b00001010, a);
+        let e = _mm256_setr_pd(-1., 1., -1., 1099511627776.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_cvtepi64_pd() {
+        let a = _mm256_setr_epi64x(0, 1, -1, 1099511627776);
+        let r = _mm256_maskz_cvtepi64_pd(0, a);
+        assert_eq_m256d(r, _mm256_setzero_pd());
+        let r = _mm256_maskz_cvtepi64_pd(0b00001010, a);
+        let e = _mm256_setr_pd(0., 1., 0., 1099511627776.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_cvtepi64_pd() {
+        let a = _mm_setr_epi64x(0, 1);
+        let r = _mm_cvtepi64_pd(a);
+        let e = _mm_setr_pd(0., 1.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_cvtepi64_pd() {
+        let a = _mm_setr_epi64x(0, 1);
+        let src = _mm_setr_pd(-1., -1.);
+        let r = _mm_mask_cvtepi64_pd(src, 0, a);
+        assert_eq_m128d(r, src);
+        let r = _mm_mask_cvtepi64_pd(src, 0b00000010, a);
+        let e = _mm_setr_pd(-1., 1.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_cvtepi64_pd() {
+        let a = _mm_setr_epi64x(0, 1);
+        let r = _mm_maskz_cvtepi64_pd(0, a);
+        assert_eq_m128d(r, _mm_setzero_pd());
+        let r = _mm_maskz_cvtepi64_pd(0b00000010, a);
+        let e = _mm_setr_pd(0., 1.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtepi64_ps() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi64(
+            0, 1, -1, 1099511627776,
+            -1099511627776, 123456, -7, 9007199254740992,
+        );
+        let r = _mm512_cvtepi64_ps(a);
+        #[rustfmt::skip]
+        let e = _mm256_setr_ps(
+            0., 1., -1., 1099511627776.,
+            -1099511627776., 123456., -7., 9007199254740992.,
+        );
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtepi64_ps() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi64(
+            0, 1, -1, 1099511627776,
+            -1099511627776, 123456, -7, 9007199254740992,
+        );
+        let src = _mm256_setr_ps(-1., -1., -1., -1., -1., -1., -1., -1.);
+        let r = _mm512_mask_cvtepi64_ps(src, 0b01101001, a);
+        let e = _mm256_setr_ps(0., -1., -1., 1099511627776., -1., 123456., -7., -1.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtepi64_ps() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi64(
+            0, 1, -1, 1099511627776,
+            -1099511627776, 123456, -7, 9007199254740992,
+        );
+        let r = _mm512_maskz_cvtepi64_ps(0, a);
+        assert_eq_m256(r, _mm256_setzero_ps());
+        let r = _mm512_maskz_cvtepi64_ps(0b01101001, a);
+        let e = _mm256_setr_ps(0., 0., 0., 1099511627776., 0., 123456., -7., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtepu64_pd() {
+        let a = _mm512_setr_epi64(
+            0,
+            1,
+            2,
+            1099511627776,
+            i64::MIN,
+            123456,
+            7,
+            9007199254740992,
+        );
+        let r = _mm512_cvtepu64_pd(a);
+        #[rustfmt::skip]
+        let e = _mm512_setr_pd(
+            0., 1., 2., 1099511627776.,
+            9223372036854775808., 123456., 7., 9007199254740992.,
+        );
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtepu64_pd() {
+        let a = _mm512_setr_epi64(
+            0,
+            1,
+            2,
+            1099511627776,
+            i64::MIN,
+            123456,
+            7,
+            9007199254740992,
+        );
+        let src = _mm512_setr_pd(-1., -1., -1., -1., -1., -1., -1., -1.);
+        let r = _mm512_mask_cvtepu64_pd(src, 0b01101001, a);
+        let e = _mm512_setr_pd(0., -1., -1., 1099511627776., -1., 123456., 7., -1.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtepu64_pd() {
+        let a = _mm512_setr_epi64(
+            0,
+            1,
+            2,
+            1099511627776,
+            i64::MIN,
+            123456,
+            7,
+            9007199254740992,
+        );
+        let r = _mm512_maskz_cvtepu64_pd(0, a);
+        assert_eq_m512d(r, _mm512_setzero_pd());
+        let r = _mm512_maskz_cvtepu64_pd(0b01101001, a);
+        let e = _mm512_setr_pd(0., 0., 0., 1099511627776., 0., 123456., 7., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtepu64_ps() {
+        let a = _mm512_setr_epi64(
+            0,
+            1,
+            2,
+            1099511627776,
+            i64::MIN,
+            123456,
+            7,
+            9007199254740992,
+        );
+        let r = _mm512_cvtepu64_ps(a);
+        #[rustfmt::skip]
+        let e = _mm256_setr_ps(
+            0., 1., 2., 1099511627776.,
+            9223372036854775808., 123456., 7., 9007199254740992.,
+        );
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtepu64_ps() {
+        let a = _mm512_setr_epi64(
+            0,
+            1,
+            2,
+            1099511627776,
+            i64::MIN,
+            123456,
+            7,
+            9007199254740992,
+        );
+        let src = _mm256_setr_ps(-1., -1., -1., -1., -1., -1., -1., -1.);
+        let r = _mm512_mask_cvtepu64_ps(src, 0b01101001, a);
+        let e = _mm256_setr_ps(0., -1., -1., 1099511627776., -1., 123456., 7., -1.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtepu64_ps() {
+        let a = _mm512_setr_epi64(
+            0,
+            1,
+            2,
+            1099511627776,
+            i64::MIN,
+            123456,
+            7,
+            9007199254740992,
+        );
+        let r = _mm512_maskz_cvtepu64_ps(0, a);
+        assert_eq_m256(r, _mm256_setzero_ps());
+        let r = _mm512_maskz_cvtepu64_ps(0b01101001, a);
+        let e = _mm256_setr_ps(0., 0., 0., 1099511627776., 0., 123456., 7., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtpd_epi64() {
+        let a = _mm512_setr_pd(0., 1.5, -1.5, 2.5, -2.5, 3.7, 1e19, std::f64::NAN);
+        let r = _mm512_cvtpd_epi64(a);
+        let e = _mm512_setr_epi64(0, 2, -2, 2, -2, 4, i64::MIN, i64::MIN);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtpd_epi64() {
+        let a = _mm512_setr_pd(0., 1.5, -1.5, 2.5, -2.5, 3.7, 1e19, std::f64::NAN);
+        let src = _mm512_setr_epi64(-1, -1, -1, -1, -1, -1, -1, -1);
+        let r = _mm512_mask_cvtpd_epi64(src, 0b01101001, a);
+        let e = _mm512_setr_epi64(0, -1, -1, 2, -1, 4, i64::MIN, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtpd_epi64() {
+        let a = _mm512_setr_pd(0., 1.5, -1.5, 2.5, -2.5, 3.7, 1e19, std::f64::NAN);
+        let r = _mm512_maskz_cvtpd_epi64(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvtpd_epi64(0b01101001, a);
+        let e = _mm512_setr_epi64(0, 0, 0, 2, 0, 4, i64::MIN, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtps_epi64() {
+        let a = _mm256_setr_ps(0., 1.5, -1.5, 2.5, -2.5, 3.75, 1e19, std::f32::NAN);
+        let r = _mm512_cvtps_epi64(a);
+        let e = _mm512_setr_epi64(0, 2, -2, 2, -2, 4, i64::MIN, i64::MIN);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtps_epi64() {
+        let a = _mm256_setr_ps(0., 1.5, -1.5, 2.5, -2.5, 3.75, 1e19, std::f32::NAN);
+        let src = _mm512_setr_epi64(-1, -1, -1, -1, -1, -1, -1, -1);
+        let r = _mm512_mask_cvtps_epi64(src, 0b01101001, a);
+        let e = _mm512_setr_epi64(0, -1, -1, 2, -1, 4, i64::MIN, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtps_epi64() {
+        let a = _mm256_setr_ps(0., 1.5, -1.5, 2.5, -2.5, 3.75, 1e19, std::f32::NAN);
+        let r = _mm512_maskz_cvtps_epi64(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvtps_epi64(0b01101001, a);
+        let e = _mm512_setr_epi64(0, 0, 0, 2, 0, 4, i64::MIN, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtpd_epu64() {
+        let a = _mm512_setr_pd(0., 1.5, 0.5, 2.5, 3.5, 3.7, 9223372036854775808., 1e20);
+        let r = _mm512_cvtpd_epu64(a);
+        let e = _mm512_setr_epi64(0, 2, 0, 2, 4, 4, i64::MIN, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtpd_epu64() {
+        let a = _mm512_setr_pd(0., 1.5, 0.5, 2.5, 3.5, 3.7, 9223372036854775808., 1e20);
+        let src = _mm512_setr_epi64(-1, -1, -1, -1, -1, -1, -1, -1);
+        let r = _mm512_mask_cvtpd_epu64(src, 0b01101001, a);
+        let e = _mm512_setr_epi64(0, -1, -1, 2, -1, 4, i64::MIN, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtpd_epu64() {
+        let a = _mm512_setr_pd(0., 1.5, 0.5, 2.5, 3.5, 3.7, 9223372036854775808., 1e20);
+        let r = _mm512_maskz_cvtpd_epu64(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvtpd_epu64(0b01101001, a);
+        let e = _mm512_setr_epi64(0, 0, 0, 2, 0, 4, i64::MIN, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtps_epu64() {
+        let a = _mm256_setr_ps(0., 1.5, 0.5, 2.5, 3.5, 3.75, 9223372036854775808., 1e20);
+        let r = _mm512_cvtps_epu64(a);
+        let e = _mm512_setr_epi64(0, 2, 0, 2, 4, 4, i64::MIN, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtps_epu64() {
+        let a = _mm256_setr_ps(0., 1.5, 0.5, 2.5, 3.5, 3.75, 9223372036854775808., 1e20);
+        let src = _mm512_setr_epi64(-1, -1, -1, -1, -1, -1, -1, -1);
+        let r = _mm512_mask_cvtps_epu64(src, 0b01101001, a);
+        let e = _mm512_setr_epi64(0, -1, -1, 2, -1, 4, i64::MIN, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtps_epu64() {
+        let a = _mm256_setr_ps(0., 1.5, 0.5, 2.5, 3.5, 3.75, 9223372036854775808., 1e20);
+        let r = _mm512_maskz_cvtps_epu64(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvtps_epu64(0b01101001, a);
+        let e = _mm512_setr_epi64(0, 0, 0, 2, 0, 4, i64::MIN, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvttpd_epi64() {
+        let a = _mm512_setr_pd(0., 1.5, -1.5, 2.5, -2.5, 3.7, 1e19, std::f64::NAN);
+        let r = _mm512_cvttpd_epi64(a);
+        let e = _mm512_setr_epi64(0, 1, -1, 2, -2, 3, i64::MIN, i64::MIN);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvttpd_epi64() {
+        let a = _mm512_setr_pd(0., 1.5, -1.5, 2.5, -2.5, 3.7, 1e19, std::f64::NAN);
+        let src = _mm512_setr_epi64(-1, -1, -1, -1, -1, -1, -1, -1);
+        let r = _mm512_mask_cvttpd_epi64(src, 0b01101001, a);
+        let e = _mm512_setr_epi64(0, -1, -1, 2, -1, 3, i64::MIN, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvttpd_epi64() {
+        let a = _mm512_setr_pd(0., 1.5, -1.5, 2.5, -2.5, 3.7, 1e19, std::f64::NAN);
+        let r = _mm512_maskz_cvttpd_epi64(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvttpd_epi64(0b01101001, a);
+        let e = _mm512_setr_epi64(0, 0, 0, 2, 0, 3, i64::MIN, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvttps_epi64() {
+        let a = _mm256_setr_ps(0., 1.5, -1.5, 2.5, -2.5, 3.75, 1e19, std::f32::NAN);
+        let r = _mm512_cvttps_epi64(a);
+        let e = _mm512_setr_epi64(0, 1, -1, 2, -2, 3, i64::MIN, i64::MIN);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvttps_epi64() {
+        let a = _mm256_setr_ps(0., 1.5, -1.5, 2.5, -2.5, 3.75, 1e19, std::f32::NAN);
+        let src = _mm512_setr_epi64(-1, -1, -1, -1, -1, -1, -1, -1);
+        let r = _mm512_mask_cvttps_epi64(src, 0b01101001, a);
+        let e = _mm512_setr_epi64(0, -1, -1, 2, -1, 3, i64::MIN, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvttps_epi64() {
+        let a = _mm256_setr_ps(0., 1.5, -1.5, 2.5, -2.5, 3.75, 1e19, std::f32::NAN);
+        let r = _mm512_maskz_cvttps_epi64(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvttps_epi64(0b01101001, a);
+        let e = _mm512_setr_epi64(0, 0, 0, 2, 0, 3, i64::MIN, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvttpd_epu64() {
+        let a = _mm512_setr_pd(0., 1.5, 0.5, 2.5, 3.5, 3.7, 9223372036854775808., 1e20);
+        let r = _mm512_cvttpd_epu64(a);
+        let e = _mm512_setr_epi64(0, 1, 0, 2, 3, 3, i64::MIN, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvttpd_epu64() {
+        let a = _mm512_setr_pd(0., 1.5, 0.5, 2.5, 3.5, 3.7, 9223372036854775808., 1e20);
+        let src = _mm512_setr_epi64(-1, -1, -1, -1, -1, -1, -1, -1);
+        let r = _mm512_mask_cvttpd_epu64(src, 0b01101001, a);
+        let e = _mm512_setr_epi64(0, -1, -1, 2, -1, 3, i64::MIN, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvttpd_epu64() {
+        let a = _mm512_setr_pd(0., 1.5, 0.5, 2.5, 3.5, 3.7, 9223372036854775808., 1e20);
+        let r = _mm512_maskz_cvttpd_epu64(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvttpd_epu64(0b01101001, a);
+        let e = _mm512_setr_epi64(0, 0, 0, 2, 0, 3, i64::MIN, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvttps_epu64() {
+        let a = _mm256_setr_ps(0., 1.5, 0.5, 2.5, 3.5, 3.75, 9223372036854775808., 1e20);
+        let r = _mm512_cvttps_epu64(a);
+        let e = _mm512_setr_epi64(0, 1, 0, 2, 3, 3, i64::MIN, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvttps_epu64() {
+        let a = _mm256_setr_ps(0., 1.5, 0.5, 2.5, 3.5, 3.75, 9223372036854775808., 1e20);
+        let src = _mm512_setr_epi64(-1, -1, -1, -1, -1, -1, -1, -1);
+        let r = _mm512_mask_cvttps_epu64(src, 0b01101001, a);
+        let e = _mm512_setr_epi64(0, -1, -1, 2, -1, 3, i64::MIN, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvttps_epu64() {
+        let a = _mm256_setr_ps(0., 1.5, 0.5, 2.5, 3.5, 3.75, 9223372036854775808., 1e20);
+        let r = _mm512_maskz_cvttps_epu64(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvttps_epu64(0b01101001, a);
+        let e = _mm512_setr_epi64(0, 0, 0, 2, 0, 3, i64::MIN, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_range_ps() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1., -2., 3., -4., 0.5, -0.5, 8., -8.,
+            1., -2., 3., -4., 0.5, -0.5, 8., -8.,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_setr_ps(
+            2., -1., -3.5, 4.5, -0.25, 0.75, 7., -9.,
+            2., -1., -3.5, 4.5, -0.25, 0.75, 7., -9.,
+        );
+        let r = _mm512_range_ps(a, b, 0b0101);
+        #[rustfmt::skip]
+        let e = _mm512_setr_ps(
+            2., -1., 3., 4.5, 0.5, 0.75, 8., -8.,
+            2., -1., 3., 4.5, 0.5, 0.75, 8., -8.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_range_ps() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1., -2., 3., -4., 0.5, -0.5, 8., -8.,
+            1., -2., 3., -4., 0.5, -0.5, 8., -8.,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_setr_ps(
+            2., -1., -3.5, 4.5, -0.25, 0.75, 7., -9.,
+            2., -1., -3.5, 4.5, -0.25, 0.75, 7., -9.,
+        );
+        #[rustfmt::skip]
+        let src = _mm512_setr_ps(
+            -1., -1., -1., -1., -1., -1., -1., -1.,
+            -1., -1., -1., -1., -1., -1., -1., -1.,
+        );
+        let r = _mm512_mask_range_ps(src, 0b01010101_10011001, a, b, 0b0101);
+        #[rustfmt::skip]
+        let e = _mm512_setr_ps(
+            2., -1., -1., 4.5, 0.5, -1., -1., -8.,
+            2., -1., 3., -1., 0.5, -1., 8., -1.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_range_ps() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1., -2., 3., -4., 0.5, -0.5, 8., -8.,
+            1., -2., 3., -4., 0.5, -0.5, 8., -8.,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_setr_ps(
+            2., -1., -3.5, 4.5, -0.25, 0.75, 7., -9.,
+            2., -1., -3.5, 4.5, -0.25, 0.75, 7., -9.,
+        );
+        let r = _mm512_maskz_range_ps(0, a, b, 0b0101);
+        assert_eq_m512(r, _mm512_setzero_ps());
+        let r = _mm512_maskz_range_ps(0b01010101_10011001, a, b, 0b0101);
+        #[rustfmt::skip]
+        let e = _mm512_setr_ps(
+            2., 0., 0., 4.5, 0.5, 0., 0., -8.,
+            2., 0., 3., 0., 0.5, 0., 8., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_range_pd() {
+        let a = _mm512_setr_pd(1., -2., 3., -4., 0.5, -0.5, 8., -8.);
+        let b = _mm512_setr_pd(2., -1., -3.5, 4.5, -0.25, 0.75, 7., -9.);
+        let r = _mm512_range_pd(a, b, 0b0101);
+        let e = _mm512_setr_pd(2., -1., 3., 4.5, 0.5, 0.75, 8., -8.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_range_pd() {
+        let a = _mm512_setr_pd(1., -2., 3., -4., 0.5, -0.5, 8., -8.);
+        let b = _mm512_setr_pd(2., -1., -3.5, 4.5, -0.25, 0.75, 7., -9.);
+        let src = _mm512_setr_pd(-1., -1., -1., -1., -1., -1., -1., -1.);
+        let r = _mm512_mask_range_pd(src, 0b01101001, a, b, 0b0101);
+        let e = _mm512_setr_pd(2., -1., -1., 4.5, -1., 0.75, 8., -1.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_range_pd() {
+        let a = _mm512_setr_pd(1., -2., 3., -4., 0.5, -0.5, 8., -8.);
+        let b = _mm512_setr_pd(2., -1., -3.5, 4.5, -0.25, 0.75, 7., -9.);
+        let r = _mm512_maskz_range_pd(0, a, b, 0b0101);
+        assert_eq_m512d(r, _mm512_setzero_pd());
+        let r = _mm512_maskz_range_pd(0b01101001, a, b, 0b0101);
+        let e = _mm512_setr_pd(2., 0., 0., 4.5, 0., 0.75, 8., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_reduce_ps() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1.25, 1.5, 2.5, -1.75, 0.75, -0.25, 10.5, -3.5,
+            1.25, 1.5, 2.5, -1.75, 0.75, -0.25, 10.5, -3.5,
+        );
+        let r = _mm512_reduce_ps(a, 0);
+        #[rustfmt::skip]
+        let e = _mm512_setr_ps(
+            0.25, -0.5, 0.5, 0.25, -0.25, -0.25, 0.5, 0.5,
+            0.25, -0.5, 0.5, 0.25, -0.25, -0.25, 0.5, 0.5,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_reduce_ps() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1.25, 1.5, 2.5, -1.75, 0.75, -0.25, 10.5, -3.5,
+            1.25, 1.5, 2.5, -1.75, 0.75, -0.25, 10.5, -3.5,
+        );
+        #[rustfmt::skip]
+        let src = _mm512_setr_ps(
+            -1., -1., -1., -1., -1., -1., -1., -1.,
+            -1., -1., -1., -1., -1., -1., -1., -1.,
+        );
+        let r = _mm512_mask_reduce_ps(src, 0b01010101_10011001, a, 0);
+        #[rustfmt::skip]
+        let e = _mm512_setr_ps(
+            0.25, -1., -1., 0.25, -0.25, -1., -1., 0.5,
+            0.25, -1., 0.5, -1., -0.25, -1., 0.5, -1.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_reduce_ps() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1.25, 1.5, 2.5, -1.75, 0.75, -0.25, 10.5, -3.5,
+            1.25, 1.5, 2.5, -1.75, 0.75, -0.25, 10.5, -3.5,
+        );
+        let r = _mm512_maskz_reduce_ps(0, a, 0);
+        assert_eq_m512(r, _mm512_setzero_ps());
+        let r = _mm512_maskz_reduce_ps(0b01010101_10011001, a, 0);
+        #[rustfmt::skip]
+        let e = _mm512_setr_ps(
+            0.25, 0., 0., 0.25, -0.25, 0., 0., 0.5,
+            0.25, 0., 0.5, 0., -0.25, 0., 0.5, 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_reduce_pd() {
+        let a = _mm512_setr_pd(1.25, 1.5, 2.5, -1.75, 0.75, -0.25, 10.5, -3.5);
+        let r = _mm512_reduce_pd(a, 0);
+        let e = _mm512_setr_pd(0.25, -0.5, 0.5, 0.25, -0.25, -0.25, 0.5, 0.5);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_reduce_pd() {
+        let a = _mm512_setr_pd(1.25, 1.5, 2.5, -1.75, 0.75, -0.25, 10.5, -3.5);
+        let src = _mm512_setr_pd(-1., -1., -1., -1., -1., -1., -1., -1.);
+        let r = _mm512_mask_reduce_pd(src, 0b01101001, a, 0);
+        let e = _mm512_setr_pd(0.25, -1., -1., 0.25, -1., -0.25, 0.5, -1.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_reduce_pd() {
+        let a = _mm512_setr_pd(1.25, 1.5, 2.5, -1.75, 0.75, -0.25, 10.5, -3.5);
+        let r = _mm512_maskz_reduce_pd(0, a, 0);
+        assert_eq_m512d(r, _mm512_setzero_pd());
+        let r = _mm512_maskz_reduce_pd(0b01101001, a, 0);
+        let e = _mm512_setr_pd(0.25, 0., 0., 0.25, 0., -0.25, 0.5, 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_fpclass_ps_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            std::f32::NAN, 0., -0., std::f32::INFINITY,
+            std::f32::NEG_INFINITY, 1., -1., 2.5,
+            std::f32::NAN, 0., -0., std::f32::INFINITY,
+            std::f32::NEG_INFINITY, 1., -1., 2.5,
+        );
+        let r = _mm512_fpclass_ps_mask(a, 0x18);
+        assert_eq!(r, 0b00011000_00011000);
+        let r = _mm512_fpclass_ps_mask(a, 0x47);
+        assert_eq!(r, 0b01000111_01000111);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_fpclass_ps_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            std::f32::NAN, 0., -0., std::f32::INFINITY,
+            std::f32::NEG_INFINITY, 1., -1., 2.5,
+            std::f32::NAN, 0., -0., std::f32::INFINITY,
+            std::f32::NEG_INFINITY, 1., -1., 2.5,
+        );
+        let r = _mm512_mask_fpclass_ps_mask(0b01010101_10011001, a, 0x47);
+        assert_eq!(r, 0b01000101_00000001);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_fpclass_pd_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_pd(
+            std::f64::NAN, 0., -0., std::f64::INFINITY,
+            std::f64::NEG_INFINITY, 1., -1., 2.5,
+        );
+        let r = _mm512_fpclass_pd_mask(a, 0x18);
+        assert_eq!(r, 0b00011000);
+        let r = _mm512_fpclass_pd_mask(a, 0x47);
+        assert_eq!(r, 0b01000111);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_fpclass_pd_mask() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_pd(
+            std::f64::NAN, 0., -0., std::f64::INFINITY,
+            std::f64::NEG_INFINITY, 1., -1., 2.5,
+        );
+        let r = _mm512_mask_fpclass_pd_mask(0b01101001, a, 0x47);
+        assert_eq!(r, 0b01000001);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_and_pd() {
+        let a = _mm512_setr_pd(1., -1., 2., -2., 0.5, -0.5, 8., -8.);
+        let b = _mm512_setr_pd(-0., -0., 2., 0., -0.5, 0.5, -8., 8.);
+        let r = _mm512_and_pd(a, b);
+        let e = _mm512_setr_pd(0., -0., 2., 0., 0.5, 0.5, 8., 8.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_and_pd() {
+        let a = _mm512_setr_pd(1., -1., 2., -2., 0.5, -0.5, 8., -8.);
+        let b = _mm512_setr_pd(-0., -0., 2., 0., -0.5, 0.5, -8., 8.);
+        let src = _mm512_setr_pd(-1., -1., -1., -1., -1., -1., -1., -1.);
+        let r = _mm512_mask_and_pd(src, 0b01101001, a, b);
+        let e = _mm512_setr_pd(0., -1., -1., 0., -1., 0.5, 8., -1.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_and_pd() {
+        let a = _mm512_setr_pd(1., -1., 2., -2., 0.5, -0.5, 8., -8.);
+        let b = _mm512_setr_pd(-0., -0., 2., 0., -0.5, 0.5, -8., 8.);
+        let r = _mm512_maskz_and_pd(0, a, b);
+        assert_eq_m512d(r, _mm512_setzero_pd());
+        let r = _mm512_maskz_and_pd(0b01101001, a, b);
+        let e = _mm512_setr_pd(0., 0., 0., 0., 0., 0.5, 8., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_and_ps() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1., -1., 2., -2., 0.5, -0.5, 8., -8.,
+            1., -1., 2., -2., 0.5, -0.5, 8., -8.,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_setr_ps(
+            -0., -0., 2., 0., -0.5, 0.5, -8., 8.,
+            -0., -0., 2., 0., -0.5, 0.5, -8., 8.,
+        );
+        let r = _mm512_and_ps(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_setr_ps(
+            0., -0., 2., 0., 0.5, 0.5, 8., 8.,
+            0., -0., 2., 0., 0.5, 0.5, 8., 8.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_and_ps() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1., -1., 2., -2., 0.5, -0.5, 8., -8.,
+            1., -1., 2., -2., 0.5, -0.5, 8., -8.,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_setr_ps(
+            -0., -0., 2., 0., -0.5, 0.5, -8., 8.,
+            -0., -0., 2., 0., -0.5, 0.5, -8., 8.,
+        );
+        #[rustfmt::skip]
+        let src = _mm512_setr_ps(
+            -1., -1., -1., -1., -1., -1., -1., -1.,
+            -1., -1., -1., -1., -1., -1., -1., -1.,
+        );
+        let r = _mm512_mask_and_ps(src, 0b01010101_10011001, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_setr_ps(
+            0., -1., -1., 0., 0.5, -1., -1., 8.,
+            0., -1., 2., -1., 0.5, -1., 8., -1.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_and_ps() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1., -1., 2., -2., 0.5, -0.5, 8., -8.,
+            1., -1., 2., -2., 0.5, -0.5, 8., -8.,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_setr_ps(
+            -0., -0., 2., 0., -0.5, 0.5, -8., 8.,
+            -0., -0., 2., 0., -0.5, 0.5, -8., 8.,
+        );
+        let r = _mm512_maskz_and_ps(0, a, b);
+        assert_eq_m512(r, _mm512_setzero_ps());
+        let r = _mm512_maskz_and_ps(0b01010101_10011001, a, b);
+        let e = _mm512_setr_ps(
+            0., 0., 0., 0., 0.5, 0., 0., 8., 0., 0., 2., 0., 0.5, 0., 8., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_andnot_pd() {
+        let a = _mm512_setr_pd(1., -1., 2., -2., 0.5, -0.5, 8., -8.);
+        let b = _mm512_setr_pd(-0., -0., 2., 0., -0.5, 0.5, -8., 8.);
+        let r = _mm512_andnot_pd(a, b);
+        let e = _mm512_setr_pd(-0., 0., 0., 0., -0., 0., -0., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_andnot_pd() {
+        let a = _mm512_setr_pd(1., -1., 2., -2., 0.5, -0.5, 8., -8.);
+        let b = _mm512_setr_pd(-0., -0., 2., 0., -0.5, 0.5, -8., 8.);
+        let src = _mm512_setr_pd(-1., -1., -1., -1., -1., -1., -1., -1.);
+        let r = _mm512_mask_andnot_pd(src, 0b01101001, a, b);
+        let e = _mm512_setr_pd(-0., -1., -1., 0., -1., 0., -0., -1.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_andnot_pd() {
+        let a = _mm512_setr_pd(1., -1., 2., -2., 0.5, -0.5, 8., -8.);
+        let b = _mm512_setr_pd(-0., -0., 2., 0., -0.5, 0.5, -8., 8.);
+        let r = _mm512_maskz_andnot_pd(0, a, b);
+        assert_eq_m512d(r, _mm512_setzero_pd());
+        let r = _mm512_maskz_andnot_pd(0b01101001, a, b);
+        let e = _mm512_setr_pd(-0., 0., 0., 0., 0., 0., -0., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_andnot_ps() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1., -1., 2., -2., 0.5, -0.5, 8., -8.,
+            1., -1., 2., -2., 0.5, -0.5, 8., -8.,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_setr_ps(
+            -0., -0., 2., 0., -0.5, 0.5, -8., 8.,
+            -0., -0., 2., 0., -0.5, 0.5, -8., 8.,
+        );
+        let r = _mm512_andnot_ps(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_setr_ps(
+            -0., 0., 0., 0., -0., 0., -0., 0.,
+            -0., 0., 0., 0., -0., 0., -0., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_andnot_ps() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1., -1., 2., -2., 0.5, -0.5, 8., -8.,
+            1., -1., 2., -2., 0.5, -0.5, 8., -8.,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_setr_ps(
+            -0., -0., 2., 0., -0.5, 0.5, -8., 8.,
+            -0., -0., 2., 0., -0.5, 0.5, -8., 8.,
+        );
+        #[rustfmt::skip]
+        let src = _mm512_setr_ps(
+            -1., -1., -1., -1., -1., -1., -1., -1.,
+            -1., -1., -1., -1., -1., -1., -1., -1.,
+        );
+        let r = _mm512_mask_andnot_ps(src, 0b01010101_10011001, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_setr_ps(
+            -0., -1., -1., 0., -0., -1., -1., 0.,
+            -0., -1., 0., -1., -0., -1., -0., -1.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_andnot_ps() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1., -1., 2., -2., 0.5, -0.5, 8., -8.,
+            1., -1., 2., -2., 0.5, -0.5, 8., -8.,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_setr_ps(
+            -0., -0., 2., 0., -0.5, 0.5, -8., 8.,
+            -0., -0., 2., 0., -0.5, 0.5, -8., 8.,
+        );
+        let r = _mm512_maskz_andnot_ps(0, a, b);
+        assert_eq_m512(r, _mm512_setzero_ps());
+        let r = _mm512_maskz_andnot_ps(0b01010101_10011001, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_setr_ps(
+            -0., 0., 0., 0., -0., 0., 0., 0.,
+            -0., 0., 0., 0., -0., 0., -0., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_or_pd() {
+        let a = _mm512_setr_pd(1., -1., 2., -2., 0.5, -0.5, 8., -8.);
+        let b = _mm512_setr_pd(-0., -0., 2., 0., -0.5, 0.5, -8., 8.);
+        let r = _mm512_or_pd(a, b);
+        let e = _mm512_setr_pd(-1., -1., 2., -2., -0.5, -0.5, -8., -8.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_or_pd() {
+        let a = _mm512_setr_pd(1., -1., 2., -2., 0.5, -0.5, 8., -8.);
+        let b = _mm512_setr_pd(-0., -0., 2., 0., -0.5, 0.5, -8., 8.);
+        let src = _mm512_setr_pd(-1., -1., -1., -1., -1., -1., -1., -1.);
+        let r = _mm512_mask_or_pd(src, 0b01101001, a, b);
+        let e = _mm512_setr_pd(-1., -1., -1., -2., -1., -0.5, -8., -1.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_or_pd() {
+        let a = _mm512_setr_pd(1., -1., 2., -2., 0.5, -0.5, 8., -8.);
+        let b = _mm512_setr_pd(-0., -0., 2., 0., -0.5, 0.5, -8., 8.);
+        let r = _mm512_maskz_or_pd(0, a, b);
+        assert_eq_m512d(r, _mm512_setzero_pd());
+        let r = _mm512_maskz_or_pd(0b01101001, a, b);
+        let e = _mm512_setr_pd(-1., 0., 0., -2., 0., -0.5, -8., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_or_ps() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1., -1., 2., -2., 0.5, -0.5, 8., -8.,
+            1., -1., 2., -2., 0.5, -0.5, 8., -8.,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_setr_ps(
+            -0., -0., 2., 0., -0.5, 0.5, -8., 8.,
+            -0., -0., 2., 0., -0.5, 0.5, -8., 8.,
+        );
+        let r = _mm512_or_ps(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_setr_ps(
+            -1., -1., 2., -2., -0.5, -0.5, -8., -8.,
+            -1., -1., 2., -2., -0.5, -0.5, -8., -8.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_or_ps() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1., -1., 2., -2., 0.5, -0.5, 8., -8.,
+            1., -1., 2., -2., 0.5, -0.5, 8., -8.,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_setr_ps(
+            -0., -0., 2., 0., -0.5, 0.5, -8., 8.,
+            -0., -0., 2., 0., -0.5, 0.5, -8., 8.,
+        );
+        #[rustfmt::skip]
+        let src = _mm512_setr_ps(
+            -1., -1., -1., -1., -1., -1., -1., -1.,
+            -1., -1., -1., -1., -1., -1., -1., -1.,
+        );
+        let r = _mm512_mask_or_ps(src, 0b01010101_10011001, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_setr_ps(
+            -1., -1., -1., -2., -0.5, -1., -1., -8.,
+            -1., -1., 2., -1., -0.5, -1., -8., -1.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_or_ps() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1., -1., 2., -2., 0.5, -0.5, 8., -8.,
+            1., -1., 2., -2., 0.5, -0.5, 8., -8.,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_setr_ps(
+            -0., -0., 2., 0., -0.5, 0.5, -8., 8.,
+            -0., -0., 2., 0., -0.5, 0.5, -8., 8.,
+        );
+        let r = _mm512_maskz_or_ps(0, a, b);
+        assert_eq_m512(r, _mm512_setzero_ps());
+        let r = _mm512_maskz_or_ps(0b01010101_10011001, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_setr_ps(
+            -1., 0., 0., -2., -0.5, 0., 0., -8.,
+            -1., 0., 2., 0., -0.5, 0., -8., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_xor_pd() {
+        let a = _mm512_setr_pd(1., -1., 2., -2., 0.5, -0.5, 8., -8.);
+        let b = _mm512_setr_pd(-0., -0., 2., 0., -0.5, 0.5, -8., 8.);
+        let r = _mm512_xor_pd(a, b);
+        let e = _mm512_setr_pd(-1., 1., 0., -2., -0., -0., -0., -0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_xor_pd() {
+        let a = _mm512_setr_pd(1., -1., 2., -2., 0.5, -0.5, 8., -8.);
+        let b = _mm512_setr_pd(-0., -0., 2., 0., -0.5, 0.5, -8., 8.);
+        let src = _mm512_setr_pd(-1., -1., -1., -1., -1., -1., -1., -1.);
+        let r = _mm512_mask_xor_pd(src, 0b01101001, a, b);
+        let e = _mm512_setr_pd(-1., -1., -1., -2., -1., -0., -0., -1.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_xor_pd() {
+        let a = _mm512_setr_pd(1., -1., 2., -2., 0.5, -0.5, 8., -8.);
+        let b = _mm512_setr_pd(-0., -0., 2., 0., -0.5, 0.5, -8., 8.);
+        let r = _mm512_maskz_xor_pd(0, a, b);
+        assert_eq_m512d(r, _mm512_setzero_pd());
+        let r = _mm512_maskz_xor_pd(0b01101001, a, b);
+        let e = _mm512_setr_pd(-1., 0., 0., -2., 0., -0., -0., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_xor_ps() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1., -1., 2., -2., 0.5, -0.5, 8., -8.,
+            1., -1., 2., -2., 0.5, -0.5, 8., -8.,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_setr_ps(
+            -0., -0., 2., 0., -0.5, 0.5, -8., 8.,
+            -0., -0., 2., 0., -0.5, 0.5, -8., 8.,
+        );
+        let r = _mm512_xor_ps(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_setr_ps(
+            -1., 1., 0., -2., -0., -0., -0., -0.,
+            -1., 1., 0., -2., -0., -0., -0., -0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_xor_ps() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1., -1., 2., -2., 0.5, -0.5, 8., -8.,
+            1., -1., 2., -2., 0.5, -0.5, 8., -8.,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_setr_ps(
+            -0., -0., 2., 0., -0.5, 0.5, -8., 8.,
+            -0., -0., 2., 0., -0.5, 0.5, -8., 8.,
+        );
+        #[rustfmt::skip]
+        let src = _mm512_setr_ps(
+            -1., -1., -1., -1., -1., -1., -1., -1.,
+            -1., -1., -1., -1., -1., -1., -1., -1.,
+        );
+        let r = _mm512_mask_xor_ps(src, 0b01010101_10011001, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_setr_ps(
+            -1., -1., -1., -2., -0., -1., -1., -0.,
+            -1., -1., 0., -1., -0., -1., -0., -1.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_xor_ps() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1., -1., 2., -2., 0.5, -0.5, 8., -8.,
+            1., -1., 2., -2., 0.5, -0.5, 8., -8.,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_setr_ps(
+            -0., -0., 2., 0., -0.5, 0.5, -8., 8.,
+            -0., -0., 2., 0., -0.5, 0.5, -8., 8.,
+        );
+        let r = _mm512_maskz_xor_ps(0, a, b);
+        assert_eq_m512(r, _mm512_setzero_ps());
+        let r = _mm512_maskz_xor_ps(0b01010101_10011001, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_setr_ps(
+            -1., 0., 0., -2., -0., 0., 0., -0.,
+            -1., 0., 0., 0., -0., 0., -0., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_logic_pd_bits() {
+        // NaN payloads and signed zeros must survive untouched, so compare
+        // the raw bits rather than the floating-point values.
+        let a: __m512d = transmute(_mm512_set1_epi64(0x7ff8_0000_ffff_0000));
+        let b: __m512d = transmute(_mm512_set1_epi64(-0x0000_0000_f0f0_f0f1));
+        let r: __m512i = transmute(_mm512_and_pd(a, b));
+        assert_eq_m512i(r, _mm512_set1_epi64(0x7ff8_0000_0f0f_0000));
+        let r: __m512i = transmute(_mm512_andnot_pd(a, b));
+        assert_eq_m512i(r, _mm512_set1_epi64(-0x7ff8_0000_ffff_f0f1));
+        let r: __m512i = transmute(_mm512_or_pd(a, b));
+        assert_eq_m512i(r, _mm512_set1_epi64(-0x0000_0000_0000_f0f1));
+        let r: __m512i = transmute(_mm512_xor_pd(a, b));
+        assert_eq_m512i(r, _mm512_set1_epi64(-0x7ff8_0000_0f0f_f0f1));
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_logic_ps_bits() {
+        // NaN payloads and signed zeros must survive untouched, so compare
+        // the raw bits rather than the floating-point values.
+        let a: __m512 = transmute(_mm512_set1_epi32(0x7fc0_ff00));
+        let b: __m512 = transmute(_mm512_set1_epi32(-0x0f0f_0f0f));
+        let r: __m512i = transmute(_mm512_and_ps(a, b));
+        assert_eq_m512i(r, _mm512_set1_epi32(0x70c0_f000));
+        let r: __m512i = transmute(_mm512_andnot_ps(a, b));
+        assert_eq_m512i(r, _mm512_set1_epi32(-0x7fcf_ff0f));
+        let r: __m512i = transmute(_mm512_or_ps(a, b));
+        assert_eq_m512i(r, _mm512_set1_epi32(-0x000f_000f));
+        let r: __m512i = transmute(_mm512_xor_ps(a, b));
+        assert_eq_m512i(r, _mm512_set1_epi32(-0x70cf_f00f));
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_broadcast_f32x2() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let r = _mm512_broadcast_f32x2(a);
+        let e = _mm512_setr_ps(
+            1., 2., 1., 2., 1., 2., 1., 2., 1., 2., 1., 2., 1., 2., 1., 2.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_broadcast_f32x2() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        #[rustfmt::skip]
+        let src = _mm512_setr_ps(
+            -1., -1., -1., -1., -1., -1., -1., -1.,
+            -1., -1., -1., -1., -1., -1., -1., -1.,
+        );
+        let r = _mm512_mask_broadcast_f32x2(src, 0b01010101_10011001, a);
+        #[rustfmt::skip]
+        let e = _mm512_setr_ps(
+            1., -1., -1., 2., 1., -1., -1., 2.,
+            1., -1., 1., -1., 1., -1., 1., -1.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_broadcast_f32x2() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let r = _mm512_maskz_broadcast_f32x2(0, a);
+        assert_eq_m512(r, _mm512_setzero_ps());
+        let r = _mm512_maskz_broadcast_f32x2(0b01010101_10011001, a);
+        let e = _mm512_setr_ps(
+            1., 0., 0., 2., 1., 0., 0., 2., 1., 0., 1., 0., 1., 0., 1., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_broadcast_i32x2() {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let r = _mm512_broadcast_i32x2(a);
+        let e = _mm512_setr_epi32(1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_broadcast_i32x2() {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        #[rustfmt::skip]
+        let src = _mm512_setr_epi32(
+            -1, -1, -1, -1, -1, -1, -1, -1,
+            -1, -1, -1, -1, -1, -1, -1, -1,
+        );
+        let r = _mm512_mask_broadcast_i32x2(src, 0b01010101_10011001, a);
+        let e = _mm512_setr_epi32(1, -1, -1, 2, 1, -1, -1, 2, 1, -1, 1, -1, 1, -1, 1, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_broadcast_i32x2() {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let r = _mm512_maskz_broadcast_i32x2(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_broadcast_i32x2(0b01010101_10011001, a);
+        let e = _mm512_setr_epi32(1, 0, 0, 2, 1, 0, 0, 2, 1, 0, 1, 0, 1, 0, 1, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_broadcast_f64x2() {
+        let a = _mm_setr_pd(1., 2.);
+        let r = _mm512_broadcast_f64x2(a);
+        let e = _mm512_setr_pd(1., 2., 1., 2., 1., 2., 1., 2.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_broadcast_f64x2() {
+        let a = _mm_setr_pd(1., 2.);
+        let src = _mm512_setr_pd(-1., -1., -1., -1., -1., -1., -1., -1.);
+        let r = _mm512_mask_broadcast_f64x2(src, 0b01101001, a);
+        let e = _mm512_setr_pd(1., -1., -1., 2., -1., 2., 1., -1.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_broadcast_f64x2() {
+        let a = _mm_setr_pd(1., 2.);
+        let r = _mm512_maskz_broadcast_f64x2(0, a);
+        assert_eq_m512d(r, _mm512_setzero_pd());
+        let r = _mm512_maskz_broadcast_f64x2(0b01101001, a);
+        let e = _mm512_setr_pd(1., 0., 0., 2., 0., 2., 1., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_broadcast_i64x2() {
+        let a = _mm_set_epi64x(2, 1);
+        let r = _mm512_broadcast_i64x2(a);
+        let e = _mm512_setr_epi64(1, 2, 1, 2, 1, 2, 1, 2);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_broadcast_i64x2() {
+        let a = _mm_set_epi64x(2, 1);
+        let src = _mm512_setr_epi64(-1, -1, -1, -1, -1, -1, -1, -1);
+        let r = _mm512_mask_broadcast_i64x2(src, 0b01101001, a);
+        let e = _mm512_setr_epi64(1, -1, -1, 2, -1, 2, 1, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_broadcast_i64x2() {
+        let a = _mm_set_epi64x(2, 1);
+        let r = _mm512_maskz_broadcast_i64x2(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_broadcast_i64x2(0b01101001, a);
+        let e = _mm512_setr_epi64(1, 0, 0, 2, 0, 2, 1, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_extractf64x2_pd() {
+        let a = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        let r = _mm512_extractf64x2_pd(a, 3);
+        let e = _mm_setr_pd(6., 7.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_extractf64x2_pd() {
+        let a = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        let src = _mm_setr_pd(-1., -1.);
+        let r = _mm512_mask_extractf64x2_pd(src, 0b00000001, a, 3);
+        let e = _mm_setr_pd(6., -1.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_extractf64x2_pd() {
+        let a = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        let r = _mm512_maskz_extractf64x2_pd(0, a, 3);
+        assert_eq_m128d(r, _mm_setzero_pd());
+        let r = _mm512_maskz_extractf64x2_pd(0b00000001, a, 3);
+        let e = _mm_setr_pd(6., 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_insertf64x2() {
+        let a = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        let b = _mm_setr_pd(-1., -2.);
+        let r = _mm512_insertf64x2(a, b, 3);
+        let e = _mm512_setr_pd(0., 1., 2., 3., 4., 5., -1., -2.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_insertf64x2() {
+        let a = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        let b = _mm_setr_pd(-1., -2.);
+        let src = _mm512_setr_pd(-9., -9., -9., -9., -9., -9., -9., -9.);
+        let r = _mm512_mask_insertf64x2(src, 0b01101001, a, b, 3);
+        let e = _mm512_setr_pd(0., -9., -9., 3., -9., 5., -1., -9.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_insertf64x2() {
+        let a = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        let b = _mm_setr_pd(-1., -2.);
+        let r = _mm512_maskz_insertf64x2(0, a, b, 3);
+        assert_eq_m512d(r, _mm512_setzero_pd());
+        let r = _mm512_maskz_insertf64x2(0b01101001, a, b, 3);
+        let e = _mm512_setr_pd(0., 0., 0., 3., 0., 5., -1., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_extracti64x2_epi64() {
+        let a = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, 6, 7);
+        let r = _mm512_extracti64x2_epi64(a, 3);
+        let e = _mm_set_epi64x(7, 6);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_extracti64x2_epi64() {
+        let a = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, 6, 7);
+        let src = _mm_set_epi64x(-1, -1);
+        let r = _mm512_mask_extracti64x2_epi64(src, 0b00000001, a, 3);
+        let e = _mm_set_epi64x(-1, 6);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_extracti64x2_epi64() {
+        let a = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, 6, 7);
+        let r = _mm512_maskz_extracti64x2_epi64(0, a, 3);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm512_maskz_extracti64x2_epi64(0b00000001, a, 3);
+        let e = _mm_set_epi64x(0, 6);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_inserti64x2() {
+        let a = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, 6, 7);
+        let b = _mm_set_epi64x(-2, -1);
+        let r = _mm512_inserti64x2(a, b, 3);
+        let e = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, -1, -2);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_inserti64x2() {
+        let a = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, 6, 7);
+        let b = _mm_set_epi64x(-2, -1);
+        let src = _mm512_setr_epi64(-9, -9, -9, -9, -9, -9, -9, -9);
+        let r = _mm512_mask_inserti64x2(src, 0b01101001, a, b, 3);
+        let e = _mm512_setr_epi64(0, -9, -9, 3, -9, 5, -1, -9);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_inserti64x2() {
+        let a = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, 6, 7);
+        let b = _mm_set_epi64x(-2, -1);
+        let r = _mm512_maskz_inserti64x2(0, a, b, 3);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_inserti64x2(0b01101001, a, b, 3);
+        let e = _mm512_setr_epi64(0, 0, 0, 3, 0, 5, -1, 0);
+        assert_eq_m512i(r, e);
+    }
+}
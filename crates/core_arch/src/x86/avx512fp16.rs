@@ -0,0 +1,6025 @@
+use crate::{
+    core_arch::{simd::*, simd_llvm::*, x86::*},
+    mem::{self, transmute},
+};
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Returns vector of type `__m512h` with all elements set to zero.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_setzero_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm512_setzero_ph() -> __m512h {
+    // All-0 is a properly initialized __m512h
+    mem::zeroed()
+}
+
+/// Returns vector of type `__m512h` with all elements set to zero.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_setzero_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm256_setzero_ph() -> __m256h {
+    // All-0 is a properly initialized __m256h
+    mem::zeroed()
+}
+
+/// Returns vector of type `__m512h` with all elements set to zero.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_setzero_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm_setzero_ph() -> __m128h {
+    // All-0 is a properly initialized __m128h
+    mem::zeroed()
+}
+
+/// Broadcasts the half-precision (16-bit) floating-point value `a` to all
+/// elements of `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_set1_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm512_set1_ph(a: __f16) -> __m512h {
+    transmute(u16x32::splat(a))
+}
+
+/// Broadcasts the half-precision (16-bit) floating-point value `a` to all
+/// elements of `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_set1_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+pub unsafe fn _mm256_set1_ph(a: __f16) -> __m256h {
+    transmute(u16x16::splat(a))
+}
+
+/// Broadcasts the half-precision (16-bit) floating-point value `a` to all
+/// elements of `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_set1_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+pub unsafe fn _mm_set1_ph(a: __f16) -> __m128h {
+    transmute(u16x8::splat(a))
+}
+
+/// Copies the lower half-precision (16-bit) floating-point element of `a` to
+/// `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtsh_h)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn _mm512_cvtsh_h(a: __m512h) -> __f16 {
+    simd_extract(a, 0)
+}
+
+/// Copies the lower half-precision (16-bit) floating-point element of `a` to
+/// `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtsh_h)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+pub unsafe fn _mm256_cvtsh_h(a: __m256h) -> __f16 {
+    simd_extract(a, 0)
+}
+
+/// Copies the lower half-precision (16-bit) floating-point element of `a` to
+/// `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtsh_h)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+pub unsafe fn _mm_cvtsh_h(a: __m128h) -> __f16 {
+    simd_extract(a, 0)
+}
+
+/// Adds packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm512_add_ph(a: __m512h, b: __m512h) -> __m512h {
+    let r: __m512h;
+    asm!("vaddph $2, $1, $0" : "=v"(r) : "v"(a), "v"(b));
+    r
+}
+
+/// Adds packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and stores the results in `dst` using writemask `k` (elements are copied
+/// from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm512_mask_add_ph(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let r = _mm512_add_ph(a, b);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Adds packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and stores the results in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm512_maskz_add_ph(k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let r = _mm512_add_ph(a, b);
+    let zero = _mm512_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Adds packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm256_add_ph(a: __m256h, b: __m256h) -> __m256h {
+    let r: __m256h;
+    asm!("vaddph $2, $1, $0" : "=v"(r) : "v"(a), "v"(b));
+    r
+}
+
+/// Adds packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and stores the results in `dst` using writemask `k` (elements are copied
+/// from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm256_mask_add_ph(src: __m256h, k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let r = _mm256_add_ph(a, b);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Adds packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and stores the results in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm256_maskz_add_ph(k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let r = _mm256_add_ph(a, b);
+    let zero = _mm256_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Adds packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm_add_ph(a: __m128h, b: __m128h) -> __m128h {
+    let r: __m128h;
+    asm!("vaddph $2, $1, $0" : "=v"(r) : "v"(a), "v"(b));
+    r
+}
+
+/// Adds packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and stores the results in `dst` using writemask `k` (elements are copied
+/// from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm_mask_add_ph(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let r = _mm_add_ph(a, b);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Adds packed half-precision (16-bit) floating-point elements in `a` and `b`,
+/// and stores the results in `dst` using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_add_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vaddph))]
+pub unsafe fn _mm_maskz_add_ph(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let r = _mm_add_ph(a, b);
+    let zero = _mm_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Subtracts packed half-precision (16-bit) floating-point elements in `b` from
+/// packed half-precision (16-bit) floating-point elements in `a`, and stores
+/// the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm512_sub_ph(a: __m512h, b: __m512h) -> __m512h {
+    let r: __m512h;
+    asm!("vsubph $2, $1, $0" : "=v"(r) : "v"(a), "v"(b));
+    r
+}
+
+/// Subtracts packed half-precision (16-bit) floating-point elements in `b` from
+/// packed half-precision (16-bit) floating-point elements in `a`, and stores
+/// the results in `dst` using writemask `k` (elements are copied from `src`
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm512_mask_sub_ph(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let r = _mm512_sub_ph(a, b);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Subtracts packed half-precision (16-bit) floating-point elements in `b` from
+/// packed half-precision (16-bit) floating-point elements in `a`, and stores
+/// the results in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm512_maskz_sub_ph(k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let r = _mm512_sub_ph(a, b);
+    let zero = _mm512_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Subtracts packed half-precision (16-bit) floating-point elements in `b` from
+/// packed half-precision (16-bit) floating-point elements in `a`, and stores
+/// the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm256_sub_ph(a: __m256h, b: __m256h) -> __m256h {
+    let r: __m256h;
+    asm!("vsubph $2, $1, $0" : "=v"(r) : "v"(a), "v"(b));
+    r
+}
+
+/// Subtracts packed half-precision (16-bit) floating-point elements in `b` from
+/// packed half-precision (16-bit) floating-point elements in `a`, and stores
+/// the results in `dst` using writemask `k` (elements are copied from `src`
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm256_mask_sub_ph(src: __m256h, k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let r = _mm256_sub_ph(a, b);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Subtracts packed half-precision (16-bit) floating-point elements in `b` from
+/// packed half-precision (16-bit) floating-point elements in `a`, and stores
+/// the results in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm256_maskz_sub_ph(k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let r = _mm256_sub_ph(a, b);
+    let zero = _mm256_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Subtracts packed half-precision (16-bit) floating-point elements in `b` from
+/// packed half-precision (16-bit) floating-point elements in `a`, and stores
+/// the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm_sub_ph(a: __m128h, b: __m128h) -> __m128h {
+    let r: __m128h;
+    asm!("vsubph $2, $1, $0" : "=v"(r) : "v"(a), "v"(b));
+    r
+}
+
+/// Subtracts packed half-precision (16-bit) floating-point elements in `b` from
+/// packed half-precision (16-bit) floating-point elements in `a`, and stores
+/// the results in `dst` using writemask `k` (elements are copied from `src`
+/// when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm_mask_sub_ph(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let r = _mm_sub_ph(a, b);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Subtracts packed half-precision (16-bit) floating-point elements in `b` from
+/// packed half-precision (16-bit) floating-point elements in `a`, and stores
+/// the results in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_sub_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsubph))]
+pub unsafe fn _mm_maskz_sub_ph(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let r = _mm_sub_ph(a, b);
+    let zero = _mm_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Multiplies packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm512_mul_ph(a: __m512h, b: __m512h) -> __m512h {
+    let r: __m512h;
+    asm!("vmulph $2, $1, $0" : "=v"(r) : "v"(a), "v"(b));
+    r
+}
+
+/// Multiplies packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores the results in `dst` using writemask `k` (elements are
+/// copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm512_mask_mul_ph(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let r = _mm512_mul_ph(a, b);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Multiplies packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores the results in `dst` using zeromask `k` (elements are zeroed
+/// out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm512_maskz_mul_ph(k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let r = _mm512_mul_ph(a, b);
+    let zero = _mm512_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Multiplies packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm256_mul_ph(a: __m256h, b: __m256h) -> __m256h {
+    let r: __m256h;
+    asm!("vmulph $2, $1, $0" : "=v"(r) : "v"(a), "v"(b));
+    r
+}
+
+/// Multiplies packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores the results in `dst` using writemask `k` (elements are
+/// copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm256_mask_mul_ph(src: __m256h, k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let r = _mm256_mul_ph(a, b);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Multiplies packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores the results in `dst` using zeromask `k` (elements are zeroed
+/// out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm256_maskz_mul_ph(k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let r = _mm256_mul_ph(a, b);
+    let zero = _mm256_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Multiplies packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm_mul_ph(a: __m128h, b: __m128h) -> __m128h {
+    let r: __m128h;
+    asm!("vmulph $2, $1, $0" : "=v"(r) : "v"(a), "v"(b));
+    r
+}
+
+/// Multiplies packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores the results in `dst` using writemask `k` (elements are
+/// copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm_mask_mul_ph(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let r = _mm_mul_ph(a, b);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Multiplies packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores the results in `dst` using zeromask `k` (elements are zeroed
+/// out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_mul_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmulph))]
+pub unsafe fn _mm_maskz_mul_ph(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let r = _mm_mul_ph(a, b);
+    let zero = _mm_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Divides packed half-precision (16-bit) floating-point elements in `a` by
+/// packed elements in `b`, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm512_div_ph(a: __m512h, b: __m512h) -> __m512h {
+    let r: __m512h;
+    asm!("vdivph $2, $1, $0" : "=v"(r) : "v"(a), "v"(b));
+    r
+}
+
+/// Divides packed half-precision (16-bit) floating-point elements in `a` by
+/// packed elements in `b`, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm512_mask_div_ph(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let r = _mm512_div_ph(a, b);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Divides packed half-precision (16-bit) floating-point elements in `a` by
+/// packed elements in `b`, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm512_maskz_div_ph(k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let r = _mm512_div_ph(a, b);
+    let zero = _mm512_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Divides packed half-precision (16-bit) floating-point elements in `a` by
+/// packed elements in `b`, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm256_div_ph(a: __m256h, b: __m256h) -> __m256h {
+    let r: __m256h;
+    asm!("vdivph $2, $1, $0" : "=v"(r) : "v"(a), "v"(b));
+    r
+}
+
+/// Divides packed half-precision (16-bit) floating-point elements in `a` by
+/// packed elements in `b`, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm256_mask_div_ph(src: __m256h, k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let r = _mm256_div_ph(a, b);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Divides packed half-precision (16-bit) floating-point elements in `a` by
+/// packed elements in `b`, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm256_maskz_div_ph(k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let r = _mm256_div_ph(a, b);
+    let zero = _mm256_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Divides packed half-precision (16-bit) floating-point elements in `a` by
+/// packed elements in `b`, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm_div_ph(a: __m128h, b: __m128h) -> __m128h {
+    let r: __m128h;
+    asm!("vdivph $2, $1, $0" : "=v"(r) : "v"(a), "v"(b));
+    r
+}
+
+/// Divides packed half-precision (16-bit) floating-point elements in `a` by
+/// packed elements in `b`, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm_mask_div_ph(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let r = _mm_div_ph(a, b);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Divides packed half-precision (16-bit) floating-point elements in `a` by
+/// packed elements in `b`, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_div_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vdivph))]
+pub unsafe fn _mm_maskz_div_ph(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let r = _mm_div_ph(a, b);
+    let zero = _mm_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Compares packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores packed maximum values in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_max_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmaxph))]
+pub unsafe fn _mm512_max_ph(a: __m512h, b: __m512h) -> __m512h {
+    let r: __m512h;
+    asm!("vmaxph $2, $1, $0" : "=v"(r) : "v"(a), "v"(b));
+    r
+}
+
+/// Compares packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores packed maximum values in `dst` using writemask `k` (elements
+/// are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_max_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmaxph))]
+pub unsafe fn _mm512_mask_max_ph(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let r = _mm512_max_ph(a, b);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Compares packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores packed maximum values in `dst` using zeromask `k` (elements
+/// are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_max_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vmaxph))]
+pub unsafe fn _mm512_maskz_max_ph(k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let r = _mm512_max_ph(a, b);
+    let zero = _mm512_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Compares packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores packed maximum values in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_max_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmaxph))]
+pub unsafe fn _mm256_max_ph(a: __m256h, b: __m256h) -> __m256h {
+    let r: __m256h;
+    asm!("vmaxph $2, $1, $0" : "=v"(r) : "v"(a), "v"(b));
+    r
+}
+
+/// Compares packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores packed maximum values in `dst` using writemask `k` (elements
+/// are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_max_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmaxph))]
+pub unsafe fn _mm256_mask_max_ph(src: __m256h, k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let r = _mm256_max_ph(a, b);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Compares packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores packed maximum values in `dst` using zeromask `k` (elements
+/// are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_max_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmaxph))]
+pub unsafe fn _mm256_maskz_max_ph(k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let r = _mm256_max_ph(a, b);
+    let zero = _mm256_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Compares packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores packed maximum values in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_max_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmaxph))]
+pub unsafe fn _mm_max_ph(a: __m128h, b: __m128h) -> __m128h {
+    let r: __m128h;
+    asm!("vmaxph $2, $1, $0" : "=v"(r) : "v"(a), "v"(b));
+    r
+}
+
+/// Compares packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores packed maximum values in `dst` using writemask `k` (elements
+/// are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_max_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmaxph))]
+pub unsafe fn _mm_mask_max_ph(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let r = _mm_max_ph(a, b);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Compares packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores packed maximum values in `dst` using zeromask `k` (elements
+/// are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_max_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vmaxph))]
+pub unsafe fn _mm_maskz_max_ph(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let r = _mm_max_ph(a, b);
+    let zero = _mm_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Compares packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores packed minimum values in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_min_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vminph))]
+pub unsafe fn _mm512_min_ph(a: __m512h, b: __m512h) -> __m512h {
+    let r: __m512h;
+    asm!("vminph $2, $1, $0" : "=v"(r) : "v"(a), "v"(b));
+    r
+}
+
+/// Compares packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores packed minimum values in `dst` using writemask `k` (elements
+/// are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_min_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vminph))]
+pub unsafe fn _mm512_mask_min_ph(src: __m512h, k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let r = _mm512_min_ph(a, b);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Compares packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores packed minimum values in `dst` using zeromask `k` (elements
+/// are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_min_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vminph))]
+pub unsafe fn _mm512_maskz_min_ph(k: __mmask32, a: __m512h, b: __m512h) -> __m512h {
+    let r = _mm512_min_ph(a, b);
+    let zero = _mm512_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Compares packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores packed minimum values in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_min_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vminph))]
+pub unsafe fn _mm256_min_ph(a: __m256h, b: __m256h) -> __m256h {
+    let r: __m256h;
+    asm!("vminph $2, $1, $0" : "=v"(r) : "v"(a), "v"(b));
+    r
+}
+
+/// Compares packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores packed minimum values in `dst` using writemask `k` (elements
+/// are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_min_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vminph))]
+pub unsafe fn _mm256_mask_min_ph(src: __m256h, k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let r = _mm256_min_ph(a, b);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Compares packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores packed minimum values in `dst` using zeromask `k` (elements
+/// are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_min_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vminph))]
+pub unsafe fn _mm256_maskz_min_ph(k: __mmask16, a: __m256h, b: __m256h) -> __m256h {
+    let r = _mm256_min_ph(a, b);
+    let zero = _mm256_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Compares packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores packed minimum values in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_min_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vminph))]
+pub unsafe fn _mm_min_ph(a: __m128h, b: __m128h) -> __m128h {
+    let r: __m128h;
+    asm!("vminph $2, $1, $0" : "=v"(r) : "v"(a), "v"(b));
+    r
+}
+
+/// Compares packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores packed minimum values in `dst` using writemask `k` (elements
+/// are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_min_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vminph))]
+pub unsafe fn _mm_mask_min_ph(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let r = _mm_min_ph(a, b);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Compares packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, and stores packed minimum values in `dst` using zeromask `k` (elements
+/// are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_min_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vminph))]
+pub unsafe fn _mm_maskz_min_ph(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let r = _mm_min_ph(a, b);
+    let zero = _mm_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Computes the square root of packed half-precision (16-bit) floating-point
+/// elements in `a`, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_sqrt_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vsqrtph))]
+pub unsafe fn _mm512_sqrt_ph(a: __m512h) -> __m512h {
+    let r: __m512h;
+    asm!("vsqrtph $1, $0" : "=v"(r) : "v"(a));
+    r
+}
+
+/// Computes the square root of packed half-precision (16-bit) floating-point
+/// elements in `a`, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_sqrt_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vsqrtph))]
+pub unsafe fn _mm512_mask_sqrt_ph(src: __m512h, k: __mmask32, a: __m512h) -> __m512h {
+    let r = _mm512_sqrt_ph(a);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Computes the square root of packed half-precision (16-bit) floating-point
+/// elements in `a`, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_sqrt_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vsqrtph))]
+pub unsafe fn _mm512_maskz_sqrt_ph(k: __mmask32, a: __m512h) -> __m512h {
+    let r = _mm512_sqrt_ph(a);
+    let zero = _mm512_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Computes the square root of packed half-precision (16-bit) floating-point
+/// elements in `a`, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_sqrt_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsqrtph))]
+pub unsafe fn _mm256_sqrt_ph(a: __m256h) -> __m256h {
+    let r: __m256h;
+    asm!("vsqrtph $1, $0" : "=v"(r) : "v"(a));
+    r
+}
+
+/// Computes the square root of packed half-precision (16-bit) floating-point
+/// elements in `a`, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_sqrt_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsqrtph))]
+pub unsafe fn _mm256_mask_sqrt_ph(src: __m256h, k: __mmask16, a: __m256h) -> __m256h {
+    let r = _mm256_sqrt_ph(a);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Computes the square root of packed half-precision (16-bit) floating-point
+/// elements in `a`, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_sqrt_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsqrtph))]
+pub unsafe fn _mm256_maskz_sqrt_ph(k: __mmask16, a: __m256h) -> __m256h {
+    let r = _mm256_sqrt_ph(a);
+    let zero = _mm256_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Computes the square root of packed half-precision (16-bit) floating-point
+/// elements in `a`, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_sqrt_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsqrtph))]
+pub unsafe fn _mm_sqrt_ph(a: __m128h) -> __m128h {
+    let r: __m128h;
+    asm!("vsqrtph $1, $0" : "=v"(r) : "v"(a));
+    r
+}
+
+/// Computes the square root of packed half-precision (16-bit) floating-point
+/// elements in `a`, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_sqrt_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsqrtph))]
+pub unsafe fn _mm_mask_sqrt_ph(src: __m128h, k: __mmask8, a: __m128h) -> __m128h {
+    let r = _mm_sqrt_ph(a);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Computes the square root of packed half-precision (16-bit) floating-point
+/// elements in `a`, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_sqrt_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vsqrtph))]
+pub unsafe fn _mm_maskz_sqrt_ph(k: __mmask8, a: __m128h) -> __m128h {
+    let r = _mm_sqrt_ph(a);
+    let zero = _mm_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Multiplies packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, adds the intermediate result to packed elements in `c`, and stores the
+/// results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmadd))]
+pub unsafe fn _mm512_fmadd_ph(a: __m512h, b: __m512h, c: __m512h) -> __m512h {
+    let r: __m512h;
+    asm!("vfmadd213ph $3, $2, $0" : "=v"(r) : "0"(a), "v"(b), "v"(c));
+    r
+}
+
+/// Multiplies packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, adds the intermediate result to packed elements in `c`, and stores the
+/// results in `dst` using writemask `k` (elements are copied from `a` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmadd))]
+pub unsafe fn _mm512_mask_fmadd_ph(a: __m512h, k: __mmask32, b: __m512h, c: __m512h) -> __m512h {
+    let r = _mm512_fmadd_ph(a, b, c);
+    simd_select_bitmask(k, r, a)
+}
+
+/// Multiplies packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, adds the intermediate result to packed elements in `c`, and stores the
+/// results in `dst` using writemask `k` (elements are copied from `c` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask3_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmadd))]
+pub unsafe fn _mm512_mask3_fmadd_ph(a: __m512h, b: __m512h, c: __m512h, k: __mmask32) -> __m512h {
+    let r = _mm512_fmadd_ph(a, b, c);
+    simd_select_bitmask(k, r, c)
+}
+
+/// Multiplies packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, adds the intermediate result to packed elements in `c`, and stores the
+/// results in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmadd))]
+pub unsafe fn _mm512_maskz_fmadd_ph(k: __mmask32, a: __m512h, b: __m512h, c: __m512h) -> __m512h {
+    let r = _mm512_fmadd_ph(a, b, c);
+    let zero = _mm512_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Multiplies packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, adds the intermediate result to packed elements in `c`, and stores the
+/// results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmadd))]
+pub unsafe fn _mm256_fmadd_ph(a: __m256h, b: __m256h, c: __m256h) -> __m256h {
+    let r: __m256h;
+    asm!("vfmadd213ph $3, $2, $0" : "=v"(r) : "0"(a), "v"(b), "v"(c));
+    r
+}
+
+/// Multiplies packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, adds the intermediate result to packed elements in `c`, and stores the
+/// results in `dst` using writemask `k` (elements are copied from `a` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmadd))]
+pub unsafe fn _mm256_mask_fmadd_ph(a: __m256h, k: __mmask16, b: __m256h, c: __m256h) -> __m256h {
+    let r = _mm256_fmadd_ph(a, b, c);
+    simd_select_bitmask(k, r, a)
+}
+
+/// Multiplies packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, adds the intermediate result to packed elements in `c`, and stores the
+/// results in `dst` using writemask `k` (elements are copied from `c` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask3_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmadd))]
+pub unsafe fn _mm256_mask3_fmadd_ph(a: __m256h, b: __m256h, c: __m256h, k: __mmask16) -> __m256h {
+    let r = _mm256_fmadd_ph(a, b, c);
+    simd_select_bitmask(k, r, c)
+}
+
+/// Multiplies packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, adds the intermediate result to packed elements in `c`, and stores the
+/// results in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmadd))]
+pub unsafe fn _mm256_maskz_fmadd_ph(k: __mmask16, a: __m256h, b: __m256h, c: __m256h) -> __m256h {
+    let r = _mm256_fmadd_ph(a, b, c);
+    let zero = _mm256_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Multiplies packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, adds the intermediate result to packed elements in `c`, and stores the
+/// results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmadd))]
+pub unsafe fn _mm_fmadd_ph(a: __m128h, b: __m128h, c: __m128h) -> __m128h {
+    let r: __m128h;
+    asm!("vfmadd213ph $3, $2, $0" : "=v"(r) : "0"(a), "v"(b), "v"(c));
+    r
+}
+
+/// Multiplies packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, adds the intermediate result to packed elements in `c`, and stores the
+/// results in `dst` using writemask `k` (elements are copied from `a` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmadd))]
+pub unsafe fn _mm_mask_fmadd_ph(a: __m128h, k: __mmask8, b: __m128h, c: __m128h) -> __m128h {
+    let r = _mm_fmadd_ph(a, b, c);
+    simd_select_bitmask(k, r, a)
+}
+
+/// Multiplies packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, adds the intermediate result to packed elements in `c`, and stores the
+/// results in `dst` using writemask `k` (elements are copied from `c` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask3_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmadd))]
+pub unsafe fn _mm_mask3_fmadd_ph(a: __m128h, b: __m128h, c: __m128h, k: __mmask8) -> __m128h {
+    let r = _mm_fmadd_ph(a, b, c);
+    simd_select_bitmask(k, r, c)
+}
+
+/// Multiplies packed half-precision (16-bit) floating-point elements in `a` and
+/// `b`, adds the intermediate result to packed elements in `c`, and stores the
+/// results in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_fmadd_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmadd))]
+pub unsafe fn _mm_maskz_fmadd_ph(k: __mmask8, a: __m128h, b: __m128h, c: __m128h) -> __m128h {
+    let r = _mm_fmadd_ph(a, b, c);
+    let zero = _mm_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Compares packed half-precision (16-bit) floating-point elements in `a` and
+/// `b` based on the comparison operand specified by `imm8`, and stores the
+/// results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cmp_ph_mask)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcmp, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_cmp_ph_mask(a: __m512h, b: __m512h, imm8: i32) -> __mmask32 {
+    let r: __mmask32;
+    macro_rules! call {
+        ($imm5:expr) => {
+            asm!(concat!("vcmpph $$", stringify!($imm5), ", $2, $1, $0")
+                 : "=k"(r)
+                 : "v"(a), "v"(b))
+        };
+    }
+    constify_imm6!(imm8, call);
+    r
+}
+
+/// Compares packed half-precision (16-bit) floating-point elements in `a` and
+/// `b` based on the comparison operand specified by `imm8`, and stores the
+/// results in mask vector `k` using zeromask `k1` (elements are zeroed out when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cmp_ph_mask)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcmp, imm8 = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm512_mask_cmp_ph_mask(
+    k1: __mmask32,
+    a: __m512h,
+    b: __m512h,
+    imm8: i32,
+) -> __mmask32 {
+    let r: __mmask32;
+    macro_rules! call {
+        ($imm5:expr) => {
+            asm!(concat!("vcmpph $$", stringify!($imm5), ", $2, $1, $0")
+                 : "=k"(r)
+                 : "v"(a), "v"(b))
+        };
+    }
+    constify_imm6!(imm8, call);
+    r & k1
+}
+
+/// Compares packed half-precision (16-bit) floating-point elements in `a` and
+/// `b` based on the comparison operand specified by `imm8`, and stores the
+/// results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cmp_ph_mask)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcmp, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm256_cmp_ph_mask(a: __m256h, b: __m256h, imm8: i32) -> __mmask16 {
+    let r: __mmask16;
+    macro_rules! call {
+        ($imm5:expr) => {
+            asm!(concat!("vcmpph $$", stringify!($imm5), ", $2, $1, $0")
+                 : "=k"(r)
+                 : "v"(a), "v"(b))
+        };
+    }
+    constify_imm6!(imm8, call);
+    r
+}
+
+/// Compares packed half-precision (16-bit) floating-point elements in `a` and
+/// `b` based on the comparison operand specified by `imm8`, and stores the
+/// results in mask vector `k` using zeromask `k1` (elements are zeroed out when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cmp_ph_mask)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcmp, imm8 = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm256_mask_cmp_ph_mask(
+    k1: __mmask16,
+    a: __m256h,
+    b: __m256h,
+    imm8: i32,
+) -> __mmask16 {
+    let r: __mmask16;
+    macro_rules! call {
+        ($imm5:expr) => {
+            asm!(concat!("vcmpph $$", stringify!($imm5), ", $2, $1, $0")
+                 : "=k"(r)
+                 : "v"(a), "v"(b))
+        };
+    }
+    constify_imm6!(imm8, call);
+    r & k1
+}
+
+/// Compares packed half-precision (16-bit) floating-point elements in `a` and
+/// `b` based on the comparison operand specified by `imm8`, and stores the
+/// results in mask vector `k`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cmp_ph_mask)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcmp, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_cmp_ph_mask(a: __m128h, b: __m128h, imm8: i32) -> __mmask8 {
+    let r: __mmask8;
+    macro_rules! call {
+        ($imm5:expr) => {
+            asm!(concat!("vcmpph $$", stringify!($imm5), ", $2, $1, $0")
+                 : "=k"(r)
+                 : "v"(a), "v"(b))
+        };
+    }
+    constify_imm6!(imm8, call);
+    r
+}
+
+/// Compares packed half-precision (16-bit) floating-point elements in `a` and
+/// `b` based on the comparison operand specified by `imm8`, and stores the
+/// results in mask vector `k` using zeromask `k1` (elements are zeroed out when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cmp_ph_mask)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcmp, imm8 = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm_mask_cmp_ph_mask(k1: __mmask8, a: __m128h, b: __m128h, imm8: i32) -> __mmask8 {
+    let r: __mmask8;
+    macro_rules! call {
+        ($imm5:expr) => {
+            asm!(concat!("vcmpph $$", stringify!($imm5), ", $2, $1, $0")
+                 : "=k"(r)
+                 : "v"(a), "v"(b))
+        };
+    }
+    constify_imm6!(imm8, call);
+    r & k1
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed single-precision (32-bit) floating-point elements, and stores the
+/// results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtxph_ps)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtph2psx))]
+pub unsafe fn _mm512_cvtxph_ps(a: __m256h) -> __m512 {
+    let r: __m512;
+    asm!("vcvtph2psx $1, $0" : "=v"(r) : "v"(a));
+    r
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed single-precision (32-bit) floating-point elements, and stores the
+/// results in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtxph_ps)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtph2psx))]
+pub unsafe fn _mm512_mask_cvtxph_ps(src: __m512, k: __mmask16, a: __m256h) -> __m512 {
+    let r = _mm512_cvtxph_ps(a).as_f32x16();
+    transmute(simd_select_bitmask(k, r, src.as_f32x16()))
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed single-precision (32-bit) floating-point elements, and stores the
+/// results in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtxph_ps)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtph2psx))]
+pub unsafe fn _mm512_maskz_cvtxph_ps(k: __mmask16, a: __m256h) -> __m512 {
+    let r = _mm512_cvtxph_ps(a).as_f32x16();
+    let zero = _mm512_setzero_ps().as_f32x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed single-precision (32-bit) floating-point elements, and stores the
+/// results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtxph_ps)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2psx))]
+pub unsafe fn _mm256_cvtxph_ps(a: __m128h) -> __m256 {
+    let r: __m256;
+    asm!("vcvtph2psx $1, $0" : "=v"(r) : "v"(a));
+    r
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed single-precision (32-bit) floating-point elements, and stores the
+/// results in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cvtxph_ps)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2psx))]
+pub unsafe fn _mm256_mask_cvtxph_ps(src: __m256, k: __mmask8, a: __m128h) -> __m256 {
+    let r = _mm256_cvtxph_ps(a).as_f32x8();
+    transmute(simd_select_bitmask(k, r, src.as_f32x8()))
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed single-precision (32-bit) floating-point elements, and stores the
+/// results in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_cvtxph_ps)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2psx))]
+pub unsafe fn _mm256_maskz_cvtxph_ps(k: __mmask8, a: __m128h) -> __m256 {
+    let r = _mm256_cvtxph_ps(a).as_f32x8();
+    let zero = _mm256_setzero_ps().as_f32x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed single-precision (32-bit) floating-point elements, and stores the
+/// results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtxph_ps)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2psx))]
+pub unsafe fn _mm_cvtxph_ps(a: __m128h) -> __m128 {
+    let r: __m128;
+    asm!("vcvtph2psx $1, $0" : "=v"(r) : "v"(a));
+    r
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed single-precision (32-bit) floating-point elements, and stores the
+/// results in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cvtxph_ps)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2psx))]
+pub unsafe fn _mm_mask_cvtxph_ps(src: __m128, k: __mmask8, a: __m128h) -> __m128 {
+    let r = _mm_cvtxph_ps(a).as_f32x4();
+    transmute(simd_select_bitmask(k, r, src.as_f32x4()))
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed single-precision (32-bit) floating-point elements, and stores the
+/// results in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_cvtxph_ps)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2psx))]
+pub unsafe fn _mm_maskz_cvtxph_ps(k: __mmask8, a: __m128h) -> __m128 {
+    let r = _mm_cvtxph_ps(a).as_f32x4();
+    let zero = _mm_setzero_ps().as_f32x4();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed half-precision (16-bit) floating-point elements, and stores the
+/// results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtxps_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtps2phx))]
+pub unsafe fn _mm512_cvtxps_ph(a: __m512) -> __m256h {
+    let r: __m256h;
+    asm!("vcvtps2phx $1, $0" : "=v"(r) : "v"(a));
+    r
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed half-precision (16-bit) floating-point elements, and stores the
+/// results in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtxps_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtps2phx))]
+pub unsafe fn _mm512_mask_cvtxps_ph(src: __m256h, k: __mmask16, a: __m512) -> __m256h {
+    let r = _mm512_cvtxps_ph(a);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed half-precision (16-bit) floating-point elements, and stores the
+/// results in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtxps_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtps2phx))]
+pub unsafe fn _mm512_maskz_cvtxps_ph(k: __mmask16, a: __m512) -> __m256h {
+    let r = _mm512_cvtxps_ph(a);
+    let zero = _mm256_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed half-precision (16-bit) floating-point elements, and stores the
+/// results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtxps_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2phx))]
+pub unsafe fn _mm256_cvtxps_ph(a: __m256) -> __m128h {
+    let r: __m128h;
+    asm!("vcvtps2phx $1, $0" : "=v"(r) : "v"(a));
+    r
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed half-precision (16-bit) floating-point elements, and stores the
+/// results in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cvtxps_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2phx))]
+pub unsafe fn _mm256_mask_cvtxps_ph(src: __m128h, k: __mmask8, a: __m256) -> __m128h {
+    let r = _mm256_cvtxps_ph(a);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed half-precision (16-bit) floating-point elements, and stores the
+/// results in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_cvtxps_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2phx))]
+pub unsafe fn _mm256_maskz_cvtxps_ph(k: __mmask8, a: __m256) -> __m128h {
+    let r = _mm256_cvtxps_ph(a);
+    let zero = _mm_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed half-precision (16-bit) floating-point elements, and stores the
+/// results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtxps_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2phx))]
+pub unsafe fn _mm_cvtxps_ph(a: __m128) -> __m128h {
+    let r: __m128h;
+    asm!("vcvtps2phx $1, $0" : "=v"(r) : "v"(a));
+    r
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed half-precision (16-bit) floating-point elements, and stores the
+/// results in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cvtxps_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2phx))]
+pub unsafe fn _mm_mask_cvtxps_ph(src: __m128h, k: __mmask8, a: __m128) -> __m128h {
+    let r = _mm_cvtxps_ph(a);
+    // The upper four elements of `dst` are zeroed whatever the mask.
+    simd_select_bitmask(k | 0b11110000, r, src)
+}
+
+/// Converts packed single-precision (32-bit) floating-point elements in `a` to
+/// packed half-precision (16-bit) floating-point elements, and stores the
+/// results in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_cvtxps_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2phx))]
+pub unsafe fn _mm_maskz_cvtxps_ph(k: __mmask8, a: __m128) -> __m128h {
+    let r = _mm_cvtxps_ph(a);
+    let zero = _mm_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 16-bit integers, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtph2w))]
+pub unsafe fn _mm512_cvtph_epi16(a: __m512h) -> __m512i {
+    let r: __m512i;
+    asm!("vcvtph2w $1, $0" : "=v"(r) : "v"(a));
+    r
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 16-bit integers, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtph2w))]
+pub unsafe fn _mm512_mask_cvtph_epi16(src: __m512i, k: __mmask32, a: __m512h) -> __m512i {
+    let r = _mm512_cvtph_epi16(a).as_i16x32();
+    transmute(simd_select_bitmask(k, r, src.as_i16x32()))
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 16-bit integers, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtph2w))]
+pub unsafe fn _mm512_maskz_cvtph_epi16(k: __mmask32, a: __m512h) -> __m512i {
+    let r = _mm512_cvtph_epi16(a).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 16-bit integers, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2w))]
+pub unsafe fn _mm256_cvtph_epi16(a: __m256h) -> __m256i {
+    let r: __m256i;
+    asm!("vcvtph2w $1, $0" : "=v"(r) : "v"(a));
+    r
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 16-bit integers, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cvtph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2w))]
+pub unsafe fn _mm256_mask_cvtph_epi16(src: __m256i, k: __mmask16, a: __m256h) -> __m256i {
+    let r = _mm256_cvtph_epi16(a).as_i16x16();
+    transmute(simd_select_bitmask(k, r, src.as_i16x16()))
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 16-bit integers, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_cvtph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2w))]
+pub unsafe fn _mm256_maskz_cvtph_epi16(k: __mmask16, a: __m256h) -> __m256i {
+    let r = _mm256_cvtph_epi16(a).as_i16x16();
+    let zero = _mm256_setzero_si256().as_i16x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 16-bit integers, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2w))]
+pub unsafe fn _mm_cvtph_epi16(a: __m128h) -> __m128i {
+    let r: __m128i;
+    asm!("vcvtph2w $1, $0" : "=v"(r) : "v"(a));
+    r
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 16-bit integers, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cvtph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2w))]
+pub unsafe fn _mm_mask_cvtph_epi16(src: __m128i, k: __mmask8, a: __m128h) -> __m128i {
+    let r = _mm_cvtph_epi16(a).as_i16x8();
+    transmute(simd_select_bitmask(k, r, src.as_i16x8()))
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 16-bit integers, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_cvtph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2w))]
+pub unsafe fn _mm_maskz_cvtph_epi16(k: __mmask8, a: __m128h) -> __m128i {
+    let r = _mm_cvtph_epi16(a).as_i16x8();
+    let zero = _mm_setzero_si128().as_i16x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 16-bit integers with truncation, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvttph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvttph2w))]
+pub unsafe fn _mm512_cvttph_epi16(a: __m512h) -> __m512i {
+    let r: __m512i;
+    asm!("vcvttph2w $1, $0" : "=v"(r) : "v"(a));
+    r
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 16-bit integers with truncation, and stores the results in `dst`
+/// using writemask `k` (elements are copied from `src` when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvttph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvttph2w))]
+pub unsafe fn _mm512_mask_cvttph_epi16(src: __m512i, k: __mmask32, a: __m512h) -> __m512i {
+    let r = _mm512_cvttph_epi16(a).as_i16x32();
+    transmute(simd_select_bitmask(k, r, src.as_i16x32()))
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 16-bit integers with truncation, and stores the results in `dst`
+/// using zeromask `k` (elements are zeroed out when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvttph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvttph2w))]
+pub unsafe fn _mm512_maskz_cvttph_epi16(k: __mmask32, a: __m512h) -> __m512i {
+    let r = _mm512_cvttph_epi16(a).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 16-bit integers with truncation, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvttph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttph2w))]
+pub unsafe fn _mm256_cvttph_epi16(a: __m256h) -> __m256i {
+    let r: __m256i;
+    asm!("vcvttph2w $1, $0" : "=v"(r) : "v"(a));
+    r
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 16-bit integers with truncation, and stores the results in `dst`
+/// using writemask `k` (elements are copied from `src` when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cvttph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttph2w))]
+pub unsafe fn _mm256_mask_cvttph_epi16(src: __m256i, k: __mmask16, a: __m256h) -> __m256i {
+    let r = _mm256_cvttph_epi16(a).as_i16x16();
+    transmute(simd_select_bitmask(k, r, src.as_i16x16()))
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 16-bit integers with truncation, and stores the results in `dst`
+/// using zeromask `k` (elements are zeroed out when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_cvttph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttph2w))]
+pub unsafe fn _mm256_maskz_cvttph_epi16(k: __mmask16, a: __m256h) -> __m256i {
+    let r = _mm256_cvttph_epi16(a).as_i16x16();
+    let zero = _mm256_setzero_si256().as_i16x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 16-bit integers with truncation, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvttph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttph2w))]
+pub unsafe fn _mm_cvttph_epi16(a: __m128h) -> __m128i {
+    let r: __m128i;
+    asm!("vcvttph2w $1, $0" : "=v"(r) : "v"(a));
+    r
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 16-bit integers with truncation, and stores the results in `dst`
+/// using writemask `k` (elements are copied from `src` when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cvttph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttph2w))]
+pub unsafe fn _mm_mask_cvttph_epi16(src: __m128i, k: __mmask8, a: __m128h) -> __m128i {
+    let r = _mm_cvttph_epi16(a).as_i16x8();
+    transmute(simd_select_bitmask(k, r, src.as_i16x8()))
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 16-bit integers with truncation, and stores the results in `dst`
+/// using zeromask `k` (elements are zeroed out when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_cvttph_epi16)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttph2w))]
+pub unsafe fn _mm_maskz_cvttph_epi16(k: __mmask8, a: __m128h) -> __m128i {
+    let r = _mm_cvttph_epi16(a).as_i16x8();
+    let zero = _mm_setzero_si128().as_i16x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 32-bit integers, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtph_epi32)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtph2dq))]
+pub unsafe fn _mm512_cvtph_epi32(a: __m256h) -> __m512i {
+    let r: __m512i;
+    asm!("vcvtph2dq $1, $0" : "=v"(r) : "v"(a));
+    r
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 32-bit integers, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtph_epi32)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtph2dq))]
+pub unsafe fn _mm512_mask_cvtph_epi32(src: __m512i, k: __mmask16, a: __m256h) -> __m512i {
+    let r = _mm512_cvtph_epi32(a).as_i32x16();
+    transmute(simd_select_bitmask(k, r, src.as_i32x16()))
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 32-bit integers, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtph_epi32)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtph2dq))]
+pub unsafe fn _mm512_maskz_cvtph_epi32(k: __mmask16, a: __m256h) -> __m512i {
+    let r = _mm512_cvtph_epi32(a).as_i32x16();
+    let zero = _mm512_setzero_si512().as_i32x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 32-bit integers, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtph_epi32)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2dq))]
+pub unsafe fn _mm256_cvtph_epi32(a: __m128h) -> __m256i {
+    let r: __m256i;
+    asm!("vcvtph2dq $1, $0" : "=v"(r) : "v"(a));
+    r
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 32-bit integers, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cvtph_epi32)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2dq))]
+pub unsafe fn _mm256_mask_cvtph_epi32(src: __m256i, k: __mmask8, a: __m128h) -> __m256i {
+    let r = _mm256_cvtph_epi32(a).as_i32x8();
+    transmute(simd_select_bitmask(k, r, src.as_i32x8()))
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 32-bit integers, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_cvtph_epi32)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2dq))]
+pub unsafe fn _mm256_maskz_cvtph_epi32(k: __mmask8, a: __m128h) -> __m256i {
+    let r = _mm256_cvtph_epi32(a).as_i32x8();
+    let zero = _mm256_setzero_si256().as_i32x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 32-bit integers, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtph_epi32)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2dq))]
+pub unsafe fn _mm_cvtph_epi32(a: __m128h) -> __m128i {
+    let r: __m128i;
+    asm!("vcvtph2dq $1, $0" : "=v"(r) : "v"(a));
+    r
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 32-bit integers, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cvtph_epi32)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2dq))]
+pub unsafe fn _mm_mask_cvtph_epi32(src: __m128i, k: __mmask8, a: __m128h) -> __m128i {
+    let r = _mm_cvtph_epi32(a).as_i32x4();
+    transmute(simd_select_bitmask(k, r, src.as_i32x4()))
+}
+
+/// Converts packed half-precision (16-bit) floating-point elements in `a` to
+/// packed 32-bit integers, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_cvtph_epi32)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtph2dq))]
+pub unsafe fn _mm_maskz_cvtph_epi32(k: __mmask8, a: __m128h) -> __m128i {
+    let r = _mm_cvtph_epi32(a).as_i32x4();
+    let zero = _mm_setzero_si128().as_i32x4();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Converts packed signed 16-bit integers in `a` to packed half-precision
+/// (16-bit) floating-point elements, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtepi16_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtw2ph))]
+pub unsafe fn _mm512_cvtepi16_ph(a: __m512i) -> __m512h {
+    let r: __m512h;
+    asm!("vcvtw2ph $1, $0" : "=v"(r) : "v"(a));
+    r
+}
+
+/// Converts packed signed 16-bit integers in `a` to packed half-precision
+/// (16-bit) floating-point elements, and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtepi16_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtw2ph))]
+pub unsafe fn _mm512_mask_cvtepi16_ph(src: __m512h, k: __mmask32, a: __m512i) -> __m512h {
+    let r = _mm512_cvtepi16_ph(a);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Converts packed signed 16-bit integers in `a` to packed half-precision
+/// (16-bit) floating-point elements, and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtepi16_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtw2ph))]
+pub unsafe fn _mm512_maskz_cvtepi16_ph(k: __mmask32, a: __m512i) -> __m512h {
+    let r = _mm512_cvtepi16_ph(a);
+    let zero = _mm512_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Converts packed signed 16-bit integers in `a` to packed half-precision
+/// (16-bit) floating-point elements, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtepi16_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtw2ph))]
+pub unsafe fn _mm256_cvtepi16_ph(a: __m256i) -> __m256h {
+    let r: __m256h;
+    asm!("vcvtw2ph $1, $0" : "=v"(r) : "v"(a));
+    r
+}
+
+/// Converts packed signed 16-bit integers in `a` to packed half-precision
+/// (16-bit) floating-point elements, and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cvtepi16_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtw2ph))]
+pub unsafe fn _mm256_mask_cvtepi16_ph(src: __m256h, k: __mmask16, a: __m256i) -> __m256h {
+    let r = _mm256_cvtepi16_ph(a);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Converts packed signed 16-bit integers in `a` to packed half-precision
+/// (16-bit) floating-point elements, and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_cvtepi16_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtw2ph))]
+pub unsafe fn _mm256_maskz_cvtepi16_ph(k: __mmask16, a: __m256i) -> __m256h {
+    let r = _mm256_cvtepi16_ph(a);
+    let zero = _mm256_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Converts packed signed 16-bit integers in `a` to packed half-precision
+/// (16-bit) floating-point elements, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtepi16_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtw2ph))]
+pub unsafe fn _mm_cvtepi16_ph(a: __m128i) -> __m128h {
+    let r: __m128h;
+    asm!("vcvtw2ph $1, $0" : "=v"(r) : "v"(a));
+    r
+}
+
+/// Converts packed signed 16-bit integers in `a` to packed half-precision
+/// (16-bit) floating-point elements, and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cvtepi16_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtw2ph))]
+pub unsafe fn _mm_mask_cvtepi16_ph(src: __m128h, k: __mmask8, a: __m128i) -> __m128h {
+    let r = _mm_cvtepi16_ph(a);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Converts packed signed 16-bit integers in `a` to packed half-precision
+/// (16-bit) floating-point elements, and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_cvtepi16_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtw2ph))]
+pub unsafe fn _mm_maskz_cvtepi16_ph(k: __mmask8, a: __m128i) -> __m128h {
+    let r = _mm_cvtepi16_ph(a);
+    let zero = _mm_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Converts packed signed 32-bit integers in `a` to packed half-precision
+/// (16-bit) floating-point elements, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_cvtepi32_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtdq2ph))]
+pub unsafe fn _mm512_cvtepi32_ph(a: __m512i) -> __m256h {
+    let r: __m256h;
+    asm!("vcvtdq2ph $1, $0" : "=v"(r) : "v"(a));
+    r
+}
+
+/// Converts packed signed 32-bit integers in `a` to packed half-precision
+/// (16-bit) floating-point elements, and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_cvtepi32_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtdq2ph))]
+pub unsafe fn _mm512_mask_cvtepi32_ph(src: __m256h, k: __mmask16, a: __m512i) -> __m256h {
+    let r = _mm512_cvtepi32_ph(a);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Converts packed signed 32-bit integers in `a` to packed half-precision
+/// (16-bit) floating-point elements, and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_cvtepi32_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vcvtdq2ph))]
+pub unsafe fn _mm512_maskz_cvtepi32_ph(k: __mmask16, a: __m512i) -> __m256h {
+    let r = _mm512_cvtepi32_ph(a);
+    let zero = _mm256_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Converts packed signed 32-bit integers in `a` to packed half-precision
+/// (16-bit) floating-point elements, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtepi32_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtdq2ph))]
+pub unsafe fn _mm256_cvtepi32_ph(a: __m256i) -> __m128h {
+    let r: __m128h;
+    asm!("vcvtdq2ph $1, $0" : "=v"(r) : "v"(a));
+    r
+}
+
+/// Converts packed signed 32-bit integers in `a` to packed half-precision
+/// (16-bit) floating-point elements, and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_cvtepi32_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtdq2ph))]
+pub unsafe fn _mm256_mask_cvtepi32_ph(src: __m128h, k: __mmask8, a: __m256i) -> __m128h {
+    let r = _mm256_cvtepi32_ph(a);
+    simd_select_bitmask(k, r, src)
+}
+
+/// Converts packed signed 32-bit integers in `a` to packed half-precision
+/// (16-bit) floating-point elements, and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_cvtepi32_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtdq2ph))]
+pub unsafe fn _mm256_maskz_cvtepi32_ph(k: __mmask8, a: __m256i) -> __m128h {
+    let r = _mm256_cvtepi32_ph(a);
+    let zero = _mm_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Converts packed signed 32-bit integers in `a` to packed half-precision
+/// (16-bit) floating-point elements, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtepi32_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtdq2ph))]
+pub unsafe fn _mm_cvtepi32_ph(a: __m128i) -> __m128h {
+    let r: __m128h;
+    asm!("vcvtdq2ph $1, $0" : "=v"(r) : "v"(a));
+    r
+}
+
+/// Converts packed signed 32-bit integers in `a` to packed half-precision
+/// (16-bit) floating-point elements, and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_cvtepi32_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtdq2ph))]
+pub unsafe fn _mm_mask_cvtepi32_ph(src: __m128h, k: __mmask8, a: __m128i) -> __m128h {
+    let r = _mm_cvtepi32_ph(a);
+    // The upper four elements of `dst` are zeroed whatever the mask.
+    simd_select_bitmask(k | 0b11110000, r, src)
+}
+
+/// Converts packed signed 32-bit integers in `a` to packed half-precision
+/// (16-bit) floating-point elements, and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_cvtepi32_ph)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtdq2ph))]
+pub unsafe fn _mm_maskz_cvtepi32_ph(k: __mmask8, a: __m128i) -> __m128h {
+    let r = _mm_cvtepi32_ph(a);
+    let zero = _mm_setzero_ph();
+    simd_select_bitmask(k, r, zero)
+}
+
+/// Multiplies packed complex numbers in `a` and `b`, and stores the results in
+/// `dst`. Each complex number is composed of two adjacent half-precision
+/// (16-bit) floating-point elements, which defines the complex number `complex
+/// = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_fmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm512_fmul_pch(a: __m512h, b: __m512h) -> __m512h {
+    // The destination must not overlap either source.
+    let r: __m512h;
+    asm!("vfmulcph $2, $1, $0" : "=&v"(r) : "v"(a), "v"(b));
+    r
+}
+
+/// Multiplies packed complex numbers in `a` and `b`, and stores the results in
+/// `dst` using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set). Each complex number is composed of two
+/// adjacent half-precision (16-bit) floating-point elements, which defines the
+/// complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_fmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm512_mask_fmul_pch(src: __m512h, k: __mmask16, a: __m512h, b: __m512h) -> __m512h {
+    let r: u32x16 = transmute(_mm512_fmul_pch(a, b));
+    transmute(simd_select_bitmask(k, r, transmute::<_, u32x16>(src)))
+}
+
+/// Multiplies packed complex numbers in `a` and `b`, and stores the results in
+/// `dst` using zeromask `k` (elements are zeroed out when the corresponding
+/// mask bit is not set). Each complex number is composed of two adjacent
+/// half-precision (16-bit) floating-point elements, which defines the complex
+/// number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_fmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm512_maskz_fmul_pch(k: __mmask16, a: __m512h, b: __m512h) -> __m512h {
+    _mm512_mask_fmul_pch(_mm512_setzero_ph(), k, a, b)
+}
+
+/// Multiplies packed complex numbers in `a` and `b`, and stores the results in
+/// `dst`. Each complex number is composed of two adjacent half-precision
+/// (16-bit) floating-point elements, which defines the complex number `complex
+/// = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_fmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm256_fmul_pch(a: __m256h, b: __m256h) -> __m256h {
+    // The destination must not overlap either source.
+    let r: __m256h;
+    asm!("vfmulcph $2, $1, $0" : "=&v"(r) : "v"(a), "v"(b));
+    r
+}
+
+/// Multiplies packed complex numbers in `a` and `b`, and stores the results in
+/// `dst` using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set). Each complex number is composed of two
+/// adjacent half-precision (16-bit) floating-point elements, which defines the
+/// complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_fmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm256_mask_fmul_pch(src: __m256h, k: __mmask8, a: __m256h, b: __m256h) -> __m256h {
+    let r: u32x8 = transmute(_mm256_fmul_pch(a, b));
+    transmute(simd_select_bitmask(k, r, transmute::<_, u32x8>(src)))
+}
+
+/// Multiplies packed complex numbers in `a` and `b`, and stores the results in
+/// `dst` using zeromask `k` (elements are zeroed out when the corresponding
+/// mask bit is not set). Each complex number is composed of two adjacent
+/// half-precision (16-bit) floating-point elements, which defines the complex
+/// number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_fmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm256_maskz_fmul_pch(k: __mmask8, a: __m256h, b: __m256h) -> __m256h {
+    _mm256_mask_fmul_pch(_mm256_setzero_ph(), k, a, b)
+}
+
+/// Multiplies packed complex numbers in `a` and `b`, and stores the results in
+/// `dst`. Each complex number is composed of two adjacent half-precision
+/// (16-bit) floating-point elements, which defines the complex number `complex
+/// = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_fmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm_fmul_pch(a: __m128h, b: __m128h) -> __m128h {
+    // The destination must not overlap either source.
+    let r: __m128h;
+    asm!("vfmulcph $2, $1, $0" : "=&v"(r) : "v"(a), "v"(b));
+    r
+}
+
+/// Multiplies packed complex numbers in `a` and `b`, and stores the results in
+/// `dst` using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set). Each complex number is composed of two
+/// adjacent half-precision (16-bit) floating-point elements, which defines the
+/// complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_fmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm_mask_fmul_pch(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let r: u32x4 = transmute(_mm_fmul_pch(a, b));
+    transmute(simd_select_bitmask(k, r, transmute::<_, u32x4>(src)))
+}
+
+/// Multiplies packed complex numbers in `a` and `b`, and stores the results in
+/// `dst` using zeromask `k` (elements are zeroed out when the corresponding
+/// mask bit is not set). Each complex number is composed of two adjacent
+/// half-precision (16-bit) floating-point elements, which defines the complex
+/// number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_fmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfmulcph))]
+pub unsafe fn _mm_maskz_fmul_pch(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    _mm_mask_fmul_pch(_mm_setzero_ph(), k, a, b)
+}
+
+/// Multiplies packed complex numbers in `a` by the complex conjugates of packed
+/// complex numbers in `b`, and stores the results in `dst`. Each complex number
+/// is composed of two adjacent half-precision (16-bit) floating-point elements,
+/// which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_fcmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm512_fcmul_pch(a: __m512h, b: __m512h) -> __m512h {
+    // The destination must not overlap either source.
+    let r: __m512h;
+    asm!("vfcmulcph $2, $1, $0" : "=&v"(r) : "v"(a), "v"(b));
+    r
+}
+
+/// Multiplies packed complex numbers in `a` by the complex conjugates of packed
+/// complex numbers in `b`, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit)
+/// floating-point elements, which defines the complex number `complex =
+/// vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_fcmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm512_mask_fcmul_pch(src: __m512h, k: __mmask16, a: __m512h, b: __m512h) -> __m512h {
+    let r: u32x16 = transmute(_mm512_fcmul_pch(a, b));
+    transmute(simd_select_bitmask(k, r, transmute::<_, u32x16>(src)))
+}
+
+/// Multiplies packed complex numbers in `a` by the complex conjugates of packed
+/// complex numbers in `b`, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set). Each
+/// complex number is composed of two adjacent half-precision (16-bit)
+/// floating-point elements, which defines the complex number `complex =
+/// vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_fcmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm512_maskz_fcmul_pch(k: __mmask16, a: __m512h, b: __m512h) -> __m512h {
+    _mm512_mask_fcmul_pch(_mm512_setzero_ph(), k, a, b)
+}
+
+/// Multiplies packed complex numbers in `a` by the complex conjugates of packed
+/// complex numbers in `b`, and stores the results in `dst`. Each complex number
+/// is composed of two adjacent half-precision (16-bit) floating-point elements,
+/// which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_fcmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm256_fcmul_pch(a: __m256h, b: __m256h) -> __m256h {
+    // The destination must not overlap either source.
+    let r: __m256h;
+    asm!("vfcmulcph $2, $1, $0" : "=&v"(r) : "v"(a), "v"(b));
+    r
+}
+
+/// Multiplies packed complex numbers in `a` by the complex conjugates of packed
+/// complex numbers in `b`, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit)
+/// floating-point elements, which defines the complex number `complex =
+/// vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_fcmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm256_mask_fcmul_pch(src: __m256h, k: __mmask8, a: __m256h, b: __m256h) -> __m256h {
+    let r: u32x8 = transmute(_mm256_fcmul_pch(a, b));
+    transmute(simd_select_bitmask(k, r, transmute::<_, u32x8>(src)))
+}
+
+/// Multiplies packed complex numbers in `a` by the complex conjugates of packed
+/// complex numbers in `b`, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set). Each
+/// complex number is composed of two adjacent half-precision (16-bit)
+/// floating-point elements, which defines the complex number `complex =
+/// vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_fcmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm256_maskz_fcmul_pch(k: __mmask8, a: __m256h, b: __m256h) -> __m256h {
+    _mm256_mask_fcmul_pch(_mm256_setzero_ph(), k, a, b)
+}
+
+/// Multiplies packed complex numbers in `a` by the complex conjugates of packed
+/// complex numbers in `b`, and stores the results in `dst`. Each complex number
+/// is composed of two adjacent half-precision (16-bit) floating-point elements,
+/// which defines the complex number `complex = vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_fcmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm_fcmul_pch(a: __m128h, b: __m128h) -> __m128h {
+    // The destination must not overlap either source.
+    let r: __m128h;
+    asm!("vfcmulcph $2, $1, $0" : "=&v"(r) : "v"(a), "v"(b));
+    r
+}
+
+/// Multiplies packed complex numbers in `a` by the complex conjugates of packed
+/// complex numbers in `b`, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+/// Each complex number is composed of two adjacent half-precision (16-bit)
+/// floating-point elements, which defines the complex number `complex =
+/// vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_fcmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm_mask_fcmul_pch(src: __m128h, k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    let r: u32x4 = transmute(_mm_fcmul_pch(a, b));
+    transmute(simd_select_bitmask(k, r, transmute::<_, u32x4>(src)))
+}
+
+/// Multiplies packed complex numbers in `a` by the complex conjugates of packed
+/// complex numbers in `b`, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set). Each
+/// complex number is composed of two adjacent half-precision (16-bit)
+/// floating-point elements, which defines the complex number `complex =
+/// vec.fp16[0] + i * vec.fp16[1]`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_fcmul_pch)
+#[inline]
+#[target_feature(enable = "avx512fp16,avx512vl")]
+#[cfg_attr(test, assert_instr(vfcmulcph))]
+pub unsafe fn _mm_maskz_fcmul_pch(k: __mmask8, a: __m128h, b: __m128h) -> __m128h {
+    _mm_mask_fcmul_pch(_mm_setzero_ph(), k, a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use std::mem::transmute;
+    use stdsimd_test::simd_test;
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_setzero_ph() {
+        let r = _mm512_setzero_ph();
+        assert_eq_m512h(r, transmute([0_u16; 32]));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_setzero_ph() {
+        let r = _mm256_setzero_ph();
+        assert_eq_m256h(r, transmute([0_u16; 16]));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_setzero_ph() {
+        let r = _mm_setzero_ph();
+        assert_eq_m128h(r, transmute([0_u16; 8]));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_set1_ph() {
+        let r = _mm512_set1_ph(0x3c00);
+        assert_eq_m512h(r, transmute([0x3c00_u16; 32]));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_set1_ph() {
+        let r = _mm256_set1_ph(0x3c00);
+        assert_eq_m256h(r, transmute([0x3c00_u16; 16]));
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_set1_ph() {
+        let r = _mm_set1_ph(0x3c00);
+        assert_eq_m128h(r, transmute([0x3c00_u16; 8]));
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cvtsh_h() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        let r = _mm512_cvtsh_h(a);
+        assert_eq!(r, 0x3c00);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cvtsh_h() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        let r = _mm256_cvtsh_h(a);
+        assert_eq!(r, 0x3c00);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cvtsh_h() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        let r = _mm_cvtsh_h(a);
+        assert_eq!(r, 0x3c00);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_add_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm512_add_ph(a, b);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x4200_u16, 0x4100, 0xc000, 0x3400, 0x4a80, 0x4820, 0x3c00, 0x0000,
+            0x4200, 0x4100, 0xc000, 0x3400, 0x4a80, 0x4820, 0x3c00, 0x0000,
+            0x4200, 0x4100, 0xc000, 0x3400, 0x4a80, 0x4820, 0x3c00, 0x0000,
+            0x4200, 0x4100, 0xc000, 0x3400, 0x4a80, 0x4820, 0x3c00, 0x0000,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_add_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm512_mask_add_ph(a, 0, a, b);
+        assert_eq_m512h(r, a);
+        let r = _mm512_mask_add_ph(a, 0b01101001_10010110_11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x4200_u16, 0x4000, 0xc000, 0x3800, 0x4a80, 0x4820, 0xbc00, 0x4400,
+            0x3c00, 0x4100, 0xc300, 0x3400, 0x4900, 0x3400, 0x3c00, 0x0000,
+            0x3c00, 0x4100, 0xc000, 0x3800, 0x4a80, 0x3400, 0xbc00, 0x0000,
+            0x4200, 0x4000, 0xc300, 0x3400, 0x4900, 0x4820, 0x3c00, 0x4400,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_add_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm512_maskz_add_ph(0, a, b);
+        assert_eq_m512h(r, _mm512_setzero_ph());
+        let r = _mm512_maskz_add_ph(0b01101001_10010110_11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x4200_u16, 0x0000, 0xc000, 0x0000, 0x4a80, 0x4820, 0x0000, 0x0000,
+            0x0000, 0x4100, 0x0000, 0x3400, 0x0000, 0x0000, 0x3c00, 0x0000,
+            0x0000, 0x4100, 0xc000, 0x0000, 0x4a80, 0x0000, 0x0000, 0x0000,
+            0x4200, 0x0000, 0x0000, 0x3400, 0x0000, 0x4820, 0x3c00, 0x0000,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_add_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm256_add_ph(a, b);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x4200_u16, 0x4100, 0xc000, 0x3400, 0x4a80, 0x4820, 0x3c00, 0x0000,
+            0x4200, 0x4100, 0xc000, 0x3400, 0x4a80, 0x4820, 0x3c00, 0x0000,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_add_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm256_mask_add_ph(a, 0, a, b);
+        assert_eq_m256h(r, a);
+        let r = _mm256_mask_add_ph(a, 0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x4200_u16, 0x4000, 0xc000, 0x3800, 0x4a80, 0x4820, 0xbc00, 0x4400,
+            0x3c00, 0x4100, 0xc300, 0x3400, 0x4900, 0x3400, 0x3c00, 0x0000,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_add_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm256_maskz_add_ph(0, a, b);
+        assert_eq_m256h(r, _mm256_setzero_ph());
+        let r = _mm256_maskz_add_ph(0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x4200_u16, 0x0000, 0xc000, 0x0000, 0x4a80, 0x4820, 0x0000, 0x0000,
+            0x0000, 0x4100, 0x0000, 0x3400, 0x0000, 0x0000, 0x3c00, 0x0000,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_add_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm_add_ph(a, b);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x4200_u16, 0x4100, 0xc000, 0x3400, 0x4a80, 0x4820, 0x3c00, 0x0000,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_add_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm_mask_add_ph(a, 0, a, b);
+        assert_eq_m128h(r, a);
+        let r = _mm_mask_add_ph(a, 0b00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x4200_u16, 0x4000, 0xc000, 0x3800, 0x4a80, 0x4820, 0xbc00, 0x4400,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_add_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm_maskz_add_ph(0, a, b);
+        assert_eq_m128h(r, _mm_setzero_ph());
+        let r = _mm_maskz_add_ph(0b00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x4200_u16, 0x0000, 0xc000, 0x0000, 0x4a80, 0x4820, 0x0000, 0x0000,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_sub_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm512_sub_ph(a, b);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0xbc00_u16, 0x3e00, 0xc500, 0x3a00, 0x4700, 0xc7c0, 0xc200, 0x4800,
+            0xbc00, 0x3e00, 0xc500, 0x3a00, 0x4700, 0xc7c0, 0xc200, 0x4800,
+            0xbc00, 0x3e00, 0xc500, 0x3a00, 0x4700, 0xc7c0, 0xc200, 0x4800,
+            0xbc00, 0x3e00, 0xc500, 0x3a00, 0x4700, 0xc7c0, 0xc200, 0x4800,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_sub_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm512_mask_sub_ph(a, 0, a, b);
+        assert_eq_m512h(r, a);
+        let r = _mm512_mask_sub_ph(a, 0b01101001_10010110_11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0xbc00_u16, 0x4000, 0xc500, 0x3800, 0x4700, 0xc7c0, 0xbc00, 0x4400,
+            0x3c00, 0x3e00, 0xc300, 0x3a00, 0x4900, 0x3400, 0xc200, 0x4800,
+            0x3c00, 0x3e00, 0xc500, 0x3800, 0x4700, 0x3400, 0xbc00, 0x4800,
+            0xbc00, 0x4000, 0xc300, 0x3a00, 0x4900, 0xc7c0, 0xc200, 0x4400,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_sub_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm512_maskz_sub_ph(0, a, b);
+        assert_eq_m512h(r, _mm512_setzero_ph());
+        let r = _mm512_maskz_sub_ph(0b01101001_10010110_11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0xbc00_u16, 0x0000, 0xc500, 0x0000, 0x4700, 0xc7c0, 0x0000, 0x0000,
+            0x0000, 0x3e00, 0x0000, 0x3a00, 0x0000, 0x0000, 0xc200, 0x4800,
+            0x0000, 0x3e00, 0xc500, 0x0000, 0x4700, 0x0000, 0x0000, 0x4800,
+            0xbc00, 0x0000, 0x0000, 0x3a00, 0x0000, 0xc7c0, 0xc200, 0x0000,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_sub_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm256_sub_ph(a, b);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0xbc00_u16, 0x3e00, 0xc500, 0x3a00, 0x4700, 0xc7c0, 0xc200, 0x4800,
+            0xbc00, 0x3e00, 0xc500, 0x3a00, 0x4700, 0xc7c0, 0xc200, 0x4800,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_sub_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm256_mask_sub_ph(a, 0, a, b);
+        assert_eq_m256h(r, a);
+        let r = _mm256_mask_sub_ph(a, 0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0xbc00_u16, 0x4000, 0xc500, 0x3800, 0x4700, 0xc7c0, 0xbc00, 0x4400,
+            0x3c00, 0x3e00, 0xc300, 0x3a00, 0x4900, 0x3400, 0xc200, 0x4800,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_sub_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm256_maskz_sub_ph(0, a, b);
+        assert_eq_m256h(r, _mm256_setzero_ph());
+        let r = _mm256_maskz_sub_ph(0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0xbc00_u16, 0x0000, 0xc500, 0x0000, 0x4700, 0xc7c0, 0x0000, 0x0000,
+            0x0000, 0x3e00, 0x0000, 0x3a00, 0x0000, 0x0000, 0xc200, 0x4800,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_sub_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm_sub_ph(a, b);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0xbc00_u16, 0x3e00, 0xc500, 0x3a00, 0x4700, 0xc7c0, 0xc200, 0x4800,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_sub_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm_mask_sub_ph(a, 0, a, b);
+        assert_eq_m128h(r, a);
+        let r = _mm_mask_sub_ph(a, 0b00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0xbc00_u16, 0x4000, 0xc500, 0x3800, 0x4700, 0xc7c0, 0xbc00, 0x4400,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_sub_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm_maskz_sub_ph(0, a, b);
+        assert_eq_m128h(r, _mm_setzero_ph());
+        let r = _mm_maskz_sub_ph(0b00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0xbc00_u16, 0x0000, 0xc500, 0x0000, 0x4700, 0xc7c0, 0x0000, 0x0000,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mul_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm512_mul_ph(a, b);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x4000_u16, 0x3c00, 0xc540, 0xb000, 0x4f80, 0x4000, 0xc000, 0xcc00,
+            0x4000, 0x3c00, 0xc540, 0xb000, 0x4f80, 0x4000, 0xc000, 0xcc00,
+            0x4000, 0x3c00, 0xc540, 0xb000, 0x4f80, 0x4000, 0xc000, 0xcc00,
+            0x4000, 0x3c00, 0xc540, 0xb000, 0x4f80, 0x4000, 0xc000, 0xcc00,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_mul_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm512_mask_mul_ph(a, 0, a, b);
+        assert_eq_m512h(r, a);
+        let r = _mm512_mask_mul_ph(a, 0b01101001_10010110_11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x4000_u16, 0x4000, 0xc540, 0x3800, 0x4f80, 0x4000, 0xbc00, 0x4400,
+            0x3c00, 0x3c00, 0xc300, 0xb000, 0x4900, 0x3400, 0xc000, 0xcc00,
+            0x3c00, 0x3c00, 0xc540, 0x3800, 0x4f80, 0x3400, 0xbc00, 0xcc00,
+            0x4000, 0x4000, 0xc300, 0xb000, 0x4900, 0x4000, 0xc000, 0x4400,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_mul_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm512_maskz_mul_ph(0, a, b);
+        assert_eq_m512h(r, _mm512_setzero_ph());
+        let r = _mm512_maskz_mul_ph(0b01101001_10010110_11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x4000_u16, 0x0000, 0xc540, 0x0000, 0x4f80, 0x4000, 0x0000, 0x0000,
+            0x0000, 0x3c00, 0x0000, 0xb000, 0x0000, 0x0000, 0xc000, 0xcc00,
+            0x0000, 0x3c00, 0xc540, 0x0000, 0x4f80, 0x0000, 0x0000, 0xcc00,
+            0x4000, 0x0000, 0x0000, 0xb000, 0x0000, 0x4000, 0xc000, 0x0000,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mul_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm256_mul_ph(a, b);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x4000_u16, 0x3c00, 0xc540, 0xb000, 0x4f80, 0x4000, 0xc000, 0xcc00,
+            0x4000, 0x3c00, 0xc540, 0xb000, 0x4f80, 0x4000, 0xc000, 0xcc00,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_mul_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm256_mask_mul_ph(a, 0, a, b);
+        assert_eq_m256h(r, a);
+        let r = _mm256_mask_mul_ph(a, 0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x4000_u16, 0x4000, 0xc540, 0x3800, 0x4f80, 0x4000, 0xbc00, 0x4400,
+            0x3c00, 0x3c00, 0xc300, 0xb000, 0x4900, 0x3400, 0xc000, 0xcc00,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_mul_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm256_maskz_mul_ph(0, a, b);
+        assert_eq_m256h(r, _mm256_setzero_ph());
+        let r = _mm256_maskz_mul_ph(0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x4000_u16, 0x0000, 0xc540, 0x0000, 0x4f80, 0x4000, 0x0000, 0x0000,
+            0x0000, 0x3c00, 0x0000, 0xb000, 0x0000, 0x0000, 0xc000, 0xcc00,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mul_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm_mul_ph(a, b);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x4000_u16, 0x3c00, 0xc540, 0xb000, 0x4f80, 0x4000, 0xc000, 0xcc00,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_mul_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm_mask_mul_ph(a, 0, a, b);
+        assert_eq_m128h(r, a);
+        let r = _mm_mask_mul_ph(a, 0b00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x4000_u16, 0x4000, 0xc540, 0x3800, 0x4f80, 0x4000, 0xbc00, 0x4400,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_mul_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm_maskz_mul_ph(0, a, b);
+        assert_eq_m128h(r, _mm_setzero_ph());
+        let r = _mm_maskz_mul_ph(0b00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x4000_u16, 0x0000, 0xc540, 0x0000, 0x4f80, 0x4000, 0x0000, 0x0000,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_div_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm512_div_ph(a, b);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x3800_u16, 0x4400, 0xc0ab, 0xc000, 0x42ab, 0x2800, 0xb800, 0xbc00,
+            0x3800, 0x4400, 0xc0ab, 0xc000, 0x42ab, 0x2800, 0xb800, 0xbc00,
+            0x3800, 0x4400, 0xc0ab, 0xc000, 0x42ab, 0x2800, 0xb800, 0xbc00,
+            0x3800, 0x4400, 0xc0ab, 0xc000, 0x42ab, 0x2800, 0xb800, 0xbc00,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_div_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm512_mask_div_ph(a, 0, a, b);
+        assert_eq_m512h(r, a);
+        let r = _mm512_mask_div_ph(a, 0b01101001_10010110_11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x3800_u16, 0x4000, 0xc0ab, 0x3800, 0x42ab, 0x2800, 0xbc00, 0x4400,
+            0x3c00, 0x4400, 0xc300, 0xc000, 0x4900, 0x3400, 0xb800, 0xbc00,
+            0x3c00, 0x4400, 0xc0ab, 0x3800, 0x42ab, 0x3400, 0xbc00, 0xbc00,
+            0x3800, 0x4000, 0xc300, 0xc000, 0x4900, 0x2800, 0xb800, 0x4400,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_div_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm512_maskz_div_ph(0, a, b);
+        assert_eq_m512h(r, _mm512_setzero_ph());
+        let r = _mm512_maskz_div_ph(0b01101001_10010110_11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x3800_u16, 0x0000, 0xc0ab, 0x0000, 0x42ab, 0x2800, 0x0000, 0x0000,
+            0x0000, 0x4400, 0x0000, 0xc000, 0x0000, 0x0000, 0xb800, 0xbc00,
+            0x0000, 0x4400, 0xc0ab, 0x0000, 0x42ab, 0x0000, 0x0000, 0xbc00,
+            0x3800, 0x0000, 0x0000, 0xc000, 0x0000, 0x2800, 0xb800, 0x0000,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_div_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm256_div_ph(a, b);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x3800_u16, 0x4400, 0xc0ab, 0xc000, 0x42ab, 0x2800, 0xb800, 0xbc00,
+            0x3800, 0x4400, 0xc0ab, 0xc000, 0x42ab, 0x2800, 0xb800, 0xbc00,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_div_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm256_mask_div_ph(a, 0, a, b);
+        assert_eq_m256h(r, a);
+        let r = _mm256_mask_div_ph(a, 0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x3800_u16, 0x4000, 0xc0ab, 0x3800, 0x42ab, 0x2800, 0xbc00, 0x4400,
+            0x3c00, 0x4400, 0xc300, 0xc000, 0x4900, 0x3400, 0xb800, 0xbc00,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_div_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm256_maskz_div_ph(0, a, b);
+        assert_eq_m256h(r, _mm256_setzero_ph());
+        let r = _mm256_maskz_div_ph(0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x3800_u16, 0x0000, 0xc0ab, 0x0000, 0x42ab, 0x2800, 0x0000, 0x0000,
+            0x0000, 0x4400, 0x0000, 0xc000, 0x0000, 0x0000, 0xb800, 0xbc00,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_div_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm_div_ph(a, b);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x3800_u16, 0x4400, 0xc0ab, 0xc000, 0x42ab, 0x2800, 0xb800, 0xbc00,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_div_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm_mask_div_ph(a, 0, a, b);
+        assert_eq_m128h(r, a);
+        let r = _mm_mask_div_ph(a, 0b00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x3800_u16, 0x4000, 0xc0ab, 0x3800, 0x42ab, 0x2800, 0xbc00, 0x4400,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_div_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm_maskz_div_ph(0, a, b);
+        assert_eq_m128h(r, _mm_setzero_ph());
+        let r = _mm_maskz_div_ph(0b00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x3800_u16, 0x0000, 0xc0ab, 0x0000, 0x42ab, 0x2800, 0x0000, 0x0000,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_max_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm512_max_ph(a, b);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x4000_u16, 0x4000, 0x3e00, 0x3800, 0x4900, 0x4800, 0x4000, 0x4400,
+            0x4000, 0x4000, 0x3e00, 0x3800, 0x4900, 0x4800, 0x4000, 0x4400,
+            0x4000, 0x4000, 0x3e00, 0x3800, 0x4900, 0x4800, 0x4000, 0x4400,
+            0x4000, 0x4000, 0x3e00, 0x3800, 0x4900, 0x4800, 0x4000, 0x4400,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_max_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm512_mask_max_ph(a, 0, a, b);
+        assert_eq_m512h(r, a);
+        let r = _mm512_mask_max_ph(a, 0b01101001_10010110_11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x4000_u16, 0x4000, 0x3e00, 0x3800, 0x4900, 0x4800, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0x4000, 0x4400,
+            0x3c00, 0x4000, 0x3e00, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x4000, 0x4000, 0xc300, 0x3800, 0x4900, 0x4800, 0x4000, 0x4400,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_max_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm512_maskz_max_ph(0, a, b);
+        assert_eq_m512h(r, _mm512_setzero_ph());
+        let r = _mm512_maskz_max_ph(0b01101001_10010110_11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x4000_u16, 0x0000, 0x3e00, 0x0000, 0x4900, 0x4800, 0x0000, 0x0000,
+            0x0000, 0x4000, 0x0000, 0x3800, 0x0000, 0x0000, 0x4000, 0x4400,
+            0x0000, 0x4000, 0x3e00, 0x0000, 0x4900, 0x0000, 0x0000, 0x4400,
+            0x4000, 0x0000, 0x0000, 0x3800, 0x0000, 0x4800, 0x4000, 0x0000,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_max_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm256_max_ph(a, b);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x4000_u16, 0x4000, 0x3e00, 0x3800, 0x4900, 0x4800, 0x4000, 0x4400,
+            0x4000, 0x4000, 0x3e00, 0x3800, 0x4900, 0x4800, 0x4000, 0x4400,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_max_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm256_mask_max_ph(a, 0, a, b);
+        assert_eq_m256h(r, a);
+        let r = _mm256_mask_max_ph(a, 0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x4000_u16, 0x4000, 0x3e00, 0x3800, 0x4900, 0x4800, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0x4000, 0x4400,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_max_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm256_maskz_max_ph(0, a, b);
+        assert_eq_m256h(r, _mm256_setzero_ph());
+        let r = _mm256_maskz_max_ph(0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x4000_u16, 0x0000, 0x3e00, 0x0000, 0x4900, 0x4800, 0x0000, 0x0000,
+            0x0000, 0x4000, 0x0000, 0x3800, 0x0000, 0x0000, 0x4000, 0x4400,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_max_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm_max_ph(a, b);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x4000_u16, 0x4000, 0x3e00, 0x3800, 0x4900, 0x4800, 0x4000, 0x4400,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_max_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm_mask_max_ph(a, 0, a, b);
+        assert_eq_m128h(r, a);
+        let r = _mm_mask_max_ph(a, 0b00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x4000_u16, 0x4000, 0x3e00, 0x3800, 0x4900, 0x4800, 0xbc00, 0x4400,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_max_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm_maskz_max_ph(0, a, b);
+        assert_eq_m128h(r, _mm_setzero_ph());
+        let r = _mm_maskz_max_ph(0b00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x4000_u16, 0x0000, 0x3e00, 0x0000, 0x4900, 0x4800, 0x0000, 0x0000,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_min_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm512_min_ph(a, b);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x3c00_u16, 0x3800, 0xc300, 0xb400, 0x4200, 0x3400, 0xbc00, 0xc400,
+            0x3c00, 0x3800, 0xc300, 0xb400, 0x4200, 0x3400, 0xbc00, 0xc400,
+            0x3c00, 0x3800, 0xc300, 0xb400, 0x4200, 0x3400, 0xbc00, 0xc400,
+            0x3c00, 0x3800, 0xc300, 0xb400, 0x4200, 0x3400, 0xbc00, 0xc400,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_min_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm512_mask_min_ph(a, 0, a, b);
+        assert_eq_m512h(r, a);
+        let r = _mm512_mask_min_ph(a, 0b01101001_10010110_11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4200, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x3800, 0xc300, 0xb400, 0x4900, 0x3400, 0xbc00, 0xc400,
+            0x3c00, 0x3800, 0xc300, 0x3800, 0x4200, 0x3400, 0xbc00, 0xc400,
+            0x3c00, 0x4000, 0xc300, 0xb400, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_min_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm512_maskz_min_ph(0, a, b);
+        assert_eq_m512h(r, _mm512_setzero_ph());
+        let r = _mm512_maskz_min_ph(0b01101001_10010110_11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x3c00_u16, 0x0000, 0xc300, 0x0000, 0x4200, 0x3400, 0x0000, 0x0000,
+            0x0000, 0x3800, 0x0000, 0xb400, 0x0000, 0x0000, 0xbc00, 0xc400,
+            0x0000, 0x3800, 0xc300, 0x0000, 0x4200, 0x0000, 0x0000, 0xc400,
+            0x3c00, 0x0000, 0x0000, 0xb400, 0x0000, 0x3400, 0xbc00, 0x0000,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_min_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm256_min_ph(a, b);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x3c00_u16, 0x3800, 0xc300, 0xb400, 0x4200, 0x3400, 0xbc00, 0xc400,
+            0x3c00, 0x3800, 0xc300, 0xb400, 0x4200, 0x3400, 0xbc00, 0xc400,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_min_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm256_mask_min_ph(a, 0, a, b);
+        assert_eq_m256h(r, a);
+        let r = _mm256_mask_min_ph(a, 0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4200, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x3800, 0xc300, 0xb400, 0x4900, 0x3400, 0xbc00, 0xc400,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_min_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm256_maskz_min_ph(0, a, b);
+        assert_eq_m256h(r, _mm256_setzero_ph());
+        let r = _mm256_maskz_min_ph(0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x3c00_u16, 0x0000, 0xc300, 0x0000, 0x4200, 0x3400, 0x0000, 0x0000,
+            0x0000, 0x3800, 0x0000, 0xb400, 0x0000, 0x0000, 0xbc00, 0xc400,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_min_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm_min_ph(a, b);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x3c00_u16, 0x3800, 0xc300, 0xb400, 0x4200, 0x3400, 0xbc00, 0xc400,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_min_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm_mask_min_ph(a, 0, a, b);
+        assert_eq_m128h(r, a);
+        let r = _mm_mask_min_ph(a, 0b00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4200, 0x3400, 0xbc00, 0x4400,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_min_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm_maskz_min_ph(0, a, b);
+        assert_eq_m128h(r, _mm_setzero_ph());
+        let r = _mm_maskz_min_ph(0b00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x3c00_u16, 0x0000, 0xc300, 0x0000, 0x4200, 0x3400, 0x0000, 0x0000,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_sqrt_ph() {
+        // `a` = [1., 4., 2., 0.25, 9., 100., 0., 3.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4400, 0x4000, 0x3400, 0x4880, 0x5640, 0x0000, 0x4200,
+            0x3c00, 0x4400, 0x4000, 0x3400, 0x4880, 0x5640, 0x0000, 0x4200,
+            0x3c00, 0x4400, 0x4000, 0x3400, 0x4880, 0x5640, 0x0000, 0x4200,
+            0x3c00, 0x4400, 0x4000, 0x3400, 0x4880, 0x5640, 0x0000, 0x4200,
+        ]);
+        let r = _mm512_sqrt_ph(a);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0x3da8, 0x3800, 0x4200, 0x4900, 0x0000, 0x3eee,
+            0x3c00, 0x4000, 0x3da8, 0x3800, 0x4200, 0x4900, 0x0000, 0x3eee,
+            0x3c00, 0x4000, 0x3da8, 0x3800, 0x4200, 0x4900, 0x0000, 0x3eee,
+            0x3c00, 0x4000, 0x3da8, 0x3800, 0x4200, 0x4900, 0x0000, 0x3eee,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_sqrt_ph() {
+        // `a` = [1., 4., 2., 0.25, 9., 100., 0., 3.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4400, 0x4000, 0x3400, 0x4880, 0x5640, 0x0000, 0x4200,
+            0x3c00, 0x4400, 0x4000, 0x3400, 0x4880, 0x5640, 0x0000, 0x4200,
+            0x3c00, 0x4400, 0x4000, 0x3400, 0x4880, 0x5640, 0x0000, 0x4200,
+            0x3c00, 0x4400, 0x4000, 0x3400, 0x4880, 0x5640, 0x0000, 0x4200,
+        ]);
+        let r = _mm512_mask_sqrt_ph(a, 0, a);
+        assert_eq_m512h(r, a);
+        let r = _mm512_mask_sqrt_ph(a, 0b01101001_10010110_11001010_00110101, a);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x3c00_u16, 0x4400, 0x3da8, 0x3400, 0x4200, 0x4900, 0x0000, 0x4200,
+            0x3c00, 0x4000, 0x4000, 0x3800, 0x4880, 0x5640, 0x0000, 0x3eee,
+            0x3c00, 0x4000, 0x3da8, 0x3400, 0x4200, 0x5640, 0x0000, 0x3eee,
+            0x3c00, 0x4400, 0x4000, 0x3800, 0x4880, 0x4900, 0x0000, 0x4200,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_sqrt_ph() {
+        // `a` = [1., 4., 2., 0.25, 9., 100., 0., 3.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4400, 0x4000, 0x3400, 0x4880, 0x5640, 0x0000, 0x4200,
+            0x3c00, 0x4400, 0x4000, 0x3400, 0x4880, 0x5640, 0x0000, 0x4200,
+            0x3c00, 0x4400, 0x4000, 0x3400, 0x4880, 0x5640, 0x0000, 0x4200,
+            0x3c00, 0x4400, 0x4000, 0x3400, 0x4880, 0x5640, 0x0000, 0x4200,
+        ]);
+        let r = _mm512_maskz_sqrt_ph(0, a);
+        assert_eq_m512h(r, _mm512_setzero_ph());
+        let r = _mm512_maskz_sqrt_ph(0b01101001_10010110_11001010_00110101, a);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x3c00_u16, 0x0000, 0x3da8, 0x0000, 0x4200, 0x4900, 0x0000, 0x0000,
+            0x0000, 0x4000, 0x0000, 0x3800, 0x0000, 0x0000, 0x0000, 0x3eee,
+            0x0000, 0x4000, 0x3da8, 0x0000, 0x4200, 0x0000, 0x0000, 0x3eee,
+            0x3c00, 0x0000, 0x0000, 0x3800, 0x0000, 0x4900, 0x0000, 0x0000,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_sqrt_ph() {
+        // `a` = [1., 4., 2., 0.25, 9., 100., 0., 3.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4400, 0x4000, 0x3400, 0x4880, 0x5640, 0x0000, 0x4200,
+            0x3c00, 0x4400, 0x4000, 0x3400, 0x4880, 0x5640, 0x0000, 0x4200,
+        ]);
+        let r = _mm256_sqrt_ph(a);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0x3da8, 0x3800, 0x4200, 0x4900, 0x0000, 0x3eee,
+            0x3c00, 0x4000, 0x3da8, 0x3800, 0x4200, 0x4900, 0x0000, 0x3eee,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_sqrt_ph() {
+        // `a` = [1., 4., 2., 0.25, 9., 100., 0., 3.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4400, 0x4000, 0x3400, 0x4880, 0x5640, 0x0000, 0x4200,
+            0x3c00, 0x4400, 0x4000, 0x3400, 0x4880, 0x5640, 0x0000, 0x4200,
+        ]);
+        let r = _mm256_mask_sqrt_ph(a, 0, a);
+        assert_eq_m256h(r, a);
+        let r = _mm256_mask_sqrt_ph(a, 0b11001010_00110101, a);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x3c00_u16, 0x4400, 0x3da8, 0x3400, 0x4200, 0x4900, 0x0000, 0x4200,
+            0x3c00, 0x4000, 0x4000, 0x3800, 0x4880, 0x5640, 0x0000, 0x3eee,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_sqrt_ph() {
+        // `a` = [1., 4., 2., 0.25, 9., 100., 0., 3.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4400, 0x4000, 0x3400, 0x4880, 0x5640, 0x0000, 0x4200,
+            0x3c00, 0x4400, 0x4000, 0x3400, 0x4880, 0x5640, 0x0000, 0x4200,
+        ]);
+        let r = _mm256_maskz_sqrt_ph(0, a);
+        assert_eq_m256h(r, _mm256_setzero_ph());
+        let r = _mm256_maskz_sqrt_ph(0b11001010_00110101, a);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x3c00_u16, 0x0000, 0x3da8, 0x0000, 0x4200, 0x4900, 0x0000, 0x0000,
+            0x0000, 0x4000, 0x0000, 0x3800, 0x0000, 0x0000, 0x0000, 0x3eee,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_sqrt_ph() {
+        // `a` = [1., 4., 2., 0.25, 9., 100., 0., 3.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4400, 0x4000, 0x3400, 0x4880, 0x5640, 0x0000, 0x4200,
+        ]);
+        let r = _mm_sqrt_ph(a);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0x3da8, 0x3800, 0x4200, 0x4900, 0x0000, 0x3eee,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_sqrt_ph() {
+        // `a` = [1., 4., 2., 0.25, 9., 100., 0., 3.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4400, 0x4000, 0x3400, 0x4880, 0x5640, 0x0000, 0x4200,
+        ]);
+        let r = _mm_mask_sqrt_ph(a, 0, a);
+        assert_eq_m128h(r, a);
+        let r = _mm_mask_sqrt_ph(a, 0b00110101, a);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x3c00_u16, 0x4400, 0x3da8, 0x3400, 0x4200, 0x4900, 0x0000, 0x4200,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_sqrt_ph() {
+        // `a` = [1., 4., 2., 0.25, 9., 100., 0., 3.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4400, 0x4000, 0x3400, 0x4880, 0x5640, 0x0000, 0x4200,
+        ]);
+        let r = _mm_maskz_sqrt_ph(0, a);
+        assert_eq_m128h(r, _mm_setzero_ph());
+        let r = _mm_maskz_sqrt_ph(0b00110101, a);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x3c00_u16, 0x0000, 0x3da8, 0x0000, 0x4200, 0x4900, 0x0000, 0x0000,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_fmadd_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        // `c` = [1., -1., 0.5, 2., -0.5, 3., 0., 1.] repeated
+        #[rustfmt::skip]
+        let c: __m512h = transmute([
+            0x3c00_u16, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+            0x3c00, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+            0x3c00, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+            0x3c00, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+        ]);
+        let r = _mm512_fmadd_ph(a, b, c);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x4200_u16, 0x0000, 0xc4c0, 0x3f80, 0x4f60, 0x4500, 0xc000, 0xcb80,
+            0x4200, 0x0000, 0xc4c0, 0x3f80, 0x4f60, 0x4500, 0xc000, 0xcb80,
+            0x4200, 0x0000, 0xc4c0, 0x3f80, 0x4f60, 0x4500, 0xc000, 0xcb80,
+            0x4200, 0x0000, 0xc4c0, 0x3f80, 0x4f60, 0x4500, 0xc000, 0xcb80,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_fmadd_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        // `c` = [1., -1., 0.5, 2., -0.5, 3., 0., 1.] repeated
+        #[rustfmt::skip]
+        let c: __m512h = transmute([
+            0x3c00_u16, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+            0x3c00, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+            0x3c00, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+            0x3c00, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+        ]);
+        let r = _mm512_mask_fmadd_ph(a, 0, b, c);
+        assert_eq_m512h(r, a);
+        let r = _mm512_mask_fmadd_ph(a, 0b01101001_10010110_11001010_00110101, b, c);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x4200_u16, 0x4000, 0xc4c0, 0x3800, 0x4f60, 0x4500, 0xbc00, 0x4400,
+            0x3c00, 0x0000, 0xc300, 0x3f80, 0x4900, 0x3400, 0xc000, 0xcb80,
+            0x3c00, 0x0000, 0xc4c0, 0x3800, 0x4f60, 0x3400, 0xbc00, 0xcb80,
+            0x4200, 0x4000, 0xc300, 0x3f80, 0x4900, 0x4500, 0xc000, 0x4400,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask3_fmadd_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        // `c` = [1., -1., 0.5, 2., -0.5, 3., 0., 1.] repeated
+        #[rustfmt::skip]
+        let c: __m512h = transmute([
+            0x3c00_u16, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+            0x3c00, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+            0x3c00, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+            0x3c00, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+        ]);
+        let r = _mm512_mask3_fmadd_ph(a, b, c, 0);
+        assert_eq_m512h(r, c);
+        let r = _mm512_mask3_fmadd_ph(a, b, c, 0b01101001_10010110_11001010_00110101);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x4200_u16, 0xbc00, 0xc4c0, 0x4000, 0x4f60, 0x4500, 0x0000, 0x3c00,
+            0x3c00, 0x0000, 0x3800, 0x3f80, 0xb800, 0x4200, 0xc000, 0xcb80,
+            0x3c00, 0x0000, 0xc4c0, 0x4000, 0x4f60, 0x4200, 0x0000, 0xcb80,
+            0x4200, 0xbc00, 0x3800, 0x3f80, 0xb800, 0x4500, 0xc000, 0x3c00,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_fmadd_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        // `c` = [1., -1., 0.5, 2., -0.5, 3., 0., 1.] repeated
+        #[rustfmt::skip]
+        let c: __m512h = transmute([
+            0x3c00_u16, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+            0x3c00, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+            0x3c00, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+            0x3c00, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+        ]);
+        let r = _mm512_maskz_fmadd_ph(0, a, b, c);
+        assert_eq_m512h(r, _mm512_setzero_ph());
+        let r = _mm512_maskz_fmadd_ph(0b01101001_10010110_11001010_00110101, a, b, c);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x4200_u16, 0x0000, 0xc4c0, 0x0000, 0x4f60, 0x4500, 0x0000, 0x0000,
+            0x0000, 0x0000, 0x0000, 0x3f80, 0x0000, 0x0000, 0xc000, 0xcb80,
+            0x0000, 0x0000, 0xc4c0, 0x0000, 0x4f60, 0x0000, 0x0000, 0xcb80,
+            0x4200, 0x0000, 0x0000, 0x3f80, 0x0000, 0x4500, 0xc000, 0x0000,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_fmadd_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        // `c` = [1., -1., 0.5, 2., -0.5, 3., 0., 1.] repeated
+        #[rustfmt::skip]
+        let c: __m256h = transmute([
+            0x3c00_u16, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+            0x3c00, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+        ]);
+        let r = _mm256_fmadd_ph(a, b, c);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x4200_u16, 0x0000, 0xc4c0, 0x3f80, 0x4f60, 0x4500, 0xc000, 0xcb80,
+            0x4200, 0x0000, 0xc4c0, 0x3f80, 0x4f60, 0x4500, 0xc000, 0xcb80,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_fmadd_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        // `c` = [1., -1., 0.5, 2., -0.5, 3., 0., 1.] repeated
+        #[rustfmt::skip]
+        let c: __m256h = transmute([
+            0x3c00_u16, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+            0x3c00, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+        ]);
+        let r = _mm256_mask_fmadd_ph(a, 0, b, c);
+        assert_eq_m256h(r, a);
+        let r = _mm256_mask_fmadd_ph(a, 0b11001010_00110101, b, c);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x4200_u16, 0x4000, 0xc4c0, 0x3800, 0x4f60, 0x4500, 0xbc00, 0x4400,
+            0x3c00, 0x0000, 0xc300, 0x3f80, 0x4900, 0x3400, 0xc000, 0xcb80,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask3_fmadd_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        // `c` = [1., -1., 0.5, 2., -0.5, 3., 0., 1.] repeated
+        #[rustfmt::skip]
+        let c: __m256h = transmute([
+            0x3c00_u16, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+            0x3c00, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+        ]);
+        let r = _mm256_mask3_fmadd_ph(a, b, c, 0);
+        assert_eq_m256h(r, c);
+        let r = _mm256_mask3_fmadd_ph(a, b, c, 0b11001010_00110101);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x4200_u16, 0xbc00, 0xc4c0, 0x4000, 0x4f60, 0x4500, 0x0000, 0x3c00,
+            0x3c00, 0x0000, 0x3800, 0x3f80, 0xb800, 0x4200, 0xc000, 0xcb80,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_fmadd_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        // `c` = [1., -1., 0.5, 2., -0.5, 3., 0., 1.] repeated
+        #[rustfmt::skip]
+        let c: __m256h = transmute([
+            0x3c00_u16, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+            0x3c00, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+        ]);
+        let r = _mm256_maskz_fmadd_ph(0, a, b, c);
+        assert_eq_m256h(r, _mm256_setzero_ph());
+        let r = _mm256_maskz_fmadd_ph(0b11001010_00110101, a, b, c);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x4200_u16, 0x0000, 0xc4c0, 0x0000, 0x4f60, 0x4500, 0x0000, 0x0000,
+            0x0000, 0x0000, 0x0000, 0x3f80, 0x0000, 0x0000, 0xc000, 0xcb80,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_fmadd_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        // `c` = [1., -1., 0.5, 2., -0.5, 3., 0., 1.]
+        #[rustfmt::skip]
+        let c: __m128h = transmute([
+            0x3c00_u16, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+        ]);
+        let r = _mm_fmadd_ph(a, b, c);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x4200_u16, 0x0000, 0xc4c0, 0x3f80, 0x4f60, 0x4500, 0xc000, 0xcb80,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_fmadd_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        // `c` = [1., -1., 0.5, 2., -0.5, 3., 0., 1.]
+        #[rustfmt::skip]
+        let c: __m128h = transmute([
+            0x3c00_u16, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+        ]);
+        let r = _mm_mask_fmadd_ph(a, 0, b, c);
+        assert_eq_m128h(r, a);
+        let r = _mm_mask_fmadd_ph(a, 0b00110101, b, c);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x4200_u16, 0x4000, 0xc4c0, 0x3800, 0x4f60, 0x4500, 0xbc00, 0x4400,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask3_fmadd_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        // `c` = [1., -1., 0.5, 2., -0.5, 3., 0., 1.]
+        #[rustfmt::skip]
+        let c: __m128h = transmute([
+            0x3c00_u16, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+        ]);
+        let r = _mm_mask3_fmadd_ph(a, b, c, 0);
+        assert_eq_m128h(r, c);
+        let r = _mm_mask3_fmadd_ph(a, b, c, 0b00110101);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x4200_u16, 0xbc00, 0xc4c0, 0x4000, 0x4f60, 0x4500, 0x0000, 0x3c00,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_fmadd_ph() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        // `c` = [1., -1., 0.5, 2., -0.5, 3., 0., 1.]
+        #[rustfmt::skip]
+        let c: __m128h = transmute([
+            0x3c00_u16, 0xbc00, 0x3800, 0x4000, 0xb800, 0x4200, 0x0000, 0x3c00,
+        ]);
+        let r = _mm_maskz_fmadd_ph(0, a, b, c);
+        assert_eq_m128h(r, _mm_setzero_ph());
+        let r = _mm_maskz_fmadd_ph(0b00110101, a, b, c);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x4200_u16, 0x0000, 0xc4c0, 0x0000, 0x4f60, 0x4500, 0x0000, 0x0000,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cmp_ph_mask() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm512_cmp_ph_mask(a, b, _CMP_LT_OS);
+        assert_eq!(r, 0b01100101_01100101_01100101_01100101);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_cmp_ph_mask() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let mask = 0b01101001_10010110_11001010_00110101;
+        let r = _mm512_mask_cmp_ph_mask(mask, a, b, _CMP_LT_OS);
+        assert_eq!(r, 0b01100001_00000100_01000000_00100101);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cmp_ph_mask() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm256_cmp_ph_mask(a, b, _CMP_LT_OS);
+        assert_eq!(r, 0b01100101_01100101);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_cmp_ph_mask() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+            0x4000, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let mask = 0b11001010_00110101;
+        let r = _mm256_mask_cmp_ph_mask(mask, a, b, _CMP_LT_OS);
+        assert_eq!(r, 0b01000000_00100101);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cmp_ph_mask() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let r = _mm_cmp_ph_mask(a, b, _CMP_LT_OS);
+        assert_eq!(r, 0b01100101);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_cmp_ph_mask() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        // `b` = [2., 0.5, 1.5, -0.25, 3., 8., 2., -4.]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x4000_u16, 0x3800, 0x3e00, 0xb400, 0x4200, 0x4800, 0x4000, 0xc400,
+        ]);
+        let mask = 0b00110101;
+        let r = _mm_mask_cmp_ph_mask(mask, a, b, _CMP_LT_OS);
+        assert_eq!(r, 0b00100101);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cvtxph_ps() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        let r = _mm512_cvtxph_ps(a);
+        #[rustfmt::skip]
+        let e = _mm512_setr_ps(
+            1., 2., -3.5, 0.5, 10., 0.25, -1., 4.,
+            1., 2., -3.5, 0.5, 10., 0.25, -1., 4.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_cvtxph_ps() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        let src = _mm512_set1_ps(100.);
+        let r = _mm512_mask_cvtxph_ps(src, 0, a);
+        assert_eq_m512(r, src);
+        let r = _mm512_mask_cvtxph_ps(src, 0b11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm512_setr_ps(
+            1., 100., -3.5, 100., 10., 0.25, 100., 100.,
+            100., 2., 100., 0.5, 100., 100., -1., 4.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_cvtxph_ps() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        let r = _mm512_maskz_cvtxph_ps(0, a);
+        assert_eq_m512(r, _mm512_setzero_ps());
+        let r = _mm512_maskz_cvtxph_ps(0b11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm512_setr_ps(
+            1., 0., -3.5, 0., 10., 0.25, 0., 0.,
+            0., 2., 0., 0.5, 0., 0., -1., 4.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cvtxph_ps() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        let r = _mm256_cvtxph_ps(a);
+        let e = _mm256_setr_ps(1., 2., -3.5, 0.5, 10., 0.25, -1., 4.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_cvtxph_ps() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        let src = _mm256_set1_ps(100.);
+        let r = _mm256_mask_cvtxph_ps(src, 0, a);
+        assert_eq_m256(r, src);
+        let r = _mm256_mask_cvtxph_ps(src, 0b00110101, a);
+        let e = _mm256_setr_ps(1., 100., -3.5, 100., 10., 0.25, 100., 100.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_cvtxph_ps() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        let r = _mm256_maskz_cvtxph_ps(0, a);
+        assert_eq_m256(r, _mm256_setzero_ps());
+        let r = _mm256_maskz_cvtxph_ps(0b00110101, a);
+        let e = _mm256_setr_ps(1., 0., -3.5, 0., 10., 0.25, 0., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cvtxph_ps() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        let r = _mm_cvtxph_ps(a);
+        let e = _mm_setr_ps(1., 2., -3.5, 0.5);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_cvtxph_ps() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        let src = _mm_set1_ps(100.);
+        let r = _mm_mask_cvtxph_ps(src, 0, a);
+        assert_eq_m128(r, src);
+        let r = _mm_mask_cvtxph_ps(src, 0b00110101, a);
+        let e = _mm_setr_ps(1., 100., -3.5, 100.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_cvtxph_ps() {
+        // `a` = [1., 2., -3.5, 0.5, 10., 0.25, -1., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        let r = _mm_maskz_cvtxph_ps(0, a);
+        assert_eq_m128(r, _mm_setzero_ps());
+        let r = _mm_maskz_cvtxph_ps(0b00110101, a);
+        let e = _mm_setr_ps(1., 0., -3.5, 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cvtxps_ph() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1., 2., -3.5, 0.5, 10., 0.25, -1., 4.,
+            1., 2., -3.5, 0.5, 10., 0.25, -1., 4.,
+        );
+        let r = _mm512_cvtxps_ph(a);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+            0x3c00, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_cvtxps_ph() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1., 2., -3.5, 0.5, 10., 0.25, -1., 4.,
+            1., 2., -3.5, 0.5, 10., 0.25, -1., 4.,
+        );
+        let src = _mm256_set1_ph(0x7e00);
+        let r = _mm512_mask_cvtxps_ph(src, 0, a);
+        assert_eq_m256h(r, src);
+        let r = _mm512_mask_cvtxps_ph(src, 0b11001010_00110101, a);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x3c00_u16, 0x7e00, 0xc300, 0x7e00, 0x4900, 0x3400, 0x7e00, 0x7e00,
+            0x7e00, 0x4000, 0x7e00, 0x3800, 0x7e00, 0x7e00, 0xbc00, 0x4400,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_cvtxps_ph() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_ps(
+            1., 2., -3.5, 0.5, 10., 0.25, -1., 4.,
+            1., 2., -3.5, 0.5, 10., 0.25, -1., 4.,
+        );
+        let r = _mm512_maskz_cvtxps_ph(0, a);
+        assert_eq_m256h(r, _mm256_setzero_ph());
+        let r = _mm512_maskz_cvtxps_ph(0b11001010_00110101, a);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x3c00_u16, 0x0000, 0xc300, 0x0000, 0x4900, 0x3400, 0x0000, 0x0000,
+            0x0000, 0x4000, 0x0000, 0x3800, 0x0000, 0x0000, 0xbc00, 0x4400,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cvtxps_ph() {
+        let a = _mm256_setr_ps(1., 2., -3.5, 0.5, 10., 0.25, -1., 4.);
+        let r = _mm256_cvtxps_ph(a);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x4900, 0x3400, 0xbc00, 0x4400,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_cvtxps_ph() {
+        let a = _mm256_setr_ps(1., 2., -3.5, 0.5, 10., 0.25, -1., 4.);
+        let src = _mm_set1_ph(0x7e00);
+        let r = _mm256_mask_cvtxps_ph(src, 0, a);
+        assert_eq_m128h(r, src);
+        let r = _mm256_mask_cvtxps_ph(src, 0b00110101, a);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x3c00_u16, 0x7e00, 0xc300, 0x7e00, 0x4900, 0x3400, 0x7e00, 0x7e00,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_cvtxps_ph() {
+        let a = _mm256_setr_ps(1., 2., -3.5, 0.5, 10., 0.25, -1., 4.);
+        let r = _mm256_maskz_cvtxps_ph(0, a);
+        assert_eq_m128h(r, _mm_setzero_ph());
+        let r = _mm256_maskz_cvtxps_ph(0b00110101, a);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x3c00_u16, 0x0000, 0xc300, 0x0000, 0x4900, 0x3400, 0x0000, 0x0000,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cvtxps_ph() {
+        let a = _mm_setr_ps(1., 2., -3.5, 0.5);
+        let r = _mm_cvtxps_ph(a);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0xc300, 0x3800, 0x0000, 0x0000, 0x0000, 0x0000,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_cvtxps_ph() {
+        let a = _mm_setr_ps(1., 2., -3.5, 0.5);
+        let src = _mm_set1_ph(0x7e00);
+        let r = _mm_mask_cvtxps_ph(src, 0, a);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x7e00_u16, 0x7e00, 0x7e00, 0x7e00, 0x0000, 0x0000, 0x0000, 0x0000,
+        ]);
+        assert_eq_m128h(r, e);
+        let r = _mm_mask_cvtxps_ph(src, 0b00110101, a);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x3c00_u16, 0x7e00, 0xc300, 0x7e00, 0x0000, 0x0000, 0x0000, 0x0000,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_cvtxps_ph() {
+        let a = _mm_setr_ps(1., 2., -3.5, 0.5);
+        let r = _mm_maskz_cvtxps_ph(0, a);
+        assert_eq_m128h(r, _mm_setzero_ph());
+        let r = _mm_maskz_cvtxps_ph(0b00110101, a);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x3c00_u16, 0x0000, 0xc300, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cvtph_epi16() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let r = _mm512_cvtph_epi16(a);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 3, -8, 100, 1, -2, 2, 2,
+            0, 3, -8, 100, 1, -2, 2, 2,
+            0, 3, -8, 100, 1, -2, 2, 2,
+            0, 3, -8, 100, 1, -2, 2, 2,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_cvtph_epi16() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let src = _mm512_set1_epi16(-1);
+        let r = _mm512_mask_cvtph_epi16(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_cvtph_epi16(src, 0b01101001_10010110_11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            -1, 3, -8, -1, 1, -1, -1, 2,
+            0, -1, -1, 100, -1, -2, 2, -1,
+            0, 3, -1, -1, 1, -1, 2, -1,
+            -1, -1, -8, 100, -1, -2, -1, 2,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_cvtph_epi16() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let r = _mm512_maskz_cvtph_epi16(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvtph_epi16(0b01101001_10010110_11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 3, -8, 0, 1, 0, 0, 2,
+            0, 0, 0, 100, 0, -2, 2, 0,
+            0, 3, 0, 0, 1, 0, 2, 0,
+            0, 0, -8, 100, 0, -2, 0, 2,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cvtph_epi16() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let r = _mm256_cvtph_epi16(a);
+        #[rustfmt::skip]
+        let e = _mm256_set_epi16(
+            0, 3, -8, 100, 1, -2, 2, 2,
+            0, 3, -8, 100, 1, -2, 2, 2,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_cvtph_epi16() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let src = _mm256_set1_epi16(-1);
+        let r = _mm256_mask_cvtph_epi16(src, 0, a);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_cvtph_epi16(src, 0b11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm256_set_epi16(
+            0, 3, -1, -1, 1, -1, 2, -1,
+            -1, -1, -8, 100, -1, -2, -1, 2,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_cvtph_epi16() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let r = _mm256_maskz_cvtph_epi16(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_cvtph_epi16(0b11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm256_set_epi16(
+            0, 3, 0, 0, 1, 0, 2, 0,
+            0, 0, -8, 100, 0, -2, 0, 2,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cvtph_epi16() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let r = _mm_cvtph_epi16(a);
+        let e = _mm_set_epi16(0, 3, -8, 100, 1, -2, 2, 2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_cvtph_epi16() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let src = _mm_set1_epi16(-1);
+        let r = _mm_mask_cvtph_epi16(src, 0, a);
+        assert_eq_m128i(r, src);
+        let r = _mm_mask_cvtph_epi16(src, 0b00110101, a);
+        let e = _mm_set_epi16(-1, -1, -8, 100, -1, -2, -1, 2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_cvtph_epi16() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let r = _mm_maskz_cvtph_epi16(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_cvtph_epi16(0b00110101, a);
+        let e = _mm_set_epi16(0, 0, -8, 100, 0, -2, 0, 2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cvttph_epi16() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let r = _mm512_cvttph_epi16(a);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 3, -7, 100, 0, -2, 2, 1,
+            0, 3, -7, 100, 0, -2, 2, 1,
+            0, 3, -7, 100, 0, -2, 2, 1,
+            0, 3, -7, 100, 0, -2, 2, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_cvttph_epi16() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let src = _mm512_set1_epi16(-1);
+        let r = _mm512_mask_cvttph_epi16(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_cvttph_epi16(src, 0b01101001_10010110_11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            -1, 3, -7, -1, 0, -1, -1, 1,
+            0, -1, -1, 100, -1, -2, 2, -1,
+            0, 3, -1, -1, 0, -1, 2, -1,
+            -1, -1, -7, 100, -1, -2, -1, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_cvttph_epi16() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let r = _mm512_maskz_cvttph_epi16(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvttph_epi16(0b01101001_10010110_11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 3, -7, 0, 0, 0, 0, 1,
+            0, 0, 0, 100, 0, -2, 2, 0,
+            0, 3, 0, 0, 0, 0, 2, 0,
+            0, 0, -7, 100, 0, -2, 0, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cvttph_epi16() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let r = _mm256_cvttph_epi16(a);
+        #[rustfmt::skip]
+        let e = _mm256_set_epi16(
+            0, 3, -7, 100, 0, -2, 2, 1,
+            0, 3, -7, 100, 0, -2, 2, 1,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_cvttph_epi16() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let src = _mm256_set1_epi16(-1);
+        let r = _mm256_mask_cvttph_epi16(src, 0, a);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_cvttph_epi16(src, 0b11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm256_set_epi16(
+            0, 3, -1, -1, 0, -1, 2, -1,
+            -1, -1, -7, 100, -1, -2, -1, 1,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_cvttph_epi16() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let r = _mm256_maskz_cvttph_epi16(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_cvttph_epi16(0b11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm256_set_epi16(
+            0, 3, 0, 0, 0, 0, 2, 0,
+            0, 0, -7, 100, 0, -2, 0, 1,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cvttph_epi16() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let r = _mm_cvttph_epi16(a);
+        let e = _mm_set_epi16(0, 3, -7, 100, 0, -2, 2, 1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_cvttph_epi16() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let src = _mm_set1_epi16(-1);
+        let r = _mm_mask_cvttph_epi16(src, 0, a);
+        assert_eq_m128i(r, src);
+        let r = _mm_mask_cvttph_epi16(src, 0b00110101, a);
+        let e = _mm_set_epi16(-1, -1, -7, 100, -1, -2, -1, 1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_cvttph_epi16() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let r = _mm_maskz_cvttph_epi16(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_cvttph_epi16(0b00110101, a);
+        let e = _mm_set_epi16(0, 0, -7, 100, 0, -2, 0, 1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cvtph_epi32() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let r = _mm512_cvtph_epi32(a);
+        #[rustfmt::skip]
+        let e = _mm512_setr_epi32(
+            2, 2, -2, 1, 100, -8, 3, 0,
+            2, 2, -2, 1, 100, -8, 3, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_cvtph_epi32() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let src = _mm512_set1_epi32(-1);
+        let r = _mm512_mask_cvtph_epi32(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_cvtph_epi32(src, 0b11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm512_setr_epi32(
+            2, -1, -2, -1, 100, -8, -1, -1,
+            -1, 2, -1, 1, -1, -1, 3, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_cvtph_epi32() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+            0x3e00, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let r = _mm512_maskz_cvtph_epi32(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_cvtph_epi32(0b11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm512_setr_epi32(
+            2, 0, -2, 0, 100, -8, 0, 0,
+            0, 2, 0, 1, 0, 0, 3, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cvtph_epi32() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let r = _mm256_cvtph_epi32(a);
+        let e = _mm256_setr_epi32(2, 2, -2, 1, 100, -8, 3, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_cvtph_epi32() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let src = _mm256_set1_epi32(-1);
+        let r = _mm256_mask_cvtph_epi32(src, 0, a);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_cvtph_epi32(src, 0b00110101, a);
+        let e = _mm256_setr_epi32(2, -1, -2, -1, 100, -8, -1, -1);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_cvtph_epi32() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let r = _mm256_maskz_cvtph_epi32(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_cvtph_epi32(0b00110101, a);
+        let e = _mm256_setr_epi32(2, 0, -2, 0, 100, -8, 0, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cvtph_epi32() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let r = _mm_cvtph_epi32(a);
+        let e = _mm_setr_epi32(2, 2, -2, 1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_cvtph_epi32() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let src = _mm_set1_epi32(-1);
+        let r = _mm_mask_cvtph_epi32(src, 0, a);
+        assert_eq_m128i(r, src);
+        let r = _mm_mask_cvtph_epi32(src, 0b00110101, a);
+        let e = _mm_setr_epi32(2, -1, -2, -1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_cvtph_epi32() {
+        // `a` = [1.5, 2.5, -2.5, 0.75, 100.25, -7.5, 3., -0.25]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3e00_u16, 0x4100, 0xc100, 0x3a00, 0x5644, 0xc780, 0x4200, 0xb400,
+        ]);
+        let r = _mm_maskz_cvtph_epi32(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_cvtph_epi32(0b00110101, a);
+        let e = _mm_setr_epi32(2, 0, -2, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cvtepi16_ph() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            2049, -1000, 100, -3, 2, -1, 1, 0,
+            2049, -1000, 100, -3, 2, -1, 1, 0,
+            2049, -1000, 100, -3, 2, -1, 1, 0,
+            2049, -1000, 100, -3, 2, -1, 1, 0,
+        );
+        let r = _mm512_cvtepi16_ph(a);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x0000_u16, 0x3c00, 0xbc00, 0x4000, 0xc200, 0x5640, 0xe3d0, 0x6800,
+            0x0000, 0x3c00, 0xbc00, 0x4000, 0xc200, 0x5640, 0xe3d0, 0x6800,
+            0x0000, 0x3c00, 0xbc00, 0x4000, 0xc200, 0x5640, 0xe3d0, 0x6800,
+            0x0000, 0x3c00, 0xbc00, 0x4000, 0xc200, 0x5640, 0xe3d0, 0x6800,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_cvtepi16_ph() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            2049, -1000, 100, -3, 2, -1, 1, 0,
+            2049, -1000, 100, -3, 2, -1, 1, 0,
+            2049, -1000, 100, -3, 2, -1, 1, 0,
+            2049, -1000, 100, -3, 2, -1, 1, 0,
+        );
+        let src = _mm512_set1_ph(0x7e00);
+        let r = _mm512_mask_cvtepi16_ph(src, 0, a);
+        assert_eq_m512h(r, src);
+        let r = _mm512_mask_cvtepi16_ph(src, 0b01101001_10010110_11001010_00110101, a);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x0000_u16, 0x7e00, 0xbc00, 0x7e00, 0xc200, 0x5640, 0x7e00, 0x7e00,
+            0x7e00, 0x3c00, 0x7e00, 0x4000, 0x7e00, 0x7e00, 0xe3d0, 0x6800,
+            0x7e00, 0x3c00, 0xbc00, 0x7e00, 0xc200, 0x7e00, 0x7e00, 0x6800,
+            0x0000, 0x7e00, 0x7e00, 0x4000, 0x7e00, 0x5640, 0xe3d0, 0x7e00,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_cvtepi16_ph() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            2049, -1000, 100, -3, 2, -1, 1, 0,
+            2049, -1000, 100, -3, 2, -1, 1, 0,
+            2049, -1000, 100, -3, 2, -1, 1, 0,
+            2049, -1000, 100, -3, 2, -1, 1, 0,
+        );
+        let r = _mm512_maskz_cvtepi16_ph(0, a);
+        assert_eq_m512h(r, _mm512_setzero_ph());
+        let r = _mm512_maskz_cvtepi16_ph(0b01101001_10010110_11001010_00110101, a);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x0000_u16, 0x0000, 0xbc00, 0x0000, 0xc200, 0x5640, 0x0000, 0x0000,
+            0x0000, 0x3c00, 0x0000, 0x4000, 0x0000, 0x0000, 0xe3d0, 0x6800,
+            0x0000, 0x3c00, 0xbc00, 0x0000, 0xc200, 0x0000, 0x0000, 0x6800,
+            0x0000, 0x0000, 0x0000, 0x4000, 0x0000, 0x5640, 0xe3d0, 0x0000,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cvtepi16_ph() {
+        #[rustfmt::skip]
+        let a = _mm256_set_epi16(
+            2049, -1000, 100, -3, 2, -1, 1, 0,
+            2049, -1000, 100, -3, 2, -1, 1, 0,
+        );
+        let r = _mm256_cvtepi16_ph(a);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x0000_u16, 0x3c00, 0xbc00, 0x4000, 0xc200, 0x5640, 0xe3d0, 0x6800,
+            0x0000, 0x3c00, 0xbc00, 0x4000, 0xc200, 0x5640, 0xe3d0, 0x6800,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_cvtepi16_ph() {
+        #[rustfmt::skip]
+        let a = _mm256_set_epi16(
+            2049, -1000, 100, -3, 2, -1, 1, 0,
+            2049, -1000, 100, -3, 2, -1, 1, 0,
+        );
+        let src = _mm256_set1_ph(0x7e00);
+        let r = _mm256_mask_cvtepi16_ph(src, 0, a);
+        assert_eq_m256h(r, src);
+        let r = _mm256_mask_cvtepi16_ph(src, 0b11001010_00110101, a);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x0000_u16, 0x7e00, 0xbc00, 0x7e00, 0xc200, 0x5640, 0x7e00, 0x7e00,
+            0x7e00, 0x3c00, 0x7e00, 0x4000, 0x7e00, 0x7e00, 0xe3d0, 0x6800,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_cvtepi16_ph() {
+        #[rustfmt::skip]
+        let a = _mm256_set_epi16(
+            2049, -1000, 100, -3, 2, -1, 1, 0,
+            2049, -1000, 100, -3, 2, -1, 1, 0,
+        );
+        let r = _mm256_maskz_cvtepi16_ph(0, a);
+        assert_eq_m256h(r, _mm256_setzero_ph());
+        let r = _mm256_maskz_cvtepi16_ph(0b11001010_00110101, a);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x0000_u16, 0x0000, 0xbc00, 0x0000, 0xc200, 0x5640, 0x0000, 0x0000,
+            0x0000, 0x3c00, 0x0000, 0x4000, 0x0000, 0x0000, 0xe3d0, 0x6800,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cvtepi16_ph() {
+        let a = _mm_set_epi16(2049, -1000, 100, -3, 2, -1, 1, 0);
+        let r = _mm_cvtepi16_ph(a);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x0000_u16, 0x3c00, 0xbc00, 0x4000, 0xc200, 0x5640, 0xe3d0, 0x6800,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_cvtepi16_ph() {
+        let a = _mm_set_epi16(2049, -1000, 100, -3, 2, -1, 1, 0);
+        let src = _mm_set1_ph(0x7e00);
+        let r = _mm_mask_cvtepi16_ph(src, 0, a);
+        assert_eq_m128h(r, src);
+        let r = _mm_mask_cvtepi16_ph(src, 0b00110101, a);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x0000_u16, 0x7e00, 0xbc00, 0x7e00, 0xc200, 0x5640, 0x7e00, 0x7e00,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_cvtepi16_ph() {
+        let a = _mm_set_epi16(2049, -1000, 100, -3, 2, -1, 1, 0);
+        let r = _mm_maskz_cvtepi16_ph(0, a);
+        assert_eq_m128h(r, _mm_setzero_ph());
+        let r = _mm_maskz_cvtepi16_ph(0b00110101, a);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x0000_u16, 0x0000, 0xbc00, 0x0000, 0xc200, 0x5640, 0x0000, 0x0000,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_cvtepi32_ph() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi32(
+            0, 1, -1, 2, -3, 100, -1000, 2049,
+            0, 1, -1, 2, -3, 100, -1000, 2049,
+        );
+        let r = _mm512_cvtepi32_ph(a);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x0000_u16, 0x3c00, 0xbc00, 0x4000, 0xc200, 0x5640, 0xe3d0, 0x6800,
+            0x0000, 0x3c00, 0xbc00, 0x4000, 0xc200, 0x5640, 0xe3d0, 0x6800,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_cvtepi32_ph() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi32(
+            0, 1, -1, 2, -3, 100, -1000, 2049,
+            0, 1, -1, 2, -3, 100, -1000, 2049,
+        );
+        let src = _mm256_set1_ph(0x7e00);
+        let r = _mm512_mask_cvtepi32_ph(src, 0, a);
+        assert_eq_m256h(r, src);
+        let r = _mm512_mask_cvtepi32_ph(src, 0b11001010_00110101, a);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x0000_u16, 0x7e00, 0xbc00, 0x7e00, 0xc200, 0x5640, 0x7e00, 0x7e00,
+            0x7e00, 0x3c00, 0x7e00, 0x4000, 0x7e00, 0x7e00, 0xe3d0, 0x6800,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_cvtepi32_ph() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi32(
+            0, 1, -1, 2, -3, 100, -1000, 2049,
+            0, 1, -1, 2, -3, 100, -1000, 2049,
+        );
+        let r = _mm512_maskz_cvtepi32_ph(0, a);
+        assert_eq_m256h(r, _mm256_setzero_ph());
+        let r = _mm512_maskz_cvtepi32_ph(0b11001010_00110101, a);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x0000_u16, 0x0000, 0xbc00, 0x0000, 0xc200, 0x5640, 0x0000, 0x0000,
+            0x0000, 0x3c00, 0x0000, 0x4000, 0x0000, 0x0000, 0xe3d0, 0x6800,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_cvtepi32_ph() {
+        let a = _mm256_setr_epi32(0, 1, -1, 2, -3, 100, -1000, 2049);
+        let r = _mm256_cvtepi32_ph(a);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x0000_u16, 0x3c00, 0xbc00, 0x4000, 0xc200, 0x5640, 0xe3d0, 0x6800,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_cvtepi32_ph() {
+        let a = _mm256_setr_epi32(0, 1, -1, 2, -3, 100, -1000, 2049);
+        let src = _mm_set1_ph(0x7e00);
+        let r = _mm256_mask_cvtepi32_ph(src, 0, a);
+        assert_eq_m128h(r, src);
+        let r = _mm256_mask_cvtepi32_ph(src, 0b00110101, a);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x0000_u16, 0x7e00, 0xbc00, 0x7e00, 0xc200, 0x5640, 0x7e00, 0x7e00,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_cvtepi32_ph() {
+        let a = _mm256_setr_epi32(0, 1, -1, 2, -3, 100, -1000, 2049);
+        let r = _mm256_maskz_cvtepi32_ph(0, a);
+        assert_eq_m128h(r, _mm_setzero_ph());
+        let r = _mm256_maskz_cvtepi32_ph(0b00110101, a);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x0000_u16, 0x0000, 0xbc00, 0x0000, 0xc200, 0x5640, 0x0000, 0x0000,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_cvtepi32_ph() {
+        let a = _mm_setr_epi32(0, 1, -1, 2);
+        let r = _mm_cvtepi32_ph(a);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x0000_u16, 0x3c00, 0xbc00, 0x4000, 0x0000, 0x0000, 0x0000, 0x0000,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_cvtepi32_ph() {
+        let a = _mm_setr_epi32(0, 1, -1, 2);
+        let src = _mm_set1_ph(0x7e00);
+        let r = _mm_mask_cvtepi32_ph(src, 0, a);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x7e00_u16, 0x7e00, 0x7e00, 0x7e00, 0x0000, 0x0000, 0x0000, 0x0000,
+        ]);
+        assert_eq_m128h(r, e);
+        let r = _mm_mask_cvtepi32_ph(src, 0b00110101, a);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x0000_u16, 0x7e00, 0xbc00, 0x7e00, 0x0000, 0x0000, 0x0000, 0x0000,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_cvtepi32_ph() {
+        let a = _mm_setr_epi32(0, 1, -1, 2);
+        let r = _mm_maskz_cvtepi32_ph(0, a);
+        assert_eq_m128h(r, _mm_setzero_ph());
+        let r = _mm_maskz_cvtepi32_ph(0b00110101, a);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x0000_u16, 0x0000, 0xbc00, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_fmul_pch() {
+        // `a` = [1., 2., 3., -1., 0.5, 0.5, -2., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+            0x3c00, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+            0x3c00, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+            0x3c00, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+        ]);
+        // `b` = [0.5, 1., 2., 2., 4., -2., 1., 0.25] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x3800_u16, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+            0x3800, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+            0x3800, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+            0x3800, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+        ]);
+        let r = _mm512_fmul_pch(a, b);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0xbe00_u16, 0x4000, 0x4800, 0x4400, 0x4200, 0x3c00, 0xc200, 0x4300,
+            0xbe00, 0x4000, 0x4800, 0x4400, 0x4200, 0x3c00, 0xc200, 0x4300,
+            0xbe00, 0x4000, 0x4800, 0x4400, 0x4200, 0x3c00, 0xc200, 0x4300,
+            0xbe00, 0x4000, 0x4800, 0x4400, 0x4200, 0x3c00, 0xc200, 0x4300,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_fmul_pch() {
+        // `a` = [1., 2., 3., -1., 0.5, 0.5, -2., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+            0x3c00, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+            0x3c00, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+            0x3c00, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+        ]);
+        // `b` = [0.5, 1., 2., 2., 4., -2., 1., 0.25] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x3800_u16, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+            0x3800, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+            0x3800, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+            0x3800, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+        ]);
+        let src = _mm512_set1_ph(0x7e00);
+        let r = _mm512_mask_fmul_pch(src, 0, a, b);
+        assert_eq_m512h(r, src);
+        let r = _mm512_mask_fmul_pch(src, 0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0xbe00_u16, 0x4000, 0x7e00, 0x7e00, 0x4200, 0x3c00, 0x7e00, 0x7e00,
+            0xbe00, 0x4000, 0x4800, 0x4400, 0x7e00, 0x7e00, 0x7e00, 0x7e00,
+            0x7e00, 0x7e00, 0x4800, 0x4400, 0x7e00, 0x7e00, 0xc200, 0x4300,
+            0x7e00, 0x7e00, 0x7e00, 0x7e00, 0x4200, 0x3c00, 0xc200, 0x4300,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_fmul_pch() {
+        // `a` = [1., 2., 3., -1., 0.5, 0.5, -2., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+            0x3c00, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+            0x3c00, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+            0x3c00, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+        ]);
+        // `b` = [0.5, 1., 2., 2., 4., -2., 1., 0.25] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x3800_u16, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+            0x3800, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+            0x3800, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+            0x3800, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+        ]);
+        let r = _mm512_maskz_fmul_pch(0, a, b);
+        assert_eq_m512h(r, _mm512_setzero_ph());
+        let r = _mm512_maskz_fmul_pch(0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0xbe00_u16, 0x4000, 0x0000, 0x0000, 0x4200, 0x3c00, 0x0000, 0x0000,
+            0xbe00, 0x4000, 0x4800, 0x4400, 0x0000, 0x0000, 0x0000, 0x0000,
+            0x0000, 0x0000, 0x4800, 0x4400, 0x0000, 0x0000, 0xc200, 0x4300,
+            0x0000, 0x0000, 0x0000, 0x0000, 0x4200, 0x3c00, 0xc200, 0x4300,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_fmul_pch() {
+        // `a` = [1., 2., 3., -1., 0.5, 0.5, -2., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+            0x3c00, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+        ]);
+        // `b` = [0.5, 1., 2., 2., 4., -2., 1., 0.25] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x3800_u16, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+            0x3800, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+        ]);
+        let r = _mm256_fmul_pch(a, b);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0xbe00_u16, 0x4000, 0x4800, 0x4400, 0x4200, 0x3c00, 0xc200, 0x4300,
+            0xbe00, 0x4000, 0x4800, 0x4400, 0x4200, 0x3c00, 0xc200, 0x4300,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_fmul_pch() {
+        // `a` = [1., 2., 3., -1., 0.5, 0.5, -2., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+            0x3c00, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+        ]);
+        // `b` = [0.5, 1., 2., 2., 4., -2., 1., 0.25] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x3800_u16, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+            0x3800, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+        ]);
+        let src = _mm256_set1_ph(0x7e00);
+        let r = _mm256_mask_fmul_pch(src, 0, a, b);
+        assert_eq_m256h(r, src);
+        let r = _mm256_mask_fmul_pch(src, 0b00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0xbe00_u16, 0x4000, 0x7e00, 0x7e00, 0x4200, 0x3c00, 0x7e00, 0x7e00,
+            0xbe00, 0x4000, 0x4800, 0x4400, 0x7e00, 0x7e00, 0x7e00, 0x7e00,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_fmul_pch() {
+        // `a` = [1., 2., 3., -1., 0.5, 0.5, -2., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+            0x3c00, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+        ]);
+        // `b` = [0.5, 1., 2., 2., 4., -2., 1., 0.25] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x3800_u16, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+            0x3800, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+        ]);
+        let r = _mm256_maskz_fmul_pch(0, a, b);
+        assert_eq_m256h(r, _mm256_setzero_ph());
+        let r = _mm256_maskz_fmul_pch(0b00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0xbe00_u16, 0x4000, 0x0000, 0x0000, 0x4200, 0x3c00, 0x0000, 0x0000,
+            0xbe00, 0x4000, 0x4800, 0x4400, 0x0000, 0x0000, 0x0000, 0x0000,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_fmul_pch() {
+        // `a` = [1., 2., 3., -1., 0.5, 0.5, -2., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+        ]);
+        // `b` = [0.5, 1., 2., 2., 4., -2., 1., 0.25]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x3800_u16, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+        ]);
+        let r = _mm_fmul_pch(a, b);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0xbe00_u16, 0x4000, 0x4800, 0x4400, 0x4200, 0x3c00, 0xc200, 0x4300,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_fmul_pch() {
+        // `a` = [1., 2., 3., -1., 0.5, 0.5, -2., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+        ]);
+        // `b` = [0.5, 1., 2., 2., 4., -2., 1., 0.25]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x3800_u16, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+        ]);
+        let src = _mm_set1_ph(0x7e00);
+        let r = _mm_mask_fmul_pch(src, 0, a, b);
+        assert_eq_m128h(r, src);
+        let r = _mm_mask_fmul_pch(src, 0b00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0xbe00_u16, 0x4000, 0x7e00, 0x7e00, 0x4200, 0x3c00, 0x7e00, 0x7e00,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_fmul_pch() {
+        // `a` = [1., 2., 3., -1., 0.5, 0.5, -2., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+        ]);
+        // `b` = [0.5, 1., 2., 2., 4., -2., 1., 0.25]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x3800_u16, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+        ]);
+        let r = _mm_maskz_fmul_pch(0, a, b);
+        assert_eq_m128h(r, _mm_setzero_ph());
+        let r = _mm_maskz_fmul_pch(0b00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0xbe00_u16, 0x4000, 0x0000, 0x0000, 0x4200, 0x3c00, 0x0000, 0x0000,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_fcmul_pch() {
+        // `a` = [1., 2., 3., -1., 0.5, 0.5, -2., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+            0x3c00, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+            0x3c00, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+            0x3c00, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+        ]);
+        // `b` = [0.5, 1., 2., 2., 4., -2., 1., 0.25] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x3800_u16, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+            0x3800, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+            0x3800, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+            0x3800, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+        ]);
+        let r = _mm512_fcmul_pch(a, b);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x4100_u16, 0x0000, 0x4400, 0xc800, 0x3c00, 0x4200, 0xbc00, 0x4480,
+            0x4100, 0x0000, 0x4400, 0xc800, 0x3c00, 0x4200, 0xbc00, 0x4480,
+            0x4100, 0x0000, 0x4400, 0xc800, 0x3c00, 0x4200, 0xbc00, 0x4480,
+            0x4100, 0x0000, 0x4400, 0xc800, 0x3c00, 0x4200, 0xbc00, 0x4480,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_mask_fcmul_pch() {
+        // `a` = [1., 2., 3., -1., 0.5, 0.5, -2., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+            0x3c00, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+            0x3c00, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+            0x3c00, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+        ]);
+        // `b` = [0.5, 1., 2., 2., 4., -2., 1., 0.25] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x3800_u16, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+            0x3800, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+            0x3800, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+            0x3800, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+        ]);
+        let src = _mm512_set1_ph(0x7e00);
+        let r = _mm512_mask_fcmul_pch(src, 0, a, b);
+        assert_eq_m512h(r, src);
+        let r = _mm512_mask_fcmul_pch(src, 0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x4100_u16, 0x0000, 0x7e00, 0x7e00, 0x3c00, 0x4200, 0x7e00, 0x7e00,
+            0x4100, 0x0000, 0x4400, 0xc800, 0x7e00, 0x7e00, 0x7e00, 0x7e00,
+            0x7e00, 0x7e00, 0x4400, 0xc800, 0x7e00, 0x7e00, 0xbc00, 0x4480,
+            0x7e00, 0x7e00, 0x7e00, 0x7e00, 0x3c00, 0x4200, 0xbc00, 0x4480,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16")]
+    unsafe fn test_mm512_maskz_fcmul_pch() {
+        // `a` = [1., 2., 3., -1., 0.5, 0.5, -2., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m512h = transmute([
+            0x3c00_u16, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+            0x3c00, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+            0x3c00, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+            0x3c00, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+        ]);
+        // `b` = [0.5, 1., 2., 2., 4., -2., 1., 0.25] repeated
+        #[rustfmt::skip]
+        let b: __m512h = transmute([
+            0x3800_u16, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+            0x3800, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+            0x3800, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+            0x3800, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+        ]);
+        let r = _mm512_maskz_fcmul_pch(0, a, b);
+        assert_eq_m512h(r, _mm512_setzero_ph());
+        let r = _mm512_maskz_fcmul_pch(0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m512h = transmute([
+            0x4100_u16, 0x0000, 0x0000, 0x0000, 0x3c00, 0x4200, 0x0000, 0x0000,
+            0x4100, 0x0000, 0x4400, 0xc800, 0x0000, 0x0000, 0x0000, 0x0000,
+            0x0000, 0x0000, 0x4400, 0xc800, 0x0000, 0x0000, 0xbc00, 0x4480,
+            0x0000, 0x0000, 0x0000, 0x0000, 0x3c00, 0x4200, 0xbc00, 0x4480,
+        ]);
+        assert_eq_m512h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_fcmul_pch() {
+        // `a` = [1., 2., 3., -1., 0.5, 0.5, -2., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+            0x3c00, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+        ]);
+        // `b` = [0.5, 1., 2., 2., 4., -2., 1., 0.25] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x3800_u16, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+            0x3800, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+        ]);
+        let r = _mm256_fcmul_pch(a, b);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x4100_u16, 0x0000, 0x4400, 0xc800, 0x3c00, 0x4200, 0xbc00, 0x4480,
+            0x4100, 0x0000, 0x4400, 0xc800, 0x3c00, 0x4200, 0xbc00, 0x4480,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_mask_fcmul_pch() {
+        // `a` = [1., 2., 3., -1., 0.5, 0.5, -2., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+            0x3c00, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+        ]);
+        // `b` = [0.5, 1., 2., 2., 4., -2., 1., 0.25] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x3800_u16, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+            0x3800, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+        ]);
+        let src = _mm256_set1_ph(0x7e00);
+        let r = _mm256_mask_fcmul_pch(src, 0, a, b);
+        assert_eq_m256h(r, src);
+        let r = _mm256_mask_fcmul_pch(src, 0b00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x4100_u16, 0x0000, 0x7e00, 0x7e00, 0x3c00, 0x4200, 0x7e00, 0x7e00,
+            0x4100, 0x0000, 0x4400, 0xc800, 0x7e00, 0x7e00, 0x7e00, 0x7e00,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm256_maskz_fcmul_pch() {
+        // `a` = [1., 2., 3., -1., 0.5, 0.5, -2., 4.] repeated
+        #[rustfmt::skip]
+        let a: __m256h = transmute([
+            0x3c00_u16, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+            0x3c00, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+        ]);
+        // `b` = [0.5, 1., 2., 2., 4., -2., 1., 0.25] repeated
+        #[rustfmt::skip]
+        let b: __m256h = transmute([
+            0x3800_u16, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+            0x3800, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+        ]);
+        let r = _mm256_maskz_fcmul_pch(0, a, b);
+        assert_eq_m256h(r, _mm256_setzero_ph());
+        let r = _mm256_maskz_fcmul_pch(0b00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m256h = transmute([
+            0x4100_u16, 0x0000, 0x0000, 0x0000, 0x3c00, 0x4200, 0x0000, 0x0000,
+            0x4100, 0x0000, 0x4400, 0xc800, 0x0000, 0x0000, 0x0000, 0x0000,
+        ]);
+        assert_eq_m256h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_fcmul_pch() {
+        // `a` = [1., 2., 3., -1., 0.5, 0.5, -2., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+        ]);
+        // `b` = [0.5, 1., 2., 2., 4., -2., 1., 0.25]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x3800_u16, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+        ]);
+        let r = _mm_fcmul_pch(a, b);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x4100_u16, 0x0000, 0x4400, 0xc800, 0x3c00, 0x4200, 0xbc00, 0x4480,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_mask_fcmul_pch() {
+        // `a` = [1., 2., 3., -1., 0.5, 0.5, -2., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+        ]);
+        // `b` = [0.5, 1., 2., 2., 4., -2., 1., 0.25]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x3800_u16, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+        ]);
+        let src = _mm_set1_ph(0x7e00);
+        let r = _mm_mask_fcmul_pch(src, 0, a, b);
+        assert_eq_m128h(r, src);
+        let r = _mm_mask_fcmul_pch(src, 0b00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x4100_u16, 0x0000, 0x7e00, 0x7e00, 0x3c00, 0x4200, 0x7e00, 0x7e00,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+
+    #[simd_test(enable = "avx512fp16,avx512vl")]
+    unsafe fn test_mm_maskz_fcmul_pch() {
+        // `a` = [1., 2., 3., -1., 0.5, 0.5, -2., 4.]
+        #[rustfmt::skip]
+        let a: __m128h = transmute([
+            0x3c00_u16, 0x4000, 0x4200, 0xbc00, 0x3800, 0x3800, 0xc000, 0x4400,
+        ]);
+        // `b` = [0.5, 1., 2., 2., 4., -2., 1., 0.25]
+        #[rustfmt::skip]
+        let b: __m128h = transmute([
+            0x3800_u16, 0x3c00, 0x4000, 0x4000, 0x4400, 0xc000, 0x3c00, 0x3400,
+        ]);
+        let r = _mm_maskz_fcmul_pch(0, a, b);
+        assert_eq_m128h(r, _mm_setzero_ph());
+        let r = _mm_maskz_fcmul_pch(0b00110101, a, b);
+        #[rustfmt::skip]
+        let e: __m128h = transmute([
+            0x4100_u16, 0x0000, 0x0000, 0x0000, 0x3c00, 0x4200, 0x0000, 0x0000,
+        ]);
+        assert_eq_m128h(r, e);
+    }
+}
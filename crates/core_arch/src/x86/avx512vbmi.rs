@@ -0,0 +1,1182 @@
+use crate::{
+    core_arch::{simd::*, simd_llvm::*, x86::*},
+    mem::transmute,
+};
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Shuffles 8-bit integers in `a` across lanes using the corresponding index in
+/// `idx`. The results are stored in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_permutexvar_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi")]
+#[cfg_attr(test, assert_instr(vpermb))]
+pub unsafe fn _mm512_permutexvar_epi8(idx: __m512i, a: __m512i) -> __m512i {
+    transmute(vpermb_512(a.as_i8x64(), idx.as_i8x64()))
+}
+
+/// Shuffles 8-bit integers in `a` across lanes using the corresponding index in
+/// `idx`. The results are stored in `dst` using writemask `k` (elements are
+/// copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_permutexvar_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi")]
+#[cfg_attr(test, assert_instr(vpermb))]
+pub unsafe fn _mm512_mask_permutexvar_epi8(
+    src: __m512i,
+    k: __mmask64,
+    idx: __m512i,
+    a: __m512i,
+) -> __m512i {
+    let r = _mm512_permutexvar_epi8(idx, a).as_i8x64();
+    transmute(simd_select_bitmask(k, r, src.as_i8x64()))
+}
+
+/// Shuffles 8-bit integers in `a` across lanes using the corresponding index in
+/// `idx`. The results are stored in `dst` using zeromask `k` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_permutexvar_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi")]
+#[cfg_attr(test, assert_instr(vpermb))]
+pub unsafe fn _mm512_maskz_permutexvar_epi8(k: __mmask64, idx: __m512i, a: __m512i) -> __m512i {
+    let r = _mm512_permutexvar_epi8(idx, a).as_i8x64();
+    let zero = _mm512_setzero_si512().as_i8x64();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Shuffles 8-bit integers in `a` across lanes using the corresponding index in
+/// `idx`. The results are stored in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_permutexvar_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+#[cfg_attr(test, assert_instr(vpermb))]
+pub unsafe fn _mm256_permutexvar_epi8(idx: __m256i, a: __m256i) -> __m256i {
+    transmute(vpermb_256(a.as_i8x32(), idx.as_i8x32()))
+}
+
+/// Shuffles 8-bit integers in `a` across lanes using the corresponding index in
+/// `idx`. The results are stored in `dst` using writemask `k` (elements are
+/// copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_permutexvar_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+#[cfg_attr(test, assert_instr(vpermb))]
+pub unsafe fn _mm256_mask_permutexvar_epi8(
+    src: __m256i,
+    k: __mmask32,
+    idx: __m256i,
+    a: __m256i,
+) -> __m256i {
+    let r = _mm256_permutexvar_epi8(idx, a).as_i8x32();
+    transmute(simd_select_bitmask(k, r, src.as_i8x32()))
+}
+
+/// Shuffles 8-bit integers in `a` across lanes using the corresponding index in
+/// `idx`. The results are stored in `dst` using zeromask `k` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_permutexvar_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+#[cfg_attr(test, assert_instr(vpermb))]
+pub unsafe fn _mm256_maskz_permutexvar_epi8(k: __mmask32, idx: __m256i, a: __m256i) -> __m256i {
+    let r = _mm256_permutexvar_epi8(idx, a).as_i8x32();
+    let zero = _mm256_setzero_si256().as_i8x32();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Shuffles 8-bit integers in `a` across lanes using the corresponding index in
+/// `idx`. The results are stored in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_permutexvar_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+#[cfg_attr(test, assert_instr(vpermb))]
+pub unsafe fn _mm_permutexvar_epi8(idx: __m128i, a: __m128i) -> __m128i {
+    transmute(vpermb_128(a.as_i8x16(), idx.as_i8x16()))
+}
+
+/// Shuffles 8-bit integers in `a` across lanes using the corresponding index in
+/// `idx`. The results are stored in `dst` using writemask `k` (elements are
+/// copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_permutexvar_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+#[cfg_attr(test, assert_instr(vpermb))]
+pub unsafe fn _mm_mask_permutexvar_epi8(
+    src: __m128i,
+    k: __mmask16,
+    idx: __m128i,
+    a: __m128i,
+) -> __m128i {
+    let r = _mm_permutexvar_epi8(idx, a).as_i8x16();
+    transmute(simd_select_bitmask(k, r, src.as_i8x16()))
+}
+
+/// Shuffles 8-bit integers in `a` across lanes using the corresponding index in
+/// `idx`. The results are stored in `dst` using zeromask `k` (elements are
+/// zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_permutexvar_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+#[cfg_attr(test, assert_instr(vpermb))]
+pub unsafe fn _mm_maskz_permutexvar_epi8(k: __mmask16, idx: __m128i, a: __m128i) -> __m128i {
+    let r = _mm_permutexvar_epi8(idx, a).as_i8x16();
+    let zero = _mm_setzero_si128().as_i8x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Shuffles 8-bit integers in `a` and `b` across lanes using the corresponding
+/// selector and index in `idx`. The results are stored in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_permutex2var_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi")]
+#[cfg_attr(test, assert_instr(vperm))]
+pub unsafe fn _mm512_permutex2var_epi8(a: __m512i, idx: __m512i, b: __m512i) -> __m512i {
+    transmute(vpermi2b_512(a.as_i8x64(), idx.as_i8x64(), b.as_i8x64()))
+}
+
+/// Shuffles 8-bit integers in `a` and `b` across lanes using the corresponding
+/// selector and index in `idx`. The results are stored in `dst` using writemask
+/// `k` (elements are copied from `a` when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_permutex2var_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi")]
+#[cfg_attr(test, assert_instr(vpermt2b))]
+pub unsafe fn _mm512_mask_permutex2var_epi8(
+    a: __m512i,
+    k: __mmask64,
+    idx: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let r = _mm512_permutex2var_epi8(a, idx, b).as_i8x64();
+    transmute(simd_select_bitmask(k, r, a.as_i8x64()))
+}
+
+/// Shuffles 8-bit integers in `a` and `b` across lanes using the corresponding
+/// selector and index in `idx`. The results are stored in `dst` using writemask
+/// `k` (elements are copied from `idx` when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask2_permutex2var_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi")]
+#[cfg_attr(test, assert_instr(vpermi2b))]
+pub unsafe fn _mm512_mask2_permutex2var_epi8(
+    a: __m512i,
+    idx: __m512i,
+    k: __mmask64,
+    b: __m512i,
+) -> __m512i {
+    let r = _mm512_permutex2var_epi8(a, idx, b).as_i8x64();
+    transmute(simd_select_bitmask(k, r, idx.as_i8x64()))
+}
+
+/// Shuffles 8-bit integers in `a` and `b` across lanes using the corresponding
+/// selector and index in `idx`. The results are stored in `dst` using zeromask
+/// `k` (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_permutex2var_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi")]
+#[cfg_attr(test, assert_instr(vperm))]
+pub unsafe fn _mm512_maskz_permutex2var_epi8(
+    k: __mmask64,
+    a: __m512i,
+    idx: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let r = _mm512_permutex2var_epi8(a, idx, b).as_i8x64();
+    let zero = _mm512_setzero_si512().as_i8x64();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Shuffles 8-bit integers in `a` and `b` across lanes using the corresponding
+/// selector and index in `idx`. The results are stored in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_permutex2var_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+#[cfg_attr(test, assert_instr(vperm))]
+pub unsafe fn _mm256_permutex2var_epi8(a: __m256i, idx: __m256i, b: __m256i) -> __m256i {
+    transmute(vpermi2b_256(a.as_i8x32(), idx.as_i8x32(), b.as_i8x32()))
+}
+
+/// Shuffles 8-bit integers in `a` and `b` across lanes using the corresponding
+/// selector and index in `idx`. The results are stored in `dst` using writemask
+/// `k` (elements are copied from `a` when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_permutex2var_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+#[cfg_attr(test, assert_instr(vpermt2b))]
+pub unsafe fn _mm256_mask_permutex2var_epi8(
+    a: __m256i,
+    k: __mmask32,
+    idx: __m256i,
+    b: __m256i,
+) -> __m256i {
+    let r = _mm256_permutex2var_epi8(a, idx, b).as_i8x32();
+    transmute(simd_select_bitmask(k, r, a.as_i8x32()))
+}
+
+/// Shuffles 8-bit integers in `a` and `b` across lanes using the corresponding
+/// selector and index in `idx`. The results are stored in `dst` using writemask
+/// `k` (elements are copied from `idx` when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask2_permutex2var_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+#[cfg_attr(test, assert_instr(vpermi2b))]
+pub unsafe fn _mm256_mask2_permutex2var_epi8(
+    a: __m256i,
+    idx: __m256i,
+    k: __mmask32,
+    b: __m256i,
+) -> __m256i {
+    let r = _mm256_permutex2var_epi8(a, idx, b).as_i8x32();
+    transmute(simd_select_bitmask(k, r, idx.as_i8x32()))
+}
+
+/// Shuffles 8-bit integers in `a` and `b` across lanes using the corresponding
+/// selector and index in `idx`. The results are stored in `dst` using zeromask
+/// `k` (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_permutex2var_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+#[cfg_attr(test, assert_instr(vperm))]
+pub unsafe fn _mm256_maskz_permutex2var_epi8(
+    k: __mmask32,
+    a: __m256i,
+    idx: __m256i,
+    b: __m256i,
+) -> __m256i {
+    let r = _mm256_permutex2var_epi8(a, idx, b).as_i8x32();
+    let zero = _mm256_setzero_si256().as_i8x32();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Shuffles 8-bit integers in `a` and `b` across lanes using the corresponding
+/// selector and index in `idx`. The results are stored in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_permutex2var_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+#[cfg_attr(test, assert_instr(vperm))]
+pub unsafe fn _mm_permutex2var_epi8(a: __m128i, idx: __m128i, b: __m128i) -> __m128i {
+    transmute(vpermi2b_128(a.as_i8x16(), idx.as_i8x16(), b.as_i8x16()))
+}
+
+/// Shuffles 8-bit integers in `a` and `b` across lanes using the corresponding
+/// selector and index in `idx`. The results are stored in `dst` using writemask
+/// `k` (elements are copied from `a` when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_permutex2var_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+#[cfg_attr(test, assert_instr(vpermt2b))]
+pub unsafe fn _mm_mask_permutex2var_epi8(
+    a: __m128i,
+    k: __mmask16,
+    idx: __m128i,
+    b: __m128i,
+) -> __m128i {
+    let r = _mm_permutex2var_epi8(a, idx, b).as_i8x16();
+    transmute(simd_select_bitmask(k, r, a.as_i8x16()))
+}
+
+/// Shuffles 8-bit integers in `a` and `b` across lanes using the corresponding
+/// selector and index in `idx`. The results are stored in `dst` using writemask
+/// `k` (elements are copied from `idx` when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask2_permutex2var_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+#[cfg_attr(test, assert_instr(vpermi2b))]
+pub unsafe fn _mm_mask2_permutex2var_epi8(
+    a: __m128i,
+    idx: __m128i,
+    k: __mmask16,
+    b: __m128i,
+) -> __m128i {
+    let r = _mm_permutex2var_epi8(a, idx, b).as_i8x16();
+    transmute(simd_select_bitmask(k, r, idx.as_i8x16()))
+}
+
+/// Shuffles 8-bit integers in `a` and `b` across lanes using the corresponding
+/// selector and index in `idx`. The results are stored in `dst` using zeromask
+/// `k` (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_permutex2var_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+#[cfg_attr(test, assert_instr(vperm))]
+pub unsafe fn _mm_maskz_permutex2var_epi8(
+    k: __mmask16,
+    a: __m128i,
+    idx: __m128i,
+    b: __m128i,
+) -> __m128i {
+    let r = _mm_permutex2var_epi8(a, idx, b).as_i8x16();
+    let zero = _mm_setzero_si128().as_i8x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// For each 64-bit element in `b`, selects 8 unaligned bytes using a
+/// byte-granular shift control within the corresponding 64-bit element of `a`,
+/// and stores the 8 assembled bytes to the corresponding 64-bit element of
+/// `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_multishift_epi64_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi")]
+#[cfg_attr(test, assert_instr(vpmultishiftqb))]
+pub unsafe fn _mm512_multishift_epi64_epi8(a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpmultishiftqb_512(a.as_i8x64(), b.as_i8x64()))
+}
+
+/// For each 64-bit element in `b`, selects 8 unaligned bytes using a
+/// byte-granular shift control within the corresponding 64-bit element of `a`,
+/// and stores the 8 assembled bytes to the corresponding 64-bit element of
+/// `dst` using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_multishift_epi64_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi")]
+#[cfg_attr(test, assert_instr(vpmultishiftqb))]
+pub unsafe fn _mm512_mask_multishift_epi64_epi8(
+    src: __m512i,
+    k: __mmask64,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let r = _mm512_multishift_epi64_epi8(a, b).as_i8x64();
+    transmute(simd_select_bitmask(k, r, src.as_i8x64()))
+}
+
+/// For each 64-bit element in `b`, selects 8 unaligned bytes using a
+/// byte-granular shift control within the corresponding 64-bit element of `a`,
+/// and stores the 8 assembled bytes to the corresponding 64-bit element of
+/// `dst` using zeromask `k` (elements are zeroed out when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_multishift_epi64_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi")]
+#[cfg_attr(test, assert_instr(vpmultishiftqb))]
+pub unsafe fn _mm512_maskz_multishift_epi64_epi8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    let r = _mm512_multishift_epi64_epi8(a, b).as_i8x64();
+    let zero = _mm512_setzero_si512().as_i8x64();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// For each 64-bit element in `b`, selects 8 unaligned bytes using a
+/// byte-granular shift control within the corresponding 64-bit element of `a`,
+/// and stores the 8 assembled bytes to the corresponding 64-bit element of
+/// `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_multishift_epi64_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmultishiftqb))]
+pub unsafe fn _mm256_multishift_epi64_epi8(a: __m256i, b: __m256i) -> __m256i {
+    transmute(vpmultishiftqb_256(a.as_i8x32(), b.as_i8x32()))
+}
+
+/// For each 64-bit element in `b`, selects 8 unaligned bytes using a
+/// byte-granular shift control within the corresponding 64-bit element of `a`,
+/// and stores the 8 assembled bytes to the corresponding 64-bit element of
+/// `dst` using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_multishift_epi64_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmultishiftqb))]
+pub unsafe fn _mm256_mask_multishift_epi64_epi8(
+    src: __m256i,
+    k: __mmask32,
+    a: __m256i,
+    b: __m256i,
+) -> __m256i {
+    let r = _mm256_multishift_epi64_epi8(a, b).as_i8x32();
+    transmute(simd_select_bitmask(k, r, src.as_i8x32()))
+}
+
+/// For each 64-bit element in `b`, selects 8 unaligned bytes using a
+/// byte-granular shift control within the corresponding 64-bit element of `a`,
+/// and stores the 8 assembled bytes to the corresponding 64-bit element of
+/// `dst` using zeromask `k` (elements are zeroed out when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_multishift_epi64_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmultishiftqb))]
+pub unsafe fn _mm256_maskz_multishift_epi64_epi8(k: __mmask32, a: __m256i, b: __m256i) -> __m256i {
+    let r = _mm256_multishift_epi64_epi8(a, b).as_i8x32();
+    let zero = _mm256_setzero_si256().as_i8x32();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// For each 64-bit element in `b`, selects 8 unaligned bytes using a
+/// byte-granular shift control within the corresponding 64-bit element of `a`,
+/// and stores the 8 assembled bytes to the corresponding 64-bit element of
+/// `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_multishift_epi64_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmultishiftqb))]
+pub unsafe fn _mm_multishift_epi64_epi8(a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpmultishiftqb_128(a.as_i8x16(), b.as_i8x16()))
+}
+
+/// For each 64-bit element in `b`, selects 8 unaligned bytes using a
+/// byte-granular shift control within the corresponding 64-bit element of `a`,
+/// and stores the 8 assembled bytes to the corresponding 64-bit element of
+/// `dst` using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_multishift_epi64_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmultishiftqb))]
+pub unsafe fn _mm_mask_multishift_epi64_epi8(
+    src: __m128i,
+    k: __mmask16,
+    a: __m128i,
+    b: __m128i,
+) -> __m128i {
+    let r = _mm_multishift_epi64_epi8(a, b).as_i8x16();
+    transmute(simd_select_bitmask(k, r, src.as_i8x16()))
+}
+
+/// For each 64-bit element in `b`, selects 8 unaligned bytes using a
+/// byte-granular shift control within the corresponding 64-bit element of `a`,
+/// and stores the 8 assembled bytes to the corresponding 64-bit element of
+/// `dst` using zeromask `k` (elements are zeroed out when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_multishift_epi64_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmultishiftqb))]
+pub unsafe fn _mm_maskz_multishift_epi64_epi8(k: __mmask16, a: __m128i, b: __m128i) -> __m128i {
+    let r = _mm_multishift_epi64_epi8(a, b).as_i8x16();
+    let zero = _mm_setzero_si128().as_i8x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.avx512.permvar.qi.512"]
+    fn vpermb_512(a: i8x64, idx: i8x64) -> i8x64;
+    #[link_name = "llvm.x86.avx512.permvar.qi.256"]
+    fn vpermb_256(a: i8x32, idx: i8x32) -> i8x32;
+    #[link_name = "llvm.x86.avx512.permvar.qi.128"]
+    fn vpermb_128(a: i8x16, idx: i8x16) -> i8x16;
+    #[link_name = "llvm.x86.avx512.vpermi2var.qi.512"]
+    fn vpermi2b_512(a: i8x64, idx: i8x64, b: i8x64) -> i8x64;
+    #[link_name = "llvm.x86.avx512.vpermi2var.qi.256"]
+    fn vpermi2b_256(a: i8x32, idx: i8x32, b: i8x32) -> i8x32;
+    #[link_name = "llvm.x86.avx512.vpermi2var.qi.128"]
+    fn vpermi2b_128(a: i8x16, idx: i8x16, b: i8x16) -> i8x16;
+    #[link_name = "llvm.x86.avx512.pmultishift.qb.512"]
+    fn vpmultishiftqb_512(a: i8x64, b: i8x64) -> i8x64;
+    #[link_name = "llvm.x86.avx512.pmultishift.qb.256"]
+    fn vpmultishiftqb_256(a: i8x32, b: i8x32) -> i8x32;
+    #[link_name = "llvm.x86.avx512.pmultishift.qb.128"]
+    fn vpmultishiftqb_128(a: i8x16, b: i8x16) -> i8x16;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use stdsimd_test::simd_test;
+
+    #[simd_test(enable = "avx512vbmi")]
+    unsafe fn test_mm512_permutexvar_epi8() {
+        #[rustfmt::skip]
+        let idx = _mm512_set_epi8(
+            62, 57, 52, 47, 42, 37, 32, 27, 22, 17, 12, 7, 2, 125, 120, 115,
+            110, 105, 100, 95, 90, 85, 80, 75, 70, 65, 60, 55, 50, 45, 40, 35,
+            30, 25, 20, 15, 10, 5, 0, 123, 118, 113, 108, 103, 98, 93, 88, 83,
+            78, 73, 68, 63, 58, 53, 48, 43, 38, 33, 28, 23, 18, 13, 8, 3,
+        );
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            126, 124, 122, 120, 118, 116, 114, 112, 110, 108, 106, 104, 102, 100, 98, 96,
+            94, 92, 90, 88, 86, 84, 82, 80, 78, 76, 74, 72, 70, 68, 66, 64,
+            62, 60, 58, 56, 54, 52, 50, 48, 46, 44, 42, 40, 38, 36, 34, 32,
+            30, 28, 26, 24, 22, 20, 18, 16, 14, 12, 10, 8, 6, 4, 2, 0,
+        );
+        let r = _mm512_permutexvar_epi8(idx, a);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            124, 114, 104, 94, 84, 74, 64, 54, 44, 34, 24, 14, 4, 122, 112, 102,
+            92, 82, 72, 62, 52, 42, 32, 22, 12, 2, 120, 110, 100, 90, 80, 70,
+            60, 50, 40, 30, 20, 10, 0, 118, 108, 98, 88, 78, 68, 58, 48, 38,
+            28, 18, 8, 126, 116, 106, 96, 86, 76, 66, 56, 46, 36, 26, 16, 6,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi")]
+    unsafe fn test_mm512_mask_permutexvar_epi8() {
+        #[rustfmt::skip]
+        let idx = _mm512_set_epi8(
+            62, 57, 52, 47, 42, 37, 32, 27, 22, 17, 12, 7, 2, 125, 120, 115,
+            110, 105, 100, 95, 90, 85, 80, 75, 70, 65, 60, 55, 50, 45, 40, 35,
+            30, 25, 20, 15, 10, 5, 0, 123, 118, 113, 108, 103, 98, 93, 88, 83,
+            78, 73, 68, 63, 58, 53, 48, 43, 38, 33, 28, 23, 18, 13, 8, 3,
+        );
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            126, 124, 122, 120, 118, 116, 114, 112, 110, 108, 106, 104, 102, 100, 98, 96,
+            94, 92, 90, 88, 86, 84, 82, 80, 78, 76, 74, 72, 70, 68, 66, 64,
+            62, 60, 58, 56, 54, 52, 50, 48, 46, 44, 42, 40, 38, 36, 34, 32,
+            30, 28, 26, 24, 22, 20, 18, 16, 14, 12, 10, 8, 6, 4, 2, 0,
+        );
+        let r = _mm512_mask_permutexvar_epi8(a, 0, idx, a);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_permutexvar_epi8(
+            a,
+            0b10011001_01010101_00110101_11001010_01101001_10010110_11001010_00110101,
+            idx,
+            a,
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            124, 124, 122, 94, 84, 116, 114, 54, 110, 34, 106, 14, 102, 122, 98, 102,
+            94, 92, 72, 62, 86, 42, 82, 22, 12, 2, 74, 72, 100, 68, 80, 64,
+            62, 50, 40, 56, 20, 52, 50, 118, 108, 44, 42, 78, 38, 58, 48, 32,
+            28, 18, 26, 24, 116, 20, 96, 16, 14, 12, 56, 46, 6, 26, 2, 6,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi")]
+    unsafe fn test_mm512_maskz_permutexvar_epi8() {
+        #[rustfmt::skip]
+        let idx = _mm512_set_epi8(
+            62, 57, 52, 47, 42, 37, 32, 27, 22, 17, 12, 7, 2, 125, 120, 115,
+            110, 105, 100, 95, 90, 85, 80, 75, 70, 65, 60, 55, 50, 45, 40, 35,
+            30, 25, 20, 15, 10, 5, 0, 123, 118, 113, 108, 103, 98, 93, 88, 83,
+            78, 73, 68, 63, 58, 53, 48, 43, 38, 33, 28, 23, 18, 13, 8, 3,
+        );
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            126, 124, 122, 120, 118, 116, 114, 112, 110, 108, 106, 104, 102, 100, 98, 96,
+            94, 92, 90, 88, 86, 84, 82, 80, 78, 76, 74, 72, 70, 68, 66, 64,
+            62, 60, 58, 56, 54, 52, 50, 48, 46, 44, 42, 40, 38, 36, 34, 32,
+            30, 28, 26, 24, 22, 20, 18, 16, 14, 12, 10, 8, 6, 4, 2, 0,
+        );
+        let r = _mm512_maskz_permutexvar_epi8(0, idx, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_permutexvar_epi8(
+            0b10011001_01010101_00110101_11001010_01101001_10010110_11001010_00110101,
+            idx,
+            a,
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            124, 0, 0, 94, 84, 0, 0, 54, 0, 34, 0, 14, 0, 122, 0, 102,
+            0, 0, 72, 62, 0, 42, 0, 22, 12, 2, 0, 0, 100, 0, 80, 0,
+            0, 50, 40, 0, 20, 0, 0, 118, 108, 0, 0, 78, 0, 58, 48, 0,
+            28, 18, 0, 0, 116, 0, 96, 0, 0, 0, 56, 46, 0, 26, 0, 6,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi,avx512vl")]
+    unsafe fn test_mm256_permutexvar_epi8() {
+        #[rustfmt::skip]
+        let idx = _mm256_setr_epi8(
+            3, 8, 13, 18, 23, 28, 33, 38, 43, 48, 53, 58, 63, 68, 73, 78,
+            83, 88, 93, 98, 103, 108, 113, 118, 123, 0, 5, 10, 15, 20, 25, 30,
+        );
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30,
+            32, 34, 36, 38, 40, 42, 44, 46, 48, 50, 52, 54, 56, 58, 60, 62,
+        );
+        let r = _mm256_permutexvar_epi8(idx, a);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            6, 16, 26, 36, 46, 56, 2, 12, 22, 32, 42, 52, 62, 8, 18, 28,
+            38, 48, 58, 4, 14, 24, 34, 44, 54, 0, 10, 20, 30, 40, 50, 60,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi,avx512vl")]
+    unsafe fn test_mm256_mask_permutexvar_epi8() {
+        #[rustfmt::skip]
+        let idx = _mm256_setr_epi8(
+            3, 8, 13, 18, 23, 28, 33, 38, 43, 48, 53, 58, 63, 68, 73, 78,
+            83, 88, 93, 98, 103, 108, 113, 118, 123, 0, 5, 10, 15, 20, 25, 30,
+        );
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30,
+            32, 34, 36, 38, 40, 42, 44, 46, 48, 50, 52, 54, 56, 58, 60, 62,
+        );
+        let r = _mm256_mask_permutexvar_epi8(a, 0, idx, a);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_permutexvar_epi8(a, 0b01101001_10010110_11001010_00110101, idx, a);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            6, 2, 26, 6, 46, 56, 12, 14, 16, 32, 20, 52, 24, 26, 18, 28,
+            32, 48, 58, 38, 14, 42, 44, 44, 54, 50, 52, 20, 56, 40, 50, 62,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi,avx512vl")]
+    unsafe fn test_mm256_maskz_permutexvar_epi8() {
+        #[rustfmt::skip]
+        let idx = _mm256_setr_epi8(
+            3, 8, 13, 18, 23, 28, 33, 38, 43, 48, 53, 58, 63, 68, 73, 78,
+            83, 88, 93, 98, 103, 108, 113, 118, 123, 0, 5, 10, 15, 20, 25, 30,
+        );
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30,
+            32, 34, 36, 38, 40, 42, 44, 46, 48, 50, 52, 54, 56, 58, 60, 62,
+        );
+        let r = _mm256_maskz_permutexvar_epi8(0, idx, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_permutexvar_epi8(0b01101001_10010110_11001010_00110101, idx, a);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            6, 0, 26, 0, 46, 56, 0, 0, 0, 32, 0, 52, 0, 0, 18, 28,
+            0, 48, 58, 0, 14, 0, 0, 44, 54, 0, 0, 20, 0, 40, 50, 0,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi,avx512vl")]
+    unsafe fn test_mm_permutexvar_epi8() {
+        let idx = _mm_setr_epi8(3, 8, 13, 18, 23, 28, 33, 38, 43, 48, 53, 58, 63, 68, 73, 78);
+        let a = _mm_setr_epi8(0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30);
+        let r = _mm_permutexvar_epi8(idx, a);
+        let e = _mm_setr_epi8(6, 16, 26, 4, 14, 24, 2, 12, 22, 0, 10, 20, 30, 8, 18, 28);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi,avx512vl")]
+    unsafe fn test_mm_mask_permutexvar_epi8() {
+        let idx = _mm_setr_epi8(3, 8, 13, 18, 23, 28, 33, 38, 43, 48, 53, 58, 63, 68, 73, 78);
+        let a = _mm_setr_epi8(0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30);
+        let r = _mm_mask_permutexvar_epi8(a, 0, idx, a);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_permutexvar_epi8(a, 0b11001010_00110101, idx, a);
+        let e = _mm_setr_epi8(6, 2, 26, 6, 14, 24, 12, 14, 16, 0, 20, 20, 24, 26, 18, 28);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi,avx512vl")]
+    unsafe fn test_mm_maskz_permutexvar_epi8() {
+        let idx = _mm_setr_epi8(3, 8, 13, 18, 23, 28, 33, 38, 43, 48, 53, 58, 63, 68, 73, 78);
+        let a = _mm_setr_epi8(0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30);
+        let r = _mm_maskz_permutexvar_epi8(0, idx, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_permutexvar_epi8(0b11001010_00110101, idx, a);
+        let e = _mm_setr_epi8(6, 0, 26, 0, 14, 24, 0, 0, 0, 0, 0, 20, 0, 0, 18, 28);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi")]
+    unsafe fn test_mm512_permutex2var_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            126, 124, 122, 120, 118, 116, 114, 112, 110, 108, 106, 104, 102, 100, 98, 96,
+            94, 92, 90, 88, 86, 84, 82, 80, 78, 76, 74, 72, 70, 68, 66, 64,
+            62, 60, 58, 56, 54, 52, 50, 48, 46, 44, 42, 40, 38, 36, 34, 32,
+            30, 28, 26, 24, 22, 20, 18, 16, 14, 12, 10, 8, 6, 4, 2, 0,
+        );
+        #[rustfmt::skip]
+        let idx = _mm512_set_epi8(
+            59, 52, 45, 38, 31, 24, 17, 10, 3, 124, 117, 110, 103, 96, 89, 82,
+            75, 68, 61, 54, 47, 40, 33, 26, 19, 12, 5, 126, 119, 112, 105, 98,
+            91, 84, 77, 70, 63, 56, 49, 42, 35, 28, 21, 14, 7, 0, 121, 114,
+            107, 100, 93, 86, 79, 72, 65, 58, 51, 44, 37, 30, 23, 16, 9, 2,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -127, -125, -123, -121, -119, -117, -115, -113, -111, -109, -107, -105, -103, -101, -99, -97,
+            -95, -93, -91, -89, -87, -85, -83, -81, -79, -77, -75, -73, -71, -69, -67, -65,
+            -63, -61, -59, -57, -55, -53, -51, -49, -47, -45, -43, -41, -39, -37, -35, -33,
+            -31, -29, -27, -25, -23, -21, -19, -17, -15, -13, -11, -9, -7, -5, -3, -1,
+        );
+        let r = _mm512_permutex2var_epi8(a, idx, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            118, 104, 90, 76, 62, 48, 34, 20, 6, -121, -107, -93, -79, -65, -51, -37,
+            -23, -9, 122, 108, 94, 80, 66, 52, 38, 24, 10, -125, -111, -97, -83, -69,
+            -55, -41, -27, -13, 126, 112, 98, 84, 70, 56, 42, 28, 14, 0, -115, -101,
+            -87, -73, -59, -45, -31, -17, -3, 116, 102, 88, 74, 60, 46, 32, 18, 4,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi")]
+    unsafe fn test_mm512_mask_permutex2var_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            126, 124, 122, 120, 118, 116, 114, 112, 110, 108, 106, 104, 102, 100, 98, 96,
+            94, 92, 90, 88, 86, 84, 82, 80, 78, 76, 74, 72, 70, 68, 66, 64,
+            62, 60, 58, 56, 54, 52, 50, 48, 46, 44, 42, 40, 38, 36, 34, 32,
+            30, 28, 26, 24, 22, 20, 18, 16, 14, 12, 10, 8, 6, 4, 2, 0,
+        );
+        #[rustfmt::skip]
+        let idx = _mm512_set_epi8(
+            59, 52, 45, 38, 31, 24, 17, 10, 3, 124, 117, 110, 103, 96, 89, 82,
+            75, 68, 61, 54, 47, 40, 33, 26, 19, 12, 5, 126, 119, 112, 105, 98,
+            91, 84, 77, 70, 63, 56, 49, 42, 35, 28, 21, 14, 7, 0, 121, 114,
+            107, 100, 93, 86, 79, 72, 65, 58, 51, 44, 37, 30, 23, 16, 9, 2,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -127, -125, -123, -121, -119, -117, -115, -113, -111, -109, -107, -105, -103, -101, -99, -97,
+            -95, -93, -91, -89, -87, -85, -83, -81, -79, -77, -75, -73, -71, -69, -67, -65,
+            -63, -61, -59, -57, -55, -53, -51, -49, -47, -45, -43, -41, -39, -37, -35, -33,
+            -31, -29, -27, -25, -23, -21, -19, -17, -15, -13, -11, -9, -7, -5, -3, -1,
+        );
+        let r = _mm512_mask_permutex2var_epi8(a, 0, idx, b);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_permutex2var_epi8(
+            a,
+            0b10011001_01010101_00110101_11001010_01101001_10010110_11001010_00110101,
+            idx,
+            b,
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            118, 124, 122, 76, 62, 116, 114, 20, 110, -121, 106, -93, 102, -65, 98, -37,
+            94, 92, 122, 108, 86, 80, 82, 52, 38, 24, 74, 72, -111, 68, -83, 64,
+            62, -41, -27, 56, 126, 52, 50, 84, 70, 44, 42, 28, 38, 0, -115, 32,
+            -87, -73, 26, 24, -31, 20, -3, 16, 14, 12, 74, 60, 6, 32, 2, 4,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi")]
+    unsafe fn test_mm512_mask2_permutex2var_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            126, 124, 122, 120, 118, 116, 114, 112, 110, 108, 106, 104, 102, 100, 98, 96,
+            94, 92, 90, 88, 86, 84, 82, 80, 78, 76, 74, 72, 70, 68, 66, 64,
+            62, 60, 58, 56, 54, 52, 50, 48, 46, 44, 42, 40, 38, 36, 34, 32,
+            30, 28, 26, 24, 22, 20, 18, 16, 14, 12, 10, 8, 6, 4, 2, 0,
+        );
+        #[rustfmt::skip]
+        let idx = _mm512_set_epi8(
+            59, 52, 45, 38, 31, 24, 17, 10, 3, 124, 117, 110, 103, 96, 89, 82,
+            75, 68, 61, 54, 47, 40, 33, 26, 19, 12, 5, 126, 119, 112, 105, 98,
+            91, 84, 77, 70, 63, 56, 49, 42, 35, 28, 21, 14, 7, 0, 121, 114,
+            107, 100, 93, 86, 79, 72, 65, 58, 51, 44, 37, 30, 23, 16, 9, 2,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -127, -125, -123, -121, -119, -117, -115, -113, -111, -109, -107, -105, -103, -101, -99, -97,
+            -95, -93, -91, -89, -87, -85, -83, -81, -79, -77, -75, -73, -71, -69, -67, -65,
+            -63, -61, -59, -57, -55, -53, -51, -49, -47, -45, -43, -41, -39, -37, -35, -33,
+            -31, -29, -27, -25, -23, -21, -19, -17, -15, -13, -11, -9, -7, -5, -3, -1,
+        );
+        let r = _mm512_mask2_permutex2var_epi8(a, idx, 0, b);
+        assert_eq_m512i(r, idx);
+        let r = _mm512_mask2_permutex2var_epi8(
+            a,
+            idx,
+            0b10011001_01010101_00110101_11001010_01101001_10010110_11001010_00110101,
+            b,
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            118, 52, 45, 76, 62, 24, 17, 20, 3, -121, 117, -93, 103, -65, 89, -37,
+            75, 68, 122, 108, 47, 80, 33, 52, 38, 24, 5, 126, -111, 112, -83, 98,
+            91, -41, -27, 70, 126, 56, 49, 84, 70, 28, 21, 28, 7, 0, -115, 114,
+            -87, -73, 93, 86, -31, 72, -3, 58, 51, 44, 74, 60, 23, 32, 9, 4,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi")]
+    unsafe fn test_mm512_maskz_permutex2var_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            126, 124, 122, 120, 118, 116, 114, 112, 110, 108, 106, 104, 102, 100, 98, 96,
+            94, 92, 90, 88, 86, 84, 82, 80, 78, 76, 74, 72, 70, 68, 66, 64,
+            62, 60, 58, 56, 54, 52, 50, 48, 46, 44, 42, 40, 38, 36, 34, 32,
+            30, 28, 26, 24, 22, 20, 18, 16, 14, 12, 10, 8, 6, 4, 2, 0,
+        );
+        #[rustfmt::skip]
+        let idx = _mm512_set_epi8(
+            59, 52, 45, 38, 31, 24, 17, 10, 3, 124, 117, 110, 103, 96, 89, 82,
+            75, 68, 61, 54, 47, 40, 33, 26, 19, 12, 5, 126, 119, 112, 105, 98,
+            91, 84, 77, 70, 63, 56, 49, 42, 35, 28, 21, 14, 7, 0, 121, 114,
+            107, 100, 93, 86, 79, 72, 65, 58, 51, 44, 37, 30, 23, 16, 9, 2,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            -127, -125, -123, -121, -119, -117, -115, -113, -111, -109, -107, -105, -103, -101, -99, -97,
+            -95, -93, -91, -89, -87, -85, -83, -81, -79, -77, -75, -73, -71, -69, -67, -65,
+            -63, -61, -59, -57, -55, -53, -51, -49, -47, -45, -43, -41, -39, -37, -35, -33,
+            -31, -29, -27, -25, -23, -21, -19, -17, -15, -13, -11, -9, -7, -5, -3, -1,
+        );
+        let r = _mm512_maskz_permutex2var_epi8(0, a, idx, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_permutex2var_epi8(
+            0b10011001_01010101_00110101_11001010_01101001_10010110_11001010_00110101,
+            a,
+            idx,
+            b,
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            118, 0, 0, 76, 62, 0, 0, 20, 0, -121, 0, -93, 0, -65, 0, -37,
+            0, 0, 122, 108, 0, 80, 0, 52, 38, 24, 0, 0, -111, 0, -83, 0,
+            0, -41, -27, 0, 126, 0, 0, 84, 70, 0, 0, 28, 0, 0, -115, 0,
+            -87, -73, 0, 0, -31, 0, -3, 0, 0, 0, 74, 60, 0, 32, 0, 4,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi,avx512vl")]
+    unsafe fn test_mm256_permutex2var_epi8() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30,
+            32, 34, 36, 38, 40, 42, 44, 46, 48, 50, 52, 54, 56, 58, 60, 62,
+        );
+        #[rustfmt::skip]
+        let idx = _mm256_setr_epi8(
+            2, 9, 16, 23, 30, 37, 44, 51, 58, 65, 72, 79, 86, 93, 100, 107,
+            114, 121, 0, 7, 14, 21, 28, 35, 42, 49, 56, 63, 70, 77, 84, 91,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            -1, -3, -5, -7, -9, -11, -13, -15, -17, -19, -21, -23, -25, -27, -29, -31,
+            -33, -35, -37, -39, -41, -43, -45, -47, -49, -51, -53, -55, -57, -59, -61, -63,
+        );
+        let r = _mm256_permutex2var_epi8(a, idx, b);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            4, 18, 32, 46, 60, -11, -25, -39, -53, 2, 16, 30, 44, 58, -9, -23,
+            -37, -51, 0, 14, 28, 42, 56, -7, -21, -35, -49, -63, 12, 26, 40, 54,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi,avx512vl")]
+    unsafe fn test_mm256_mask_permutex2var_epi8() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30,
+            32, 34, 36, 38, 40, 42, 44, 46, 48, 50, 52, 54, 56, 58, 60, 62,
+        );
+        #[rustfmt::skip]
+        let idx = _mm256_setr_epi8(
+            2, 9, 16, 23, 30, 37, 44, 51, 58, 65, 72, 79, 86, 93, 100, 107,
+            114, 121, 0, 7, 14, 21, 28, 35, 42, 49, 56, 63, 70, 77, 84, 91,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            -1, -3, -5, -7, -9, -11, -13, -15, -17, -19, -21, -23, -25, -27, -29, -31,
+            -33, -35, -37, -39, -41, -43, -45, -47, -49, -51, -53, -55, -57, -59, -61, -63,
+        );
+        let r = _mm256_mask_permutex2var_epi8(a, 0, idx, b);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_permutex2var_epi8(a, 0b01101001_10010110_11001010_00110101, idx, b);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            4, 2, 32, 6, 60, -11, 12, 14, 16, 2, 20, 30, 24, 26, -9, -23,
+            32, -51, 0, 38, 28, 42, 44, -7, -21, 50, 52, -63, 56, 26, 40, 62,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi,avx512vl")]
+    unsafe fn test_mm256_mask2_permutex2var_epi8() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30,
+            32, 34, 36, 38, 40, 42, 44, 46, 48, 50, 52, 54, 56, 58, 60, 62,
+        );
+        #[rustfmt::skip]
+        let idx = _mm256_setr_epi8(
+            2, 9, 16, 23, 30, 37, 44, 51, 58, 65, 72, 79, 86, 93, 100, 107,
+            114, 121, 0, 7, 14, 21, 28, 35, 42, 49, 56, 63, 70, 77, 84, 91,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            -1, -3, -5, -7, -9, -11, -13, -15, -17, -19, -21, -23, -25, -27, -29, -31,
+            -33, -35, -37, -39, -41, -43, -45, -47, -49, -51, -53, -55, -57, -59, -61, -63,
+        );
+        let r = _mm256_mask2_permutex2var_epi8(a, idx, 0, b);
+        assert_eq_m256i(r, idx);
+        let r = _mm256_mask2_permutex2var_epi8(a, idx, 0b01101001_10010110_11001010_00110101, b);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            4, 9, 32, 23, 60, -11, 44, 51, 58, 2, 72, 30, 86, 93, -9, -23,
+            114, -51, 0, 7, 28, 21, 28, -7, -21, 49, 56, -63, 70, 26, 40, 91,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi,avx512vl")]
+    unsafe fn test_mm256_maskz_permutex2var_epi8() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30,
+            32, 34, 36, 38, 40, 42, 44, 46, 48, 50, 52, 54, 56, 58, 60, 62,
+        );
+        #[rustfmt::skip]
+        let idx = _mm256_setr_epi8(
+            2, 9, 16, 23, 30, 37, 44, 51, 58, 65, 72, 79, 86, 93, 100, 107,
+            114, 121, 0, 7, 14, 21, 28, 35, 42, 49, 56, 63, 70, 77, 84, 91,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            -1, -3, -5, -7, -9, -11, -13, -15, -17, -19, -21, -23, -25, -27, -29, -31,
+            -33, -35, -37, -39, -41, -43, -45, -47, -49, -51, -53, -55, -57, -59, -61, -63,
+        );
+        let r = _mm256_maskz_permutex2var_epi8(0, a, idx, b);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_permutex2var_epi8(0b01101001_10010110_11001010_00110101, a, idx, b);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            4, 0, 32, 0, 60, -11, 0, 0, 0, 2, 0, 30, 0, 0, -9, -23,
+            0, -51, 0, 0, 28, 0, 0, -7, -21, 0, 0, -63, 0, 26, 40, 0,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi,avx512vl")]
+    unsafe fn test_mm_permutex2var_epi8() {
+        let a = _mm_setr_epi8(0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30);
+        #[rustfmt::skip]
+        let idx = _mm_setr_epi8(
+            2, 9, 16, 23, 30, 37, 44, 51,
+            58, 65, 72, 79, 86, 93, 100, 107,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            -1, -3, -5, -7, -9, -11, -13, -15,
+            -17, -19, -21, -23, -25, -27, -29, -31,
+        );
+        let r = _mm_permutex2var_epi8(a, idx, b);
+        #[rustfmt::skip]
+        let e = _mm_setr_epi8(
+            4, 18, -1, -15, -29, 10, 24, -7,
+            -21, 2, 16, 30, -13, -27, 8, 22,
+        );
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi,avx512vl")]
+    unsafe fn test_mm_mask_permutex2var_epi8() {
+        let a = _mm_setr_epi8(0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30);
+        #[rustfmt::skip]
+        let idx = _mm_setr_epi8(
+            2, 9, 16, 23, 30, 37, 44, 51,
+            58, 65, 72, 79, 86, 93, 100, 107,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            -1, -3, -5, -7, -9, -11, -13, -15,
+            -17, -19, -21, -23, -25, -27, -29, -31,
+        );
+        let r = _mm_mask_permutex2var_epi8(a, 0, idx, b);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_permutex2var_epi8(a, 0b11001010_00110101, idx, b);
+        let e = _mm_setr_epi8(4, 2, -1, 6, -29, 10, 12, 14, 16, 2, 20, 30, 24, 26, 8, 22);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi,avx512vl")]
+    unsafe fn test_mm_mask2_permutex2var_epi8() {
+        let a = _mm_setr_epi8(0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30);
+        #[rustfmt::skip]
+        let idx = _mm_setr_epi8(
+            2, 9, 16, 23, 30, 37, 44, 51,
+            58, 65, 72, 79, 86, 93, 100, 107,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            -1, -3, -5, -7, -9, -11, -13, -15,
+            -17, -19, -21, -23, -25, -27, -29, -31,
+        );
+        let r = _mm_mask2_permutex2var_epi8(a, idx, 0, b);
+        assert_eq_m128i(r, idx);
+        let r = _mm_mask2_permutex2var_epi8(a, idx, 0b11001010_00110101, b);
+        let e = _mm_setr_epi8(4, 9, -1, 23, -29, 10, 44, 51, 58, 2, 72, 30, 86, 93, 8, 22);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi,avx512vl")]
+    unsafe fn test_mm_maskz_permutex2var_epi8() {
+        let a = _mm_setr_epi8(0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30);
+        #[rustfmt::skip]
+        let idx = _mm_setr_epi8(
+            2, 9, 16, 23, 30, 37, 44, 51,
+            58, 65, 72, 79, 86, 93, 100, 107,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            -1, -3, -5, -7, -9, -11, -13, -15,
+            -17, -19, -21, -23, -25, -27, -29, -31,
+        );
+        let r = _mm_maskz_permutex2var_epi8(0, a, idx, b);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_permutex2var_epi8(0b11001010_00110101, a, idx, b);
+        let e = _mm_setr_epi8(4, 0, -1, 0, -29, 10, 0, 0, 0, 2, 0, 30, 0, 0, 8, 22);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi")]
+    unsafe fn test_mm512_multishift_epi64_epi8() {
+        let a = _mm512_set1_epi64(0x383f_0160_3c08_0400);
+        let b = _mm512_set1_epi64(0x0123_4567_89ab_cdef);
+        let r = _mm512_multishift_epi64_epi8(a, b);
+        let e = _mm512_set1_epi64(0x01de_f767_f0cd_deef);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi")]
+    unsafe fn test_mm512_mask_multishift_epi64_epi8() {
+        let a = _mm512_set1_epi64(0x383f_0160_3c08_0400);
+        let b = _mm512_set1_epi64(0x0123_4567_89ab_cdef);
+        let r = _mm512_mask_multishift_epi64_epi8(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_multishift_epi64_epi8(
+            a,
+            0b10011001_01010101_00110101_11001010_01101001_10010110_11001010_00110101,
+            a,
+            b,
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            1, 63, 1, 103, -16, 8, 4, -17, 56, -34, 1, 103, 60, -51, 4, -17,
+            56, 63, -9, 103, 60, -51, 4, -17, 1, -34, 1, 96, -16, 8, -34, 0,
+            56, -34, -9, 96, -16, 8, 4, -17, 1, 63, 1, 103, 60, -51, -34, 0,
+            1, -34, 1, 96, -16, 8, -34, 0, 56, 63, -9, 103, 60, -51, 4, -17,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi")]
+    unsafe fn test_mm512_maskz_multishift_epi64_epi8() {
+        let a = _mm512_set1_epi64(0x383f_0160_3c08_0400);
+        let b = _mm512_set1_epi64(0x0123_4567_89ab_cdef);
+        let r = _mm512_maskz_multishift_epi64_epi8(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_multishift_epi64_epi8(
+            0b10011001_01010101_00110101_11001010_01101001_10010110_11001010_00110101,
+            a,
+            b,
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            1, 0, 0, 103, -16, 0, 0, -17, 0, -34, 0, 103, 0, -51, 0, -17,
+            0, 0, -9, 103, 0, -51, 0, -17, 1, -34, 0, 0, -16, 0, -34, 0,
+            0, -34, -9, 0, -16, 0, 0, -17, 1, 0, 0, 103, 0, -51, -34, 0,
+            1, -34, 0, 0, -16, 0, -34, 0, 0, 0, -9, 103, 0, -51, 0, -17,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi,avx512vl")]
+    unsafe fn test_mm256_multishift_epi64_epi8() {
+        let a = _mm256_set1_epi64x(0x383f_0160_3c08_0400);
+        let b = _mm256_set1_epi64x(0x0123_4567_89ab_cdef);
+        let r = _mm256_multishift_epi64_epi8(a, b);
+        let e = _mm256_set1_epi64x(0x01de_f767_f0cd_deef);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi,avx512vl")]
+    unsafe fn test_mm256_mask_multishift_epi64_epi8() {
+        let a = _mm256_set1_epi64x(0x383f_0160_3c08_0400);
+        let b = _mm256_set1_epi64x(0x0123_4567_89ab_cdef);
+        let r = _mm256_mask_multishift_epi64_epi8(a, 0, a, b);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_multishift_epi64_epi8(a, 0b01101001_10010110_11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            -17, 4, -51, 60, 103, -9, 63, 56, 0, -34, 8, -16, 96, 1, -34, 1,
+            0, -34, -51, 60, 103, 1, 63, 1, -17, 4, 8, -16, 96, -9, -34, 56,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi,avx512vl")]
+    unsafe fn test_mm256_maskz_multishift_epi64_epi8() {
+        let a = _mm256_set1_epi64x(0x383f_0160_3c08_0400);
+        let b = _mm256_set1_epi64x(0x0123_4567_89ab_cdef);
+        let r = _mm256_maskz_multishift_epi64_epi8(0, a, b);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_multishift_epi64_epi8(0b01101001_10010110_11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            -17, 0, -51, 0, 103, -9, 0, 0, 0, -34, 0, -16, 0, 0, -34, 1,
+            0, -34, -51, 0, 103, 0, 0, 1, -17, 0, 0, -16, 0, -9, -34, 0,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi,avx512vl")]
+    unsafe fn test_mm_multishift_epi64_epi8() {
+        let a = _mm_set1_epi64x(0x383f_0160_3c08_0400);
+        let b = _mm_set1_epi64x(0x0123_4567_89ab_cdef);
+        let r = _mm_multishift_epi64_epi8(a, b);
+        let e = _mm_set1_epi64x(0x01de_f767_f0cd_deef);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi,avx512vl")]
+    unsafe fn test_mm_mask_multishift_epi64_epi8() {
+        let a = _mm_set1_epi64x(0x383f_0160_3c08_0400);
+        let b = _mm_set1_epi64x(0x0123_4567_89ab_cdef);
+        let r = _mm_mask_multishift_epi64_epi8(a, 0, a, b);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_multishift_epi64_epi8(a, 0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e = _mm_setr_epi8(
+            -17, 4, -51, 60, 103, -9, 63, 56,
+            0, -34, 8, -16, 96, 1, -34, 1,
+        );
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi,avx512vl")]
+    unsafe fn test_mm_maskz_multishift_epi64_epi8() {
+        let a = _mm_set1_epi64x(0x383f_0160_3c08_0400);
+        let b = _mm_set1_epi64x(0x0123_4567_89ab_cdef);
+        let r = _mm_maskz_multishift_epi64_epi8(0, a, b);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_multishift_epi64_epi8(0b11001010_00110101, a, b);
+        let e = _mm_setr_epi8(-17, 0, -51, 0, 103, -9, 0, 0, 0, -34, 0, -16, 0, 0, -34, 1);
+        assert_eq_m128i(r, e);
+    }
+}
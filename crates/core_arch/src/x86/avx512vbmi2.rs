@@ -0,0 +1,4494 @@
+use crate::{
+    core_arch::{simd::*, simd_llvm::*, x86::*},
+    mem::transmute,
+};
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Contiguously stores the active 8-bit integers in `a` (those with their
+/// respective bit set in writemask `k`) to `dst`, and passes through the
+/// remaining elements from `src`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_compress_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpcompressb))]
+pub unsafe fn _mm512_mask_compress_epi8(src: __m512i, k: __mmask64, a: __m512i) -> __m512i {
+    transmute(vpcompressb_512(a.as_i8x64(), src.as_i8x64(), k))
+}
+
+/// Contiguously stores the active 8-bit integers in `a` (those with their
+/// respective bit set in zeromask `k`) to `dst`, and sets the remaining
+/// elements to zero.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_compress_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpcompressb))]
+pub unsafe fn _mm512_maskz_compress_epi8(k: __mmask64, a: __m512i) -> __m512i {
+    transmute(vpcompressb_512(
+        a.as_i8x64(),
+        _mm512_setzero_si512().as_i8x64(),
+        k,
+    ))
+}
+
+/// Contiguously stores the active 8-bit integers in `a` (those with their
+/// respective bit set in writemask `k`) to unaligned memory at `base_addr`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_compressstoreu_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpcompressb))]
+pub unsafe fn _mm512_mask_compressstoreu_epi8(base_addr: *mut u8, k: __mmask64, a: __m512i) {
+    vpcompressbstore_512(base_addr, a.as_i8x64(), k)
+}
+
+/// Contiguously stores the active 8-bit integers in `a` (those with their
+/// respective bit set in writemask `k`) to `dst`, and passes through the
+/// remaining elements from `src`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_compress_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcompressb))]
+pub unsafe fn _mm256_mask_compress_epi8(src: __m256i, k: __mmask32, a: __m256i) -> __m256i {
+    transmute(vpcompressb_256(a.as_i8x32(), src.as_i8x32(), k))
+}
+
+/// Contiguously stores the active 8-bit integers in `a` (those with their
+/// respective bit set in zeromask `k`) to `dst`, and sets the remaining
+/// elements to zero.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_compress_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcompressb))]
+pub unsafe fn _mm256_maskz_compress_epi8(k: __mmask32, a: __m256i) -> __m256i {
+    transmute(vpcompressb_256(
+        a.as_i8x32(),
+        _mm256_setzero_si256().as_i8x32(),
+        k,
+    ))
+}
+
+/// Contiguously stores the active 8-bit integers in `a` (those with their
+/// respective bit set in writemask `k`) to unaligned memory at `base_addr`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_compressstoreu_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcompressb))]
+pub unsafe fn _mm256_mask_compressstoreu_epi8(base_addr: *mut u8, k: __mmask32, a: __m256i) {
+    vpcompressbstore_256(base_addr, a.as_i8x32(), k)
+}
+
+/// Contiguously stores the active 8-bit integers in `a` (those with their
+/// respective bit set in writemask `k`) to `dst`, and passes through the
+/// remaining elements from `src`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_compress_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcompressb))]
+pub unsafe fn _mm_mask_compress_epi8(src: __m128i, k: __mmask16, a: __m128i) -> __m128i {
+    transmute(vpcompressb_128(a.as_i8x16(), src.as_i8x16(), k))
+}
+
+/// Contiguously stores the active 8-bit integers in `a` (those with their
+/// respective bit set in zeromask `k`) to `dst`, and sets the remaining
+/// elements to zero.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_compress_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcompressb))]
+pub unsafe fn _mm_maskz_compress_epi8(k: __mmask16, a: __m128i) -> __m128i {
+    transmute(vpcompressb_128(
+        a.as_i8x16(),
+        _mm_setzero_si128().as_i8x16(),
+        k,
+    ))
+}
+
+/// Contiguously stores the active 8-bit integers in `a` (those with their
+/// respective bit set in writemask `k`) to unaligned memory at `base_addr`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_compressstoreu_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcompressb))]
+pub unsafe fn _mm_mask_compressstoreu_epi8(base_addr: *mut u8, k: __mmask16, a: __m128i) {
+    vpcompressbstore_128(base_addr, a.as_i8x16(), k)
+}
+
+/// Contiguously stores the active 16-bit integers in `a` (those with their
+/// respective bit set in writemask `k`) to `dst`, and passes through the
+/// remaining elements from `src`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_compress_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpcompressw))]
+pub unsafe fn _mm512_mask_compress_epi16(src: __m512i, k: __mmask32, a: __m512i) -> __m512i {
+    transmute(vpcompressw_512(a.as_i16x32(), src.as_i16x32(), k))
+}
+
+/// Contiguously stores the active 16-bit integers in `a` (those with their
+/// respective bit set in zeromask `k`) to `dst`, and sets the remaining
+/// elements to zero.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_compress_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpcompressw))]
+pub unsafe fn _mm512_maskz_compress_epi16(k: __mmask32, a: __m512i) -> __m512i {
+    transmute(vpcompressw_512(
+        a.as_i16x32(),
+        _mm512_setzero_si512().as_i16x32(),
+        k,
+    ))
+}
+
+/// Contiguously stores the active 16-bit integers in `a` (those with their
+/// respective bit set in writemask `k`) to unaligned memory at `base_addr`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_compressstoreu_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpcompressw))]
+pub unsafe fn _mm512_mask_compressstoreu_epi16(base_addr: *mut u8, k: __mmask32, a: __m512i) {
+    vpcompresswstore_512(base_addr, a.as_i16x32(), k)
+}
+
+/// Contiguously stores the active 16-bit integers in `a` (those with their
+/// respective bit set in writemask `k`) to `dst`, and passes through the
+/// remaining elements from `src`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_compress_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcompressw))]
+pub unsafe fn _mm256_mask_compress_epi16(src: __m256i, k: __mmask16, a: __m256i) -> __m256i {
+    transmute(vpcompressw_256(a.as_i16x16(), src.as_i16x16(), k))
+}
+
+/// Contiguously stores the active 16-bit integers in `a` (those with their
+/// respective bit set in zeromask `k`) to `dst`, and sets the remaining
+/// elements to zero.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_compress_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcompressw))]
+pub unsafe fn _mm256_maskz_compress_epi16(k: __mmask16, a: __m256i) -> __m256i {
+    transmute(vpcompressw_256(
+        a.as_i16x16(),
+        _mm256_setzero_si256().as_i16x16(),
+        k,
+    ))
+}
+
+/// Contiguously stores the active 16-bit integers in `a` (those with their
+/// respective bit set in writemask `k`) to unaligned memory at `base_addr`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_compressstoreu_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcompressw))]
+pub unsafe fn _mm256_mask_compressstoreu_epi16(base_addr: *mut u8, k: __mmask16, a: __m256i) {
+    vpcompresswstore_256(base_addr, a.as_i16x16(), k)
+}
+
+/// Contiguously stores the active 16-bit integers in `a` (those with their
+/// respective bit set in writemask `k`) to `dst`, and passes through the
+/// remaining elements from `src`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_compress_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcompressw))]
+pub unsafe fn _mm_mask_compress_epi16(src: __m128i, k: __mmask8, a: __m128i) -> __m128i {
+    transmute(vpcompressw_128(a.as_i16x8(), src.as_i16x8(), k))
+}
+
+/// Contiguously stores the active 16-bit integers in `a` (those with their
+/// respective bit set in zeromask `k`) to `dst`, and sets the remaining
+/// elements to zero.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_compress_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcompressw))]
+pub unsafe fn _mm_maskz_compress_epi16(k: __mmask8, a: __m128i) -> __m128i {
+    transmute(vpcompressw_128(
+        a.as_i16x8(),
+        _mm_setzero_si128().as_i16x8(),
+        k,
+    ))
+}
+
+/// Contiguously stores the active 16-bit integers in `a` (those with their
+/// respective bit set in writemask `k`) to unaligned memory at `base_addr`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_compressstoreu_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcompressw))]
+pub unsafe fn _mm_mask_compressstoreu_epi16(base_addr: *mut u8, k: __mmask8, a: __m128i) {
+    vpcompresswstore_128(base_addr, a.as_i16x8(), k)
+}
+
+/// Loads contiguous active 8-bit integers from `a` (those with their respective
+/// bit set in mask `k`), and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_expand_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpexpandb))]
+pub unsafe fn _mm512_mask_expand_epi8(src: __m512i, k: __mmask64, a: __m512i) -> __m512i {
+    transmute(vpexpandb_512(a.as_i8x64(), src.as_i8x64(), k))
+}
+
+/// Loads contiguous active 8-bit integers from `a` (those with their respective
+/// bit set in mask `k`), and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_expand_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpexpandb))]
+pub unsafe fn _mm512_maskz_expand_epi8(k: __mmask64, a: __m512i) -> __m512i {
+    transmute(vpexpandb_512(
+        a.as_i8x64(),
+        _mm512_setzero_si512().as_i8x64(),
+        k,
+    ))
+}
+
+/// Loads contiguous active 8-bit integers from unaligned memory at `mem_addr`
+/// (those with their respective bit set in mask `k`), and stores the results in
+/// `dst` using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_expandloadu_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpexpandb))]
+pub unsafe fn _mm512_mask_expandloadu_epi8(
+    src: __m512i,
+    k: __mmask64,
+    mem_addr: *const u8,
+) -> __m512i {
+    transmute(vpexpandbload_512(mem_addr, src.as_i8x64(), k))
+}
+
+/// Loads contiguous active 8-bit integers from unaligned memory at `mem_addr`
+/// (those with their respective bit set in mask `k`), and stores the results in
+/// `dst` using zeromask `k` (elements are zeroed out when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_expandloadu_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpexpandb))]
+pub unsafe fn _mm512_maskz_expandloadu_epi8(k: __mmask64, mem_addr: *const u8) -> __m512i {
+    transmute(vpexpandbload_512(
+        mem_addr,
+        _mm512_setzero_si512().as_i8x64(),
+        k,
+    ))
+}
+
+/// Loads contiguous active 8-bit integers from `a` (those with their respective
+/// bit set in mask `k`), and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_expand_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandb))]
+pub unsafe fn _mm256_mask_expand_epi8(src: __m256i, k: __mmask32, a: __m256i) -> __m256i {
+    transmute(vpexpandb_256(a.as_i8x32(), src.as_i8x32(), k))
+}
+
+/// Loads contiguous active 8-bit integers from `a` (those with their respective
+/// bit set in mask `k`), and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_expand_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandb))]
+pub unsafe fn _mm256_maskz_expand_epi8(k: __mmask32, a: __m256i) -> __m256i {
+    transmute(vpexpandb_256(
+        a.as_i8x32(),
+        _mm256_setzero_si256().as_i8x32(),
+        k,
+    ))
+}
+
+/// Loads contiguous active 8-bit integers from unaligned memory at `mem_addr`
+/// (those with their respective bit set in mask `k`), and stores the results in
+/// `dst` using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_expandloadu_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandb))]
+pub unsafe fn _mm256_mask_expandloadu_epi8(
+    src: __m256i,
+    k: __mmask32,
+    mem_addr: *const u8,
+) -> __m256i {
+    transmute(vpexpandbload_256(mem_addr, src.as_i8x32(), k))
+}
+
+/// Loads contiguous active 8-bit integers from unaligned memory at `mem_addr`
+/// (those with their respective bit set in mask `k`), and stores the results in
+/// `dst` using zeromask `k` (elements are zeroed out when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_expandloadu_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandb))]
+pub unsafe fn _mm256_maskz_expandloadu_epi8(k: __mmask32, mem_addr: *const u8) -> __m256i {
+    transmute(vpexpandbload_256(
+        mem_addr,
+        _mm256_setzero_si256().as_i8x32(),
+        k,
+    ))
+}
+
+/// Loads contiguous active 8-bit integers from `a` (those with their respective
+/// bit set in mask `k`), and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_expand_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandb))]
+pub unsafe fn _mm_mask_expand_epi8(src: __m128i, k: __mmask16, a: __m128i) -> __m128i {
+    transmute(vpexpandb_128(a.as_i8x16(), src.as_i8x16(), k))
+}
+
+/// Loads contiguous active 8-bit integers from `a` (those with their respective
+/// bit set in mask `k`), and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_expand_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandb))]
+pub unsafe fn _mm_maskz_expand_epi8(k: __mmask16, a: __m128i) -> __m128i {
+    transmute(vpexpandb_128(
+        a.as_i8x16(),
+        _mm_setzero_si128().as_i8x16(),
+        k,
+    ))
+}
+
+/// Loads contiguous active 8-bit integers from unaligned memory at `mem_addr`
+/// (those with their respective bit set in mask `k`), and stores the results in
+/// `dst` using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_expandloadu_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandb))]
+pub unsafe fn _mm_mask_expandloadu_epi8(
+    src: __m128i,
+    k: __mmask16,
+    mem_addr: *const u8,
+) -> __m128i {
+    transmute(vpexpandbload_128(mem_addr, src.as_i8x16(), k))
+}
+
+/// Loads contiguous active 8-bit integers from unaligned memory at `mem_addr`
+/// (those with their respective bit set in mask `k`), and stores the results in
+/// `dst` using zeromask `k` (elements are zeroed out when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_expandloadu_epi8)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandb))]
+pub unsafe fn _mm_maskz_expandloadu_epi8(k: __mmask16, mem_addr: *const u8) -> __m128i {
+    transmute(vpexpandbload_128(
+        mem_addr,
+        _mm_setzero_si128().as_i8x16(),
+        k,
+    ))
+}
+
+/// Loads contiguous active 16-bit integers from `a` (those with their
+/// respective bit set in mask `k`), and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_expand_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpexpandw))]
+pub unsafe fn _mm512_mask_expand_epi16(src: __m512i, k: __mmask32, a: __m512i) -> __m512i {
+    transmute(vpexpandw_512(a.as_i16x32(), src.as_i16x32(), k))
+}
+
+/// Loads contiguous active 16-bit integers from `a` (those with their
+/// respective bit set in mask `k`), and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_expand_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpexpandw))]
+pub unsafe fn _mm512_maskz_expand_epi16(k: __mmask32, a: __m512i) -> __m512i {
+    transmute(vpexpandw_512(
+        a.as_i16x32(),
+        _mm512_setzero_si512().as_i16x32(),
+        k,
+    ))
+}
+
+/// Loads contiguous active 16-bit integers from unaligned memory at `mem_addr`
+/// (those with their respective bit set in mask `k`), and stores the results in
+/// `dst` using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_expandloadu_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpexpandw))]
+pub unsafe fn _mm512_mask_expandloadu_epi16(
+    src: __m512i,
+    k: __mmask32,
+    mem_addr: *const u8,
+) -> __m512i {
+    transmute(vpexpandwload_512(mem_addr, src.as_i16x32(), k))
+}
+
+/// Loads contiguous active 16-bit integers from unaligned memory at `mem_addr`
+/// (those with their respective bit set in mask `k`), and stores the results in
+/// `dst` using zeromask `k` (elements are zeroed out when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_expandloadu_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpexpandw))]
+pub unsafe fn _mm512_maskz_expandloadu_epi16(k: __mmask32, mem_addr: *const u8) -> __m512i {
+    transmute(vpexpandwload_512(
+        mem_addr,
+        _mm512_setzero_si512().as_i16x32(),
+        k,
+    ))
+}
+
+/// Loads contiguous active 16-bit integers from `a` (those with their
+/// respective bit set in mask `k`), and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_expand_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandw))]
+pub unsafe fn _mm256_mask_expand_epi16(src: __m256i, k: __mmask16, a: __m256i) -> __m256i {
+    transmute(vpexpandw_256(a.as_i16x16(), src.as_i16x16(), k))
+}
+
+/// Loads contiguous active 16-bit integers from `a` (those with their
+/// respective bit set in mask `k`), and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_expand_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandw))]
+pub unsafe fn _mm256_maskz_expand_epi16(k: __mmask16, a: __m256i) -> __m256i {
+    transmute(vpexpandw_256(
+        a.as_i16x16(),
+        _mm256_setzero_si256().as_i16x16(),
+        k,
+    ))
+}
+
+/// Loads contiguous active 16-bit integers from unaligned memory at `mem_addr`
+/// (those with their respective bit set in mask `k`), and stores the results in
+/// `dst` using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_expandloadu_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandw))]
+pub unsafe fn _mm256_mask_expandloadu_epi16(
+    src: __m256i,
+    k: __mmask16,
+    mem_addr: *const u8,
+) -> __m256i {
+    transmute(vpexpandwload_256(mem_addr, src.as_i16x16(), k))
+}
+
+/// Loads contiguous active 16-bit integers from unaligned memory at `mem_addr`
+/// (those with their respective bit set in mask `k`), and stores the results in
+/// `dst` using zeromask `k` (elements are zeroed out when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_expandloadu_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandw))]
+pub unsafe fn _mm256_maskz_expandloadu_epi16(k: __mmask16, mem_addr: *const u8) -> __m256i {
+    transmute(vpexpandwload_256(
+        mem_addr,
+        _mm256_setzero_si256().as_i16x16(),
+        k,
+    ))
+}
+
+/// Loads contiguous active 16-bit integers from `a` (those with their
+/// respective bit set in mask `k`), and stores the results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_expand_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandw))]
+pub unsafe fn _mm_mask_expand_epi16(src: __m128i, k: __mmask8, a: __m128i) -> __m128i {
+    transmute(vpexpandw_128(a.as_i16x8(), src.as_i16x8(), k))
+}
+
+/// Loads contiguous active 16-bit integers from `a` (those with their
+/// respective bit set in mask `k`), and stores the results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_expand_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandw))]
+pub unsafe fn _mm_maskz_expand_epi16(k: __mmask8, a: __m128i) -> __m128i {
+    transmute(vpexpandw_128(
+        a.as_i16x8(),
+        _mm_setzero_si128().as_i16x8(),
+        k,
+    ))
+}
+
+/// Loads contiguous active 16-bit integers from unaligned memory at `mem_addr`
+/// (those with their respective bit set in mask `k`), and stores the results in
+/// `dst` using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_expandloadu_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandw))]
+pub unsafe fn _mm_mask_expandloadu_epi16(
+    src: __m128i,
+    k: __mmask8,
+    mem_addr: *const u8,
+) -> __m128i {
+    transmute(vpexpandwload_128(mem_addr, src.as_i16x8(), k))
+}
+
+/// Loads contiguous active 16-bit integers from unaligned memory at `mem_addr`
+/// (those with their respective bit set in mask `k`), and stores the results in
+/// `dst` using zeromask `k` (elements are zeroed out when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_expandloadu_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpexpandw))]
+pub unsafe fn _mm_maskz_expandloadu_epi16(k: __mmask8, mem_addr: *const u8) -> __m128i {
+    transmute(vpexpandwload_128(
+        mem_addr,
+        _mm_setzero_si128().as_i16x8(),
+        k,
+    ))
+}
+
+/// Concatenates packed 64-bit integers in `a` and `b` producing an intermediate
+/// 128-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 64 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_shldv_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshldvq))]
+pub unsafe fn _mm512_shldv_epi64(a: __m512i, b: __m512i, c: __m512i) -> __m512i {
+    transmute(vpshldvq_512(a.as_i64x8(), b.as_i64x8(), c.as_i64x8()))
+}
+
+/// Concatenates packed 64-bit integers in `a` and `b` producing an intermediate
+/// 128-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 64 bits in `dst` using
+/// writemask `k` (elements are copied from `a` when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_shldv_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshldvq))]
+pub unsafe fn _mm512_mask_shldv_epi64(a: __m512i, k: __mmask8, b: __m512i, c: __m512i) -> __m512i {
+    let r = _mm512_shldv_epi64(a, b, c).as_i64x8();
+    transmute(simd_select_bitmask(k, r, a.as_i64x8()))
+}
+
+/// Concatenates packed 64-bit integers in `a` and `b` producing an intermediate
+/// 128-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 64 bits in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_shldv_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshldvq))]
+pub unsafe fn _mm512_maskz_shldv_epi64(k: __mmask8, a: __m512i, b: __m512i, c: __m512i) -> __m512i {
+    let r = _mm512_shldv_epi64(a, b, c).as_i64x8();
+    let zero = _mm512_setzero_si512().as_i64x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 64-bit integers in `a` and `b` producing an intermediate
+/// 128-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 64 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_shldv_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldvq))]
+pub unsafe fn _mm256_shldv_epi64(a: __m256i, b: __m256i, c: __m256i) -> __m256i {
+    transmute(vpshldvq_256(a.as_i64x4(), b.as_i64x4(), c.as_i64x4()))
+}
+
+/// Concatenates packed 64-bit integers in `a` and `b` producing an intermediate
+/// 128-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 64 bits in `dst` using
+/// writemask `k` (elements are copied from `a` when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_shldv_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldvq))]
+pub unsafe fn _mm256_mask_shldv_epi64(a: __m256i, k: __mmask8, b: __m256i, c: __m256i) -> __m256i {
+    let r = _mm256_shldv_epi64(a, b, c).as_i64x4();
+    transmute(simd_select_bitmask(k, r, a.as_i64x4()))
+}
+
+/// Concatenates packed 64-bit integers in `a` and `b` producing an intermediate
+/// 128-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 64 bits in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_shldv_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldvq))]
+pub unsafe fn _mm256_maskz_shldv_epi64(k: __mmask8, a: __m256i, b: __m256i, c: __m256i) -> __m256i {
+    let r = _mm256_shldv_epi64(a, b, c).as_i64x4();
+    let zero = _mm256_setzero_si256().as_i64x4();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 64-bit integers in `a` and `b` producing an intermediate
+/// 128-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 64 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_shldv_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldvq))]
+pub unsafe fn _mm_shldv_epi64(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    transmute(vpshldvq_128(a.as_i64x2(), b.as_i64x2(), c.as_i64x2()))
+}
+
+/// Concatenates packed 64-bit integers in `a` and `b` producing an intermediate
+/// 128-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 64 bits in `dst` using
+/// writemask `k` (elements are copied from `a` when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_shldv_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldvq))]
+pub unsafe fn _mm_mask_shldv_epi64(a: __m128i, k: __mmask8, b: __m128i, c: __m128i) -> __m128i {
+    let r = _mm_shldv_epi64(a, b, c).as_i64x2();
+    transmute(simd_select_bitmask(k, r, a.as_i64x2()))
+}
+
+/// Concatenates packed 64-bit integers in `a` and `b` producing an intermediate
+/// 128-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 64 bits in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_shldv_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldvq))]
+pub unsafe fn _mm_maskz_shldv_epi64(k: __mmask8, a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    let r = _mm_shldv_epi64(a, b, c).as_i64x2();
+    let zero = _mm_setzero_si128().as_i64x2();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 32-bit integers in `a` and `b` producing an intermediate
+/// 64-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 32 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_shldv_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshldvd))]
+pub unsafe fn _mm512_shldv_epi32(a: __m512i, b: __m512i, c: __m512i) -> __m512i {
+    transmute(vpshldvd_512(a.as_i32x16(), b.as_i32x16(), c.as_i32x16()))
+}
+
+/// Concatenates packed 32-bit integers in `a` and `b` producing an intermediate
+/// 64-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 32 bits in `dst` using
+/// writemask `k` (elements are copied from `a` when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_shldv_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshldvd))]
+pub unsafe fn _mm512_mask_shldv_epi32(a: __m512i, k: __mmask16, b: __m512i, c: __m512i) -> __m512i {
+    let r = _mm512_shldv_epi32(a, b, c).as_i32x16();
+    transmute(simd_select_bitmask(k, r, a.as_i32x16()))
+}
+
+/// Concatenates packed 32-bit integers in `a` and `b` producing an intermediate
+/// 64-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 32 bits in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_shldv_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshldvd))]
+pub unsafe fn _mm512_maskz_shldv_epi32(
+    k: __mmask16,
+    a: __m512i,
+    b: __m512i,
+    c: __m512i,
+) -> __m512i {
+    let r = _mm512_shldv_epi32(a, b, c).as_i32x16();
+    let zero = _mm512_setzero_si512().as_i32x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 32-bit integers in `a` and `b` producing an intermediate
+/// 64-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 32 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_shldv_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldvd))]
+pub unsafe fn _mm256_shldv_epi32(a: __m256i, b: __m256i, c: __m256i) -> __m256i {
+    transmute(vpshldvd_256(a.as_i32x8(), b.as_i32x8(), c.as_i32x8()))
+}
+
+/// Concatenates packed 32-bit integers in `a` and `b` producing an intermediate
+/// 64-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 32 bits in `dst` using
+/// writemask `k` (elements are copied from `a` when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_shldv_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldvd))]
+pub unsafe fn _mm256_mask_shldv_epi32(a: __m256i, k: __mmask8, b: __m256i, c: __m256i) -> __m256i {
+    let r = _mm256_shldv_epi32(a, b, c).as_i32x8();
+    transmute(simd_select_bitmask(k, r, a.as_i32x8()))
+}
+
+/// Concatenates packed 32-bit integers in `a` and `b` producing an intermediate
+/// 64-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 32 bits in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_shldv_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldvd))]
+pub unsafe fn _mm256_maskz_shldv_epi32(k: __mmask8, a: __m256i, b: __m256i, c: __m256i) -> __m256i {
+    let r = _mm256_shldv_epi32(a, b, c).as_i32x8();
+    let zero = _mm256_setzero_si256().as_i32x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 32-bit integers in `a` and `b` producing an intermediate
+/// 64-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 32 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_shldv_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldvd))]
+pub unsafe fn _mm_shldv_epi32(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    transmute(vpshldvd_128(a.as_i32x4(), b.as_i32x4(), c.as_i32x4()))
+}
+
+/// Concatenates packed 32-bit integers in `a` and `b` producing an intermediate
+/// 64-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 32 bits in `dst` using
+/// writemask `k` (elements are copied from `a` when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_shldv_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldvd))]
+pub unsafe fn _mm_mask_shldv_epi32(a: __m128i, k: __mmask8, b: __m128i, c: __m128i) -> __m128i {
+    let r = _mm_shldv_epi32(a, b, c).as_i32x4();
+    transmute(simd_select_bitmask(k, r, a.as_i32x4()))
+}
+
+/// Concatenates packed 32-bit integers in `a` and `b` producing an intermediate
+/// 64-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 32 bits in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_shldv_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldvd))]
+pub unsafe fn _mm_maskz_shldv_epi32(k: __mmask8, a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    let r = _mm_shldv_epi32(a, b, c).as_i32x4();
+    let zero = _mm_setzero_si128().as_i32x4();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 16-bit integers in `a` and `b` producing an intermediate
+/// 32-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 16 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_shldv_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshldvw))]
+pub unsafe fn _mm512_shldv_epi16(a: __m512i, b: __m512i, c: __m512i) -> __m512i {
+    transmute(vpshldvw_512(a.as_i16x32(), b.as_i16x32(), c.as_i16x32()))
+}
+
+/// Concatenates packed 16-bit integers in `a` and `b` producing an intermediate
+/// 32-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 16 bits in `dst` using
+/// writemask `k` (elements are copied from `a` when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_shldv_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshldvw))]
+pub unsafe fn _mm512_mask_shldv_epi16(a: __m512i, k: __mmask32, b: __m512i, c: __m512i) -> __m512i {
+    let r = _mm512_shldv_epi16(a, b, c).as_i16x32();
+    transmute(simd_select_bitmask(k, r, a.as_i16x32()))
+}
+
+/// Concatenates packed 16-bit integers in `a` and `b` producing an intermediate
+/// 32-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 16 bits in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_shldv_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshldvw))]
+pub unsafe fn _mm512_maskz_shldv_epi16(
+    k: __mmask32,
+    a: __m512i,
+    b: __m512i,
+    c: __m512i,
+) -> __m512i {
+    let r = _mm512_shldv_epi16(a, b, c).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 16-bit integers in `a` and `b` producing an intermediate
+/// 32-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 16 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_shldv_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldvw))]
+pub unsafe fn _mm256_shldv_epi16(a: __m256i, b: __m256i, c: __m256i) -> __m256i {
+    transmute(vpshldvw_256(a.as_i16x16(), b.as_i16x16(), c.as_i16x16()))
+}
+
+/// Concatenates packed 16-bit integers in `a` and `b` producing an intermediate
+/// 32-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 16 bits in `dst` using
+/// writemask `k` (elements are copied from `a` when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_shldv_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldvw))]
+pub unsafe fn _mm256_mask_shldv_epi16(a: __m256i, k: __mmask16, b: __m256i, c: __m256i) -> __m256i {
+    let r = _mm256_shldv_epi16(a, b, c).as_i16x16();
+    transmute(simd_select_bitmask(k, r, a.as_i16x16()))
+}
+
+/// Concatenates packed 16-bit integers in `a` and `b` producing an intermediate
+/// 32-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 16 bits in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_shldv_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldvw))]
+pub unsafe fn _mm256_maskz_shldv_epi16(
+    k: __mmask16,
+    a: __m256i,
+    b: __m256i,
+    c: __m256i,
+) -> __m256i {
+    let r = _mm256_shldv_epi16(a, b, c).as_i16x16();
+    let zero = _mm256_setzero_si256().as_i16x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 16-bit integers in `a` and `b` producing an intermediate
+/// 32-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 16 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_shldv_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldvw))]
+pub unsafe fn _mm_shldv_epi16(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    transmute(vpshldvw_128(a.as_i16x8(), b.as_i16x8(), c.as_i16x8()))
+}
+
+/// Concatenates packed 16-bit integers in `a` and `b` producing an intermediate
+/// 32-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 16 bits in `dst` using
+/// writemask `k` (elements are copied from `a` when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_shldv_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldvw))]
+pub unsafe fn _mm_mask_shldv_epi16(a: __m128i, k: __mmask8, b: __m128i, c: __m128i) -> __m128i {
+    let r = _mm_shldv_epi16(a, b, c).as_i16x8();
+    transmute(simd_select_bitmask(k, r, a.as_i16x8()))
+}
+
+/// Concatenates packed 16-bit integers in `a` and `b` producing an intermediate
+/// 32-bit result. Shifts the result left by the amount specified in the
+/// corresponding element of `c`, and stores the upper 16 bits in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_shldv_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldvw))]
+pub unsafe fn _mm_maskz_shldv_epi16(k: __mmask8, a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    let r = _mm_shldv_epi16(a, b, c).as_i16x8();
+    let zero = _mm_setzero_si128().as_i16x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 64-bit integers in `b` and `a` producing an intermediate
+/// 128-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 64 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_shrdv_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshrdvq))]
+pub unsafe fn _mm512_shrdv_epi64(a: __m512i, b: __m512i, c: __m512i) -> __m512i {
+    transmute(vpshrdvq_512(b.as_i64x8(), a.as_i64x8(), c.as_i64x8()))
+}
+
+/// Concatenates packed 64-bit integers in `b` and `a` producing an intermediate
+/// 128-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 64 bits in `dst` using
+/// writemask `k` (elements are copied from `a` when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_shrdv_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshrdvq))]
+pub unsafe fn _mm512_mask_shrdv_epi64(a: __m512i, k: __mmask8, b: __m512i, c: __m512i) -> __m512i {
+    let r = _mm512_shrdv_epi64(a, b, c).as_i64x8();
+    transmute(simd_select_bitmask(k, r, a.as_i64x8()))
+}
+
+/// Concatenates packed 64-bit integers in `b` and `a` producing an intermediate
+/// 128-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 64 bits in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_shrdv_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshrdvq))]
+pub unsafe fn _mm512_maskz_shrdv_epi64(k: __mmask8, a: __m512i, b: __m512i, c: __m512i) -> __m512i {
+    let r = _mm512_shrdv_epi64(a, b, c).as_i64x8();
+    let zero = _mm512_setzero_si512().as_i64x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 64-bit integers in `b` and `a` producing an intermediate
+/// 128-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 64 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_shrdv_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdvq))]
+pub unsafe fn _mm256_shrdv_epi64(a: __m256i, b: __m256i, c: __m256i) -> __m256i {
+    transmute(vpshrdvq_256(b.as_i64x4(), a.as_i64x4(), c.as_i64x4()))
+}
+
+/// Concatenates packed 64-bit integers in `b` and `a` producing an intermediate
+/// 128-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 64 bits in `dst` using
+/// writemask `k` (elements are copied from `a` when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_shrdv_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdvq))]
+pub unsafe fn _mm256_mask_shrdv_epi64(a: __m256i, k: __mmask8, b: __m256i, c: __m256i) -> __m256i {
+    let r = _mm256_shrdv_epi64(a, b, c).as_i64x4();
+    transmute(simd_select_bitmask(k, r, a.as_i64x4()))
+}
+
+/// Concatenates packed 64-bit integers in `b` and `a` producing an intermediate
+/// 128-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 64 bits in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_shrdv_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdvq))]
+pub unsafe fn _mm256_maskz_shrdv_epi64(k: __mmask8, a: __m256i, b: __m256i, c: __m256i) -> __m256i {
+    let r = _mm256_shrdv_epi64(a, b, c).as_i64x4();
+    let zero = _mm256_setzero_si256().as_i64x4();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 64-bit integers in `b` and `a` producing an intermediate
+/// 128-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 64 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_shrdv_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdvq))]
+pub unsafe fn _mm_shrdv_epi64(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    transmute(vpshrdvq_128(b.as_i64x2(), a.as_i64x2(), c.as_i64x2()))
+}
+
+/// Concatenates packed 64-bit integers in `b` and `a` producing an intermediate
+/// 128-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 64 bits in `dst` using
+/// writemask `k` (elements are copied from `a` when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_shrdv_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdvq))]
+pub unsafe fn _mm_mask_shrdv_epi64(a: __m128i, k: __mmask8, b: __m128i, c: __m128i) -> __m128i {
+    let r = _mm_shrdv_epi64(a, b, c).as_i64x2();
+    transmute(simd_select_bitmask(k, r, a.as_i64x2()))
+}
+
+/// Concatenates packed 64-bit integers in `b` and `a` producing an intermediate
+/// 128-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 64 bits in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_shrdv_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdvq))]
+pub unsafe fn _mm_maskz_shrdv_epi64(k: __mmask8, a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    let r = _mm_shrdv_epi64(a, b, c).as_i64x2();
+    let zero = _mm_setzero_si128().as_i64x2();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 32-bit integers in `b` and `a` producing an intermediate
+/// 64-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 32 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_shrdv_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshrdvd))]
+pub unsafe fn _mm512_shrdv_epi32(a: __m512i, b: __m512i, c: __m512i) -> __m512i {
+    transmute(vpshrdvd_512(b.as_i32x16(), a.as_i32x16(), c.as_i32x16()))
+}
+
+/// Concatenates packed 32-bit integers in `b` and `a` producing an intermediate
+/// 64-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 32 bits in `dst` using
+/// writemask `k` (elements are copied from `a` when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_shrdv_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshrdvd))]
+pub unsafe fn _mm512_mask_shrdv_epi32(a: __m512i, k: __mmask16, b: __m512i, c: __m512i) -> __m512i {
+    let r = _mm512_shrdv_epi32(a, b, c).as_i32x16();
+    transmute(simd_select_bitmask(k, r, a.as_i32x16()))
+}
+
+/// Concatenates packed 32-bit integers in `b` and `a` producing an intermediate
+/// 64-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 32 bits in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_shrdv_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshrdvd))]
+pub unsafe fn _mm512_maskz_shrdv_epi32(
+    k: __mmask16,
+    a: __m512i,
+    b: __m512i,
+    c: __m512i,
+) -> __m512i {
+    let r = _mm512_shrdv_epi32(a, b, c).as_i32x16();
+    let zero = _mm512_setzero_si512().as_i32x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 32-bit integers in `b` and `a` producing an intermediate
+/// 64-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 32 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_shrdv_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdvd))]
+pub unsafe fn _mm256_shrdv_epi32(a: __m256i, b: __m256i, c: __m256i) -> __m256i {
+    transmute(vpshrdvd_256(b.as_i32x8(), a.as_i32x8(), c.as_i32x8()))
+}
+
+/// Concatenates packed 32-bit integers in `b` and `a` producing an intermediate
+/// 64-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 32 bits in `dst` using
+/// writemask `k` (elements are copied from `a` when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_shrdv_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdvd))]
+pub unsafe fn _mm256_mask_shrdv_epi32(a: __m256i, k: __mmask8, b: __m256i, c: __m256i) -> __m256i {
+    let r = _mm256_shrdv_epi32(a, b, c).as_i32x8();
+    transmute(simd_select_bitmask(k, r, a.as_i32x8()))
+}
+
+/// Concatenates packed 32-bit integers in `b` and `a` producing an intermediate
+/// 64-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 32 bits in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_shrdv_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdvd))]
+pub unsafe fn _mm256_maskz_shrdv_epi32(k: __mmask8, a: __m256i, b: __m256i, c: __m256i) -> __m256i {
+    let r = _mm256_shrdv_epi32(a, b, c).as_i32x8();
+    let zero = _mm256_setzero_si256().as_i32x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 32-bit integers in `b` and `a` producing an intermediate
+/// 64-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 32 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_shrdv_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdvd))]
+pub unsafe fn _mm_shrdv_epi32(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    transmute(vpshrdvd_128(b.as_i32x4(), a.as_i32x4(), c.as_i32x4()))
+}
+
+/// Concatenates packed 32-bit integers in `b` and `a` producing an intermediate
+/// 64-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 32 bits in `dst` using
+/// writemask `k` (elements are copied from `a` when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_shrdv_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdvd))]
+pub unsafe fn _mm_mask_shrdv_epi32(a: __m128i, k: __mmask8, b: __m128i, c: __m128i) -> __m128i {
+    let r = _mm_shrdv_epi32(a, b, c).as_i32x4();
+    transmute(simd_select_bitmask(k, r, a.as_i32x4()))
+}
+
+/// Concatenates packed 32-bit integers in `b` and `a` producing an intermediate
+/// 64-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 32 bits in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_shrdv_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdvd))]
+pub unsafe fn _mm_maskz_shrdv_epi32(k: __mmask8, a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    let r = _mm_shrdv_epi32(a, b, c).as_i32x4();
+    let zero = _mm_setzero_si128().as_i32x4();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 16-bit integers in `b` and `a` producing an intermediate
+/// 32-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 16 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_shrdv_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshrdvw))]
+pub unsafe fn _mm512_shrdv_epi16(a: __m512i, b: __m512i, c: __m512i) -> __m512i {
+    transmute(vpshrdvw_512(b.as_i16x32(), a.as_i16x32(), c.as_i16x32()))
+}
+
+/// Concatenates packed 16-bit integers in `b` and `a` producing an intermediate
+/// 32-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 16 bits in `dst` using
+/// writemask `k` (elements are copied from `a` when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_shrdv_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshrdvw))]
+pub unsafe fn _mm512_mask_shrdv_epi16(a: __m512i, k: __mmask32, b: __m512i, c: __m512i) -> __m512i {
+    let r = _mm512_shrdv_epi16(a, b, c).as_i16x32();
+    transmute(simd_select_bitmask(k, r, a.as_i16x32()))
+}
+
+/// Concatenates packed 16-bit integers in `b` and `a` producing an intermediate
+/// 32-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 16 bits in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_shrdv_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshrdvw))]
+pub unsafe fn _mm512_maskz_shrdv_epi16(
+    k: __mmask32,
+    a: __m512i,
+    b: __m512i,
+    c: __m512i,
+) -> __m512i {
+    let r = _mm512_shrdv_epi16(a, b, c).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 16-bit integers in `b` and `a` producing an intermediate
+/// 32-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 16 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_shrdv_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdvw))]
+pub unsafe fn _mm256_shrdv_epi16(a: __m256i, b: __m256i, c: __m256i) -> __m256i {
+    transmute(vpshrdvw_256(b.as_i16x16(), a.as_i16x16(), c.as_i16x16()))
+}
+
+/// Concatenates packed 16-bit integers in `b` and `a` producing an intermediate
+/// 32-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 16 bits in `dst` using
+/// writemask `k` (elements are copied from `a` when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_shrdv_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdvw))]
+pub unsafe fn _mm256_mask_shrdv_epi16(a: __m256i, k: __mmask16, b: __m256i, c: __m256i) -> __m256i {
+    let r = _mm256_shrdv_epi16(a, b, c).as_i16x16();
+    transmute(simd_select_bitmask(k, r, a.as_i16x16()))
+}
+
+/// Concatenates packed 16-bit integers in `b` and `a` producing an intermediate
+/// 32-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 16 bits in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_shrdv_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdvw))]
+pub unsafe fn _mm256_maskz_shrdv_epi16(
+    k: __mmask16,
+    a: __m256i,
+    b: __m256i,
+    c: __m256i,
+) -> __m256i {
+    let r = _mm256_shrdv_epi16(a, b, c).as_i16x16();
+    let zero = _mm256_setzero_si256().as_i16x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 16-bit integers in `b` and `a` producing an intermediate
+/// 32-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 16 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_shrdv_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdvw))]
+pub unsafe fn _mm_shrdv_epi16(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    transmute(vpshrdvw_128(b.as_i16x8(), a.as_i16x8(), c.as_i16x8()))
+}
+
+/// Concatenates packed 16-bit integers in `b` and `a` producing an intermediate
+/// 32-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 16 bits in `dst` using
+/// writemask `k` (elements are copied from `a` when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_shrdv_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdvw))]
+pub unsafe fn _mm_mask_shrdv_epi16(a: __m128i, k: __mmask8, b: __m128i, c: __m128i) -> __m128i {
+    let r = _mm_shrdv_epi16(a, b, c).as_i16x8();
+    transmute(simd_select_bitmask(k, r, a.as_i16x8()))
+}
+
+/// Concatenates packed 16-bit integers in `b` and `a` producing an intermediate
+/// 32-bit result. Shifts the result right by the amount specified in the
+/// corresponding element of `c`, and stores the lower 16 bits in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_shrdv_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdvw))]
+pub unsafe fn _mm_maskz_shrdv_epi16(k: __mmask8, a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    let r = _mm_shrdv_epi16(a, b, c).as_i16x8();
+    let zero = _mm_setzero_si128().as_i16x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 64-bit integers in `a` and `b` producing an intermediate
+/// 128-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 64 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_shldi_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshldq, imm8 = 5))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_shldi_epi64(a: __m512i, b: __m512i, imm8: i32) -> __m512i {
+    transmute(vpshldvq_512(
+        a.as_i64x8(),
+        b.as_i64x8(),
+        _mm512_set1_epi64(imm8 as i64).as_i64x8(),
+    ))
+}
+
+/// Concatenates packed 64-bit integers in `a` and `b` producing an intermediate
+/// 128-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 64 bits in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_shldi_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshldq, imm8 = 5))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm512_mask_shldi_epi64(
+    src: __m512i,
+    k: __mmask8,
+    a: __m512i,
+    b: __m512i,
+    imm8: i32,
+) -> __m512i {
+    let r = _mm512_shldi_epi64(a, b, imm8).as_i64x8();
+    transmute(simd_select_bitmask(k, r, src.as_i64x8()))
+}
+
+/// Concatenates packed 64-bit integers in `a` and `b` producing an intermediate
+/// 128-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 64 bits in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_shldi_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshldq, imm8 = 5))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm512_maskz_shldi_epi64(k: __mmask8, a: __m512i, b: __m512i, imm8: i32) -> __m512i {
+    let r = _mm512_shldi_epi64(a, b, imm8).as_i64x8();
+    let zero = _mm512_setzero_si512().as_i64x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 64-bit integers in `a` and `b` producing an intermediate
+/// 128-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 64 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_shldi_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldq, imm8 = 5))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm256_shldi_epi64(a: __m256i, b: __m256i, imm8: i32) -> __m256i {
+    transmute(vpshldvq_256(
+        a.as_i64x4(),
+        b.as_i64x4(),
+        _mm256_set1_epi64x(imm8 as i64).as_i64x4(),
+    ))
+}
+
+/// Concatenates packed 64-bit integers in `a` and `b` producing an intermediate
+/// 128-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 64 bits in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_shldi_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldq, imm8 = 5))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm256_mask_shldi_epi64(
+    src: __m256i,
+    k: __mmask8,
+    a: __m256i,
+    b: __m256i,
+    imm8: i32,
+) -> __m256i {
+    let r = _mm256_shldi_epi64(a, b, imm8).as_i64x4();
+    transmute(simd_select_bitmask(k, r, src.as_i64x4()))
+}
+
+/// Concatenates packed 64-bit integers in `a` and `b` producing an intermediate
+/// 128-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 64 bits in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_shldi_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldq, imm8 = 5))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm256_maskz_shldi_epi64(k: __mmask8, a: __m256i, b: __m256i, imm8: i32) -> __m256i {
+    let r = _mm256_shldi_epi64(a, b, imm8).as_i64x4();
+    let zero = _mm256_setzero_si256().as_i64x4();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 64-bit integers in `a` and `b` producing an intermediate
+/// 128-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 64 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_shldi_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldq, imm8 = 5))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_shldi_epi64(a: __m128i, b: __m128i, imm8: i32) -> __m128i {
+    transmute(vpshldvq_128(
+        a.as_i64x2(),
+        b.as_i64x2(),
+        _mm_set1_epi64x(imm8 as i64).as_i64x2(),
+    ))
+}
+
+/// Concatenates packed 64-bit integers in `a` and `b` producing an intermediate
+/// 128-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 64 bits in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_shldi_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldq, imm8 = 5))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm_mask_shldi_epi64(
+    src: __m128i,
+    k: __mmask8,
+    a: __m128i,
+    b: __m128i,
+    imm8: i32,
+) -> __m128i {
+    let r = _mm_shldi_epi64(a, b, imm8).as_i64x2();
+    transmute(simd_select_bitmask(k, r, src.as_i64x2()))
+}
+
+/// Concatenates packed 64-bit integers in `a` and `b` producing an intermediate
+/// 128-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 64 bits in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_shldi_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldq, imm8 = 5))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm_maskz_shldi_epi64(k: __mmask8, a: __m128i, b: __m128i, imm8: i32) -> __m128i {
+    let r = _mm_shldi_epi64(a, b, imm8).as_i64x2();
+    let zero = _mm_setzero_si128().as_i64x2();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 32-bit integers in `a` and `b` producing an intermediate
+/// 64-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 32 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_shldi_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshldd, imm8 = 5))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_shldi_epi32(a: __m512i, b: __m512i, imm8: i32) -> __m512i {
+    transmute(vpshldvd_512(
+        a.as_i32x16(),
+        b.as_i32x16(),
+        _mm512_set1_epi32(imm8 as i32).as_i32x16(),
+    ))
+}
+
+/// Concatenates packed 32-bit integers in `a` and `b` producing an intermediate
+/// 64-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 32 bits in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_shldi_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshldd, imm8 = 5))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm512_mask_shldi_epi32(
+    src: __m512i,
+    k: __mmask16,
+    a: __m512i,
+    b: __m512i,
+    imm8: i32,
+) -> __m512i {
+    let r = _mm512_shldi_epi32(a, b, imm8).as_i32x16();
+    transmute(simd_select_bitmask(k, r, src.as_i32x16()))
+}
+
+/// Concatenates packed 32-bit integers in `a` and `b` producing an intermediate
+/// 64-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 32 bits in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_shldi_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshldd, imm8 = 5))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm512_maskz_shldi_epi32(k: __mmask16, a: __m512i, b: __m512i, imm8: i32) -> __m512i {
+    let r = _mm512_shldi_epi32(a, b, imm8).as_i32x16();
+    let zero = _mm512_setzero_si512().as_i32x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 32-bit integers in `a` and `b` producing an intermediate
+/// 64-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 32 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_shldi_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldd, imm8 = 5))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm256_shldi_epi32(a: __m256i, b: __m256i, imm8: i32) -> __m256i {
+    transmute(vpshldvd_256(
+        a.as_i32x8(),
+        b.as_i32x8(),
+        _mm256_set1_epi32(imm8 as i32).as_i32x8(),
+    ))
+}
+
+/// Concatenates packed 32-bit integers in `a` and `b` producing an intermediate
+/// 64-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 32 bits in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_shldi_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldd, imm8 = 5))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm256_mask_shldi_epi32(
+    src: __m256i,
+    k: __mmask8,
+    a: __m256i,
+    b: __m256i,
+    imm8: i32,
+) -> __m256i {
+    let r = _mm256_shldi_epi32(a, b, imm8).as_i32x8();
+    transmute(simd_select_bitmask(k, r, src.as_i32x8()))
+}
+
+/// Concatenates packed 32-bit integers in `a` and `b` producing an intermediate
+/// 64-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 32 bits in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_shldi_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldd, imm8 = 5))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm256_maskz_shldi_epi32(k: __mmask8, a: __m256i, b: __m256i, imm8: i32) -> __m256i {
+    let r = _mm256_shldi_epi32(a, b, imm8).as_i32x8();
+    let zero = _mm256_setzero_si256().as_i32x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 32-bit integers in `a` and `b` producing an intermediate
+/// 64-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 32 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_shldi_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldd, imm8 = 5))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_shldi_epi32(a: __m128i, b: __m128i, imm8: i32) -> __m128i {
+    transmute(vpshldvd_128(
+        a.as_i32x4(),
+        b.as_i32x4(),
+        _mm_set1_epi32(imm8 as i32).as_i32x4(),
+    ))
+}
+
+/// Concatenates packed 32-bit integers in `a` and `b` producing an intermediate
+/// 64-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 32 bits in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_shldi_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldd, imm8 = 5))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm_mask_shldi_epi32(
+    src: __m128i,
+    k: __mmask8,
+    a: __m128i,
+    b: __m128i,
+    imm8: i32,
+) -> __m128i {
+    let r = _mm_shldi_epi32(a, b, imm8).as_i32x4();
+    transmute(simd_select_bitmask(k, r, src.as_i32x4()))
+}
+
+/// Concatenates packed 32-bit integers in `a` and `b` producing an intermediate
+/// 64-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 32 bits in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_shldi_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldd, imm8 = 5))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm_maskz_shldi_epi32(k: __mmask8, a: __m128i, b: __m128i, imm8: i32) -> __m128i {
+    let r = _mm_shldi_epi32(a, b, imm8).as_i32x4();
+    let zero = _mm_setzero_si128().as_i32x4();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 16-bit integers in `a` and `b` producing an intermediate
+/// 32-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 16 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_shldi_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshldw, imm8 = 5))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_shldi_epi16(a: __m512i, b: __m512i, imm8: i32) -> __m512i {
+    transmute(vpshldvw_512(
+        a.as_i16x32(),
+        b.as_i16x32(),
+        _mm512_set1_epi16(imm8 as i16).as_i16x32(),
+    ))
+}
+
+/// Concatenates packed 16-bit integers in `a` and `b` producing an intermediate
+/// 32-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 16 bits in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_shldi_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshldw, imm8 = 5))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm512_mask_shldi_epi16(
+    src: __m512i,
+    k: __mmask32,
+    a: __m512i,
+    b: __m512i,
+    imm8: i32,
+) -> __m512i {
+    let r = _mm512_shldi_epi16(a, b, imm8).as_i16x32();
+    transmute(simd_select_bitmask(k, r, src.as_i16x32()))
+}
+
+/// Concatenates packed 16-bit integers in `a` and `b` producing an intermediate
+/// 32-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 16 bits in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_shldi_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshldw, imm8 = 5))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm512_maskz_shldi_epi16(k: __mmask32, a: __m512i, b: __m512i, imm8: i32) -> __m512i {
+    let r = _mm512_shldi_epi16(a, b, imm8).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 16-bit integers in `a` and `b` producing an intermediate
+/// 32-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 16 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_shldi_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldw, imm8 = 5))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm256_shldi_epi16(a: __m256i, b: __m256i, imm8: i32) -> __m256i {
+    transmute(vpshldvw_256(
+        a.as_i16x16(),
+        b.as_i16x16(),
+        _mm256_set1_epi16(imm8 as i16).as_i16x16(),
+    ))
+}
+
+/// Concatenates packed 16-bit integers in `a` and `b` producing an intermediate
+/// 32-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 16 bits in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_shldi_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldw, imm8 = 5))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm256_mask_shldi_epi16(
+    src: __m256i,
+    k: __mmask16,
+    a: __m256i,
+    b: __m256i,
+    imm8: i32,
+) -> __m256i {
+    let r = _mm256_shldi_epi16(a, b, imm8).as_i16x16();
+    transmute(simd_select_bitmask(k, r, src.as_i16x16()))
+}
+
+/// Concatenates packed 16-bit integers in `a` and `b` producing an intermediate
+/// 32-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 16 bits in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_shldi_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldw, imm8 = 5))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm256_maskz_shldi_epi16(k: __mmask16, a: __m256i, b: __m256i, imm8: i32) -> __m256i {
+    let r = _mm256_shldi_epi16(a, b, imm8).as_i16x16();
+    let zero = _mm256_setzero_si256().as_i16x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 16-bit integers in `a` and `b` producing an intermediate
+/// 32-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 16 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_shldi_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldw, imm8 = 5))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_shldi_epi16(a: __m128i, b: __m128i, imm8: i32) -> __m128i {
+    transmute(vpshldvw_128(
+        a.as_i16x8(),
+        b.as_i16x8(),
+        _mm_set1_epi16(imm8 as i16).as_i16x8(),
+    ))
+}
+
+/// Concatenates packed 16-bit integers in `a` and `b` producing an intermediate
+/// 32-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 16 bits in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_shldi_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldw, imm8 = 5))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm_mask_shldi_epi16(
+    src: __m128i,
+    k: __mmask8,
+    a: __m128i,
+    b: __m128i,
+    imm8: i32,
+) -> __m128i {
+    let r = _mm_shldi_epi16(a, b, imm8).as_i16x8();
+    transmute(simd_select_bitmask(k, r, src.as_i16x8()))
+}
+
+/// Concatenates packed 16-bit integers in `a` and `b` producing an intermediate
+/// 32-bit result. Shifts the result left by `imm8` bits, and stores the upper
+/// 16 bits in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_shldi_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshldw, imm8 = 5))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm_maskz_shldi_epi16(k: __mmask8, a: __m128i, b: __m128i, imm8: i32) -> __m128i {
+    let r = _mm_shldi_epi16(a, b, imm8).as_i16x8();
+    let zero = _mm_setzero_si128().as_i16x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 64-bit integers in `b` and `a` producing an intermediate
+/// 128-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 64 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_shrdi_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshrdq, imm8 = 5))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_shrdi_epi64(a: __m512i, b: __m512i, imm8: i32) -> __m512i {
+    transmute(vpshrdvq_512(
+        b.as_i64x8(),
+        a.as_i64x8(),
+        _mm512_set1_epi64(imm8 as i64).as_i64x8(),
+    ))
+}
+
+/// Concatenates packed 64-bit integers in `b` and `a` producing an intermediate
+/// 128-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 64 bits in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_shrdi_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshrdq, imm8 = 5))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm512_mask_shrdi_epi64(
+    src: __m512i,
+    k: __mmask8,
+    a: __m512i,
+    b: __m512i,
+    imm8: i32,
+) -> __m512i {
+    let r = _mm512_shrdi_epi64(a, b, imm8).as_i64x8();
+    transmute(simd_select_bitmask(k, r, src.as_i64x8()))
+}
+
+/// Concatenates packed 64-bit integers in `b` and `a` producing an intermediate
+/// 128-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 64 bits in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_shrdi_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshrdq, imm8 = 5))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm512_maskz_shrdi_epi64(k: __mmask8, a: __m512i, b: __m512i, imm8: i32) -> __m512i {
+    let r = _mm512_shrdi_epi64(a, b, imm8).as_i64x8();
+    let zero = _mm512_setzero_si512().as_i64x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 64-bit integers in `b` and `a` producing an intermediate
+/// 128-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 64 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_shrdi_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdq, imm8 = 5))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm256_shrdi_epi64(a: __m256i, b: __m256i, imm8: i32) -> __m256i {
+    transmute(vpshrdvq_256(
+        b.as_i64x4(),
+        a.as_i64x4(),
+        _mm256_set1_epi64x(imm8 as i64).as_i64x4(),
+    ))
+}
+
+/// Concatenates packed 64-bit integers in `b` and `a` producing an intermediate
+/// 128-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 64 bits in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_shrdi_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdq, imm8 = 5))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm256_mask_shrdi_epi64(
+    src: __m256i,
+    k: __mmask8,
+    a: __m256i,
+    b: __m256i,
+    imm8: i32,
+) -> __m256i {
+    let r = _mm256_shrdi_epi64(a, b, imm8).as_i64x4();
+    transmute(simd_select_bitmask(k, r, src.as_i64x4()))
+}
+
+/// Concatenates packed 64-bit integers in `b` and `a` producing an intermediate
+/// 128-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 64 bits in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_shrdi_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdq, imm8 = 5))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm256_maskz_shrdi_epi64(k: __mmask8, a: __m256i, b: __m256i, imm8: i32) -> __m256i {
+    let r = _mm256_shrdi_epi64(a, b, imm8).as_i64x4();
+    let zero = _mm256_setzero_si256().as_i64x4();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 64-bit integers in `b` and `a` producing an intermediate
+/// 128-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 64 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_shrdi_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdq, imm8 = 5))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_shrdi_epi64(a: __m128i, b: __m128i, imm8: i32) -> __m128i {
+    transmute(vpshrdvq_128(
+        b.as_i64x2(),
+        a.as_i64x2(),
+        _mm_set1_epi64x(imm8 as i64).as_i64x2(),
+    ))
+}
+
+/// Concatenates packed 64-bit integers in `b` and `a` producing an intermediate
+/// 128-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 64 bits in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_shrdi_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdq, imm8 = 5))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm_mask_shrdi_epi64(
+    src: __m128i,
+    k: __mmask8,
+    a: __m128i,
+    b: __m128i,
+    imm8: i32,
+) -> __m128i {
+    let r = _mm_shrdi_epi64(a, b, imm8).as_i64x2();
+    transmute(simd_select_bitmask(k, r, src.as_i64x2()))
+}
+
+/// Concatenates packed 64-bit integers in `b` and `a` producing an intermediate
+/// 128-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 64 bits in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_shrdi_epi64)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdq, imm8 = 5))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm_maskz_shrdi_epi64(k: __mmask8, a: __m128i, b: __m128i, imm8: i32) -> __m128i {
+    let r = _mm_shrdi_epi64(a, b, imm8).as_i64x2();
+    let zero = _mm_setzero_si128().as_i64x2();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 32-bit integers in `b` and `a` producing an intermediate
+/// 64-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 32 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_shrdi_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshrdd, imm8 = 5))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_shrdi_epi32(a: __m512i, b: __m512i, imm8: i32) -> __m512i {
+    transmute(vpshrdvd_512(
+        b.as_i32x16(),
+        a.as_i32x16(),
+        _mm512_set1_epi32(imm8 as i32).as_i32x16(),
+    ))
+}
+
+/// Concatenates packed 32-bit integers in `b` and `a` producing an intermediate
+/// 64-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 32 bits in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_shrdi_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshrdd, imm8 = 5))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm512_mask_shrdi_epi32(
+    src: __m512i,
+    k: __mmask16,
+    a: __m512i,
+    b: __m512i,
+    imm8: i32,
+) -> __m512i {
+    let r = _mm512_shrdi_epi32(a, b, imm8).as_i32x16();
+    transmute(simd_select_bitmask(k, r, src.as_i32x16()))
+}
+
+/// Concatenates packed 32-bit integers in `b` and `a` producing an intermediate
+/// 64-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 32 bits in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_shrdi_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshrdd, imm8 = 5))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm512_maskz_shrdi_epi32(k: __mmask16, a: __m512i, b: __m512i, imm8: i32) -> __m512i {
+    let r = _mm512_shrdi_epi32(a, b, imm8).as_i32x16();
+    let zero = _mm512_setzero_si512().as_i32x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 32-bit integers in `b` and `a` producing an intermediate
+/// 64-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 32 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_shrdi_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdd, imm8 = 5))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm256_shrdi_epi32(a: __m256i, b: __m256i, imm8: i32) -> __m256i {
+    transmute(vpshrdvd_256(
+        b.as_i32x8(),
+        a.as_i32x8(),
+        _mm256_set1_epi32(imm8 as i32).as_i32x8(),
+    ))
+}
+
+/// Concatenates packed 32-bit integers in `b` and `a` producing an intermediate
+/// 64-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 32 bits in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_shrdi_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdd, imm8 = 5))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm256_mask_shrdi_epi32(
+    src: __m256i,
+    k: __mmask8,
+    a: __m256i,
+    b: __m256i,
+    imm8: i32,
+) -> __m256i {
+    let r = _mm256_shrdi_epi32(a, b, imm8).as_i32x8();
+    transmute(simd_select_bitmask(k, r, src.as_i32x8()))
+}
+
+/// Concatenates packed 32-bit integers in `b` and `a` producing an intermediate
+/// 64-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 32 bits in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_shrdi_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdd, imm8 = 5))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm256_maskz_shrdi_epi32(k: __mmask8, a: __m256i, b: __m256i, imm8: i32) -> __m256i {
+    let r = _mm256_shrdi_epi32(a, b, imm8).as_i32x8();
+    let zero = _mm256_setzero_si256().as_i32x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 32-bit integers in `b` and `a` producing an intermediate
+/// 64-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 32 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_shrdi_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdd, imm8 = 5))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_shrdi_epi32(a: __m128i, b: __m128i, imm8: i32) -> __m128i {
+    transmute(vpshrdvd_128(
+        b.as_i32x4(),
+        a.as_i32x4(),
+        _mm_set1_epi32(imm8 as i32).as_i32x4(),
+    ))
+}
+
+/// Concatenates packed 32-bit integers in `b` and `a` producing an intermediate
+/// 64-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 32 bits in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_shrdi_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdd, imm8 = 5))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm_mask_shrdi_epi32(
+    src: __m128i,
+    k: __mmask8,
+    a: __m128i,
+    b: __m128i,
+    imm8: i32,
+) -> __m128i {
+    let r = _mm_shrdi_epi32(a, b, imm8).as_i32x4();
+    transmute(simd_select_bitmask(k, r, src.as_i32x4()))
+}
+
+/// Concatenates packed 32-bit integers in `b` and `a` producing an intermediate
+/// 64-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 32 bits in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_shrdi_epi32)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdd, imm8 = 5))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm_maskz_shrdi_epi32(k: __mmask8, a: __m128i, b: __m128i, imm8: i32) -> __m128i {
+    let r = _mm_shrdi_epi32(a, b, imm8).as_i32x4();
+    let zero = _mm_setzero_si128().as_i32x4();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 16-bit integers in `b` and `a` producing an intermediate
+/// 32-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 16 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_shrdi_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshrdw, imm8 = 5))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_shrdi_epi16(a: __m512i, b: __m512i, imm8: i32) -> __m512i {
+    transmute(vpshrdvw_512(
+        b.as_i16x32(),
+        a.as_i16x32(),
+        _mm512_set1_epi16(imm8 as i16).as_i16x32(),
+    ))
+}
+
+/// Concatenates packed 16-bit integers in `b` and `a` producing an intermediate
+/// 32-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 16 bits in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_shrdi_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshrdw, imm8 = 5))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm512_mask_shrdi_epi16(
+    src: __m512i,
+    k: __mmask32,
+    a: __m512i,
+    b: __m512i,
+    imm8: i32,
+) -> __m512i {
+    let r = _mm512_shrdi_epi16(a, b, imm8).as_i16x32();
+    transmute(simd_select_bitmask(k, r, src.as_i16x32()))
+}
+
+/// Concatenates packed 16-bit integers in `b` and `a` producing an intermediate
+/// 32-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 16 bits in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_shrdi_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2")]
+#[cfg_attr(test, assert_instr(vpshrdw, imm8 = 5))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm512_maskz_shrdi_epi16(k: __mmask32, a: __m512i, b: __m512i, imm8: i32) -> __m512i {
+    let r = _mm512_shrdi_epi16(a, b, imm8).as_i16x32();
+    let zero = _mm512_setzero_si512().as_i16x32();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 16-bit integers in `b` and `a` producing an intermediate
+/// 32-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 16 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_shrdi_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdw, imm8 = 5))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm256_shrdi_epi16(a: __m256i, b: __m256i, imm8: i32) -> __m256i {
+    transmute(vpshrdvw_256(
+        b.as_i16x16(),
+        a.as_i16x16(),
+        _mm256_set1_epi16(imm8 as i16).as_i16x16(),
+    ))
+}
+
+/// Concatenates packed 16-bit integers in `b` and `a` producing an intermediate
+/// 32-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 16 bits in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_shrdi_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdw, imm8 = 5))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm256_mask_shrdi_epi16(
+    src: __m256i,
+    k: __mmask16,
+    a: __m256i,
+    b: __m256i,
+    imm8: i32,
+) -> __m256i {
+    let r = _mm256_shrdi_epi16(a, b, imm8).as_i16x16();
+    transmute(simd_select_bitmask(k, r, src.as_i16x16()))
+}
+
+/// Concatenates packed 16-bit integers in `b` and `a` producing an intermediate
+/// 32-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 16 bits in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_shrdi_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdw, imm8 = 5))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm256_maskz_shrdi_epi16(k: __mmask16, a: __m256i, b: __m256i, imm8: i32) -> __m256i {
+    let r = _mm256_shrdi_epi16(a, b, imm8).as_i16x16();
+    let zero = _mm256_setzero_si256().as_i16x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Concatenates packed 16-bit integers in `b` and `a` producing an intermediate
+/// 32-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 16 bits in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_shrdi_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdw, imm8 = 5))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_shrdi_epi16(a: __m128i, b: __m128i, imm8: i32) -> __m128i {
+    transmute(vpshrdvw_128(
+        b.as_i16x8(),
+        a.as_i16x8(),
+        _mm_set1_epi16(imm8 as i16).as_i16x8(),
+    ))
+}
+
+/// Concatenates packed 16-bit integers in `b` and `a` producing an intermediate
+/// 32-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 16 bits in `dst` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_shrdi_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdw, imm8 = 5))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm_mask_shrdi_epi16(
+    src: __m128i,
+    k: __mmask8,
+    a: __m128i,
+    b: __m128i,
+    imm8: i32,
+) -> __m128i {
+    let r = _mm_shrdi_epi16(a, b, imm8).as_i16x8();
+    transmute(simd_select_bitmask(k, r, src.as_i16x8()))
+}
+
+/// Concatenates packed 16-bit integers in `b` and `a` producing an intermediate
+/// 32-bit result. Shifts the result right by `imm8` bits, and stores the lower
+/// 16 bits in `dst` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_shrdi_epi16)
+#[inline]
+#[target_feature(enable = "avx512vbmi2,avx512vl")]
+#[cfg_attr(test, assert_instr(vpshrdw, imm8 = 5))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm_maskz_shrdi_epi16(k: __mmask8, a: __m128i, b: __m128i, imm8: i32) -> __m128i {
+    let r = _mm_shrdi_epi16(a, b, imm8).as_i16x8();
+    let zero = _mm_setzero_si128().as_i16x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.avx512.mask.compress.b.512"]
+    fn vpcompressb_512(a: i8x64, src: i8x64, k: __mmask64) -> i8x64;
+    #[link_name = "llvm.x86.avx512.mask.compress.store.b.512"]
+    fn vpcompressbstore_512(base_addr: *mut u8, a: i8x64, k: __mmask64);
+    #[link_name = "llvm.x86.avx512.mask.compress.b.256"]
+    fn vpcompressb_256(a: i8x32, src: i8x32, k: __mmask32) -> i8x32;
+    #[link_name = "llvm.x86.avx512.mask.compress.store.b.256"]
+    fn vpcompressbstore_256(base_addr: *mut u8, a: i8x32, k: __mmask32);
+    #[link_name = "llvm.x86.avx512.mask.compress.b.128"]
+    fn vpcompressb_128(a: i8x16, src: i8x16, k: __mmask16) -> i8x16;
+    #[link_name = "llvm.x86.avx512.mask.compress.store.b.128"]
+    fn vpcompressbstore_128(base_addr: *mut u8, a: i8x16, k: __mmask16);
+    #[link_name = "llvm.x86.avx512.mask.compress.w.512"]
+    fn vpcompressw_512(a: i16x32, src: i16x32, k: __mmask32) -> i16x32;
+    #[link_name = "llvm.x86.avx512.mask.compress.store.w.512"]
+    fn vpcompresswstore_512(base_addr: *mut u8, a: i16x32, k: __mmask32);
+    #[link_name = "llvm.x86.avx512.mask.compress.w.256"]
+    fn vpcompressw_256(a: i16x16, src: i16x16, k: __mmask16) -> i16x16;
+    #[link_name = "llvm.x86.avx512.mask.compress.store.w.256"]
+    fn vpcompresswstore_256(base_addr: *mut u8, a: i16x16, k: __mmask16);
+    #[link_name = "llvm.x86.avx512.mask.compress.w.128"]
+    fn vpcompressw_128(a: i16x8, src: i16x8, k: __mmask8) -> i16x8;
+    #[link_name = "llvm.x86.avx512.mask.compress.store.w.128"]
+    fn vpcompresswstore_128(base_addr: *mut u8, a: i16x8, k: __mmask8);
+    #[link_name = "llvm.x86.avx512.mask.expand.b.512"]
+    fn vpexpandb_512(a: i8x64, src: i8x64, k: __mmask64) -> i8x64;
+    #[link_name = "llvm.x86.avx512.mask.expand.load.b.512"]
+    fn vpexpandbload_512(mem_addr: *const u8, src: i8x64, k: __mmask64) -> i8x64;
+    #[link_name = "llvm.x86.avx512.mask.expand.b.256"]
+    fn vpexpandb_256(a: i8x32, src: i8x32, k: __mmask32) -> i8x32;
+    #[link_name = "llvm.x86.avx512.mask.expand.load.b.256"]
+    fn vpexpandbload_256(mem_addr: *const u8, src: i8x32, k: __mmask32) -> i8x32;
+    #[link_name = "llvm.x86.avx512.mask.expand.b.128"]
+    fn vpexpandb_128(a: i8x16, src: i8x16, k: __mmask16) -> i8x16;
+    #[link_name = "llvm.x86.avx512.mask.expand.load.b.128"]
+    fn vpexpandbload_128(mem_addr: *const u8, src: i8x16, k: __mmask16) -> i8x16;
+    #[link_name = "llvm.x86.avx512.mask.expand.w.512"]
+    fn vpexpandw_512(a: i16x32, src: i16x32, k: __mmask32) -> i16x32;
+    #[link_name = "llvm.x86.avx512.mask.expand.load.w.512"]
+    fn vpexpandwload_512(mem_addr: *const u8, src: i16x32, k: __mmask32) -> i16x32;
+    #[link_name = "llvm.x86.avx512.mask.expand.w.256"]
+    fn vpexpandw_256(a: i16x16, src: i16x16, k: __mmask16) -> i16x16;
+    #[link_name = "llvm.x86.avx512.mask.expand.load.w.256"]
+    fn vpexpandwload_256(mem_addr: *const u8, src: i16x16, k: __mmask16) -> i16x16;
+    #[link_name = "llvm.x86.avx512.mask.expand.w.128"]
+    fn vpexpandw_128(a: i16x8, src: i16x8, k: __mmask8) -> i16x8;
+    #[link_name = "llvm.x86.avx512.mask.expand.load.w.128"]
+    fn vpexpandwload_128(mem_addr: *const u8, src: i16x8, k: __mmask8) -> i16x8;
+    #[link_name = "llvm.fshl.v8i64"]
+    fn vpshldvq_512(a: i64x8, b: i64x8, c: i64x8) -> i64x8;
+    #[link_name = "llvm.fshl.v4i64"]
+    fn vpshldvq_256(a: i64x4, b: i64x4, c: i64x4) -> i64x4;
+    #[link_name = "llvm.fshl.v2i64"]
+    fn vpshldvq_128(a: i64x2, b: i64x2, c: i64x2) -> i64x2;
+    #[link_name = "llvm.fshl.v16i32"]
+    fn vpshldvd_512(a: i32x16, b: i32x16, c: i32x16) -> i32x16;
+    #[link_name = "llvm.fshl.v8i32"]
+    fn vpshldvd_256(a: i32x8, b: i32x8, c: i32x8) -> i32x8;
+    #[link_name = "llvm.fshl.v4i32"]
+    fn vpshldvd_128(a: i32x4, b: i32x4, c: i32x4) -> i32x4;
+    #[link_name = "llvm.fshl.v32i16"]
+    fn vpshldvw_512(a: i16x32, b: i16x32, c: i16x32) -> i16x32;
+    #[link_name = "llvm.fshl.v16i16"]
+    fn vpshldvw_256(a: i16x16, b: i16x16, c: i16x16) -> i16x16;
+    #[link_name = "llvm.fshl.v8i16"]
+    fn vpshldvw_128(a: i16x8, b: i16x8, c: i16x8) -> i16x8;
+    #[link_name = "llvm.fshr.v8i64"]
+    fn vpshrdvq_512(a: i64x8, b: i64x8, c: i64x8) -> i64x8;
+    #[link_name = "llvm.fshr.v4i64"]
+    fn vpshrdvq_256(a: i64x4, b: i64x4, c: i64x4) -> i64x4;
+    #[link_name = "llvm.fshr.v2i64"]
+    fn vpshrdvq_128(a: i64x2, b: i64x2, c: i64x2) -> i64x2;
+    #[link_name = "llvm.fshr.v16i32"]
+    fn vpshrdvd_512(a: i32x16, b: i32x16, c: i32x16) -> i32x16;
+    #[link_name = "llvm.fshr.v8i32"]
+    fn vpshrdvd_256(a: i32x8, b: i32x8, c: i32x8) -> i32x8;
+    #[link_name = "llvm.fshr.v4i32"]
+    fn vpshrdvd_128(a: i32x4, b: i32x4, c: i32x4) -> i32x4;
+    #[link_name = "llvm.fshr.v32i16"]
+    fn vpshrdvw_512(a: i16x32, b: i16x32, c: i16x32) -> i16x32;
+    #[link_name = "llvm.fshr.v16i16"]
+    fn vpshrdvw_256(a: i16x16, b: i16x16, c: i16x16) -> i16x16;
+    #[link_name = "llvm.fshr.v8i16"]
+    fn vpshrdvw_128(a: i16x8, b: i16x8, c: i16x8) -> i16x8;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use stdsimd_test::simd_test;
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_mask_compress_epi8() {
+        #[rustfmt::skip]
+        let src = _mm512_set_epi8(
+            -64, -63, -62, -61, -60, -59, -58, -57, -56, -55, -54, -53, -52, -51, -50, -49,
+            -48, -47, -46, -45, -44, -43, -42, -41, -40, -39, -38, -37, -36, -35, -34, -33,
+            -32, -31, -30, -29, -28, -27, -26, -25, -24, -23, -22, -21, -20, -19, -18, -17,
+            -16, -15, -14, -13, -12, -11, -10, -9, -8, -7, -6, -5, -4, -3, -2, -1,
+        );
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            64, 63, 62, 61, 60, 59, 58, 57, 56, 55, 54, 53, 52, 51, 50, 49,
+            48, 47, 46, 45, 44, 43, 42, 41, 40, 39, 38, 37, 36, 35, 34, 33,
+            32, 31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17,
+            16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_mask_compress_epi8(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_compress_epi8(
+            src,
+            0b10011001_01010101_00110101_11001010_01101001_10010110_11001010_00110101,
+            a,
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            -64, -63, -62, -61, -60, -59, -58, -57, -56, -55, -54, -53, -52, -51, -50, -49,
+            -48, -47, -46, -45, -44, -43, -42, -41, -40, -39, -38, -37, -36, -35, -34, -33,
+            64, 61, 60, 57, 55, 53, 51, 49, 46, 45, 43, 41, 40, 39, 36, 34,
+            31, 30, 28, 25, 24, 21, 19, 18, 16, 15, 12, 10, 6, 5, 3, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_maskz_compress_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            64, 63, 62, 61, 60, 59, 58, 57, 56, 55, 54, 53, 52, 51, 50, 49,
+            48, 47, 46, 45, 44, 43, 42, 41, 40, 39, 38, 37, 36, 35, 34, 33,
+            32, 31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17,
+            16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_maskz_compress_epi8(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_compress_epi8(
+            0b10011001_01010101_00110101_11001010_01101001_10010110_11001010_00110101,
+            a,
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            64, 61, 60, 57, 55, 53, 51, 49, 46, 45, 43, 41, 40, 39, 36, 34,
+            31, 30, 28, 25, 24, 21, 19, 18, 16, 15, 12, 10, 6, 5, 3, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_mask_compressstoreu_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            64, 63, 62, 61, 60, 59, 58, 57, 56, 55, 54, 53, 52, 51, 50, 49,
+            48, 47, 46, 45, 44, 43, 42, 41, 40, 39, 38, 37, 36, 35, 34, 33,
+            32, 31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17,
+            16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let mut r = _mm512_setzero_si512();
+        _mm512_mask_compressstoreu_epi8(
+            &mut r as *mut _ as *mut u8,
+            0b10011001_01010101_00110101_11001010_01101001_10010110_11001010_00110101,
+            a,
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            64, 61, 60, 57, 55, 53, 51, 49, 46, 45, 43, 41, 40, 39, 36, 34,
+            31, 30, 28, 25, 24, 21, 19, 18, 16, 15, 12, 10, 6, 5, 3, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_mask_compress_epi8() {
+        #[rustfmt::skip]
+        let src = _mm256_setr_epi8(
+            -1, -2, -3, -4, -5, -6, -7, -8, -9, -10, -11, -12, -13, -14, -15, -16,
+            -17, -18, -19, -20, -21, -22, -23, -24, -25, -26, -27, -28, -29, -30, -31, -32,
+        );
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+            17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+        );
+        let r = _mm256_mask_compress_epi8(src, 0, a);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_compress_epi8(src, 0b01101001_10010110_11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            1, 3, 5, 6, 10, 12, 15, 16, 18, 19, 21, 24, 25, 28, 30, 31,
+            -17, -18, -19, -20, -21, -22, -23, -24, -25, -26, -27, -28, -29, -30, -31, -32,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_maskz_compress_epi8() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+            17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+        );
+        let r = _mm256_maskz_compress_epi8(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_compress_epi8(0b01101001_10010110_11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            1, 3, 5, 6, 10, 12, 15, 16, 18, 19, 21, 24, 25, 28, 30, 31,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_mask_compressstoreu_epi8() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+            17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+        );
+        let mut r = _mm256_setzero_si256();
+        _mm256_mask_compressstoreu_epi8(
+            &mut r as *mut _ as *mut u8,
+            0b01101001_10010110_11001010_00110101,
+            a,
+        );
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            1, 3, 5, 6, 10, 12, 15, 16, 18, 19, 21, 24, 25, 28, 30, 31,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_mask_compress_epi8() {
+        #[rustfmt::skip]
+        let src = _mm_setr_epi8(
+            -1, -2, -3, -4, -5, -6, -7, -8,
+            -9, -10, -11, -12, -13, -14, -15, -16,
+        );
+        let a = _mm_setr_epi8(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let r = _mm_mask_compress_epi8(src, 0, a);
+        assert_eq_m128i(r, src);
+        let r = _mm_mask_compress_epi8(src, 0b11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm_setr_epi8(
+            1, 3, 5, 6, 10, 12, 15, 16,
+            -9, -10, -11, -12, -13, -14, -15, -16,
+        );
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_maskz_compress_epi8() {
+        let a = _mm_setr_epi8(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let r = _mm_maskz_compress_epi8(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_compress_epi8(0b11001010_00110101, a);
+        let e = _mm_setr_epi8(1, 3, 5, 6, 10, 12, 15, 16, 0, 0, 0, 0, 0, 0, 0, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_mask_compressstoreu_epi8() {
+        let a = _mm_setr_epi8(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let mut r = _mm_setzero_si128();
+        _mm_mask_compressstoreu_epi8(&mut r as *mut _ as *mut u8, 0b11001010_00110101, a);
+        let e = _mm_setr_epi8(1, 3, 5, 6, 10, 12, 15, 16, 0, 0, 0, 0, 0, 0, 0, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_mask_compress_epi16() {
+        #[rustfmt::skip]
+        let src = _mm512_set_epi16(
+            -32, -31, -30, -29, -28, -27, -26, -25, -24, -23, -22, -21, -20, -19, -18, -17,
+            -16, -15, -14, -13, -12, -11, -10, -9, -8, -7, -6, -5, -4, -3, -2, -1,
+        );
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            32, 31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17,
+            16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_mask_compress_epi16(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_compress_epi16(src, 0b01101001_10010110_11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            -32, -31, -30, -29, -28, -27, -26, -25, -24, -23, -22, -21, -20, -19, -18, -17,
+            31, 30, 28, 25, 24, 21, 19, 18, 16, 15, 12, 10, 6, 5, 3, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_maskz_compress_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            32, 31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17,
+            16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_maskz_compress_epi16(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_compress_epi16(0b01101001_10010110_11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            31, 30, 28, 25, 24, 21, 19, 18, 16, 15, 12, 10, 6, 5, 3, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_mask_compressstoreu_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            32, 31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17,
+            16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let mut r = _mm512_setzero_si512();
+        _mm512_mask_compressstoreu_epi16(
+            &mut r as *mut _ as *mut u8,
+            0b01101001_10010110_11001010_00110101,
+            a,
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            31, 30, 28, 25, 24, 21, 19, 18, 16, 15, 12, 10, 6, 5, 3, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_mask_compress_epi16() {
+        #[rustfmt::skip]
+        let src = _mm256_setr_epi16(
+            -1, -2, -3, -4, -5, -6, -7, -8,
+            -9, -10, -11, -12, -13, -14, -15, -16,
+        );
+        let a = _mm256_setr_epi16(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let r = _mm256_mask_compress_epi16(src, 0, a);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_compress_epi16(src, 0b11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi16(
+            1, 3, 5, 6, 10, 12, 15, 16,
+            -9, -10, -11, -12, -13, -14, -15, -16,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_maskz_compress_epi16() {
+        let a = _mm256_setr_epi16(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let r = _mm256_maskz_compress_epi16(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_compress_epi16(0b11001010_00110101, a);
+        let e = _mm256_setr_epi16(1, 3, 5, 6, 10, 12, 15, 16, 0, 0, 0, 0, 0, 0, 0, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_mask_compressstoreu_epi16() {
+        let a = _mm256_setr_epi16(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let mut r = _mm256_setzero_si256();
+        _mm256_mask_compressstoreu_epi16(&mut r as *mut _ as *mut u8, 0b11001010_00110101, a);
+        let e = _mm256_setr_epi16(1, 3, 5, 6, 10, 12, 15, 16, 0, 0, 0, 0, 0, 0, 0, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_mask_compress_epi16() {
+        let src = _mm_setr_epi16(-1, -2, -3, -4, -5, -6, -7, -8);
+        let a = _mm_setr_epi16(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm_mask_compress_epi16(src, 0, a);
+        assert_eq_m128i(r, src);
+        let r = _mm_mask_compress_epi16(src, 0b00110101, a);
+        let e = _mm_setr_epi16(1, 3, 5, 6, -5, -6, -7, -8);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_maskz_compress_epi16() {
+        let a = _mm_setr_epi16(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm_maskz_compress_epi16(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_compress_epi16(0b00110101, a);
+        let e = _mm_setr_epi16(1, 3, 5, 6, 0, 0, 0, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_mask_compressstoreu_epi16() {
+        let a = _mm_setr_epi16(1, 2, 3, 4, 5, 6, 7, 8);
+        let mut r = _mm_setzero_si128();
+        _mm_mask_compressstoreu_epi16(&mut r as *mut _ as *mut u8, 0b00110101, a);
+        let e = _mm_setr_epi16(1, 3, 5, 6, 0, 0, 0, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_mask_expand_epi8() {
+        #[rustfmt::skip]
+        let src = _mm512_set_epi8(
+            -64, -63, -62, -61, -60, -59, -58, -57, -56, -55, -54, -53, -52, -51, -50, -49,
+            -48, -47, -46, -45, -44, -43, -42, -41, -40, -39, -38, -37, -36, -35, -34, -33,
+            -32, -31, -30, -29, -28, -27, -26, -25, -24, -23, -22, -21, -20, -19, -18, -17,
+            -16, -15, -14, -13, -12, -11, -10, -9, -8, -7, -6, -5, -4, -3, -2, -1,
+        );
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            64, 63, 62, 61, 60, 59, 58, 57, 56, 55, 54, 53, 52, 51, 50, 49,
+            48, 47, 46, 45, 44, 43, 42, 41, 40, 39, 38, 37, 36, 35, 34, 33,
+            32, 31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17,
+            16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_mask_expand_epi8(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_expand_epi8(
+            src,
+            0b10011001_01010101_00110101_11001010_01101001_10010110_11001010_00110101,
+            a,
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            32, -63, -62, 31, 30, -59, -58, 29, -56, 28, -54, 27, -52, 26, -50, 25,
+            -48, -47, 24, 23, -44, 22, -42, 21, 20, 19, -38, -37, 18, -35, 17, -33,
+            -32, 16, 15, -29, 14, -27, -26, 13, 12, -23, -22, 11, -20, 10, 9, -17,
+            8, 7, -14, -13, 6, -11, 5, -9, -8, -7, 4, 3, -4, 2, -2, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_maskz_expand_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            64, 63, 62, 61, 60, 59, 58, 57, 56, 55, 54, 53, 52, 51, 50, 49,
+            48, 47, 46, 45, 44, 43, 42, 41, 40, 39, 38, 37, 36, 35, 34, 33,
+            32, 31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17,
+            16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_maskz_expand_epi8(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_expand_epi8(
+            0b10011001_01010101_00110101_11001010_01101001_10010110_11001010_00110101,
+            a,
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            32, 0, 0, 31, 30, 0, 0, 29, 0, 28, 0, 27, 0, 26, 0, 25,
+            0, 0, 24, 23, 0, 22, 0, 21, 20, 19, 0, 0, 18, 0, 17, 0,
+            0, 16, 15, 0, 14, 0, 0, 13, 12, 0, 0, 11, 0, 10, 9, 0,
+            8, 7, 0, 0, 6, 0, 5, 0, 0, 0, 4, 3, 0, 2, 0, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_mask_expandloadu_epi8() {
+        #[rustfmt::skip]
+        let a: [i8; 64] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21,
+            22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40,
+            41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59,
+            60, 61, 62, 63, 64,
+        ];
+        let p = a.as_ptr() as *const u8;
+        #[rustfmt::skip]
+        let src = _mm512_set_epi8(
+            -64, -63, -62, -61, -60, -59, -58, -57, -56, -55, -54, -53, -52, -51, -50, -49,
+            -48, -47, -46, -45, -44, -43, -42, -41, -40, -39, -38, -37, -36, -35, -34, -33,
+            -32, -31, -30, -29, -28, -27, -26, -25, -24, -23, -22, -21, -20, -19, -18, -17,
+            -16, -15, -14, -13, -12, -11, -10, -9, -8, -7, -6, -5, -4, -3, -2, -1,
+        );
+        let r = _mm512_mask_expandloadu_epi8(
+            src,
+            0b10011001_01010101_00110101_11001010_01101001_10010110_11001010_00110101,
+            p,
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            32, -63, -62, 31, 30, -59, -58, 29, -56, 28, -54, 27, -52, 26, -50, 25,
+            -48, -47, 24, 23, -44, 22, -42, 21, 20, 19, -38, -37, 18, -35, 17, -33,
+            -32, 16, 15, -29, 14, -27, -26, 13, 12, -23, -22, 11, -20, 10, 9, -17,
+            8, 7, -14, -13, 6, -11, 5, -9, -8, -7, 4, 3, -4, 2, -2, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_maskz_expandloadu_epi8() {
+        #[rustfmt::skip]
+        let a: [i8; 64] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21,
+            22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40,
+            41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59,
+            60, 61, 62, 63, 64,
+        ];
+        let p = a.as_ptr() as *const u8;
+        let r = _mm512_maskz_expandloadu_epi8(
+            0b10011001_01010101_00110101_11001010_01101001_10010110_11001010_00110101,
+            p,
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            32, 0, 0, 31, 30, 0, 0, 29, 0, 28, 0, 27, 0, 26, 0, 25,
+            0, 0, 24, 23, 0, 22, 0, 21, 20, 19, 0, 0, 18, 0, 17, 0,
+            0, 16, 15, 0, 14, 0, 0, 13, 12, 0, 0, 11, 0, 10, 9, 0,
+            8, 7, 0, 0, 6, 0, 5, 0, 0, 0, 4, 3, 0, 2, 0, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_mask_expand_epi8() {
+        #[rustfmt::skip]
+        let src = _mm256_setr_epi8(
+            -1, -2, -3, -4, -5, -6, -7, -8, -9, -10, -11, -12, -13, -14, -15, -16,
+            -17, -18, -19, -20, -21, -22, -23, -24, -25, -26, -27, -28, -29, -30, -31, -32,
+        );
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+            17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+        );
+        let r = _mm256_mask_expand_epi8(src, 0, a);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_expand_epi8(src, 0b01101001_10010110_11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            1, -2, 2, -4, 3, 4, -7, -8, -9, 5, -11, 6, -13, -14, 7, 8,
+            -17, 9, 10, -20, 11, -22, -23, 12, 13, -26, -27, 14, -29, 15, 16, -32,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_maskz_expand_epi8() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+            17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+        );
+        let r = _mm256_maskz_expand_epi8(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_expand_epi8(0b01101001_10010110_11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            1, 0, 2, 0, 3, 4, 0, 0, 0, 5, 0, 6, 0, 0, 7, 8,
+            0, 9, 10, 0, 11, 0, 0, 12, 13, 0, 0, 14, 0, 15, 16, 0,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_mask_expandloadu_epi8() {
+        #[rustfmt::skip]
+        let a: [i8; 32] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21,
+            22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+        ];
+        let p = a.as_ptr() as *const u8;
+        #[rustfmt::skip]
+        let src = _mm256_setr_epi8(
+            -1, -2, -3, -4, -5, -6, -7, -8, -9, -10, -11, -12, -13, -14, -15, -16,
+            -17, -18, -19, -20, -21, -22, -23, -24, -25, -26, -27, -28, -29, -30, -31, -32,
+        );
+        let r = _mm256_mask_expandloadu_epi8(src, 0b01101001_10010110_11001010_00110101, p);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            1, -2, 2, -4, 3, 4, -7, -8, -9, 5, -11, 6, -13, -14, 7, 8,
+            -17, 9, 10, -20, 11, -22, -23, 12, 13, -26, -27, 14, -29, 15, 16, -32,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_maskz_expandloadu_epi8() {
+        #[rustfmt::skip]
+        let a: [i8; 32] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21,
+            22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+        ];
+        let p = a.as_ptr() as *const u8;
+        let r = _mm256_maskz_expandloadu_epi8(0b01101001_10010110_11001010_00110101, p);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            1, 0, 2, 0, 3, 4, 0, 0, 0, 5, 0, 6, 0, 0, 7, 8,
+            0, 9, 10, 0, 11, 0, 0, 12, 13, 0, 0, 14, 0, 15, 16, 0,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_mask_expand_epi8() {
+        #[rustfmt::skip]
+        let src = _mm_setr_epi8(
+            -1, -2, -3, -4, -5, -6, -7, -8,
+            -9, -10, -11, -12, -13, -14, -15, -16,
+        );
+        let a = _mm_setr_epi8(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let r = _mm_mask_expand_epi8(src, 0, a);
+        assert_eq_m128i(r, src);
+        let r = _mm_mask_expand_epi8(src, 0b11001010_00110101, a);
+        let e = _mm_setr_epi8(1, -2, 2, -4, 3, 4, -7, -8, -9, 5, -11, 6, -13, -14, 7, 8);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_maskz_expand_epi8() {
+        let a = _mm_setr_epi8(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let r = _mm_maskz_expand_epi8(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_expand_epi8(0b11001010_00110101, a);
+        let e = _mm_setr_epi8(1, 0, 2, 0, 3, 4, 0, 0, 0, 5, 0, 6, 0, 0, 7, 8);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_mask_expandloadu_epi8() {
+        let a: [i8; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        let p = a.as_ptr() as *const u8;
+        #[rustfmt::skip]
+        let src = _mm_setr_epi8(
+            -1, -2, -3, -4, -5, -6, -7, -8,
+            -9, -10, -11, -12, -13, -14, -15, -16,
+        );
+        let r = _mm_mask_expandloadu_epi8(src, 0b11001010_00110101, p);
+        let e = _mm_setr_epi8(1, -2, 2, -4, 3, 4, -7, -8, -9, 5, -11, 6, -13, -14, 7, 8);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_maskz_expandloadu_epi8() {
+        let a: [i8; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        let p = a.as_ptr() as *const u8;
+        let r = _mm_maskz_expandloadu_epi8(0b11001010_00110101, p);
+        let e = _mm_setr_epi8(1, 0, 2, 0, 3, 4, 0, 0, 0, 5, 0, 6, 0, 0, 7, 8);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_mask_expand_epi16() {
+        #[rustfmt::skip]
+        let src = _mm512_set_epi16(
+            -32, -31, -30, -29, -28, -27, -26, -25, -24, -23, -22, -21, -20, -19, -18, -17,
+            -16, -15, -14, -13, -12, -11, -10, -9, -8, -7, -6, -5, -4, -3, -2, -1,
+        );
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            32, 31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17,
+            16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_mask_expand_epi16(src, 0, a);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_expand_epi16(src, 0b01101001_10010110_11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            -32, 16, 15, -29, 14, -27, -26, 13, 12, -23, -22, 11, -20, 10, 9, -17,
+            8, 7, -14, -13, 6, -11, 5, -9, -8, -7, 4, 3, -4, 2, -2, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_maskz_expand_epi16() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi16(
+            32, 31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17,
+            16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let r = _mm512_maskz_expand_epi16(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_expand_epi16(0b01101001_10010110_11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 16, 15, 0, 14, 0, 0, 13, 12, 0, 0, 11, 0, 10, 9, 0,
+            8, 7, 0, 0, 6, 0, 5, 0, 0, 0, 4, 3, 0, 2, 0, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_mask_expandloadu_epi16() {
+        #[rustfmt::skip]
+        let a: [i16; 32] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21,
+            22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+        ];
+        let p = a.as_ptr() as *const u8;
+        #[rustfmt::skip]
+        let src = _mm512_set_epi16(
+            -32, -31, -30, -29, -28, -27, -26, -25, -24, -23, -22, -21, -20, -19, -18, -17,
+            -16, -15, -14, -13, -12, -11, -10, -9, -8, -7, -6, -5, -4, -3, -2, -1,
+        );
+        let r = _mm512_mask_expandloadu_epi16(src, 0b01101001_10010110_11001010_00110101, p);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            -32, 16, 15, -29, 14, -27, -26, 13, 12, -23, -22, 11, -20, 10, 9, -17,
+            8, 7, -14, -13, 6, -11, 5, -9, -8, -7, 4, 3, -4, 2, -2, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_maskz_expandloadu_epi16() {
+        #[rustfmt::skip]
+        let a: [i16; 32] = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21,
+            22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+        ];
+        let p = a.as_ptr() as *const u8;
+        let r = _mm512_maskz_expandloadu_epi16(0b01101001_10010110_11001010_00110101, p);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 16, 15, 0, 14, 0, 0, 13, 12, 0, 0, 11, 0, 10, 9, 0,
+            8, 7, 0, 0, 6, 0, 5, 0, 0, 0, 4, 3, 0, 2, 0, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_mask_expand_epi16() {
+        #[rustfmt::skip]
+        let src = _mm256_setr_epi16(
+            -1, -2, -3, -4, -5, -6, -7, -8,
+            -9, -10, -11, -12, -13, -14, -15, -16,
+        );
+        let a = _mm256_setr_epi16(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let r = _mm256_mask_expand_epi16(src, 0, a);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_expand_epi16(src, 0b11001010_00110101, a);
+        let e = _mm256_setr_epi16(1, -2, 2, -4, 3, 4, -7, -8, -9, 5, -11, 6, -13, -14, 7, 8);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_maskz_expand_epi16() {
+        let a = _mm256_setr_epi16(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let r = _mm256_maskz_expand_epi16(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_expand_epi16(0b11001010_00110101, a);
+        let e = _mm256_setr_epi16(1, 0, 2, 0, 3, 4, 0, 0, 0, 5, 0, 6, 0, 0, 7, 8);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_mask_expandloadu_epi16() {
+        let a: [i16; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        let p = a.as_ptr() as *const u8;
+        #[rustfmt::skip]
+        let src = _mm256_setr_epi16(
+            -1, -2, -3, -4, -5, -6, -7, -8,
+            -9, -10, -11, -12, -13, -14, -15, -16,
+        );
+        let r = _mm256_mask_expandloadu_epi16(src, 0b11001010_00110101, p);
+        let e = _mm256_setr_epi16(1, -2, 2, -4, 3, 4, -7, -8, -9, 5, -11, 6, -13, -14, 7, 8);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_maskz_expandloadu_epi16() {
+        let a: [i16; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        let p = a.as_ptr() as *const u8;
+        let r = _mm256_maskz_expandloadu_epi16(0b11001010_00110101, p);
+        let e = _mm256_setr_epi16(1, 0, 2, 0, 3, 4, 0, 0, 0, 5, 0, 6, 0, 0, 7, 8);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_mask_expand_epi16() {
+        let src = _mm_setr_epi16(-1, -2, -3, -4, -5, -6, -7, -8);
+        let a = _mm_setr_epi16(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm_mask_expand_epi16(src, 0, a);
+        assert_eq_m128i(r, src);
+        let r = _mm_mask_expand_epi16(src, 0b00110101, a);
+        let e = _mm_setr_epi16(1, -2, 2, -4, 3, 4, -7, -8);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_maskz_expand_epi16() {
+        let a = _mm_setr_epi16(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm_maskz_expand_epi16(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_expand_epi16(0b00110101, a);
+        let e = _mm_setr_epi16(1, 0, 2, 0, 3, 4, 0, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_mask_expandloadu_epi16() {
+        let a: [i16; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+        let p = a.as_ptr() as *const u8;
+        let src = _mm_setr_epi16(-1, -2, -3, -4, -5, -6, -7, -8);
+        let r = _mm_mask_expandloadu_epi16(src, 0b00110101, p);
+        let e = _mm_setr_epi16(1, -2, 2, -4, 3, 4, -7, -8);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_maskz_expandloadu_epi16() {
+        let a: [i16; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+        let p = a.as_ptr() as *const u8;
+        let r = _mm_maskz_expandloadu_epi16(0b00110101, p);
+        let e = _mm_setr_epi16(1, 0, 2, 0, 3, 4, 0, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_shldv_epi64() {
+        let a = _mm512_set1_epi64(1);
+        let b = _mm512_set1_epi64(std::i64::MIN);
+        let c = _mm512_set1_epi64(2);
+        let r = _mm512_shldv_epi64(a, b, c);
+        let e = _mm512_set1_epi64(6);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_mask_shldv_epi64() {
+        let a = _mm512_set1_epi64(1);
+        let b = _mm512_set1_epi64(std::i64::MIN);
+        let c = _mm512_set1_epi64(2);
+        let r = _mm512_mask_shldv_epi64(a, 0, b, c);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_shldv_epi64(a, 0b00110101, b, c);
+        let e = _mm512_setr_epi64(6, 1, 6, 1, 6, 6, 1, 1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_maskz_shldv_epi64() {
+        let a = _mm512_set1_epi64(1);
+        let b = _mm512_set1_epi64(std::i64::MIN);
+        let c = _mm512_set1_epi64(2);
+        let r = _mm512_maskz_shldv_epi64(0, a, b, c);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_shldv_epi64(0b00110101, a, b, c);
+        let e = _mm512_setr_epi64(6, 0, 6, 0, 6, 6, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_shldv_epi64() {
+        let a = _mm256_set1_epi64x(1);
+        let b = _mm256_set1_epi64x(std::i64::MIN);
+        let c = _mm256_set1_epi64x(2);
+        let r = _mm256_shldv_epi64(a, b, c);
+        let e = _mm256_set1_epi64x(6);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_mask_shldv_epi64() {
+        let a = _mm256_set1_epi64x(1);
+        let b = _mm256_set1_epi64x(std::i64::MIN);
+        let c = _mm256_set1_epi64x(2);
+        let r = _mm256_mask_shldv_epi64(a, 0, b, c);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_shldv_epi64(a, 0b00001010, b, c);
+        let e = _mm256_setr_epi64x(1, 6, 1, 6);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_maskz_shldv_epi64() {
+        let a = _mm256_set1_epi64x(1);
+        let b = _mm256_set1_epi64x(std::i64::MIN);
+        let c = _mm256_set1_epi64x(2);
+        let r = _mm256_maskz_shldv_epi64(0, a, b, c);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_shldv_epi64(0b00001010, a, b, c);
+        let e = _mm256_setr_epi64x(0, 6, 0, 6);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_shldv_epi64() {
+        let a = _mm_set1_epi64x(1);
+        let b = _mm_set1_epi64x(std::i64::MIN);
+        let c = _mm_set1_epi64x(2);
+        let r = _mm_shldv_epi64(a, b, c);
+        let e = _mm_set1_epi64x(6);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_mask_shldv_epi64() {
+        let a = _mm_set1_epi64x(1);
+        let b = _mm_set1_epi64x(std::i64::MIN);
+        let c = _mm_set1_epi64x(2);
+        let r = _mm_mask_shldv_epi64(a, 0, b, c);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_shldv_epi64(a, 0b00000010, b, c);
+        let e = _mm_setr_epi64x(1, 6);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_maskz_shldv_epi64() {
+        let a = _mm_set1_epi64x(1);
+        let b = _mm_set1_epi64x(std::i64::MIN);
+        let c = _mm_set1_epi64x(2);
+        let r = _mm_maskz_shldv_epi64(0, a, b, c);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_shldv_epi64(0b00000010, a, b, c);
+        let e = _mm_setr_epi64x(0, 6);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_shldv_epi32() {
+        let a = _mm512_set1_epi32(1);
+        let b = _mm512_set1_epi32(std::i32::MIN);
+        let c = _mm512_set1_epi32(2);
+        let r = _mm512_shldv_epi32(a, b, c);
+        let e = _mm512_set1_epi32(6);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_mask_shldv_epi32() {
+        let a = _mm512_set1_epi32(1);
+        let b = _mm512_set1_epi32(std::i32::MIN);
+        let c = _mm512_set1_epi32(2);
+        let r = _mm512_mask_shldv_epi32(a, 0, b, c);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_shldv_epi32(a, 0b11001010_00110101, b, c);
+        let e = _mm512_setr_epi32(6, 1, 6, 1, 6, 6, 1, 1, 1, 6, 1, 6, 1, 1, 6, 6);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_maskz_shldv_epi32() {
+        let a = _mm512_set1_epi32(1);
+        let b = _mm512_set1_epi32(std::i32::MIN);
+        let c = _mm512_set1_epi32(2);
+        let r = _mm512_maskz_shldv_epi32(0, a, b, c);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_shldv_epi32(0b11001010_00110101, a, b, c);
+        let e = _mm512_setr_epi32(6, 0, 6, 0, 6, 6, 0, 0, 0, 6, 0, 6, 0, 0, 6, 6);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_shldv_epi32() {
+        let a = _mm256_set1_epi32(1);
+        let b = _mm256_set1_epi32(std::i32::MIN);
+        let c = _mm256_set1_epi32(2);
+        let r = _mm256_shldv_epi32(a, b, c);
+        let e = _mm256_set1_epi32(6);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_mask_shldv_epi32() {
+        let a = _mm256_set1_epi32(1);
+        let b = _mm256_set1_epi32(std::i32::MIN);
+        let c = _mm256_set1_epi32(2);
+        let r = _mm256_mask_shldv_epi32(a, 0, b, c);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_shldv_epi32(a, 0b00110101, b, c);
+        let e = _mm256_setr_epi32(6, 1, 6, 1, 6, 6, 1, 1);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_maskz_shldv_epi32() {
+        let a = _mm256_set1_epi32(1);
+        let b = _mm256_set1_epi32(std::i32::MIN);
+        let c = _mm256_set1_epi32(2);
+        let r = _mm256_maskz_shldv_epi32(0, a, b, c);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_shldv_epi32(0b00110101, a, b, c);
+        let e = _mm256_setr_epi32(6, 0, 6, 0, 6, 6, 0, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_shldv_epi32() {
+        let a = _mm_set1_epi32(1);
+        let b = _mm_set1_epi32(std::i32::MIN);
+        let c = _mm_set1_epi32(2);
+        let r = _mm_shldv_epi32(a, b, c);
+        let e = _mm_set1_epi32(6);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_mask_shldv_epi32() {
+        let a = _mm_set1_epi32(1);
+        let b = _mm_set1_epi32(std::i32::MIN);
+        let c = _mm_set1_epi32(2);
+        let r = _mm_mask_shldv_epi32(a, 0, b, c);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_shldv_epi32(a, 0b00001010, b, c);
+        let e = _mm_setr_epi32(1, 6, 1, 6);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_maskz_shldv_epi32() {
+        let a = _mm_set1_epi32(1);
+        let b = _mm_set1_epi32(std::i32::MIN);
+        let c = _mm_set1_epi32(2);
+        let r = _mm_maskz_shldv_epi32(0, a, b, c);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_shldv_epi32(0b00001010, a, b, c);
+        let e = _mm_setr_epi32(0, 6, 0, 6);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_shldv_epi16() {
+        let a = _mm512_set1_epi16(1);
+        let b = _mm512_set1_epi16(std::i16::MIN);
+        let c = _mm512_set1_epi16(2);
+        let r = _mm512_shldv_epi16(a, b, c);
+        let e = _mm512_set1_epi16(6);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_mask_shldv_epi16() {
+        let a = _mm512_set1_epi16(1);
+        let b = _mm512_set1_epi16(std::i16::MIN);
+        let c = _mm512_set1_epi16(2);
+        let r = _mm512_mask_shldv_epi16(a, 0, b, c);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_shldv_epi16(a, 0b01101001_10010110_11001010_00110101, b, c);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            1, 6, 6, 1, 6, 1, 1, 6, 6, 1, 1, 6, 1, 6, 6, 1,
+            6, 6, 1, 1, 6, 1, 6, 1, 1, 1, 6, 6, 1, 6, 1, 6,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_maskz_shldv_epi16() {
+        let a = _mm512_set1_epi16(1);
+        let b = _mm512_set1_epi16(std::i16::MIN);
+        let c = _mm512_set1_epi16(2);
+        let r = _mm512_maskz_shldv_epi16(0, a, b, c);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_shldv_epi16(0b01101001_10010110_11001010_00110101, a, b, c);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 6, 6, 0, 6, 0, 0, 6, 6, 0, 0, 6, 0, 6, 6, 0,
+            6, 6, 0, 0, 6, 0, 6, 0, 0, 0, 6, 6, 0, 6, 0, 6,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_shldv_epi16() {
+        let a = _mm256_set1_epi16(1);
+        let b = _mm256_set1_epi16(std::i16::MIN);
+        let c = _mm256_set1_epi16(2);
+        let r = _mm256_shldv_epi16(a, b, c);
+        let e = _mm256_set1_epi16(6);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_mask_shldv_epi16() {
+        let a = _mm256_set1_epi16(1);
+        let b = _mm256_set1_epi16(std::i16::MIN);
+        let c = _mm256_set1_epi16(2);
+        let r = _mm256_mask_shldv_epi16(a, 0, b, c);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_shldv_epi16(a, 0b11001010_00110101, b, c);
+        let e = _mm256_setr_epi16(6, 1, 6, 1, 6, 6, 1, 1, 1, 6, 1, 6, 1, 1, 6, 6);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_maskz_shldv_epi16() {
+        let a = _mm256_set1_epi16(1);
+        let b = _mm256_set1_epi16(std::i16::MIN);
+        let c = _mm256_set1_epi16(2);
+        let r = _mm256_maskz_shldv_epi16(0, a, b, c);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_shldv_epi16(0b11001010_00110101, a, b, c);
+        let e = _mm256_setr_epi16(6, 0, 6, 0, 6, 6, 0, 0, 0, 6, 0, 6, 0, 0, 6, 6);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_shldv_epi16() {
+        let a = _mm_set1_epi16(1);
+        let b = _mm_set1_epi16(std::i16::MIN);
+        let c = _mm_set1_epi16(2);
+        let r = _mm_shldv_epi16(a, b, c);
+        let e = _mm_set1_epi16(6);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_mask_shldv_epi16() {
+        let a = _mm_set1_epi16(1);
+        let b = _mm_set1_epi16(std::i16::MIN);
+        let c = _mm_set1_epi16(2);
+        let r = _mm_mask_shldv_epi16(a, 0, b, c);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_shldv_epi16(a, 0b00110101, b, c);
+        let e = _mm_setr_epi16(6, 1, 6, 1, 6, 6, 1, 1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_maskz_shldv_epi16() {
+        let a = _mm_set1_epi16(1);
+        let b = _mm_set1_epi16(std::i16::MIN);
+        let c = _mm_set1_epi16(2);
+        let r = _mm_maskz_shldv_epi16(0, a, b, c);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_shldv_epi16(0b00110101, a, b, c);
+        let e = _mm_setr_epi16(6, 0, 6, 0, 6, 6, 0, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_shrdv_epi64() {
+        let a = _mm512_set1_epi64(8);
+        let b = _mm512_set1_epi64(1);
+        let c = _mm512_set1_epi64(2);
+        let r = _mm512_shrdv_epi64(a, b, c);
+        let e = _mm512_set1_epi64((1 << 62) | 2);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_mask_shrdv_epi64() {
+        let a = _mm512_set1_epi64(8);
+        let b = _mm512_set1_epi64(1);
+        let c = _mm512_set1_epi64(2);
+        let r = _mm512_mask_shrdv_epi64(a, 0, b, c);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_shrdv_epi64(a, 0b00110101, b, c);
+        #[rustfmt::skip]
+        let e = _mm512_setr_epi64(
+            4611686018427387906, 8, 4611686018427387906, 8,
+            4611686018427387906, 4611686018427387906, 8, 8,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_maskz_shrdv_epi64() {
+        let a = _mm512_set1_epi64(8);
+        let b = _mm512_set1_epi64(1);
+        let c = _mm512_set1_epi64(2);
+        let r = _mm512_maskz_shrdv_epi64(0, a, b, c);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_shrdv_epi64(0b00110101, a, b, c);
+        #[rustfmt::skip]
+        let e = _mm512_setr_epi64(
+            4611686018427387906, 0, 4611686018427387906, 0,
+            4611686018427387906, 4611686018427387906, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_shrdv_epi64() {
+        let a = _mm256_set1_epi64x(8);
+        let b = _mm256_set1_epi64x(1);
+        let c = _mm256_set1_epi64x(2);
+        let r = _mm256_shrdv_epi64(a, b, c);
+        let e = _mm256_set1_epi64x((1 << 62) | 2);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_mask_shrdv_epi64() {
+        let a = _mm256_set1_epi64x(8);
+        let b = _mm256_set1_epi64x(1);
+        let c = _mm256_set1_epi64x(2);
+        let r = _mm256_mask_shrdv_epi64(a, 0, b, c);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_shrdv_epi64(a, 0b00001010, b, c);
+        let e = _mm256_setr_epi64x(8, 4611686018427387906, 8, 4611686018427387906);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_maskz_shrdv_epi64() {
+        let a = _mm256_set1_epi64x(8);
+        let b = _mm256_set1_epi64x(1);
+        let c = _mm256_set1_epi64x(2);
+        let r = _mm256_maskz_shrdv_epi64(0, a, b, c);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_shrdv_epi64(0b00001010, a, b, c);
+        let e = _mm256_setr_epi64x(0, 4611686018427387906, 0, 4611686018427387906);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_shrdv_epi64() {
+        let a = _mm_set1_epi64x(8);
+        let b = _mm_set1_epi64x(1);
+        let c = _mm_set1_epi64x(2);
+        let r = _mm_shrdv_epi64(a, b, c);
+        let e = _mm_set1_epi64x((1 << 62) | 2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_mask_shrdv_epi64() {
+        let a = _mm_set1_epi64x(8);
+        let b = _mm_set1_epi64x(1);
+        let c = _mm_set1_epi64x(2);
+        let r = _mm_mask_shrdv_epi64(a, 0, b, c);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_shrdv_epi64(a, 0b00000010, b, c);
+        let e = _mm_setr_epi64x(8, 4611686018427387906);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_maskz_shrdv_epi64() {
+        let a = _mm_set1_epi64x(8);
+        let b = _mm_set1_epi64x(1);
+        let c = _mm_set1_epi64x(2);
+        let r = _mm_maskz_shrdv_epi64(0, a, b, c);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_shrdv_epi64(0b00000010, a, b, c);
+        let e = _mm_setr_epi64x(0, 4611686018427387906);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_shrdv_epi32() {
+        let a = _mm512_set1_epi32(8);
+        let b = _mm512_set1_epi32(1);
+        let c = _mm512_set1_epi32(2);
+        let r = _mm512_shrdv_epi32(a, b, c);
+        let e = _mm512_set1_epi32((1 << 30) | 2);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_mask_shrdv_epi32() {
+        let a = _mm512_set1_epi32(8);
+        let b = _mm512_set1_epi32(1);
+        let c = _mm512_set1_epi32(2);
+        let r = _mm512_mask_shrdv_epi32(a, 0, b, c);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_shrdv_epi32(a, 0b11001010_00110101, b, c);
+        #[rustfmt::skip]
+        let e = _mm512_setr_epi32(
+            1073741826, 8, 1073741826, 8,
+            1073741826, 1073741826, 8, 8,
+            8, 1073741826, 8, 1073741826,
+            8, 8, 1073741826, 1073741826,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_maskz_shrdv_epi32() {
+        let a = _mm512_set1_epi32(8);
+        let b = _mm512_set1_epi32(1);
+        let c = _mm512_set1_epi32(2);
+        let r = _mm512_maskz_shrdv_epi32(0, a, b, c);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_shrdv_epi32(0b11001010_00110101, a, b, c);
+        #[rustfmt::skip]
+        let e = _mm512_setr_epi32(
+            1073741826, 0, 1073741826, 0,
+            1073741826, 1073741826, 0, 0,
+            0, 1073741826, 0, 1073741826,
+            0, 0, 1073741826, 1073741826,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_shrdv_epi32() {
+        let a = _mm256_set1_epi32(8);
+        let b = _mm256_set1_epi32(1);
+        let c = _mm256_set1_epi32(2);
+        let r = _mm256_shrdv_epi32(a, b, c);
+        let e = _mm256_set1_epi32((1 << 30) | 2);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_mask_shrdv_epi32() {
+        let a = _mm256_set1_epi32(8);
+        let b = _mm256_set1_epi32(1);
+        let c = _mm256_set1_epi32(2);
+        let r = _mm256_mask_shrdv_epi32(a, 0, b, c);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_shrdv_epi32(a, 0b00110101, b, c);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi32(
+            1073741826, 8, 1073741826, 8,
+            1073741826, 1073741826, 8, 8,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_maskz_shrdv_epi32() {
+        let a = _mm256_set1_epi32(8);
+        let b = _mm256_set1_epi32(1);
+        let c = _mm256_set1_epi32(2);
+        let r = _mm256_maskz_shrdv_epi32(0, a, b, c);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_shrdv_epi32(0b00110101, a, b, c);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi32(
+            1073741826, 0, 1073741826, 0,
+            1073741826, 1073741826, 0, 0,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_shrdv_epi32() {
+        let a = _mm_set1_epi32(8);
+        let b = _mm_set1_epi32(1);
+        let c = _mm_set1_epi32(2);
+        let r = _mm_shrdv_epi32(a, b, c);
+        let e = _mm_set1_epi32((1 << 30) | 2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_mask_shrdv_epi32() {
+        let a = _mm_set1_epi32(8);
+        let b = _mm_set1_epi32(1);
+        let c = _mm_set1_epi32(2);
+        let r = _mm_mask_shrdv_epi32(a, 0, b, c);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_shrdv_epi32(a, 0b00001010, b, c);
+        let e = _mm_setr_epi32(8, 1073741826, 8, 1073741826);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_maskz_shrdv_epi32() {
+        let a = _mm_set1_epi32(8);
+        let b = _mm_set1_epi32(1);
+        let c = _mm_set1_epi32(2);
+        let r = _mm_maskz_shrdv_epi32(0, a, b, c);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_shrdv_epi32(0b00001010, a, b, c);
+        let e = _mm_setr_epi32(0, 1073741826, 0, 1073741826);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_shrdv_epi16() {
+        let a = _mm512_set1_epi16(8);
+        let b = _mm512_set1_epi16(1);
+        let c = _mm512_set1_epi16(2);
+        let r = _mm512_shrdv_epi16(a, b, c);
+        let e = _mm512_set1_epi16((1 << 14) | 2);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_mask_shrdv_epi16() {
+        let a = _mm512_set1_epi16(8);
+        let b = _mm512_set1_epi16(1);
+        let c = _mm512_set1_epi16(2);
+        let r = _mm512_mask_shrdv_epi16(a, 0, b, c);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_shrdv_epi16(a, 0b01101001_10010110_11001010_00110101, b, c);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            8, 16386, 16386, 8, 16386, 8, 8, 16386,
+            16386, 8, 8, 16386, 8, 16386, 16386, 8,
+            16386, 16386, 8, 8, 16386, 8, 16386, 8,
+            8, 8, 16386, 16386, 8, 16386, 8, 16386,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_maskz_shrdv_epi16() {
+        let a = _mm512_set1_epi16(8);
+        let b = _mm512_set1_epi16(1);
+        let c = _mm512_set1_epi16(2);
+        let r = _mm512_maskz_shrdv_epi16(0, a, b, c);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_shrdv_epi16(0b01101001_10010110_11001010_00110101, a, b, c);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 16386, 16386, 0, 16386, 0, 0, 16386,
+            16386, 0, 0, 16386, 0, 16386, 16386, 0,
+            16386, 16386, 0, 0, 16386, 0, 16386, 0,
+            0, 0, 16386, 16386, 0, 16386, 0, 16386,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_shrdv_epi16() {
+        let a = _mm256_set1_epi16(8);
+        let b = _mm256_set1_epi16(1);
+        let c = _mm256_set1_epi16(2);
+        let r = _mm256_shrdv_epi16(a, b, c);
+        let e = _mm256_set1_epi16((1 << 14) | 2);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_mask_shrdv_epi16() {
+        let a = _mm256_set1_epi16(8);
+        let b = _mm256_set1_epi16(1);
+        let c = _mm256_set1_epi16(2);
+        let r = _mm256_mask_shrdv_epi16(a, 0, b, c);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_shrdv_epi16(a, 0b11001010_00110101, b, c);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi16(
+            16386, 8, 16386, 8, 16386, 16386, 8, 8,
+            8, 16386, 8, 16386, 8, 8, 16386, 16386,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_maskz_shrdv_epi16() {
+        let a = _mm256_set1_epi16(8);
+        let b = _mm256_set1_epi16(1);
+        let c = _mm256_set1_epi16(2);
+        let r = _mm256_maskz_shrdv_epi16(0, a, b, c);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_shrdv_epi16(0b11001010_00110101, a, b, c);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi16(
+            16386, 0, 16386, 0, 16386, 16386, 0, 0,
+            0, 16386, 0, 16386, 0, 0, 16386, 16386,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_shrdv_epi16() {
+        let a = _mm_set1_epi16(8);
+        let b = _mm_set1_epi16(1);
+        let c = _mm_set1_epi16(2);
+        let r = _mm_shrdv_epi16(a, b, c);
+        let e = _mm_set1_epi16((1 << 14) | 2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_mask_shrdv_epi16() {
+        let a = _mm_set1_epi16(8);
+        let b = _mm_set1_epi16(1);
+        let c = _mm_set1_epi16(2);
+        let r = _mm_mask_shrdv_epi16(a, 0, b, c);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_shrdv_epi16(a, 0b00110101, b, c);
+        let e = _mm_setr_epi16(16386, 8, 16386, 8, 16386, 16386, 8, 8);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_maskz_shrdv_epi16() {
+        let a = _mm_set1_epi16(8);
+        let b = _mm_set1_epi16(1);
+        let c = _mm_set1_epi16(2);
+        let r = _mm_maskz_shrdv_epi16(0, a, b, c);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_shrdv_epi16(0b00110101, a, b, c);
+        let e = _mm_setr_epi16(16386, 0, 16386, 0, 16386, 16386, 0, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_shldi_epi64() {
+        let a = _mm512_set1_epi64(1);
+        let b = _mm512_set1_epi64(std::i64::MIN);
+        let r = _mm512_shldi_epi64(a, b, 2);
+        let e = _mm512_set1_epi64(6);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_mask_shldi_epi64() {
+        let a = _mm512_set1_epi64(1);
+        let b = _mm512_set1_epi64(std::i64::MIN);
+        let r = _mm512_mask_shldi_epi64(a, 0, a, b, 2);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_shldi_epi64(a, 0b00110101, a, b, 2);
+        let e = _mm512_setr_epi64(6, 1, 6, 1, 6, 6, 1, 1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_maskz_shldi_epi64() {
+        let a = _mm512_set1_epi64(1);
+        let b = _mm512_set1_epi64(std::i64::MIN);
+        let r = _mm512_maskz_shldi_epi64(0, a, b, 2);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_shldi_epi64(0b00110101, a, b, 2);
+        let e = _mm512_setr_epi64(6, 0, 6, 0, 6, 6, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_shldi_epi64() {
+        let a = _mm256_set1_epi64x(1);
+        let b = _mm256_set1_epi64x(std::i64::MIN);
+        let r = _mm256_shldi_epi64(a, b, 2);
+        let e = _mm256_set1_epi64x(6);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_mask_shldi_epi64() {
+        let a = _mm256_set1_epi64x(1);
+        let b = _mm256_set1_epi64x(std::i64::MIN);
+        let r = _mm256_mask_shldi_epi64(a, 0, a, b, 2);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_shldi_epi64(a, 0b00001010, a, b, 2);
+        let e = _mm256_setr_epi64x(1, 6, 1, 6);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_maskz_shldi_epi64() {
+        let a = _mm256_set1_epi64x(1);
+        let b = _mm256_set1_epi64x(std::i64::MIN);
+        let r = _mm256_maskz_shldi_epi64(0, a, b, 2);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_shldi_epi64(0b00001010, a, b, 2);
+        let e = _mm256_setr_epi64x(0, 6, 0, 6);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_shldi_epi64() {
+        let a = _mm_set1_epi64x(1);
+        let b = _mm_set1_epi64x(std::i64::MIN);
+        let r = _mm_shldi_epi64(a, b, 2);
+        let e = _mm_set1_epi64x(6);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_mask_shldi_epi64() {
+        let a = _mm_set1_epi64x(1);
+        let b = _mm_set1_epi64x(std::i64::MIN);
+        let r = _mm_mask_shldi_epi64(a, 0, a, b, 2);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_shldi_epi64(a, 0b00000010, a, b, 2);
+        let e = _mm_setr_epi64x(1, 6);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_maskz_shldi_epi64() {
+        let a = _mm_set1_epi64x(1);
+        let b = _mm_set1_epi64x(std::i64::MIN);
+        let r = _mm_maskz_shldi_epi64(0, a, b, 2);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_shldi_epi64(0b00000010, a, b, 2);
+        let e = _mm_setr_epi64x(0, 6);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_shldi_epi32() {
+        let a = _mm512_set1_epi32(1);
+        let b = _mm512_set1_epi32(std::i32::MIN);
+        let r = _mm512_shldi_epi32(a, b, 2);
+        let e = _mm512_set1_epi32(6);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_mask_shldi_epi32() {
+        let a = _mm512_set1_epi32(1);
+        let b = _mm512_set1_epi32(std::i32::MIN);
+        let r = _mm512_mask_shldi_epi32(a, 0, a, b, 2);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_shldi_epi32(a, 0b11001010_00110101, a, b, 2);
+        let e = _mm512_setr_epi32(6, 1, 6, 1, 6, 6, 1, 1, 1, 6, 1, 6, 1, 1, 6, 6);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_maskz_shldi_epi32() {
+        let a = _mm512_set1_epi32(1);
+        let b = _mm512_set1_epi32(std::i32::MIN);
+        let r = _mm512_maskz_shldi_epi32(0, a, b, 2);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_shldi_epi32(0b11001010_00110101, a, b, 2);
+        let e = _mm512_setr_epi32(6, 0, 6, 0, 6, 6, 0, 0, 0, 6, 0, 6, 0, 0, 6, 6);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_shldi_epi32() {
+        let a = _mm256_set1_epi32(1);
+        let b = _mm256_set1_epi32(std::i32::MIN);
+        let r = _mm256_shldi_epi32(a, b, 2);
+        let e = _mm256_set1_epi32(6);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_mask_shldi_epi32() {
+        let a = _mm256_set1_epi32(1);
+        let b = _mm256_set1_epi32(std::i32::MIN);
+        let r = _mm256_mask_shldi_epi32(a, 0, a, b, 2);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_shldi_epi32(a, 0b00110101, a, b, 2);
+        let e = _mm256_setr_epi32(6, 1, 6, 1, 6, 6, 1, 1);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_maskz_shldi_epi32() {
+        let a = _mm256_set1_epi32(1);
+        let b = _mm256_set1_epi32(std::i32::MIN);
+        let r = _mm256_maskz_shldi_epi32(0, a, b, 2);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_shldi_epi32(0b00110101, a, b, 2);
+        let e = _mm256_setr_epi32(6, 0, 6, 0, 6, 6, 0, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_shldi_epi32() {
+        let a = _mm_set1_epi32(1);
+        let b = _mm_set1_epi32(std::i32::MIN);
+        let r = _mm_shldi_epi32(a, b, 2);
+        let e = _mm_set1_epi32(6);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_mask_shldi_epi32() {
+        let a = _mm_set1_epi32(1);
+        let b = _mm_set1_epi32(std::i32::MIN);
+        let r = _mm_mask_shldi_epi32(a, 0, a, b, 2);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_shldi_epi32(a, 0b00001010, a, b, 2);
+        let e = _mm_setr_epi32(1, 6, 1, 6);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_maskz_shldi_epi32() {
+        let a = _mm_set1_epi32(1);
+        let b = _mm_set1_epi32(std::i32::MIN);
+        let r = _mm_maskz_shldi_epi32(0, a, b, 2);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_shldi_epi32(0b00001010, a, b, 2);
+        let e = _mm_setr_epi32(0, 6, 0, 6);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_shldi_epi16() {
+        let a = _mm512_set1_epi16(1);
+        let b = _mm512_set1_epi16(std::i16::MIN);
+        let r = _mm512_shldi_epi16(a, b, 2);
+        let e = _mm512_set1_epi16(6);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_mask_shldi_epi16() {
+        let a = _mm512_set1_epi16(1);
+        let b = _mm512_set1_epi16(std::i16::MIN);
+        let r = _mm512_mask_shldi_epi16(a, 0, a, b, 2);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_shldi_epi16(a, 0b01101001_10010110_11001010_00110101, a, b, 2);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            1, 6, 6, 1, 6, 1, 1, 6, 6, 1, 1, 6, 1, 6, 6, 1,
+            6, 6, 1, 1, 6, 1, 6, 1, 1, 1, 6, 6, 1, 6, 1, 6,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_maskz_shldi_epi16() {
+        let a = _mm512_set1_epi16(1);
+        let b = _mm512_set1_epi16(std::i16::MIN);
+        let r = _mm512_maskz_shldi_epi16(0, a, b, 2);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_shldi_epi16(0b01101001_10010110_11001010_00110101, a, b, 2);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 6, 6, 0, 6, 0, 0, 6, 6, 0, 0, 6, 0, 6, 6, 0,
+            6, 6, 0, 0, 6, 0, 6, 0, 0, 0, 6, 6, 0, 6, 0, 6,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_shldi_epi16() {
+        let a = _mm256_set1_epi16(1);
+        let b = _mm256_set1_epi16(std::i16::MIN);
+        let r = _mm256_shldi_epi16(a, b, 2);
+        let e = _mm256_set1_epi16(6);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_mask_shldi_epi16() {
+        let a = _mm256_set1_epi16(1);
+        let b = _mm256_set1_epi16(std::i16::MIN);
+        let r = _mm256_mask_shldi_epi16(a, 0, a, b, 2);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_shldi_epi16(a, 0b11001010_00110101, a, b, 2);
+        let e = _mm256_setr_epi16(6, 1, 6, 1, 6, 6, 1, 1, 1, 6, 1, 6, 1, 1, 6, 6);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_maskz_shldi_epi16() {
+        let a = _mm256_set1_epi16(1);
+        let b = _mm256_set1_epi16(std::i16::MIN);
+        let r = _mm256_maskz_shldi_epi16(0, a, b, 2);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_shldi_epi16(0b11001010_00110101, a, b, 2);
+        let e = _mm256_setr_epi16(6, 0, 6, 0, 6, 6, 0, 0, 0, 6, 0, 6, 0, 0, 6, 6);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_shldi_epi16() {
+        let a = _mm_set1_epi16(1);
+        let b = _mm_set1_epi16(std::i16::MIN);
+        let r = _mm_shldi_epi16(a, b, 2);
+        let e = _mm_set1_epi16(6);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_mask_shldi_epi16() {
+        let a = _mm_set1_epi16(1);
+        let b = _mm_set1_epi16(std::i16::MIN);
+        let r = _mm_mask_shldi_epi16(a, 0, a, b, 2);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_shldi_epi16(a, 0b00110101, a, b, 2);
+        let e = _mm_setr_epi16(6, 1, 6, 1, 6, 6, 1, 1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_maskz_shldi_epi16() {
+        let a = _mm_set1_epi16(1);
+        let b = _mm_set1_epi16(std::i16::MIN);
+        let r = _mm_maskz_shldi_epi16(0, a, b, 2);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_shldi_epi16(0b00110101, a, b, 2);
+        let e = _mm_setr_epi16(6, 0, 6, 0, 6, 6, 0, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_shrdi_epi64() {
+        let a = _mm512_set1_epi64(8);
+        let b = _mm512_set1_epi64(1);
+        let r = _mm512_shrdi_epi64(a, b, 2);
+        let e = _mm512_set1_epi64((1 << 62) | 2);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_mask_shrdi_epi64() {
+        let a = _mm512_set1_epi64(8);
+        let b = _mm512_set1_epi64(1);
+        let r = _mm512_mask_shrdi_epi64(a, 0, a, b, 2);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_shrdi_epi64(a, 0b00110101, a, b, 2);
+        #[rustfmt::skip]
+        let e = _mm512_setr_epi64(
+            4611686018427387906, 8, 4611686018427387906, 8,
+            4611686018427387906, 4611686018427387906, 8, 8,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_maskz_shrdi_epi64() {
+        let a = _mm512_set1_epi64(8);
+        let b = _mm512_set1_epi64(1);
+        let r = _mm512_maskz_shrdi_epi64(0, a, b, 2);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_shrdi_epi64(0b00110101, a, b, 2);
+        #[rustfmt::skip]
+        let e = _mm512_setr_epi64(
+            4611686018427387906, 0, 4611686018427387906, 0,
+            4611686018427387906, 4611686018427387906, 0, 0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_shrdi_epi64() {
+        let a = _mm256_set1_epi64x(8);
+        let b = _mm256_set1_epi64x(1);
+        let r = _mm256_shrdi_epi64(a, b, 2);
+        let e = _mm256_set1_epi64x((1 << 62) | 2);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_mask_shrdi_epi64() {
+        let a = _mm256_set1_epi64x(8);
+        let b = _mm256_set1_epi64x(1);
+        let r = _mm256_mask_shrdi_epi64(a, 0, a, b, 2);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_shrdi_epi64(a, 0b00001010, a, b, 2);
+        let e = _mm256_setr_epi64x(8, 4611686018427387906, 8, 4611686018427387906);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_maskz_shrdi_epi64() {
+        let a = _mm256_set1_epi64x(8);
+        let b = _mm256_set1_epi64x(1);
+        let r = _mm256_maskz_shrdi_epi64(0, a, b, 2);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_shrdi_epi64(0b00001010, a, b, 2);
+        let e = _mm256_setr_epi64x(0, 4611686018427387906, 0, 4611686018427387906);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_shrdi_epi64() {
+        let a = _mm_set1_epi64x(8);
+        let b = _mm_set1_epi64x(1);
+        let r = _mm_shrdi_epi64(a, b, 2);
+        let e = _mm_set1_epi64x((1 << 62) | 2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_mask_shrdi_epi64() {
+        let a = _mm_set1_epi64x(8);
+        let b = _mm_set1_epi64x(1);
+        let r = _mm_mask_shrdi_epi64(a, 0, a, b, 2);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_shrdi_epi64(a, 0b00000010, a, b, 2);
+        let e = _mm_setr_epi64x(8, 4611686018427387906);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_maskz_shrdi_epi64() {
+        let a = _mm_set1_epi64x(8);
+        let b = _mm_set1_epi64x(1);
+        let r = _mm_maskz_shrdi_epi64(0, a, b, 2);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_shrdi_epi64(0b00000010, a, b, 2);
+        let e = _mm_setr_epi64x(0, 4611686018427387906);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_shrdi_epi32() {
+        let a = _mm512_set1_epi32(8);
+        let b = _mm512_set1_epi32(1);
+        let r = _mm512_shrdi_epi32(a, b, 2);
+        let e = _mm512_set1_epi32((1 << 30) | 2);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_mask_shrdi_epi32() {
+        let a = _mm512_set1_epi32(8);
+        let b = _mm512_set1_epi32(1);
+        let r = _mm512_mask_shrdi_epi32(a, 0, a, b, 2);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_shrdi_epi32(a, 0b11001010_00110101, a, b, 2);
+        #[rustfmt::skip]
+        let e = _mm512_setr_epi32(
+            1073741826, 8, 1073741826, 8,
+            1073741826, 1073741826, 8, 8,
+            8, 1073741826, 8, 1073741826,
+            8, 8, 1073741826, 1073741826,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_maskz_shrdi_epi32() {
+        let a = _mm512_set1_epi32(8);
+        let b = _mm512_set1_epi32(1);
+        let r = _mm512_maskz_shrdi_epi32(0, a, b, 2);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_shrdi_epi32(0b11001010_00110101, a, b, 2);
+        #[rustfmt::skip]
+        let e = _mm512_setr_epi32(
+            1073741826, 0, 1073741826, 0,
+            1073741826, 1073741826, 0, 0,
+            0, 1073741826, 0, 1073741826,
+            0, 0, 1073741826, 1073741826,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_shrdi_epi32() {
+        let a = _mm256_set1_epi32(8);
+        let b = _mm256_set1_epi32(1);
+        let r = _mm256_shrdi_epi32(a, b, 2);
+        let e = _mm256_set1_epi32((1 << 30) | 2);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_mask_shrdi_epi32() {
+        let a = _mm256_set1_epi32(8);
+        let b = _mm256_set1_epi32(1);
+        let r = _mm256_mask_shrdi_epi32(a, 0, a, b, 2);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_shrdi_epi32(a, 0b00110101, a, b, 2);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi32(
+            1073741826, 8, 1073741826, 8,
+            1073741826, 1073741826, 8, 8,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_maskz_shrdi_epi32() {
+        let a = _mm256_set1_epi32(8);
+        let b = _mm256_set1_epi32(1);
+        let r = _mm256_maskz_shrdi_epi32(0, a, b, 2);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_shrdi_epi32(0b00110101, a, b, 2);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi32(
+            1073741826, 0, 1073741826, 0,
+            1073741826, 1073741826, 0, 0,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_shrdi_epi32() {
+        let a = _mm_set1_epi32(8);
+        let b = _mm_set1_epi32(1);
+        let r = _mm_shrdi_epi32(a, b, 2);
+        let e = _mm_set1_epi32((1 << 30) | 2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_mask_shrdi_epi32() {
+        let a = _mm_set1_epi32(8);
+        let b = _mm_set1_epi32(1);
+        let r = _mm_mask_shrdi_epi32(a, 0, a, b, 2);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_shrdi_epi32(a, 0b00001010, a, b, 2);
+        let e = _mm_setr_epi32(8, 1073741826, 8, 1073741826);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_maskz_shrdi_epi32() {
+        let a = _mm_set1_epi32(8);
+        let b = _mm_set1_epi32(1);
+        let r = _mm_maskz_shrdi_epi32(0, a, b, 2);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_shrdi_epi32(0b00001010, a, b, 2);
+        let e = _mm_setr_epi32(0, 1073741826, 0, 1073741826);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_shrdi_epi16() {
+        let a = _mm512_set1_epi16(8);
+        let b = _mm512_set1_epi16(1);
+        let r = _mm512_shrdi_epi16(a, b, 2);
+        let e = _mm512_set1_epi16((1 << 14) | 2);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_mask_shrdi_epi16() {
+        let a = _mm512_set1_epi16(8);
+        let b = _mm512_set1_epi16(1);
+        let r = _mm512_mask_shrdi_epi16(a, 0, a, b, 2);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_shrdi_epi16(a, 0b01101001_10010110_11001010_00110101, a, b, 2);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            8, 16386, 16386, 8, 16386, 8, 8, 16386,
+            16386, 8, 8, 16386, 8, 16386, 16386, 8,
+            16386, 16386, 8, 8, 16386, 8, 16386, 8,
+            8, 8, 16386, 16386, 8, 16386, 8, 16386,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2")]
+    unsafe fn test_mm512_maskz_shrdi_epi16() {
+        let a = _mm512_set1_epi16(8);
+        let b = _mm512_set1_epi16(1);
+        let r = _mm512_maskz_shrdi_epi16(0, a, b, 2);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_shrdi_epi16(0b01101001_10010110_11001010_00110101, a, b, 2);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi16(
+            0, 16386, 16386, 0, 16386, 0, 0, 16386,
+            16386, 0, 0, 16386, 0, 16386, 16386, 0,
+            16386, 16386, 0, 0, 16386, 0, 16386, 0,
+            0, 0, 16386, 16386, 0, 16386, 0, 16386,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_shrdi_epi16() {
+        let a = _mm256_set1_epi16(8);
+        let b = _mm256_set1_epi16(1);
+        let r = _mm256_shrdi_epi16(a, b, 2);
+        let e = _mm256_set1_epi16((1 << 14) | 2);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_mask_shrdi_epi16() {
+        let a = _mm256_set1_epi16(8);
+        let b = _mm256_set1_epi16(1);
+        let r = _mm256_mask_shrdi_epi16(a, 0, a, b, 2);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_shrdi_epi16(a, 0b11001010_00110101, a, b, 2);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi16(
+            16386, 8, 16386, 8, 16386, 16386, 8, 8,
+            8, 16386, 8, 16386, 8, 8, 16386, 16386,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm256_maskz_shrdi_epi16() {
+        let a = _mm256_set1_epi16(8);
+        let b = _mm256_set1_epi16(1);
+        let r = _mm256_maskz_shrdi_epi16(0, a, b, 2);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_shrdi_epi16(0b11001010_00110101, a, b, 2);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi16(
+            16386, 0, 16386, 0, 16386, 16386, 0, 0,
+            0, 16386, 0, 16386, 0, 0, 16386, 16386,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_shrdi_epi16() {
+        let a = _mm_set1_epi16(8);
+        let b = _mm_set1_epi16(1);
+        let r = _mm_shrdi_epi16(a, b, 2);
+        let e = _mm_set1_epi16((1 << 14) | 2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_mask_shrdi_epi16() {
+        let a = _mm_set1_epi16(8);
+        let b = _mm_set1_epi16(1);
+        let r = _mm_mask_shrdi_epi16(a, 0, a, b, 2);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_shrdi_epi16(a, 0b00110101, a, b, 2);
+        let e = _mm_setr_epi16(16386, 8, 16386, 8, 16386, 16386, 8, 8);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vbmi2,avx512vl")]
+    unsafe fn test_mm_maskz_shrdi_epi16() {
+        let a = _mm_set1_epi16(8);
+        let b = _mm_set1_epi16(1);
+        let r = _mm_maskz_shrdi_epi16(0, a, b, 2);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_shrdi_epi16(0b00110101, a, b, 2);
+        let e = _mm_setr_epi16(16386, 0, 16386, 0, 16386, 16386, 0, 0);
+        assert_eq_m128i(r, e);
+    }
+}
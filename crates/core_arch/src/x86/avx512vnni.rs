@@ -0,0 +1,1130 @@
+use crate::{
+    core_arch::{simd::*, simd_llvm::*, x86::*},
+    mem::transmute,
+};
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Multiplies groups of 4 adjacent pairs of unsigned 8-bit integers in `a` with
+/// corresponding signed 8-bit integers in `b`, producing 4 intermediate signed
+/// 16-bit results. Sums these 4 results with the corresponding 32-bit integer
+/// in `src`, and stores the packed 32-bit results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_dpbusd_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni")]
+#[cfg_attr(test, assert_instr(vpdpbusd))]
+pub unsafe fn _mm512_dpbusd_epi32(src: __m512i, a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpdpbusd_512(src.as_i32x16(), a.as_i32x16(), b.as_i32x16()))
+}
+
+/// Multiplies groups of 4 adjacent pairs of unsigned 8-bit integers in `a` with
+/// corresponding signed 8-bit integers in `b`, producing 4 intermediate signed
+/// 16-bit results. Sums these 4 results with the corresponding 32-bit integer
+/// in `src`, and stores the packed 32-bit results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_dpbusd_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni")]
+#[cfg_attr(test, assert_instr(vpdpbusd))]
+pub unsafe fn _mm512_mask_dpbusd_epi32(
+    src: __m512i,
+    k: __mmask16,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let r = _mm512_dpbusd_epi32(src, a, b).as_i32x16();
+    transmute(simd_select_bitmask(k, r, src.as_i32x16()))
+}
+
+/// Multiplies groups of 4 adjacent pairs of unsigned 8-bit integers in `a` with
+/// corresponding signed 8-bit integers in `b`, producing 4 intermediate signed
+/// 16-bit results. Sums these 4 results with the corresponding 32-bit integer
+/// in `src`, and stores the packed 32-bit results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_dpbusd_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni")]
+#[cfg_attr(test, assert_instr(vpdpbusd))]
+pub unsafe fn _mm512_maskz_dpbusd_epi32(
+    k: __mmask16,
+    src: __m512i,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let r = _mm512_dpbusd_epi32(src, a, b).as_i32x16();
+    let zero = _mm512_setzero_si512().as_i32x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Multiplies groups of 4 adjacent pairs of unsigned 8-bit integers in `a` with
+/// corresponding signed 8-bit integers in `b`, producing 4 intermediate signed
+/// 16-bit results. Sums these 4 results with the corresponding 32-bit integer
+/// in `src`, and stores the packed 32-bit results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_dpbusd_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni,avx512vl")]
+#[cfg_attr(test, assert_instr(vpdpbusd))]
+pub unsafe fn _mm256_dpbusd_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i {
+    transmute(vpdpbusd_256(src.as_i32x8(), a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Multiplies groups of 4 adjacent pairs of unsigned 8-bit integers in `a` with
+/// corresponding signed 8-bit integers in `b`, producing 4 intermediate signed
+/// 16-bit results. Sums these 4 results with the corresponding 32-bit integer
+/// in `src`, and stores the packed 32-bit results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_dpbusd_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni,avx512vl")]
+#[cfg_attr(test, assert_instr(vpdpbusd))]
+pub unsafe fn _mm256_mask_dpbusd_epi32(
+    src: __m256i,
+    k: __mmask8,
+    a: __m256i,
+    b: __m256i,
+) -> __m256i {
+    let r = _mm256_dpbusd_epi32(src, a, b).as_i32x8();
+    transmute(simd_select_bitmask(k, r, src.as_i32x8()))
+}
+
+/// Multiplies groups of 4 adjacent pairs of unsigned 8-bit integers in `a` with
+/// corresponding signed 8-bit integers in `b`, producing 4 intermediate signed
+/// 16-bit results. Sums these 4 results with the corresponding 32-bit integer
+/// in `src`, and stores the packed 32-bit results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_dpbusd_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni,avx512vl")]
+#[cfg_attr(test, assert_instr(vpdpbusd))]
+pub unsafe fn _mm256_maskz_dpbusd_epi32(
+    k: __mmask8,
+    src: __m256i,
+    a: __m256i,
+    b: __m256i,
+) -> __m256i {
+    let r = _mm256_dpbusd_epi32(src, a, b).as_i32x8();
+    let zero = _mm256_setzero_si256().as_i32x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Multiplies groups of 4 adjacent pairs of unsigned 8-bit integers in `a` with
+/// corresponding signed 8-bit integers in `b`, producing 4 intermediate signed
+/// 16-bit results. Sums these 4 results with the corresponding 32-bit integer
+/// in `src`, and stores the packed 32-bit results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_dpbusd_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni,avx512vl")]
+#[cfg_attr(test, assert_instr(vpdpbusd))]
+pub unsafe fn _mm_dpbusd_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpdpbusd_128(src.as_i32x4(), a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Multiplies groups of 4 adjacent pairs of unsigned 8-bit integers in `a` with
+/// corresponding signed 8-bit integers in `b`, producing 4 intermediate signed
+/// 16-bit results. Sums these 4 results with the corresponding 32-bit integer
+/// in `src`, and stores the packed 32-bit results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_dpbusd_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni,avx512vl")]
+#[cfg_attr(test, assert_instr(vpdpbusd))]
+pub unsafe fn _mm_mask_dpbusd_epi32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i {
+    let r = _mm_dpbusd_epi32(src, a, b).as_i32x4();
+    transmute(simd_select_bitmask(k, r, src.as_i32x4()))
+}
+
+/// Multiplies groups of 4 adjacent pairs of unsigned 8-bit integers in `a` with
+/// corresponding signed 8-bit integers in `b`, producing 4 intermediate signed
+/// 16-bit results. Sums these 4 results with the corresponding 32-bit integer
+/// in `src`, and stores the packed 32-bit results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_dpbusd_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni,avx512vl")]
+#[cfg_attr(test, assert_instr(vpdpbusd))]
+pub unsafe fn _mm_maskz_dpbusd_epi32(k: __mmask8, src: __m128i, a: __m128i, b: __m128i) -> __m128i {
+    let r = _mm_dpbusd_epi32(src, a, b).as_i32x4();
+    let zero = _mm_setzero_si128().as_i32x4();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Multiplies groups of 4 adjacent pairs of unsigned 8-bit integers in `a` with
+/// corresponding signed 8-bit integers in `b`, producing 4 intermediate signed
+/// 16-bit results. Sums these 4 results with the corresponding 32-bit integer
+/// in `src` using signed saturation, and stores the packed 32-bit results in
+/// `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_dpbusds_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni")]
+#[cfg_attr(test, assert_instr(vpdpbusds))]
+pub unsafe fn _mm512_dpbusds_epi32(src: __m512i, a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpdpbusds_512(src.as_i32x16(), a.as_i32x16(), b.as_i32x16()))
+}
+
+/// Multiplies groups of 4 adjacent pairs of unsigned 8-bit integers in `a` with
+/// corresponding signed 8-bit integers in `b`, producing 4 intermediate signed
+/// 16-bit results. Sums these 4 results with the corresponding 32-bit integer
+/// in `src` using signed saturation, and stores the packed 32-bit results in
+/// `dst` using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_dpbusds_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni")]
+#[cfg_attr(test, assert_instr(vpdpbusds))]
+pub unsafe fn _mm512_mask_dpbusds_epi32(
+    src: __m512i,
+    k: __mmask16,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let r = _mm512_dpbusds_epi32(src, a, b).as_i32x16();
+    transmute(simd_select_bitmask(k, r, src.as_i32x16()))
+}
+
+/// Multiplies groups of 4 adjacent pairs of unsigned 8-bit integers in `a` with
+/// corresponding signed 8-bit integers in `b`, producing 4 intermediate signed
+/// 16-bit results. Sums these 4 results with the corresponding 32-bit integer
+/// in `src` using signed saturation, and stores the packed 32-bit results in
+/// `dst` using zeromask `k` (elements are zeroed out when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_dpbusds_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni")]
+#[cfg_attr(test, assert_instr(vpdpbusds))]
+pub unsafe fn _mm512_maskz_dpbusds_epi32(
+    k: __mmask16,
+    src: __m512i,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let r = _mm512_dpbusds_epi32(src, a, b).as_i32x16();
+    let zero = _mm512_setzero_si512().as_i32x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Multiplies groups of 4 adjacent pairs of unsigned 8-bit integers in `a` with
+/// corresponding signed 8-bit integers in `b`, producing 4 intermediate signed
+/// 16-bit results. Sums these 4 results with the corresponding 32-bit integer
+/// in `src` using signed saturation, and stores the packed 32-bit results in
+/// `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_dpbusds_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni,avx512vl")]
+#[cfg_attr(test, assert_instr(vpdpbusds))]
+pub unsafe fn _mm256_dpbusds_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i {
+    transmute(vpdpbusds_256(src.as_i32x8(), a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Multiplies groups of 4 adjacent pairs of unsigned 8-bit integers in `a` with
+/// corresponding signed 8-bit integers in `b`, producing 4 intermediate signed
+/// 16-bit results. Sums these 4 results with the corresponding 32-bit integer
+/// in `src` using signed saturation, and stores the packed 32-bit results in
+/// `dst` using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_dpbusds_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni,avx512vl")]
+#[cfg_attr(test, assert_instr(vpdpbusds))]
+pub unsafe fn _mm256_mask_dpbusds_epi32(
+    src: __m256i,
+    k: __mmask8,
+    a: __m256i,
+    b: __m256i,
+) -> __m256i {
+    let r = _mm256_dpbusds_epi32(src, a, b).as_i32x8();
+    transmute(simd_select_bitmask(k, r, src.as_i32x8()))
+}
+
+/// Multiplies groups of 4 adjacent pairs of unsigned 8-bit integers in `a` with
+/// corresponding signed 8-bit integers in `b`, producing 4 intermediate signed
+/// 16-bit results. Sums these 4 results with the corresponding 32-bit integer
+/// in `src` using signed saturation, and stores the packed 32-bit results in
+/// `dst` using zeromask `k` (elements are zeroed out when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_dpbusds_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni,avx512vl")]
+#[cfg_attr(test, assert_instr(vpdpbusds))]
+pub unsafe fn _mm256_maskz_dpbusds_epi32(
+    k: __mmask8,
+    src: __m256i,
+    a: __m256i,
+    b: __m256i,
+) -> __m256i {
+    let r = _mm256_dpbusds_epi32(src, a, b).as_i32x8();
+    let zero = _mm256_setzero_si256().as_i32x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Multiplies groups of 4 adjacent pairs of unsigned 8-bit integers in `a` with
+/// corresponding signed 8-bit integers in `b`, producing 4 intermediate signed
+/// 16-bit results. Sums these 4 results with the corresponding 32-bit integer
+/// in `src` using signed saturation, and stores the packed 32-bit results in
+/// `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_dpbusds_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni,avx512vl")]
+#[cfg_attr(test, assert_instr(vpdpbusds))]
+pub unsafe fn _mm_dpbusds_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpdpbusds_128(src.as_i32x4(), a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Multiplies groups of 4 adjacent pairs of unsigned 8-bit integers in `a` with
+/// corresponding signed 8-bit integers in `b`, producing 4 intermediate signed
+/// 16-bit results. Sums these 4 results with the corresponding 32-bit integer
+/// in `src` using signed saturation, and stores the packed 32-bit results in
+/// `dst` using writemask `k` (elements are copied from `src` when the
+/// corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_dpbusds_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni,avx512vl")]
+#[cfg_attr(test, assert_instr(vpdpbusds))]
+pub unsafe fn _mm_mask_dpbusds_epi32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i {
+    let r = _mm_dpbusds_epi32(src, a, b).as_i32x4();
+    transmute(simd_select_bitmask(k, r, src.as_i32x4()))
+}
+
+/// Multiplies groups of 4 adjacent pairs of unsigned 8-bit integers in `a` with
+/// corresponding signed 8-bit integers in `b`, producing 4 intermediate signed
+/// 16-bit results. Sums these 4 results with the corresponding 32-bit integer
+/// in `src` using signed saturation, and stores the packed 32-bit results in
+/// `dst` using zeromask `k` (elements are zeroed out when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_dpbusds_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni,avx512vl")]
+#[cfg_attr(test, assert_instr(vpdpbusds))]
+pub unsafe fn _mm_maskz_dpbusds_epi32(
+    k: __mmask8,
+    src: __m128i,
+    a: __m128i,
+    b: __m128i,
+) -> __m128i {
+    let r = _mm_dpbusds_epi32(src, a, b).as_i32x4();
+    let zero = _mm_setzero_si128().as_i32x4();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Multiplies groups of 2 adjacent pairs of signed 16-bit integers in `a` with
+/// corresponding 16-bit integers in `b`, producing 2 intermediate signed 32-bit
+/// results. Sums these 2 results with the corresponding 32-bit integer in
+/// `src`, and stores the packed 32-bit results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_dpwssd_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni")]
+#[cfg_attr(test, assert_instr(vpdpwssd))]
+pub unsafe fn _mm512_dpwssd_epi32(src: __m512i, a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpdpwssd_512(src.as_i32x16(), a.as_i32x16(), b.as_i32x16()))
+}
+
+/// Multiplies groups of 2 adjacent pairs of signed 16-bit integers in `a` with
+/// corresponding 16-bit integers in `b`, producing 2 intermediate signed 32-bit
+/// results. Sums these 2 results with the corresponding 32-bit integer in
+/// `src`, and stores the packed 32-bit results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_dpwssd_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni")]
+#[cfg_attr(test, assert_instr(vpdpwssd))]
+pub unsafe fn _mm512_mask_dpwssd_epi32(
+    src: __m512i,
+    k: __mmask16,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let r = _mm512_dpwssd_epi32(src, a, b).as_i32x16();
+    transmute(simd_select_bitmask(k, r, src.as_i32x16()))
+}
+
+/// Multiplies groups of 2 adjacent pairs of signed 16-bit integers in `a` with
+/// corresponding 16-bit integers in `b`, producing 2 intermediate signed 32-bit
+/// results. Sums these 2 results with the corresponding 32-bit integer in
+/// `src`, and stores the packed 32-bit results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_dpwssd_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni")]
+#[cfg_attr(test, assert_instr(vpdpwssd))]
+pub unsafe fn _mm512_maskz_dpwssd_epi32(
+    k: __mmask16,
+    src: __m512i,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let r = _mm512_dpwssd_epi32(src, a, b).as_i32x16();
+    let zero = _mm512_setzero_si512().as_i32x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Multiplies groups of 2 adjacent pairs of signed 16-bit integers in `a` with
+/// corresponding 16-bit integers in `b`, producing 2 intermediate signed 32-bit
+/// results. Sums these 2 results with the corresponding 32-bit integer in
+/// `src`, and stores the packed 32-bit results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_dpwssd_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni,avx512vl")]
+#[cfg_attr(test, assert_instr(vpdpwssd))]
+pub unsafe fn _mm256_dpwssd_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i {
+    transmute(vpdpwssd_256(src.as_i32x8(), a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Multiplies groups of 2 adjacent pairs of signed 16-bit integers in `a` with
+/// corresponding 16-bit integers in `b`, producing 2 intermediate signed 32-bit
+/// results. Sums these 2 results with the corresponding 32-bit integer in
+/// `src`, and stores the packed 32-bit results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_dpwssd_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni,avx512vl")]
+#[cfg_attr(test, assert_instr(vpdpwssd))]
+pub unsafe fn _mm256_mask_dpwssd_epi32(
+    src: __m256i,
+    k: __mmask8,
+    a: __m256i,
+    b: __m256i,
+) -> __m256i {
+    let r = _mm256_dpwssd_epi32(src, a, b).as_i32x8();
+    transmute(simd_select_bitmask(k, r, src.as_i32x8()))
+}
+
+/// Multiplies groups of 2 adjacent pairs of signed 16-bit integers in `a` with
+/// corresponding 16-bit integers in `b`, producing 2 intermediate signed 32-bit
+/// results. Sums these 2 results with the corresponding 32-bit integer in
+/// `src`, and stores the packed 32-bit results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_dpwssd_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni,avx512vl")]
+#[cfg_attr(test, assert_instr(vpdpwssd))]
+pub unsafe fn _mm256_maskz_dpwssd_epi32(
+    k: __mmask8,
+    src: __m256i,
+    a: __m256i,
+    b: __m256i,
+) -> __m256i {
+    let r = _mm256_dpwssd_epi32(src, a, b).as_i32x8();
+    let zero = _mm256_setzero_si256().as_i32x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Multiplies groups of 2 adjacent pairs of signed 16-bit integers in `a` with
+/// corresponding 16-bit integers in `b`, producing 2 intermediate signed 32-bit
+/// results. Sums these 2 results with the corresponding 32-bit integer in
+/// `src`, and stores the packed 32-bit results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_dpwssd_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni,avx512vl")]
+#[cfg_attr(test, assert_instr(vpdpwssd))]
+pub unsafe fn _mm_dpwssd_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpdpwssd_128(src.as_i32x4(), a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Multiplies groups of 2 adjacent pairs of signed 16-bit integers in `a` with
+/// corresponding 16-bit integers in `b`, producing 2 intermediate signed 32-bit
+/// results. Sums these 2 results with the corresponding 32-bit integer in
+/// `src`, and stores the packed 32-bit results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_dpwssd_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni,avx512vl")]
+#[cfg_attr(test, assert_instr(vpdpwssd))]
+pub unsafe fn _mm_mask_dpwssd_epi32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i {
+    let r = _mm_dpwssd_epi32(src, a, b).as_i32x4();
+    transmute(simd_select_bitmask(k, r, src.as_i32x4()))
+}
+
+/// Multiplies groups of 2 adjacent pairs of signed 16-bit integers in `a` with
+/// corresponding 16-bit integers in `b`, producing 2 intermediate signed 32-bit
+/// results. Sums these 2 results with the corresponding 32-bit integer in
+/// `src`, and stores the packed 32-bit results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_dpwssd_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni,avx512vl")]
+#[cfg_attr(test, assert_instr(vpdpwssd))]
+pub unsafe fn _mm_maskz_dpwssd_epi32(k: __mmask8, src: __m128i, a: __m128i, b: __m128i) -> __m128i {
+    let r = _mm_dpwssd_epi32(src, a, b).as_i32x4();
+    let zero = _mm_setzero_si128().as_i32x4();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Multiplies groups of 2 adjacent pairs of signed 16-bit integers in `a` with
+/// corresponding 16-bit integers in `b`, producing 2 intermediate signed 32-bit
+/// results. Sums these 2 results with the corresponding 32-bit integer in `src`
+/// using signed saturation, and stores the packed 32-bit results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_dpwssds_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni")]
+#[cfg_attr(test, assert_instr(vpdpwssds))]
+pub unsafe fn _mm512_dpwssds_epi32(src: __m512i, a: __m512i, b: __m512i) -> __m512i {
+    transmute(vpdpwssds_512(src.as_i32x16(), a.as_i32x16(), b.as_i32x16()))
+}
+
+/// Multiplies groups of 2 adjacent pairs of signed 16-bit integers in `a` with
+/// corresponding 16-bit integers in `b`, producing 2 intermediate signed 32-bit
+/// results. Sums these 2 results with the corresponding 32-bit integer in `src`
+/// using signed saturation, and stores the packed 32-bit results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_dpwssds_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni")]
+#[cfg_attr(test, assert_instr(vpdpwssds))]
+pub unsafe fn _mm512_mask_dpwssds_epi32(
+    src: __m512i,
+    k: __mmask16,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let r = _mm512_dpwssds_epi32(src, a, b).as_i32x16();
+    transmute(simd_select_bitmask(k, r, src.as_i32x16()))
+}
+
+/// Multiplies groups of 2 adjacent pairs of signed 16-bit integers in `a` with
+/// corresponding 16-bit integers in `b`, producing 2 intermediate signed 32-bit
+/// results. Sums these 2 results with the corresponding 32-bit integer in `src`
+/// using signed saturation, and stores the packed 32-bit results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_dpwssds_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni")]
+#[cfg_attr(test, assert_instr(vpdpwssds))]
+pub unsafe fn _mm512_maskz_dpwssds_epi32(
+    k: __mmask16,
+    src: __m512i,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let r = _mm512_dpwssds_epi32(src, a, b).as_i32x16();
+    let zero = _mm512_setzero_si512().as_i32x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Multiplies groups of 2 adjacent pairs of signed 16-bit integers in `a` with
+/// corresponding 16-bit integers in `b`, producing 2 intermediate signed 32-bit
+/// results. Sums these 2 results with the corresponding 32-bit integer in `src`
+/// using signed saturation, and stores the packed 32-bit results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_dpwssds_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni,avx512vl")]
+#[cfg_attr(test, assert_instr(vpdpwssds))]
+pub unsafe fn _mm256_dpwssds_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i {
+    transmute(vpdpwssds_256(src.as_i32x8(), a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Multiplies groups of 2 adjacent pairs of signed 16-bit integers in `a` with
+/// corresponding 16-bit integers in `b`, producing 2 intermediate signed 32-bit
+/// results. Sums these 2 results with the corresponding 32-bit integer in `src`
+/// using signed saturation, and stores the packed 32-bit results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_dpwssds_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni,avx512vl")]
+#[cfg_attr(test, assert_instr(vpdpwssds))]
+pub unsafe fn _mm256_mask_dpwssds_epi32(
+    src: __m256i,
+    k: __mmask8,
+    a: __m256i,
+    b: __m256i,
+) -> __m256i {
+    let r = _mm256_dpwssds_epi32(src, a, b).as_i32x8();
+    transmute(simd_select_bitmask(k, r, src.as_i32x8()))
+}
+
+/// Multiplies groups of 2 adjacent pairs of signed 16-bit integers in `a` with
+/// corresponding 16-bit integers in `b`, producing 2 intermediate signed 32-bit
+/// results. Sums these 2 results with the corresponding 32-bit integer in `src`
+/// using signed saturation, and stores the packed 32-bit results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_dpwssds_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni,avx512vl")]
+#[cfg_attr(test, assert_instr(vpdpwssds))]
+pub unsafe fn _mm256_maskz_dpwssds_epi32(
+    k: __mmask8,
+    src: __m256i,
+    a: __m256i,
+    b: __m256i,
+) -> __m256i {
+    let r = _mm256_dpwssds_epi32(src, a, b).as_i32x8();
+    let zero = _mm256_setzero_si256().as_i32x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Multiplies groups of 2 adjacent pairs of signed 16-bit integers in `a` with
+/// corresponding 16-bit integers in `b`, producing 2 intermediate signed 32-bit
+/// results. Sums these 2 results with the corresponding 32-bit integer in `src`
+/// using signed saturation, and stores the packed 32-bit results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_dpwssds_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni,avx512vl")]
+#[cfg_attr(test, assert_instr(vpdpwssds))]
+pub unsafe fn _mm_dpwssds_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpdpwssds_128(src.as_i32x4(), a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Multiplies groups of 2 adjacent pairs of signed 16-bit integers in `a` with
+/// corresponding 16-bit integers in `b`, producing 2 intermediate signed 32-bit
+/// results. Sums these 2 results with the corresponding 32-bit integer in `src`
+/// using signed saturation, and stores the packed 32-bit results in `dst` using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_dpwssds_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni,avx512vl")]
+#[cfg_attr(test, assert_instr(vpdpwssds))]
+pub unsafe fn _mm_mask_dpwssds_epi32(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i {
+    let r = _mm_dpwssds_epi32(src, a, b).as_i32x4();
+    transmute(simd_select_bitmask(k, r, src.as_i32x4()))
+}
+
+/// Multiplies groups of 2 adjacent pairs of signed 16-bit integers in `a` with
+/// corresponding 16-bit integers in `b`, producing 2 intermediate signed 32-bit
+/// results. Sums these 2 results with the corresponding 32-bit integer in `src`
+/// using signed saturation, and stores the packed 32-bit results in `dst` using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is not
+/// set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_dpwssds_epi32)
+#[inline]
+#[target_feature(enable = "avx512vnni,avx512vl")]
+#[cfg_attr(test, assert_instr(vpdpwssds))]
+pub unsafe fn _mm_maskz_dpwssds_epi32(
+    k: __mmask8,
+    src: __m128i,
+    a: __m128i,
+    b: __m128i,
+) -> __m128i {
+    let r = _mm_dpwssds_epi32(src, a, b).as_i32x4();
+    let zero = _mm_setzero_si128().as_i32x4();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.avx512.vpdpbusd.512"]
+    fn vpdpbusd_512(src: i32x16, a: i32x16, b: i32x16) -> i32x16;
+    #[link_name = "llvm.x86.avx512.vpdpbusd.256"]
+    fn vpdpbusd_256(src: i32x8, a: i32x8, b: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx512.vpdpbusd.128"]
+    fn vpdpbusd_128(src: i32x4, a: i32x4, b: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.avx512.vpdpbusds.512"]
+    fn vpdpbusds_512(src: i32x16, a: i32x16, b: i32x16) -> i32x16;
+    #[link_name = "llvm.x86.avx512.vpdpbusds.256"]
+    fn vpdpbusds_256(src: i32x8, a: i32x8, b: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx512.vpdpbusds.128"]
+    fn vpdpbusds_128(src: i32x4, a: i32x4, b: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.avx512.vpdpwssd.512"]
+    fn vpdpwssd_512(src: i32x16, a: i32x16, b: i32x16) -> i32x16;
+    #[link_name = "llvm.x86.avx512.vpdpwssd.256"]
+    fn vpdpwssd_256(src: i32x8, a: i32x8, b: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx512.vpdpwssd.128"]
+    fn vpdpwssd_128(src: i32x4, a: i32x4, b: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.avx512.vpdpwssds.512"]
+    fn vpdpwssds_512(src: i32x16, a: i32x16, b: i32x16) -> i32x16;
+    #[link_name = "llvm.x86.avx512.vpdpwssds.256"]
+    fn vpdpwssds_256(src: i32x8, a: i32x8, b: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx512.vpdpwssds.128"]
+    fn vpdpwssds_128(src: i32x4, a: i32x4, b: i32x4) -> i32x4;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use stdsimd_test::simd_test;
+
+    #[simd_test(enable = "avx512vnni")]
+    unsafe fn test_mm512_dpbusd_epi32() {
+        let src = _mm512_set1_epi32(100);
+        let a = _mm512_set1_epi32(0x0102_0304);
+        let b = _mm512_set1_epi32(-1);
+        let r = _mm512_dpbusd_epi32(src, a, b);
+        let e = _mm512_set1_epi32(90);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni")]
+    unsafe fn test_mm512_mask_dpbusd_epi32() {
+        let src = _mm512_set1_epi32(100);
+        let a = _mm512_set1_epi32(0x0102_0304);
+        let b = _mm512_set1_epi32(-1);
+        let r = _mm512_mask_dpbusd_epi32(src, 0, a, b);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_dpbusd_epi32(src, 0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_setr_epi32(
+            90, 100, 90, 100, 90, 90, 100, 100,
+            100, 90, 100, 90, 100, 100, 90, 90,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni")]
+    unsafe fn test_mm512_maskz_dpbusd_epi32() {
+        let src = _mm512_set1_epi32(100);
+        let a = _mm512_set1_epi32(0x0102_0304);
+        let b = _mm512_set1_epi32(-1);
+        let r = _mm512_maskz_dpbusd_epi32(0, src, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_dpbusd_epi32(0b11001010_00110101, src, a, b);
+        let e = _mm512_setr_epi32(90, 0, 90, 0, 90, 90, 0, 0, 0, 90, 0, 90, 0, 0, 90, 90);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni,avx512vl")]
+    unsafe fn test_mm256_dpbusd_epi32() {
+        let src = _mm256_set1_epi32(100);
+        let a = _mm256_set1_epi32(0x0102_0304);
+        let b = _mm256_set1_epi32(-1);
+        let r = _mm256_dpbusd_epi32(src, a, b);
+        let e = _mm256_set1_epi32(90);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni,avx512vl")]
+    unsafe fn test_mm256_mask_dpbusd_epi32() {
+        let src = _mm256_set1_epi32(100);
+        let a = _mm256_set1_epi32(0x0102_0304);
+        let b = _mm256_set1_epi32(-1);
+        let r = _mm256_mask_dpbusd_epi32(src, 0, a, b);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_dpbusd_epi32(src, 0b00110101, a, b);
+        let e = _mm256_setr_epi32(90, 100, 90, 100, 90, 90, 100, 100);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni,avx512vl")]
+    unsafe fn test_mm256_maskz_dpbusd_epi32() {
+        let src = _mm256_set1_epi32(100);
+        let a = _mm256_set1_epi32(0x0102_0304);
+        let b = _mm256_set1_epi32(-1);
+        let r = _mm256_maskz_dpbusd_epi32(0, src, a, b);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_dpbusd_epi32(0b00110101, src, a, b);
+        let e = _mm256_setr_epi32(90, 0, 90, 0, 90, 90, 0, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni,avx512vl")]
+    unsafe fn test_mm_dpbusd_epi32() {
+        let src = _mm_set1_epi32(100);
+        let a = _mm_set1_epi32(0x0102_0304);
+        let b = _mm_set1_epi32(-1);
+        let r = _mm_dpbusd_epi32(src, a, b);
+        let e = _mm_set1_epi32(90);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni,avx512vl")]
+    unsafe fn test_mm_mask_dpbusd_epi32() {
+        let src = _mm_set1_epi32(100);
+        let a = _mm_set1_epi32(0x0102_0304);
+        let b = _mm_set1_epi32(-1);
+        let r = _mm_mask_dpbusd_epi32(src, 0, a, b);
+        assert_eq_m128i(r, src);
+        let r = _mm_mask_dpbusd_epi32(src, 0b00001010, a, b);
+        let e = _mm_setr_epi32(100, 90, 100, 90);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni,avx512vl")]
+    unsafe fn test_mm_maskz_dpbusd_epi32() {
+        let src = _mm_set1_epi32(100);
+        let a = _mm_set1_epi32(0x0102_0304);
+        let b = _mm_set1_epi32(-1);
+        let r = _mm_maskz_dpbusd_epi32(0, src, a, b);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_dpbusd_epi32(0b00001010, src, a, b);
+        let e = _mm_setr_epi32(0, 90, 0, 90);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni")]
+    unsafe fn test_mm512_dpbusds_epi32() {
+        let src = _mm512_set1_epi32(std::i32::MAX - 10);
+        let a = _mm512_set1_epi32(-1);
+        let b = _mm512_set1_epi32(0x7f7f_7f7f);
+        let r = _mm512_dpbusds_epi32(src, a, b);
+        let e = _mm512_set1_epi32(std::i32::MAX);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni")]
+    unsafe fn test_mm512_mask_dpbusds_epi32() {
+        let src = _mm512_set1_epi32(std::i32::MAX - 10);
+        let a = _mm512_set1_epi32(-1);
+        let b = _mm512_set1_epi32(0x7f7f_7f7f);
+        let r = _mm512_mask_dpbusds_epi32(src, 0, a, b);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_dpbusds_epi32(src, 0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_setr_epi32(
+            std::i32::MAX, 2147483637, std::i32::MAX, 2147483637,
+            std::i32::MAX, std::i32::MAX, 2147483637, 2147483637,
+            2147483637, std::i32::MAX, 2147483637, std::i32::MAX,
+            2147483637, 2147483637, std::i32::MAX, std::i32::MAX,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni")]
+    unsafe fn test_mm512_maskz_dpbusds_epi32() {
+        let src = _mm512_set1_epi32(std::i32::MAX - 10);
+        let a = _mm512_set1_epi32(-1);
+        let b = _mm512_set1_epi32(0x7f7f_7f7f);
+        let r = _mm512_maskz_dpbusds_epi32(0, src, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_dpbusds_epi32(0b11001010_00110101, src, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_setr_epi32(
+            std::i32::MAX, 0, std::i32::MAX, 0,
+            std::i32::MAX, std::i32::MAX, 0, 0,
+            0, std::i32::MAX, 0, std::i32::MAX,
+            0, 0, std::i32::MAX, std::i32::MAX,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni,avx512vl")]
+    unsafe fn test_mm256_dpbusds_epi32() {
+        let src = _mm256_set1_epi32(std::i32::MAX - 10);
+        let a = _mm256_set1_epi32(-1);
+        let b = _mm256_set1_epi32(0x7f7f_7f7f);
+        let r = _mm256_dpbusds_epi32(src, a, b);
+        let e = _mm256_set1_epi32(std::i32::MAX);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni,avx512vl")]
+    unsafe fn test_mm256_mask_dpbusds_epi32() {
+        let src = _mm256_set1_epi32(std::i32::MAX - 10);
+        let a = _mm256_set1_epi32(-1);
+        let b = _mm256_set1_epi32(0x7f7f_7f7f);
+        let r = _mm256_mask_dpbusds_epi32(src, 0, a, b);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_dpbusds_epi32(src, 0b00110101, a, b);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi32(
+            std::i32::MAX, 2147483637, std::i32::MAX, 2147483637,
+            std::i32::MAX, std::i32::MAX, 2147483637, 2147483637,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni,avx512vl")]
+    unsafe fn test_mm256_maskz_dpbusds_epi32() {
+        let src = _mm256_set1_epi32(std::i32::MAX - 10);
+        let a = _mm256_set1_epi32(-1);
+        let b = _mm256_set1_epi32(0x7f7f_7f7f);
+        let r = _mm256_maskz_dpbusds_epi32(0, src, a, b);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_dpbusds_epi32(0b00110101, src, a, b);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi32(
+            std::i32::MAX, 0, std::i32::MAX, 0,
+            std::i32::MAX, std::i32::MAX, 0, 0,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni,avx512vl")]
+    unsafe fn test_mm_dpbusds_epi32() {
+        let src = _mm_set1_epi32(std::i32::MAX - 10);
+        let a = _mm_set1_epi32(-1);
+        let b = _mm_set1_epi32(0x7f7f_7f7f);
+        let r = _mm_dpbusds_epi32(src, a, b);
+        let e = _mm_set1_epi32(std::i32::MAX);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni,avx512vl")]
+    unsafe fn test_mm_mask_dpbusds_epi32() {
+        let src = _mm_set1_epi32(std::i32::MAX - 10);
+        let a = _mm_set1_epi32(-1);
+        let b = _mm_set1_epi32(0x7f7f_7f7f);
+        let r = _mm_mask_dpbusds_epi32(src, 0, a, b);
+        assert_eq_m128i(r, src);
+        let r = _mm_mask_dpbusds_epi32(src, 0b00001010, a, b);
+        let e = _mm_setr_epi32(2147483637, std::i32::MAX, 2147483637, std::i32::MAX);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni,avx512vl")]
+    unsafe fn test_mm_maskz_dpbusds_epi32() {
+        let src = _mm_set1_epi32(std::i32::MAX - 10);
+        let a = _mm_set1_epi32(-1);
+        let b = _mm_set1_epi32(0x7f7f_7f7f);
+        let r = _mm_maskz_dpbusds_epi32(0, src, a, b);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_dpbusds_epi32(0b00001010, src, a, b);
+        let e = _mm_setr_epi32(0, std::i32::MAX, 0, std::i32::MAX);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni")]
+    unsafe fn test_mm512_dpwssd_epi32() {
+        let src = _mm512_set1_epi32(100);
+        let a = _mm512_set1_epi32(0x0002_0003);
+        let b = _mm512_set1_epi32(0x0004_fffb);
+        let r = _mm512_dpwssd_epi32(src, a, b);
+        let e = _mm512_set1_epi32(93);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni")]
+    unsafe fn test_mm512_mask_dpwssd_epi32() {
+        let src = _mm512_set1_epi32(100);
+        let a = _mm512_set1_epi32(0x0002_0003);
+        let b = _mm512_set1_epi32(0x0004_fffb);
+        let r = _mm512_mask_dpwssd_epi32(src, 0, a, b);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_dpwssd_epi32(src, 0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_setr_epi32(
+            93, 100, 93, 100, 93, 93, 100, 100,
+            100, 93, 100, 93, 100, 100, 93, 93,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni")]
+    unsafe fn test_mm512_maskz_dpwssd_epi32() {
+        let src = _mm512_set1_epi32(100);
+        let a = _mm512_set1_epi32(0x0002_0003);
+        let b = _mm512_set1_epi32(0x0004_fffb);
+        let r = _mm512_maskz_dpwssd_epi32(0, src, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_dpwssd_epi32(0b11001010_00110101, src, a, b);
+        let e = _mm512_setr_epi32(93, 0, 93, 0, 93, 93, 0, 0, 0, 93, 0, 93, 0, 0, 93, 93);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni,avx512vl")]
+    unsafe fn test_mm256_dpwssd_epi32() {
+        let src = _mm256_set1_epi32(100);
+        let a = _mm256_set1_epi32(0x0002_0003);
+        let b = _mm256_set1_epi32(0x0004_fffb);
+        let r = _mm256_dpwssd_epi32(src, a, b);
+        let e = _mm256_set1_epi32(93);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni,avx512vl")]
+    unsafe fn test_mm256_mask_dpwssd_epi32() {
+        let src = _mm256_set1_epi32(100);
+        let a = _mm256_set1_epi32(0x0002_0003);
+        let b = _mm256_set1_epi32(0x0004_fffb);
+        let r = _mm256_mask_dpwssd_epi32(src, 0, a, b);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_dpwssd_epi32(src, 0b00110101, a, b);
+        let e = _mm256_setr_epi32(93, 100, 93, 100, 93, 93, 100, 100);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni,avx512vl")]
+    unsafe fn test_mm256_maskz_dpwssd_epi32() {
+        let src = _mm256_set1_epi32(100);
+        let a = _mm256_set1_epi32(0x0002_0003);
+        let b = _mm256_set1_epi32(0x0004_fffb);
+        let r = _mm256_maskz_dpwssd_epi32(0, src, a, b);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_dpwssd_epi32(0b00110101, src, a, b);
+        let e = _mm256_setr_epi32(93, 0, 93, 0, 93, 93, 0, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni,avx512vl")]
+    unsafe fn test_mm_dpwssd_epi32() {
+        let src = _mm_set1_epi32(100);
+        let a = _mm_set1_epi32(0x0002_0003);
+        let b = _mm_set1_epi32(0x0004_fffb);
+        let r = _mm_dpwssd_epi32(src, a, b);
+        let e = _mm_set1_epi32(93);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni,avx512vl")]
+    unsafe fn test_mm_mask_dpwssd_epi32() {
+        let src = _mm_set1_epi32(100);
+        let a = _mm_set1_epi32(0x0002_0003);
+        let b = _mm_set1_epi32(0x0004_fffb);
+        let r = _mm_mask_dpwssd_epi32(src, 0, a, b);
+        assert_eq_m128i(r, src);
+        let r = _mm_mask_dpwssd_epi32(src, 0b00001010, a, b);
+        let e = _mm_setr_epi32(100, 93, 100, 93);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni,avx512vl")]
+    unsafe fn test_mm_maskz_dpwssd_epi32() {
+        let src = _mm_set1_epi32(100);
+        let a = _mm_set1_epi32(0x0002_0003);
+        let b = _mm_set1_epi32(0x0004_fffb);
+        let r = _mm_maskz_dpwssd_epi32(0, src, a, b);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_dpwssd_epi32(0b00001010, src, a, b);
+        let e = _mm_setr_epi32(0, 93, 0, 93);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni")]
+    unsafe fn test_mm512_dpwssds_epi32() {
+        let src = _mm512_set1_epi32(std::i32::MAX - 10);
+        let a = _mm512_set1_epi32(0x7fff_7fff);
+        let b = _mm512_set1_epi32(0x7fff_7fff);
+        let r = _mm512_dpwssds_epi32(src, a, b);
+        let e = _mm512_set1_epi32(std::i32::MAX);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni")]
+    unsafe fn test_mm512_mask_dpwssds_epi32() {
+        let src = _mm512_set1_epi32(std::i32::MAX - 10);
+        let a = _mm512_set1_epi32(0x7fff_7fff);
+        let b = _mm512_set1_epi32(0x7fff_7fff);
+        let r = _mm512_mask_dpwssds_epi32(src, 0, a, b);
+        assert_eq_m512i(r, src);
+        let r = _mm512_mask_dpwssds_epi32(src, 0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_setr_epi32(
+            std::i32::MAX, 2147483637, std::i32::MAX, 2147483637,
+            std::i32::MAX, std::i32::MAX, 2147483637, 2147483637,
+            2147483637, std::i32::MAX, 2147483637, std::i32::MAX,
+            2147483637, 2147483637, std::i32::MAX, std::i32::MAX,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni")]
+    unsafe fn test_mm512_maskz_dpwssds_epi32() {
+        let src = _mm512_set1_epi32(std::i32::MAX - 10);
+        let a = _mm512_set1_epi32(0x7fff_7fff);
+        let b = _mm512_set1_epi32(0x7fff_7fff);
+        let r = _mm512_maskz_dpwssds_epi32(0, src, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_dpwssds_epi32(0b11001010_00110101, src, a, b);
+        #[rustfmt::skip]
+        let e = _mm512_setr_epi32(
+            std::i32::MAX, 0, std::i32::MAX, 0,
+            std::i32::MAX, std::i32::MAX, 0, 0,
+            0, std::i32::MAX, 0, std::i32::MAX,
+            0, 0, std::i32::MAX, std::i32::MAX,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni,avx512vl")]
+    unsafe fn test_mm256_dpwssds_epi32() {
+        let src = _mm256_set1_epi32(std::i32::MAX - 10);
+        let a = _mm256_set1_epi32(0x7fff_7fff);
+        let b = _mm256_set1_epi32(0x7fff_7fff);
+        let r = _mm256_dpwssds_epi32(src, a, b);
+        let e = _mm256_set1_epi32(std::i32::MAX);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni,avx512vl")]
+    unsafe fn test_mm256_mask_dpwssds_epi32() {
+        let src = _mm256_set1_epi32(std::i32::MAX - 10);
+        let a = _mm256_set1_epi32(0x7fff_7fff);
+        let b = _mm256_set1_epi32(0x7fff_7fff);
+        let r = _mm256_mask_dpwssds_epi32(src, 0, a, b);
+        assert_eq_m256i(r, src);
+        let r = _mm256_mask_dpwssds_epi32(src, 0b00110101, a, b);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi32(
+            std::i32::MAX, 2147483637, std::i32::MAX, 2147483637,
+            std::i32::MAX, std::i32::MAX, 2147483637, 2147483637,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni,avx512vl")]
+    unsafe fn test_mm256_maskz_dpwssds_epi32() {
+        let src = _mm256_set1_epi32(std::i32::MAX - 10);
+        let a = _mm256_set1_epi32(0x7fff_7fff);
+        let b = _mm256_set1_epi32(0x7fff_7fff);
+        let r = _mm256_maskz_dpwssds_epi32(0, src, a, b);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_dpwssds_epi32(0b00110101, src, a, b);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi32(
+            std::i32::MAX, 0, std::i32::MAX, 0,
+            std::i32::MAX, std::i32::MAX, 0, 0,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni,avx512vl")]
+    unsafe fn test_mm_dpwssds_epi32() {
+        let src = _mm_set1_epi32(std::i32::MAX - 10);
+        let a = _mm_set1_epi32(0x7fff_7fff);
+        let b = _mm_set1_epi32(0x7fff_7fff);
+        let r = _mm_dpwssds_epi32(src, a, b);
+        let e = _mm_set1_epi32(std::i32::MAX);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni,avx512vl")]
+    unsafe fn test_mm_mask_dpwssds_epi32() {
+        let src = _mm_set1_epi32(std::i32::MAX - 10);
+        let a = _mm_set1_epi32(0x7fff_7fff);
+        let b = _mm_set1_epi32(0x7fff_7fff);
+        let r = _mm_mask_dpwssds_epi32(src, 0, a, b);
+        assert_eq_m128i(r, src);
+        let r = _mm_mask_dpwssds_epi32(src, 0b00001010, a, b);
+        let e = _mm_setr_epi32(2147483637, std::i32::MAX, 2147483637, std::i32::MAX);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vnni,avx512vl")]
+    unsafe fn test_mm_maskz_dpwssds_epi32() {
+        let src = _mm_set1_epi32(std::i32::MAX - 10);
+        let a = _mm_set1_epi32(0x7fff_7fff);
+        let b = _mm_set1_epi32(0x7fff_7fff);
+        let r = _mm_maskz_dpwssds_epi32(0, src, a, b);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_dpwssds_epi32(0b00001010, src, a, b);
+        let e = _mm_setr_epi32(0, std::i32::MAX, 0, std::i32::MAX);
+        assert_eq_m128i(r, e);
+    }
+}
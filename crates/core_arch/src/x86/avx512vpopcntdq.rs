@@ -0,0 +1,465 @@
+use crate::{
+    core_arch::{simd::*, simd_llvm::*, x86::*},
+    mem::transmute,
+};
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Counts the number of logical 1 bits in packed 32-bit integers in `a`, and
+/// stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_popcnt_epi32)
+#[inline]
+#[target_feature(enable = "avx512vpopcntdq")]
+#[cfg_attr(test, assert_instr(vpopcntd))]
+pub unsafe fn _mm512_popcnt_epi32(a: __m512i) -> __m512i {
+    transmute(popcnt_v16i32(a.as_i32x16()))
+}
+
+/// Counts the number of logical 1 bits in packed 32-bit integers in `a`, and
+/// stores the results in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_popcnt_epi32)
+#[inline]
+#[target_feature(enable = "avx512vpopcntdq")]
+#[cfg_attr(test, assert_instr(vpopcntd))]
+pub unsafe fn _mm512_mask_popcnt_epi32(src: __m512i, k: __mmask16, a: __m512i) -> __m512i {
+    let r = _mm512_popcnt_epi32(a).as_i32x16();
+    transmute(simd_select_bitmask(k, r, src.as_i32x16()))
+}
+
+/// Counts the number of logical 1 bits in packed 32-bit integers in `a`, and
+/// stores the results in `dst` using zeromask `k` (elements are zeroed out when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_popcnt_epi32)
+#[inline]
+#[target_feature(enable = "avx512vpopcntdq")]
+#[cfg_attr(test, assert_instr(vpopcntd))]
+pub unsafe fn _mm512_maskz_popcnt_epi32(k: __mmask16, a: __m512i) -> __m512i {
+    let r = _mm512_popcnt_epi32(a).as_i32x16();
+    let zero = _mm512_setzero_si512().as_i32x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Counts the number of logical 1 bits in packed 32-bit integers in `a`, and
+/// stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_popcnt_epi32)
+#[inline]
+#[target_feature(enable = "avx512vpopcntdq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntd))]
+pub unsafe fn _mm256_popcnt_epi32(a: __m256i) -> __m256i {
+    transmute(popcnt_v8i32(a.as_i32x8()))
+}
+
+/// Counts the number of logical 1 bits in packed 32-bit integers in `a`, and
+/// stores the results in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_popcnt_epi32)
+#[inline]
+#[target_feature(enable = "avx512vpopcntdq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntd))]
+pub unsafe fn _mm256_mask_popcnt_epi32(src: __m256i, k: __mmask8, a: __m256i) -> __m256i {
+    let r = _mm256_popcnt_epi32(a).as_i32x8();
+    transmute(simd_select_bitmask(k, r, src.as_i32x8()))
+}
+
+/// Counts the number of logical 1 bits in packed 32-bit integers in `a`, and
+/// stores the results in `dst` using zeromask `k` (elements are zeroed out when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_popcnt_epi32)
+#[inline]
+#[target_feature(enable = "avx512vpopcntdq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntd))]
+pub unsafe fn _mm256_maskz_popcnt_epi32(k: __mmask8, a: __m256i) -> __m256i {
+    let r = _mm256_popcnt_epi32(a).as_i32x8();
+    let zero = _mm256_setzero_si256().as_i32x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Counts the number of logical 1 bits in packed 32-bit integers in `a`, and
+/// stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_popcnt_epi32)
+#[inline]
+#[target_feature(enable = "avx512vpopcntdq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntd))]
+pub unsafe fn _mm_popcnt_epi32(a: __m128i) -> __m128i {
+    transmute(popcnt_v4i32(a.as_i32x4()))
+}
+
+/// Counts the number of logical 1 bits in packed 32-bit integers in `a`, and
+/// stores the results in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_popcnt_epi32)
+#[inline]
+#[target_feature(enable = "avx512vpopcntdq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntd))]
+pub unsafe fn _mm_mask_popcnt_epi32(src: __m128i, k: __mmask8, a: __m128i) -> __m128i {
+    let r = _mm_popcnt_epi32(a).as_i32x4();
+    transmute(simd_select_bitmask(k, r, src.as_i32x4()))
+}
+
+/// Counts the number of logical 1 bits in packed 32-bit integers in `a`, and
+/// stores the results in `dst` using zeromask `k` (elements are zeroed out when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_popcnt_epi32)
+#[inline]
+#[target_feature(enable = "avx512vpopcntdq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntd))]
+pub unsafe fn _mm_maskz_popcnt_epi32(k: __mmask8, a: __m128i) -> __m128i {
+    let r = _mm_popcnt_epi32(a).as_i32x4();
+    let zero = _mm_setzero_si128().as_i32x4();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Counts the number of logical 1 bits in packed 64-bit integers in `a`, and
+/// stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_popcnt_epi64)
+#[inline]
+#[target_feature(enable = "avx512vpopcntdq")]
+#[cfg_attr(test, assert_instr(vpopcntq))]
+pub unsafe fn _mm512_popcnt_epi64(a: __m512i) -> __m512i {
+    transmute(popcnt_v8i64(a.as_i64x8()))
+}
+
+/// Counts the number of logical 1 bits in packed 64-bit integers in `a`, and
+/// stores the results in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_popcnt_epi64)
+#[inline]
+#[target_feature(enable = "avx512vpopcntdq")]
+#[cfg_attr(test, assert_instr(vpopcntq))]
+pub unsafe fn _mm512_mask_popcnt_epi64(src: __m512i, k: __mmask8, a: __m512i) -> __m512i {
+    let r = _mm512_popcnt_epi64(a).as_i64x8();
+    transmute(simd_select_bitmask(k, r, src.as_i64x8()))
+}
+
+/// Counts the number of logical 1 bits in packed 64-bit integers in `a`, and
+/// stores the results in `dst` using zeromask `k` (elements are zeroed out when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_popcnt_epi64)
+#[inline]
+#[target_feature(enable = "avx512vpopcntdq")]
+#[cfg_attr(test, assert_instr(vpopcntq))]
+pub unsafe fn _mm512_maskz_popcnt_epi64(k: __mmask8, a: __m512i) -> __m512i {
+    let r = _mm512_popcnt_epi64(a).as_i64x8();
+    let zero = _mm512_setzero_si512().as_i64x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Counts the number of logical 1 bits in packed 64-bit integers in `a`, and
+/// stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_popcnt_epi64)
+#[inline]
+#[target_feature(enable = "avx512vpopcntdq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntq))]
+pub unsafe fn _mm256_popcnt_epi64(a: __m256i) -> __m256i {
+    transmute(popcnt_v4i64(a.as_i64x4()))
+}
+
+/// Counts the number of logical 1 bits in packed 64-bit integers in `a`, and
+/// stores the results in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_popcnt_epi64)
+#[inline]
+#[target_feature(enable = "avx512vpopcntdq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntq))]
+pub unsafe fn _mm256_mask_popcnt_epi64(src: __m256i, k: __mmask8, a: __m256i) -> __m256i {
+    let r = _mm256_popcnt_epi64(a).as_i64x4();
+    transmute(simd_select_bitmask(k, r, src.as_i64x4()))
+}
+
+/// Counts the number of logical 1 bits in packed 64-bit integers in `a`, and
+/// stores the results in `dst` using zeromask `k` (elements are zeroed out when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_popcnt_epi64)
+#[inline]
+#[target_feature(enable = "avx512vpopcntdq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntq))]
+pub unsafe fn _mm256_maskz_popcnt_epi64(k: __mmask8, a: __m256i) -> __m256i {
+    let r = _mm256_popcnt_epi64(a).as_i64x4();
+    let zero = _mm256_setzero_si256().as_i64x4();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Counts the number of logical 1 bits in packed 64-bit integers in `a`, and
+/// stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_popcnt_epi64)
+#[inline]
+#[target_feature(enable = "avx512vpopcntdq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntq))]
+pub unsafe fn _mm_popcnt_epi64(a: __m128i) -> __m128i {
+    transmute(popcnt_v2i64(a.as_i64x2()))
+}
+
+/// Counts the number of logical 1 bits in packed 64-bit integers in `a`, and
+/// stores the results in `dst` using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_popcnt_epi64)
+#[inline]
+#[target_feature(enable = "avx512vpopcntdq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntq))]
+pub unsafe fn _mm_mask_popcnt_epi64(src: __m128i, k: __mmask8, a: __m128i) -> __m128i {
+    let r = _mm_popcnt_epi64(a).as_i64x2();
+    transmute(simd_select_bitmask(k, r, src.as_i64x2()))
+}
+
+/// Counts the number of logical 1 bits in packed 64-bit integers in `a`, and
+/// stores the results in `dst` using zeromask `k` (elements are zeroed out when
+/// the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_popcnt_epi64)
+#[inline]
+#[target_feature(enable = "avx512vpopcntdq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntq))]
+pub unsafe fn _mm_maskz_popcnt_epi64(k: __mmask8, a: __m128i) -> __m128i {
+    let r = _mm_popcnt_epi64(a).as_i64x2();
+    let zero = _mm_setzero_si128().as_i64x2();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.ctpop.v16i32"]
+    fn popcnt_v16i32(x: i32x16) -> i32x16;
+    #[link_name = "llvm.ctpop.v8i32"]
+    fn popcnt_v8i32(x: i32x8) -> i32x8;
+    #[link_name = "llvm.ctpop.v4i32"]
+    fn popcnt_v4i32(x: i32x4) -> i32x4;
+    #[link_name = "llvm.ctpop.v8i64"]
+    fn popcnt_v8i64(x: i64x8) -> i64x8;
+    #[link_name = "llvm.ctpop.v4i64"]
+    fn popcnt_v4i64(x: i64x4) -> i64x4;
+    #[link_name = "llvm.ctpop.v2i64"]
+    fn popcnt_v2i64(x: i64x2) -> i64x2;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use stdsimd_test::simd_test;
+
+    #[simd_test(enable = "avx512vpopcntdq")]
+    unsafe fn test_mm512_popcnt_epi32() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi32(
+            14, 2046, 262142, 33554430,
+            -2, 126, 16382, 2097150,
+            268435454, 6, 1022, 131070,
+            16777214, 2147483646, 62, 8190,
+        );
+        let r = _mm512_popcnt_epi32(a);
+        let e = _mm512_setr_epi32(3, 10, 17, 24, 31, 6, 13, 20, 27, 2, 9, 16, 23, 30, 5, 12);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vpopcntdq")]
+    unsafe fn test_mm512_mask_popcnt_epi32() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi32(
+            14, 2046, 262142, 33554430,
+            -2, 126, 16382, 2097150,
+            268435454, 6, 1022, 131070,
+            16777214, 2147483646, 62, 8190,
+        );
+        let r = _mm512_mask_popcnt_epi32(a, 0, a);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_popcnt_epi32(a, 0b11001010_00110101, a);
+        #[rustfmt::skip]
+        let e = _mm512_setr_epi32(
+            3, 2046, 17, 33554430,
+            31, 6, 16382, 2097150,
+            268435454, 2, 1022, 16,
+            16777214, 2147483646, 5, 12,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vpopcntdq")]
+    unsafe fn test_mm512_maskz_popcnt_epi32() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi32(
+            14, 2046, 262142, 33554430,
+            -2, 126, 16382, 2097150,
+            268435454, 6, 1022, 131070,
+            16777214, 2147483646, 62, 8190,
+        );
+        let r = _mm512_maskz_popcnt_epi32(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_popcnt_epi32(0b11001010_00110101, a);
+        let e = _mm512_setr_epi32(3, 0, 17, 0, 31, 6, 0, 0, 0, 2, 0, 16, 0, 0, 5, 12);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vpopcntdq,avx512vl")]
+    unsafe fn test_mm256_popcnt_epi32() {
+        let a = _mm256_setr_epi32(14, 2046, 262142, 33554430, -2, 126, 16382, 2097150);
+        let r = _mm256_popcnt_epi32(a);
+        let e = _mm256_setr_epi32(3, 10, 17, 24, 31, 6, 13, 20);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vpopcntdq,avx512vl")]
+    unsafe fn test_mm256_mask_popcnt_epi32() {
+        let a = _mm256_setr_epi32(14, 2046, 262142, 33554430, -2, 126, 16382, 2097150);
+        let r = _mm256_mask_popcnt_epi32(a, 0, a);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_popcnt_epi32(a, 0b00110101, a);
+        let e = _mm256_setr_epi32(3, 2046, 17, 33554430, 31, 6, 16382, 2097150);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vpopcntdq,avx512vl")]
+    unsafe fn test_mm256_maskz_popcnt_epi32() {
+        let a = _mm256_setr_epi32(14, 2046, 262142, 33554430, -2, 126, 16382, 2097150);
+        let r = _mm256_maskz_popcnt_epi32(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_popcnt_epi32(0b00110101, a);
+        let e = _mm256_setr_epi32(3, 0, 17, 0, 31, 6, 0, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vpopcntdq,avx512vl")]
+    unsafe fn test_mm_popcnt_epi32() {
+        let a = _mm_setr_epi32(14, 2046, 262142, 33554430);
+        let r = _mm_popcnt_epi32(a);
+        let e = _mm_setr_epi32(3, 10, 17, 24);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vpopcntdq,avx512vl")]
+    unsafe fn test_mm_mask_popcnt_epi32() {
+        let a = _mm_setr_epi32(14, 2046, 262142, 33554430);
+        let r = _mm_mask_popcnt_epi32(a, 0, a);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_popcnt_epi32(a, 0b00001010, a);
+        let e = _mm_setr_epi32(14, 10, 262142, 24);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vpopcntdq,avx512vl")]
+    unsafe fn test_mm_maskz_popcnt_epi32() {
+        let a = _mm_setr_epi32(14, 2046, 262142, 33554430);
+        let r = _mm_maskz_popcnt_epi32(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_popcnt_epi32(0b00001010, a);
+        let e = _mm_setr_epi32(0, 10, 0, 24);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vpopcntdq")]
+    unsafe fn test_mm512_popcnt_epi64() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi64(
+            14, 2046, 262142, 33554430,
+            4294967294, 549755813886, 70368744177662, 9007199254740990,
+        );
+        let r = _mm512_popcnt_epi64(a);
+        let e = _mm512_setr_epi64(3, 10, 17, 24, 31, 38, 45, 52);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vpopcntdq")]
+    unsafe fn test_mm512_mask_popcnt_epi64() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi64(
+            14, 2046, 262142, 33554430,
+            4294967294, 549755813886, 70368744177662, 9007199254740990,
+        );
+        let r = _mm512_mask_popcnt_epi64(a, 0, a);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_popcnt_epi64(a, 0b00110101, a);
+        #[rustfmt::skip]
+        let e = _mm512_setr_epi64(
+            3, 2046, 17, 33554430,
+            31, 38, 70368744177662, 9007199254740990,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vpopcntdq")]
+    unsafe fn test_mm512_maskz_popcnt_epi64() {
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi64(
+            14, 2046, 262142, 33554430,
+            4294967294, 549755813886, 70368744177662, 9007199254740990,
+        );
+        let r = _mm512_maskz_popcnt_epi64(0, a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_popcnt_epi64(0b00110101, a);
+        let e = _mm512_setr_epi64(3, 0, 17, 0, 31, 38, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vpopcntdq,avx512vl")]
+    unsafe fn test_mm256_popcnt_epi64() {
+        let a = _mm256_setr_epi64x(14, 2046, 262142, 33554430);
+        let r = _mm256_popcnt_epi64(a);
+        let e = _mm256_setr_epi64x(3, 10, 17, 24);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vpopcntdq,avx512vl")]
+    unsafe fn test_mm256_mask_popcnt_epi64() {
+        let a = _mm256_setr_epi64x(14, 2046, 262142, 33554430);
+        let r = _mm256_mask_popcnt_epi64(a, 0, a);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_popcnt_epi64(a, 0b00001010, a);
+        let e = _mm256_setr_epi64x(14, 10, 262142, 24);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vpopcntdq,avx512vl")]
+    unsafe fn test_mm256_maskz_popcnt_epi64() {
+        let a = _mm256_setr_epi64x(14, 2046, 262142, 33554430);
+        let r = _mm256_maskz_popcnt_epi64(0, a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_popcnt_epi64(0b00001010, a);
+        let e = _mm256_setr_epi64x(0, 10, 0, 24);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vpopcntdq,avx512vl")]
+    unsafe fn test_mm_popcnt_epi64() {
+        let a = _mm_setr_epi64x(14, 2046);
+        let r = _mm_popcnt_epi64(a);
+        let e = _mm_setr_epi64x(3, 10);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vpopcntdq,avx512vl")]
+    unsafe fn test_mm_mask_popcnt_epi64() {
+        let a = _mm_setr_epi64x(14, 2046);
+        let r = _mm_mask_popcnt_epi64(a, 0, a);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_popcnt_epi64(a, 0b00000010, a);
+        let e = _mm_setr_epi64x(14, 10);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512vpopcntdq,avx512vl")]
+    unsafe fn test_mm_maskz_popcnt_epi64() {
+        let a = _mm_setr_epi64x(14, 2046);
+        let r = _mm_maskz_popcnt_epi64(0, a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_popcnt_epi64(0b00000010, a);
+        let e = _mm_setr_epi64x(0, 10);
+        assert_eq_m128i(r, e);
+    }
+}
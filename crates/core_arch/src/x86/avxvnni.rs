@@ -0,0 +1,219 @@
+use crate::{
+    core_arch::{simd::*, x86::*},
+    mem::transmute,
+};
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Multiplies groups of 4 adjacent pairs of unsigned 8-bit integers in `a` with
+/// corresponding signed 8-bit integers in `b`, producing 4 intermediate signed
+/// 16-bit results. Sums these 4 results with the corresponding 32-bit integer
+/// in `src`, and stores the packed 32-bit results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_dpbusd_avx_epi32)
+#[inline]
+#[target_feature(enable = "avxvnni")]
+#[cfg_attr(test, assert_instr(vpdpbusd))]
+pub unsafe fn _mm256_dpbusd_avx_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i {
+    transmute(vpdpbusd_256(src.as_i32x8(), a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Multiplies groups of 4 adjacent pairs of unsigned 8-bit integers in `a` with
+/// corresponding signed 8-bit integers in `b`, producing 4 intermediate signed
+/// 16-bit results. Sums these 4 results with the corresponding 32-bit integer
+/// in `src`, and stores the packed 32-bit results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_dpbusd_avx_epi32)
+#[inline]
+#[target_feature(enable = "avxvnni")]
+#[cfg_attr(test, assert_instr(vpdpbusd))]
+pub unsafe fn _mm_dpbusd_avx_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpdpbusd_128(src.as_i32x4(), a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Multiplies groups of 4 adjacent pairs of unsigned 8-bit integers in `a` with
+/// corresponding signed 8-bit integers in `b`, producing 4 intermediate signed
+/// 16-bit results. Sums these 4 results with the corresponding 32-bit integer
+/// in `src` using signed saturation, and stores the packed 32-bit results in
+/// `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_dpbusds_avx_epi32)
+#[inline]
+#[target_feature(enable = "avxvnni")]
+#[cfg_attr(test, assert_instr(vpdpbusds))]
+pub unsafe fn _mm256_dpbusds_avx_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i {
+    transmute(vpdpbusds_256(src.as_i32x8(), a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Multiplies groups of 4 adjacent pairs of unsigned 8-bit integers in `a` with
+/// corresponding signed 8-bit integers in `b`, producing 4 intermediate signed
+/// 16-bit results. Sums these 4 results with the corresponding 32-bit integer
+/// in `src` using signed saturation, and stores the packed 32-bit results in
+/// `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_dpbusds_avx_epi32)
+#[inline]
+#[target_feature(enable = "avxvnni")]
+#[cfg_attr(test, assert_instr(vpdpbusds))]
+pub unsafe fn _mm_dpbusds_avx_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpdpbusds_128(src.as_i32x4(), a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Multiplies groups of 2 adjacent pairs of signed 16-bit integers in `a` with
+/// corresponding 16-bit integers in `b`, producing 2 intermediate signed 32-bit
+/// results. Sums these 2 results with the corresponding 32-bit integer in
+/// `src`, and stores the packed 32-bit results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_dpwssd_avx_epi32)
+#[inline]
+#[target_feature(enable = "avxvnni")]
+#[cfg_attr(test, assert_instr(vpdpwssd))]
+pub unsafe fn _mm256_dpwssd_avx_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i {
+    transmute(vpdpwssd_256(src.as_i32x8(), a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Multiplies groups of 2 adjacent pairs of signed 16-bit integers in `a` with
+/// corresponding 16-bit integers in `b`, producing 2 intermediate signed 32-bit
+/// results. Sums these 2 results with the corresponding 32-bit integer in
+/// `src`, and stores the packed 32-bit results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_dpwssd_avx_epi32)
+#[inline]
+#[target_feature(enable = "avxvnni")]
+#[cfg_attr(test, assert_instr(vpdpwssd))]
+pub unsafe fn _mm_dpwssd_avx_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpdpwssd_128(src.as_i32x4(), a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Multiplies groups of 2 adjacent pairs of signed 16-bit integers in `a` with
+/// corresponding 16-bit integers in `b`, producing 2 intermediate signed 32-bit
+/// results. Sums these 2 results with the corresponding 32-bit integer in `src`
+/// using signed saturation, and stores the packed 32-bit results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_dpwssds_avx_epi32)
+#[inline]
+#[target_feature(enable = "avxvnni")]
+#[cfg_attr(test, assert_instr(vpdpwssds))]
+pub unsafe fn _mm256_dpwssds_avx_epi32(src: __m256i, a: __m256i, b: __m256i) -> __m256i {
+    transmute(vpdpwssds_256(src.as_i32x8(), a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Multiplies groups of 2 adjacent pairs of signed 16-bit integers in `a` with
+/// corresponding 16-bit integers in `b`, producing 2 intermediate signed 32-bit
+/// results. Sums these 2 results with the corresponding 32-bit integer in `src`
+/// using signed saturation, and stores the packed 32-bit results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_dpwssds_avx_epi32)
+#[inline]
+#[target_feature(enable = "avxvnni")]
+#[cfg_attr(test, assert_instr(vpdpwssds))]
+pub unsafe fn _mm_dpwssds_avx_epi32(src: __m128i, a: __m128i, b: __m128i) -> __m128i {
+    transmute(vpdpwssds_128(src.as_i32x4(), a.as_i32x4(), b.as_i32x4()))
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.avx512.vpdpbusd.256"]
+    fn vpdpbusd_256(src: i32x8, a: i32x8, b: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx512.vpdpbusd.128"]
+    fn vpdpbusd_128(src: i32x4, a: i32x4, b: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.avx512.vpdpbusds.256"]
+    fn vpdpbusds_256(src: i32x8, a: i32x8, b: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx512.vpdpbusds.128"]
+    fn vpdpbusds_128(src: i32x4, a: i32x4, b: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.avx512.vpdpwssd.256"]
+    fn vpdpwssd_256(src: i32x8, a: i32x8, b: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx512.vpdpwssd.128"]
+    fn vpdpwssd_128(src: i32x4, a: i32x4, b: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.avx512.vpdpwssds.256"]
+    fn vpdpwssds_256(src: i32x8, a: i32x8, b: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx512.vpdpwssds.128"]
+    fn vpdpwssds_128(src: i32x4, a: i32x4, b: i32x4) -> i32x4;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use stdsimd_test::simd_test;
+
+    #[simd_test(enable = "avxvnni")]
+    unsafe fn test_mm256_dpbusd_avx_epi32() {
+        let src = _mm256_set1_epi32(100);
+        let a = _mm256_set1_epi32(0x0102_0304);
+        let b = _mm256_set1_epi32(-1);
+        let r = _mm256_dpbusd_avx_epi32(src, a, b);
+        let e = _mm256_set1_epi32(90);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avxvnni")]
+    unsafe fn test_mm_dpbusd_avx_epi32() {
+        let src = _mm_set1_epi32(100);
+        let a = _mm_set1_epi32(0x0102_0304);
+        let b = _mm_set1_epi32(-1);
+        let r = _mm_dpbusd_avx_epi32(src, a, b);
+        let e = _mm_set1_epi32(90);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avxvnni")]
+    unsafe fn test_mm256_dpbusds_avx_epi32() {
+        let src = _mm256_set1_epi32(std::i32::MAX - 10);
+        let a = _mm256_set1_epi32(-1);
+        let b = _mm256_set1_epi32(0x7f7f_7f7f);
+        let r = _mm256_dpbusds_avx_epi32(src, a, b);
+        let e = _mm256_set1_epi32(std::i32::MAX);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avxvnni")]
+    unsafe fn test_mm_dpbusds_avx_epi32() {
+        let src = _mm_set1_epi32(std::i32::MAX - 10);
+        let a = _mm_set1_epi32(-1);
+        let b = _mm_set1_epi32(0x7f7f_7f7f);
+        let r = _mm_dpbusds_avx_epi32(src, a, b);
+        let e = _mm_set1_epi32(std::i32::MAX);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avxvnni")]
+    unsafe fn test_mm256_dpwssd_avx_epi32() {
+        let src = _mm256_set1_epi32(100);
+        let a = _mm256_set1_epi32(0x0002_0003);
+        let b = _mm256_set1_epi32(0x0004_fffb);
+        let r = _mm256_dpwssd_avx_epi32(src, a, b);
+        let e = _mm256_set1_epi32(93);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avxvnni")]
+    unsafe fn test_mm_dpwssd_avx_epi32() {
+        let src = _mm_set1_epi32(100);
+        let a = _mm_set1_epi32(0x0002_0003);
+        let b = _mm_set1_epi32(0x0004_fffb);
+        let r = _mm_dpwssd_avx_epi32(src, a, b);
+        let e = _mm_set1_epi32(93);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avxvnni")]
+    unsafe fn test_mm256_dpwssds_avx_epi32() {
+        let src = _mm256_set1_epi32(std::i32::MAX - 10);
+        let a = _mm256_set1_epi32(0x7fff_7fff);
+        let b = _mm256_set1_epi32(0x7fff_7fff);
+        let r = _mm256_dpwssds_avx_epi32(src, a, b);
+        let e = _mm256_set1_epi32(std::i32::MAX);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avxvnni")]
+    unsafe fn test_mm_dpwssds_avx_epi32() {
+        let src = _mm_set1_epi32(std::i32::MAX - 10);
+        let a = _mm_set1_epi32(0x7fff_7fff);
+        let b = _mm_set1_epi32(0x7fff_7fff);
+        let r = _mm_dpwssds_avx_epi32(src, a, b);
+        let e = _mm_set1_epi32(std::i32::MAX);
+        assert_eq_m128i(r, e);
+    }
+}
@@ -0,0 +1,199 @@
+//! Half-precision floating-point conversion instructions (F16C)
+//!
+//! The F16C instruction set converts between packed single-precision (32-bit)
+//! floating-point values and packed IEEE 754 half-precision (16-bit)
+//! floating-point values stored in the 16-bit lanes of an integer vector.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use crate::{
+    core_arch::{simd::*, x86::*},
+    mem::transmute,
+};
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.vcvtph2ps.128"]
+    fn vcvtph2ps_128(a: i16x8) -> __m128;
+    #[link_name = "llvm.x86.vcvtph2ps.256"]
+    fn vcvtph2ps_256(a: i16x8) -> __m256;
+    #[link_name = "llvm.x86.vcvtps2ph.128"]
+    fn vcvtps2ph_128(a: __m128, rounding: i32) -> i16x8;
+    #[link_name = "llvm.x86.vcvtps2ph.256"]
+    fn vcvtps2ph_256(a: __m256, rounding: i32) -> i16x8;
+}
+
+/// Converts the four packed half-precision (16-bit) floating-point elements
+/// in the low 64 bits of `a` to packed single-precision (32-bit)
+/// floating-point elements, and stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtph_ps)
+#[inline]
+#[target_feature(enable = "f16c")]
+#[cfg_attr(test, assert_instr(vcvtph2ps))]
+pub unsafe fn _mm_cvtph_ps(a: __m128i) -> __m128 {
+    vcvtph2ps_128(a.as_i16x8())
+}
+
+/// Converts the eight packed half-precision (16-bit) floating-point elements
+/// in `a` to packed single-precision (32-bit) floating-point elements, and
+/// stores the results in `dst`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtph_ps)
+#[inline]
+#[target_feature(enable = "f16c")]
+#[cfg_attr(test, assert_instr(vcvtph2ps))]
+pub unsafe fn _mm256_cvtph_ps(a: __m128i) -> __m256 {
+    vcvtph2ps_256(a.as_i16x8())
+}
+
+/// Converts the four packed single-precision (32-bit) floating-point elements
+/// in `a` to packed half-precision (16-bit) floating-point elements, and
+/// stores the results in the low 64 bits of `dst`. The upper 64 bits of `dst`
+/// are zeroed.
+///
+/// Rounding is done according to the `rounding` parameter, which can be one
+/// of:
+///
+/// * `_MM_FROUND_TO_NEAREST_INT`: round to nearest
+/// * `_MM_FROUND_TO_NEG_INF`: round down
+/// * `_MM_FROUND_TO_POS_INF`: round up
+/// * `_MM_FROUND_TO_ZERO`: truncate
+/// * `_MM_FROUND_CUR_DIRECTION`: use `MXCSR.RC`; see `_MM_SET_ROUNDING_MODE`
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_cvtps_ph)
+#[inline]
+#[target_feature(enable = "f16c")]
+#[cfg_attr(test, assert_instr(vcvtps2ph, rounding = 0))]
+#[rustc_args_required_const(1)]
+pub unsafe fn _mm_cvtps_ph(a: __m128, rounding: i32) -> __m128i {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vcvtps2ph_128(a, $imm3)
+        };
+    }
+    transmute(constify_imm3!(rounding, call))
+}
+
+/// Converts the eight packed single-precision (32-bit) floating-point
+/// elements in `a` to packed half-precision (16-bit) floating-point elements,
+/// and stores the results in `dst`.
+///
+/// Rounding is done according to the `rounding` parameter, which can be one
+/// of:
+///
+/// * `_MM_FROUND_TO_NEAREST_INT`: round to nearest
+/// * `_MM_FROUND_TO_NEG_INF`: round down
+/// * `_MM_FROUND_TO_POS_INF`: round up
+/// * `_MM_FROUND_TO_ZERO`: truncate
+/// * `_MM_FROUND_CUR_DIRECTION`: use `MXCSR.RC`; see `_MM_SET_ROUNDING_MODE`
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_cvtps_ph)
+#[inline]
+#[target_feature(enable = "f16c")]
+#[cfg_attr(test, assert_instr(vcvtps2ph, rounding = 0))]
+#[rustc_args_required_const(1)]
+pub unsafe fn _mm256_cvtps_ph(a: __m256, rounding: i32) -> __m128i {
+    macro_rules! call {
+        ($imm3:expr) => {
+            vcvtps2ph_256(a, $imm3)
+        };
+    }
+    transmute(constify_imm3!(rounding, call))
+}
+
+#[cfg(test)]
+mod tests {
+    // The integer constants in the tests below are IEEE 754 bit patterns;
+    // they should not be interpreted as signed integers.
+    #![allow(overflowing_literals)]
+
+    use stdsimd_test::simd_test;
+
+    use crate::core_arch::x86::*;
+
+    #[simd_test(enable = "f16c")]
+    unsafe fn test_mm_cvtph_ps() {
+        // 1.0, the smallest denormal, -inf and a quiet NaN with a payload.
+        // The upper four elements are ignored.
+        let a = _mm_setr_epi16(0x3c00, 0x0001, 0xfc00, 0x7e01, 1, 2, 3, 4);
+        let r = _mm_cvtph_ps(a);
+        let e = _mm_setr_epi32(0x3f80_0000, 0x3380_0000, 0xff80_0000, 0x7fc0_2000);
+        assert_eq_m128i(_mm_castps_si128(r), e);
+    }
+
+    #[simd_test(enable = "f16c")]
+    unsafe fn test_mm256_cvtph_ps() {
+        // The signalling NaN in the last element is quieted, keeping its
+        // payload.
+        #[rustfmt::skip]
+        let a = _mm_setr_epi16(
+            0x3c00, 0x0001, 0xfc00, 0x7e01,
+            0xc000, 0x03ff, 0x7c00, 0xfd00,
+        );
+        let r = _mm256_cvtph_ps(a);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi32(
+            0x3f80_0000, 0x3380_0000, 0xff80_0000, 0x7fc0_2000,
+            0xc000_0000, 0x387f_c000, 0x7f80_0000, 0xffe0_0000,
+        );
+        assert_eq_m256i(_mm256_castps_si256(r), e);
+    }
+
+    #[simd_test(enable = "f16c")]
+    unsafe fn test_mm_cvtps_ph() {
+        // A tie between 1.0 and the next half, a value that overflows when
+        // rounded to nearest, a tie between zero and the smallest denormal,
+        // and a quiet NaN whose payload survives the conversion.
+        let a = _mm_setr_ps(
+            f32::from_bits(0x3f80_1000),
+            65520.,
+            f32::from_bits(0x3300_0000),
+            f32::from_bits(0x7fc0_2000),
+        );
+        let r = _mm_cvtps_ph(a, _MM_FROUND_TO_NEAREST_INT);
+        let e = _mm_setr_epi16(0x3c00, 0x7c00, 0x0000, 0x7e01, 0, 0, 0, 0);
+        assert_eq_m128i(r, e);
+        let r = _mm_cvtps_ph(a, _MM_FROUND_TO_ZERO);
+        let e = _mm_setr_epi16(0x3c00, 0x7bff, 0x0000, 0x7e01, 0, 0, 0, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "f16c")]
+    unsafe fn test_mm256_cvtps_ph() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_ps(
+            f32::from_bits(0x3f80_1000), f32::from_bits(0xbf80_1000),
+            65520., -65520.,
+            f32::from_bits(0x3300_0000), f32::from_bits(0xb300_0000),
+            std::f32::INFINITY, f32::from_bits(0xffc0_2000),
+        );
+        let r = _mm256_cvtps_ph(a, _MM_FROUND_TO_POS_INF);
+        #[rustfmt::skip]
+        let e = _mm_setr_epi16(
+            0x3c01, 0xbc00, 0x7c00, 0xfbff,
+            0x0001, 0x8000, 0x7c00, 0xfe01,
+        );
+        assert_eq_m128i(r, e);
+        let r = _mm256_cvtps_ph(a, _MM_FROUND_TO_NEG_INF);
+        #[rustfmt::skip]
+        let e = _mm_setr_epi16(
+            0x3c00, 0xbc01, 0x7bff, 0xfc00,
+            0x0000, 0x8001, 0x7c00, 0xfe01,
+        );
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "f16c")]
+    unsafe fn test_mm_cvtps_ph_roundtrip() {
+        let a = _mm_setr_ps(1.5, -0.25, 1024., 0.000_061_035_156_25);
+        let r = _mm_cvtph_ps(_mm_cvtps_ph(a, _MM_FROUND_TO_NEAREST_INT));
+        assert_eq_m128(r, a);
+    }
+}
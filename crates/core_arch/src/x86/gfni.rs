@@ -0,0 +1,1528 @@
+//! Galois Field New Instructions (GFNI)
+//!
+//! The intrinsics here operate on bytes as elements of the finite field
+//! GF(2^8), either multiplying them or applying an affine transformation
+//! given by a bit matrix.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use crate::{
+    core_arch::{simd::*, simd_llvm::*, x86::*},
+    mem::transmute,
+};
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Performs a multiplication in GF(2^8) on the packed bytes of `a` and `b`. The
+/// field is in polynomial representation with the reduction polynomial x^8 +
+/// x^4 + x^3 + x + 1.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_gf2p8mul_epi8)
+#[inline]
+#[target_feature(enable = "gfni,avx512f")]
+#[cfg_attr(test, assert_instr(vgf2p8mulb))]
+pub unsafe fn _mm512_gf2p8mul_epi8(a: __m512i, b: __m512i) -> __m512i {
+    transmute(vgf2p8mulb_512(a.as_i8x64(), b.as_i8x64()))
+}
+
+/// Performs a multiplication in GF(2^8) on the packed bytes of `a` and `b`. The
+/// field is in polynomial representation with the reduction polynomial x^8 +
+/// x^4 + x^3 + x + 1, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_gf2p8mul_epi8)
+#[inline]
+#[target_feature(enable = "gfni,avx512bw")]
+#[cfg_attr(test, assert_instr(vgf2p8mulb))]
+pub unsafe fn _mm512_mask_gf2p8mul_epi8(
+    src: __m512i,
+    k: __mmask64,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let r = vgf2p8mulb_512(a.as_i8x64(), b.as_i8x64());
+    transmute(simd_select_bitmask(k, r, src.as_i8x64()))
+}
+
+/// Performs a multiplication in GF(2^8) on the packed bytes of `a` and `b`. The
+/// field is in polynomial representation with the reduction polynomial x^8 +
+/// x^4 + x^3 + x + 1, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_gf2p8mul_epi8)
+#[inline]
+#[target_feature(enable = "gfni,avx512bw")]
+#[cfg_attr(test, assert_instr(vgf2p8mulb))]
+pub unsafe fn _mm512_maskz_gf2p8mul_epi8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i {
+    let r = vgf2p8mulb_512(a.as_i8x64(), b.as_i8x64());
+    let zero = _mm512_setzero_si512().as_i8x64();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Performs a multiplication in GF(2^8) on the packed bytes of `a` and `b`. The
+/// field is in polynomial representation with the reduction polynomial x^8 +
+/// x^4 + x^3 + x + 1.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_gf2p8mul_epi8)
+#[inline]
+#[target_feature(enable = "gfni,avx")]
+#[cfg_attr(test, assert_instr(vgf2p8mulb))]
+pub unsafe fn _mm256_gf2p8mul_epi8(a: __m256i, b: __m256i) -> __m256i {
+    transmute(vgf2p8mulb_256(a.as_i8x32(), b.as_i8x32()))
+}
+
+/// Performs a multiplication in GF(2^8) on the packed bytes of `a` and `b`. The
+/// field is in polynomial representation with the reduction polynomial x^8 +
+/// x^4 + x^3 + x + 1, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_gf2p8mul_epi8)
+#[inline]
+#[target_feature(enable = "gfni,avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vgf2p8mulb))]
+pub unsafe fn _mm256_mask_gf2p8mul_epi8(
+    src: __m256i,
+    k: __mmask32,
+    a: __m256i,
+    b: __m256i,
+) -> __m256i {
+    let r = vgf2p8mulb_256(a.as_i8x32(), b.as_i8x32());
+    transmute(simd_select_bitmask(k, r, src.as_i8x32()))
+}
+
+/// Performs a multiplication in GF(2^8) on the packed bytes of `a` and `b`. The
+/// field is in polynomial representation with the reduction polynomial x^8 +
+/// x^4 + x^3 + x + 1, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_gf2p8mul_epi8)
+#[inline]
+#[target_feature(enable = "gfni,avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vgf2p8mulb))]
+pub unsafe fn _mm256_maskz_gf2p8mul_epi8(k: __mmask32, a: __m256i, b: __m256i) -> __m256i {
+    let r = vgf2p8mulb_256(a.as_i8x32(), b.as_i8x32());
+    let zero = _mm256_setzero_si256().as_i8x32();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Performs a multiplication in GF(2^8) on the packed bytes of `a` and `b`. The
+/// field is in polynomial representation with the reduction polynomial x^8 +
+/// x^4 + x^3 + x + 1.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_gf2p8mul_epi8)
+#[inline]
+#[target_feature(enable = "gfni")]
+#[cfg_attr(test, assert_instr(gf2p8mulb))]
+pub unsafe fn _mm_gf2p8mul_epi8(a: __m128i, b: __m128i) -> __m128i {
+    transmute(vgf2p8mulb_128(a.as_i8x16(), b.as_i8x16()))
+}
+
+/// Performs a multiplication in GF(2^8) on the packed bytes of `a` and `b`. The
+/// field is in polynomial representation with the reduction polynomial x^8 +
+/// x^4 + x^3 + x + 1, and stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_gf2p8mul_epi8)
+#[inline]
+#[target_feature(enable = "gfni,avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vgf2p8mulb))]
+pub unsafe fn _mm_mask_gf2p8mul_epi8(
+    src: __m128i,
+    k: __mmask16,
+    a: __m128i,
+    b: __m128i,
+) -> __m128i {
+    let r = vgf2p8mulb_128(a.as_i8x16(), b.as_i8x16());
+    transmute(simd_select_bitmask(k, r, src.as_i8x16()))
+}
+
+/// Performs a multiplication in GF(2^8) on the packed bytes of `a` and `b`. The
+/// field is in polynomial representation with the reduction polynomial x^8 +
+/// x^4 + x^3 + x + 1, and stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_gf2p8mul_epi8)
+#[inline]
+#[target_feature(enable = "gfni,avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vgf2p8mulb))]
+pub unsafe fn _mm_maskz_gf2p8mul_epi8(k: __mmask16, a: __m128i, b: __m128i) -> __m128i {
+    let r = vgf2p8mulb_128(a.as_i8x16(), b.as_i8x16());
+    let zero = _mm_setzero_si128().as_i8x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Performs an affine transformation on the packed bytes in `x`. That is,
+/// computes `a * x + b` over the Galois field GF(2^8) for each packed byte,
+/// with `a` being an 8x8 bit matrix taken from the corresponding 64-bit element
+/// of `a` and `b` being an 8-bit constant vector.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_gf2p8affine_epi64_epi8)
+#[inline]
+#[target_feature(enable = "gfni,avx512f")]
+#[cfg_attr(test, assert_instr(vgf2p8affineqb, b = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_gf2p8affine_epi64_epi8(x: __m512i, a: __m512i, b: i32) -> __m512i {
+    let x = x.as_i8x64();
+    let a = a.as_i8x64();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vgf2p8affineqb_512(x, a, $imm8)
+        };
+    }
+    transmute(constify_imm8!(b, call))
+}
+
+/// Performs an affine transformation on the packed bytes in `x`. That is,
+/// computes `a * x + b` over the Galois field GF(2^8) for each packed byte,
+/// with `a` being an 8x8 bit matrix taken from the corresponding 64-bit element
+/// of `a` and `b` being an 8-bit constant vector. Stores the results in `dst`
+/// using writemask `k` (elements are copied from `src` when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_gf2p8affine_epi64_epi8)
+#[inline]
+#[target_feature(enable = "gfni,avx512bw")]
+#[cfg_attr(test, assert_instr(vgf2p8affineqb, b = 0))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm512_mask_gf2p8affine_epi64_epi8(
+    src: __m512i,
+    k: __mmask64,
+    x: __m512i,
+    a: __m512i,
+    b: i32,
+) -> __m512i {
+    let x = x.as_i8x64();
+    let a = a.as_i8x64();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vgf2p8affineqb_512(x, a, $imm8)
+        };
+    }
+    let r = constify_imm8!(b, call);
+    transmute(simd_select_bitmask(k, r, src.as_i8x64()))
+}
+
+/// Performs an affine transformation on the packed bytes in `x`. That is,
+/// computes `a * x + b` over the Galois field GF(2^8) for each packed byte,
+/// with `a` being an 8x8 bit matrix taken from the corresponding 64-bit element
+/// of `a` and `b` being an 8-bit constant vector. Stores the results in `dst`
+/// using zeromask `k` (elements are zeroed out when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_gf2p8affine_epi64_epi8)
+#[inline]
+#[target_feature(enable = "gfni,avx512bw")]
+#[cfg_attr(test, assert_instr(vgf2p8affineqb, b = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm512_maskz_gf2p8affine_epi64_epi8(
+    k: __mmask64,
+    x: __m512i,
+    a: __m512i,
+    b: i32,
+) -> __m512i {
+    let x = x.as_i8x64();
+    let a = a.as_i8x64();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vgf2p8affineqb_512(x, a, $imm8)
+        };
+    }
+    let r = constify_imm8!(b, call);
+    let zero = _mm512_setzero_si512().as_i8x64();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Performs an affine transformation on the packed bytes in `x`. That is,
+/// computes `a * x + b` over the Galois field GF(2^8) for each packed byte,
+/// with `a` being an 8x8 bit matrix taken from the corresponding 64-bit element
+/// of `a` and `b` being an 8-bit constant vector.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_gf2p8affine_epi64_epi8)
+#[inline]
+#[target_feature(enable = "gfni,avx")]
+#[cfg_attr(test, assert_instr(vgf2p8affineqb, b = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm256_gf2p8affine_epi64_epi8(x: __m256i, a: __m256i, b: i32) -> __m256i {
+    let x = x.as_i8x32();
+    let a = a.as_i8x32();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vgf2p8affineqb_256(x, a, $imm8)
+        };
+    }
+    transmute(constify_imm8!(b, call))
+}
+
+/// Performs an affine transformation on the packed bytes in `x`. That is,
+/// computes `a * x + b` over the Galois field GF(2^8) for each packed byte,
+/// with `a` being an 8x8 bit matrix taken from the corresponding 64-bit element
+/// of `a` and `b` being an 8-bit constant vector. Stores the results in `dst`
+/// using writemask `k` (elements are copied from `src` when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_gf2p8affine_epi64_epi8)
+#[inline]
+#[target_feature(enable = "gfni,avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vgf2p8affineqb, b = 0))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm256_mask_gf2p8affine_epi64_epi8(
+    src: __m256i,
+    k: __mmask32,
+    x: __m256i,
+    a: __m256i,
+    b: i32,
+) -> __m256i {
+    let x = x.as_i8x32();
+    let a = a.as_i8x32();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vgf2p8affineqb_256(x, a, $imm8)
+        };
+    }
+    let r = constify_imm8!(b, call);
+    transmute(simd_select_bitmask(k, r, src.as_i8x32()))
+}
+
+/// Performs an affine transformation on the packed bytes in `x`. That is,
+/// computes `a * x + b` over the Galois field GF(2^8) for each packed byte,
+/// with `a` being an 8x8 bit matrix taken from the corresponding 64-bit element
+/// of `a` and `b` being an 8-bit constant vector. Stores the results in `dst`
+/// using zeromask `k` (elements are zeroed out when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_gf2p8affine_epi64_epi8)
+#[inline]
+#[target_feature(enable = "gfni,avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vgf2p8affineqb, b = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm256_maskz_gf2p8affine_epi64_epi8(
+    k: __mmask32,
+    x: __m256i,
+    a: __m256i,
+    b: i32,
+) -> __m256i {
+    let x = x.as_i8x32();
+    let a = a.as_i8x32();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vgf2p8affineqb_256(x, a, $imm8)
+        };
+    }
+    let r = constify_imm8!(b, call);
+    let zero = _mm256_setzero_si256().as_i8x32();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Performs an affine transformation on the packed bytes in `x`. That is,
+/// computes `a * x + b` over the Galois field GF(2^8) for each packed byte,
+/// with `a` being an 8x8 bit matrix taken from the corresponding 64-bit element
+/// of `a` and `b` being an 8-bit constant vector.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_gf2p8affine_epi64_epi8)
+#[inline]
+#[target_feature(enable = "gfni")]
+#[cfg_attr(test, assert_instr(gf2p8affineqb, b = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_gf2p8affine_epi64_epi8(x: __m128i, a: __m128i, b: i32) -> __m128i {
+    let x = x.as_i8x16();
+    let a = a.as_i8x16();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vgf2p8affineqb_128(x, a, $imm8)
+        };
+    }
+    transmute(constify_imm8!(b, call))
+}
+
+/// Performs an affine transformation on the packed bytes in `x`. That is,
+/// computes `a * x + b` over the Galois field GF(2^8) for each packed byte,
+/// with `a` being an 8x8 bit matrix taken from the corresponding 64-bit element
+/// of `a` and `b` being an 8-bit constant vector. Stores the results in `dst`
+/// using writemask `k` (elements are copied from `src` when the corresponding
+/// mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_gf2p8affine_epi64_epi8)
+#[inline]
+#[target_feature(enable = "gfni,avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vgf2p8affineqb, b = 0))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm_mask_gf2p8affine_epi64_epi8(
+    src: __m128i,
+    k: __mmask16,
+    x: __m128i,
+    a: __m128i,
+    b: i32,
+) -> __m128i {
+    let x = x.as_i8x16();
+    let a = a.as_i8x16();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vgf2p8affineqb_128(x, a, $imm8)
+        };
+    }
+    let r = constify_imm8!(b, call);
+    transmute(simd_select_bitmask(k, r, src.as_i8x16()))
+}
+
+/// Performs an affine transformation on the packed bytes in `x`. That is,
+/// computes `a * x + b` over the Galois field GF(2^8) for each packed byte,
+/// with `a` being an 8x8 bit matrix taken from the corresponding 64-bit element
+/// of `a` and `b` being an 8-bit constant vector. Stores the results in `dst`
+/// using zeromask `k` (elements are zeroed out when the corresponding mask bit
+/// is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_gf2p8affine_epi64_epi8)
+#[inline]
+#[target_feature(enable = "gfni,avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vgf2p8affineqb, b = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm_maskz_gf2p8affine_epi64_epi8(
+    k: __mmask16,
+    x: __m128i,
+    a: __m128i,
+    b: i32,
+) -> __m128i {
+    let x = x.as_i8x16();
+    let a = a.as_i8x16();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vgf2p8affineqb_128(x, a, $imm8)
+        };
+    }
+    let r = constify_imm8!(b, call);
+    let zero = _mm_setzero_si128().as_i8x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Performs an affine transformation on the inverted packed bytes in `x`. That
+/// is, computes `a * inv(x) + b` over the Galois field GF(2^8) for each packed
+/// byte, with `a` being an 8x8 bit matrix taken from the corresponding 64-bit
+/// element of `a` and `b` being an 8-bit constant vector. The inverse of a byte
+/// is defined with respect to the reduction polynomial x^8 + x^4 + x^3 + x + 1,
+/// and the inverse of 0 is 0.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_gf2p8affineinv_epi64_epi8)
+#[inline]
+#[target_feature(enable = "gfni,avx512f")]
+#[cfg_attr(test, assert_instr(vgf2p8affineinvqb, b = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_gf2p8affineinv_epi64_epi8(x: __m512i, a: __m512i, b: i32) -> __m512i {
+    let x = x.as_i8x64();
+    let a = a.as_i8x64();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vgf2p8affineinvqb_512(x, a, $imm8)
+        };
+    }
+    transmute(constify_imm8!(b, call))
+}
+
+/// Performs an affine transformation on the inverted packed bytes in `x`. That
+/// is, computes `a * inv(x) + b` over the Galois field GF(2^8) for each packed
+/// byte, with `a` being an 8x8 bit matrix taken from the corresponding 64-bit
+/// element of `a` and `b` being an 8-bit constant vector. The inverse of a byte
+/// is defined with respect to the reduction polynomial x^8 + x^4 + x^3 + x + 1,
+/// and the inverse of 0 is 0. Stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_mask_gf2p8affineinv_epi64_epi8)
+#[inline]
+#[target_feature(enable = "gfni,avx512bw")]
+#[cfg_attr(test, assert_instr(vgf2p8affineinvqb, b = 0))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm512_mask_gf2p8affineinv_epi64_epi8(
+    src: __m512i,
+    k: __mmask64,
+    x: __m512i,
+    a: __m512i,
+    b: i32,
+) -> __m512i {
+    let x = x.as_i8x64();
+    let a = a.as_i8x64();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vgf2p8affineinvqb_512(x, a, $imm8)
+        };
+    }
+    let r = constify_imm8!(b, call);
+    transmute(simd_select_bitmask(k, r, src.as_i8x64()))
+}
+
+/// Performs an affine transformation on the inverted packed bytes in `x`. That
+/// is, computes `a * inv(x) + b` over the Galois field GF(2^8) for each packed
+/// byte, with `a` being an 8x8 bit matrix taken from the corresponding 64-bit
+/// element of `a` and `b` being an 8-bit constant vector. The inverse of a byte
+/// is defined with respect to the reduction polynomial x^8 + x^4 + x^3 + x + 1,
+/// and the inverse of 0 is 0. Stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_maskz_gf2p8affineinv_epi64_epi8)
+#[inline]
+#[target_feature(enable = "gfni,avx512bw")]
+#[cfg_attr(test, assert_instr(vgf2p8affineinvqb, b = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm512_maskz_gf2p8affineinv_epi64_epi8(
+    k: __mmask64,
+    x: __m512i,
+    a: __m512i,
+    b: i32,
+) -> __m512i {
+    let x = x.as_i8x64();
+    let a = a.as_i8x64();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vgf2p8affineinvqb_512(x, a, $imm8)
+        };
+    }
+    let r = constify_imm8!(b, call);
+    let zero = _mm512_setzero_si512().as_i8x64();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Performs an affine transformation on the inverted packed bytes in `x`. That
+/// is, computes `a * inv(x) + b` over the Galois field GF(2^8) for each packed
+/// byte, with `a` being an 8x8 bit matrix taken from the corresponding 64-bit
+/// element of `a` and `b` being an 8-bit constant vector. The inverse of a byte
+/// is defined with respect to the reduction polynomial x^8 + x^4 + x^3 + x + 1,
+/// and the inverse of 0 is 0.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_gf2p8affineinv_epi64_epi8)
+#[inline]
+#[target_feature(enable = "gfni,avx")]
+#[cfg_attr(test, assert_instr(vgf2p8affineinvqb, b = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm256_gf2p8affineinv_epi64_epi8(x: __m256i, a: __m256i, b: i32) -> __m256i {
+    let x = x.as_i8x32();
+    let a = a.as_i8x32();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vgf2p8affineinvqb_256(x, a, $imm8)
+        };
+    }
+    transmute(constify_imm8!(b, call))
+}
+
+/// Performs an affine transformation on the inverted packed bytes in `x`. That
+/// is, computes `a * inv(x) + b` over the Galois field GF(2^8) for each packed
+/// byte, with `a` being an 8x8 bit matrix taken from the corresponding 64-bit
+/// element of `a` and `b` being an 8-bit constant vector. The inverse of a byte
+/// is defined with respect to the reduction polynomial x^8 + x^4 + x^3 + x + 1,
+/// and the inverse of 0 is 0. Stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_mask_gf2p8affineinv_epi64_epi8)
+#[inline]
+#[target_feature(enable = "gfni,avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vgf2p8affineinvqb, b = 0))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm256_mask_gf2p8affineinv_epi64_epi8(
+    src: __m256i,
+    k: __mmask32,
+    x: __m256i,
+    a: __m256i,
+    b: i32,
+) -> __m256i {
+    let x = x.as_i8x32();
+    let a = a.as_i8x32();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vgf2p8affineinvqb_256(x, a, $imm8)
+        };
+    }
+    let r = constify_imm8!(b, call);
+    transmute(simd_select_bitmask(k, r, src.as_i8x32()))
+}
+
+/// Performs an affine transformation on the inverted packed bytes in `x`. That
+/// is, computes `a * inv(x) + b` over the Galois field GF(2^8) for each packed
+/// byte, with `a` being an 8x8 bit matrix taken from the corresponding 64-bit
+/// element of `a` and `b` being an 8-bit constant vector. The inverse of a byte
+/// is defined with respect to the reduction polynomial x^8 + x^4 + x^3 + x + 1,
+/// and the inverse of 0 is 0. Stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_maskz_gf2p8affineinv_epi64_epi8)
+#[inline]
+#[target_feature(enable = "gfni,avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vgf2p8affineinvqb, b = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm256_maskz_gf2p8affineinv_epi64_epi8(
+    k: __mmask32,
+    x: __m256i,
+    a: __m256i,
+    b: i32,
+) -> __m256i {
+    let x = x.as_i8x32();
+    let a = a.as_i8x32();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vgf2p8affineinvqb_256(x, a, $imm8)
+        };
+    }
+    let r = constify_imm8!(b, call);
+    let zero = _mm256_setzero_si256().as_i8x32();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Performs an affine transformation on the inverted packed bytes in `x`. That
+/// is, computes `a * inv(x) + b` over the Galois field GF(2^8) for each packed
+/// byte, with `a` being an 8x8 bit matrix taken from the corresponding 64-bit
+/// element of `a` and `b` being an 8-bit constant vector. The inverse of a byte
+/// is defined with respect to the reduction polynomial x^8 + x^4 + x^3 + x + 1,
+/// and the inverse of 0 is 0.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_gf2p8affineinv_epi64_epi8)
+#[inline]
+#[target_feature(enable = "gfni")]
+#[cfg_attr(test, assert_instr(gf2p8affineinvqb, b = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_gf2p8affineinv_epi64_epi8(x: __m128i, a: __m128i, b: i32) -> __m128i {
+    let x = x.as_i8x16();
+    let a = a.as_i8x16();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vgf2p8affineinvqb_128(x, a, $imm8)
+        };
+    }
+    transmute(constify_imm8!(b, call))
+}
+
+/// Performs an affine transformation on the inverted packed bytes in `x`. That
+/// is, computes `a * inv(x) + b` over the Galois field GF(2^8) for each packed
+/// byte, with `a` being an 8x8 bit matrix taken from the corresponding 64-bit
+/// element of `a` and `b` being an 8-bit constant vector. The inverse of a byte
+/// is defined with respect to the reduction polynomial x^8 + x^4 + x^3 + x + 1,
+/// and the inverse of 0 is 0. Stores the results in `dst` using writemask `k`
+/// (elements are copied from `src` when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_mask_gf2p8affineinv_epi64_epi8)
+#[inline]
+#[target_feature(enable = "gfni,avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vgf2p8affineinvqb, b = 0))]
+#[rustc_args_required_const(4)]
+pub unsafe fn _mm_mask_gf2p8affineinv_epi64_epi8(
+    src: __m128i,
+    k: __mmask16,
+    x: __m128i,
+    a: __m128i,
+    b: i32,
+) -> __m128i {
+    let x = x.as_i8x16();
+    let a = a.as_i8x16();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vgf2p8affineinvqb_128(x, a, $imm8)
+        };
+    }
+    let r = constify_imm8!(b, call);
+    transmute(simd_select_bitmask(k, r, src.as_i8x16()))
+}
+
+/// Performs an affine transformation on the inverted packed bytes in `x`. That
+/// is, computes `a * inv(x) + b` over the Galois field GF(2^8) for each packed
+/// byte, with `a` being an 8x8 bit matrix taken from the corresponding 64-bit
+/// element of `a` and `b` being an 8-bit constant vector. The inverse of a byte
+/// is defined with respect to the reduction polynomial x^8 + x^4 + x^3 + x + 1,
+/// and the inverse of 0 is 0. Stores the results in `dst` using zeromask `k`
+/// (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm_maskz_gf2p8affineinv_epi64_epi8)
+#[inline]
+#[target_feature(enable = "gfni,avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vgf2p8affineinvqb, b = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm_maskz_gf2p8affineinv_epi64_epi8(
+    k: __mmask16,
+    x: __m128i,
+    a: __m128i,
+    b: i32,
+) -> __m128i {
+    let x = x.as_i8x16();
+    let a = a.as_i8x16();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vgf2p8affineinvqb_128(x, a, $imm8)
+        };
+    }
+    let r = constify_imm8!(b, call);
+    let zero = _mm_setzero_si128().as_i8x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.vgf2p8mulb.512"]
+    fn vgf2p8mulb_512(a: i8x64, b: i8x64) -> i8x64;
+    #[link_name = "llvm.x86.vgf2p8mulb.256"]
+    fn vgf2p8mulb_256(a: i8x32, b: i8x32) -> i8x32;
+    #[link_name = "llvm.x86.vgf2p8mulb.128"]
+    fn vgf2p8mulb_128(a: i8x16, b: i8x16) -> i8x16;
+    #[link_name = "llvm.x86.vgf2p8affineqb.512"]
+    fn vgf2p8affineqb_512(x: i8x64, a: i8x64, b: u8) -> i8x64;
+    #[link_name = "llvm.x86.vgf2p8affineqb.256"]
+    fn vgf2p8affineqb_256(x: i8x32, a: i8x32, b: u8) -> i8x32;
+    #[link_name = "llvm.x86.vgf2p8affineqb.128"]
+    fn vgf2p8affineqb_128(x: i8x16, a: i8x16, b: u8) -> i8x16;
+    #[link_name = "llvm.x86.vgf2p8affineinvqb.512"]
+    fn vgf2p8affineinvqb_512(x: i8x64, a: i8x64, b: u8) -> i8x64;
+    #[link_name = "llvm.x86.vgf2p8affineinvqb.256"]
+    fn vgf2p8affineinvqb_256(x: i8x32, a: i8x32, b: u8) -> i8x32;
+    #[link_name = "llvm.x86.vgf2p8affineinvqb.128"]
+    fn vgf2p8affineinvqb_128(x: i8x16, a: i8x16, b: u8) -> i8x16;
+}
+
+#[cfg(test)]
+mod tests {
+    // The matrices in the tests below are just bit patterns; they should not
+    // be interpreted as signed integers.
+    #![allow(overflowing_literals)]
+
+    use crate::core_arch::x86::*;
+    use stdsimd_test::simd_test;
+
+    #[simd_test(enable = "gfni,avx512f")]
+    unsafe fn test_mm512_gf2p8mul_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            38, 1, -36, -73,
+            -110, 109, 72, 35,
+            -2, -39, -76, -113,
+            106, 69, 32, -5,
+            -42, -79, -116, 103,
+            66, 29, -8, -45,
+            -82, -119, 100, 63,
+            26, -11, -48, -85,
+            -122, 97, 60, 23,
+            -14, -51, -88, -125,
+            94, 57, 20, -17,
+            -54, -91, std::i8::MIN, 91,
+            54, 17, -20, -57,
+            -94, 125, 88, 51,
+            14, -23, -60, -97,
+            122, 85, 48, 11,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            45, -46, 119, 28, -63, 102, 11, -80, 85, -6, -97, 68, -23, -114, 51, -40,
+            125, 34, -57, 108, 17, -74, 91, 0, -91, 74, -17, -108, 57, -34, -125, 40,
+            -51, 114, 23, -68, 97, 6, -85, 80, -11, -102, 63, -28, -119, 46, -45, 120,
+            29, -62, 103, 12, -79, 86, -5, -96, 69, -22, -113, 52, -39, 126, 35, -56,
+        );
+        let r = _mm512_gf2p8mul_epi8(a, b);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            57, -46, 52, -96, 106, 100, -82, 58, -83, 101, -100, -86, -84, 96, 58, 71,
+            18, -88, 41, 110, 14, 10, -17, 0, 76, 22, -90, -109, 28, 51, 100, -109,
+            -107, 47, 89, 44, 73, -104, 85, 101, 33, -125, 33, 27, 96, -53, -108, -115,
+            8, 86, 37, 83, -63, -10, 20, -46, -101, 60, -15, 31, -122, -114, 10, 89,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "gfni,avx512bw")]
+    unsafe fn test_mm512_mask_gf2p8mul_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            38, 1, -36, -73,
+            -110, 109, 72, 35,
+            -2, -39, -76, -113,
+            106, 69, 32, -5,
+            -42, -79, -116, 103,
+            66, 29, -8, -45,
+            -82, -119, 100, 63,
+            26, -11, -48, -85,
+            -122, 97, 60, 23,
+            -14, -51, -88, -125,
+            94, 57, 20, -17,
+            -54, -91, std::i8::MIN, 91,
+            54, 17, -20, -57,
+            -94, 125, 88, 51,
+            14, -23, -60, -97,
+            122, 85, 48, 11,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            45, -46, 119, 28, -63, 102, 11, -80, 85, -6, -97, 68, -23, -114, 51, -40,
+            125, 34, -57, 108, 17, -74, 91, 0, -91, 74, -17, -108, 57, -34, -125, 40,
+            -51, 114, 23, -68, 97, 6, -85, 80, -11, -102, 63, -28, -119, 46, -45, 120,
+            29, -62, 103, 12, -79, 86, -5, -96, 69, -22, -113, 52, -39, 126, 35, -56,
+        );
+        let r = _mm512_mask_gf2p8mul_epi8(a, 0, a, b);
+        assert_eq_m512i(r, a);
+        let r = _mm512_mask_gf2p8mul_epi8(
+            a,
+            0b10011001_01010101_00110101_11001010_01101001_10010110_11001010_00110101,
+            a,
+            b,
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            57, 1, -36, -96, 106, 109, 72, 58, -2, 101, -76, -86, 106, 96, 32, 71,
+            -42, -79, 41, 110, 66, 10, -8, 0, 76, 22, 100, 63, 28, -11, 100, -85,
+            -122, 47, 89, 23, 73, -51, -88, 101, 33, 57, 20, 27, -54, -53, -108, 91,
+            8, 86, -20, -57, -63, 125, 20, 51, 14, -23, -15, 31, 122, -114, 48, 89,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "gfni,avx512bw")]
+    unsafe fn test_mm512_maskz_gf2p8mul_epi8() {
+        #[rustfmt::skip]
+        let a = _mm512_set_epi8(
+            38, 1, -36, -73,
+            -110, 109, 72, 35,
+            -2, -39, -76, -113,
+            106, 69, 32, -5,
+            -42, -79, -116, 103,
+            66, 29, -8, -45,
+            -82, -119, 100, 63,
+            26, -11, -48, -85,
+            -122, 97, 60, 23,
+            -14, -51, -88, -125,
+            94, 57, 20, -17,
+            -54, -91, std::i8::MIN, 91,
+            54, 17, -20, -57,
+            -94, 125, 88, 51,
+            14, -23, -60, -97,
+            122, 85, 48, 11,
+        );
+        #[rustfmt::skip]
+        let b = _mm512_set_epi8(
+            45, -46, 119, 28, -63, 102, 11, -80, 85, -6, -97, 68, -23, -114, 51, -40,
+            125, 34, -57, 108, 17, -74, 91, 0, -91, 74, -17, -108, 57, -34, -125, 40,
+            -51, 114, 23, -68, 97, 6, -85, 80, -11, -102, 63, -28, -119, 46, -45, 120,
+            29, -62, 103, 12, -79, 86, -5, -96, 69, -22, -113, 52, -39, 126, 35, -56,
+        );
+        let r = _mm512_maskz_gf2p8mul_epi8(0, a, b);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_gf2p8mul_epi8(
+            0b10011001_01010101_00110101_11001010_01101001_10010110_11001010_00110101,
+            a,
+            b,
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            57, 0, 0, -96, 106, 0, 0, 58, 0, 101, 0, -86, 0, 96, 0, 71,
+            0, 0, 41, 110, 0, 10, 0, 0, 76, 22, 0, 0, 28, 0, 100, 0,
+            0, 47, 89, 0, 73, 0, 0, 101, 33, 0, 0, 27, 0, -53, -108, 0,
+            8, 86, 0, 0, -63, 0, 20, 0, 0, 0, -15, 31, 0, -114, 0, 89,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "gfni,avx512f")]
+    unsafe fn test_mm512_gf2p8affine_epi64_epi8() {
+        #[rustfmt::skip]
+        let x = _mm512_set_epi8(
+            38, 1, -36, -73,
+            -110, 109, 72, 35,
+            -2, -39, -76, -113,
+            106, 69, 32, -5,
+            -42, -79, -116, 103,
+            66, 29, -8, -45,
+            -82, -119, 100, 63,
+            26, -11, -48, -85,
+            -122, 97, 60, 23,
+            -14, -51, -88, -125,
+            94, 57, 20, -17,
+            -54, -91, std::i8::MIN, 91,
+            54, 17, -20, -57,
+            -94, 125, 88, 51,
+            14, -23, -60, -97,
+            122, 85, 48, 11,
+        );
+        let identity = _mm512_set1_epi64(0x0102_0408_1020_4080);
+        let r = _mm512_gf2p8affine_epi64_epi8(x, identity, 0);
+        assert_eq_m512i(r, x);
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi64(
+            0x8040_2010_0804_0201, 0x3ca5_0ff0_9966_c318,
+            0x8040_2010_0804_0201, 0x3ca5_0ff0_9966_c318,
+            0x8040_2010_0804_0201, 0x3ca5_0ff0_9966_c318,
+            0x8040_2010_0804_0201, 0x3ca5_0ff0_9966_c318,
+        );
+        let r = _mm512_gf2p8affine_epi64_epi8(x, a, 0x5a);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            50, 12, 67, -89,
+            -3, -3, -89, 67,
+            37, -63, 119, -85,
+            12, -8, 94, -123,
+            -78, -28, -78, 12,
+            86, 39, 79, -61,
+            47, -53, 124, -90,
+            2, -11, 81, -113,
+            67, 79, 90, -42,
+            -66, -116, -66, 50,
+            32, -58, 114, -83,
+            9, -1, 91, std::i8::MIN,
+            -85, -107, -15, 125,
+            79, 100, 62, -38,
+            42, -51, 121, -93,
+            4, -16, 86, -118,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "gfni,avx512bw")]
+    unsafe fn test_mm512_mask_gf2p8affine_epi64_epi8() {
+        #[rustfmt::skip]
+        let x = _mm512_set_epi8(
+            38, 1, -36, -73,
+            -110, 109, 72, 35,
+            -2, -39, -76, -113,
+            106, 69, 32, -5,
+            -42, -79, -116, 103,
+            66, 29, -8, -45,
+            -82, -119, 100, 63,
+            26, -11, -48, -85,
+            -122, 97, 60, 23,
+            -14, -51, -88, -125,
+            94, 57, 20, -17,
+            -54, -91, std::i8::MIN, 91,
+            54, 17, -20, -57,
+            -94, 125, 88, 51,
+            14, -23, -60, -97,
+            122, 85, 48, 11,
+        );
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi64(
+            0x8040_2010_0804_0201, 0x3ca5_0ff0_9966_c318,
+            0x8040_2010_0804_0201, 0x3ca5_0ff0_9966_c318,
+            0x8040_2010_0804_0201, 0x3ca5_0ff0_9966_c318,
+            0x8040_2010_0804_0201, 0x3ca5_0ff0_9966_c318,
+        );
+        let r = _mm512_mask_gf2p8affine_epi64_epi8(x, 0, x, a, 0x5a);
+        assert_eq_m512i(r, x);
+        let r = _mm512_mask_gf2p8affine_epi64_epi8(
+            x,
+            0b10011001_01010101_00110101_11001010_01101001_10010110_11001010_00110101,
+            x,
+            a,
+            0x5a,
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            50, 1, -36, -89, -3, 109, 72, 67, -2, -63, -76, -85, 106, -8, 32, -123,
+            -42, -79, -78, 12, 66, 39, -8, -61, 47, -53, 100, 63, 2, -11, 81, -85,
+            -122, 79, 90, 23, -66, -51, -88, 50, 32, 57, 20, -83, -54, -1, 91, 91,
+            -85, -107, -20, -57, 79, 125, 62, 51, 14, -23, 121, -93, 122, -16, 48, -118,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "gfni,avx512bw")]
+    unsafe fn test_mm512_maskz_gf2p8affine_epi64_epi8() {
+        #[rustfmt::skip]
+        let x = _mm512_set_epi8(
+            38, 1, -36, -73,
+            -110, 109, 72, 35,
+            -2, -39, -76, -113,
+            106, 69, 32, -5,
+            -42, -79, -116, 103,
+            66, 29, -8, -45,
+            -82, -119, 100, 63,
+            26, -11, -48, -85,
+            -122, 97, 60, 23,
+            -14, -51, -88, -125,
+            94, 57, 20, -17,
+            -54, -91, std::i8::MIN, 91,
+            54, 17, -20, -57,
+            -94, 125, 88, 51,
+            14, -23, -60, -97,
+            122, 85, 48, 11,
+        );
+        #[rustfmt::skip]
+        let a = _mm512_setr_epi64(
+            0x8040_2010_0804_0201, 0x3ca5_0ff0_9966_c318,
+            0x8040_2010_0804_0201, 0x3ca5_0ff0_9966_c318,
+            0x8040_2010_0804_0201, 0x3ca5_0ff0_9966_c318,
+            0x8040_2010_0804_0201, 0x3ca5_0ff0_9966_c318,
+        );
+        let r = _mm512_maskz_gf2p8affine_epi64_epi8(0, x, a, 0x5a);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_gf2p8affine_epi64_epi8(
+            0b10011001_01010101_00110101_11001010_01101001_10010110_11001010_00110101,
+            x,
+            a,
+            0x5a,
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            50, 0, 0, -89, -3, 0, 0, 67, 0, -63, 0, -85, 0, -8, 0, -123,
+            0, 0, -78, 12, 0, 39, 0, -61, 47, -53, 0, 0, 2, 0, 81, 0,
+            0, 79, 90, 0, -66, 0, 0, 50, 32, 0, 0, -83, 0, -1, 91, 0,
+            -85, -107, 0, 0, 79, 0, 62, 0, 0, 0, 121, -93, 0, -16, 0, -118,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "gfni,avx512f")]
+    unsafe fn test_mm512_gf2p8affineinv_epi64_epi8() {
+        #[rustfmt::skip]
+        let x = _mm512_set_epi8(
+            38, 1, -36, -73,
+            -110, 109, 72, 35,
+            -2, -39, -76, -113,
+            106, 69, 32, -5,
+            -42, -79, -116, 103,
+            66, 29, -8, -45,
+            -82, -119, 100, 63,
+            26, -11, -48, -85,
+            -122, 97, 60, 23,
+            -14, -51, -88, -125,
+            94, 57, 20, -17,
+            -54, -91, std::i8::MIN, 91,
+            54, 17, -20, -57,
+            -94, 125, 88, 51,
+            14, -23, -60, -97,
+            122, 85, 48, 11,
+        );
+        // The AES S-box is an affine transformation of the inverse.
+        let a = _mm512_set1_epi64(0xf1e3_c78f_1f3e_7cf8);
+        let r = _mm512_gf2p8affineinv_epi64_epi8(x, a, 0x63);
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            -9, 124, -122, -87, 79, 60, 82, 38, -69, 53, -115, 115, 2, 110, -73, 15,
+            -10, -56, 100, -123, 44, -92, 65, 102, -28, -89, 67, 117, -94, -26, 112, 98,
+            68, -17, -21, -16, -119, -67, -62, -20, 88, 18, -6, -33, 116, 6, -51, 57,
+            5, -126, -50, -58, 58, -1, 106, -61, -85, 30, 28, -37, -38, -4, 4, 43,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "gfni,avx512bw")]
+    unsafe fn test_mm512_mask_gf2p8affineinv_epi64_epi8() {
+        #[rustfmt::skip]
+        let x = _mm512_set_epi8(
+            38, 1, -36, -73,
+            -110, 109, 72, 35,
+            -2, -39, -76, -113,
+            106, 69, 32, -5,
+            -42, -79, -116, 103,
+            66, 29, -8, -45,
+            -82, -119, 100, 63,
+            26, -11, -48, -85,
+            -122, 97, 60, 23,
+            -14, -51, -88, -125,
+            94, 57, 20, -17,
+            -54, -91, std::i8::MIN, 91,
+            54, 17, -20, -57,
+            -94, 125, 88, 51,
+            14, -23, -60, -97,
+            122, 85, 48, 11,
+        );
+        // The AES S-box is an affine transformation of the inverse.
+        let a = _mm512_set1_epi64(0xf1e3_c78f_1f3e_7cf8);
+        let r = _mm512_mask_gf2p8affineinv_epi64_epi8(x, 0, x, a, 0x63);
+        assert_eq_m512i(r, x);
+        let r = _mm512_mask_gf2p8affineinv_epi64_epi8(
+            x,
+            0b10011001_01010101_00110101_11001010_01101001_10010110_11001010_00110101,
+            x,
+            a,
+            0x63,
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            -9, 1, -36, -87, 79, 109, 72, 38, -2, 53, -76, 115, 106, 110, 32, 15,
+            -42, -79, 100, -123, 66, -92, -8, 102, -28, -89, 100, 63, -94, -11, 112, -85,
+            -122, -17, -21, 23, -119, -51, -88, -20, 88, 57, 20, -33, -54, 6, -51, 91,
+            5, -126, -20, -57, 58, 125, 106, 51, 14, -23, 28, -37, 122, -4, 48, 43,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "gfni,avx512bw")]
+    unsafe fn test_mm512_maskz_gf2p8affineinv_epi64_epi8() {
+        #[rustfmt::skip]
+        let x = _mm512_set_epi8(
+            38, 1, -36, -73,
+            -110, 109, 72, 35,
+            -2, -39, -76, -113,
+            106, 69, 32, -5,
+            -42, -79, -116, 103,
+            66, 29, -8, -45,
+            -82, -119, 100, 63,
+            26, -11, -48, -85,
+            -122, 97, 60, 23,
+            -14, -51, -88, -125,
+            94, 57, 20, -17,
+            -54, -91, std::i8::MIN, 91,
+            54, 17, -20, -57,
+            -94, 125, 88, 51,
+            14, -23, -60, -97,
+            122, 85, 48, 11,
+        );
+        // The AES S-box is an affine transformation of the inverse.
+        let a = _mm512_set1_epi64(0xf1e3_c78f_1f3e_7cf8);
+        let r = _mm512_maskz_gf2p8affineinv_epi64_epi8(0, x, a, 0x63);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+        let r = _mm512_maskz_gf2p8affineinv_epi64_epi8(
+            0b10011001_01010101_00110101_11001010_01101001_10010110_11001010_00110101,
+            x,
+            a,
+            0x63,
+        );
+        #[rustfmt::skip]
+        let e = _mm512_set_epi8(
+            -9, 0, 0, -87, 79, 0, 0, 38, 0, 53, 0, 115, 0, 110, 0, 15,
+            0, 0, 100, -123, 0, -92, 0, 102, -28, -89, 0, 0, -94, 0, 112, 0,
+            0, -17, -21, 0, -119, 0, 0, -20, 88, 0, 0, -33, 0, 6, -51, 0,
+            5, -126, 0, 0, 58, 0, 106, 0, 0, 0, 28, -37, 0, -4, 0, 43,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "gfni,avx")]
+    unsafe fn test_mm256_gf2p8mul_epi8() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            11, 48, 85, 122,
+            -97, -60, -23, 14,
+            51, 88, 125, -94,
+            -57, -20, 17, 54,
+            91, std::i8::MIN, -91, -54,
+            -17, 20, 57, 94,
+            -125, -88, -51, -14,
+            23, 60, 97, -122,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            -56, 35, 126, -39, 52, -113, -22, 69, -96, -5, 86, -79, 12, 103, -62, 29,
+            120, -45, 46, -119, -28, 63, -102, -11, 80, -85, 6, 97, -68, 23, 114, -51,
+        );
+        let r = _mm256_gf2p8mul_epi8(a, b);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            89, 10, -114, -122, 31, -15, 60, -101, -46, 20, -10, -63, 83, 37, 86, 8,
+            -115, -108, -53, 96, 27, 33, -125, 33, 101, 85, -104, 73, 44, 89, 47, -107,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "gfni,avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_gf2p8mul_epi8() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            11, 48, 85, 122,
+            -97, -60, -23, 14,
+            51, 88, 125, -94,
+            -57, -20, 17, 54,
+            91, std::i8::MIN, -91, -54,
+            -17, 20, 57, 94,
+            -125, -88, -51, -14,
+            23, 60, 97, -122,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            -56, 35, 126, -39, 52, -113, -22, 69, -96, -5, 86, -79, 12, 103, -62, 29,
+            120, -45, 46, -119, -28, 63, -102, -11, 80, -85, 6, 97, -68, 23, 114, -51,
+        );
+        let r = _mm256_mask_gf2p8mul_epi8(a, 0, a, b);
+        assert_eq_m256i(r, a);
+        let r = _mm256_mask_gf2p8mul_epi8(a, 0b01101001_10010110_11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            89, 48, -114, 122, 31, -15, -23, 14, 51, 20, 125, -63, -57, -20, 86, 8,
+            91, -108, -53, -54, 27, 20, 57, 33, 101, -88, -51, 73, 23, 89, 47, -122,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "gfni,avx512bw,avx512vl")]
+    unsafe fn test_mm256_maskz_gf2p8mul_epi8() {
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi8(
+            11, 48, 85, 122,
+            -97, -60, -23, 14,
+            51, 88, 125, -94,
+            -57, -20, 17, 54,
+            91, std::i8::MIN, -91, -54,
+            -17, 20, 57, 94,
+            -125, -88, -51, -14,
+            23, 60, 97, -122,
+        );
+        #[rustfmt::skip]
+        let b = _mm256_setr_epi8(
+            -56, 35, 126, -39, 52, -113, -22, 69, -96, -5, 86, -79, 12, 103, -62, 29,
+            120, -45, 46, -119, -28, 63, -102, -11, 80, -85, 6, 97, -68, 23, 114, -51,
+        );
+        let r = _mm256_maskz_gf2p8mul_epi8(0, a, b);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_gf2p8mul_epi8(0b01101001_10010110_11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            89, 0, -114, 0, 31, -15, 0, 0, 0, 20, 0, -63, 0, 0, 86, 8,
+            0, -108, -53, 0, 27, 0, 0, 33, 101, 0, 0, 73, 0, 89, 47, 0,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "gfni,avx")]
+    unsafe fn test_mm256_gf2p8affine_epi64_epi8() {
+        #[rustfmt::skip]
+        let x = _mm256_setr_epi8(
+            11, 48, 85, 122,
+            -97, -60, -23, 14,
+            51, 88, 125, -94,
+            -57, -20, 17, 54,
+            91, std::i8::MIN, -91, -54,
+            -17, 20, 57, 94,
+            -125, -88, -51, -14,
+            23, 60, 97, -122,
+        );
+        let identity = _mm256_set1_epi64x(0x0102_0408_1020_4080);
+        let r = _mm256_gf2p8affine_epi64_epi8(x, identity, 0);
+        assert_eq_m256i(r, x);
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi64x(
+            0x8040_2010_0804_0201, 0x3ca5_0ff0_9966_c318,
+            0x8040_2010_0804_0201, 0x3ca5_0ff0_9966_c318,
+        );
+        let r = _mm256_gf2p8affine_epi64_epi8(x, a, 0x5a);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            -118, 86, -16, 4,
+            -93, 121, -51, 42,
+            -38, 62, 100, 79,
+            125, -15, -107, -85,
+            std::i8::MIN, 91, -1, 9,
+            -83, 114, -58, 32,
+            50, -66, -116, -66,
+            -42, 90, 79, 67,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "gfni,avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_gf2p8affine_epi64_epi8() {
+        #[rustfmt::skip]
+        let x = _mm256_setr_epi8(
+            11, 48, 85, 122,
+            -97, -60, -23, 14,
+            51, 88, 125, -94,
+            -57, -20, 17, 54,
+            91, std::i8::MIN, -91, -54,
+            -17, 20, 57, 94,
+            -125, -88, -51, -14,
+            23, 60, 97, -122,
+        );
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi64x(
+            0x8040_2010_0804_0201, 0x3ca5_0ff0_9966_c318,
+            0x8040_2010_0804_0201, 0x3ca5_0ff0_9966_c318,
+        );
+        let r = _mm256_mask_gf2p8affine_epi64_epi8(x, 0, x, a, 0x5a);
+        assert_eq_m256i(r, x);
+        let r = _mm256_mask_gf2p8affine_epi64_epi8(
+            x,
+            0b01101001_10010110_11001010_00110101,
+            x,
+            a,
+            0x5a,
+        );
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            -118, 48, -16, 122, -93, 121, -23, 14, 51, 62, 125, 79, -57, -20, -107, -85,
+            91, 91, -1, -54, -83, 20, 57, 32, 50, -88, -51, -66, 23, 90, 79, -122,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "gfni,avx512bw,avx512vl")]
+    unsafe fn test_mm256_maskz_gf2p8affine_epi64_epi8() {
+        #[rustfmt::skip]
+        let x = _mm256_setr_epi8(
+            11, 48, 85, 122,
+            -97, -60, -23, 14,
+            51, 88, 125, -94,
+            -57, -20, 17, 54,
+            91, std::i8::MIN, -91, -54,
+            -17, 20, 57, 94,
+            -125, -88, -51, -14,
+            23, 60, 97, -122,
+        );
+        #[rustfmt::skip]
+        let a = _mm256_setr_epi64x(
+            0x8040_2010_0804_0201, 0x3ca5_0ff0_9966_c318,
+            0x8040_2010_0804_0201, 0x3ca5_0ff0_9966_c318,
+        );
+        let r = _mm256_maskz_gf2p8affine_epi64_epi8(0, x, a, 0x5a);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r =
+            _mm256_maskz_gf2p8affine_epi64_epi8(0b01101001_10010110_11001010_00110101, x, a, 0x5a);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            -118, 0, -16, 0, -93, 121, 0, 0, 0, 62, 0, 79, 0, 0, -107, -85,
+            0, 91, -1, 0, -83, 0, 0, 32, 50, 0, 0, -66, 0, 90, 79, 0,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "gfni,avx")]
+    unsafe fn test_mm256_gf2p8affineinv_epi64_epi8() {
+        #[rustfmt::skip]
+        let x = _mm256_setr_epi8(
+            11, 48, 85, 122,
+            -97, -60, -23, 14,
+            51, 88, 125, -94,
+            -57, -20, 17, 54,
+            91, std::i8::MIN, -91, -54,
+            -17, 20, 57, 94,
+            -125, -88, -51, -14,
+            23, 60, 97, -122,
+        );
+        // The AES S-box is an affine transformation of the inverse.
+        let a = _mm256_set1_epi64x(0xf1e3_c78f_1f3e_7cf8);
+        let r = _mm256_gf2p8affineinv_epi64_epi8(x, a, 0x63);
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            43, 4, -4, -38, -37, 28, 30, -85, -61, 106, -1, 58, -58, -50, -126, 5,
+            57, -51, 6, 116, -33, -6, 18, 88, -20, -62, -67, -119, -16, -21, -17, 68,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "gfni,avx512bw,avx512vl")]
+    unsafe fn test_mm256_mask_gf2p8affineinv_epi64_epi8() {
+        #[rustfmt::skip]
+        let x = _mm256_setr_epi8(
+            11, 48, 85, 122,
+            -97, -60, -23, 14,
+            51, 88, 125, -94,
+            -57, -20, 17, 54,
+            91, std::i8::MIN, -91, -54,
+            -17, 20, 57, 94,
+            -125, -88, -51, -14,
+            23, 60, 97, -122,
+        );
+        // The AES S-box is an affine transformation of the inverse.
+        let a = _mm256_set1_epi64x(0xf1e3_c78f_1f3e_7cf8);
+        let r = _mm256_mask_gf2p8affineinv_epi64_epi8(x, 0, x, a, 0x63);
+        assert_eq_m256i(r, x);
+        let r = _mm256_mask_gf2p8affineinv_epi64_epi8(
+            x,
+            0b01101001_10010110_11001010_00110101,
+            x,
+            a,
+            0x63,
+        );
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            43, 48, -4, 122, -37, 28, -23, 14, 51, 106, 125, 58, -57, -20, -126, 5,
+            91, -51, 6, -54, -33, 20, 57, 88, -20, -88, -51, -119, 23, -21, -17, -122,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "gfni,avx512bw,avx512vl")]
+    unsafe fn test_mm256_maskz_gf2p8affineinv_epi64_epi8() {
+        #[rustfmt::skip]
+        let x = _mm256_setr_epi8(
+            11, 48, 85, 122,
+            -97, -60, -23, 14,
+            51, 88, 125, -94,
+            -57, -20, 17, 54,
+            91, std::i8::MIN, -91, -54,
+            -17, 20, 57, 94,
+            -125, -88, -51, -14,
+            23, 60, 97, -122,
+        );
+        // The AES S-box is an affine transformation of the inverse.
+        let a = _mm256_set1_epi64x(0xf1e3_c78f_1f3e_7cf8);
+        let r = _mm256_maskz_gf2p8affineinv_epi64_epi8(0, x, a, 0x63);
+        assert_eq_m256i(r, _mm256_setzero_si256());
+        let r = _mm256_maskz_gf2p8affineinv_epi64_epi8(
+            0b01101001_10010110_11001010_00110101,
+            x,
+            a,
+            0x63,
+        );
+        #[rustfmt::skip]
+        let e = _mm256_setr_epi8(
+            43, 0, -4, 0, -37, 28, 0, 0, 0, 106, 0, 58, 0, 0, -126, 5,
+            0, -51, 6, 0, -33, 0, 0, 88, -20, 0, 0, -119, 0, -21, -17, 0,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "gfni")]
+    unsafe fn test_mm_gf2p8mul_epi8() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            11, 48, 85, 122, -97, -60, -23, 14,
+            51, 88, 125, -94, -57, -20, 17, 54,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            -56, 35, 126, -39, 52, -113, -22, 69,
+            -96, -5, 86, -79, 12, 103, -62, 29,
+        );
+        let r = _mm_gf2p8mul_epi8(a, b);
+        #[rustfmt::skip]
+        let e = _mm_setr_epi8(
+            89, 10, -114, -122, 31, -15, 60, -101,
+            -46, 20, -10, -63, 83, 37, 86, 8,
+        );
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "gfni,avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_gf2p8mul_epi8() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            11, 48, 85, 122, -97, -60, -23, 14,
+            51, 88, 125, -94, -57, -20, 17, 54,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            -56, 35, 126, -39, 52, -113, -22, 69,
+            -96, -5, 86, -79, 12, 103, -62, 29,
+        );
+        let r = _mm_mask_gf2p8mul_epi8(a, 0, a, b);
+        assert_eq_m128i(r, a);
+        let r = _mm_mask_gf2p8mul_epi8(a, 0b11001010_00110101, a, b);
+        #[rustfmt::skip]
+        let e = _mm_setr_epi8(
+            89, 48, -114, 122, 31, -15, -23, 14,
+            51, 20, 125, -63, -57, -20, 86, 8,
+        );
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "gfni,avx512bw,avx512vl")]
+    unsafe fn test_mm_maskz_gf2p8mul_epi8() {
+        #[rustfmt::skip]
+        let a = _mm_setr_epi8(
+            11, 48, 85, 122, -97, -60, -23, 14,
+            51, 88, 125, -94, -57, -20, 17, 54,
+        );
+        #[rustfmt::skip]
+        let b = _mm_setr_epi8(
+            -56, 35, 126, -39, 52, -113, -22, 69,
+            -96, -5, 86, -79, 12, 103, -62, 29,
+        );
+        let r = _mm_maskz_gf2p8mul_epi8(0, a, b);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_gf2p8mul_epi8(0b11001010_00110101, a, b);
+        let e = _mm_setr_epi8(89, 0, -114, 0, 31, -15, 0, 0, 0, 20, 0, -63, 0, 0, 86, 8);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "gfni")]
+    unsafe fn test_mm_gf2p8affine_epi64_epi8() {
+        #[rustfmt::skip]
+        let x = _mm_setr_epi8(
+            11, 48, 85, 122, -97, -60, -23, 14,
+            51, 88, 125, -94, -57, -20, 17, 54,
+        );
+        let identity = _mm_set1_epi64x(0x0102_0408_1020_4080);
+        let r = _mm_gf2p8affine_epi64_epi8(x, identity, 0);
+        assert_eq_m128i(r, x);
+        let a = _mm_setr_epi64x(0x8040_2010_0804_0201, 0x3ca5_0ff0_9966_c318);
+        let r = _mm_gf2p8affine_epi64_epi8(x, a, 0x5a);
+        #[rustfmt::skip]
+        let e = _mm_setr_epi8(
+            -118, 86, -16, 4, -93, 121, -51, 42,
+            -38, 62, 100, 79, 125, -15, -107, -85,
+        );
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "gfni,avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_gf2p8affine_epi64_epi8() {
+        #[rustfmt::skip]
+        let x = _mm_setr_epi8(
+            11, 48, 85, 122, -97, -60, -23, 14,
+            51, 88, 125, -94, -57, -20, 17, 54,
+        );
+        let a = _mm_setr_epi64x(0x8040_2010_0804_0201, 0x3ca5_0ff0_9966_c318);
+        let r = _mm_mask_gf2p8affine_epi64_epi8(x, 0, x, a, 0x5a);
+        assert_eq_m128i(r, x);
+        let r = _mm_mask_gf2p8affine_epi64_epi8(x, 0b11001010_00110101, x, a, 0x5a);
+        #[rustfmt::skip]
+        let e = _mm_setr_epi8(
+            -118, 48, -16, 122, -93, 121, -23, 14,
+            51, 62, 125, 79, -57, -20, -107, -85,
+        );
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "gfni,avx512bw,avx512vl")]
+    unsafe fn test_mm_maskz_gf2p8affine_epi64_epi8() {
+        #[rustfmt::skip]
+        let x = _mm_setr_epi8(
+            11, 48, 85, 122, -97, -60, -23, 14,
+            51, 88, 125, -94, -57, -20, 17, 54,
+        );
+        let a = _mm_setr_epi64x(0x8040_2010_0804_0201, 0x3ca5_0ff0_9966_c318);
+        let r = _mm_maskz_gf2p8affine_epi64_epi8(0, x, a, 0x5a);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_gf2p8affine_epi64_epi8(0b11001010_00110101, x, a, 0x5a);
+        #[rustfmt::skip]
+        let e = _mm_setr_epi8(
+            -118, 0, -16, 0, -93, 121, 0, 0,
+            0, 62, 0, 79, 0, 0, -107, -85,
+        );
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "gfni")]
+    unsafe fn test_mm_gf2p8affineinv_epi64_epi8() {
+        #[rustfmt::skip]
+        let x = _mm_setr_epi8(
+            11, 48, 85, 122, -97, -60, -23, 14,
+            51, 88, 125, -94, -57, -20, 17, 54,
+        );
+        // The AES S-box is an affine transformation of the inverse.
+        let a = _mm_set1_epi64x(0xf1e3_c78f_1f3e_7cf8);
+        let r = _mm_gf2p8affineinv_epi64_epi8(x, a, 0x63);
+        #[rustfmt::skip]
+        let e = _mm_setr_epi8(
+            43, 4, -4, -38, -37, 28, 30, -85,
+            -61, 106, -1, 58, -58, -50, -126, 5,
+        );
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "gfni,avx512bw,avx512vl")]
+    unsafe fn test_mm_mask_gf2p8affineinv_epi64_epi8() {
+        #[rustfmt::skip]
+        let x = _mm_setr_epi8(
+            11, 48, 85, 122, -97, -60, -23, 14,
+            51, 88, 125, -94, -57, -20, 17, 54,
+        );
+        // The AES S-box is an affine transformation of the inverse.
+        let a = _mm_set1_epi64x(0xf1e3_c78f_1f3e_7cf8);
+        let r = _mm_mask_gf2p8affineinv_epi64_epi8(x, 0, x, a, 0x63);
+        assert_eq_m128i(r, x);
+        let r = _mm_mask_gf2p8affineinv_epi64_epi8(x, 0b11001010_00110101, x, a, 0x63);
+        #[rustfmt::skip]
+        let e = _mm_setr_epi8(
+            43, 48, -4, 122, -37, 28, -23, 14,
+            51, 106, 125, 58, -57, -20, -126, 5,
+        );
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "gfni,avx512bw,avx512vl")]
+    unsafe fn test_mm_maskz_gf2p8affineinv_epi64_epi8() {
+        #[rustfmt::skip]
+        let x = _mm_setr_epi8(
+            11, 48, 85, 122, -97, -60, -23, 14,
+            51, 88, 125, -94, -57, -20, 17, 54,
+        );
+        // The AES S-box is an affine transformation of the inverse.
+        let a = _mm_set1_epi64x(0xf1e3_c78f_1f3e_7cf8);
+        let r = _mm_maskz_gf2p8affineinv_epi64_epi8(0, x, a, 0x63);
+        assert_eq_m128i(r, _mm_setzero_si128());
+        let r = _mm_maskz_gf2p8affineinv_epi64_epi8(0b11001010_00110101, x, a, 0x63);
+        let e = _mm_setr_epi8(43, 0, -4, 0, -37, 28, 0, 0, 0, 106, 0, 58, 0, 0, -126, 5);
+        assert_eq_m128i(r, e);
+    }
+}
@@ -340,11 +340,103 @@ types! {
     /// suffixed with "pd" (or otherwise contain "pd"). Not to be confused with
     /// "ps" which is used for `__m512`.
     pub struct __m512d(f64, f64, f64, f64, f64, f64, f64, f64);
+
+    /// 128-bit wide set of eight BF16 values, x86-specific
+    ///
+    /// This type is the same as the `__m128bh` type defined by Intel,
+    /// representing a 128-bit SIMD register which internally is consisted of
+    /// eight packed BF16 ("brain floating point") instances. Usage of this
+    /// type typically corresponds to the `avx512bf16` target feature.
+    ///
+    /// Rust has no native BF16 type, so each element is stored as the raw
+    /// `u16` bit pattern of the BF16 value: the upper 16 bits of the
+    /// corresponding `f32`.
+    pub struct __m128bh(u16, u16, u16, u16, u16, u16, u16, u16);
+
+    /// 256-bit wide set of sixteen BF16 values, x86-specific
+    ///
+    /// This type is the same as the `__m256bh` type defined by Intel,
+    /// representing a 256-bit SIMD register which internally is consisted of
+    /// sixteen packed BF16 instances, stored as `u16` bit patterns like
+    /// `__m128bh`.
+    pub struct __m256bh(
+        u16, u16, u16, u16, u16, u16, u16, u16,
+        u16, u16, u16, u16, u16, u16, u16, u16,
+    );
+
+    /// 512-bit wide set of thirty-two BF16 values, x86-specific
+    ///
+    /// This type is the same as the `__m512bh` type defined by Intel,
+    /// representing a 512-bit SIMD register which internally is consisted of
+    /// thirty-two packed BF16 instances, stored as `u16` bit patterns like
+    /// `__m128bh`.
+    pub struct __m512bh(
+        u16, u16, u16, u16, u16, u16, u16, u16,
+        u16, u16, u16, u16, u16, u16, u16, u16,
+        u16, u16, u16, u16, u16, u16, u16, u16,
+        u16, u16, u16, u16, u16, u16, u16, u16,
+    );
+
+    /// 128-bit wide set of eight half-precision values, x86-specific
+    ///
+    /// This type is the same as the `__m128h` type defined by Intel,
+    /// representing a 128-bit SIMD register which internally is consisted of
+    /// eight packed IEEE 754 half-precision (16-bit) floating-point instances.
+    /// Usage of this type typically corresponds to the `avx512fp16` target
+    /// feature.
+    ///
+    /// Rust has no native half-precision type, so each element is stored as
+    /// the raw `u16` bit pattern of the value, like the scalar `__f16`.
+    pub struct __m128h(u16, u16, u16, u16, u16, u16, u16, u16);
+
+    /// 256-bit wide set of sixteen half-precision values, x86-specific
+    ///
+    /// This type is the same as the `__m256h` type defined by Intel,
+    /// representing a 256-bit SIMD register which internally is consisted of
+    /// sixteen packed half-precision instances, stored as `u16` bit patterns
+    /// like `__m128h`.
+    pub struct __m256h(
+        u16, u16, u16, u16, u16, u16, u16, u16,
+        u16, u16, u16, u16, u16, u16, u16, u16,
+    );
+
+    /// 512-bit wide set of thirty-two half-precision values, x86-specific
+    ///
+    /// This type is the same as the `__m512h` type defined by Intel,
+    /// representing a 512-bit SIMD register which internally is consisted of
+    /// thirty-two packed half-precision instances, stored as `u16` bit
+    /// patterns like `__m128h`.
+    pub struct __m512h(
+        u16, u16, u16, u16, u16, u16, u16, u16,
+        u16, u16, u16, u16, u16, u16, u16, u16,
+        u16, u16, u16, u16, u16, u16, u16, u16,
+        u16, u16, u16, u16, u16, u16, u16, u16,
+    );
 }
 
+/// The `__mmask8` type used in AVX-512 intrinsics, an 8-bit integer
+#[allow(non_camel_case_types)]
+pub type __mmask8 = u8;
+
 /// The `__mmask16` type used in AVX-512 intrinsics, a 16-bit integer
+///
+/// Like Intel's `unsigned short` definition, and the other mask types, it is
+/// unsigned, so that masks with the top bit set can be written as literals.
 #[allow(non_camel_case_types)]
-pub type __mmask16 = i16;
+pub type __mmask16 = u16;
+
+/// The `__mmask32` type used in AVX-512 intrinsics, a 32-bit integer
+#[allow(non_camel_case_types)]
+pub type __mmask32 = u32;
+
+/// The `__mmask64` type used in AVX-512 intrinsics, a 64-bit integer
+#[allow(non_camel_case_types)]
+pub type __mmask64 = u64;
+
+/// The `_Float16` type used in AVX-512 FP16 intrinsics, the raw bit pattern
+/// of an IEEE 754 half-precision (16-bit) floating-point value
+#[allow(non_camel_case_types)]
+pub type __f16 = u16;
 
 #[cfg(test)]
 mod test;
@@ -462,10 +554,45 @@ impl m256iExt for __m256i {
 pub(crate) trait m512iExt: Sized {
     fn as_m512i(self) -> __m512i;
 
+    #[inline]
+    fn as_u32x16(self) -> crate::core_arch::simd::u32x16 {
+        unsafe { transmute(self.as_m512i()) }
+    }
+
+    #[inline]
+    fn as_u8x64(self) -> crate::core_arch::simd::u8x64 {
+        unsafe { transmute(self.as_m512i()) }
+    }
+
+    #[inline]
+    fn as_u16x32(self) -> crate::core_arch::simd::u16x32 {
+        unsafe { transmute(self.as_m512i()) }
+    }
+
+    #[inline]
+    fn as_u64x8(self) -> crate::core_arch::simd::u64x8 {
+        unsafe { transmute(self.as_m512i()) }
+    }
+
+    #[inline]
+    fn as_i8x64(self) -> crate::core_arch::simd::i8x64 {
+        unsafe { transmute(self.as_m512i()) }
+    }
+
+    #[inline]
+    fn as_i16x32(self) -> crate::core_arch::simd::i16x32 {
+        unsafe { transmute(self.as_m512i()) }
+    }
+
     #[inline]
     fn as_i32x16(self) -> crate::core_arch::simd::i32x16 {
         unsafe { transmute(self.as_m512i()) }
     }
+
+    #[inline]
+    fn as_i64x8(self) -> crate::core_arch::simd::i64x8 {
+        unsafe { transmute(self.as_m512i()) }
+    }
 }
 
 impl m512iExt for __m512i {
@@ -508,6 +635,9 @@ mod avx;
 pub use self::avx::*;
 mod avx2;
 pub use self::avx2::*;
+mod f16c;
+pub use self::f16c::*;
+
 mod fma;
 pub use self::fma::*;
 
@@ -535,9 +665,18 @@ pub use self::mmx::*;
 mod pclmulqdq;
 pub use self::pclmulqdq::*;
 
+mod vpclmulqdq;
+pub use self::vpclmulqdq::*;
+
 mod aes;
 pub use self::aes::*;
 
+mod vaes;
+pub use self::vaes::*;
+
+mod gfni;
+pub use self::gfni::*;
+
 mod rdrand;
 pub use self::rdrand::*;
 
@@ -557,11 +696,44 @@ pub unsafe fn ud2() -> ! {
     intrinsics::abort()
 }
 
+mod avx512bf16;
+pub use self::avx512bf16::*;
+
+mod avx512bitalg;
+pub use self::avx512bitalg::*;
+
+mod avx512bw;
+pub use self::avx512bw::*;
+
+mod avx512cd;
+pub use self::avx512cd::*;
+
+mod avx512dq;
+pub use self::avx512dq::*;
+
 mod avx512f;
 pub use self::avx512f::*;
 
+mod avx512fp16;
+pub use self::avx512fp16::*;
+
 mod avx512ifma;
 pub use self::avx512ifma::*;
 
+mod avx512vbmi;
+pub use self::avx512vbmi::*;
+
+mod avx512vbmi2;
+pub use self::avx512vbmi2::*;
+
+mod avx512vnni;
+pub use self::avx512vnni::*;
+
+mod avx512vpopcntdq;
+pub use self::avx512vpopcntdq::*;
+
+mod avxvnni;
+pub use self::avxvnni::*;
+
 mod bt;
 pub use self::bt::*;
@@ -143,3 +143,67 @@ pub unsafe fn assert_eq_m512i(a: __m512i, b: __m512i) {
     }
     assert_eq!(A { a }.b, A { a: b }.b)
 }
+
+pub unsafe fn assert_eq_m512(a: __m512, b: __m512) {
+    union A {
+        a: __m512,
+        b: [f32; 16],
+    }
+    assert_eq!(A { a }.b, A { a: b }.b)
+}
+
+pub unsafe fn assert_eq_m512d(a: __m512d, b: __m512d) {
+    union A {
+        a: __m512d,
+        b: [f64; 8],
+    }
+    assert_eq!(A { a }.b, A { a: b }.b)
+}
+
+pub unsafe fn assert_eq_m128bh(a: __m128bh, b: __m128bh) {
+    union A {
+        a: __m128bh,
+        b: [u16; 8],
+    }
+    assert_eq!(A { a }.b, A { a: b }.b)
+}
+
+pub unsafe fn assert_eq_m256bh(a: __m256bh, b: __m256bh) {
+    union A {
+        a: __m256bh,
+        b: [u16; 16],
+    }
+    assert_eq!(A { a }.b, A { a: b }.b)
+}
+
+pub unsafe fn assert_eq_m512bh(a: __m512bh, b: __m512bh) {
+    union A {
+        a: __m512bh,
+        b: [u16; 32],
+    }
+    assert_eq!(A { a }.b, A { a: b }.b)
+}
+
+pub unsafe fn assert_eq_m128h(a: __m128h, b: __m128h) {
+    union A {
+        a: __m128h,
+        b: [u16; 8],
+    }
+    assert_eq!(A { a }.b, A { a: b }.b)
+}
+
+pub unsafe fn assert_eq_m256h(a: __m256h, b: __m256h) {
+    union A {
+        a: __m256h,
+        b: [u16; 16],
+    }
+    assert_eq!(A { a }.b, A { a: b }.b)
+}
+
+pub unsafe fn assert_eq_m512h(a: __m512h, b: __m512h) {
+    union A {
+        a: __m512h,
+        b: [u16; 32],
+    }
+    assert_eq!(A { a }.b, A { a: b }.b)
+}
@@ -0,0 +1,252 @@
+//! Vectorized AES Instructions (VAES)
+//!
+//! The intrinsics here perform AES rounds on each 128-bit lane of a 256-bit or
+//! 512-bit vector, and otherwise behave like the AES-NI intrinsics in
+//! `aes.rs`.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use crate::core_arch::x86::{__m256i, __m512i};
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.aesni.aesenc.256"]
+    fn aesenc_256(a: __m256i, round_key: __m256i) -> __m256i;
+    #[link_name = "llvm.x86.aesni.aesenclast.256"]
+    fn aesenclast_256(a: __m256i, round_key: __m256i) -> __m256i;
+    #[link_name = "llvm.x86.aesni.aesdec.256"]
+    fn aesdec_256(a: __m256i, round_key: __m256i) -> __m256i;
+    #[link_name = "llvm.x86.aesni.aesdeclast.256"]
+    fn aesdeclast_256(a: __m256i, round_key: __m256i) -> __m256i;
+    #[link_name = "llvm.x86.aesni.aesenc.512"]
+    fn aesenc_512(a: __m512i, round_key: __m512i) -> __m512i;
+    #[link_name = "llvm.x86.aesni.aesenclast.512"]
+    fn aesenclast_512(a: __m512i, round_key: __m512i) -> __m512i;
+    #[link_name = "llvm.x86.aesni.aesdec.512"]
+    fn aesdec_512(a: __m512i, round_key: __m512i) -> __m512i;
+    #[link_name = "llvm.x86.aesni.aesdeclast.512"]
+    fn aesdeclast_512(a: __m512i, round_key: __m512i) -> __m512i;
+}
+
+/// Performs one round of an AES encryption flow on each 128-bit lane (state) of
+/// `a`, using the corresponding 128-bit lane (key) in `round_key`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_aesenc_epi128)
+#[inline]
+#[target_feature(enable = "vaes,avx")]
+#[cfg_attr(test, assert_instr(vaesenc))]
+pub unsafe fn _mm256_aesenc_epi128(a: __m256i, round_key: __m256i) -> __m256i {
+    aesenc_256(a, round_key)
+}
+
+/// Performs the last round of an AES encryption flow on each 128-bit lane
+/// (state) of `a`, using the corresponding 128-bit lane (key) in `round_key`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_aesenclast_epi128)
+#[inline]
+#[target_feature(enable = "vaes,avx")]
+#[cfg_attr(test, assert_instr(vaesenclast))]
+pub unsafe fn _mm256_aesenclast_epi128(a: __m256i, round_key: __m256i) -> __m256i {
+    aesenclast_256(a, round_key)
+}
+
+/// Performs one round of an AES decryption flow on each 128-bit lane (state) of
+/// `a`, using the corresponding 128-bit lane (key) in `round_key`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_aesdec_epi128)
+#[inline]
+#[target_feature(enable = "vaes,avx")]
+#[cfg_attr(test, assert_instr(vaesdec))]
+pub unsafe fn _mm256_aesdec_epi128(a: __m256i, round_key: __m256i) -> __m256i {
+    aesdec_256(a, round_key)
+}
+
+/// Performs the last round of an AES decryption flow on each 128-bit lane
+/// (state) of `a`, using the corresponding 128-bit lane (key) in `round_key`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_aesdeclast_epi128)
+#[inline]
+#[target_feature(enable = "vaes,avx")]
+#[cfg_attr(test, assert_instr(vaesdeclast))]
+pub unsafe fn _mm256_aesdeclast_epi128(a: __m256i, round_key: __m256i) -> __m256i {
+    aesdeclast_256(a, round_key)
+}
+
+/// Performs one round of an AES encryption flow on each 128-bit lane (state) of
+/// `a`, using the corresponding 128-bit lane (key) in `round_key`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_aesenc_epi128)
+#[inline]
+#[target_feature(enable = "vaes,avx512f")]
+#[cfg_attr(test, assert_instr(vaesenc))]
+pub unsafe fn _mm512_aesenc_epi128(a: __m512i, round_key: __m512i) -> __m512i {
+    aesenc_512(a, round_key)
+}
+
+/// Performs the last round of an AES encryption flow on each 128-bit lane
+/// (state) of `a`, using the corresponding 128-bit lane (key) in `round_key`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_aesenclast_epi128)
+#[inline]
+#[target_feature(enable = "vaes,avx512f")]
+#[cfg_attr(test, assert_instr(vaesenclast))]
+pub unsafe fn _mm512_aesenclast_epi128(a: __m512i, round_key: __m512i) -> __m512i {
+    aesenclast_512(a, round_key)
+}
+
+/// Performs one round of an AES decryption flow on each 128-bit lane (state) of
+/// `a`, using the corresponding 128-bit lane (key) in `round_key`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_aesdec_epi128)
+#[inline]
+#[target_feature(enable = "vaes,avx512f")]
+#[cfg_attr(test, assert_instr(vaesdec))]
+pub unsafe fn _mm512_aesdec_epi128(a: __m512i, round_key: __m512i) -> __m512i {
+    aesdec_512(a, round_key)
+}
+
+/// Performs the last round of an AES decryption flow on each 128-bit lane
+/// (state) of `a`, using the corresponding 128-bit lane (key) in `round_key`.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_aesdeclast_epi128)
+#[inline]
+#[target_feature(enable = "vaes,avx512f")]
+#[cfg_attr(test, assert_instr(vaesdeclast))]
+pub unsafe fn _mm512_aesdeclast_epi128(a: __m512i, round_key: __m512i) -> __m512i {
+    aesdeclast_512(a, round_key)
+}
+
+#[cfg(test)]
+mod tests {
+    // The constants in the tests below are just bit patterns. They should not
+    // be interpreted as integers; signedness does not make sense for them, but
+    // __m128i happens to be defined in terms of signed integers.
+    #![allow(overflowing_literals)]
+
+    use std::mem::transmute;
+
+    use stdsimd_test::simd_test;
+
+    use crate::core_arch::x86::*;
+
+    // Returns four distinct states and round keys, derived from the AES-NI
+    // test vectors, so that mixing up lanes is detected.
+    unsafe fn lanes() -> ([__m128i; 4], [__m128i; 4]) {
+        let a = _mm_set_epi64x(0x0123456789abcdef, 0x8899aabbccddeeff);
+        let k = _mm_set_epi64x(0x1133557799bbddff, 0x0022446688aaccee);
+        let state = |i| _mm_add_epi64(a, _mm_set1_epi64x(i));
+        let key = |i| _mm_xor_si128(k, _mm_set1_epi8(i));
+        (
+            [state(0), state(1), state(2), state(3)],
+            [key(0), key(1), key(2), key(3)],
+        )
+    }
+
+    #[simd_test(enable = "aes,vaes,avx")]
+    unsafe fn test_mm256_aesenc_epi128() {
+        let (a, k) = lanes();
+        let r = _mm256_aesenc_epi128(transmute([a[0], a[1]]), transmute([k[0], k[1]]));
+        let e: __m256i = transmute([_mm_aesenc_si128(a[0], k[0]), _mm_aesenc_si128(a[1], k[1])]);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "aes,vaes,avx")]
+    unsafe fn test_mm256_aesenclast_epi128() {
+        let (a, k) = lanes();
+        let r = _mm256_aesenclast_epi128(transmute([a[0], a[1]]), transmute([k[0], k[1]]));
+        let e: __m256i = transmute([
+            _mm_aesenclast_si128(a[0], k[0]),
+            _mm_aesenclast_si128(a[1], k[1]),
+        ]);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "aes,vaes,avx")]
+    unsafe fn test_mm256_aesdec_epi128() {
+        let (a, k) = lanes();
+        let r = _mm256_aesdec_epi128(transmute([a[0], a[1]]), transmute([k[0], k[1]]));
+        let e: __m256i = transmute([_mm_aesdec_si128(a[0], k[0]), _mm_aesdec_si128(a[1], k[1])]);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "aes,vaes,avx")]
+    unsafe fn test_mm256_aesdeclast_epi128() {
+        let (a, k) = lanes();
+        let r = _mm256_aesdeclast_epi128(transmute([a[0], a[1]]), transmute([k[0], k[1]]));
+        let e: __m256i = transmute([
+            _mm_aesdeclast_si128(a[0], k[0]),
+            _mm_aesdeclast_si128(a[1], k[1]),
+        ]);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "aes,vaes,avx512f")]
+    unsafe fn test_mm512_aesenc_epi128() {
+        let (a, k) = lanes();
+        let r = _mm512_aesenc_epi128(
+            transmute([a[0], a[1], a[2], a[3]]),
+            transmute([k[0], k[1], k[2], k[3]]),
+        );
+        let e: __m512i = transmute([
+            _mm_aesenc_si128(a[0], k[0]),
+            _mm_aesenc_si128(a[1], k[1]),
+            _mm_aesenc_si128(a[2], k[2]),
+            _mm_aesenc_si128(a[3], k[3]),
+        ]);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "aes,vaes,avx512f")]
+    unsafe fn test_mm512_aesenclast_epi128() {
+        let (a, k) = lanes();
+        let r = _mm512_aesenclast_epi128(
+            transmute([a[0], a[1], a[2], a[3]]),
+            transmute([k[0], k[1], k[2], k[3]]),
+        );
+        let e: __m512i = transmute([
+            _mm_aesenclast_si128(a[0], k[0]),
+            _mm_aesenclast_si128(a[1], k[1]),
+            _mm_aesenclast_si128(a[2], k[2]),
+            _mm_aesenclast_si128(a[3], k[3]),
+        ]);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "aes,vaes,avx512f")]
+    unsafe fn test_mm512_aesdec_epi128() {
+        let (a, k) = lanes();
+        let r = _mm512_aesdec_epi128(
+            transmute([a[0], a[1], a[2], a[3]]),
+            transmute([k[0], k[1], k[2], k[3]]),
+        );
+        let e: __m512i = transmute([
+            _mm_aesdec_si128(a[0], k[0]),
+            _mm_aesdec_si128(a[1], k[1]),
+            _mm_aesdec_si128(a[2], k[2]),
+            _mm_aesdec_si128(a[3], k[3]),
+        ]);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "aes,vaes,avx512f")]
+    unsafe fn test_mm512_aesdeclast_epi128() {
+        let (a, k) = lanes();
+        let r = _mm512_aesdeclast_epi128(
+            transmute([a[0], a[1], a[2], a[3]]),
+            transmute([k[0], k[1], k[2], k[3]]),
+        );
+        let e: __m512i = transmute([
+            _mm_aesdeclast_si128(a[0], k[0]),
+            _mm_aesdeclast_si128(a[1], k[1]),
+            _mm_aesdeclast_si128(a[2], k[2]),
+            _mm_aesdeclast_si128(a[3], k[3]),
+        ]);
+        assert_eq_m512i(r, e);
+    }
+}
@@ -0,0 +1,149 @@
+//! Vectorized Carry-less Multiplication (VCLMUL)
+//!
+//! The intrinsics here perform a carry-less multiplication on each 128-bit
+//! lane of a 256-bit or 512-bit vector, and otherwise behave like
+//! `_mm_clmulepi64_si128` in `pclmulqdq.rs`.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref] (p. 4-241).
+//!
+//! [intel64_ref]: http://www.intel.com/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use crate::core_arch::x86::{__m256i, __m512i};
+
+#[cfg(test)]
+use crate::stdsimd_test::assert_instr;
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.pclmulqdq.256"]
+    fn pclmulqdq_256(a: __m256i, b: __m256i, imm8: u8) -> __m256i;
+    #[link_name = "llvm.x86.pclmulqdq.512"]
+    fn pclmulqdq_512(a: __m512i, b: __m512i, imm8: u8) -> __m512i;
+}
+
+/// Performs a carry-less multiplication of two 64-bit polynomials over the
+/// finite field GF(2^k) in each of the two 128-bit lanes.
+///
+/// The immediate byte is used for determining which halves of each lane of
+/// `a` and `b` should be used. Immediate bits other than 0 and 4 are ignored.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm256_clmulepi64_epi128)
+#[inline]
+#[target_feature(enable = "vpclmulqdq,avx")]
+#[cfg_attr(
+    all(test, not(target_os = "linux")),
+    assert_instr(vpclmulqdq, imm8 = 0)
+)]
+#[cfg_attr(all(test, target_os = "linux"), assert_instr(vpclmullqlqdq, imm8 = 0))]
+#[cfg_attr(all(test, target_os = "linux"), assert_instr(vpclmulhqlqdq, imm8 = 1))]
+#[cfg_attr(all(test, target_os = "linux"), assert_instr(vpclmullqhqdq, imm8 = 16))]
+#[cfg_attr(all(test, target_os = "linux"), assert_instr(vpclmulhqhqdq, imm8 = 17))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm256_clmulepi64_epi128(a: __m256i, b: __m256i, imm8: i32) -> __m256i {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pclmulqdq_256(a, b, $imm8)
+        };
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Performs a carry-less multiplication of two 64-bit polynomials over the
+/// finite field GF(2^k) in each of the four 128-bit lanes.
+///
+/// The immediate byte is used for determining which halves of each lane of
+/// `a` and `b` should be used. Immediate bits other than 0 and 4 are ignored.
+///
+/// [Intel's documentation](https://software.intel.com/sites/landingpage/IntrinsicsGuide/#text=_mm512_clmulepi64_epi128)
+#[inline]
+#[target_feature(enable = "vpclmulqdq,avx512f")]
+#[cfg_attr(
+    all(test, not(target_os = "linux")),
+    assert_instr(vpclmulqdq, imm8 = 0)
+)]
+#[cfg_attr(all(test, target_os = "linux"), assert_instr(vpclmullqlqdq, imm8 = 0))]
+#[cfg_attr(all(test, target_os = "linux"), assert_instr(vpclmulhqlqdq, imm8 = 1))]
+#[cfg_attr(all(test, target_os = "linux"), assert_instr(vpclmullqhqdq, imm8 = 16))]
+#[cfg_attr(all(test, target_os = "linux"), assert_instr(vpclmulhqhqdq, imm8 = 17))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_clmulepi64_epi128(a: __m512i, b: __m512i, imm8: i32) -> __m512i {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pclmulqdq_512(a, b, $imm8)
+        };
+    }
+    constify_imm8!(imm8, call)
+}
+
+#[cfg(test)]
+mod tests {
+    // The constants in the tests below are just bit patterns. They should not
+    // be interpreted as integers; signedness does not make sense for them, but
+    // __m128i happens to be defined in terms of signed integers.
+    #![allow(overflowing_literals)]
+
+    use std::mem::transmute;
+
+    use stdsimd_test::simd_test;
+
+    use crate::core_arch::x86::*;
+
+    // Returns four distinct pairs of lanes, derived from the CLMUL white paper
+    // test vectors, so that mixing up lanes is detected.
+    unsafe fn lanes() -> ([__m128i; 4], [__m128i; 4]) {
+        // Constants taken from https://software.intel.com/sites/default/files/managed/72/cc/clmul-wp-rev-2.02-2014-04-20.pdf
+        let a = _mm_set_epi64x(0x7b5b546573745665, 0x63746f725d53475d);
+        let b = _mm_set_epi64x(0x4869285368617929, 0x5b477565726f6e5d);
+        let lane_a = |i| _mm_xor_si128(a, _mm_set1_epi8(i));
+        let lane_b = |i| _mm_add_epi64(b, _mm_set1_epi64x(i));
+        (
+            [lane_a(0), lane_a(1), lane_a(2), lane_a(3)],
+            [lane_b(0), lane_b(1), lane_b(2), lane_b(3)],
+        )
+    }
+
+    #[simd_test(enable = "pclmulqdq,vpclmulqdq,avx")]
+    unsafe fn test_mm256_clmulepi64_epi128() {
+        let (a, b) = lanes();
+        let va: __m256i = transmute([a[0], a[1]]);
+        let vb: __m256i = transmute([b[0], b[1]]);
+        macro_rules! check {
+            ($imm8:expr) => {
+                let r = _mm256_clmulepi64_epi128(va, vb, $imm8);
+                let e: __m256i = transmute([
+                    _mm_clmulepi64_si128(a[0], b[0], $imm8),
+                    _mm_clmulepi64_si128(a[1], b[1], $imm8),
+                ]);
+                assert_eq_m256i(r, e);
+            };
+        }
+        check!(0x00);
+        check!(0x01);
+        check!(0x10);
+        check!(0x11);
+    }
+
+    #[simd_test(enable = "pclmulqdq,vpclmulqdq,avx512f")]
+    unsafe fn test_mm512_clmulepi64_epi128() {
+        let (a, b) = lanes();
+        let va: __m512i = transmute(a);
+        let vb: __m512i = transmute(b);
+        macro_rules! check {
+            ($imm8:expr) => {
+                let r = _mm512_clmulepi64_epi128(va, vb, $imm8);
+                let e: __m512i = transmute([
+                    _mm_clmulepi64_si128(a[0], b[0], $imm8),
+                    _mm_clmulepi64_si128(a[1], b[1], $imm8),
+                    _mm_clmulepi64_si128(a[2], b[2], $imm8),
+                    _mm_clmulepi64_si128(a[3], b[3], $imm8),
+                ]);
+                assert_eq_m512i(r, e);
+            };
+        }
+        check!(0x00);
+        check!(0x01);
+        check!(0x10);
+        check!(0x11);
+    }
+}
@@ -0,0 +1,132 @@
+//! Prints a diagnostic report of the run-time feature detection.
+//!
+//! The report contains all features known to the current architecture and
+//! whether they were detected, the raw values the detection is based on
+//! (CPUID leaves, ELF auxiliary vector entries, `/proc/cpuinfo` fields, ...),
+//! and the features that were disabled although the hardware reports them.
+//!
+//! Usage: `std-detect-report [--machine]`
+//!
+//! With `--machine` the report is printed one record per line, with
+//! tab-separated fields:
+//!
+//! ```text
+//! arch    <target_arch>
+//! os      <target_os>
+//! feature <name>  <0|1>
+//! source  <name>  <value>
+//! veto    <name>  <reason>
+//! ```
+
+#![feature(stdsimd)]
+#![cfg_attr(stdsimd_strict, deny(warnings))]
+#![cfg_attr(
+    feature = "cargo-clippy",
+    allow(clippy::use_debug, clippy::print_stdout)
+)]
+
+extern crate std_detect;
+
+use std::env;
+use std::process;
+
+use std_detect::detect;
+
+fn main() {
+    let mut machine = false;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--machine" | "-m" => machine = true,
+            "--help" | "-h" => {
+                println!("usage: std-detect-report [--machine]");
+                return;
+            }
+            _ => {
+                eprintln!("unknown argument: {}", arg);
+                eprintln!("usage: std-detect-report [--machine]");
+                process::exit(2);
+            }
+        }
+    }
+
+    let arch = if cfg!(target_arch = "x86") {
+        "x86"
+    } else if cfg!(target_arch = "x86_64") {
+        "x86_64"
+    } else if cfg!(target_arch = "arm") {
+        "arm"
+    } else if cfg!(target_arch = "aarch64") {
+        "aarch64"
+    } else if cfg!(target_arch = "mips") {
+        "mips"
+    } else if cfg!(target_arch = "mips64") {
+        "mips64"
+    } else if cfg!(target_arch = "powerpc") {
+        "powerpc"
+    } else if cfg!(target_arch = "powerpc64") {
+        "powerpc64"
+    } else {
+        "unknown"
+    };
+    let os = env::consts::OS;
+
+    let report = detect::report();
+
+    if machine {
+        println!("arch\t{}", arch);
+        println!("os\t{}", os);
+        for &(name, detected) in report.features() {
+            println!("feature\t{}\t{}", name, detected as u8);
+        }
+        for &(ref name, ref value) in report.sources() {
+            println!("source\t{}\t{}", name, value);
+        }
+        for &(name, reason) in report.vetoes() {
+            println!("veto\t{}\t{}", name, reason);
+        }
+        return;
+    }
+
+    println!("std_detect {} on {}-{}", env!("CARGO_PKG_VERSION"), arch, os);
+
+    println!();
+    println!("features:");
+    let width = report
+        .features()
+        .iter()
+        .map(|&(name, _)| name.len())
+        .max()
+        .unwrap_or(0);
+    for &(name, detected) in report.features() {
+        println!(
+            "  {:width$}  {}",
+            name,
+            if detected { "yes" } else { "no" },
+            width = width
+        );
+    }
+
+    println!();
+    println!("sources:");
+    if report.sources().is_empty() {
+        println!("  (none)");
+    }
+    let width = report
+        .sources()
+        .iter()
+        .map(|&(ref name, _)| name.len())
+        .max()
+        .unwrap_or(0);
+    for &(ref name, ref value) in report.sources() {
+        println!("  {:width$}  {}", name, value, width = width);
+    }
+
+    println!();
+    println!("vetoes:");
+    if report.vetoes().is_empty() {
+        println!("  (none)");
+    }
+    for &(name, reason) in report.vetoes() {
+        println!("  {}: {}", name, reason);
+    }
+}
@@ -54,6 +54,22 @@ macro_rules! is_aarch64_feature_detected {
         cfg!(target_feature = "dotprod") ||
             $crate::detect::check_for($crate::detect::Feature::dotprod)
     };
+    ("paca") => {
+        cfg!(target_feature = "paca") ||
+            $crate::detect::check_for($crate::detect::Feature::paca)
+    };
+    ("pacg") => {
+        cfg!(target_feature = "pacg") ||
+            $crate::detect::check_for($crate::detect::Feature::pacg)
+    };
+    ("bti") => {
+        cfg!(target_feature = "bti") ||
+            $crate::detect::check_for($crate::detect::Feature::bti)
+    };
+    ("mte") => {
+        cfg!(target_feature = "mte") ||
+            $crate::detect::check_for($crate::detect::Feature::mte)
+    };
     ("ras") => {
         compile_error!("\"ras\" feature cannot be detected at run-time")
     };
@@ -72,35 +88,46 @@ macro_rules! is_aarch64_feature_detected {
     ($t:tt) => { compile_error!(concat!("unknown aarch64 target feature: ", $t)) };
 }
 
-/// ARM Aarch64 CPU Feature enum. Each variant denotes a position in a bitset
-/// for a particular feature.
-///
-/// PLEASE: do not use this, it is an implementation detail subject to change.
-#[doc(hidden)]
-#[allow(non_camel_case_types)]
-#[repr(u8)]
-#[unstable(feature = "stdsimd_internal", issue = "0")]
-pub enum Feature {
-    /// ARM Advanced SIMD (ASIMD)
-    asimd,
-    /// Polynomial Multiply
-    pmull,
-    /// Floating point support
-    fp,
-    /// Half-float support.
-    fp16,
-    /// Scalable Vector Extension (SVE)
-    sve,
-    /// CRC32 (Cyclic Redundancy Check)
-    crc,
-    /// Crypto: AES + PMULL + SHA1 + SHA2
-    crypto,
-    /// Atomics (Large System Extension)
-    lse,
-    /// Rounding Double Multiply (ASIMDRDM)
-    rdm,
-    /// Release consistent Processor consistent (RcPc)
-    rcpc,
-    /// Vector Dot-Product (ASIMDDP)
-    dotprod,
+features! {
+    /// ARM Aarch64 CPU Feature enum. Each variant denotes a position in a bitset
+    /// for a particular feature.
+    ///
+    /// PLEASE: do not use this, it is an implementation detail subject to change.
+    #[doc(hidden)]
+    #[allow(non_camel_case_types)]
+    #[derive(Copy, Clone)]
+    #[repr(u8)]
+    #[unstable(feature = "stdsimd_internal", issue = "0")]
+    pub enum Feature {
+        /// ARM Advanced SIMD (ASIMD)
+        asimd => "asimd",
+        /// Polynomial Multiply
+        pmull => "pmull",
+        /// Floating point support
+        fp => "fp",
+        /// Half-float support.
+        fp16 => "fp16",
+        /// Scalable Vector Extension (SVE)
+        sve => "sve",
+        /// CRC32 (Cyclic Redundancy Check)
+        crc => "crc",
+        /// Crypto: AES + PMULL + SHA1 + SHA2
+        crypto => "crypto",
+        /// Atomics (Large System Extension)
+        lse => "lse",
+        /// Rounding Double Multiply (ASIMDRDM)
+        rdm => "rdm",
+        /// Release consistent Processor consistent (RcPc)
+        rcpc => "rcpc",
+        /// Vector Dot-Product (ASIMDDP)
+        dotprod => "dotprod",
+        /// Pointer Authentication, address authentication (PACA)
+        paca => "paca",
+        /// Pointer Authentication, generic authentication (PACG)
+        pacg => "pacg",
+        /// Branch Target Identification (BTI)
+        bti => "bti",
+        /// Memory Tagging Extension (MTE)
+        mte => "mte",
+    }
 }
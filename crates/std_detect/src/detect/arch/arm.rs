@@ -23,17 +23,20 @@ macro_rules! is_arm_feature_detected {
     ($t:tt) => { compile_error!(concat!("unknown arm target feature: ", $t)) };
 }
 
-/// ARM CPU Feature enum. Each variant denotes a position in a bitset for a
-/// particular feature.
-///
-/// PLEASE: do not use this, it is an implementation detail subject to change.
-#[doc(hidden)]
-#[allow(non_camel_case_types)]
-#[repr(u8)]
-#[unstable(feature = "stdsimd_internal", issue = "0")]
-pub enum Feature {
-    /// ARM Advanced SIMD (NEON) - Aarch32
-    neon,
-    /// Polynomial Multiply
-    pmull,
+features! {
+    /// ARM CPU Feature enum. Each variant denotes a position in a bitset for a
+    /// particular feature.
+    ///
+    /// PLEASE: do not use this, it is an implementation detail subject to change.
+    #[doc(hidden)]
+    #[allow(non_camel_case_types)]
+    #[derive(Copy, Clone)]
+    #[repr(u8)]
+    #[unstable(feature = "stdsimd_internal", issue = "0")]
+    pub enum Feature {
+        /// ARM Advanced SIMD (NEON) - Aarch32
+        neon => "neon",
+        /// Polynomial Multiply
+        pmull => "pmull",
+    }
 }
@@ -15,15 +15,18 @@ macro_rules! is_mips_feature_detected {
     ($t:tt) => { compile_error!(concat!("unknown mips target feature: ", $t)) };
 }
 
-/// MIPS CPU Feature enum. Each variant denotes a position in a bitset for a
-/// particular feature.
-///
-/// PLEASE: do not use this, it is an implementation detail subject to change.
-#[doc(hidden)]
-#[allow(non_camel_case_types)]
-#[repr(u8)]
-#[unstable(feature = "stdsimd_internal", issue = "0")]
-pub enum Feature {
-    /// MIPS SIMD Architecture (MSA)
-    msa,
+features! {
+    /// MIPS CPU Feature enum. Each variant denotes a position in a bitset for a
+    /// particular feature.
+    ///
+    /// PLEASE: do not use this, it is an implementation detail subject to change.
+    #[doc(hidden)]
+    #[allow(non_camel_case_types)]
+    #[derive(Copy, Clone)]
+    #[repr(u8)]
+    #[unstable(feature = "stdsimd_internal", issue = "0")]
+    pub enum Feature {
+        /// MIPS SIMD Architecture (MSA)
+        msa => "msa",
+    }
 }
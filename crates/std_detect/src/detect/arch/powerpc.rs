@@ -24,19 +24,22 @@ macro_rules! is_powerpc_feature_detected {
 }
 
 
-/// PowerPC CPU Feature enum. Each variant denotes a position in a bitset
-/// for a particular feature.
-///
-/// PLEASE: do not use this, it is an implementation detail subject to change.
-#[doc(hidden)]
-#[allow(non_camel_case_types)]
-#[repr(u8)]
-#[unstable(feature = "stdsimd_internal", issue = "0")]
-pub enum Feature {
-    /// Altivec
-    altivec,
-    /// VSX
-    vsx,
-    /// Power8
-    power8,
+features! {
+    /// PowerPC CPU Feature enum. Each variant denotes a position in a bitset
+    /// for a particular feature.
+    ///
+    /// PLEASE: do not use this, it is an implementation detail subject to change.
+    #[doc(hidden)]
+    #[allow(non_camel_case_types)]
+    #[derive(Copy, Clone)]
+    #[repr(u8)]
+    #[unstable(feature = "stdsimd_internal", issue = "0")]
+    pub enum Feature {
+        /// Altivec
+        altivec => "altivec",
+        /// VSX
+        vsx => "vsx",
+        /// Power8
+        power8 => "power8",
+    }
 }
@@ -38,6 +38,8 @@
 ///
 /// * `"aes"`
 /// * `"pclmulqdq"`
+/// * `"vaes"`
+/// * `"vpclmulqdq"`
 /// * `"rdrand"`
 /// * `"rdseed"`
 /// * `"tsc"`
@@ -50,8 +52,10 @@
 /// * `"sse4.2"`
 /// * `"sse4a"`
 /// * `"sha"`
+/// * `"gfni"`
 /// * `"avx"`
 /// * `"avx2"`
+/// * `"avxvnni"`
 /// * `"avx512f"`
 /// * `"avx512cd"`
 /// * `"avx512er"`
@@ -61,7 +65,13 @@
 /// * `"avx512vl"`
 /// * `"avx512ifma"`
 /// * `"avx512vbmi"`
+/// * `"avx512vbmi2"`
+/// * `"avx512vnni"`
 /// * `"avx512vpopcntdq"`
+/// * `"avx512bitalg"`
+/// * `"avx512bf16"`
+/// * `"avx512fp16"`
+/// * `"f16c"`
 /// * `"fma"`
 /// * `"bmi1"`
 /// * `"bmi2"`
@@ -74,6 +84,13 @@
 /// * `"xsaveopt"`
 /// * `"xsaves"`
 /// * `"xsavec"`
+/// * `"xop"`
+/// * `"fma4"`
+/// * `"lwp"`
+/// * `"mwaitx"`
+/// * `"clzero"`
+/// * `"rdpru"`
+/// * `"wbnoinvd"`
 ///
 /// [docs]: https://software.intel.com/sites/landingpage/IntrinsicsGuide
 #[macro_export]
@@ -86,6 +103,12 @@ macro_rules! is_x86_feature_detected {
     ("pclmulqdq") => {
         cfg!(target_feature = "pclmulqdq") || $crate::detect::check_for(
             $crate::detect::Feature::pclmulqdq)  };
+    ("vaes") => {
+        cfg!(target_feature = "vaes") || $crate::detect::check_for(
+            $crate::detect::Feature::vaes)  };
+    ("vpclmulqdq") => {
+        cfg!(target_feature = "vpclmulqdq") || $crate::detect::check_for(
+            $crate::detect::Feature::vpclmulqdq)  };
     ("rdrand") => {
         cfg!(target_feature = "rdrand") || $crate::detect::check_for(
             $crate::detect::Feature::rdrand)  };
@@ -129,6 +152,10 @@ macro_rules! is_x86_feature_detected {
         cfg!(target_feature = "sha") || $crate::detect::check_for(
             $crate::detect::Feature::sha)
     };
+    ("gfni") => {
+        cfg!(target_feature = "gfni") || $crate::detect::check_for(
+            $crate::detect::Feature::gfni)
+    };
     ("avx") => {
         cfg!(target_feature = "avx") || $crate::detect::check_for(
             $crate::detect::Feature::avx)
@@ -137,6 +164,10 @@ macro_rules! is_x86_feature_detected {
         cfg!(target_feature = "avx2") || $crate::detect::check_for(
             $crate::detect::Feature::avx2)
     };
+    ("avxvnni") => {
+        cfg!(target_feature = "avxvnni") || $crate::detect::check_for(
+            $crate::detect::Feature::avxvnni)
+    };
     ("avx512f") => {
         cfg!(target_feature = "avx512f") || $crate::detect::check_for(
             $crate::detect::Feature::avx512f)
@@ -173,10 +204,34 @@ macro_rules! is_x86_feature_detected {
         cfg!(target_feature = "avx512vbmi") || $crate::detect::check_for(
             $crate::detect::Feature::avx512_vbmi)
     };
+    ("avx512vbmi2") => {
+        cfg!(target_feature = "avx512vbmi2") || $crate::detect::check_for(
+            $crate::detect::Feature::avx512_vbmi2)
+    };
+    ("avx512vnni") => {
+        cfg!(target_feature = "avx512vnni") || $crate::detect::check_for(
+            $crate::detect::Feature::avx512_vnni)
+    };
     ("avx512vpopcntdq") => {
         cfg!(target_feature = "avx512vpopcntdq") || $crate::detect::check_for(
             $crate::detect::Feature::avx512_vpopcntdq)
     };
+    ("avx512bitalg") => {
+        cfg!(target_feature = "avx512bitalg") || $crate::detect::check_for(
+            $crate::detect::Feature::avx512_bitalg)
+    };
+    ("avx512bf16") => {
+        cfg!(target_feature = "avx512bf16") || $crate::detect::check_for(
+            $crate::detect::Feature::avx512_bf16)
+    };
+    ("avx512fp16") => {
+        cfg!(target_feature = "avx512fp16") || $crate::detect::check_for(
+            $crate::detect::Feature::avx512_fp16)
+    };
+    ("f16c") => {
+        cfg!(target_feature = "f16c") || $crate::detect::check_for(
+            $crate::detect::Feature::f16c)
+    };
     ("fma") => {
         cfg!(target_feature = "fma") || $crate::detect::check_for(
             $crate::detect::Feature::fma)
@@ -233,6 +288,34 @@ macro_rules! is_x86_feature_detected {
         cfg!(target_feature = "adx") || $crate::detect::check_for(
             $crate::detect::Feature::adx)
     };
+    ("xop") => {
+        cfg!(target_feature = "xop") || $crate::detect::check_for(
+            $crate::detect::Feature::xop)
+    };
+    ("fma4") => {
+        cfg!(target_feature = "fma4") || $crate::detect::check_for(
+            $crate::detect::Feature::fma4)
+    };
+    ("lwp") => {
+        cfg!(target_feature = "lwp") || $crate::detect::check_for(
+            $crate::detect::Feature::lwp)
+    };
+    ("mwaitx") => {
+        cfg!(target_feature = "mwaitx") || $crate::detect::check_for(
+            $crate::detect::Feature::mwaitx)
+    };
+    ("clzero") => {
+        cfg!(target_feature = "clzero") || $crate::detect::check_for(
+            $crate::detect::Feature::clzero)
+    };
+    ("rdpru") => {
+        cfg!(target_feature = "rdpru") || $crate::detect::check_for(
+            $crate::detect::Feature::rdpru)
+    };
+    ("wbnoinvd") => {
+        cfg!(target_feature = "wbnoinvd") || $crate::detect::check_for(
+            $crate::detect::Feature::wbnoinvd)
+    };
     ($t:tt,) => {
         is_x86_feature_detected!($t);
     };
@@ -241,93 +324,130 @@ macro_rules! is_x86_feature_detected {
     };
 }
 
-/// X86 CPU Feature enum. Each variant denotes a position in a bitset for a
-/// particular feature.
-///
-/// This is an unstable implementation detail subject to change.
-#[allow(non_camel_case_types)]
-#[repr(u8)]
-#[doc(hidden)]
-#[unstable(feature = "stdsimd_internal", issue = "0")]
-pub enum Feature {
-    /// AES (Advanced Encryption Standard New Instructions AES-NI)
-    aes,
-    /// CLMUL (Carry-less Multiplication)
-    pclmulqdq,
-    /// RDRAND
-    rdrand,
-    /// RDSEED
-    rdseed,
-    /// TSC (Time Stamp Counter)
-    tsc,
-    /// MMX
-    mmx,
-    /// SSE (Streaming SIMD Extensions)
-    sse,
-    /// SSE2 (Streaming SIMD Extensions 2)
-    sse2,
-    /// SSE3 (Streaming SIMD Extensions 3)
-    sse3,
-    /// SSSE3 (Supplemental Streaming SIMD Extensions 3)
-    ssse3,
-    /// SSE4.1 (Streaming SIMD Extensions 4.1)
-    sse4_1,
-    /// SSE4.2 (Streaming SIMD Extensions 4.2)
-    sse4_2,
-    /// SSE4a (Streaming SIMD Extensions 4a)
-    sse4a,
-    /// SHA
-    sha,
-    /// AVX (Advanced Vector Extensions)
-    avx,
-    /// AVX2 (Advanced Vector Extensions 2)
-    avx2,
-    /// AVX-512 F (Foundation)
-    avx512f,
-    /// AVX-512 CD (Conflict Detection Instructions)
-    avx512cd,
-    /// AVX-512 ER (Exponential and Reciprocal Instructions)
-    avx512er,
-    /// AVX-512 PF (Prefetch Instructions)
-    avx512pf,
-    /// AVX-512 BW (Byte and Word Instructions)
-    avx512bw,
-    /// AVX-512 DQ (Doubleword and Quadword)
-    avx512dq,
-    /// AVX-512 VL (Vector Length Extensions)
-    avx512vl,
-    /// AVX-512 IFMA (Integer Fused Multiply Add)
-    avx512_ifma,
-    /// AVX-512 VBMI (Vector Byte Manipulation Instructions)
-    avx512_vbmi,
-    /// AVX-512 VPOPCNTDQ (Vector Population Count Doubleword and
-    /// Quadword)
-    avx512_vpopcntdq,
-    /// FMA (Fused Multiply Add)
-    fma,
-    /// BMI1 (Bit Manipulation Instructions 1)
-    bmi,
-    /// BMI1 (Bit Manipulation Instructions 2)
-    bmi2,
-    /// ABM (Advanced Bit Manipulation) on AMD / LZCNT (Leading Zero
-    /// Count) on Intel
-    abm,
-    /// TBM (Trailing Bit Manipulation)
-    tbm,
-    /// POPCNT (Population Count)
-    popcnt,
-    /// FXSR (Floating-point context fast save and restor)
-    fxsr,
-    /// XSAVE (Save Processor Extended States)
-    xsave,
-    /// XSAVEOPT (Save Processor Extended States Optimized)
-    xsaveopt,
-    /// XSAVES (Save Processor Extended States Supervisor)
-    xsaves,
-    /// XSAVEC (Save Processor Extended States Compacted)
-    xsavec,
-    /// CMPXCH16B, a 16-byte compare-and-swap instruction
-    cmpxchg16b,
-    /// ADX, Intel ADX (Multi-Precision Add-Carry Instruction Extensions)
-    adx,
+features! {
+    /// X86 CPU Feature enum. Each variant denotes a position in a bitset for a
+    /// particular feature.
+    ///
+    /// This is an unstable implementation detail subject to change.
+    #[allow(non_camel_case_types)]
+    #[derive(Copy, Clone)]
+    #[repr(u8)]
+    #[doc(hidden)]
+    #[unstable(feature = "stdsimd_internal", issue = "0")]
+    pub enum Feature {
+        /// AES (Advanced Encryption Standard New Instructions AES-NI)
+        aes => "aes",
+        /// CLMUL (Carry-less Multiplication)
+        pclmulqdq => "pclmulqdq",
+        /// VAES (Vectorized AES Instructions)
+        vaes => "vaes",
+        /// VPCLMULQDQ (Vectorized Carry-less Multiplication)
+        vpclmulqdq => "vpclmulqdq",
+        /// RDRAND
+        rdrand => "rdrand",
+        /// RDSEED
+        rdseed => "rdseed",
+        /// TSC (Time Stamp Counter)
+        tsc => "tsc",
+        /// MMX
+        mmx => "mmx",
+        /// SSE (Streaming SIMD Extensions)
+        sse => "sse",
+        /// SSE2 (Streaming SIMD Extensions 2)
+        sse2 => "sse2",
+        /// SSE3 (Streaming SIMD Extensions 3)
+        sse3 => "sse3",
+        /// SSSE3 (Supplemental Streaming SIMD Extensions 3)
+        ssse3 => "ssse3",
+        /// SSE4.1 (Streaming SIMD Extensions 4.1)
+        sse4_1 => "sse4.1",
+        /// SSE4.2 (Streaming SIMD Extensions 4.2)
+        sse4_2 => "sse4.2",
+        /// SSE4a (Streaming SIMD Extensions 4a)
+        sse4a => "sse4a",
+        /// SHA
+        sha => "sha",
+        /// GFNI (Galois Field New Instructions)
+        gfni => "gfni",
+        /// AVX (Advanced Vector Extensions)
+        avx => "avx",
+        /// AVX2 (Advanced Vector Extensions 2)
+        avx2 => "avx2",
+        /// AVX-VNNI (VEX-encoded Vector Neural Network Instructions)
+        avxvnni => "avxvnni",
+        /// AVX-512 F (Foundation)
+        avx512f => "avx512f",
+        /// AVX-512 CD (Conflict Detection Instructions)
+        avx512cd => "avx512cd",
+        /// AVX-512 ER (Exponential and Reciprocal Instructions)
+        avx512er => "avx512er",
+        /// AVX-512 PF (Prefetch Instructions)
+        avx512pf => "avx512pf",
+        /// AVX-512 BW (Byte and Word Instructions)
+        avx512bw => "avx512bw",
+        /// AVX-512 DQ (Doubleword and Quadword)
+        avx512dq => "avx512dq",
+        /// AVX-512 VL (Vector Length Extensions)
+        avx512vl => "avx512vl",
+        /// AVX-512 IFMA (Integer Fused Multiply Add)
+        avx512_ifma => "avx512ifma",
+        /// AVX-512 VBMI (Vector Byte Manipulation Instructions)
+        avx512_vbmi => "avx512vbmi",
+        /// AVX-512 VBMI2 (Vector Byte Manipulation Instructions 2)
+        avx512_vbmi2 => "avx512vbmi2",
+        /// AVX-512 VNNI (Vector Neural Network Instructions)
+        avx512_vnni => "avx512vnni",
+        /// AVX-512 VPOPCNTDQ (Vector Population Count Doubleword and
+        /// Quadword)
+        avx512_vpopcntdq => "avx512vpopcntdq",
+        /// AVX-512 BITALG (Bit Algorithms)
+        avx512_bitalg => "avx512bitalg",
+        /// AVX-512 BF16 (BFLOAT16 Instructions)
+        avx512_bf16 => "avx512bf16",
+        /// AVX-512 FP16 (Half-Precision Floating-Point Instructions)
+        avx512_fp16 => "avx512fp16",
+        /// F16C (Conversions between IEEE-754 `binary16` and `binary32` formats)
+        f16c => "f16c",
+        /// FMA (Fused Multiply Add)
+        fma => "fma",
+        /// BMI1 (Bit Manipulation Instructions 1)
+        bmi => "bmi1",
+        /// BMI1 (Bit Manipulation Instructions 2)
+        bmi2 => "bmi2",
+        /// ABM (Advanced Bit Manipulation) on AMD / LZCNT (Leading Zero
+        /// Count) on Intel
+        abm => "abm",
+        /// TBM (Trailing Bit Manipulation)
+        tbm => "tbm",
+        /// POPCNT (Population Count)
+        popcnt => "popcnt",
+        /// FXSR (Floating-point context fast save and restor)
+        fxsr => "fxsr",
+        /// XSAVE (Save Processor Extended States)
+        xsave => "xsave",
+        /// XSAVEOPT (Save Processor Extended States Optimized)
+        xsaveopt => "xsaveopt",
+        /// XSAVES (Save Processor Extended States Supervisor)
+        xsaves => "xsaves",
+        /// XSAVEC (Save Processor Extended States Compacted)
+        xsavec => "xsavec",
+        /// CMPXCH16B, a 16-byte compare-and-swap instruction
+        cmpxchg16b => "cmpxchg16b",
+        /// ADX, Intel ADX (Multi-Precision Add-Carry Instruction Extensions)
+        adx => "adx",
+        /// XOP (eXtended Operations)
+        xop => "xop",
+        /// FMA4 (Four-operand Fused Multiply Add)
+        fma4 => "fma4",
+        /// LWP (Lightweight Profiling)
+        lwp => "lwp",
+        /// MONITORX/MWAITX (User-mode MONITOR/MWAIT with timer)
+        mwaitx => "mwaitx",
+        /// CLZERO (Zero a cache line)
+        clzero => "clzero",
+        /// RDPRU (Read Processor Register at User level)
+        rdpru => "rdpru",
+        /// WBNOINVD (Write Back and do Not Invalidate cache)
+        wbnoinvd => "wbnoinvd",
+    }
 }
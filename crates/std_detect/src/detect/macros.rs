@@ -0,0 +1,42 @@
+//! Helper macros for the `arch/{target_arch}.rs` modules.
+
+/// Defines the `Feature` enum of an architecture from a list of
+/// `variant => "name"` pairs.
+///
+/// Besides the enum itself, this generates `Feature::to_str`, which returns
+/// the name accepted by the `is_{target_arch}_feature_detected!` macro, and
+/// `Feature::ALL`, which lists every variant in declaration order.
+macro_rules! features {
+    (
+        $(#[$enum_attr:meta])*
+        pub enum Feature {
+            $(
+                $(#[$feature_attr:meta])*
+                $feature:ident => $name:expr,
+            )*
+        }
+    ) => {
+        $(#[$enum_attr])*
+        pub enum Feature {
+            $(
+                $(#[$feature_attr])*
+                $feature,
+            )*
+        }
+
+        impl Feature {
+            /// All the features of the current architecture.
+            #[allow(dead_code)]
+            pub(crate) const ALL: &'static [Feature] = &[$(Feature::$feature),*];
+
+            /// Returns the name of the feature, as accepted by the
+            /// `is_{target_arch}_feature_detected!` macro.
+            #[inline]
+            pub fn to_str(self) -> &'static str {
+                match self {
+                    $(Feature::$feature => $name,)*
+                }
+            }
+        }
+    };
+}
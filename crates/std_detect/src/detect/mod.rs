@@ -10,7 +10,7 @@
 //! feature is enabled.
 //!
 //! The `Feature` enums are also implemented in the `arch/{target_arch}.rs`
-//! modules.
+//! modules, using the `features!` macro.
 //!
 //! The `check_for` functions are, in general, Operating System dependent. Most
 //! architectures do not allow user-space programs to query the feature bits
@@ -20,6 +20,9 @@
 #[macro_use]
 mod error_macros;
 
+#[macro_use]
+mod macros;
+
 cfg_if! {
     if #[cfg(any(target_arch = "x86", target_arch = "x86_64"))] {
         #[path = "arch/x86.rs"]
@@ -52,8 +55,9 @@ cfg_if! {
     } else {
         // Unimplemented architecture:
         mod arch {
-            pub enum Feature {
-                Null
+            features! {
+                #[derive(Copy, Clone)]
+                pub enum Feature {}
             }
         }
     }
@@ -62,15 +66,22 @@ pub use self::arch::Feature;
 
 mod bit;
 mod cache;
+mod report;
+
+#[cfg(feature = "std_detect_file_io")]
+pub use self::report::{report, Report};
 
 cfg_if! {
     if #[cfg(any(target_arch = "x86", target_arch = "x86_64"))] {
         // On x86/x86_64 no OS specific functionality is required.
         #[path = "os/x86.rs"]
         mod os;
+        pub use self::os::{hybrid_info, HybridInfo, CoreType};
     } else if #[cfg(all(target_os = "linux", feature = "use_std"))] {
         #[path = "os/linux/mod.rs"]
         mod os;
+        #[cfg(all(target_arch = "aarch64", feature = "libc"))]
+        pub use self::os::{tagged_addr_ctrl, TaggedAddrCtrl, MteTagCheckFault};
     } else if #[cfg(target_os = "freebsd")] {
         #[cfg(target_arch = "aarch64")]
         #[path = "os/aarch64.rs"]
@@ -3,8 +3,8 @@
 //! On FreeBSD >= 12.0, Linux >= 4.11 and other operating systems, it is possible to use
 //! privileged system registers from userspace to check CPU feature support.
 //!
-//! AArch64 system registers ID_AA64ISAR0_EL1, ID_AA64PFR0_EL1, ID_AA64ISAR1_EL1
-//! have bits dedicated to features like AdvSIMD, CRC32, AES, atomics (LSE), etc.
+//! AArch64 system registers ID_AA64ISAR0_EL1, ID_AA64PFR0_EL1, ID_AA64ISAR1_EL1,
+//! ID_AA64PFR1_EL1 have bits dedicated to features like AdvSIMD, CRC32, AES, atomics (LSE), etc.
 //! Each part of the register indicates the level of support for a certain feature, e.g.
 //! when ID_AA64ISAR0_EL1\[7:4\] is >= 1, AES is supported; when it's >= 2, PMULL is supported.
 //!
@@ -17,11 +17,13 @@
 //! - [Linux documentation](https://www.kernel.org/doc/Documentation/arm64/cpu-feature-registers.txt)
 
 use crate::detect::{Feature, cache};
+use crate::detect::report::Recorder;
 
-/// Try to read the features from the system registers.
+/// Try to read the features from the system registers, passing their raw
+/// values to `r`.
 ///
 /// This will cause SIGILL if the current OS is not trapping the mrs instruction.
-pub(crate) fn detect_features() -> cache::Initializer {
+pub(crate) fn detect_features_with<R: Recorder>(r: &mut R) -> cache::Initializer {
     let mut value = cache::Initializer::default();
 
     {
@@ -34,6 +36,7 @@ pub(crate) fn detect_features() -> cache::Initializer {
         // ID_AA64ISAR0_EL1 - Instruction Set Attribute Register 0
         let aa64isar0: u64;
         unsafe { asm!("mrs $0, ID_AA64ISAR0_EL1" : "=r"(aa64isar0)); }
+        r.source(format_args!("ID_AA64ISAR0_EL1"), format_args!("{:#018x}", aa64isar0));
 
         let aes = bits_shift(aa64isar0, 7, 4) >= 1;
         let pmull = bits_shift(aa64isar0, 7, 4) >= 2;
@@ -48,6 +51,7 @@ pub(crate) fn detect_features() -> cache::Initializer {
         // ID_AA64PFR0_EL1 - Processor Feature Register 0
         let aa64pfr0: u64;
         unsafe { asm!("mrs $0, ID_AA64PFR0_EL1" : "=r"(aa64pfr0)); }
+        r.source(format_args!("ID_AA64PFR0_EL1"), format_args!("{:#018x}", aa64pfr0));
 
         let fp = bits_shift(aa64pfr0, 19, 16) < 0xF;
         let fphp = bits_shift(aa64pfr0, 19, 16) >= 1;
@@ -66,8 +70,25 @@ pub(crate) fn detect_features() -> cache::Initializer {
         // ID_AA64ISAR1_EL1 - Instruction Set Attribute Register 1
         let aa64isar1: u64;
         unsafe { asm!("mrs $0, ID_AA64ISAR1_EL1" : "=r"(aa64isar1)); }
+        r.source(format_args!("ID_AA64ISAR1_EL1"), format_args!("{:#018x}", aa64isar1));
 
         enable_feature(Feature::rcpc, bits_shift(aa64isar1, 23, 20) >= 1);
+        // Address authentication is either the architected (APA) or an
+        // implementation defined (API) algorithm, and likewise for generic
+        // authentication (GPA, GPI):
+        enable_feature(Feature::paca, bits_shift(aa64isar1, 7, 4) >= 1 ||
+                                      bits_shift(aa64isar1, 11, 8) >= 1);
+        enable_feature(Feature::pacg, bits_shift(aa64isar1, 27, 24) >= 1 ||
+                                      bits_shift(aa64isar1, 31, 28) >= 1);
+
+        // ID_AA64PFR1_EL1 - Processor Feature Register 1
+        let aa64pfr1: u64;
+        unsafe { asm!("mrs $0, S3_0_C0_C4_1" : "=r"(aa64pfr1)); }
+        r.source(format_args!("ID_AA64PFR1_EL1"), format_args!("{:#018x}", aa64pfr1));
+
+        enable_feature(Feature::bti, bits_shift(aa64pfr1, 3, 0) >= 1);
+        // MTE == 1 only implements the EL0 instructions, without tag checks:
+        enable_feature(Feature::mte, bits_shift(aa64pfr1, 11, 8) >= 2);
     }
 
     value
@@ -1,12 +1,12 @@
 //! Run-time feature detection for Aarch64 on FreeBSD.
 
 use crate::detect::{Feature, cache};
-use super::super::aarch64::detect_features;
+pub(crate) use super::super::aarch64::detect_features_with;
 
 /// Performs run-time feature detection.
 #[inline]
 pub fn check_for(x: Feature) -> bool {
-    cache::test(x as u32, detect_features)
+    cache::test(x as u32, || detect_features_with(&mut ()))
 }
 
 #[cfg(test)]
@@ -24,5 +24,9 @@ mod tests {
         println!("rdm: {:?}", is_aarch64_feature_detected!("rdm"));
         println!("rcpc: {:?}", is_aarch64_feature_detected!("rcpc"));
         println!("dotprod: {:?}", is_aarch64_feature_detected!("dotprod"));
+        println!("paca: {:?}", is_aarch64_feature_detected!("paca"));
+        println!("pacg: {:?}", is_aarch64_feature_detected!("pacg"));
+        println!("bti: {:?}", is_aarch64_feature_detected!("bti"));
+        println!("mte: {:?}", is_aarch64_feature_detected!("mte"));
     }
 }
@@ -4,11 +4,20 @@ cfg_if! {
     if #[cfg(target_arch = "aarch64")] {
         mod aarch64;
         pub use self::aarch64::check_for;
+        pub(crate) use self::aarch64::detect_features_with;
     } else {
         use crate::arch::detect::Feature;
+        use crate::detect::cache;
+        use crate::detect::report::Recorder;
         /// Performs run-time feature detection.
         pub fn check_for(_x: Feature) -> bool {
             false
         }
+        /// Performs run-time feature detection, which detects nothing.
+        pub(crate) fn detect_features_with<R: Recorder>(
+            _r: &mut R,
+        ) -> cache::Initializer {
+            cache::Initializer::default()
+        }
     }
 }
@@ -1,25 +1,34 @@
 //! Run-time feature detection for Aarch64 on Linux.
 
 use crate::detect::{Feature, cache, bit};
+use crate::detect::report::Recorder;
 use super::{auxvec, cpuinfo};
 
 /// Performs run-time feature detection.
 #[inline]
 pub fn check_for(x: Feature) -> bool {
-    cache::test(x as u32, detect_features)
+    cache::test(x as u32, || detect_features_with(&mut ()))
 }
 
 /// Try to read the features from the auxiliary vector, and if that fails, try
-/// to read them from /proc/cpuinfo.
-fn detect_features() -> cache::Initializer {
+/// to read them from /proc/cpuinfo. The raw values are passed to `r`.
+pub(crate) fn detect_features_with<R: Recorder>(r: &mut R) -> cache::Initializer {
     if let Ok(auxv) = auxvec::auxv() {
+        r.source(format_args!("AT_HWCAP"), format_args!("{:#x}", auxv.hwcap));
+        r.source(format_args!("AT_HWCAP2"), format_args!("{:#x}", auxv.hwcap2));
         let hwcap: AtHwcap = auxv.into();
-        return hwcap.cache();
+        return hwcap.cache(r);
     }
     if let Ok(c) = cpuinfo::CpuInfo::new() {
+        r.source(
+            format_args!("/proc/cpuinfo Features"),
+            format_args!("{}", c.field("Features").value()),
+        );
         let hwcap: AtHwcap = c.into();
-        return hwcap.cache();
+        return hwcap.cache(r);
     }
+    r.source(format_args!("AT_HWCAP"), format_args!("unavailable"));
+    r.source(format_args!("/proc/cpuinfo"), format_args!("unavailable"));
     cache::Initializer::default()
 }
 
@@ -50,6 +59,19 @@ struct AtHwcap {
     asimddp: bool, // 20
     // sha512: bool, // 21
     sve: bool, // 22
+    // asimdfhm: bool, // 23
+    // dit: bool, // 24
+    // uscat: bool, // 25
+    // ilrcpc: bool, // 26
+    // flagm: bool, // 27
+    // ssbs: bool, // 28
+    // sb: bool, // 29
+    paca: bool, // 30
+    pacg: bool, // 31
+
+    // HWCAP2:
+    bti: bool, // 17
+    mte: bool, // 18
 }
 
 impl From<auxvec::AuxVec> for AtHwcap {
@@ -79,6 +101,18 @@ impl From<auxvec::AuxVec> for AtHwcap {
             asimddp: bit::test(auxv.hwcap, 20),
             // sha512: bit::test(auxv.hwcap, 21),
             sve: bit::test(auxv.hwcap, 22),
+            // asimdfhm: bit::test(auxv.hwcap, 23),
+            // dit: bit::test(auxv.hwcap, 24),
+            // uscat: bit::test(auxv.hwcap, 25),
+            // ilrcpc: bit::test(auxv.hwcap, 26),
+            // flagm: bit::test(auxv.hwcap, 27),
+            // ssbs: bit::test(auxv.hwcap, 28),
+            // sb: bit::test(auxv.hwcap, 29),
+            paca: bit::test(auxv.hwcap, 30),
+            pacg: bit::test(auxv.hwcap, 31),
+
+            bti: bit::test(auxv.hwcap2, 17),
+            mte: bit::test(auxv.hwcap2, 18),
         }
     }
 }
@@ -114,6 +148,18 @@ impl From<cpuinfo::CpuInfo> for AtHwcap {
             asimddp: f.has("asimddp"),
             // sha512: f.has("sha512"),
             sve: f.has("sve"),
+            // asimdfhm: f.has("asimdfhm"),
+            // dit: f.has("dit"),
+            // uscat: f.has("uscat"),
+            // ilrcpc: f.has("ilrcpc"),
+            // flagm: f.has("flagm"),
+            // ssbs: f.has("ssbs"),
+            // sb: f.has("sb"),
+            paca: f.has("paca"),
+            pacg: f.has("pacg"),
+
+            bti: f.has("bti"),
+            mte: f.has("mte"),
         }
     }
 }
@@ -123,7 +169,10 @@ impl AtHwcap {
     ///
     /// The features are enabled approximately like in LLVM host feature detection:
     /// https://github.com/llvm-mirror/llvm/blob/master/lib/Support/Host.cpp#L1273
-    fn cache(self) -> cache::Initializer {
+    ///
+    /// The features that are disabled because a feature they depend on is
+    /// missing are passed to `r`.
+    fn cache<R: Recorder>(self, r: &mut R) -> cache::Initializer {
         let mut value = cache::Initializer::default();
         {
             let mut enable_feature = |f, enable| {
@@ -135,6 +184,9 @@ impl AtHwcap {
             enable_feature(Feature::fp, self.fp);
             // Half-float support requires float support
             enable_feature(Feature::fp16, self.fp && self.fphp);
+            if self.fphp && !self.fp {
+                r.veto(Feature::fp16, "requires fp");
+            }
             enable_feature(Feature::pmull, self.pmull);
             enable_feature(Feature::crc, self.crc32);
             enable_feature(Feature::lse, self.atomics);
@@ -144,14 +196,156 @@ impl AtHwcap {
             // supported, it also requires half-float support:
             let asimd = self.fp && self.asimd && (!self.fphp | self.asimdhp);
             enable_feature(Feature::asimd, asimd);
+            if self.asimd && !asimd {
+                r.veto(Feature::asimd, "requires fp, and asimdhp if fphp is supported");
+            }
             // SIMD extensions require SIMD support:
             enable_feature(Feature::rdm, self.asimdrdm && asimd);
             enable_feature(Feature::dotprod, self.asimddp && asimd);
             enable_feature(Feature::sve, self.sve && asimd);
+            if !asimd {
+                let mut veto = |f, supported| {
+                    if supported {
+                        r.veto(f, "requires asimd");
+                    }
+                };
+                veto(Feature::rdm, self.asimdrdm);
+                veto(Feature::dotprod, self.asimddp);
+                veto(Feature::sve, self.sve);
+            }
 
             // Crypto is specified as AES + PMULL + SHA1 + SHA2 per LLVM/hosts.cpp
             enable_feature(Feature::crypto, self.aes && self.pmull && self.sha1 && self.sha2);
+
+            // Security features:
+            enable_feature(Feature::paca, self.paca);
+            enable_feature(Feature::pacg, self.pacg);
+            enable_feature(Feature::bti, self.bti);
+            enable_feature(Feature::mte, self.mte);
         }
         value
     }
 }
+
+/// `prctl` option that reads the tagged address ABI control word of the
+/// calling process, see [linux/prctl.h][prctl_h].
+///
+/// [prctl_h]: https://github.com/torvalds/linux/blob/master/include/uapi/linux/prctl.h
+#[cfg(feature = "libc")]
+const PR_GET_TAGGED_ADDR_CTRL: libc::c_int = 56;
+#[cfg(feature = "libc")]
+const PR_TAGGED_ADDR_ENABLE: u64 = 1 << 0;
+#[cfg(feature = "libc")]
+const PR_MTE_TCF_SHIFT: u64 = 1;
+#[cfg(feature = "libc")]
+const PR_MTE_TCF_MASK: u64 = 3 << PR_MTE_TCF_SHIFT;
+#[cfg(feature = "libc")]
+const PR_MTE_TAG_SHIFT: u64 = 3;
+#[cfg(feature = "libc")]
+const PR_MTE_TAG_MASK: u64 = 0xffff << PR_MTE_TAG_SHIFT;
+
+/// MTE tag check fault mode of the calling thread.
+#[cfg(feature = "libc")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MteTagCheckFault {
+    /// Tag check faults are ignored.
+    None,
+    /// Tag check faults are reported synchronously via `SIGSEGV`.
+    Sync,
+    /// Tag check faults are accumulated and reported asynchronously.
+    Async,
+    /// Both modes were requested; the kernel picks one per CPU
+    /// (Linux >= 5.18).
+    Both,
+}
+
+/// Tagged address ABI state of the calling thread, as reported by
+/// `prctl(PR_GET_TAGGED_ADDR_CTRL)`.
+#[cfg(feature = "libc")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TaggedAddrCtrl(u64);
+
+#[cfg(feature = "libc")]
+impl TaggedAddrCtrl {
+    /// Is the tagged address ABI enabled, that is, may user-space pass
+    /// pointers with a non-zero top byte to system calls?
+    #[inline]
+    pub fn tagged_addr_enabled(self) -> bool {
+        self.0 & PR_TAGGED_ADDR_ENABLE != 0
+    }
+
+    /// MTE tag check fault mode.
+    #[inline]
+    pub fn mte_tcf(self) -> MteTagCheckFault {
+        match (self.0 & PR_MTE_TCF_MASK) >> PR_MTE_TCF_SHIFT {
+            0 => MteTagCheckFault::None,
+            1 => MteTagCheckFault::Sync,
+            2 => MteTagCheckFault::Async,
+            _ => MteTagCheckFault::Both,
+        }
+    }
+
+    /// Mask of the tags that `IRG` is allowed to generate.
+    #[inline]
+    pub fn mte_tag_mask(self) -> u16 {
+        ((self.0 & PR_MTE_TAG_MASK) >> PR_MTE_TAG_SHIFT) as u16
+    }
+
+    /// The raw control word returned by the kernel.
+    #[inline]
+    pub fn bits(self) -> u64 {
+        self.0
+    }
+}
+
+/// Queries the tagged address ABI state of the calling thread.
+///
+/// Unlike the features above, this is not cached: the state is per-thread and
+/// can be changed at any time with `prctl(PR_SET_TAGGED_ADDR_CTRL)`.
+///
+/// Returns `None` if the kernel does not support the tagged address ABI
+/// (Linux < 5.4, or `CONFIG_ARM64_TAGGED_ADDR_ABI` disabled).
+#[cfg(feature = "libc")]
+#[inline]
+pub fn tagged_addr_ctrl() -> Option<TaggedAddrCtrl> {
+    // `prctl` is variadic: the unused arguments must be passed as zeroed
+    // `unsigned long`s, or the kernel may see garbage and return `EINVAL`.
+    let zero = 0 as libc::c_ulong;
+    let r = unsafe {
+        libc::prctl(PR_GET_TAGGED_ADDR_CTRL, zero, zero, zero, zero)
+    };
+    if r < 0 {
+        None
+    } else {
+        Some(TaggedAddrCtrl(r as u64))
+    }
+}
+
+#[cfg(all(test, feature = "libc"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tagged_addr_ctrl_bits() {
+        let ctrl = TaggedAddrCtrl(0);
+        assert!(!ctrl.tagged_addr_enabled());
+        assert_eq!(ctrl.mte_tcf(), MteTagCheckFault::None);
+        assert_eq!(ctrl.mte_tag_mask(), 0);
+
+        // PR_TAGGED_ADDR_ENABLE | PR_MTE_TCF_SYNC | (0xfffe << PR_MTE_TAG_SHIFT)
+        let ctrl = TaggedAddrCtrl(1 | (1 << 1) | (0xfffe << 3));
+        assert!(ctrl.tagged_addr_enabled());
+        assert_eq!(ctrl.mte_tcf(), MteTagCheckFault::Sync);
+        assert_eq!(ctrl.mte_tag_mask(), 0xfffe);
+
+        let ctrl = TaggedAddrCtrl(1 | (2 << 1));
+        assert_eq!(ctrl.mte_tcf(), MteTagCheckFault::Async);
+        let ctrl = TaggedAddrCtrl(1 | (3 << 1));
+        assert_eq!(ctrl.mte_tcf(), MteTagCheckFault::Both);
+    }
+
+    #[test]
+    fn dump() {
+        println!("tagged_addr_ctrl: {:?}", tagged_addr_ctrl());
+    }
+}
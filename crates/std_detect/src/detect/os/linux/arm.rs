@@ -1,17 +1,18 @@
 //! Run-time feature detection for ARM on Linux.
 
 use crate::detect::{Feature, cache, bit};
+use crate::detect::report::Recorder;
 use super::{auxvec, cpuinfo};
 
 /// Performs run-time feature detection.
 #[inline]
 pub fn check_for(x: Feature) -> bool {
-    cache::test(x as u32, detect_features)
+    cache::test(x as u32, || detect_features_with(&mut ()))
 }
 
 /// Try to read the features from the auxiliary vector, and if that fails, try
-/// to read them from /proc/cpuinfo.
-fn detect_features() -> cache::Initializer {
+/// to read them from /proc/cpuinfo. The raw values are passed to `r`.
+pub(crate) fn detect_features_with<R: Recorder>(r: &mut R) -> cache::Initializer {
     let mut value = cache::Initializer::default();
     let enable_feature = |value: &mut cache::Initializer, f, enable| {
         if enable {
@@ -23,14 +24,24 @@ fn detect_features() -> cache::Initializer {
     //
     // [hwcap]: https://github.com/torvalds/linux/blob/master/arch/arm64/include/uapi/asm/hwcap.h
     if let Ok(auxv) = auxvec::auxv() {
+        r.source(format_args!("AT_HWCAP"), format_args!("{:#x}", auxv.hwcap));
+        r.source(format_args!("AT_HWCAP2"), format_args!("{:#x}", auxv.hwcap2));
         enable_feature(&mut value, Feature::neon, bit::test(auxv.hwcap, 12));
         enable_feature(&mut value, Feature::pmull, bit::test(auxv.hwcap2, 1));
         return value;
     }
 
     if let Ok(c) = cpuinfo::CpuInfo::new() {
+        r.source(
+            format_args!("/proc/cpuinfo Features"),
+            format_args!("{}", c.field("Features").value()),
+        );
+        let broken_neon = has_broken_neon(&c);
+        if broken_neon && c.field("Features").has("neon") {
+            r.veto(Feature::neon, "the CPU is known to have a broken NEON unit");
+        }
         enable_feature(&mut value, Feature::neon, c.field("Features").has("neon") &&
-            !has_broken_neon(&c));
+            !broken_neon);
         enable_feature(&mut value, Feature::pmull, c.field("Features").has("pmull"));
         return value;
     }
@@ -7,7 +7,8 @@ use crate::{fs::File, io::Read};
 /// Key to access the CPU Hardware capabilities bitfield.
 pub(crate) const AT_HWCAP: usize = 16;
 /// Key to access the CPU Hardware capabilities 2 bitfield.
-#[cfg(any(target_arch = "arm", target_arch = "powerpc64"))]
+#[cfg(any(target_arch = "aarch64", target_arch = "arm",
+          target_arch = "powerpc64"))]
 pub(crate) const AT_HWCAP2: usize = 26;
 
 /// Cache HWCAP bitfields of the ELF Auxiliary Vector.
@@ -17,7 +18,8 @@ pub(crate) const AT_HWCAP2: usize = 26;
 #[derive(Debug, Copy, Clone)]
 pub(crate) struct AuxVec {
     pub hwcap: usize,
-    #[cfg(any(target_arch = "arm", target_arch = "powerpc64"))]
+    #[cfg(any(target_arch = "aarch64", target_arch = "arm",
+              target_arch = "powerpc64"))]
     pub hwcap2: usize,
 }
 
@@ -55,14 +57,24 @@ pub(crate) fn auxv() -> Result<AuxVec, ()> {
         // Try to call a dynamically-linked getauxval function.
         if let Ok(hwcap) = getauxval(AT_HWCAP) {
             // Targets with only AT_HWCAP:
-            #[cfg(any(target_arch = "aarch64", target_arch = "mips",
-                      target_arch = "mips64"))]
+            #[cfg(any(target_arch = "mips", target_arch = "mips64"))]
             {
                 if hwcap != 0 {
                     return Ok(AuxVec { hwcap });
                 }
             }
 
+            // Targets with AT_HWCAP and an optional AT_HWCAP2. Kernels older
+            // than 4.18 do not provide AT_HWCAP2 on aarch64, in which case it
+            // reads as zero:
+            #[cfg(target_arch = "aarch64")]
+            {
+                if hwcap != 0 {
+                    let hwcap2 = getauxval(AT_HWCAP2).unwrap_or(0);
+                    return Ok(AuxVec { hwcap, hwcap2 });
+                }
+            }
+
             // Targets with AT_HWCAP and AT_HWCAP2:
             #[cfg(any(target_arch = "arm", target_arch = "powerpc64"))]
             {
@@ -88,14 +100,22 @@ pub(crate) fn auxv() -> Result<AuxVec, ()> {
         let hwcap = unsafe { ffi_getauxval(AT_HWCAP) };
 
         // Targets with only AT_HWCAP:
-        #[cfg(any(target_arch = "aarch64", target_arch = "mips",
-                  target_arch = "mips64"))]
+        #[cfg(any(target_arch = "mips", target_arch = "mips64"))]
         {
             if hwcap != 0 {
                 return Ok(AuxVec { hwcap });
             }
         }
 
+        // Targets with AT_HWCAP and an optional AT_HWCAP2:
+        #[cfg(target_arch = "aarch64")]
+        {
+            if hwcap != 0 {
+                let hwcap2 = unsafe { ffi_getauxval(AT_HWCAP2) };
+                return Ok(AuxVec { hwcap, hwcap2 });
+            }
+        }
+
         // Targets with AT_HWCAP and AT_HWCAP2:
         #[cfg(any(target_arch = "arm", target_arch = "powerpc64"))]
         {
@@ -153,8 +173,7 @@ fn auxv_from_file(file: &str) -> Result<AuxVec, ()> {
 #[cfg(feature = "std_detect_file_io")]
 fn auxv_from_buf(buf: &[usize; 64]) -> Result<AuxVec, ()> {
     // Targets with only AT_HWCAP:
-    #[cfg(any(target_arch = "aarch64", target_arch = "mips",
-              target_arch = "mips64"))]
+    #[cfg(any(target_arch = "mips", target_arch = "mips64"))]
     {
         for el in buf.chunks(2) {
             match el[0] {
@@ -163,6 +182,23 @@ fn auxv_from_buf(buf: &[usize; 64]) -> Result<AuxVec, ()> {
             }
         }
     }
+    // Targets with AT_HWCAP and an optional AT_HWCAP2:
+    #[cfg(target_arch = "aarch64")]
+    {
+        let mut hwcap = None;
+        let mut hwcap2 = 0;
+        for el in buf.chunks(2) {
+            match el[0] {
+                AT_HWCAP => hwcap = Some(el[1]),
+                AT_HWCAP2 => hwcap2 = el[1],
+                _ => (),
+            }
+        }
+
+        if let Some(hwcap) = hwcap {
+            return Ok(AuxVec { hwcap, hwcap2 });
+        }
+    }
     // Targets with AT_HWCAP and AT_HWCAP2:
     #[cfg(any(target_arch = "arm", target_arch = "powerpc64"))]
     {
@@ -227,7 +263,8 @@ mod tests {
         }
 
         // Targets with AT_HWCAP and AT_HWCAP2:
-        #[cfg(any(target_arch = "arm", target_arch = "powerpc64"))]
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm",
+                  target_arch = "powerpc64"))]
         {
             if let Some(hwcap2) = auxv_crate_getauxval(AT_HWCAP2) {
                 let rt_hwcap2 = v.expect("failed to find hwcap2 key").hwcap2;
@@ -297,7 +334,8 @@ mod tests {
         }
 
         // Targets with AT_HWCAP and AT_HWCAP2:
-        #[cfg(any(target_arch = "arm", target_arch = "powerpc64"))]
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm",
+                  target_arch = "powerpc64"))]
         {
             if let Some(hwcap2) = auxv_crate_getprocfs(AT_HWCAP2) {
                 assert_eq!(v.unwrap().hwcap2, hwcap2);
@@ -61,6 +61,10 @@ impl<'a> CpuInfoField<'a> {
             Some(f) => CpuInfoField::<'b>(Some(f.trim())),
         }
     }
+    /// Returns the value of the field, or `""` if it does not exist.
+    pub(crate) fn value(&self) -> &'a str {
+        self.0.unwrap_or("")
+    }
     /// Does the field exist?
     #[cfg(test)]
     pub(crate) fn exists(&self) -> bool {
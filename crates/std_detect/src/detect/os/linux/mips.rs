@@ -1,17 +1,18 @@
 //! Run-time feature detection for MIPS on Linux.
 
 use crate::detect::{Feature, cache, bit};
+use crate::detect::report::Recorder;
 use super::auxvec;
 
 /// Performs run-time feature detection.
 #[inline]
 pub fn check_for(x: Feature) -> bool {
-    cache::test(x as u32, detect_features)
+    cache::test(x as u32, || detect_features_with(&mut ()))
 }
 
 /// Try to read the features from the auxiliary vector, and if that fails, try
-/// to read them from `/proc/cpuinfo`.
-fn detect_features() -> cache::Initializer {
+/// to read them from `/proc/cpuinfo`. The raw values are passed to `r`.
+pub(crate) fn detect_features_with<R: Recorder>(r: &mut R) -> cache::Initializer {
     let mut value = cache::Initializer::default();
     let enable_feature = |value: &mut cache::Initializer, f, enable| {
         if enable {
@@ -23,6 +24,7 @@ fn detect_features() -> cache::Initializer {
     //
     // [hwcap]: https://github.com/torvalds/linux/blob/master/arch/arm64/include/uapi/asm/hwcap.h
     if let Ok(auxv) = auxvec::auxv() {
+        r.source(format_args!("AT_HWCAP"), format_args!("{:#x}", auxv.hwcap));
         enable_feature(&mut value, Feature::msa, bit::test(auxv.hwcap, 1));
         return value;
     }
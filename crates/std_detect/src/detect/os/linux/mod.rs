@@ -9,20 +9,33 @@ cfg_if! {
     if #[cfg(target_arch = "aarch64")] {
         mod aarch64;
         pub use self::aarch64::check_for;
+        pub(crate) use self::aarch64::detect_features_with;
+        #[cfg(feature = "libc")]
+        pub use self::aarch64::{tagged_addr_ctrl, TaggedAddrCtrl, MteTagCheckFault};
     } else if #[cfg(target_arch = "arm")] {
         mod arm;
         pub use self::arm::check_for;
+        pub(crate) use self::arm::detect_features_with;
     } else  if #[cfg(any(target_arch = "mips", target_arch = "mips64"))] {
         mod mips;
         pub use self::mips::check_for;
+        pub(crate) use self::mips::detect_features_with;
     } else if #[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))] {
         mod powerpc;
         pub use self::powerpc::check_for;
+        pub(crate) use self::powerpc::detect_features_with;
     } else {
-        use crate::detect::Feature;
+        use crate::detect::{Feature, cache};
+        use crate::detect::report::Recorder;
         /// Performs run-time feature detection.
         pub fn check_for(_x: Feature) -> bool {
             false
         }
+        /// Performs run-time feature detection, which detects nothing.
+        pub(crate) fn detect_features_with<R: Recorder>(
+            _r: &mut R,
+        ) -> cache::Initializer {
+            cache::Initializer::default()
+        }
     }
 }
@@ -1,17 +1,18 @@
 //! Run-time feature detection for PowerPC on Linux.
 
 use crate::detect::{Feature, cache};
+use crate::detect::report::Recorder;
 use super::{auxvec, cpuinfo};
 
 /// Performs run-time feature detection.
 #[inline]
 pub fn check_for(x: Feature) -> bool {
-    cache::test(x as u32, detect_features)
+    cache::test(x as u32, || detect_features_with(&mut ()))
 }
 
 /// Try to read the features from the auxiliary vector, and if that fails, try
-/// to read them from /proc/cpuinfo.
-fn detect_features() -> cache::Initializer {
+/// to read them from /proc/cpuinfo. The raw values are passed to `r`.
+pub(crate) fn detect_features_with<R: Recorder>(r: &mut R) -> cache::Initializer {
     let mut value = cache::Initializer::default();
     let enable_feature = |value: &mut cache::Initializer, f, enable| {
         if enable {
@@ -23,6 +24,7 @@ fn detect_features() -> cache::Initializer {
     //
     // [cputable]: https://github.com/torvalds/linux/blob/master/arch/powerpc/include/uapi/asm/cputable.h
     if let Ok(auxv) = auxvec::auxv() {
+        r.source(format_args!("AT_HWCAP"), format_args!("{:#x}", auxv.hwcap));
         // note: the PowerPC values are the mask to do the test (instead of the
         // index of the bit to test like in ARM and Aarch64)
         enable_feature(&mut value, Feature::altivec, auxv.hwcap & 0x10000000 != 0);
@@ -34,6 +36,10 @@ fn detect_features() -> cache::Initializer {
     // PowerPC's /proc/cpuinfo lacks a proper Feature field,
     // but `altivec` support is indicated in the `cpu` field.
     if let Ok(c) = cpuinfo::CpuInfo::new() {
+        r.source(
+            format_args!("/proc/cpuinfo cpu"),
+            format_args!("{}", c.field("cpu").value()),
+        );
         enable_feature(&mut value, Feature::altivec, c.field("cpu").has("altivec"));
         return value;
     }
@@ -1,9 +1,17 @@
 //! Other operating systems
 
-use crate::detect::Feature;
+use crate::detect::{Feature, cache};
+use crate::detect::report::Recorder;
 
 /// Performs run-time feature detection.
 #[inline]
 pub fn check_for(_x: Feature) -> bool {
     false
 }
+
+/// Performs run-time feature detection, which detects nothing.
+pub(crate) fn detect_features_with<R: Recorder>(
+    _r: &mut R,
+) -> cache::Initializer {
+    cache::Initializer::default()
+}
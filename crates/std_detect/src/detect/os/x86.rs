@@ -6,17 +6,83 @@ use crate::arch::x86::*;
 use crate::arch::x86_64::*;
 
 use crate::mem;
+use core::str;
 
 use crate::detect::{Feature, cache, bit};
+use crate::detect::report::Recorder;
 
 /// Performs run-time feature detection.
 #[inline]
 pub fn check_for(x: Feature) -> bool {
-    cache::test(x as u32, detect_features)
+    cache::test(x as u32, || detect_features_with(&mut ()))
+}
+
+/// The processor the features are detected on.
+///
+/// This only abstracts over the processor so that the detection logic can be
+/// tested with made-up CPUID values.
+trait Cpu {
+    /// Executes `cpuid`.
+    fn cpuid(&mut self, leaf: u32, sub_leaf: u32) -> CpuidResult;
+
+    /// Reads `XCR0`. Only called if the CPU supports `xsave` and the OS has
+    /// set `osxsave`.
+    fn xcr0(&mut self) -> u64;
+}
+
+/// The processor the calling thread runs on, which must support the `cpuid`
+/// instruction.
+struct CurrentCpu;
+
+impl Cpu for CurrentCpu {
+    #[inline]
+    fn cpuid(&mut self, leaf: u32, sub_leaf: u32) -> CpuidResult {
+        // This is safe because a `CurrentCpu` is only created after checking
+        // that the CPU has `cpuid` support.
+        unsafe { __cpuid_count(leaf, sub_leaf) }
+    }
+
+    #[inline]
+    fn xcr0(&mut self) -> u64 {
+        // This is safe because the CPU supports `xsave` and the OS has set
+        // `osxsave`.
+        unsafe { _xgetbv(0) }
+    }
+}
+
+/// Executes `cpuid` on `cpu` and records its result.
+#[inline]
+fn cpuid<C: Cpu, R: Recorder>(
+    cpu: &mut C,
+    r: &mut R,
+    leaf: u32,
+    sub_leaf: u32,
+) -> CpuidResult {
+    let res = cpu.cpuid(leaf, sub_leaf);
+    r.source(
+        format_args!("cpuid({:#x}, {:#x})", leaf, sub_leaf),
+        format_args!(
+            "eax={:#010x} ebx={:#010x} ecx={:#010x} edx={:#010x}",
+            res.eax, res.ebx, res.ecx, res.edx
+        ),
+    );
+    res
 }
 
 /// Run-time feature detection on x86 works by using the CPUID instruction.
 ///
+/// The features are read from the CPUID leaves of whatever core the calling
+/// thread happens to run on, and are then cached for the whole process. On
+/// hybrid processors (e.g. Alder Lake), the shipping parts report the same
+/// CPUID leaf 1 and leaf 7 feature flags on all their cores, so the result
+/// does not depend on the core type.
+///
+/// AVX-512 is nevertheless never reported on processors with the hybrid flag
+/// set. This is a deliberately conservative policy: the P-cores of some
+/// early parts could execute AVX-512 when the E-cores were disabled, and
+/// nothing guarantees that future hybrid parts implement it on every core.
+/// See [`hybrid_info`] for querying the per-core information.
+///
 /// The [CPUID Wikipedia page][wiki_cpuid] contains
 /// all the information about which flags to set to query which values, and in
 /// which registers these are reported.
@@ -27,42 +93,59 @@ pub fn check_for(x: Feature) -> bool {
 /// - [AMD64 Architecture Programmer's Manual, Volume 3: General-Purpose and
 ///   System Instructions][amd64_ref].
 ///
+/// The raw CPUID values and the features that are disabled despite the CPU
+/// supporting them are passed to `r`.
+///
 /// [wiki_cpuid]: https://en.wikipedia.org/wiki/CPUID
 /// [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
 /// [amd64_ref]: http://support.amd.com/TechDocs/24594.pdf
-#[cfg_attr(feature = "cargo-clippy", allow(clippy::similar_names))]
-fn detect_features() -> cache::Initializer {
-    let mut value = cache::Initializer::default();
-
+pub(crate) fn detect_features_with<R: Recorder>(
+    r: &mut R,
+) -> cache::Initializer {
     // If the x86 CPU does not support the CPUID instruction then it is too
     // old to support any of the currently-detectable features.
     if !has_cpuid() {
-        return value;
+        r.source(format_args!("cpuid"), format_args!("not supported"));
+        return cache::Initializer::default();
     }
 
-    // Calling `__cpuid`/`__cpuid_count` from here on is safe because the CPU
-    // has `cpuid` support.
+    detect_features_on(&mut CurrentCpu, r)
+}
+
+/// Detects the features of `cpu`, see `detect_features_with`.
+#[cfg_attr(feature = "cargo-clippy", allow(clippy::similar_names))]
+fn detect_features_on<C: Cpu, R: Recorder>(
+    cpu: &mut C,
+    r: &mut R,
+) -> cache::Initializer {
+    let mut value = cache::Initializer::default();
 
     // 0. EAX = 0: Basic Information:
     // - EAX returns the "Highest Function Parameter", that is, the maximum
     // leaf value for subsequent calls of `cpuinfo` in range [0,
     // 0x8000_0000]. - The vendor ID is stored in 12 u8 ascii chars,
     // returned in EBX, EDX, and   ECX (in that order):
-    let (max_basic_leaf, vendor_id) = unsafe {
+    let (max_basic_leaf, vendor_id) = {
         let CpuidResult {
             eax: max_basic_leaf,
             ebx,
             ecx,
             edx,
-        } = __cpuid(0);
-        let vendor_id: [[u8; 4]; 3] = [
-            mem::transmute(ebx),
-            mem::transmute(edx),
-            mem::transmute(ecx),
-        ];
-        let vendor_id: [u8; 12] = mem::transmute(vendor_id);
+        } = cpuid(cpu, r, 0, 0);
+        let vendor_id: [[u8; 4]; 3] = unsafe {
+            [
+                mem::transmute(ebx),
+                mem::transmute(edx),
+                mem::transmute(ecx),
+            ]
+        };
+        let vendor_id: [u8; 12] = unsafe { mem::transmute(vendor_id) };
         (max_basic_leaf, vendor_id)
     };
+    r.source(
+        format_args!("vendor"),
+        format_args!("{}", str::from_utf8(&vendor_id).unwrap_or("?")),
+    );
 
     if max_basic_leaf < 1 {
         // Earlier Intel 486, CPUID not implemented
@@ -75,35 +158,111 @@ fn detect_features() -> cache::Initializer {
         ecx: proc_info_ecx,
         edx: proc_info_edx,
         ..
-    } = unsafe { __cpuid(0x0000_0001_u32) };
+    } = cpuid(cpu, r, 0x0000_0001_u32, 0);
 
     // EAX = 7, ECX = 0: Queries "Extended Features";
     // Contains information about bmi,bmi2, and avx2 support.
-    let (extended_features_ebx, extended_features_ecx) = if max_basic_leaf >= 7
-    {
-        let CpuidResult { ebx, ecx, .. } = unsafe { __cpuid(0x0000_0007_u32) };
-        (ebx, ecx)
+    // - EAX returns the maximum sub-leaf of leaf 7.
+    let (
+        extended_features_max_sub_leaf,
+        extended_features_ebx,
+        extended_features_ecx,
+        extended_features_edx,
+    ) = if max_basic_leaf >= 7 {
+        let CpuidResult { eax, ebx, ecx, edx } =
+            cpuid(cpu, r, 0x0000_0007_u32, 0);
+        (eax, ebx, ecx, edx)
     } else {
-        (0, 0) // CPUID does not support "Extended Features"
+        (0, 0, 0, 0) // CPUID does not support "Extended Features"
     };
 
+    // EAX = 7, ECX = 1: Queries "Extended Features" sub-leaf 1;
+    // Contains information about avxvnni and avx512bf16 support.
+    let extended_features_eax_leaf_1 =
+        if max_basic_leaf >= 7 && extended_features_max_sub_leaf >= 1 {
+            let CpuidResult { eax, .. } =
+                cpuid(cpu, r, 0x0000_0007_u32, 1);
+            eax
+        } else {
+            0
+        };
+
+    // Hybrid processors mix cores of different types, see `hybrid_info`:
+    let cpu_hybrid = bit::test(extended_features_edx as usize, 15);
+
     // EAX = 0x8000_0000, ECX = 0: Get Highest Extended Function Supported
     // - EAX returns the max leaf value for extended information, that is,
     // `cpuid` calls in range [0x8000_0000; u32::MAX]:
     let CpuidResult {
         eax: extended_max_basic_leaf,
         ..
-    } = unsafe { __cpuid(0x8000_0000_u32) };
+    } = cpuid(cpu, r, 0x8000_0000_u32, 0);
 
     // EAX = 0x8000_0001, ECX=0: Queries "Extended Processor Info and Feature
     // Bits"
     let extended_proc_info_ecx = if extended_max_basic_leaf >= 1 {
-        let CpuidResult { ecx, .. } = unsafe { __cpuid(0x8000_0001_u32) };
+        let CpuidResult { ecx, .. } = cpuid(cpu, r, 0x8000_0001_u32, 0);
         ecx
     } else {
         0
     };
 
+    // EAX = 0x8000_0008, ECX=0: Queries "Processor Capacity Parameters and
+    // Extended Feature Identification"; EBX contains extended feature flags
+    // like `clzero` and `rdpru` (AMD only) and `wbnoinvd`.
+    let extended_capabilities_ebx =
+        if extended_max_basic_leaf >= 0x8000_0008_u32 {
+            let CpuidResult { ebx, .. } =
+                cpuid(cpu, r, 0x8000_0008_u32, 0);
+            ebx
+        } else {
+            0
+        };
+
+    let is_amd = vendor_id == *b"AuthenticAMD";
+
+    // Features that use the 256-bit wide registers, and therefore require
+    // the OS to save the AVX state:
+    let amd_extended_proc_info_ecx =
+        if is_amd { extended_proc_info_ecx } else { 0 };
+    let avx_features = [
+        // FMA:
+        (proc_info_ecx, 12, Feature::fma),
+        // F16C:
+        (proc_info_ecx, 29, Feature::f16c),
+        // XOP and FMA4 (AMD only):
+        (amd_extended_proc_info_ecx, 11, Feature::xop),
+        (amd_extended_proc_info_ecx, 16, Feature::fma4),
+        // And AVX/AVX2:
+        (proc_info_ecx, 28, Feature::avx),
+        (extended_features_ebx, 5, Feature::avx2),
+        // VAES and VPCLMULQDQ:
+        (extended_features_ecx, 9, Feature::vaes),
+        (extended_features_ecx, 10, Feature::vpclmulqdq),
+        // AVX-VNNI (VEX-encoded VNNI):
+        (extended_features_eax_leaf_1, 4, Feature::avxvnni),
+    ];
+
+    // Features that use the 512-bit wide registers, and therefore require
+    // the OS to save the AVX-512 state:
+    let avx512_features = [
+        (extended_features_ebx, 16, Feature::avx512f),
+        (extended_features_ebx, 17, Feature::avx512dq),
+        (extended_features_ebx, 21, Feature::avx512_ifma),
+        (extended_features_ebx, 26, Feature::avx512pf),
+        (extended_features_ebx, 27, Feature::avx512er),
+        (extended_features_ebx, 28, Feature::avx512cd),
+        (extended_features_ebx, 30, Feature::avx512bw),
+        (extended_features_ebx, 31, Feature::avx512vl),
+        (extended_features_ecx, 1, Feature::avx512_vbmi),
+        (extended_features_ecx, 6, Feature::avx512_vbmi2),
+        (extended_features_ecx, 11, Feature::avx512_vnni),
+        (extended_features_ecx, 12, Feature::avx512_bitalg),
+        (extended_features_eax_leaf_1, 5, Feature::avx512_bf16),
+        (extended_features_edx, 23, Feature::avx512_fp16),
+        (extended_features_ecx, 14, Feature::avx512_vpopcntdq),
+    ];
+
     {
         // borrows value till the end of this scope:
         let mut enable = |r, rb, f| {
@@ -111,6 +270,14 @@ fn detect_features() -> cache::Initializer {
                 value.set(f as u32);
             }
         };
+        // Records the features that the CPU supports but that are disabled:
+        let veto = |r: &mut R, features: &[(u32, u32, Feature)], reason| {
+            for &(reg, rb, f) in features {
+                if bit::test(reg as usize, rb) {
+                    r.veto(f, reason);
+                }
+            }
+        };
 
         enable(proc_info_ecx, 0, Feature::sse3);
         enable(proc_info_ecx, 9, Feature::ssse3);
@@ -129,6 +296,7 @@ fn detect_features() -> cache::Initializer {
         enable(proc_info_edx, 25, Feature::sse);
         enable(proc_info_edx, 26, Feature::sse2);
         enable(extended_features_ebx, 29, Feature::sha);
+        enable(extended_features_ecx, 8, Feature::gfni);
 
         enable(extended_features_ebx, 3, Feature::bmi);
         enable(extended_features_ebx, 8, Feature::bmi2);
@@ -158,10 +326,8 @@ fn detect_features() -> cache::Initializer {
                 // * AVX-512 -> `XCR0.AVX-512[7:5]`.
                 //
                 // by setting the corresponding bits of `XCR0` to `1`.
-                //
-                // This is safe because the CPU supports `xsave`
-                // and the OS has set `osxsave`.
-                let xcr0 = unsafe { _xgetbv(0) };
+                let xcr0 = cpu.xcr0();
+                r.source(format_args!("xcr0"), format_args!("{:#x}", xcr0));
                 // Test `XCR0.SSE[1]` and `XCR0.AVX[2]` with the mask `0b110 == 6`:
                 let os_avx_support = xcr0 & 6 == 6;
                 // Test `XCR0.AVX-512[7:5]` with the mask `0b1110_0000 == 224`:
@@ -189,39 +355,53 @@ fn detect_features() -> cache::Initializer {
                         let CpuidResult {
                             eax: proc_extended_state1_eax,
                             ..
-                        } = unsafe { __cpuid_count(0xd_u32, 1) };
+                        } = cpuid(cpu, r, 0xd_u32, 1);
                         enable(proc_extended_state1_eax, 0, Feature::xsaveopt);
                         enable(proc_extended_state1_eax, 1, Feature::xsavec);
                         enable(proc_extended_state1_eax, 3, Feature::xsaves);
                     }
 
-                    // FMA (uses 256-bit wide registers):
-                    enable(proc_info_ecx, 12, Feature::fma);
-
-                    // And AVX/AVX2:
-                    enable(proc_info_ecx, 28, Feature::avx);
-                    enable(extended_features_ebx, 5, Feature::avx2);
+                    for &(reg, rb, f) in &avx_features {
+                        enable(reg, rb, f);
+                    }
 
                     // For AVX-512 the OS also needs to support saving/restoring
-                    // the extended state, only then we enable AVX-512 support:
-                    if os_avx512_support {
-                        enable(extended_features_ebx, 16, Feature::avx512f);
-                        enable(extended_features_ebx, 17, Feature::avx512dq);
-                        enable(extended_features_ebx, 21, Feature::avx512_ifma);
-                        enable(extended_features_ebx, 26, Feature::avx512pf);
-                        enable(extended_features_ebx, 27, Feature::avx512er);
-                        enable(extended_features_ebx, 28, Feature::avx512cd);
-                        enable(extended_features_ebx, 30, Feature::avx512bw);
-                        enable(extended_features_ebx, 31, Feature::avx512vl);
-                        enable(extended_features_ecx, 1, Feature::avx512_vbmi);
-                        enable(
-                            extended_features_ecx,
-                            14,
-                            Feature::avx512_vpopcntdq,
+                    // the extended state, only then we enable AVX-512 support.
+                    // As a conservative policy, it is never enabled on hybrid
+                    // processors, see above:
+                    if !os_avx512_support {
+                        veto(
+                            r,
+                            &avx512_features,
+                            "the OS does not save the AVX-512 state (XCR0[7:5])",
+                        );
+                    } else if cpu_hybrid {
+                        veto(
+                            r,
+                            &avx512_features,
+                            "AVX-512 is never reported on hybrid processors (conservative policy)",
                         );
+                    } else {
+                        for &(reg, rb, f) in &avx512_features {
+                            enable(reg, rb, f);
+                        }
                     }
+                } else {
+                    let reason = "the OS does not save the AVX state (XCR0[2:1])";
+                    veto(r, &[(proc_info_ecx, 26, Feature::xsave)], reason);
+                    veto(r, &avx_features, reason);
+                    veto(r, &avx512_features, reason);
                 }
+            } else {
+                let reason = "the OS has not enabled XSAVE (OSXSAVE)";
+                veto(r, &[(proc_info_ecx, 26, Feature::xsave)], reason);
+                veto(r, &avx_features, reason);
+                veto(r, &avx512_features, reason);
             }
+        } else {
+            let reason = "the CPU does not support XSAVE";
+            veto(r, &avx_features, reason);
+            veto(r, &avx512_features, reason);
         }
 
         // This detects ABM on AMD CPUs and LZCNT on Intel CPUs.
@@ -232,24 +412,280 @@ fn detect_features() -> cache::Initializer {
         // The `is_x86_feature_detected!("lzcnt")` macro then
         // internally maps to Feature::abm.
         enable(extended_proc_info_ecx, 5, Feature::abm);
-        if vendor_id == *b"AuthenticAMD" {
+        // Both AMD and Intel (since Ice Lake-SP) report WBNOINVD here:
+        enable(extended_capabilities_ebx, 9, Feature::wbnoinvd);
+        if is_amd {
             // These features are only available on AMD CPUs:
             enable(extended_proc_info_ecx, 6, Feature::sse4a);
+            enable(extended_proc_info_ecx, 15, Feature::lwp);
             enable(extended_proc_info_ecx, 21, Feature::tbm);
+            enable(extended_proc_info_ecx, 29, Feature::mwaitx);
+
+            enable(extended_capabilities_ebx, 0, Feature::clzero);
+            enable(extended_capabilities_ebx, 4, Feature::rdpru);
         }
     }
 
     value
 }
 
+/// Type of a core of a hybrid processor.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CoreType {
+    /// Intel Atom, the efficiency cores ("E-cores").
+    Atom,
+    /// Intel Core, the performance cores ("P-cores").
+    Core,
+    /// A core type that is not known yet.
+    Other(u8),
+}
+
+/// Hybrid processor information of the core the calling thread ran on.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct HybridInfo {
+    hybrid: bool,
+    core_type: Option<CoreType>,
+    native_model_id: u32,
+}
+
+impl HybridInfo {
+    /// Is the processor hybrid, that is, does it mix cores of different
+    /// types (`CPUID.(EAX=07H,ECX=0):EDX[15]`)?
+    #[inline]
+    pub fn is_hybrid(self) -> bool {
+        self.hybrid
+    }
+
+    /// Type of the core (`CPUID.(EAX=1AH,ECX=0):EAX[31:24]`), if the processor
+    /// reports it.
+    #[inline]
+    pub fn core_type(self) -> Option<CoreType> {
+        self.core_type
+    }
+
+    /// Native model ID of the core (`CPUID.(EAX=1AH,ECX=0):EAX[23:0]`), or
+    /// `0` if the processor does not report it.
+    #[inline]
+    pub fn native_model_id(self) -> u32 {
+        self.native_model_id
+    }
+}
+
+/// Queries the hybrid processor information using the "Native Model ID
+/// Enumeration" CPUID leaf (EAX = 1AH).
+///
+/// Unlike the detected features, the result is not cached: the core type and
+/// native model ID are those of the core the calling thread was running on
+/// when this function executed CPUID. Unless the thread is pinned to a core,
+/// the OS may migrate it to a core of a different type at any time.
+#[inline]
+pub fn hybrid_info() -> HybridInfo {
+    if !has_cpuid() {
+        return HybridInfo {
+            hybrid: false,
+            core_type: None,
+            native_model_id: 0,
+        };
+    }
+
+    hybrid_info_on(&mut CurrentCpu)
+}
+
+/// Queries the hybrid processor information of `cpu`, see `hybrid_info`.
+fn hybrid_info_on<C: Cpu>(cpu: &mut C) -> HybridInfo {
+    let mut info = HybridInfo {
+        hybrid: false,
+        core_type: None,
+        native_model_id: 0,
+    };
+
+    let max_basic_leaf = cpu.cpuid(0, 0).eax;
+    if max_basic_leaf < 7 {
+        return info;
+    }
+    let CpuidResult { edx, .. } = cpu.cpuid(0x0000_0007_u32, 0);
+    info.hybrid = bit::test(edx as usize, 15);
+
+    if max_basic_leaf >= 0x1a {
+        let CpuidResult { eax, .. } = cpu.cpuid(0x1a_u32, 0);
+        // All zeros if the leaf is not supported by the current core:
+        if eax != 0 {
+            info.core_type = Some(match (eax >> 24) as u8 {
+                0x20 => CoreType::Atom,
+                0x40 => CoreType::Core,
+                t => CoreType::Other(t),
+            });
+            info.native_model_id = eax & 0x00ff_ffff;
+        }
+    }
+
+    info
+}
+
 #[cfg(test)]
 mod tests {
     extern crate cupid;
 
+    use super::{
+        detect_features_on, hybrid_info, hybrid_info_on, CoreType, Cpu,
+        CpuidResult,
+    };
+    use crate::detect::report::Recorder;
+    use crate::detect::Feature;
+    use std::vec::Vec;
+
+    /// A CPU that reports made-up CPUID values; unlisted leaves are zero.
+    struct FakeCpu {
+        leaves: Vec<(u32, u32, CpuidResult)>,
+        xcr0: u64,
+    }
+
+    impl Cpu for FakeCpu {
+        fn cpuid(&mut self, leaf: u32, sub_leaf: u32) -> CpuidResult {
+            self.leaves
+                .iter()
+                .find(|&&(l, s, _)| l == leaf && s == sub_leaf)
+                .map(|&(_, _, res)| res)
+                .unwrap_or(CpuidResult {
+                    eax: 0,
+                    ebx: 0,
+                    ecx: 0,
+                    edx: 0,
+                })
+        }
+
+        fn xcr0(&mut self) -> u64 {
+            self.xcr0
+        }
+    }
+
+    /// Records the vetoed features.
+    #[derive(Default)]
+    struct Vetoes(Vec<(&'static str, &'static str)>);
+
+    impl Recorder for Vetoes {
+        fn veto(&mut self, feature: Feature, reason: &'static str) {
+            self.0.push((feature.to_str(), reason));
+        }
+    }
+
+    /// An Intel CPU with AVX, AVX2, AVX-512F and AVX-512BW, running on an OS
+    /// that saves the AVX-512 state.
+    fn avx512_cpu(hybrid: bool) -> FakeCpu {
+        let cpuid = |eax, ebx, ecx, edx| CpuidResult { eax, ebx, ecx, edx };
+        FakeCpu {
+            leaves: [
+                // Maximum basic leaf 7, "GenuineIntel":
+                (0, 0, cpuid(7, 0x756e_6547, 0x6c65_746e, 0x4965_6e69)),
+                // XSAVE, OSXSAVE and AVX:
+                (1, 0, cpuid(0, 0, (1 << 26) | (1 << 27) | (1 << 28), 0)),
+                // AVX2, AVX-512F, AVX-512BW and the hybrid flag:
+                (
+                    7,
+                    0,
+                    cpuid(
+                        0,
+                        (1 << 5) | (1 << 16) | (1 << 30),
+                        0,
+                        if hybrid { 1 << 15 } else { 0 },
+                    ),
+                ),
+            ]
+            .to_vec(),
+            xcr0: 0xe7,
+        }
+    }
+
+    #[test]
+    fn avx512_not_vetoed_on_non_hybrid() {
+        let mut vetoes = Vetoes::default();
+        let value = detect_features_on(&mut avx512_cpu(false), &mut vetoes);
+        assert!(value.test(Feature::avx2 as u32));
+        assert!(value.test(Feature::avx512f as u32));
+        assert!(value.test(Feature::avx512bw as u32));
+        assert!(vetoes.0.is_empty());
+    }
+
+    #[test]
+    fn avx512_vetoed_on_hybrid() {
+        let mut vetoes = Vetoes::default();
+        let value = detect_features_on(&mut avx512_cpu(true), &mut vetoes);
+        assert!(value.test(Feature::avx2 as u32));
+        assert!(!value.test(Feature::avx512f as u32));
+        assert!(!value.test(Feature::avx512bw as u32));
+        let vetoed: Vec<_> = vetoes.0.iter().map(|&(f, _)| f).collect();
+        assert_eq!(vetoed, ["avx512f", "avx512bw"]);
+        for &(_, reason) in &vetoes.0 {
+            assert!(reason.contains("hybrid"));
+        }
+    }
+
+    /// A hybrid CPU whose current core reports `native_model` in the "Native
+    /// Model ID Enumeration" leaf.
+    fn hybrid_cpu(native_model: u32) -> FakeCpu {
+        let cpuid = |eax, edx| CpuidResult {
+            eax,
+            ebx: 0,
+            ecx: 0,
+            edx,
+        };
+        FakeCpu {
+            leaves: [
+                (0, 0, cpuid(0x1a, 0)),
+                (7, 0, cpuid(0, 1 << 15)),
+                (0x1a, 0, cpuid(native_model, 0)),
+            ]
+            .to_vec(),
+            xcr0: 0,
+        }
+    }
+
+    #[test]
+    fn hybrid_info_atom() {
+        let info = hybrid_info_on(&mut hybrid_cpu(0x2000_0001));
+        assert!(info.is_hybrid());
+        assert_eq!(info.core_type(), Some(CoreType::Atom));
+        assert_eq!(info.native_model_id(), 1);
+    }
+
+    #[test]
+    fn hybrid_info_core() {
+        let info = hybrid_info_on(&mut hybrid_cpu(0x4000_0002));
+        assert!(info.is_hybrid());
+        assert_eq!(info.core_type(), Some(CoreType::Core));
+        assert_eq!(info.native_model_id(), 2);
+    }
+
+    #[test]
+    fn hybrid_info_unsupported_leaf() {
+        // The leaf is all zeros on cores that do not support it:
+        let info = hybrid_info_on(&mut hybrid_cpu(0));
+        assert!(info.is_hybrid());
+        assert_eq!(info.core_type(), None);
+        assert_eq!(info.native_model_id(), 0);
+    }
+
+    #[test]
+    fn dump_hybrid_info() {
+        let info = hybrid_info();
+        println!("hybrid: {:?}", info.is_hybrid());
+        println!("core type: {:?}", info.core_type());
+        println!("native model id: {:#x}", info.native_model_id());
+        if info.is_hybrid() {
+            // AVX-512 is never reported on hybrid processors:
+            assert!(!is_x86_feature_detected!("avx512f"));
+        }
+        if info.core_type().is_none() {
+            assert_eq!(info.native_model_id(), 0);
+        }
+    }
+
     #[test]
     fn dump() {
         println!("aes: {:?}", is_x86_feature_detected!("aes"));
         println!("pclmulqdq: {:?}", is_x86_feature_detected!("pclmulqdq"));
+        println!("vaes: {:?}", is_x86_feature_detected!("vaes"));
+        println!("vpclmulqdq: {:?}", is_x86_feature_detected!("vpclmulqdq"));
         println!("rdrand: {:?}", is_x86_feature_detected!("rdrand"));
         println!("rdseed: {:?}", is_x86_feature_detected!("rdseed"));
         println!("tsc: {:?}", is_x86_feature_detected!("tsc"));
@@ -261,6 +697,7 @@ mod tests {
         println!("sse4.2: {:?}", is_x86_feature_detected!("sse4.2"));
         println!("sse4a: {:?}", is_x86_feature_detected!("sse4a"));
         println!("sha: {:?}", is_x86_feature_detected!("sha"));
+        println!("gfni: {:?}", is_x86_feature_detected!("gfni"));
         println!("avx: {:?}", is_x86_feature_detected!("avx"));
         println!("avx2: {:?}", is_x86_feature_detected!("avx2"));
         println!("avx512f {:?}", is_x86_feature_detected!("avx512f"));
@@ -272,10 +709,17 @@ mod tests {
         println!("avx512vl {:?}", is_x86_feature_detected!("avx512vl"));
         println!("avx512_ifma {:?}", is_x86_feature_detected!("avx512ifma"));
         println!("avx512_vbmi {:?}", is_x86_feature_detected!("avx512vbmi"));
+        println!("avx512_vbmi2 {:?}", is_x86_feature_detected!("avx512vbmi2"));
+        println!("avx512_vnni {:?}", is_x86_feature_detected!("avx512vnni"));
+        println!("avxvnni {:?}", is_x86_feature_detected!("avxvnni"));
         println!(
             "avx512_vpopcntdq {:?}",
             is_x86_feature_detected!("avx512vpopcntdq")
         );
+        println!("avx512_bitalg {:?}", is_x86_feature_detected!("avx512bitalg"));
+        println!("avx512_bf16 {:?}", is_x86_feature_detected!("avx512bf16"));
+        println!("avx512_fp16 {:?}", is_x86_feature_detected!("avx512fp16"));
+        println!("f16c: {:?}", is_x86_feature_detected!("f16c"));
         println!("fma: {:?}", is_x86_feature_detected!("fma"));
         println!("abm: {:?}", is_x86_feature_detected!("abm"));
         println!("bmi: {:?}", is_x86_feature_detected!("bmi1"));
@@ -290,6 +734,13 @@ mod tests {
         println!("xsavec: {:?}", is_x86_feature_detected!("xsavec"));
         println!("cmpxchg16b: {:?}", is_x86_feature_detected!("cmpxchg16b"));
         println!("adx: {:?}", is_x86_feature_detected!("adx"));
+        println!("xop: {:?}", is_x86_feature_detected!("xop"));
+        println!("fma4: {:?}", is_x86_feature_detected!("fma4"));
+        println!("lwp: {:?}", is_x86_feature_detected!("lwp"));
+        println!("mwaitx: {:?}", is_x86_feature_detected!("mwaitx"));
+        println!("clzero: {:?}", is_x86_feature_detected!("clzero"));
+        println!("rdpru: {:?}", is_x86_feature_detected!("rdpru"));
+        println!("wbnoinvd: {:?}", is_x86_feature_detected!("wbnoinvd"));
     }
 
     #[test]
@@ -329,6 +780,7 @@ mod tests {
             is_x86_feature_detected!("avx512vpopcntdq"),
             information.avx512_vpopcntdq()
         );
+        assert_eq!(is_x86_feature_detected!("f16c"), information.f16c());
         assert_eq!(is_x86_feature_detected!("fma"), information.fma());
         assert_eq!(is_x86_feature_detected!("bmi1"), information.bmi1());
         assert_eq!(is_x86_feature_detected!("bmi2"), information.bmi2());
@@ -0,0 +1,94 @@
+//! Diagnostic reports of the run-time feature detection.
+//!
+//! The OS-specific `detect_features_with` functions pass the raw values the
+//! detection is based on (CPUID leaves, ELF auxiliary vector entries,
+//! `/proc/cpuinfo` fields, ...) and the features that are disabled despite
+//! the hardware reporting them (the "vetoes") to a `Recorder`. The regular,
+//! cached, detection uses the `()` recorder, which discards everything.
+
+use core::fmt;
+
+use crate::detect::Feature;
+
+/// Receives the raw data of a run-time feature detection run.
+pub(crate) trait Recorder {
+    /// Records the raw `value` read from `source`.
+    fn source(&mut self, _source: fmt::Arguments, _value: fmt::Arguments) {}
+
+    /// Records that `feature` is disabled although the hardware reports it.
+    fn veto(&mut self, _feature: Feature, _reason: &'static str) {}
+}
+
+impl Recorder for () {}
+
+#[cfg(feature = "std_detect_file_io")]
+pub use self::std_report::*;
+
+#[cfg(feature = "std_detect_file_io")]
+mod std_report {
+    use super::Recorder;
+    use crate::detect::{check_for, os, Feature};
+    use core::fmt;
+    use std::prelude::v1::*;
+
+    /// A diagnostic report of the run-time feature detection.
+    #[derive(Debug, Clone)]
+    pub struct Report {
+        features: Vec<(&'static str, bool)>,
+        sources: Vec<(String, String)>,
+        vetoes: Vec<(&'static str, &'static str)>,
+    }
+
+    impl Report {
+        /// All features known to the current architecture, and whether they
+        /// are detected at run-time.
+        #[inline]
+        pub fn features(&self) -> &[(&'static str, bool)] {
+            &self.features
+        }
+
+        /// The raw values the detection is based on, for example
+        /// `("cpuid(0x7, 0x0)", "eax=... ebx=... ecx=... edx=...")` or
+        /// `("AT_HWCAP", "0x...")`.
+        #[inline]
+        pub fn sources(&self) -> &[(String, String)] {
+            &self.sources
+        }
+
+        /// The features that are disabled although the hardware reports
+        /// them, and why.
+        #[inline]
+        pub fn vetoes(&self) -> &[(&'static str, &'static str)] {
+            &self.vetoes
+        }
+    }
+
+    impl Recorder for Report {
+        fn source(&mut self, source: fmt::Arguments, value: fmt::Arguments) {
+            self.sources.push((source.to_string(), value.to_string()));
+        }
+
+        fn veto(&mut self, feature: Feature, reason: &'static str) {
+            self.vetoes.push((feature.to_str(), reason));
+        }
+    }
+
+    /// Runs the run-time feature detection again, and reports its raw data.
+    ///
+    /// The reported features are the cached ones, that is, those returned by
+    /// the `is_{arch}_feature_detected!` macros.
+    #[inline]
+    pub fn report() -> Report {
+        let mut report = Report {
+            features: Vec::new(),
+            sources: Vec::new(),
+            vetoes: Vec::new(),
+        };
+        os::detect_features_with(&mut report);
+        report.features = Feature::ALL
+            .iter()
+            .map(|&f| (f.to_str(), check_for(f)))
+            .collect();
+        report
+    }
+}
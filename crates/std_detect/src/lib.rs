@@ -44,7 +44,7 @@ cfg_if! {
     }
 }
 
-#[cfg(feature = "std_detect_dlsym_getauxval")]
+#[cfg(feature = "libc")]
 extern crate libc;
 
 #[doc(hidden)]
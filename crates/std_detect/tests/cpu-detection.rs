@@ -40,6 +40,10 @@ fn aarch64_linux() {
     println!("rdm: {}", is_aarch64_feature_detected!("rdm"));
     println!("rcpc: {}", is_aarch64_feature_detected!("rcpc"));
     println!("dotprod: {}", is_aarch64_feature_detected!("dotprod"));
+    println!("paca: {}", is_aarch64_feature_detected!("paca"));
+    println!("pacg: {}", is_aarch64_feature_detected!("pacg"));
+    println!("bti: {}", is_aarch64_feature_detected!("bti"));
+    println!("mte: {}", is_aarch64_feature_detected!("mte"));
 }
 
 #[test]
@@ -63,6 +67,8 @@ fn powerpc64_linux() {
 fn x86_all() {
     println!("aes: {:?}", is_x86_feature_detected!("aes"));
     println!("pcmulqdq: {:?}", is_x86_feature_detected!("pclmulqdq"));
+    println!("vaes: {:?}", is_x86_feature_detected!("vaes"));
+    println!("vpclmulqdq: {:?}", is_x86_feature_detected!("vpclmulqdq"));
     println!("rdrand: {:?}", is_x86_feature_detected!("rdrand"));
     println!("rdseed: {:?}", is_x86_feature_detected!("rdseed"));
     println!("tsc: {:?}", is_x86_feature_detected!("tsc"));
@@ -75,6 +81,7 @@ fn x86_all() {
     println!("sse4.2: {:?}", is_x86_feature_detected!("sse4.2"));
     println!("sse4a: {:?}", is_x86_feature_detected!("sse4a"));
     println!("sha: {:?}", is_x86_feature_detected!("sha"));
+    println!("gfni: {:?}", is_x86_feature_detected!("gfni"));
     println!("avx: {:?}", is_x86_feature_detected!("avx"));
     println!("avx2: {:?}", is_x86_feature_detected!("avx2"));
     println!("avx512f {:?}", is_x86_feature_detected!("avx512f"));
@@ -86,10 +93,17 @@ fn x86_all() {
     println!("avx512vl {:?}", is_x86_feature_detected!("avx512vl"));
     println!("avx512_ifma {:?}", is_x86_feature_detected!("avx512ifma"));
     println!("avx512_vbmi {:?}", is_x86_feature_detected!("avx512vbmi"));
+    println!("avx512_vbmi2 {:?}", is_x86_feature_detected!("avx512vbmi2"));
+    println!("avx512_vnni {:?}", is_x86_feature_detected!("avx512vnni"));
+    println!("avxvnni {:?}", is_x86_feature_detected!("avxvnni"));
     println!(
         "avx512_vpopcntdq {:?}",
         is_x86_feature_detected!("avx512vpopcntdq")
     );
+    println!("avx512_bitalg {:?}", is_x86_feature_detected!("avx512bitalg"));
+    println!("avx512_bf16 {:?}", is_x86_feature_detected!("avx512bf16"));
+    println!("avx512_fp16 {:?}", is_x86_feature_detected!("avx512fp16"));
+    println!("f16c: {:?}", is_x86_feature_detected!("f16c"));
     println!("fma: {:?}", is_x86_feature_detected!("fma"));
     println!("bmi1: {:?}", is_x86_feature_detected!("bmi1"));
     println!("bmi2: {:?}", is_x86_feature_detected!("bmi2"));
@@ -102,4 +116,25 @@ fn x86_all() {
     println!("xsaveopt: {:?}", is_x86_feature_detected!("xsaveopt"));
     println!("xsaves: {:?}", is_x86_feature_detected!("xsaves"));
     println!("xsavec: {:?}", is_x86_feature_detected!("xsavec"));
+    println!("xop: {:?}", is_x86_feature_detected!("xop"));
+    println!("fma4: {:?}", is_x86_feature_detected!("fma4"));
+    println!("lwp: {:?}", is_x86_feature_detected!("lwp"));
+    println!("mwaitx: {:?}", is_x86_feature_detected!("mwaitx"));
+    println!("clzero: {:?}", is_x86_feature_detected!("clzero"));
+    println!("rdpru: {:?}", is_x86_feature_detected!("rdpru"));
+    println!("wbnoinvd: {:?}", is_x86_feature_detected!("wbnoinvd"));
+}
+
+#[test]
+#[cfg(feature = "std_detect_file_io")]
+fn report() {
+    let report = std_detect::detect::report();
+    for &(ref name, ref value) in report.sources() {
+        println!("{}: {}", name, value);
+    }
+    // Vetoed features are never detected:
+    for &(name, reason) in report.vetoes() {
+        println!("{} vetoed: {}", name, reason);
+        assert!(report.features().iter().any(|&(n, d)| n == name && !d));
+    }
 }
@@ -28,6 +28,7 @@ struct Function {
     required_const: &'static [usize],
 }
 
+static F16: Type = Type::PrimFloat(16);
 static F32: Type = Type::PrimFloat(32);
 static F64: Type = Type::PrimFloat(64);
 static I16: Type = Type::PrimSigned(16);
@@ -51,13 +52,26 @@ static M256D: Type = Type::M256D;
 static M512: Type = Type::M512;
 static M512I: Type = Type::M512I;
 static M512D: Type = Type::M512D;
+static M128BH: Type = Type::M128BH;
+static M256BH: Type = Type::M256BH;
+static M512BH: Type = Type::M512BH;
+static M128H: Type = Type::M128H;
+static M256H: Type = Type::M256H;
+static M512H: Type = Type::M512H;
+static MMASK8: Type = Type::MMASK8;
 static MMASK16: Type = Type::MMASK16;
+static MMASK32: Type = Type::MMASK32;
+static MMASK64: Type = Type::MMASK64;
+static MM_CMPINT_ENUM: Type = Type::MM_CMPINT_ENUM;
+static MM_MANTISSA_NORM_ENUM: Type = Type::MM_MANTISSA_NORM_ENUM;
+static MM_MANTISSA_SIGN_ENUM: Type = Type::MM_MANTISSA_SIGN_ENUM;
 
 static TUPLE: Type = Type::Tuple;
 static CPUID: Type = Type::CpuidResult;
 static NEVER: Type = Type::Never;
 
 #[derive(Debug)]
+#[allow(non_camel_case_types)]
 enum Type {
     PrimFloat(u8),
     PrimSigned(u8),
@@ -73,7 +87,19 @@ enum Type {
     M512,
     M512D,
     M512I,
+    M128BH,
+    M256BH,
+    M512BH,
+    M128H,
+    M256H,
+    M512H,
+    MMASK8,
     MMASK16,
+    MMASK32,
+    MMASK64,
+    MM_CMPINT_ENUM,
+    MM_MANTISSA_NORM_ENUM,
+    MM_MANTISSA_SIGN_ENUM,
     Tuple,
     CpuidResult,
     Never,
@@ -293,6 +319,16 @@ fn matches(rust: &Function, intel: &Intrinsic) -> Result<(), String> {
         // name with the Rust name.
         let fixup_cpuid = |cpuid: String| match cpuid.as_ref() {
             "avx512ifma52" => String::from("avx512ifma"),
+            // The newer AVX-512 extensions are spelled with an underscore in
+            // the XML file, e.g. "AVX512_VBMI" rather than "avx512vbmi".
+            "avx512_vbmi" => String::from("avx512vbmi"),
+            "avx512_vbmi2" => String::from("avx512vbmi2"),
+            "avx512_vnni" => String::from("avx512vnni"),
+            "avx_vnni" => String::from("avxvnni"),
+            "avx512_bitalg" => String::from("avx512bitalg"),
+            "avx512_vpopcntdq" => String::from("avx512vpopcntdq"),
+            "avx512_bf16" => String::from("avx512bf16"),
+            "avx512_fp16" => String::from("avx512fp16"),
             _ => cpuid,
         };
         let fixed_cpuid = fixup_cpuid(cpuid);
@@ -384,7 +420,9 @@ fn matches(rust: &Function, intel: &Intrinsic) -> Result<(), String> {
         // Apparently all of clang/msvc/gcc accept these intrinsics on
         // 32-bit, so let's do the same
         "_mm_set_epi64x" | "_mm_set1_epi64x" | "_mm256_set_epi64x" | "_mm256_setr_epi64x"
-        | "_mm256_set1_epi64x" | "_mm512_set1_epi64" => true,
+        | "_mm256_set1_epi64x" | "_mm512_set1_epi64" | "_mm512_set_epi64" | "_mm512_setr_epi64" => {
+            true
+        }
 
         // These return a 64-bit argument but they're assembled from other
         // 32-bit registers, so these work on 32-bit just fine. See #308 for
@@ -413,6 +451,7 @@ fn equate(t: &Type, intel: &str, intrinsic: &str, is_const: bool) -> Result<(),
         Err(format!("argument required to be const but isn't"))
     };
     match (t, &intel[..]) {
+        (&Type::PrimFloat(16), "_Float16") => {}
         (&Type::PrimFloat(32), "float") => {}
         (&Type::PrimFloat(64), "double") => {}
         (&Type::PrimSigned(16), "__int16") => {}
@@ -437,6 +476,7 @@ fn equate(t: &Type, intel: &str, intrinsic: &str, is_const: bool) -> Result<(),
         (&Type::Ptr(&Type::PrimSigned(8)), "char*") => {}
         (&Type::Ptr(&Type::PrimUnsigned(16)), "unsigned short*") => {}
         (&Type::Ptr(&Type::PrimUnsigned(32)), "unsigned int*") => {}
+        (&Type::Ptr(&Type::PrimUnsigned(8)), "unsigned char*") => {}
         (&Type::Ptr(&Type::PrimUnsigned(64)), "unsigned __int64*") => {}
         (&Type::Ptr(&Type::PrimUnsigned(8)), "const void*") => {}
         (&Type::Ptr(&Type::PrimUnsigned(8)), "void*") => {}
@@ -464,7 +504,25 @@ fn equate(t: &Type, intel: &str, intrinsic: &str, is_const: bool) -> Result<(),
         | (&Type::M512, "__m512")
         | (&Type::Ptr(&Type::M512), "__m512*") => {}
 
+        (&Type::M128BH, "__m128bh")
+        | (&Type::M256BH, "__m256bh")
+        | (&Type::M512BH, "__m512bh") => {}
+
+        (&Type::M128H, "__m128h")
+        | (&Type::M256H, "__m256h")
+        | (&Type::M512H, "__m512h") => {}
+
+        (&Type::MMASK8, "__mmask8") => {}
         (&Type::MMASK16, "__mmask16") => {}
+        (&Type::MMASK32, "__mmask32") => {}
+        (&Type::MMASK64, "__mmask64") => {}
+        (&Type::Ptr(&Type::MMASK8), "__mmask8*")
+        | (&Type::Ptr(&Type::MMASK16), "__mmask16*")
+        | (&Type::Ptr(&Type::MMASK32), "__mmask32*")
+        | (&Type::Ptr(&Type::MMASK64), "__mmask64*") => {}
+        (&Type::MM_CMPINT_ENUM, "_MM_CMPINT_ENUM") => {}
+        (&Type::MM_MANTISSA_NORM_ENUM, "_MM_MANTISSA_NORM_ENUM") => {}
+        (&Type::MM_MANTISSA_SIGN_ENUM, "_MM_MANTISSA_SIGN_ENUM") => {}
 
         // This is a macro (?) in C which seems to mutate its arguments, but
         // that means that we're taking pointers to arguments in rust